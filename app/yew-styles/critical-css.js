@@ -0,0 +1,174 @@
+#!/usr/bin/env node
+const fs = require('fs');
+const path = require('path');
+const sass = require('sass');
+
+// Sass modules needed by each yew_styles cargo feature, ci/run_checks.sh checks it against crate/yew_styles/Cargo.toml
+const features = {
+    layouts: ['_layout.sass'],
+    button: ['_button.sass'],
+    navbar: ['_layout.sass', '_navbar.sass'],
+    forms: ['_form.sass', '_form-range-slider.sass'],
+    form_component: ['_form.sass'],
+    form_group: ['_form.sass'],
+    form_label: ['_form.sass'],
+    form_input: ['_form.sass'],
+    form_textarea: ['_form.sass'],
+    form_select: ['_form.sass'],
+    form_file: ['_form.sass'],
+    form_submit: ['_form.sass'],
+    card: ['_layout.sass', '_card.sass'],
+    modal: ['_modal.sass'],
+    text: ['_text.sass'],
+    dropdown: ['_click-away.sass', '_dropdown.sass'],
+    spinner: ['_spinkit.sass', '_spinner.sass'],
+    carousel: ['_carousel.sass'],
+    tooltip: ['_tooltip.sass'],
+    calendar: ['_calendar.sass'],
+    toast: ['_toast.sass', '_transition-group.sass'],
+    typography: ['_typography.sass'],
+    sidenav: ['_layout.sass', '_navbar.sass', '_sidenav.sass'],
+    image_zoom: ['_image-zoom.sass'],
+    compare_slider: ['_compare-slider.sass'],
+    video_thumb: ['_modal.sass', '_video-thumb.sass'],
+    pdf_viewer: ['_pdf-viewer.sass'],
+    embed: ['_embed.sass'],
+    highlight: ['_highlight.sass'],
+    icon: ['_icon.sass'],
+    count_up: ['_count-up.sass'],
+    truncate_middle: ['_truncate-middle.sass'],
+    click_away: ['_click-away.sass'],
+    secret_text: ['_secret-text.sass'],
+    filterable_list: ['_highlight.sass', '_filterable-list.sass'],
+    availability_grid: ['_availability-grid.sass'],
+    surface: ['_surface.sass'],
+    seat_map: ['_seat-map.sass'],
+    status_page: ['_status-page.sass'],
+    changelog: ['_changelog.sass'],
+    document_viewer: ['_document-viewer.sass'],
+    copy_button: ['_button.sass', '_copy-button.sass'],
+    empty_state: ['_empty-state.sass'],
+    transition_group: ['_transition-group.sass'],
+    confetti: ['_confetti.sass'],
+    stat: ['_icon.sass', '_stat.sass'],
+    list: ['_list.sass'],
+    menu: ['_click-away.sass', '_icon.sass', '_menu.sass'],
+    scrollspy: ['_scrollspy.sass'],
+    affix: ['_affix.sass'],
+    back_to_top: ['_icon.sass', '_back-to-top.sass'],
+    sticky: ['_sticky.sass'],
+    gallery: ['_icon.sass', '_gallery.sass'],
+    form_range_slider: ['_form-range-slider.sass'],
+    infinite_scroll: ['_spinkit.sass', '_spinner.sass', '_infinite-scroll.sass'],
+    sortable_list: ['_icon.sass', '_sortable-list.sass'],
+    split_pane: ['_split-pane.sass'],
+    form_builder: ['_form.sass'],
+    form_error_summary: ['_form.sass'],
+    loading_overlay: ['_spinkit.sass', '_spinner.sass', '_loading-overlay.sass'],
+};
+
+// Sass modules shared by every feature, like the density and the utilities of the elevation, margin and padding properties
+const baseModules = ['_density.sass', '_elevation.sass', '_spacing.sass', '_animations.sass', '_ripple.sass'];
+
+const styleId = 'yew-styles-critical';
+
+function usage() {
+    console.log(`Usage: yew-styles-critical [options] <features...>
+
+Compile the styles of the selected yew_styles features into a static css file
+
+Options:
+  --out <file>     write the css in a file instead of the standard output
+  --inline <html>  inline the css in a <style id="${styleId}"> tag inside of the html head
+  --minify         compress the generated css
+
+Features: ${Object.keys(features).join(', ')}, full`);
+}
+
+function parseArgs(args) {
+    const options = { features: [], out: null, inline: null, minify: false };
+
+    for (let i = 0; i < args.length; i++) {
+        switch (args[i]) {
+            case '--out':
+                options.out = args[++i];
+                break;
+            case '--inline':
+                options.inline = args[++i];
+                break;
+            case '--minify':
+                options.minify = true;
+                break;
+            case '--help':
+            case '-h':
+                usage();
+                process.exit(0);
+            default:
+                options.features.push(args[i]);
+        }
+    }
+
+    return options;
+}
+
+function getModules(selected) {
+    const names = selected.includes('full') ? Object.keys(features) : selected;
+
+    const modules = names.reduce((acc, name) => {
+        if (!features[name]) {
+            console.error(`Unknown feature '${name}'`);
+            process.exit(1);
+        }
+
+        return acc.concat(features[name].filter(module => !acc.includes(module)));
+    }, baseModules);
+
+    return modules;
+}
+
+function compile(modules, minify) {
+    const result = sass.renderSync({
+        data: modules.map(module => `@import "${module}"`).join('\n'),
+        indentedSyntax: true,
+        includePaths: [__dirname],
+        outputStyle: minify ? 'compressed' : 'expanded',
+    });
+
+    return result.css.toString();
+}
+
+function inline(htmlPath, css) {
+    const html = fs.readFileSync(htmlPath, 'utf8');
+    const style = `<style id="${styleId}">${css}</style>`;
+    const current = new RegExp(`<style id="${styleId}">[\\s\\S]*?</style>`);
+
+    if (current.test(html)) {
+        return html.replace(current, style);
+    }
+
+    if (!html.includes('</head>')) {
+        console.error(`'${htmlPath}' doesn't contain a head tag`);
+        process.exit(1);
+    }
+
+    return html.replace('</head>', `    ${style}\n</head>`);
+}
+
+const options = parseArgs(process.argv.slice(2));
+
+if (!options.features.length) {
+    usage();
+    process.exit(1);
+}
+
+const css = compile(getModules(options.features), options.minify);
+
+if (options.inline) {
+    fs.writeFileSync(options.inline, inline(path.resolve(options.inline), css));
+    console.log(`Critical css inlined in '${options.inline}' ✅`);
+} else if (options.out) {
+    fs.writeFileSync(options.out, css);
+    console.log(`Critical css written in '${options.out}' ✅`);
+} else {
+    process.stdout.write(css);
+}
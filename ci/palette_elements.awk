@@ -0,0 +1,25 @@
+# Prints the classes of the elements which take the colors of a palette mixin in the sass partial,
+# except the style classes of an element, e.g. `&.regular`, which get the generic rules of the palette
+function indent(line) { match(line, /^ */); return RLENGTH }
+/^ *$/ { next }
+{
+    level = indent($0)
+    while (depth > 0 && levels[depth] >= level) depth--
+}
+/@include (pallete-style|modal-bg|spinner-style|arrow-style|spinner-plane-style|dropdown-style|carousel-dot-style|carousel-indicator-style|navbar-style|sidenav-style|form-style)\(/ {
+    styled = selectors[depth] ~ /^&/
+    if (styled && $0 ~ /pallete-style\(.*, *false\)/) next
+
+    for (i = depth; i > 0 && selectors[i] ~ /^&/; i--);
+    rest = selectors[i]
+    while (match(rest, /\.[a-z][a-z-]*/)) {
+        print substr(rest, RSTART, RLENGTH)
+        rest = substr(rest, RSTART + RLENGTH)
+    }
+    next
+}
+/^ *[.&a-z]/ && $0 !~ /:( |$)/ || /^ *&:/ {
+    depth++
+    levels[depth] = level
+    selectors[depth] = substr($0, level + 1)
+}
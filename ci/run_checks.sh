@@ -3,4 +3,55 @@ set -euxo pipefail
 
 cargo fmt --manifest-path=crate/yew_styles/Cargo.toml --all -- --check
 cargo clippy --manifest-path=crate/yew_styles/Cargo.toml --all --all-features -- --deny=warnings
+cargo fmt --manifest-path=crate/yew_styles_macro/Cargo.toml --all -- --check
+cargo clippy --manifest-path=crate/yew_styles_macro/Cargo.toml --all -- --deny=warnings
+
+# main.css is committed next to the sass partials, so every change of a partial must rebuild it in the same commit
+npm install --no-save --prefix app/yew-styles
+compiled_css=$(mktemp -d)
+app/yew-styles/node_modules/.bin/sass app/yew-styles/main.sass "$compiled_css/main.css"
+if ! diff -q "$compiled_css/main.css" app/yew-styles/main.css; then
+    echo "app/yew-styles/main.css is not built from the sass partials, run: sass app/yew-styles/main.sass app/yew-styles/main.css"
+    exit 1
+fi
+
+# The feature map of the critical css cli is written by hand, every cargo feature with a sass partial
+# must be in it, with its partial, and every partial of a component must be used by a feature
+features=$(sed -n '/^\[features\]/,/^\[/p' crate/yew_styles/Cargo.toml | grep -oE '^[a-z_]+' | grep -v '^full$')
+critical_css=app/yew-styles/critical-css.js
+
+for feature in $features; do
+    partial="_${feature//_/-}.sass"
+
+    if [[ -f "app/yew-styles/$partial" ]] && ! grep -qE "^    $feature: \[.*'$partial'" "$critical_css"; then
+        echo "The feature '$feature' is missing '$partial' in $critical_css"
+        exit 1
+    fi
+done
+
+for feature in $(sed -n '/^const features = {/,/^};/p' "$critical_css" | grep -oE '^    [a-z_]+:' | tr -d ' :'); do
+    if ! grep -qx "$feature" <<< "$features"; then
+        echo "The feature '$feature' of $critical_css is not a cargo feature"
+        exit 1
+    fi
+done
+
+for partial in app/yew-styles/_*.sass; do
+    partial=$(basename "$partial")
+
+    if [[ "$partial" != "_mixins.sass" && "$partial" != "_global-variables.sass" ]] && ! grep -q "'$partial'" "$critical_css"; then
+        echo "The partial '$partial' is not used by any feature of $critical_css"
+        exit 1
+    fi
+done
+
+# The custom palettes of crate/yew_styles/src/palettes.rs repeat the palette rules of the sass partials,
+# every element which takes a palette in the sass must be listed there
+for class in $(awk -f ci/palette_elements.awk app/yew-styles/_*.sass | sort -u); do
+    if ! grep -qE "\"\\$class[\".: ]" crate/yew_styles/src/palettes.rs; then
+        echo "The palette element '$class' is missing in crate/yew_styles/src/palettes.rs"
+        exit 1
+    fi
+done
+
 wasm-pack test --headless --chrome --firefox crate
\ No newline at end of file
@@ -1,6 +1,14 @@
 use page::{
-    AssetsPage, BasicFormPage, ButtonPage, CardPage, CarouselPage, DropDownPage, FormPage,
-    HomePage, LayoutsPage, ModalPage, NavbarPage, SpinnerPage, StylistPage, TextPage, TooltipPage,
+    AnnouncerPage, AssetsPage, AvailabilityGridPage, BackToTopPage, BasicFormPage, ButtonPage,
+    CalendarPage, CardPage, CarouselPage, ChangelogPage, ClickAwayPage, CompareSliderPage,
+    ConfettiPage, CopyButtonPage, CountUpPage, DocumentViewerPage, DropDownPage, EmbedPage,
+    EmptyStatePage, FilterableListPage, FocusTrapPage, FormPage, GalleryPage, HighlightPage,
+    HomePage, IconPage, ImageZoomPage, InfiniteScrollPage, LayoutsPage, ListPage,
+    LoadingOverlayPage, MemoPage, MenuPage, ModalPage, NavbarPage, PdfViewerPage, PortalPage,
+    ScrollspyPage, SeatMapPage, SecretTextPage, SideNavPage, SortableListPage, SpinnerPage,
+    SplitPanePage, StatPage, StickyPage, StylistPage, SurfacePage, SystemStatusPage, TextPage,
+    ThemePage, ToastPage, TooltipPage, TransitionGroupPage, TruncateMiddlePage, TypographyPage,
+    VideoThumbPage,
 };
 use yew::prelude::*;
 use yew_router::{prelude::*, route::Route, switch::Permissive, Switch};
@@ -43,6 +51,90 @@ pub enum AppRouter {
     TooltipPath,
     #[to = "/stylist"]
     StylistPagePath,
+    #[to = "/calendar!"]
+    CalendarPath,
+    #[to = "/memo!"]
+    MemoPath,
+    #[to = "/toast!"]
+    ToastPath,
+    #[to = "/typography!"]
+    TypographyPath,
+    #[to = "/sidenav!"]
+    SideNavPath,
+    #[to = "/focus-trap!"]
+    FocusTrapPath,
+    #[to = "/image-zoom!"]
+    ImageZoomPath,
+    #[to = "/compare-slider!"]
+    CompareSliderPath,
+    #[to = "/announcer!"]
+    AnnouncerPath,
+    #[to = "/video-thumb!"]
+    VideoThumbPath,
+    #[to = "/pdf-viewer!"]
+    PdfViewerPath,
+    #[to = "/embed!"]
+    EmbedPath,
+    #[to = "/highlight!"]
+    HighlightPath,
+    #[to = "/icon!"]
+    IconPath,
+    #[to = "/count-up!"]
+    CountUpPath,
+    #[to = "/truncate-middle!"]
+    TruncateMiddlePath,
+    #[to = "/click-away!"]
+    ClickAwayPath,
+    #[to = "/secret-text!"]
+    SecretTextPath,
+    #[to = "/theme!"]
+    ThemePath,
+    #[to = "/filterable-list!"]
+    FilterableListPath,
+    #[to = "/availability-grid!"]
+    AvailabilityGridPath,
+    #[to = "/surface!"]
+    SurfacePath,
+    #[to = "/seat-map!"]
+    SeatMapPath,
+    #[to = "/portal!"]
+    PortalPath,
+    #[to = "/status-page!"]
+    StatusPagePath,
+    #[to = "/changelog!"]
+    ChangelogPath,
+    #[to = "/document-viewer!"]
+    DocumentViewerPath,
+    #[to = "/copy-button!"]
+    CopyButtonPath,
+    #[to = "/empty-state!"]
+    EmptyStatePath,
+    #[to = "/transition-group!"]
+    TransitionGroupPath,
+    #[to = "/confetti!"]
+    ConfettiPath,
+    #[to = "/stat!"]
+    StatPath,
+    #[to = "/list!"]
+    ListPath,
+    #[to = "/menu!"]
+    MenuPath,
+    #[to = "/scrollspy!"]
+    ScrollspyPath,
+    #[to = "/back-to-top!"]
+    BackToTopPath,
+    #[to = "/sticky!"]
+    StickyPath,
+    #[to = "/gallery!"]
+    GalleryPath,
+    #[to = "/infinite-scroll!"]
+    InfiniteScrollPath,
+    #[to = "/sortable-list!"]
+    SortableListPath,
+    #[to = "/split-pane!"]
+    SplitPanePath,
+    #[to = "/loading-overlay!"]
+    LoadingOverlayPath,
     #[to = "/page-not-found"]
     PageNotFound(Permissive<String>),
 }
@@ -120,6 +212,132 @@ impl Component for App {
                         <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
                         <RouterAnchor<AppRouter> route=AppRouter::StylistPagePath>{"Stylist"}</RouterAnchor<AppRouter>>
                     </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::CalendarPath>{"Calendar"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::MemoPath>{"Memo"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ToastPath>{"Toast"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::TypographyPath>{"Typography"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SideNavPath>{"SideNav"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::FocusTrapPath>{"Focus Trap"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ImageZoomPath>{"Image Zoom"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::CompareSliderPath>{"Compare Slider"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::AnnouncerPath>{"Announcer"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::VideoThumbPath>{"Video Thumb"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::PdfViewerPath>{"Pdf Viewer"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::EmbedPath>{"Embed"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::HighlightPath>{"Highlight"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::IconPath>{"Icon"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::CountUpPath>{"Count Up"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::TruncateMiddlePath>{"Truncate Middle"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ClickAwayPath>{"Click Away"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SecretTextPath>{"Secret Text"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ThemePath>{"Theme"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::FilterableListPath>{"Filterable List"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::AvailabilityGridPath>{"Availability Grid"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SurfacePath>{"Surface"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SeatMapPath>{"Seat Map"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::PortalPath>{"Portal"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::StatusPagePath>{"Status Page"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ChangelogPath>{"Changelog"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::DocumentViewerPath>{"Document Viewer"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::CopyButtonPath>{"Copy Button"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::EmptyStatePath>{"Empty State"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::TransitionGroupPath>{"Transition Group"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ConfettiPath>{"Confetti"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::StatPath>{"Stat"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ListPath>{"List"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::MenuPath>{"Menu"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ScrollspyPath>{"Scrollspy and Affix"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::BackToTopPath>{"Back To Top"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::StickyPath>{"Sticky"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::GalleryPath>{"Gallery"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::InfiniteScrollPath>{"Infinite Scroll"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SortableListPath>{"Sortable List"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SplitPanePath>{"Split Pane"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::LoadingOverlayPath>{"Loading Overlay"}</RouterAnchor<AppRouter>>
+                        </Item>
                     </Container>
                 </Item>
                 <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItL(10) )>
@@ -141,6 +359,48 @@ impl Component for App {
                                 AppRouter::CarouselPath => html!{<CarouselPage/>},
                                 AppRouter::TooltipPath => html!{<TooltipPage/>},
                                 AppRouter::StylistPagePath => html!{<StylistPage/>},
+                                AppRouter::CalendarPath => html!{<CalendarPage/>},
+                                AppRouter::MemoPath => html!{<MemoPage/>},
+                                AppRouter::ToastPath => html!{<ToastPage/>},
+                                AppRouter::TypographyPath => html!{<TypographyPage/>},
+                                AppRouter::SideNavPath => html!{<SideNavPage/>},
+                                AppRouter::FocusTrapPath => html!{<FocusTrapPage/>},
+                                AppRouter::ImageZoomPath => html!{<ImageZoomPage/>},
+                                AppRouter::CompareSliderPath => html!{<CompareSliderPage/>},
+                                AppRouter::AnnouncerPath => html!{<AnnouncerPage/>},
+                                AppRouter::VideoThumbPath => html!{<VideoThumbPage/>},
+                                AppRouter::PdfViewerPath => html!{<PdfViewerPage/>},
+                                AppRouter::EmbedPath => html!{<EmbedPage/>},
+                                AppRouter::HighlightPath => html!{<HighlightPage/>},
+                                AppRouter::IconPath => html!{<IconPage/>},
+                                AppRouter::CountUpPath => html!{<CountUpPage/>},
+                                AppRouter::TruncateMiddlePath => html!{<TruncateMiddlePage/>},
+                                AppRouter::ClickAwayPath => html!{<ClickAwayPage/>},
+                                AppRouter::SecretTextPath => html!{<SecretTextPage/>},
+                                AppRouter::ThemePath => html!{<ThemePage/>},
+                                AppRouter::FilterableListPath => html!{<FilterableListPage/>},
+                                AppRouter::AvailabilityGridPath => html!{<AvailabilityGridPage/>},
+                                AppRouter::SurfacePath => html!{<SurfacePage/>},
+                                AppRouter::SeatMapPath => html!{<SeatMapPage/>},
+                                AppRouter::PortalPath => html!{<PortalPage/>},
+                                AppRouter::StatusPagePath => html!{<SystemStatusPage/>},
+                                AppRouter::ChangelogPath => html!{<ChangelogPage/>},
+                                AppRouter::DocumentViewerPath => html!{<DocumentViewerPage/>},
+                                AppRouter::CopyButtonPath => html!{<CopyButtonPage/>},
+                                AppRouter::EmptyStatePath => html!{<EmptyStatePage/>},
+                                AppRouter::TransitionGroupPath => html!{<TransitionGroupPage/>},
+                                AppRouter::ConfettiPath => html!{<ConfettiPage/>},
+                                AppRouter::StatPath => html!{<StatPage/>},
+                                AppRouter::ListPath => html!{<ListPage/>},
+                                AppRouter::MenuPath => html!{<MenuPage/>},
+                                AppRouter::ScrollspyPath => html!{<ScrollspyPage/>},
+                                AppRouter::BackToTopPath => html!{<BackToTopPage/>},
+                                AppRouter::StickyPath => html!{<StickyPage/>},
+                                AppRouter::GalleryPath => html!{<GalleryPage/>},
+                                AppRouter::InfiniteScrollPath => html!{<InfiniteScrollPage/>},
+                                AppRouter::SortableListPath => html!{<SortableListPage/>},
+                                AppRouter::SplitPanePath => html!{<SplitPanePage/>},
+                                AppRouter::LoadingOverlayPath => html!{<LoadingOverlayPage/>},
                                 AppRouter::PageNotFound(Permissive(None)) => html!{"Page not found"},
                                 AppRouter::PageNotFound(Permissive(Some(missed_route))) => html!{format!("Page '{}' not found", missed_route)}
                             }
@@ -0,0 +1,78 @@
+use super::highlighters::get_announcer;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::announcer::{announce, Announcer, Politeness};
+use yew_styles::button::Button;
+use yew_styles::styles::{Palette, Style};
+
+pub struct AnnouncerPage {
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Save,
+    Fail,
+}
+
+impl Component for AnnouncerPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Save => announce("Document saved", Politeness::Polite),
+            Msg::Fail => announce("The document could not be saved", Politeness::Assertive),
+        };
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Announcer Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"announcer"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_announcer()
+                    language="rust"
+                />
+
+                <h2>{"Usage"}</h2>
+                <p>{"Live regions which read to the screen readers the messages sent with "}<code>{"announce"}</code>
+                    {", like the result of a search, a saved form or a finished loading. Mount it once, usually in the root component. If it is not mounted, "}
+                    <code>{"announce"}</code>{" adds the regions at the end of the body."}</p>
+                <ul>
+                    <li><b>{"announce: "}</b>{"read the message with the screen readers, the same message is read again if it is repeated. It receives the message and the "}
+                        <code>{"Politeness"}</code>{"."}</li>
+                    <li><b>{"Politeness::Polite: "}</b>{"read when the screen reader is idle."}</li>
+                    <li><b>{"Politeness::Assertive: "}</b>{"interrupt the screen reader, only for errors and urgent messages."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <p>{"Turn on a screen reader to hear the messages"}</p>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Save)
+                    button_palette=Palette::Success
+                    button_style=Style::Light
+                >{"Save"}</Button>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Fail)
+                    button_palette=Palette::Danger
+                    button_style=Style::Light
+                >{"Fail"}</Button>
+                <Announcer/>
+            </>
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::highlighters::get_availability_grid;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::availability_grid::{AvailabilityGrid, Slot};
+use yew_styles::calendar::CalendarDate;
+use yew_styles::styles::Palette;
+
+pub struct AvailabilityGridPage {
+    link: ComponentLink<Self>,
+    available: Vec<Slot>,
+}
+
+pub enum Msg {
+    Change(Vec<Slot>),
+}
+
+impl Component for AvailabilityGridPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            available: vec![],
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Change(available) => self.available = available,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let responses = vec![
+            (18..26)
+                .map(|slot| Slot {
+                    date: CalendarDate::new(2021, 7, 13),
+                    minutes: slot * 30,
+                })
+                .collect::<Vec<Slot>>(),
+            (20..30)
+                .map(|slot| Slot {
+                    date: CalendarDate::new(2021, 7, 13),
+                    minutes: slot * 30,
+                })
+                .collect::<Vec<Slot>>(),
+        ];
+
+        html! {
+            <>
+                <h1>{"Availability Grid Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"availability_grid"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_availability_grid()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"start_date: "}</b>{"first day of the grid. Required."}</li>
+                    <li><b>{"days: "}</b>{"number of days from the start date. Default "}<code>{"7"}</code>{"."}</li>
+                    <li><b>{"start_minutes: "}</b>{"start of the first slot in minutes from midnight. Default "}<code>{"540"}</code>{" (9:00)."}</li>
+                    <li><b>{"end_minutes: "}</b>{"end of the last slot in minutes from midnight. Default "}<code>{"1080"}</code>{" (18:00)."}</li>
+                    <li><b>{"slot_minutes: "}</b>{"duration of each slot in minutes. Default "}<code>{"30"}</code>{"."}</li>
+                    <li><b>{"selected: "}</b>{"slots painted as available."}</li>
+                    <li><b>{"responses: "}</b>{"available slots of each respondent, when it is not empty the grid is read only and shows how many respondents are available in each slot."}</li>
+                    <li><b>{"time_zone: "}</b>{"time zone shown over the grid. Default "}<code>{"None"}</code>{", the time zone of the browser."}</li>
+                    <li><b>{"grid_palette: "}</b>{"type availability grid palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Success"}</code>{"."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"signal emitted with all the available slots when the user ends painting."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The slots are painted click-dragging over the cells or with the arrow keys and space."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <AvailabilityGrid
+                    start_date=CalendarDate::new(2021, 7, 12)
+                    days=5
+                    start_minutes=8 * 60
+                    end_minutes=17 * 60
+                    selected=self.available.clone()
+                    onchange_signal=self.link.callback(Msg::Change)
+                />
+                <p>{format!("Available slots: {}", self.available.len())}</p>
+
+                <h3>{"Responses"}</h3>
+                <AvailabilityGrid
+                    start_date=CalendarDate::new(2021, 7, 12)
+                    days=3
+                    responses=responses
+                    grid_palette=Palette::Info
+                />
+            </>
+        }
+    }
+}
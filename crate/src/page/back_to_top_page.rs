@@ -0,0 +1,66 @@
+use super::highlighters::get_back_to_top;
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::back_to_top::BackToTop;
+
+pub struct BackToTopPage;
+
+impl Component for BackToTopPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Back To Top Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"back_to_top"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_back_to_top()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"threshold: "}</b>{"pixels scrolled before the button appears. Default "}<code>{"400"}</code>{"."}</li>
+                    <li><b>{"target: "}</b>{"scrollable element which is scrolled to the top. Default "}<code>{"NodeRef::default()"}</code>{", the page."}</li>
+                    <li><b>{"children: "}</b>{"content of the button. Default an arrow up."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The button scrolls smoothly back to the top, at once when the motion is reduced."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <p>{"Scroll down the page to show the button in the bottom right corner."}</p>
+                <article>
+                    <p>{lipsum(300)}</p>
+                    <p>{lipsum(300)}</p>
+                    <p>{lipsum(300)}</p>
+                </article>
+                <BackToTop threshold=300 />
+            </>
+        }
+    }
+}
@@ -127,7 +127,7 @@ impl Component for BasicFormPage {
                 </Item>
                 <Item layouts=vec!(ItemLayout::ItXs(12))>
                     <h2>{"Visual example"}</h2>
-                    <Form onsubmit_signal=self.link.callback(|_| Msg::Submit)>
+                    <Form onsubmit_event_signal=self.link.callback(|_| Msg::Submit)>
                         <Container wrap=Wrap::Wrap direction=Direction::Row>
                             <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
                                 <FormGroup orientation=Orientation::Horizontal>
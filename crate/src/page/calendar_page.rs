@@ -0,0 +1,121 @@
+use super::highlighters::get_calendar;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::calendar::{Calendar, CalendarDate, CalendarEvent, CalendarView};
+use yew_styles::styles::{Palette, Style};
+
+pub struct CalendarPage {
+    link: ComponentLink<Self>,
+    date: CalendarDate,
+    selected: Option<CalendarDate>,
+}
+
+pub enum Msg {
+    Navigate(CalendarDate),
+    SelectDay(CalendarDate),
+}
+
+impl Component for CalendarPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            date: CalendarDate::new(2021, 7, 1),
+            selected: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Navigate(date) => self.date = date,
+            Msg::SelectDay(date) => self.selected = Some(date),
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Calendar Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"calendar"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_calendar()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"date: "}</b>{"reference date, the month or the week that contains it is displayed. Required."}</li>
+                    <li><b>{"calendar_view: "}</b>{"display a whole month or a single week. Options included in "}<code>{"CalendarView"}</code>{". Default "}<code>{"Month"}</code>{"."}</li>
+                    <li><b>{"events: "}</b>{"events showed as markers in their day cell. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"selected: "}</b>{"day marked as selected. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"day_render: "}</b>{"custom render for the content of the day cell, it receives the day and its events. Example: "}
+                        <code>{"DayRender::new(move |day, events| html! {...})"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"week_days: "}</b>{"name of the week days starting by monday. Default "}<code>{"Messages::week_days"}</code>{"."}</li>
+                    <li><b>{"months: "}</b>{"name of the months starting by january. Default "}<code>{"Messages::months"}</code>{"."}</li>
+                    <li><b>{"ondayclick_signal: "}</b>{"click event for the day cell."}</li>
+                    <li><b>{"onnavigate_signal: "}</b>{"emit the new reference date when the previous or next controls are clicked."}</li>
+                    <li><b>{"calendar_palette: "}</b>{"type calendar palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"calendar_style: "}</b>{"calendar styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"calendar_size: "}</b>{"three diffent calendar standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <h3>{"Month"}</h3>
+                <Calendar
+                    date=self.date.clone()
+                    selected=self.selected.clone()
+                    calendar_view=CalendarView::Month
+                    calendar_palette=Palette::Info
+                    calendar_style=Style::Light
+                    events=get_events()
+                    onnavigate_signal=self.link.callback(Msg::Navigate)
+                    ondayclick_signal=self.link.callback(Msg::SelectDay)
+                />
+                <h3>{"Week"}</h3>
+                <Calendar
+                    date=self.date.clone()
+                    selected=self.selected.clone()
+                    calendar_view=CalendarView::Week
+                    calendar_palette=Palette::Primary
+                    calendar_style=Style::Outline
+                    events=get_events()
+                    onnavigate_signal=self.link.callback(Msg::Navigate)
+                    ondayclick_signal=self.link.callback(Msg::SelectDay)
+                />
+            </>
+        }
+    }
+}
+
+fn get_events() -> Vec<CalendarEvent> {
+    vec![
+        CalendarEvent {
+            date: CalendarDate::new(2021, 7, 1),
+            title: "Planning".to_string(),
+            palette: Palette::Info,
+        },
+        CalendarEvent {
+            date: CalendarDate::new(2021, 7, 14),
+            title: "Release".to_string(),
+            palette: Palette::Success,
+        },
+    ]
+}
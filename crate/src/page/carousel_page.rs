@@ -142,6 +142,11 @@ impl Component for CarouselPage {
                     <li><b>{"onwheel_signal: "}</b>{"wheel event for carousel."}</li>
                     <li><b>{"onmouseover_signal: "}</b>{"mouse over event for carousel."}</li>
                     <li><b>{"onmouseleave_signal: "}</b>{"mouse leave event for carousel."}</li>
+                    <li><b>{"autoplay: "}</b>{"time in milliseconds that each slide is shown before "}<code>{"onautoplay_signal"}</code>{" is emitted, 0 disables the autoplay. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"slide_durations: "}</b>{"time in milliseconds of specific slides as "}<code>{"(slide index, time)"}</code>{", overriding "}<code>{"autoplay"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"active_slide: "}</b>{"index of the active slide, the autoplay starts again when it changes. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"pause_on_hover: "}</b>{"pause the autoplay while the mouse is over the carousel. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"onautoplay_signal: "}</b>{"emitted with the index of the active slide when its time is over."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
@@ -186,6 +191,30 @@ impl Component for CarouselPage {
                     <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
                 </ul>
 
+                <h3>{"Carousel Indicator"}</h3>
+                <ul>
+                    <li><b>{"current: "}</b>{"index of the active slide. Required."}</li>
+                    <li><b>{"total: "}</b>{"number of slides. Required."}</li>
+                    <li><b>{"indicator_type: "}</b>{"fraction ("}<code>{"3/10"}</code>{") or progress bar. Options included in "}<code>{"IndicatorType"}</code>{". Default "}<code>{"Fraction"}</code>{"."}</li>
+                    <li><b>{"carousel_indicator_palette: "}</b>{"type carousel indicator style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                </ul>
+
+                <h3>{"Carousel Thumbnails"}</h3>
+                <ul>
+                    <li><b>{"images: "}</b>{"url path of the thumbnails, in the same order than the slides. Required."}</li>
+                    <li><b>{"active: "}</b>{"index of the active slide, its thumbnail is scrolled into the view. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"emitted with the index of the clicked thumbnail."}</li>
+                    <li><b>{"carousel_thumbnails_palette: "}</b>{"type carousel thumbnails style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                </ul>
+
                 <h2>{"Visual example"}</h2>
                 <div>
                     <Carousel
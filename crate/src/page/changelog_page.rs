@@ -0,0 +1,90 @@
+use super::highlighters::get_changelog;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::changelog::{Change, ChangeKind, Changelog, ChangelogEntry};
+
+pub struct ChangelogPage;
+
+impl Component for ChangelogPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Changelog Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"changelog"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_changelog()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"entries: "}</b>{"versions from the newest to the oldest, with their changes and an optional body with any html. Required."}</li>
+                    <li><b>{"expanded: "}</b>{"number of versions expanded, the rest are collapsed until the user opens them. Default "}<code>{"1"}</code>{"."}</li>
+                    <li><b>{"anchor_prefix: "}</b>{"prefix of the anchor of each version, e.g. "}<code>{"#v0.11.0"}</code>{". Default "}<code>{"\"v\""}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The kind of each change is an option included in "}<code>{"ChangeKind"}</code>
+                    {": "}<code>{"Added"}</code>{", "}<code>{"Changed"}</code>{", "}<code>{"Deprecated"}</code>{", "}
+                    <code>{"Removed"}</code>{", "}<code>{"Fixed"}</code>{" or "}<code>{"Security"}</code>{"."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Changelog
+                    expanded=2
+                    entries=vec![
+                        ChangelogEntry {
+                            version: "0.11.0".to_string(),
+                            date: Some("2021-06-01".to_string()),
+                            changes: vec![
+                                Change::new(ChangeKind::Added, "Toast component"),
+                                Change::new(ChangeKind::Fixed, "Focus of the modal"),
+                                Change::new(ChangeKind::Security, "Escape the text of the tooltips"),
+                            ],
+                            body: Some(html! {<p>{"Summer release"}</p>}),
+                        },
+                        ChangelogEntry {
+                            version: "0.10.0".to_string(),
+                            date: Some("2021-03-01".to_string()),
+                            changes: vec![
+                                Change::new(ChangeKind::Changed, "Yew 0.18"),
+                                Change::new(ChangeKind::Deprecated, "Size property of the spinner"),
+                            ],
+                            body: None,
+                        },
+                        ChangelogEntry {
+                            version: "0.9.0".to_string(),
+                            date: Some("2020-12-01".to_string()),
+                            changes: vec![Change::new(ChangeKind::Removed, "Old layout classes")],
+                            body: None,
+                        },
+                    ]
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::highlighters::get_click_away;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::click_away::ClickAway;
+use yew_styles::styles::{Palette, Style};
+
+pub struct ClickAwayPage {
+    link: ComponentLink<Self>,
+    open: bool,
+}
+
+pub enum Msg {
+    Open,
+    Close,
+}
+
+impl Component for ClickAwayPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, open: false }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open => self.open = true,
+            Msg::Close => self.open = false,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Click Away Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"click_away"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_click_away()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"active: "}</b>{"listen to the presses outside, e.g. only while the overlay is open. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"onclickaway_signal: "}</b>{"emitted with the event when the user presses outside of the children."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the children are wrapped in an element with "}<code>{"display: contents"}</code>{", so the layout doesn't change."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <ClickAway active=self.open onclickaway_signal=self.link.callback(|_| Msg::Close)>
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::Open)
+                        button_palette=Palette::Info
+                        button_style=Style::Light
+                    >{"Options"}</Button>
+                    {if self.open {
+                        html! {<p>{"Click outside to close this content"}</p>}
+                    } else {
+                        html! {}
+                    }}
+                </ClickAway>
+            </>
+        }
+    }
+}
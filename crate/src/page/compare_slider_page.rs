@@ -0,0 +1,94 @@
+use super::highlighters::get_compare_slider;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::compare_slider::CompareSlider;
+
+pub struct CompareSliderPage {
+    link: ComponentLink<Self>,
+    position: f64,
+}
+
+pub enum Msg {
+    Move(f64),
+}
+
+impl Component for CompareSliderPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            position: 30.0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Move(position) => self.position = position,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Compare Slider Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"compare_slider"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_compare_slider()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"before_src: "}</b>{"url path of the image before the change. Required."}</li>
+                    <li><b>{"after_src: "}</b>{"url path of the image after the change. Required."}</li>
+                    <li><b>{"before_alt: "}</b>{"alternative text of the image before the change. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"after_alt: "}</b>{"alternative text of the image after the change. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"before_label: "}</b>{"label over the image before the change, it is not shown if it is empty. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"after_label: "}</b>{"label over the image after the change, it is not shown if it is empty. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"position: "}</b>{"initial position of the divider in percentage. Default "}<code>{"50.0"}</code>{"."}</li>
+                    <li><b>{"step: "}</b>{"percentage moved by the arrow keys, "}<code>{"Home"}</code>{" and "}<code>{"End"}</code>
+                        {" move the divider to the edges. Default "}<code>{"5.0"}</code>{"."}</li>
+                    <li><b>{"vertical: "}</b>{"split the images from top to bottom instead of from left to right. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"emitted with the new position of the divider in percentage."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <p>{format!("Position: {:.0}%", self.position)}</p>
+                <CompareSlider
+                    before_src="/slide_1.jpg"
+                    after_src="/slide_2.jpg"
+                    before_label="Before"
+                    after_label="After"
+                    position=30.0
+                    onchange_signal=self.link.callback(Msg::Move)
+                />
+                <h3>{"Vertical"}</h3>
+                <CompareSlider
+                    before_src="/slide_2.jpg"
+                    after_src="/slide_3.jpg"
+                    before_label="Before"
+                    after_label="After"
+                    vertical=true
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use super::highlighters::get_confetti;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::confetti::{Confetti, ConfettiHandle};
+use yew_styles::styles::{Palette, Style};
+
+pub struct ConfettiPage {
+    link: ComponentLink<Self>,
+    confetti: ConfettiHandle,
+}
+
+pub enum Msg {
+    Pay,
+    Celebrate,
+}
+
+impl Component for ConfettiPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            confetti: ConfettiHandle::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Pay => self.confetti.fire(),
+            Msg::Celebrate => self.confetti.fire_from(0.1, 0.9),
+        };
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Confetti Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"confetti"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_confetti()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"handle: "}</b>{"handle which fires the bursts, with "}<code>{"fire"}</code>{" from the origin or "}<code>{"fire_from"}</code>{" from a point of the viewport. Required."}</li>
+                    <li><b>{"particle_count: "}</b>{"number of pieces of each burst. Default "}<code>{"150"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"milliseconds until the pieces of a burst disappear. Default "}<code>{"3000"}</code>{"."}</li>
+                    <li><b>{"origin: "}</b>{"point of the viewport where the bursts start, "}<code>{"x"}</code>{" and "}<code>{"y"}</code>{" from "}<code>{"0.0"}</code>{" to "}<code>{"1.0"}</code>
+                        {". Default "}<code>{"(0.5, 0.5)"}</code>{"."}</li>
+                    <li><b>{"spread: "}</b>{"degrees of the cone where the pieces are thrown upwards. Default "}<code>{"90.0"}</code>{"."}</li>
+                    <li><b>{"palettes: "}</b>{"palettes which give the colors of the pieces. Default "}<code>{"Primary"}</code>{", "}<code>{"Secondary"}</code>{", "}
+                        <code>{"Success"}</code>{", "}<code>{"Info"}</code>{", "}<code>{"Warning"}</code>{" and "}<code>{"Danger"}</code>{"."}</li>
+                    <li><b>{"onend_signal: "}</b>{"signal emitted when the last piece disappears."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <p>{"The canvas doesn't catch the pointer events and when the motion is reduced nothing is drawn."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Pay)
+                    button_palette=Palette::Success
+                    button_style=Style::Light
+                >{"Pay"}</Button>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Celebrate)
+                    button_palette=Palette::Secondary
+                    button_style=Style::Light
+                >{"Celebrate from the corner"}</Button>
+                <Confetti handle=self.confetti.clone() particle_count=200 />
+            </>
+        }
+    }
+}
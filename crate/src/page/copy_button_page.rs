@@ -0,0 +1,89 @@
+use super::highlighters::get_copy_button;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::copy_button::CopyButton;
+use yew_styles::styles::{Palette, Size, Style};
+
+pub struct CopyButtonPage {
+    link: ComponentLink<Self>,
+    message: String,
+}
+
+pub enum Msg {
+    Copied(String),
+    NotCopied(String),
+}
+
+impl Component for CopyButtonPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            message: String::from(""),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Copied(text) => self.message = format!("\"{}\" copied", text),
+            Msg::NotCopied(text) => self.message = format!("\"{}\" couldn't be copied", text),
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Copy Button Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"copy_button"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_copy_button()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"text: "}</b>{"text written in the clipboard. Required."}</li>
+                    <li><b>{"label: "}</b>{"label of the button. Default "}<code>{"Messages::copy"}</code>{"."}</li>
+                    <li><b>{"feedback_duration: "}</b>{"milliseconds the copied or failed message is shown. Default "}<code>{"1500"}</code>{"."}</li>
+                    <li><b>{"oncopy_signal: "}</b>{"signal emitted with the text when it is written in the clipboard."}</li>
+                    <li><b>{"onerror_signal: "}</b>{"signal emitted with the text when the clipboard rejects it or it is not available."}</li>
+                    <li><b>{"button_palette: "}</b>{"type button palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"button_style: "}</b>{"button styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"button_size: "}</b>{"three different button sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the async clipboard api is only available in secure contexts, like https or localhost."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <code>{"sk_live_51H8"}</code>
+                <CopyButton
+                    text="sk_live_51H8"
+                    button_palette=Palette::Info
+                    button_style=Style::Outline
+                    button_size=Size::Small
+                    oncopy_signal=self.link.callback(Msg::Copied)
+                    onerror_signal=self.link.callback(Msg::NotCopied)
+                />
+                <p>{&self.message}</p>
+            </>
+        }
+    }
+}
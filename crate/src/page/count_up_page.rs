@@ -0,0 +1,97 @@
+use super::highlighters::get_count_up;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::count_up::{CountUp, Formatter};
+use yew_styles::styles::animations::Easing;
+use yew_styles::styles::{Palette, Style};
+
+pub struct CountUpPage {
+    link: ComponentLink<Self>,
+    total: f64,
+    currency: String,
+}
+
+pub enum Msg {
+    AddSale,
+}
+
+impl Component for CountUpPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            total: 12500.0,
+            currency: "€".to_string(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::AddSale => self.total += 1250.5,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let currency = self.currency.clone();
+
+        html! {
+            <>
+                <h1>{"Count Up Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"count_up"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_count_up()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"value: "}</b>{"number to show. Required."}</li>
+                    <li><b>{"from: "}</b>{"number where the count starts when the component is shown. Default "}<code>{"0.0"}</code>{"."}</li>
+                    <li><b>{"animate_on_mount: "}</b>{"count from "}<code>{"from"}</code>{" when the component is shown instead of showing the value. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"duration of the count in milliseconds. Default "}<code>{"1000"}</code>{"."}</li>
+                    <li><b>{"easing: "}</b>{"timing function of the count. Options included in "}<code>{"Easing"}</code>{". Default "}<code>{"EaseOut"}</code>{"."}</li>
+                    <li><b>{"decimals: "}</b>{"number of decimals when there is no formatter. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"formatter: "}</b>{"format the number, the closure can capture the state of the parent component. Example: "}
+                        <code>{"Formatter::new(move |value| format!(\"{:.2} {}\", value, currency))"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onend_signal: "}</b>{"emitted with the value when the count ends."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the screen readers only read the final number, and when the motion is reduced the number changes at once."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <h3>
+                    <CountUp
+                        value=self.total
+                        duration=2000
+                        easing=Easing::Emphasized
+                        formatter=Formatter::new(move |value| format!("{:.2} {}", value, currency))
+                    />
+                </h3>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::AddSale)
+                    button_palette=Palette::Success
+                    button_style=Style::Light
+                >{"Add sale"}</Button>
+            </>
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use super::highlighters::get_document_viewer;
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::document_viewer::{DocumentSection, DocumentViewer};
+
+pub struct DocumentViewerPage {
+    link: ComponentLink<Self>,
+    section: String,
+}
+
+pub enum Msg {
+    ChangeSection(String),
+}
+
+impl Component for DocumentViewerPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            section: "acceptance".to_string(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeSection(section) => self.section = section,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let sections = vec![
+            ("acceptance", "Acceptance of the terms"),
+            ("account", "Your account"),
+            ("privacy", "Privacy"),
+            ("payments", "Payments"),
+            ("termination", "Termination"),
+        ];
+
+        html! {
+            <>
+                <h1>{"Document Viewer Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"document_viewer"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_document_viewer()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"sections: "}</b>{"sections of the document with their id, title and content, they are listed in the table of contents. Required."}</li>
+                    <li><b>{"title: "}</b>{"title of the document. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"updated: "}</b>{"date of the last update of the document. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"show_progress: "}</b>{"show the reading progress bar. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"printable: "}</b>{"show the print button, when it is printed only the content is shown. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"onsection_signal: "}</b>{"signal emitted with the id of the section being read when it changes."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <p>{format!("Section being read: {}", self.section)}</p>
+                <div style="height: 400px">
+                    <DocumentViewer
+                        title="Terms of service"
+                        updated="June 1, 2021"
+                        sections=sections
+                            .into_iter()
+                            .map(|(id, title)| DocumentSection {
+                                id: id.to_string(),
+                                title: title.to_string(),
+                                content: html! {
+                                    <>
+                                        <p>{lipsum(80)}</p>
+                                        <p>{lipsum(60)}</p>
+                                    </>
+                                },
+                            })
+                            .collect::<Vec<DocumentSection>>()
+                        onsection_signal=self.link.callback(Msg::ChangeSection)
+                    />
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::highlighters::get_embed;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::embed::Embed;
+
+pub struct EmbedPage;
+
+impl Component for EmbedPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Embed Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"embed"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_embed()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"src: "}</b>{"url of the content. Required."}</li>
+                    <li><b>{"title: "}</b>{"title of the iframe for the screen readers. Required."}</li>
+                    <li><b>{"aspect_ratio: "}</b>{"width and height proportion, "}<code>{"None"}</code>{" leaves the size to the styles. Default "}<code>{"Some((16, 9))"}</code>{"."}</li>
+                    <li><b>{"lazy: "}</b>{"load the iframe only when it is close to the viewport. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"sandbox: "}</b>{"restrictions of the iframe, e.g. "}<code>{"\"allow-scripts allow-same-origin\""}</code>{". "}
+                        <code>{"Some(\"\")"}</code>{" applies all of them, "}<code>{"None"}</code>{" none of them. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"allow: "}</b>{"permissions policy of the iframe, e.g. "}<code>{"\"autoplay; fullscreen; geolocation\""}</code>{". Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"referrer_policy: "}</b>{"referrer sent to the third party. Default "}<code>{"\"strict-origin-when-cross-origin\""}</code>{"."}</li>
+                    <li><b>{"consent: "}</b>{"show a placeholder and load the iframe after the user clicks the load button. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"placeholder: "}</b>{"content of the placeholder, a text about the third party by default. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onconsent_signal: "}</b>{"emitted when the user accepts to load the content."}</li>
+                    <li><b>{"onload_signal: "}</b>{"emitted when the iframe is loaded."}</li>
+                    <li><b>{"onerror_signal: "}</b>{"emitted when the iframe cannot be loaded."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <Embed
+                    src="https://www.openstreetmap.org/export/embed.html?bbox=-0.1,51.5,-0.09,51.51"
+                    title="Office location"
+                    aspect_ratio=Some((4, 3))
+                    sandbox=Some("allow-scripts allow-same-origin".to_string())
+                    consent=true
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::highlighters::get_empty_state;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::empty_state::EmptyState;
+use yew_styles::icon::{Icon, IconType};
+use yew_styles::styles::{Palette, Style};
+
+pub struct EmptyStatePage {
+    link: ComponentLink<Self>,
+    projects: u32,
+}
+
+pub enum Msg {
+    Create,
+    Clear,
+}
+
+impl Component for EmptyStatePage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, projects: 0 }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Create => self.projects += 1,
+            Msg::Clear => self.projects = 0,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Empty State Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"empty_state"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_empty_state()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"title: "}</b>{"title which says what is missing. Required."}</li>
+                    <li><b>{"illustration: "}</b>{"illustration or icon shown above the title. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"description: "}</b>{"text with the reason or the next step. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"action: "}</b>{"buttons or links shown below the description. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"compact: "}</b>{"smaller spaces and illustration, for tables and small containers. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                {if self.projects == 0 {
+                    html! {
+                        <EmptyState
+                            illustration=html! {<Icon icon_type=IconType::Plus />}
+                            title="Nothing here yet"
+                            description="Create a project to start tracking your work"
+                            action=html! {
+                                <Button
+                                    button_palette=Palette::Primary
+                                    onclick_signal=self.link.callback(|_| Msg::Create)
+                                >{"New project"}</Button>
+                            }
+                        />
+                    }
+                } else {
+                    html! {
+                        <>
+                            <p>{format!("Projects: {}", self.projects)}</p>
+                            <Button
+                                button_palette=Palette::Standard
+                                button_style=Style::Outline
+                                onclick_signal=self.link.callback(|_| Msg::Clear)
+                            >{"Remove the projects"}</Button>
+                        </>
+                    }
+                }}
+
+                <h3>{"Compact"}</h3>
+                <EmptyState
+                    compact=true
+                    illustration=html! {<Icon icon_type=IconType::Search />}
+                    title="No results"
+                    description="Try with other filters"
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::highlighters::get_filterable_list;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::filterable_list::FilterableList;
+
+pub struct FilterableListPage {
+    link: ComponentLink<Self>,
+    selected: Option<String>,
+}
+
+pub enum Msg {
+    Select(String),
+}
+
+impl Component for FilterableListPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            selected: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Select(country) => self.selected = Some(country),
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Filterable List Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"filterable_list"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_filterable_list()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"items: "}</b>{"items of the list. Required."}</li>
+                    <li><b>{"placeholder: "}</b>{"placeholder of the search box. Default "}<code>{"Messages::filter"}</code>{"."}</li>
+                    <li><b>{"case_sensitive: "}</b>{"match the query with the same case. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"empty_state: "}</b>{"content shown when no item matches. Default "}<code>{"Messages::no_matches"}</code>{"."}</li>
+                    <li><b>{"onselect_signal: "}</b>{"signal emitted with the item when it is clicked or selected with enter."}</li>
+                    <li><b>{"onfilter_signal: "}</b>{"signal emitted with the query when it changes."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The arrow keys move the active item while the focus stays in the search box, the matched text is highlighted."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <FilterableList
+                    items=vec![
+                        "Argentina".to_string(),
+                        "Belgium".to_string(),
+                        "Brazil".to_string(),
+                        "Chile".to_string(),
+                        "Spain".to_string(),
+                    ]
+                    placeholder="Search a country"
+                    empty_state=html! {<p>{"There is no country with that name"}</p>}
+                    onselect_signal=self.link.callback(Msg::Select)
+                />
+                <p>{format!("Selected country: {}", self.selected.clone().unwrap_or_default())}</p>
+            </>
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use super::highlighters::get_focus_trap;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::focus_trap::FocusTrap;
+use yew_styles::forms::form_input::FormInput;
+use yew_styles::styles::{Palette, Style};
+
+pub struct FocusTrapPage {
+    link: ComponentLink<Self>,
+    open: bool,
+}
+
+pub enum Msg {
+    Toggle,
+}
+
+impl Component for FocusTrapPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, open: false }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => self.open = !self.open,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Focus Trap Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"focus_trap"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_focus_trap()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"active: "}</b>{"keep the focus inside of the children, Tab and Shift+Tab cycle between the focusable elements. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"auto_focus: "}</b>{"move the focus to the first focusable element when it is activated. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"restore_focus: "}</b>{"move the focus back to the previous element when it is deactivated or removed. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the "}<code>{"Modal"}</code>{" component already includes it."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Toggle)
+                    button_palette=Palette::Info
+                    button_style=Style::Light
+                >{"Open"}</Button>
+                {if self.open {
+                    html! {
+                        <FocusTrap>
+                            <FormInput placeholder="Tab does not leave this section"/>
+                            <Button
+                                onclick_signal=self.link.callback(|_| Msg::Toggle)
+                                button_palette=Palette::Standard
+                                button_style=Style::Outline
+                            >{"Close"}</Button>
+                        </FocusTrap>
+                    }
+                } else {
+                    html! {}
+                }}
+            </>
+        }
+    }
+}
@@ -1,18 +1,29 @@
-use super::highlighters::{file_code, input_code, select_code, textarea_code};
+use super::highlighters::{
+    error_summary_code, file_code, form_builder_code, input_code, range_slider_code, select_code,
+    textarea_code, upload_code,
+};
 use crate::app::AppRouter;
+use std::time::Duration;
 use wasm_bindgen::JsCast;
 use web_sys::{File, HtmlOptionElement, Url};
 use yew::prelude::*;
+use yew::services::interval::{IntervalService, IntervalTask};
 use yew::services::ConsoleService;
 use yew_prism::Prism;
 use yew_router::prelude::*;
 use yew_styles::forms::{
+    form_builder::{FieldKind, FormBuilder, FormField, FormValues, Validator},
+    form_component::Form,
+    form_error_summary::FormErrorSummary,
     form_file::FormFile,
     form_group::{FormGroup, Orientation},
     form_input::{FormInput, InputType},
     form_label::FormLabel,
+    form_range_slider::FormRangeSlider,
     form_select::FormSelect,
+    form_submit::FormSubmit,
     form_textarea::FormTextArea,
+    form_upload::UploadRequest,
 };
 use yew_styles::layouts::{
     container::{Container, Direction, Wrap},
@@ -25,6 +36,10 @@ pub struct FormPage {
     pub value: Vec<String>,
     pub multiple_values: Vec<String>,
     pub file_path: String,
+    pub price: (f64, f64),
+    pub uploads: Vec<(UploadRequest, f64)>,
+    pub upload_task: Option<IntervalTask>,
+    pub contact: Option<FormValues>,
 }
 
 pub enum Msg {
@@ -33,6 +48,10 @@ pub enum Msg {
     MultipleSelect(Vec<String>),
     UploadFile(File),
     ErrorUploadImage,
+    ChangePrice((f64, f64)),
+    Upload(UploadRequest),
+    UploadTick,
+    SaveContact(FormValues),
 }
 
 impl Component for FormPage {
@@ -44,6 +63,10 @@ impl Component for FormPage {
             value: vec!["".to_string(); 8],
             multiple_values: vec![],
             file_path: "".to_string(),
+            price: (20.0, 120.0),
+            uploads: vec![],
+            upload_task: None,
+            contact: None,
         }
     }
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -64,6 +87,40 @@ impl Component for FormPage {
             Msg::ErrorUploadImage => {
                 ConsoleService::error("Error to upload image");
             }
+            Msg::ChangePrice(price) => {
+                self.price = price;
+            }
+            Msg::Upload(request) => {
+                self.uploads.push((request, 0.0));
+
+                if self.upload_task.is_none() {
+                    self.upload_task = Some(IntervalService::spawn(
+                        Duration::from_millis(400),
+                        self.link.callback(|_| Msg::UploadTick),
+                    ));
+                }
+            }
+            Msg::UploadTick => {
+                self.uploads.retain(|(request, _)| !request.is_cancelled());
+
+                for (request, progress) in self.uploads.iter_mut() {
+                    *progress += 0.2;
+                    request.progress.emit(progress.min(1.0));
+
+                    if *progress >= 1.0 {
+                        request.respond.emit(Ok(request.file.name()));
+                    }
+                }
+
+                self.uploads.retain(|(_, progress)| *progress < 1.0);
+
+                if self.uploads.is_empty() {
+                    self.upload_task = None;
+                }
+            }
+            Msg::SaveContact(contact) => {
+                self.contact = Some(contact);
+            }
         }
         true
     }
@@ -89,7 +146,10 @@ impl Component for FormPage {
 
                 <h2>{"Form"}</h2>
                 <ul>
-                    <li><b>{"onsubmit_signal: "}</b>{"signal to emit the event submit."}</li>
+                    <li><b>{"onsubmit_signal: "}</b>{"signal to emit the values of the named form controls as "}<code>{"HashMap<String, FormValue>"}</code>
+                        {". It is not emitted while any form control is invalid."}</li>
+                    <li><b>{"onsubmit_event_signal: "}</b>{"signal to emit the event submit, even when a form control is invalid."}</li>
+                    <li><b>{"reset_on_submit: "}</b>{"reset all the form controls after a valid submission. Default "}<code>{"false"}</code>{"."}</li>
                     <li><b>{"action: "}</b>{"the URL that processes the form submission."}</li>
                     <li><b>{"method: "}</b>{"the HTTP method to submit the form. Options included in "}<code>{"Method"}</code>
                         {". Default "}<code>{"Post"}</code>{"."}</li>
@@ -165,6 +225,14 @@ impl Component for FormPage {
                     <li><b>{"step: "}</b>{"incremental values that are valid."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"aria_label: "}</b>{"accessible name when there is no visible label."}</li>
+                    <li><b>{"aria_labelledby: "}</b>{"id of the element which labels the field."}</li>
+                    <li><b>{"aria_describedby: "}</b>{"ids of the elements which describe the field, the error message is linked automatically."}</li>
+                    <li><b>{"onvalidate_signal: "}</b>{"signal to validate the value asynchronously after the user stops typing. It receives a "}<code>{"ValidationRequest"}</code>
+                        {" with the value and the "}<code>{"respond"}</code>{" callback where the result is sent, an error shows its message."}</li>
+                    <li><b>{"validation_debounce: "}</b>{"milliseconds to wait since the last input before the async validation. Default "}<code>{"300"}</code>{"."}</li>
+                    <li><b>{"show_counter: "}</b>{"show a counter with the number of characters and the maxlength. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"counter_threshold: "}</b>{"remaining characters to show the counter with the danger color. Default "}<code>{"10"}</code>{"."}</li>
                 </ul>
                 <h3>{"Visual example"}</h3>
                 {get_form_inputs(self)}
@@ -182,6 +250,14 @@ impl Component for FormPage {
                     <li><b>{"input_size: "}</b>{"the size of the input. Options included in "}<code>{"Size"}</code>
                         {". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"onchange_signal: "}</b>{"signal to emit the event change."}</li>
+                    <li><b>{"upload_url: "}</b>{"endpoint where the selected files are sent one by one with a "}<code>{"POST"}</code>
+                        {" multipart request, in the field "}<code>{"name"}</code>{" or "}<code>{"file"}</code>{" when there is no name. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onupload_signal: "}</b>{"custom driver which uploads the selected files instead of "}<code>{"upload_url"}</code>{". It receives an "}
+                        <code>{"UploadRequest"}</code>{" with the file, the "}<code>{"progress"}</code>{" callback and the "}<code>{"respond"}</code>
+                        {" callback where the result is sent. The driver stops a cancelled upload with the callback registered in "}
+                        <code>{"oncancel"}</code>{" or checking "}<code>{"is_cancelled"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onuploaded_signal: "}</b>{"signal emitted with the name of the file and the response when an upload is completed."}</li>
+                    <li><b>{"onuploaderror_signal: "}</b>{"signal emitted with the name of the file and the error when an upload fails."}</li>
                     <li><b>{"accept: "}</b>{"hint for expected file type in file upload controls."}</li>
                     <li><b>{"alt: "}</b>{"alt attribute for the image type. Required for accessibiltiy."}</li>
                     <li><b>{"autofocus: "}</b>{"automatically focus the form control when the page is loaded."}</li>
@@ -193,9 +269,20 @@ impl Component for FormPage {
                     <li><b>{"capture: "}</b>{"media capture input method in file upload controls."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"aria_label: "}</b>{"accessible name when there is no visible label."}</li>
+                    <li><b>{"aria_labelledby: "}</b>{"id of the element which labels the field."}</li>
+                    <li><b>{"aria_describedby: "}</b>{"ids of the elements which describe the field, the error message is linked automatically."}</li>
                 </ul>
                 <h3>{"Visual examples"}</h3>
                 {get_form_file(self)}
+                <h3>{"Upload progress"}</h3>
+                <p>{"With "}<code>{"upload_url"}</code>{" or "}<code>{"onupload_signal"}</code>
+                    {" each selected file is listed with its progress, a button to cancel the upload and a button to retry it when it fails or it is cancelled."}</p>
+                <Prism
+                    code=upload_code()
+                    language="rust"
+                />
+                {get_form_upload(self)}
 
                 <h2>{"Form select"}</h2>
                 <h3>{"Code example"}</h3>
@@ -217,6 +304,9 @@ impl Component for FormPage {
                         this attribute represents the number of rows in the list that should be visible at one time."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"aria_label: "}</b>{"accessible name when there is no visible label."}</li>
+                    <li><b>{"aria_labelledby: "}</b>{"id of the element which labels the field."}</li>
+                    <li><b>{"aria_describedby: "}</b>{"ids of the elements which describe the field, the error message is linked automatically."}</li>
                 </ul>
                 <h3>{"Visual examples"}</h3>
                 {get_select_form(self)}
@@ -247,17 +337,109 @@ impl Component for FormPage {
                     <li><b>{"readonly: "}</b>{"the value is not editable."}</li>
                     <li><b>{"required: "}</b>{"a value is required or must be check for the form to be submittable."}</li>
                     <li><b>{"disabled: "}</b>{"whether the form control is disabled."}</li>
+                    <li><b>{"auto_resize: "}</b>{"the textarea grows vertically with its content. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"auto_shrink: "}</b>{"the textarea also shrinks when content is removed. Only with auto_resize. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"max_rows: "}</b>{"maximum number of visible text lines with auto_resize, after that it scrolls. Default "}<code>{"None"}</code>{"."}</li>
                     <li><b>{"wrap: "}</b>{"indicates how the control wraps text. Options included in "}<code>{"WrapText"}</code>
                         {". Default "}<code>{"Soft"}</code>{"."}</li>
+                    <li><b>{"resize: "}</b>{"directions in which the user can resize the textarea, auto_resize disables it. Options included in "}<code>{"Resize"}</code>
+                        {". Default "}<code>{"Both"}</code>{"."}</li>
                     <li><b>{"cols: "}</b>{"the visible width of the text control."}</li>
                     <li><b>{"rows: "}</b>{"the number of visible text lines for the control."}</li>
                     <li><b>{"spellcheck: "}</b>{"specifies whether the "}<code>{"<textarea>"}</code>
                         {" is subject to spell checking by the underlying browser/OS."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"aria_label: "}</b>{"accessible name when there is no visible label."}</li>
+                    <li><b>{"aria_labelledby: "}</b>{"id of the element which labels the field."}</li>
+                    <li><b>{"aria_describedby: "}</b>{"ids of the elements which describe the field, the error message is linked automatically."}</li>
+                    <li><b>{"onvalidate_signal: "}</b>{"signal to validate the value asynchronously after the user stops typing. It receives a "}<code>{"ValidationRequest"}</code>
+                        {" with the value and the "}<code>{"respond"}</code>{" callback where the result is sent, an error shows its message."}</li>
+                    <li><b>{"validation_debounce: "}</b>{"milliseconds to wait since the last input before the async validation. Default "}<code>{"300"}</code>{"."}</li>
+                    <li><b>{"show_counter: "}</b>{"show a counter with the number of characters and the maxlength. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"counter_threshold: "}</b>{"remaining characters to show the counter with the danger color. Default "}<code>{"10"}</code>{"."}</li>
                 </ul>
                 <h3>{"Visual examples"}</h3>
                 {get_textarea(self)}
+
+                <h2>{"Form range slider"}</h2>
+                <p>{"Slider with two handles to select an interval, like the price filter of a shop. The handles are dragged or moved with the arrow keys, "}
+                    <code>{"Page Up"}</code>{", "}<code>{"Page Down"}</code>{", "}<code>{"Home"}</code>{" and "}<code>{"End"}</code>
+                    {", and a press in the track moves the closest handle."}</p>
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=range_slider_code()
+                    language="rust"
+                />
+                <ul>
+                    <li><b>{"value: "}</b>{"selected interval controlled by the parent, each new value replaces the one selected with the handles. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"default_value: "}</b>{"initial interval when there is no value, then the slider keeps the one selected with the handles. Default "}<code>{"(0.0, 100.0)"}</code>{"."}</li>
+                    <li><b>{"min: "}</b>{"lowest value of the slider. Default "}<code>{"0.0"}</code>{"."}</li>
+                    <li><b>{"max: "}</b>{"highest value of the slider. Default "}<code>{"100.0"}</code>{"."}</li>
+                    <li><b>{"step: "}</b>{"increment of the values, they are snapped to it. Default "}<code>{"1.0"}</code>{"."}</li>
+                    <li><b>{"min_gap: "}</b>{"minimum distance between the two values. Default "}<code>{"0.0"}</code>{"."}</li>
+                    <li><b>{"ticks: "}</b>{"values with their labels shown below the track. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"slider_palette: "}</b>{"palette of the selected interval and the handles. Options included in "}<code>{"Palette"}</code>
+                        {". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"disabled: "}</b>{"whether the form control is disabled."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"signal emitted with the interval when a handle is moved."}</li>
+                    <li><b>{"aria_label: "}</b>{"accessible name when there is no visible label."}</li>
+                    <li><b>{"aria_labelledby: "}</b>{"id of the element which labels the field."}</li>
+                </ul>
+                <h3>{"Visual examples"}</h3>
+                {get_range_slider(self)}
+
+                <h2>{"Form builder"}</h2>
+                <p>{"Renders a whole form from a list of "}<code>{"FormField"}</code>{": a label and the form control of each field in a grid, the validation messages and the submit button. "}
+                    {"The fields are validated when they lose the focus and all together when the form is submitted, the focus goes to the first invalid field. "}
+                    {"Once every field is valid the values are converted with "}<code>{"FromFormValues"}</code>{" into the type of "}<code>{"onsubmit_signal"}</code>
+                    {", "}<code>{"FormValues"}</code>{" keeps them untyped."}</p>
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=form_builder_code()
+                    language="rust"
+                />
+                <ul>
+                    <li><b>{"fields: "}</b>{"fields of the form in the order they are shown. Required."}</li>
+                    <li><b>{"onsubmit_signal: "}</b>{"signal emitted with the converted values when the form is submitted and every field is valid."}</li>
+                    <li><b>{"columns: "}</b>{"number of columns of the grid of fields. Default "}<code>{"1"}</code>{"."}</li>
+                    <li><b>{"orientation: "}</b>{"position of the labels next to the form controls. Options included in "}<code>{"Orientation"}</code>
+                        {". Default "}<code>{"Vertical"}</code>{"."}</li>
+                    <li><b>{"submit_label: "}</b>{"text of the submit button. Default "}<code>{"Messages::form_submit"}</code>{"."}</li>
+                    <li><b>{"submit_palette: "}</b>{"palette of the submit button. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"reset_on_submit: "}</b>{"clear the form controls after submitting. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"error_summary: "}</b>{"show the list of the errors at the top of the form, see "}<code>{"FormErrorSummary"}</code>{". Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+                <h3>{"Form field"}</h3>
+                <ul>
+                    <li><b>{"new: "}</b>{"create a field with its name, its label and its kind. Options included in "}<code>{"FieldKind"}</code>{"."}</li>
+                    <li><b>{"validator: "}</b>{"add a rule checked on the value, in the order they are added. Options included in "}<code>{"Validator"}</code>{"."}</li>
+                    <li><b>{"option: "}</b>{"add an option to the selects."}</li>
+                    <li><b>{"placeholder: "}</b>{"text shown while the field is empty."}</li>
+                    <li><b>{"full_width: "}</b>{"take the whole row of the grid."}</li>
+                </ul>
+                <h3>{"Visual examples"}</h3>
+                {get_form_builder(self)}
+
+                <h2>{"Form error summary"}</h2>
+                <p>{"List of the errors of the form controls shown at the top of a long form. It collects the form controls marked as invalid in the form which contains it, "}
+                    {"with their label and their error message, and it keeps the list updated while the errors change. Each error links to its form control and clicking it moves the focus there. "}
+                    {"Nothing is rendered while there are no errors."}</p>
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=error_summary_code()
+                    language="rust"
+                />
+                <ul>
+                    <li><b>{"form_ref: "}</b>{"form with the form controls. Default "}<code>{"NodeRef::default()"}</code>{", the form which contains the summary."}</li>
+                    <li><b>{"title: "}</b>{"title of the list. Default "}<code>{"Messages::form_error_summary"}</code>{"."}</li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+                <h3>{"Visual examples"}</h3>
+                {get_error_summary(self)}
             </>
         }
     }
@@ -378,6 +560,23 @@ fn get_form_file(form_page: &FormPage) -> Html {
     }
 }
 
+fn get_form_upload(form_page: &FormPage) -> Html {
+    html! {
+        <Container wrap=Wrap::Wrap direction=Direction::Row>
+            <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormLabel text="Upload documents: "/>
+                    <FormFile
+                        multiple=true
+                        onchange_signal=Callback::noop()
+                        onupload_signal=form_page.link.callback(Msg::Upload)
+                    />
+                </FormGroup>
+            </Item>
+        </Container>
+    }
+}
+
 fn get_select_form(form_page: &FormPage) -> Html {
     html! {
         <Container wrap = Wrap::Wrap direction = Direction::Row>
@@ -487,3 +686,104 @@ fn get_textarea(form_page: &FormPage) -> Html {
         </Container>
     }
 }
+
+fn get_range_slider(form_page: &FormPage) -> Html {
+    html! {
+        <Container wrap = Wrap::Wrap direction = Direction::Row>
+            <Item layouts=vec!(ItemLayout::ItL(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormLabel
+                        text="Price"
+                        id="form-range-slider-price"
+                    />
+                    <FormRangeSlider
+                        value=form_page.price
+                        min=0.0
+                        max=200.0
+                        step=5.0
+                        min_gap=10.0
+                        ticks=vec![(0.0, "0 €".to_string()), (100.0, "100 €".to_string()), (200.0, "200 €".to_string())]
+                        slider_palette=Palette::Primary
+                        aria_labelledby="form-range-slider-price"
+                        onchange_signal=form_page.link.callback(Msg::ChangePrice)
+                    />
+                    <div>{format!("Value: {} € - {} €", form_page.price.0, form_page.price.1)}</div>
+                </FormGroup>
+            </Item>
+            <Item layouts=vec!(ItemLayout::ItL(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormLabel
+                        text="Uncontrolled"
+                    />
+                    <FormRangeSlider
+                        default_value=(25.0, 75.0)
+                        slider_palette=Palette::Success
+                        aria_label="Uncontrolled"
+                    />
+                </FormGroup>
+            </Item>
+        </Container>
+    }
+}
+
+fn get_form_builder(form_page: &FormPage) -> Html {
+    html! {
+        <>
+            <FormBuilder<FormValues>
+                columns=2
+                submit_label="Save contact"
+                submit_palette=Palette::Primary
+                onsubmit_signal=form_page.link.callback(Msg::SaveContact)
+                fields=vec![
+                    FormField::new("name", "Name", FieldKind::Text)
+                        .validator(Validator::Required)
+                        .validator(Validator::MaxLength(80)),
+                    FormField::new("email", "Email", FieldKind::Email)
+                        .validator(Validator::Required)
+                        .validator(Validator::Email),
+                    FormField::new("age", "Age", FieldKind::Number)
+                        .validator(Validator::Min(18.0)),
+                    FormField::new("country", "Country", FieldKind::Select)
+                        .option("ar", "Argentina")
+                        .option("es", "Spain"),
+                    FormField::new("newsletter", "Subscribe to the newsletter", FieldKind::Checkbox)
+                        .full_width(),
+                ]
+            />
+            {match &form_page.contact {
+                Some(contact) => html! {
+                    <div>{format!(
+                        "Saved: {} <{}>, newsletter: {}",
+                        contact.text("name"),
+                        contact.text("email"),
+                        contact.checked("newsletter")
+                    )}</div>
+                },
+                None => html! {},
+            }}
+        </>
+    }
+}
+
+fn get_error_summary(form_page: &FormPage) -> Html {
+    let email = &form_page.value[7];
+    let email_error = !email.is_empty() && !email.contains('@');
+
+    html! {
+        <Form onsubmit_signal=Callback::noop()>
+            <FormErrorSummary />
+            <FormGroup orientation=Orientation::Vertical>
+                <FormLabel text="Email" label_for="form-error-summary-email" />
+                <FormInput
+                    id="form-error-summary-email"
+                    input_type=InputType::Email
+                    placeholder="write an email without @"
+                    error_state=email_error
+                    error_message="Enter a valid email address"
+                    oninput_signal=form_page.link.callback(|e: InputData| Msg::Input(e.value, 7))
+                />
+            </FormGroup>
+            <FormSubmit value="Sign up" />
+        </Form>
+    }
+}
@@ -0,0 +1,86 @@
+use super::highlighters::get_gallery;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::gallery::{Gallery, GalleryImage};
+
+pub struct GalleryPage {
+    link: ComponentLink<Self>,
+    viewing: Option<usize>,
+}
+
+pub enum Msg {
+    View(Option<usize>),
+}
+
+impl Component for GalleryPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            viewing: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::View(viewing) => self.viewing = viewing,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Gallery Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"gallery"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_gallery()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"images: "}</b>{"images of the gallery, with an optional thumbnail for the grid and a caption for the viewer. Required."}</li>
+                    <li><b>{"thumbnail_width: "}</b>{"minimum width of the thumbnails in pixels, the grid fits as many columns as it can. Default "}<code>{"160"}</code>{"."}</li>
+                    <li><b>{"max_scale: "}</b>{"maximum scale of the pinch in the viewer. Default "}<code>{"4.0"}</code>{"."}</li>
+                    <li><b>{"onview_signal: "}</b>{"signal emitted with the index of the image shown in the viewer, "}<code>{"None"}</code>{" when it is closed."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"In the viewer the arrows "}<code>{"Left"}</code>{" and "}<code>{"Right"}</code>{", "}<code>{"Home"}</code>{" and "}<code>{"End"}</code>
+                    {" go through the images and "}<code>{"Escape"}</code>{" closes it. In touch devices the images are changed swiping and zoomed with a pinch."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Gallery
+                    thumbnail_width=200
+                    images=vec![
+                        GalleryImage::new("/slide_1.jpg", "First slide").caption("Slide 1"),
+                        GalleryImage::new("/slide_2.jpg", "Second slide").caption("Slide 2"),
+                        GalleryImage::new("/slide_3.jpg", "Third slide").caption("Slide 3"),
+                    ]
+                    onview_signal=self.link.callback(Msg::View)
+                />
+                <p>{match self.viewing {
+                    Some(index) => format!("Viewing the image {}", index + 1),
+                    None => String::from("The viewer is closed"),
+                }}</p>
+            </>
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use super::highlighters::get_highlight;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::forms::form_input::FormInput;
+use yew_styles::highlight::Highlight;
+use yew_styles::styles::Palette;
+
+pub struct HighlightPage {
+    link: ComponentLink<Self>,
+    search: String,
+}
+
+pub enum Msg {
+    Search(String),
+}
+
+impl Component for HighlightPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            search: "yew".to_string(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Search(search) => self.search = search,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Highlight Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"highlight"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_highlight()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"text: "}</b>{"text where the terms are searched. Required."}</li>
+                    <li><b>{"terms: "}</b>{"terms to highlight, the empty ones are ignored. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"case_sensitive: "}</b>{"distinguish between uppercase and lowercase letters. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"regex: "}</b>{"use the terms as javascript regular expressions, invalid ones are ignored. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"highlight_palette: "}</b>{"palette of the marks. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Warning"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b><code>{"find_matches"}</code>{" returns the same ranges to highlight the matches in your own elements."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <FormInput
+                    value=self.search.clone()
+                    placeholder="Search"
+                    oninput_signal=self.link.callback(|e: InputData| Msg::Search(e.value))
+                />
+                <p>
+                    <Highlight
+                        text="Yew Styles is a framework of styles for yew"
+                        terms=self.search.split_whitespace().map(String::from).collect::<Vec<String>>()
+                    />
+                </p>
+                <p>
+                    <Highlight
+                        text="Yew Styles is a framework of styles for yew"
+                        terms=vec!["st[a-z]+".to_string()]
+                        regex=true
+                        highlight_palette=Palette::Info
+                    />
+                </p>
+            </>
+        }
+    }
+}
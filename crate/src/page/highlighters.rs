@@ -105,6 +105,18 @@ pub fn file_code() -> String {
     .to_string()
 }
 
+pub fn upload_code() -> String {
+    "<FormFile
+    name=\"document\"
+    multiple=true
+    upload_url=\"/api/documents\"
+    onchange_signal=Callback::noop()
+    onuploaded_signal=self.link.callback(|(name, response)| Msg::Uploaded(name, response))
+    onuploaderror_signal=self.link.callback(|(name, error)| Msg::UploadFailed(name, error))
+/>"
+    .to_string()
+}
+
 pub fn select_code() -> String {
     "<FormSelect
     select_size=Size::Medium
@@ -137,13 +149,86 @@ pub fn textarea_code() -> String {
     .to_string()
 }
 
+pub fn range_slider_code() -> String {
+    "<FormRangeSlider
+    value=self.price
+    min=0.0
+    max=200.0
+    step=5.0
+    min_gap=10.0
+    ticks=vec![(0.0, \"0 €\".to_string()), (100.0, \"100 €\".to_string()), (200.0, \"200 €\".to_string())]
+    slider_palette=Palette::Primary
+    aria_label=\"Price\"
+    onchange_signal=self.link.callback(Msg::ChangePrice)
+/>"
+    .to_string()
+}
+
+pub fn form_builder_code() -> String {
+    "#[derive(Clone, PartialEq)]
+pub struct Contact {
+    name: String,
+    email: String,
+    age: Option<u8>,
+    newsletter: bool,
+}
+
+impl FromFormValues for Contact {
+    fn from_form_values(values: &FormValues) -> Result<Self, String> {
+        Ok(Contact {
+            name: values.text(\"name\"),
+            email: values.text(\"email\"),
+            age: values.parse(\"age\").ok(),
+            newsletter: values.checked(\"newsletter\"),
+        })
+    }
+}
+
+// view
+<FormBuilder<Contact>
+    columns=2
+    submit_label=\"Save contact\"
+    onsubmit_signal=self.link.callback(Msg::Save)
+    fields=vec![
+        FormField::new(\"name\", \"Name\", FieldKind::Text)
+            .validator(Validator::Required)
+            .validator(Validator::MaxLength(80)),
+        FormField::new(\"email\", \"Email\", FieldKind::Email)
+            .validator(Validator::Required)
+            .validator(Validator::Email),
+        FormField::new(\"age\", \"Age\", FieldKind::Number)
+            .validator(Validator::Min(18.0)),
+        FormField::new(\"newsletter\", \"Subscribe to the newsletter\", FieldKind::Checkbox)
+            .full_width(),
+    ]
+/>"
+    .to_string()
+}
+
+pub fn error_summary_code() -> String {
+    "<Form onsubmit_signal=Callback::noop()>
+    <FormErrorSummary />
+    <FormGroup>
+        <FormLabel text=\"Email\" label_for=\"email\" />
+        <FormInput
+            id=\"email\"
+            error_state=self.email_error
+            error_message=\"Enter a valid email address\"
+            oninput_signal=self.link.callback(|e: InputData| Msg::Email(e.value))
+        />
+    </FormGroup>
+    <FormSubmit value=\"Sign up\" />
+</Form>"
+        .to_string()
+}
+
 pub fn basic_form_code() -> String {
     "<Container wrap=Wrap::Wrap direction=Direction::Row>
     <Item layouts=vec!(ItemLayout::ItXs(12))>
         <h1>{\"Basic Form\"}</h1>
     </Item>
     <Item layouts=vec!(ItemLayout::ItXs(12))>
-        <Form onsubmit_signal=self.link.callback(|e| Msg::Submit)>
+        <Form onsubmit_event_signal=self.link.callback(|_| Msg::Submit)>
             <Container wrap=Wrap::Wrap direction=Direction::Row>
                 <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
                     <FormGroup orientation=Orientation::Horizontal>
@@ -474,3 +559,737 @@ pub fn get_stylist() -> String {
 />"
     .to_string()
 }
+
+pub fn get_calendar() -> String {
+    "<Calendar
+    date=self.date.clone()
+    selected=self.selected.clone()
+    calendar_view=CalendarView::Month
+    calendar_palette=Palette::Info
+    calendar_style=Style::Light
+    events=vec![
+        CalendarEvent {
+            date: CalendarDate::new(2021, 7, 14),
+            title: \"Release\".to_string(),
+            palette: Palette::Success,
+        },
+    ]
+    onnavigate_signal=self.link.callback(Msg::Navigate)
+    ondayclick_signal=self.link.callback(Msg::SelectDay)
+/>"
+    .to_string()
+}
+
+pub fn get_memo() -> String {
+    "<button onclick=self.link.callback(|_| Msg::Increment)>{self.counter}</button>
+<Memo dependency=memo_key(&self.chart_points)>
+    <svg>
+        {self.chart_points.iter().map(|(x, y)| html! {
+            <circle cx=x.to_string() cy=y.to_string() r=\"2\"/>
+        }).collect::<Html>()}
+    </svg>
+</Memo>"
+        .to_string()
+}
+
+pub fn get_toast() -> String {
+    "<Button onclick_signal=self.link.callback(|_| Msg::AddToast)>{\"Save\"}</Button>
+<ToastContainer max_visible=2 toast_position=ToastPosition::BottomRight>
+    {for self.toasts.iter().map(|id| {
+        let id = *id;
+
+        html! {
+            <Toast
+                key=id.to_string()
+                toast_palette=Palette::Success
+                onclose_signal=self.link.callback(move |reason| Msg::CloseToast(id, reason))
+            >
+                {format!(\"Document {} saved\", id)}
+            </Toast>
+        }
+    })}
+</ToastContainer>"
+        .to_string()
+}
+
+pub fn get_undo_toast() -> String {
+    "<Toast
+    action=html! {\"Undo\"}
+    confirmation=html! {\"Message deleted\"}
+    onaction_signal=self.link.callback(move |_| Msg::RestoreMessage(id))
+    onconfirm_signal=self.link.callback(move |_| Msg::DeleteMessage(id))
+    onclose_signal=self.link.callback(move |_| Msg::CloseToast(id))
+>
+    {\"Deleting message...\"}
+</Toast>"
+        .to_string()
+}
+
+pub fn get_error_toast() -> String {
+    "fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    install_panic_hook();
+    Self { link }
+}
+
+fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+        Msg::Save => {
+            if let Err(error) = save() {
+                report_error(error.context(\"Document not saved\"));
+            }
+        }
+    };
+    false
+}
+
+fn view(&self) -> Html {
+    html! {
+        <>
+            <button onclick=self.link.callback(|_| Msg::Save)>{\"Save\"}</button>
+            <ErrorToastHost/>
+        </>
+    }
+}"
+    .to_string()
+}
+
+pub fn get_typography() -> String {
+    "<Heading heading_level=HeadingLevel::H1>{\"Yew Styles\"}</Heading>
+<Heading
+    heading_level=HeadingLevel::H2
+    heading_size=FontSize::Medium
+    heading_weight=FontWeight::Regular
+>{\"Framework styles for yew\"}</Heading>
+<Text>{\"Yew Styles is a framework of styles for yew\"}</Text>
+<Text inline=true muted=true small=true>{\"Last update 2 days ago\"}</Text>
+<Label label_for=\"email\" required=true>{\"Email\"}</Label>
+<input id=\"email\" type=\"email\"/>"
+        .to_string()
+}
+
+pub fn get_sidenav() -> String {
+    "<SideNav
+    sidenav_palette=Palette::Clean
+    sidenav_style=Style::Light
+    storage_key=\"admin-sidenav\"
+>
+    <SideNavItem icon=html!{\"🏠\"} route=\"/\">{\"Home\"}</SideNavItem>
+    <SideNavSection
+        title=\"Orders\"
+        icon=html!{\"📦\"}
+        storage_key=\"admin-sidenav-orders\"
+    >
+        <SideNavItem route=\"/orders\" badge=html!{\"12\"}>{\"All orders\"}</SideNavItem>
+        <SideNavItem route=\"/orders/returns\" active_match=ActiveMatch::Prefix>
+            {\"Returns\"}
+        </SideNavItem>
+    </SideNavSection>
+</SideNav>"
+        .to_string()
+}
+
+pub fn get_focus_trap() -> String {
+    "<button onclick=self.link.callback(|_| Msg::Toggle)>{\"Open\"}</button>
+{if self.open {
+    html! {
+        <FocusTrap>
+            <div class=\"dialog\">
+                <input type=\"text\"/>
+                <button onclick=self.link.callback(|_| Msg::Toggle)>{\"Close\"}</button>
+            </div>
+        </FocusTrap>
+    }
+} else {
+    html! {}
+}}"
+    .to_string()
+}
+
+pub fn get_image_zoom() -> String {
+    "<ImageZoom
+    img_src=\"/shoe.jpg\"
+    zoom_img_src=\"/shoe_large.jpg\"
+    img_alt=\"Running shoe\"
+    zoom=3.0
+/>"
+    .to_string()
+}
+
+pub fn get_compare_slider() -> String {
+    "<CompareSlider
+    before_src=\"/painting_before.jpg\"
+    after_src=\"/painting_after.jpg\"
+    before_label=\"Before\"
+    after_label=\"After\"
+    position=30.0
+/>"
+    .to_string()
+}
+
+pub fn get_announcer() -> String {
+    "fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    match msg {
+        Msg::Save => announce(\"Document saved\", Politeness::Polite),
+    };
+    false
+}
+
+fn view(&self) -> Html {
+    html! {
+        <>
+            <button onclick=self.link.callback(|_| Msg::Save)>{\"Save\"}</button>
+            <Announcer/>
+        </>
+    }
+}"
+    .to_string()
+}
+
+pub fn get_video_thumb() -> String {
+    "<VideoThumb
+    video_src=\"/trailer.mp4\"
+    poster_src=\"/trailer.jpg\"
+    title=\"Official trailer\"
+    close_on_end=true
+/>"
+    .to_string()
+}
+
+pub fn get_pdf_viewer() -> String {
+    "<PdfViewer
+    src=\"/invoice.pdf\"
+    title=\"Invoice\"
+    pages=Some(3)
+    file_name=\"invoice.pdf\"
+/>"
+    .to_string()
+}
+
+pub fn get_embed() -> String {
+    "<Embed
+    src=\"https://www.openstreetmap.org/export/embed.html?bbox=-0.1,51.5,-0.09,51.51\"
+    title=\"Office location\"
+    aspect_ratio=Some((4, 3))
+    sandbox=Some(\"allow-scripts allow-same-origin\".to_string())
+    consent=true
+/>"
+    .to_string()
+}
+
+pub fn get_line_clamp() -> String {
+    "<Text line_clamp=Some(3) ontoggle_signal=self.link.callback(Msg::Toggle)>
+    {\"A long description which is expanded with the show more button\"}
+</Text>"
+        .to_string()
+}
+
+pub fn get_highlight() -> String {
+    "<Highlight
+    text=\"Yew Styles is a framework of styles for yew\"
+    terms=vec![\"yew\".to_string(), \"style\".to_string()]
+/>"
+    .to_string()
+}
+
+pub fn get_icon() -> String {
+    "<Button>
+    <Icon icon_type=IconType::Download icon_size=Size::Small />
+    {\"Download\"}
+</Button>
+<Icon icon_type=IconType::AlertTriangle icon_palette=Some(Palette::Warning) title=Some(\"Warning\".to_string()) />
+<Icon icon_type=IconType::ArrowUp rotate=45 />
+<Icon icon_type=IconType::ChevronRight flip=Some(Flip::Horizontal) />
+<Icon icon_type=IconType::Custom>
+    <circle cx=\"12\" cy=\"12\" r=\"4\" />
+</Icon>"
+        .to_string()
+}
+
+pub fn get_count_up() -> String {
+    "let currency = self.currency.clone();
+
+html! {
+    <CountUp
+        value=self.total
+        duration=2000
+        easing=Easing::Emphasized
+        formatter=Formatter::new(move |value| format!(\"{:.2} {}\", value, currency))
+    />
+}"
+    .to_string()
+}
+
+pub fn get_truncate_middle() -> String {
+    "<TruncateMiddle
+    text=\"/home/user/projects/yew_styles/src/components/truncate_middle.rs\"
+    end_chars=Some(18)
+/>
+<TruncateMiddle
+    text=\"9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08\"
+    copyable=true
+/>"
+    .to_string()
+}
+
+pub fn get_click_away() -> String {
+    "<ClickAway active=self.open onclickaway_signal=self.link.callback(|_| Msg::Close)>
+    <button onclick=self.link.callback(|_| Msg::Open)>{\"Options\"}</button>
+    {if self.open {
+        html! {<div class=\"popup\">{\"Popup content\"}</div>}
+    } else {
+        html! {}
+    }}
+</ClickAway>"
+        .to_string()
+}
+
+pub fn get_secret_text() -> String {
+    "<SecretText
+    value=\"sk_live_51H8mW2KZ9x\"
+    clear_clipboard_after=Some(20000)
+    onreveal_signal=self.link.callback(|_| Msg::Revealed)
+/>"
+    .to_string()
+}
+
+pub fn get_global_theme() -> String {
+    "<GlobalTheme
+    variables=vec![(\"--ys-border-radius\".to_string(), \"8px\".to_string())]
+    density=Density::Compact
+/>
+<div style=\"border: 1px solid var(--ys-primary)\">{\"Custom element\"}</div>"
+        .to_string()
+}
+
+pub fn get_global_styles() -> String {
+    "<GlobalStyles />
+<GlobalTheme />
+<main>{\"Content\"}</main>"
+        .to_string()
+}
+
+pub fn get_filterable_list() -> String {
+    "<FilterableList
+    items=vec![\"Argentina\".to_string(), \"Belgium\".to_string(), \"Chile\".to_string()]
+    placeholder=\"Search a country\"
+    empty_state=html! {<p>{\"There is no country with that name\"}</p>}
+    onselect_signal=self.link.callback(Msg::Select)
+/>"
+    .to_string()
+}
+
+pub fn get_availability_grid() -> String {
+    "<AvailabilityGrid
+    start_date=CalendarDate::new(2021, 7, 12)
+    days=5
+    start_minutes=8 * 60
+    end_minutes=17 * 60
+    selected=self.available.clone()
+    onchange_signal=self.link.callback(Msg::Change)
+/>"
+    .to_string()
+}
+
+pub fn get_surface() -> String {
+    "<Surface elevation=Elevation::Level3 radius=Radius::Large padding=Spacing::Large>
+    {\"Floating panel\"}
+</Surface>"
+        .to_string()
+}
+
+pub fn get_seat_map() -> String {
+    "<SeatMap
+    rows=8
+    columns=12
+    gaps=(0..8).map(|row| SeatId::new(row, 6)).collect::<Vec<SeatId>>()
+    taken=vec![SeatId::new(2, 3), SeatId::new(2, 4)]
+    categories=vec![SeatCategory {
+        name: \"VIP\".to_string(),
+        palette: Palette::Secondary,
+        seats: (0..12).map(|column| SeatId::new(0, column)).collect(),
+    }]
+    max_selected=Some(4)
+    selected=self.seats.clone()
+    onselect_signal=self.link.callback(Msg::Select)
+/>"
+    .to_string()
+}
+
+pub fn get_progress_toast() -> String {
+    "// update
+match msg {
+    Msg::Start => self.upload = Some(start_progress(\"Uploading report.pdf\")),
+    Msg::Progress(progress) => {
+        if let Some(upload) = &self.upload {
+            upload.set_progress(progress);
+        }
+    }
+    Msg::Done => {
+        if let Some(upload) = self.upload.take() {
+            upload.succeed(\"report.pdf uploaded\");
+        }
+    }
+};
+
+// view
+<>
+    <button onclick=self.link.callback(|_| Msg::Start)>{\"Upload\"}</button>
+    <ProgressToastHost/>
+</>"
+    .to_string()
+}
+
+pub fn get_portal() -> String {
+    "<div style=\"overflow: hidden; height: 40px\">
+    <button onclick=self.link.callback(|_| Msg::Toggle)>{\"Show banner\"}</button>
+    {if self.open {
+        html! {
+            <Portal>
+                <div class=\"banner\">
+                    <button onclick=self.link.callback(|_| Msg::Toggle)>{\"Close\"}</button>
+                </div>
+            </Portal>
+        }
+    } else {
+        html! {}
+    }}
+</div>"
+        .to_string()
+}
+
+pub fn get_status_page() -> String {
+    "<StatusPage
+    services=vec![
+        ServiceRow {
+            name: \"API\".to_string(),
+            status: ServiceStatus::Operational,
+            uptime: vec![
+                DayUptime { date: \"2021-06-01\".to_string(), uptime: Some(100.0) },
+                DayUptime { date: \"2021-06-02\".to_string(), uptime: Some(98.5) },
+            ],
+        },
+    ]
+    incidents=vec![
+        Incident {
+            title: \"Slow responses of the API\".to_string(),
+            impact: ServiceStatus::Degraded,
+            date: \"2021-06-02\".to_string(),
+            resolved: true,
+            updates: vec![IncidentUpdate {
+                date: \"2021-06-02 10:30\".to_string(),
+                message: \"The database has been scaled up\".to_string(),
+            }],
+        },
+    ]
+    subscribe=html! {
+        <form>
+            <input type=\"email\" placeholder=\"Email\"/>
+            <button>{\"Subscribe\"}</button>
+        </form>
+    }
+/>"
+    .to_string()
+}
+
+pub fn get_changelog() -> String {
+    "<Changelog
+    entries=vec![
+        ChangelogEntry {
+            version: \"0.11.0\".to_string(),
+            date: Some(\"2021-06-01\".to_string()),
+            changes: vec![
+                Change::new(ChangeKind::Added, \"Toast component\"),
+                Change::new(ChangeKind::Fixed, \"Focus of the modal\"),
+            ],
+            body: Some(html! {<p>{\"Summer release\"}</p>}),
+        },
+        ChangelogEntry {
+            version: \"0.10.0\".to_string(),
+            date: Some(\"2021-03-01\".to_string()),
+            changes: vec![Change::new(ChangeKind::Changed, \"Yew 0.18\")],
+            body: None,
+        },
+    ]
+/>"
+    .to_string()
+}
+
+pub fn get_notification() -> String {
+    "// update
+match msg {
+    Msg::Save => {
+        NotificationService::push(Notification::success(\"Saved\").title(\"Document\"));
+    }
+};
+
+// view
+<>
+    <button onclick=self.link.callback(|_| Msg::Save)>{\"Save\"}</button>
+    <NotificationHost/>
+</>"
+    .to_string()
+}
+
+pub fn get_document_viewer() -> String {
+    "<DocumentViewer
+    title=\"Terms of service\"
+    updated=\"June 1, 2021\"
+    sections=vec![
+        DocumentSection {
+            id: \"acceptance\".to_string(),
+            title: \"Acceptance of the terms\".to_string(),
+            content: html! {<p>{\"By using the service you accept these terms.\"}</p>},
+        },
+        DocumentSection {
+            id: \"privacy\".to_string(),
+            title: \"Privacy\".to_string(),
+            content: html! {<p>{\"Your data is never sold.\"}</p>},
+        },
+    ]
+/>"
+    .to_string()
+}
+
+pub fn get_copy_button() -> String {
+    "<CopyButton
+    text=\"sk_live_51H8\"
+    button_palette=Palette::Info
+    button_style=Style::Outline
+    oncopy_signal=self.link.callback(Msg::Copied)
+    onerror_signal=self.link.callback(Msg::NotCopied)
+/>"
+    .to_string()
+}
+
+pub fn get_page_style() -> String {
+    "<PageStyle
+    css=\".landing {
+        padding: ${spacing-large};
+        h1 { color: ${primary}; }
+    }\"
+/>
+<main class=\"landing\"><h1>{\"Welcome\"}</h1></main>"
+        .to_string()
+}
+
+pub fn get_empty_state() -> String {
+    "<EmptyState
+    illustration=html! {<img src=\"/assets/empty-folder.svg\" alt=\"\" />}
+    title=\"Nothing here yet\"
+    description=\"Create a project to start tracking your work\"
+    action=html! {
+        <Button
+            button_palette=Palette::Primary
+            onclick_signal=self.link.callback(|_| Msg::Create)
+        >{\"New project\"}</Button>
+    }
+/>"
+    .to_string()
+}
+
+pub fn get_transition_group() -> String {
+    "<div class=\"tasks\">
+    <TransitionGroup transition=Transition::Collapse>
+        {for self.tasks.iter().enumerate().map(|(index, task)| html! {
+            <div key=task.clone() onclick=self.link.callback(move |_| Msg::Remove(index))>
+                {task}
+            </div>
+        })}
+    </TransitionGroup>
+</div>"
+        .to_string()
+}
+
+pub fn get_confetti() -> String {
+    "// update
+match msg {
+    Msg::Pay => self.confetti.fire(),
+};
+
+// view
+<>
+    <Button onclick_signal=self.link.callback(|_| Msg::Pay)>{\"Pay\"}</Button>
+    <Confetti handle=self.confetti.clone() particle_count=200 />
+</>"
+    .to_string()
+}
+
+pub fn get_stat() -> String {
+    "<Container direction=Direction::Row wrap=Wrap::Wrap>
+    <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(6))>
+        <Stat
+            label=\"Revenue\"
+            value=\"€48,250\"
+            delta=\"12%\"
+            trend=Trend::Up
+            caption=\"vs last month\"
+            icon=html! {<Icon icon_type=IconType::Star />}
+        />
+    </Item>
+    <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(6))>
+        <Stat label=\"Failed payments\" value=\"32\" delta=\"4%\" trend=Trend::Down inverted=true />
+    </Item>
+</Container>"
+        .to_string()
+}
+
+pub fn get_list() -> String {
+    "<List
+    selection=Selection::Multiple
+    selected=self.selected.clone()
+    dividers=true
+    onselect_signal=self.link.callback(Msg::Select)
+>
+    <ListItem
+        value=\"welcome\"
+        leading=html! {<Icon icon_type=IconType::Mail />}
+        description=\"The team\"
+    >
+        {\"Welcome to the workspace\"}
+    </ListItem>
+    <ListItem
+        value=\"invoice\"
+        leading=html! {<Icon icon_type=IconType::Mail />}
+        trailing=html! {<Icon icon_type=IconType::Star />}
+    >
+        {\"Invoice of june\"}
+    </ListItem>
+</List>"
+        .to_string()
+}
+
+pub fn get_menu() -> String {
+    "<Menu
+    trigger=html! {<Icon icon_type=IconType::MoreHorizontal />}
+    trigger_label=\"Actions\"
+    align=MenuAlign::End
+    items=vec![
+        MenuEntry::Item(
+            MenuItem::new(\"Edit\")
+                .icon(html! {<Icon icon_type=IconType::Edit />})
+                .shortcut(\"Ctrl+E\")
+                .onselect(link.callback(|_| Msg::Action(String::from(\"edit\")))),
+        ),
+        MenuEntry::Item(MenuItem::new(\"Move to\").submenu(vec![
+            MenuEntry::Item(
+                MenuItem::new(\"Archive\")
+                    .onselect(link.callback(|_| Msg::Action(String::from(\"archive\")))),
+            ),
+            MenuEntry::Item(MenuItem::new(\"Trash\").disabled(true)),
+        ])),
+        MenuEntry::Separator,
+        MenuEntry::Item(
+            MenuItem::new(\"Delete\")
+                .onselect(link.callback(|_| Msg::Action(String::from(\"delete\")))),
+        ),
+    ]
+/>"
+    .to_string()
+}
+
+pub fn get_scrollspy() -> String {
+    "<>
+    <Scrollspy
+        offset=64
+        sections=vec![
+            ScrollspySection::new(\"install\", \"Installation\"),
+            ScrollspySection::new(\"usage\", \"Usage\"),
+        ]
+    />
+    <h2 id=\"install\">{\"Installation\"}</h2>
+    <p>{\"Add yew_styles to the dependencies\"}</p>
+    <h2 id=\"usage\">{\"Usage\"}</h2>
+    <p>{\"Import the components from the prelude\"}</p>
+</>"
+    .to_string()
+}
+
+pub fn get_affix() -> String {
+    "<Affix offset_top=64>
+    <Scrollspy
+        offset=64
+        sections=vec![
+            ScrollspySection::new(\"install\", \"Installation\"),
+            ScrollspySection::new(\"usage\", \"Usage\"),
+        ]
+    />
+</Affix>"
+        .to_string()
+}
+
+pub fn get_back_to_top() -> String {
+    "<>
+    <article>{\"A long article\"}</article>
+    <BackToTop threshold=600 />
+</>"
+    .to_string()
+}
+
+pub fn get_sticky() -> String {
+    "<>
+    <Sticky
+        offset_top=64
+        elevation=Elevation::Level3
+        onstick_signal=self.link.callback(Msg::Stick)
+    >
+        <div class=\"toolbar\">{\"Filters\"}</div>
+    </Sticky>
+    <table>{\"A long table\"}</table>
+</>"
+    .to_string()
+}
+
+pub fn get_gallery() -> String {
+    "<Gallery
+    thumbnail_width=200
+    images=vec![
+        GalleryImage::new(\"/lake.jpg\", \"Lake at sunrise\")
+            .thumbnail(\"/lake_small.jpg\")
+            .caption(\"Day 1, the lake\"),
+        GalleryImage::new(\"/peak.jpg\", \"Snowy peak\").thumbnail(\"/peak_small.jpg\"),
+    ]
+/>"
+    .to_string()
+}
+
+pub fn get_infinite_scroll() -> String {
+    "<InfiniteScroll
+    loading=self.loading
+    has_more=self.posts.len() < 100
+    onloadmore_signal=self.link.callback(|_| Msg::LoadMore)
+>
+    <List>
+        {for self.posts.iter().map(|post| html! {
+            <ListItem value=post.clone()>{post}</ListItem>
+        })}
+    </List>
+</InfiniteScroll>"
+        .to_string()
+}
+
+pub fn get_sortable_list() -> String {
+    "<SortableList onreorder_signal=self.link.callback(Msg::Reorder)>
+    {for self.songs.iter().map(|song| html! {<span>{song}</span>})}
+</SortableList>"
+        .to_string()
+}
+
+pub fn get_split_pane() -> String {
+    "<SplitPane
+    size=self.sidebar_size
+    min_size=15.0
+    max_size=50.0
+    collapsible=true
+    onresize_signal=self.link.callback(Msg::Resize)
+    first=html! {<nav>{\"Files\"}</nav>}
+    second=html! {<main>{\"Code\"}</main>}
+/>"
+    .to_string()
+}
+
+pub fn get_loading_overlay() -> String {
+    "<LoadingOverlay loading=self.loading message=\"Loading the orders\" blur=true>
+    <Card header=Some(html! {\"Orders\"}) body=Some(html! {\"No orders yet\"}) />
+</LoadingOverlay>"
+        .to_string()
+}
@@ -0,0 +1,141 @@
+use super::highlighters::get_icon;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::icon::{Flip, Icon, IconType};
+use yew_styles::layouts::{
+    container::{Container, Direction, Wrap},
+    item::{Item, ItemLayout},
+};
+use yew_styles::styles::{Palette, Size, Style};
+
+pub struct IconPage;
+
+impl Component for IconPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Icon Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"icon"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_icon()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"icon_type: "}</b>{"icon to show. Options included in "}<code>{"IconType"}</code>{". Required."}</li>
+                    <li><b>{"icon_size: "}</b>{"three diffent icon standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"icon_palette: "}</b>{"color of the icon, "}<code>{"None"}</code>{" takes the color of the text. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"filled: "}</b>{"fill the shapes with the color besides the lines. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"stroke_width: "}</b>{"width of the lines. Default "}<code>{"2.0"}</code>{"."}</li>
+                    <li><b>{"rotate: "}</b>{"rotation in degrees clockwise. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"flip: "}</b>{"mirror the icon. Options included in "}<code>{"Flip"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"title: "}</b>{"text for the screen readers, without it the icon is hidden to them. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"view_box: "}</b>{"view box of the custom icons. Default "}<code>{"\"0 0 24 24\""}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"with "}<code>{"IconType::Custom"}</code>{" the children are drawn inside of the svg."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <h3>{"Icon types"}</h3>
+                <Container direction=Direction::Row wrap=Wrap::Wrap>
+                    {get_icon_types()}
+                </Container>
+                <h3>{"Sizes and palettes"}</h3>
+                <Icon icon_type=IconType::Star icon_size=Size::Small icon_palette=Some(Palette::Warning) />
+                <Icon icon_type=IconType::Star icon_palette=Some(Palette::Warning) filled=true />
+                <Icon icon_type=IconType::Star icon_size=Size::Big icon_palette=Some(Palette::Warning) stroke_width=1.0 />
+                <h3>{"Rotate and flip"}</h3>
+                <Icon icon_type=IconType::ArrowUp rotate=45 />
+                <Icon icon_type=IconType::ChevronRight flip=Some(Flip::Horizontal) />
+                <h3>{"Custom"}</h3>
+                <Icon icon_type=IconType::Custom>
+                    <circle cx="12" cy="12" r="4" />
+                </Icon>
+                <h3>{"Inside of a button"}</h3>
+                <Button button_palette=Palette::Info button_style=Style::Light>
+                    <Icon icon_type=IconType::Download icon_size=Size::Small />
+                    {"Download"}
+                </Button>
+            </>
+        }
+    }
+}
+
+fn get_icon_types() -> Html {
+    let icon_types: Vec<IconType> = vec![
+        IconType::AlertCircle,
+        IconType::AlertTriangle,
+        IconType::ArrowDown,
+        IconType::ArrowUp,
+        IconType::Bell,
+        IconType::Calendar,
+        IconType::Check,
+        IconType::CheckCircle,
+        IconType::ChevronDown,
+        IconType::ChevronLeft,
+        IconType::ChevronRight,
+        IconType::ChevronUp,
+        IconType::Clock,
+        IconType::Copy,
+        IconType::Download,
+        IconType::Edit,
+        IconType::ExternalLink,
+        IconType::Eye,
+        IconType::EyeOff,
+        IconType::Filter,
+        IconType::Heart,
+        IconType::Home,
+        IconType::Info,
+        IconType::Lock,
+        IconType::Mail,
+        IconType::Menu,
+        IconType::Minus,
+        IconType::MoreHorizontal,
+        IconType::Plus,
+        IconType::Search,
+        IconType::Star,
+        IconType::Trash,
+        IconType::Upload,
+        IconType::User,
+    ];
+
+    icon_types
+        .into_iter()
+        .map(|icon_type| {
+            html! {
+                <Item layouts=vec![ItemLayout::ItXs(4), ItemLayout::ItM(2)]>
+                    <Icon icon_type=icon_type />
+                    <p>{format!("{:?}", icon_type)}</p>
+                </Item>
+            }
+        })
+        .collect::<Html>()
+}
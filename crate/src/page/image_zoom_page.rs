@@ -0,0 +1,67 @@
+use super::highlighters::get_image_zoom;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::image_zoom::ImageZoom;
+
+pub struct ImageZoomPage;
+
+impl Component for ImageZoomPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Image Zoom Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"image_zoom"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_image_zoom()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"img_src: "}</b>{"url image path. Required."}</li>
+                    <li><b>{"zoom_img_src: "}</b>{"url of a bigger version of the image for the lens and the full view. Default "}<code>{"img_src"}</code>{"."}</li>
+                    <li><b>{"img_alt: "}</b>{"alternative text of the image. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"zoom: "}</b>{"magnification of the lens. Default "}<code>{"2.0"}</code>{"."}</li>
+                    <li><b>{"lens_size: "}</b>{"size of the lens in pixels. Default "}<code>{"150"}</code>{"."}</li>
+                    <li><b>{"hover_zoom: "}</b>{"show the lens when the mouse is over the image. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"click_to_zoom: "}</b>{"open the image in full view with a click, it is closed with "}<code>{"Escape"}</code>
+                        {" or clicking the full view. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"max_scale: "}</b>{"maximum scale of the pinch in the full view. Default "}<code>{"4.0"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <ImageZoom
+                    img_src="/slide_1.jpg"
+                    img_alt="Slide"
+                    zoom=3.0
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use super::highlighters::get_infinite_scroll;
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew_prism::Prism;
+use yew_styles::infinite_scroll::InfiniteScroll;
+use yew_styles::list::{List, ListItem};
+
+pub struct InfiniteScrollPage {
+    link: ComponentLink<Self>,
+    container: NodeRef,
+    posts: Vec<String>,
+    loading: bool,
+    load_task: Option<TimeoutTask>,
+}
+
+pub enum Msg {
+    LoadMore,
+    Loaded,
+}
+
+impl Component for InfiniteScrollPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            container: NodeRef::default(),
+            posts: (1..=10).map(|post| format!("Post {}", post)).collect(),
+            loading: false,
+            load_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::LoadMore => {
+                self.loading = true;
+                self.load_task = Some(TimeoutService::spawn(
+                    Duration::from_millis(800),
+                    self.link.callback(|_| Msg::Loaded),
+                ));
+            }
+            Msg::Loaded => {
+                let next_post = self.posts.len() + 1;
+
+                self.posts
+                    .extend((next_post..next_post + 10).map(|post| format!("Post {}", post)));
+                self.loading = false;
+                self.load_task = None;
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Infinite Scroll Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"infinite_scroll"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_infinite_scroll()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"loading: "}</b>{"more content is being loaded, it shows the loading content and the signal isn't emitted again. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"has_more: "}</b>{"there is more content to load, when it is false the end of the results is shown. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"threshold: "}</b>{"pixels before the end of the content where more content is asked. Default "}<code>{"200"}</code>{"."}</li>
+                    <li><b>{"container: "}</b>{"scrollable element which contains the content. Default "}<code>{"NodeRef::default()"}</code>{", the page."}</li>
+                    <li><b>{"loading_content: "}</b>{"content shown while loading. Default a small spinner."}</li>
+                    <li><b>{"end_content: "}</b>{"content shown when there is no more content. Default "}<code>{"Messages::infinite_scroll_end"}</code>{"."}</li>
+                    <li><b>{"onloadmore_signal: "}</b>{"signal emitted when the end of the content is near and there is more content to load."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"If the loaded content doesn't fill the page or the container it keeps asking for more."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <div ref=self.container.clone() style="height: 300px; overflow-y: auto">
+                    <InfiniteScroll
+                        loading=self.loading
+                        has_more=self.posts.len() < 50
+                        container=self.container.clone()
+                        onloadmore_signal=self.link.callback(|_| Msg::LoadMore)
+                    >
+                        <List dividers=true>
+                            {for self.posts.iter().map(|post| html! {
+                                <ListItem value=post.clone()>{post}</ListItem>
+                            })}
+                        </List>
+                    </InfiniteScroll>
+                </div>
+            </>
+        }
+    }
+}
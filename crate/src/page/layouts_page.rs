@@ -3,8 +3,11 @@ use yew::prelude::*;
 use yew_prism::Prism;
 use yew_styles::layouts::{
     container::{AlignContent, AlignItems, Container, Direction, JustifyContent, Mode, Wrap},
+    hidden::Hidden,
     item::{AlignSelf, Item, ItemLayout},
+    visible::Visible,
 };
+use yew_styles::styles::breakpoints::Breakpoint;
 
 pub struct LayoutsPage;
 
@@ -47,6 +50,8 @@ impl Component for LayoutsPage {
                     <li><b>{"justify_content: "}</b>{"set how will be justified the content. Options included in "}<code>{"JustifyContent"}</code>{". Default "}<code>{"FlexStart(No Mode)"}</code>{"."}</li>
                     <li><b>{"align_content: "}</b>{"set how will be aligned the content. Options included in "}<code>{"AlignContent"}</code>{". Default "}<code>{"Stretch(NoMode)"}</code>{"."}</li>
                     <li><b>{"align_items: "}</b>{"set how will be aligned the items. Options included in "}<code>{"AlignItems"}</code>{". Default "}<code>{"Stretch(NoMode)"}</code>{"."}</li>
+                    <li><b>{"responsive_direction: "}</b>{"direction of the items from a breakpoint up, which replaces direction in bigger screens. Example: "}<code>{"vec![(Breakpoint::M, Direction::Row)]"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"fixed: "}</b>{"limit the width of the container to the current breakpoint and center it, otherwise it is fluid. Default "}<code>{"false"}</code>{"."}</li>
                     <li><b>{"mode: "}</b>{"safe postion handler which is additional option for justify_content, align_content and align_items. Options included in "}<code>{"Mode"}</code>{". Default "}<code>{"NoMode"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
@@ -67,6 +72,48 @@ impl Component for LayoutsPage {
                     <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
                 </ul>
 
+                <h2>{"Row properties"}</h2>
+                <ul>
+                    <li><b>{"gutter: "}</b>{"horizontal space between the columns. Options included in "}<code>{"Spacing"}</code>{". Default "}<code>{"Spacing::Medium"}</code>{"."}</li>
+                    <li><b>{"vertical_gutter: "}</b>{"vertical space between the lines of columns when they wrap. Default "}<code>{"Spacing::None"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Column properties"}</h2>
+                <ul>
+                    <li><b>{"spans: "}</b>{"columns of the 12 columns grid that takes the column from a breakpoint up. Example: "}<code>{"vec![(Breakpoint::Xs, 12), (Breakpoint::M, 6)]"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"offsets: "}</b>{"columns left before the column from a breakpoint up. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"orders: "}</b>{"visual order of the column in the row from a breakpoint up. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Hidden properties"}</h2>
+                <ul>
+                    <li><b>{"hidden_on: "}</b>{"breakpoints where the children are hidden. Example: "}<code>{"vec![Breakpoint::Xs, Breakpoint::S]"}</code>{". Required."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Visible properties"}</h2>
+                <ul>
+                    <li><b>{"visible_on: "}</b>{"breakpoints where the children are shown. Example: "}<code>{"vec![Breakpoint::Xs, Breakpoint::S]"}</code>{". Required."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
 
                 <h2>{"Visual examples"}</h2>
                 <h3>{"Wrap"}</h3>
@@ -205,6 +252,14 @@ impl Component for LayoutsPage {
                 <p>{"To know about more options please visit "}
                     <a href="https://developer.mozilla.org/en-US/docs/Web/CSS/align-self" target="_bank">{"Align Self"}</a>
                 </p>
+                <h3>{"Hidden and Visible"}</h3>
+                <p>{"Resize the window to see which text is shown in each breakpoint"}</p>
+                <Hidden hidden_on=vec![Breakpoint::Xs, Breakpoint::S]>
+                    <span>{"Only in medium and big screens"}</span>
+                </Hidden>
+                <Visible visible_on=vec![Breakpoint::Xs, Breakpoint::S]>
+                    <span>{"Only in small screens"}</span>
+                </Visible>
             </>
         }
     }
@@ -0,0 +1,137 @@
+use super::highlighters::get_list;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::icon::{Icon, IconType};
+use yew_styles::list::{List, ListItem, Selection};
+
+pub struct ListPage {
+    link: ComponentLink<Self>,
+    selected: Vec<String>,
+}
+
+pub enum Msg {
+    Select(Vec<String>),
+}
+
+impl Component for ListPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            selected: vec![],
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Select(selected) => self.selected = selected,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"List Components"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"list"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_list()
+                    language="rust"
+                />
+
+                <h2>{"Common properties"}</h2>
+                <ul>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"List"}</h2>
+                <ul>
+                    <li><b>{"selection: "}</b>{"how many items can be selected. Options included in "}<code>{"Selection"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"selected: "}</b>{"values of the selected items. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"dividers: "}</b>{"lines between the items. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"dense: "}</b>{"smaller paddings and font. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"onselect_signal: "}</b>{"signal emitted with the values of the selected items when the selection changes."}</li>
+                </ul>
+
+                <h2>{"List item"}</h2>
+                <ul>
+                    <li><b>{"value: "}</b>{"value emitted by the list when the item is selected. Required."}</li>
+                    <li><b>{"leading: "}</b>{"icon or avatar shown before the content. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"trailing: "}</b>{"action shown after the content, its clicks don't select the item. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"description: "}</b>{"secondary text below the content. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"disabled: "}</b>{"the item can't be clicked or selected. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"onclick_signal: "}</b>{"click event for the item."}</li>
+                </ul>
+
+                <p>{"The items are selected clicking them or with "}<code>{"Enter"}</code>{" and "}<code>{"Space"}</code>
+                    {", the arrow keys, "}<code>{"Home"}</code>{" and "}<code>{"End"}</code>{" move the focus between the items."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <List
+                    selection=Selection::Multiple
+                    selected=self.selected.clone()
+                    dividers=true
+                    onselect_signal=self.link.callback(Msg::Select)
+                >
+                    <ListItem
+                        value="welcome"
+                        leading=html! {<Icon icon_type=IconType::Mail />}
+                        description="The team"
+                    >
+                        {"Welcome to the workspace"}
+                    </ListItem>
+                    <ListItem
+                        value="invoice"
+                        leading=html! {<Icon icon_type=IconType::Mail />}
+                        trailing=html! {<Icon icon_type=IconType::Star />}
+                    >
+                        {"Invoice of june"}
+                    </ListItem>
+                    <ListItem
+                        value="newsletter"
+                        leading=html! {<Icon icon_type=IconType::Mail />}
+                        description="Archived"
+                        disabled=true
+                    >
+                        {"Monthly newsletter"}
+                    </ListItem>
+                </List>
+                <p>{format!("Selected: {}", self.selected.join(", "))}</p>
+
+                <h3>{"Dense"}</h3>
+                <List dense=true>
+                    <ListItem
+                        value="ada"
+                        leading=html! {<Icon icon_type=IconType::User />}
+                        description="ada@example.com"
+                    >
+                        {"Ada Lovelace"}
+                    </ListItem>
+                    <ListItem
+                        value="alan"
+                        leading=html! {<Icon icon_type=IconType::User />}
+                        description="alan@example.com"
+                    >
+                        {"Alan Turing"}
+                    </ListItem>
+                </List>
+            </>
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use super::highlighters::get_loading_overlay;
+use lipsum::lipsum;
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::card::Card;
+use yew_styles::loading_overlay::LoadingOverlay;
+use yew_styles::spinner::SpinnerType;
+use yew_styles::styles::{Palette, Style};
+
+pub struct LoadingOverlayPage {
+    link: ComponentLink<Self>,
+    loading: bool,
+    full_page: bool,
+    load_task: Option<TimeoutTask>,
+}
+
+pub enum Msg {
+    Load(bool),
+    Loaded,
+}
+
+impl Component for LoadingOverlayPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            loading: false,
+            full_page: false,
+            load_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Load(full_page) => {
+                self.loading = true;
+                self.full_page = full_page;
+                self.load_task = Some(TimeoutService::spawn(
+                    Duration::from_millis(2000),
+                    self.link.callback(|_| Msg::Loaded),
+                ));
+            }
+            Msg::Loaded => {
+                self.loading = false;
+                self.load_task = None;
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Loading Overlay Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"loading_overlay"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_loading_overlay()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"loading: "}</b>{"show the overlay over the children. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"message: "}</b>{"text shown under the spinner. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"blur: "}</b>{"blur the children under the overlay. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"full_page: "}</b>{"cover the whole viewport over the rest of the overlays instead of only the children, e.g. while the page loads. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"spinner_type: "}</b>{"spinner type. Options included in "}<code>{"SpinnerType"}</code>{". Default "}<code>{"Circle"}</code>{"."}</li>
+                    <li><b>{"spinner_palette: "}</b>{"spinner palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"While it is loading the children can't be clicked or reached with the keyboard, the focus inside of them moves to the overlay."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Load(false))
+                    button_palette=Palette::Info
+                    button_style=Style::Light
+                >{"Load the orders"}</Button>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Load(true))
+                    button_palette=Palette::Primary
+                    button_style=Style::Light
+                >{"Load the page"}</Button>
+                <LoadingOverlay
+                    loading=self.loading
+                    full_page=self.full_page
+                    message="Loading the orders"
+                    blur=true
+                    spinner_type=SpinnerType::Chase
+                    spinner_palette=Palette::Info
+                >
+                    <Card header=Some(html! {"Orders"}) body=Some(html! {lipsum(30)}) />
+                </LoadingOverlay>
+            </>
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::highlighters::get_memo;
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::memo::{memo_key, Memo};
+use yew_styles::styles::{Palette, Style};
+
+pub struct MemoPage {
+    link: ComponentLink<Self>,
+    counter: u32,
+    words: usize,
+}
+
+pub enum Msg {
+    Increment,
+    AddWords,
+}
+
+impl Component for MemoPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            counter: 0,
+            words: 20,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Increment => self.counter += 1,
+            Msg::AddWords => self.words += 10,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Memo Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"memo"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_memo()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"dependency: "}</b>{"hash of the values used to build the children, the children are only rendered again when it changes. Use "}
+                        <code>{"memo_key"}</code>{" to get it. Required."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Increment)
+                    button_palette=Palette::Info
+                    button_style=Style::Light
+                >{format!("Counter: {}", self.counter)}</Button>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::AddWords)
+                    button_palette=Palette::Primary
+                    button_style=Style::Light
+                >{"Add words"}</Button>
+                <Memo dependency=memo_key(&self.words)>
+                    <p>{lipsum(self.words)}</p>
+                </Memo>
+            </>
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use super::highlighters::get_menu;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::icon::{Icon, IconType};
+use yew_styles::menu::{Menu, MenuAlign, MenuEntry, MenuItem};
+
+pub struct MenuPage {
+    link: ComponentLink<Self>,
+    action: String,
+}
+
+pub enum Msg {
+    Action(String),
+}
+
+impl Component for MenuPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            action: String::from(""),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Action(action) => self.action = action,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let link = &self.link;
+
+        html! {
+            <>
+                <h1>{"Menu Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"menu"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_menu()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"trigger: "}</b>{"content of the button which opens the menu. Required."}</li>
+                    <li><b>{"items: "}</b>{"entries of the menu, a "}<code>{"MenuEntry::Item"}</code>{" or a "}<code>{"MenuEntry::Separator"}</code>{". Required."}</li>
+                    <li><b>{"trigger_label: "}</b>{"accessible name of the trigger, for the triggers with only an icon. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"align: "}</b>{"side of the trigger where the menu is aligned. Options included in "}<code>{"MenuAlign"}</code>{". Default "}<code>{"Start"}</code>{"."}</li>
+                    <li><b>{"elevation: "}</b>{"shadow level of the menu and the submenus. Options included in "}<code>{"Elevation"}</code>{". Default "}<code>{"Level2"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Menu item"}</h2>
+                <p>{"Unlike "}<code>{"FormSelect"}</code>{" the menu doesn't keep a value, each item calls its own callback when it is chosen."}</p>
+                <ul>
+                    <li><b>{"new: "}</b>{"create an item with its label."}</li>
+                    <li><b>{"icon: "}</b>{"add an icon before the label."}</li>
+                    <li><b>{"shortcut: "}</b>{"show a keyboard shortcut after the label, only as a hint."}</li>
+                    <li><b>{"disabled: "}</b>{"the item can't be chosen."}</li>
+                    <li><b>{"onselect: "}</b>{"callback called when the item is chosen."}</li>
+                    <li><b>{"submenu: "}</b>{"open a nested submenu with these entries."}</li>
+                </ul>
+
+                <p>{"The arrows "}<code>{"Up"}</code>{" and "}<code>{"Down"}</code>{" move between the enabled items, "}<code>{"Right"}</code>
+                    {" opens the submenu of the item and "}<code>{"Left"}</code>{" closes it, "}<code>{"Enter"}</code>{" and "}<code>{"Space"}</code>
+                    {" choose the item and "}<code>{"Escape"}</code>{" closes the menu returning the focus to the trigger."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Menu
+                    trigger=html! {<Icon icon_type=IconType::MoreHorizontal />}
+                    trigger_label="Actions"
+                    items=vec![
+                        MenuEntry::Item(
+                            MenuItem::new("Edit")
+                                .icon(html! {<Icon icon_type=IconType::Edit />})
+                                .shortcut("Ctrl+E")
+                                .onselect(link.callback(|_| Msg::Action(String::from("edit")))),
+                        ),
+                        MenuEntry::Item(MenuItem::new("Move to").submenu(vec![
+                            MenuEntry::Item(
+                                MenuItem::new("Archive")
+                                    .onselect(link.callback(|_| Msg::Action(String::from("archive")))),
+                            ),
+                            MenuEntry::Item(MenuItem::new("Trash").disabled(true)),
+                        ])),
+                        MenuEntry::Separator,
+                        MenuEntry::Item(
+                            MenuItem::new("Delete")
+                                .icon(html! {<Icon icon_type=IconType::Trash />})
+                                .onselect(link.callback(|_| Msg::Action(String::from("delete")))),
+                        ),
+                    ]
+                />
+                <Menu
+                    trigger=html! {"Options"}
+                    align=MenuAlign::End
+                    items=vec![
+                        MenuEntry::Item(
+                            MenuItem::new("Download")
+                                .icon(html! {<Icon icon_type=IconType::Download />})
+                                .onselect(link.callback(|_| Msg::Action(String::from("download")))),
+                        ),
+                        MenuEntry::Item(
+                            MenuItem::new("Share")
+                                .icon(html! {<Icon icon_type=IconType::ExternalLink />})
+                                .onselect(link.callback(|_| Msg::Action(String::from("share")))),
+                        ),
+                    ]
+                />
+                <p>{format!("Last action: {}", self.action)}</p>
+            </>
+        }
+    }
+}
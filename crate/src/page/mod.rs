@@ -1,32 +1,116 @@
+pub mod announcer_page;
 pub mod assets_page;
+pub mod availability_grid_page;
+pub mod back_to_top_page;
 pub mod basic_form_page;
 pub mod button_page;
+pub mod calendar_page;
 pub mod card_page;
 pub mod carousel_page;
+pub mod changelog_page;
+pub mod click_away_page;
+pub mod compare_slider_page;
+pub mod confetti_page;
+pub mod copy_button_page;
+pub mod count_up_page;
+pub mod document_viewer_page;
 pub mod dropdown_page;
+pub mod embed_page;
+pub mod empty_state_page;
+pub mod filterable_list_page;
+pub mod focus_trap_page;
 pub mod form_pages;
+pub mod gallery_page;
+pub mod highlight_page;
 mod highlighters;
 pub mod home_page;
+pub mod icon_page;
+pub mod image_zoom_page;
+pub mod infinite_scroll_page;
 pub mod layouts_page;
+pub mod list_page;
+pub mod loading_overlay_page;
+pub mod memo_page;
+pub mod menu_page;
 pub mod modal_page;
 pub mod navbar_page;
+pub mod pdf_viewer_page;
+pub mod portal_page;
+pub mod scrollspy_page;
+pub mod seat_map_page;
+pub mod secret_text_page;
+pub mod sidenav_page;
+pub mod sortable_list_page;
 pub mod spinner_page;
+pub mod split_pane_page;
+pub mod stat_page;
+pub mod sticky_page;
 pub mod stylist_page;
+pub mod surface_page;
+pub mod system_status_page;
 pub mod text_page;
+pub mod theme_page;
+pub mod toast_page;
 pub mod tooltip_page;
+pub mod transition_group_page;
+pub mod truncate_middle_page;
+pub mod typography_page;
+pub mod video_thumb_page;
 
+pub use self::announcer_page::AnnouncerPage;
 pub use self::assets_page::AssetsPage;
+pub use self::availability_grid_page::AvailabilityGridPage;
+pub use self::back_to_top_page::BackToTopPage;
 pub use self::basic_form_page::BasicFormPage;
 pub use self::button_page::ButtonPage;
+pub use self::calendar_page::CalendarPage;
 pub use self::card_page::CardPage;
 pub use self::carousel_page::CarouselPage;
+pub use self::changelog_page::ChangelogPage;
+pub use self::click_away_page::ClickAwayPage;
+pub use self::compare_slider_page::CompareSliderPage;
+pub use self::confetti_page::ConfettiPage;
+pub use self::copy_button_page::CopyButtonPage;
+pub use self::count_up_page::CountUpPage;
+pub use self::document_viewer_page::DocumentViewerPage;
 pub use self::dropdown_page::DropDownPage;
+pub use self::embed_page::EmbedPage;
+pub use self::empty_state_page::EmptyStatePage;
+pub use self::filterable_list_page::FilterableListPage;
+pub use self::focus_trap_page::FocusTrapPage;
 pub use self::form_pages::FormPage;
+pub use self::gallery_page::GalleryPage;
+pub use self::highlight_page::HighlightPage;
 pub use self::home_page::HomePage;
+pub use self::icon_page::IconPage;
+pub use self::image_zoom_page::ImageZoomPage;
+pub use self::infinite_scroll_page::InfiniteScrollPage;
 pub use self::layouts_page::LayoutsPage;
+pub use self::list_page::ListPage;
+pub use self::loading_overlay_page::LoadingOverlayPage;
+pub use self::memo_page::MemoPage;
+pub use self::menu_page::MenuPage;
 pub use self::modal_page::ModalPage;
 pub use self::navbar_page::NavbarPage;
+pub use self::pdf_viewer_page::PdfViewerPage;
+pub use self::portal_page::PortalPage;
+pub use self::scrollspy_page::ScrollspyPage;
+pub use self::seat_map_page::SeatMapPage;
+pub use self::secret_text_page::SecretTextPage;
+pub use self::sidenav_page::SideNavPage;
+pub use self::sortable_list_page::SortableListPage;
 pub use self::spinner_page::SpinnerPage;
+pub use self::split_pane_page::SplitPanePage;
+pub use self::stat_page::StatPage;
+pub use self::sticky_page::StickyPage;
 pub use self::stylist_page::StylistPage;
+pub use self::surface_page::SurfacePage;
+pub use self::system_status_page::SystemStatusPage;
 pub use self::text_page::TextPage;
+pub use self::theme_page::ThemePage;
+pub use self::toast_page::ToastPage;
 pub use self::tooltip_page::TooltipPage;
+pub use self::transition_group_page::TransitionGroupPage;
+pub use self::truncate_middle_page::TruncateMiddlePage;
+pub use self::typography_page::TypographyPage;
+pub use self::video_thumb_page::VideoThumbPage;
@@ -148,6 +148,10 @@ impl Component for NavbarPage {
                 <ul>
                     <li><b>{"active: "}</b>{"active nav item style. Default "}<code>{"false"}</code></li>
                     <li><b>{"interaction_effect: "}</b>{"if hove, focus, active effects are enable. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"route: "}</b>{"route of the item, it is active when the route matches the current path. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"active_match: "}</b>{"how the route is compared with the current path, Exact or Prefix. Default "}<code>{"ActiveMatch::Exact"}</code>{"."}</li>
+                    <li><b>{"current_path: "}</b>{"current path of the router, pass it to update the item on navigation. Default "}<code>{"window location path"}</code>{"."}</li>
+                    <li><b>{"active_indicator: "}</b>{"show an animated bar under the active item. Default "}<code>{"false"}</code>{"."}</li>
                     <li><b>{"onclick_signal: "}</b>{"click event for navbar item. Default "}<code>{"noop()"}</code></li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
@@ -160,6 +164,8 @@ impl Component for NavbarPage {
                 <ul>
                     <li><b>{"main_content: "}</b>{"clickeable content to show the dropdown. Required"}</li>
                     <li><b>{"active: "}</b>{"show with style when the dropdown is currrently active. Default "}<code>{"false"}</code></li>
+                    <li><b>{"mega_menu: "}</b>{"show the items in a full width panel that can contain any html, opened on hover and with Enter, Space or ArrowDown and closed with Escape. Default "}<code>{"false"}</code></li>
+                    <li><b>{"hover_delay: "}</b>{"milliseconds the pointer has to stay over or out of the dropdown to open or close the mega menu. Default "}<code>{"150"}</code></li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
                     <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
@@ -170,6 +176,9 @@ impl Component for NavbarPage {
                 <ul>
                     <li><b>{"onclick_signal: "}</b>{"click event for navbar dropdown item. Default "}<code>{"noop()"}</code></li>
                     <li><b>{"active: "}</b>{"show with style when the dropdown item is currrently active. Default "}<code>{"false"}</code></li>
+                    <li><b>{"route: "}</b>{"route of the item, it is active when the route matches the current path. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"active_match: "}</b>{"how the route is compared with the current path, Exact or Prefix. Default "}<code>{"ActiveMatch::Exact"}</code>{"."}</li>
+                    <li><b>{"current_path: "}</b>{"current path of the router, pass it to update the item on navigation. Default "}<code>{"window location path"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
                     <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
@@ -0,0 +1,78 @@
+use super::highlighters::get_pdf_viewer;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::pdf_viewer::PdfViewer;
+use yew_styles::styles::{Palette, Style};
+
+pub struct PdfViewerPage;
+
+impl Component for PdfViewerPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Pdf Viewer Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"pdf_viewer"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_pdf_viewer()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"src: "}</b>{"url path of the document. Required."}</li>
+                    <li><b>{"title: "}</b>{"title of the document for the screen readers. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"renderer: "}</b>{"how the document is shown. Options included in "}<code>{"PdfRenderer"}</code>{". Default "}<code>{"Iframe"}</code>{"."}</li>
+                    <li><b>{"page: "}</b>{"initial page, starting by 1. Default "}<code>{"1"}</code>{"."}</li>
+                    <li><b>{"pages: "}</b>{"number of pages, to disable the next button in the last page. Pdf.js reads it from the document. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"zoom: "}</b>{"initial zoom in percentage. Default "}<code>{"100"}</code>{"."}</li>
+                    <li><b>{"zoom_step: "}</b>{"percentage added or removed by the zoom buttons. Default "}<code>{"25"}</code>{"."}</li>
+                    <li><b>{"show_controls: "}</b>{"show the toolbar with the page and zoom controls. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"show_download: "}</b>{"show the download button. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"file_name: "}</b>{"name of the downloaded file, the name of the url if it is empty. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"onpage_signal: "}</b>{"emitted with the new page when it changes."}</li>
+                    <li><b>{"pdf_viewer_palette: "}</b>{"type toolbar palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"pdf_viewer_style: "}</b>{"toolbar styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"by default the document is shown by the viewer of the browser in an iframe. With the "}<code>{"pdf_js"}</code>
+                    {" feature and "}<code>{"renderer=PdfRenderer::PdfJs"}</code>{" the pages are drawn in a canvas with "}
+                    <a href="https://mozilla.github.io/pdf.js/" target="_blank">{"pdf.js"}</a>{", which must be added to the page before."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <PdfViewer
+                    src="https://mozilla.github.io/pdf.js/web/compressed.tracemonkey-pldi-09.pdf"
+                    title="Trace-based Just-in-Time Type Specialization for Dynamic Languages"
+                    pages=Some(14)
+                    pdf_viewer_palette=Palette::Info
+                    pdf_viewer_style=Style::Light
+                />
+            </>
+        }
+    }
+}
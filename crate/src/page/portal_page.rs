@@ -0,0 +1,91 @@
+use super::highlighters::get_portal;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::portal::Portal;
+use yew_styles::styles::{Palette, Style};
+
+pub struct PortalPage {
+    link: ComponentLink<Self>,
+    open: bool,
+}
+
+pub enum Msg {
+    Toggle,
+}
+
+impl Component for PortalPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, open: false }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => self.open = !self.open,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Portal Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"portal"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_portal()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"host: "}</b>{"element where the children are rendered. Default "}<code>{"None"}</code>{", the end of "}<code>{"document.body"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the "}<code>{"Modal"}</code>{" and "}<code>{"ToastContainer"}</code>{" components already render inside of a portal."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <div style="overflow: hidden; height: 50px">
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::Toggle)
+                        button_palette=Palette::Info
+                        button_style=Style::Light
+                    >{"Show banner"}</Button>
+                    {if self.open {
+                        html! {
+                            <Portal>
+                                <div style="position: fixed; top: 0; left: 0; right: 0; z-index: 10; padding: 10px; background-color: #ffffff">
+                                    <span>{"The banner is outside of the hidden overflow"}</span>
+                                    <Button
+                                        onclick_signal=self.link.callback(|_| Msg::Toggle)
+                                        button_palette=Palette::Standard
+                                        button_style=Style::Outline
+                                    >{"Close"}</Button>
+                                </div>
+                            </Portal>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::highlighters::{get_affix, get_scrollspy};
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::affix::Affix;
+use yew_styles::scrollspy::{Scrollspy, ScrollspySection};
+
+pub struct ScrollspyPage {
+    link: ComponentLink<Self>,
+    section: String,
+    pinned: bool,
+}
+
+pub enum Msg {
+    ChangeSection(String),
+    Affix(bool),
+}
+
+impl Component for ScrollspyPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            section: String::from(""),
+            pinned: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeSection(section) => self.section = section,
+            Msg::Affix(pinned) => self.pinned = pinned,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let sections = vec![
+            ("scrollspy-install", "Installation"),
+            ("scrollspy-usage", "Usage"),
+            ("scrollspy-themes", "Themes"),
+            ("scrollspy-faq", "FAQ"),
+        ];
+
+        html! {
+            <>
+                <h1>{"Scrollspy and Affix Components"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"scrollspy"}</code>{", "}<code>{"affix"}</code></span>
+
+                <h2>{"Scrollspy"}</h2>
+                <p>{"Navigation of the sections of a page which highlights the link of the section being read. "}
+                    {"The current section is the last one whose heading has scrolled past the "}<code>{"offset"}</code>
+                    {", or the last section when the page is scrolled to the end."}</p>
+
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=get_scrollspy()
+                    language="rust"
+                />
+
+                <h3>{"Properties"}</h3>
+                <ul>
+                    <li><b>{"sections: "}</b>{"sections of the page with the id of their heading and their label, in the order they appear. Required."}</li>
+                    <li><b>{"offset: "}</b>{"pixels from the top where a heading becomes the current section, e.g. the height of a fixed navbar. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"container: "}</b>{"scrollable element with the sections. Default "}<code>{"NodeRef::default()"}</code>{", the page."}</li>
+                    <li><b>{"onsection_signal: "}</b>{"signal emitted with the id of the current section when it changes."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Affix"}</h2>
+                <p>{"Pins its children to the top of the window once the page scrolls past them, e.g. the table of contents of a documentation page. "}
+                    {"While it is pinned it keeps the space of the children in the page, so the content below doesn't jump."}</p>
+
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=get_affix()
+                    language="rust"
+                />
+
+                <h3>{"Properties"}</h3>
+                <ul>
+                    <li><b>{"offset_top: "}</b>{"pixels from the top of the window where the children are pinned, e.g. the height of a fixed navbar. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"onaffix_signal: "}</b>{"signal emitted with "}<code>{"true"}</code>{" when the children are pinned and "}<code>{"false"}</code>{" when they return to the page."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <p>{format!("Current section: {}, pinned: {}", self.section, self.pinned)}</p>
+                <Affix onaffix_signal=self.link.callback(Msg::Affix)>
+                    <Scrollspy
+                        sections=sections
+                            .iter()
+                            .map(|(id, label)| ScrollspySection::new(id, label))
+                            .collect::<Vec<ScrollspySection>>()
+                        onsection_signal=self.link.callback(Msg::ChangeSection)
+                    />
+                </Affix>
+                {for sections.iter().map(|(id, label)| html! {
+                    <>
+                        <h3 id=*id>{label}</h3>
+                        <p>{lipsum(150)}</p>
+                    </>
+                })}
+            </>
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::highlighters::get_seat_map;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::seat_map::{SeatCategory, SeatId, SeatMap};
+use yew_styles::styles::Palette;
+
+pub struct SeatMapPage {
+    link: ComponentLink<Self>,
+    seats: Vec<SeatId>,
+}
+
+pub enum Msg {
+    Select(Vec<SeatId>),
+}
+
+impl Component for SeatMapPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            seats: vec![],
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Select(seats) => self.seats = seats,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Seat Map Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"seat_map"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_seat_map()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"rows: "}</b>{"number of rows. Required."}</li>
+                    <li><b>{"columns: "}</b>{"number of seats in each row. Required."}</li>
+                    <li><b>{"taken: "}</b>{"seats which can't be selected."}</li>
+                    <li><b>{"gaps: "}</b>{"positions without seat, e.g. the aisles."}</li>
+                    <li><b>{"categories: "}</b>{"categories of the seats with their own palette, e.g. the price ranges. The seats without category use the "}
+                        <code>{"seat_palette"}</code>{"."}</li>
+                    <li><b>{"selected: "}</b>{"selected seats."}</li>
+                    <li><b>{"max_selected: "}</b>{"maximum number of selected seats. Default "}<code>{"None"}</code>{", without limit."}</li>
+                    <li><b>{"row_labels: "}</b>{"labels of the rows. Default "}<code>{"None"}</code>{", letters from A."}</li>
+                    <li><b>{"seat_palette: "}</b>{"type seat map palette of the seats without category. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Success"}</code>{"."}</li>
+                    <li><b>{"onselect_signal: "}</b>{"signal emitted with all the selected seats when a seat is selected or unselected."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The seats are selected with the pointer, or moving with the arrow keys and pressing enter or space."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <SeatMap
+                    rows=8
+                    columns=12
+                    gaps=(0..8).map(|row| SeatId::new(row, 6)).collect::<Vec<SeatId>>()
+                    taken=vec![SeatId::new(2, 3), SeatId::new(2, 4), SeatId::new(5, 9)]
+                    categories=vec![SeatCategory {
+                        name: "VIP".to_string(),
+                        palette: Palette::Secondary,
+                        seats: (0..12).map(|column| SeatId::new(0, column)).collect(),
+                    }]
+                    max_selected=Some(4)
+                    selected=self.seats.clone()
+                    onselect_signal=self.link.callback(Msg::Select)
+                />
+                <p>{format!("Selected seats: {}", self.seats.len())}</p>
+            </>
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::highlighters::get_secret_text;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::secret_text::SecretText;
+
+pub struct SecretTextPage {
+    link: ComponentLink<Self>,
+    reveals: u32,
+}
+
+pub enum Msg {
+    Revealed,
+}
+
+impl Component for SecretTextPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, reveals: 0 }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Revealed => self.reveals += 1,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Secret Text Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"secret_text"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_secret_text()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"value: "}</b>{"secret value. Required."}</li>
+                    <li><b>{"mask_char: "}</b>{"character which hides the value. Default "}<code>{"'•'"}</code>{"."}</li>
+                    <li><b>{"mask_length: "}</b>{"number of mask characters, so the length of the value is not shown. "}<code>{"None"}</code>
+                        {" uses the length of the value. Default "}<code>{"Some(8)"}</code>{"."}</li>
+                    <li><b>{"copyable: "}</b>{"add a button to copy the value. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"clear_clipboard_after: "}</b>{"milliseconds after which the copied value is removed from the clipboard, "}<code>{"None"}</code>
+                        {" keeps it. Default "}<code>{"Some(30000)"}</code>{"."}</li>
+                    <li><b>{"onreveal_signal: "}</b>{"emitted each time the value is revealed, e.g. for audit logs."}</li>
+                    <li><b>{"oncopy_signal: "}</b>{"emitted each time the value is copied."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the value is only revealed while the reveal button is held with the pointer or the keyboard."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <SecretText
+                    value="sk_live_51H8mW2KZ9x"
+                    clear_clipboard_after=Some(20000)
+                    onreveal_signal=self.link.callback(|_| Msg::Revealed)
+                />
+                <p>{format!("Revealed {} times", self.reveals)}</p>
+            </>
+        }
+    }
+}
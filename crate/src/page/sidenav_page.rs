@@ -0,0 +1,134 @@
+use super::highlighters::get_sidenav;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::navbar::navbar_route::ActiveMatch;
+use yew_styles::sidenav::{
+    sidenav_component::SideNav, sidenav_item::SideNavItem, sidenav_section::SideNavSection,
+};
+use yew_styles::styles::{Palette, Style};
+
+pub struct SideNavPage {
+    link: ComponentLink<Self>,
+    current_path: String,
+}
+
+pub enum Msg {
+    Navigate(&'static str),
+}
+
+impl Component for SideNavPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            current_path: "/".to_string(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Navigate(path) => self.current_path = path.to_string(),
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"SideNav Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"sidenav"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_sidenav()
+                    language="rust"
+                />
+
+                <h2>{"Common properties"}</h2>
+                <ul>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"SideNav"}</h2>
+                <ul>
+                    <li><b>{"mini: "}</b>{"show only the icons of the items, the labels appear as tooltips. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"collapsible: "}</b>{"show a button to switch between the full and the mini mode. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"storage_key: "}</b>{"key to persist the mini mode in the local storage. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"ontoggle_signal: "}</b>{"signal emitted with the mini mode when the toggle button is clicked."}</li>
+                    <li><b>{"sidenav_palette: "}</b>{"type sidenav palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"sidenav_style: "}</b>{"sidenav styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"aria_label: "}</b>{"label for the screen readers. Default "}<code>{"Messages::side_navigation"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"SideNav section"}</h2>
+                <ul>
+                    <li><b>{"title: "}</b>{"title of the section. Required."}</li>
+                    <li><b>{"icon: "}</b>{"icon shown before the title and in the mini mode. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"badge: "}</b>{"badge shown after the title. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"expanded: "}</b>{"show the items of the section. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"storage_key: "}</b>{"key to persist the expansion state in the local storage. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"ontoggle_signal: "}</b>{"signal emitted with the expansion state when the title is clicked."}</li>
+                </ul>
+
+                <h2>{"SideNav item"}</h2>
+                <ul>
+                    <li><b>{"icon: "}</b>{"icon shown before the label and in the mini mode. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"badge: "}</b>{"badge shown after the label. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"active: "}</b>{"active item style. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"route: "}</b>{"route of the item, it is active when the route matches the current path. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"active_match: "}</b>{"how the route is compared with the current path. Options included in "}<code>{"ActiveMatch"}</code>{". Default "}<code>{"Exact"}</code>{"."}</li>
+                    <li><b>{"current_path: "}</b>{"current path of the router. Default the path of the window location."}</li>
+                    <li><b>{"onclick_signal: "}</b>{"click event for the item."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <SideNav
+                    sidenav_palette=Palette::Clean
+                    sidenav_style=Style::Light
+                >
+                    <SideNavItem
+                        icon=html!{"🏠"}
+                        route="/"
+                        current_path=self.current_path.clone()
+                        onclick_signal=self.link.callback(|_| Msg::Navigate("/"))
+                    >{"Home"}</SideNavItem>
+                    <SideNavSection title="Orders" icon=html!{"📦"} expanded=true>
+                        <SideNavItem
+                            route="/orders"
+                            badge=html!{"12"}
+                            current_path=self.current_path.clone()
+                            onclick_signal=self.link.callback(|_| Msg::Navigate("/orders"))
+                        >{"All orders"}</SideNavItem>
+                        <SideNavItem
+                            route="/orders/returns"
+                            active_match=ActiveMatch::Prefix
+                            current_path=self.current_path.clone()
+                            onclick_signal=self.link.callback(|_| Msg::Navigate("/orders/returns"))
+                        >{"Returns"}</SideNavItem>
+                    </SideNavSection>
+                    <SideNavSection title="Settings" icon=html!{"⚙"}>
+                        <SideNavItem
+                            route="/settings/profile"
+                            current_path=self.current_path.clone()
+                            onclick_signal=self.link.callback(|_| Msg::Navigate("/settings/profile"))
+                        >{"Profile"}</SideNavItem>
+                    </SideNavSection>
+                </SideNav>
+            </>
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::highlighters::get_sortable_list;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::sortable_list::SortableList;
+
+pub struct SortableListPage {
+    link: ComponentLink<Self>,
+    songs: Vec<String>,
+}
+
+pub enum Msg {
+    Reorder(Vec<usize>),
+}
+
+impl Component for SortableListPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            songs: vec![
+                "Bohemian Rhapsody".to_string(),
+                "Hotel California".to_string(),
+                "Imagine".to_string(),
+                "Stairway to Heaven".to_string(),
+            ],
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Reorder(order) => {
+                self.songs = order
+                    .into_iter()
+                    .map(|index| self.songs[index].clone())
+                    .collect();
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Sortable List Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"sortable_list"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_sortable_list()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"drag_handle: "}</b>{"items are dragged by their handle, otherwise by the whole item. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"handle: "}</b>{"content of the handles. Default a menu icon."}</li>
+                    <li><b>{"disabled: "}</b>{"the items can't be reordered. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"onreorder_signal: "}</b>{"signal emitted with the previous indexes of the items in their new order, e.g. "}<code>{"[1, 0, 2]"}</code>
+                        {" when the first item is moved below the second one."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The list doesn't reorder the children itself, the parent applies the new order. The focused handle moves its item with the arrow keys."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <SortableList onreorder_signal=self.link.callback(Msg::Reorder)>
+                    {for self.songs.iter().map(|song| html! {<span>{song}</span>})}
+                </SortableList>
+
+                <h3>{"Dragged by the whole item"}</h3>
+                <SortableList drag_handle=false onreorder_signal=self.link.callback(Msg::Reorder)>
+                    {for self.songs.iter().map(|song| html! {<span>{song}</span>})}
+                </SortableList>
+            </>
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use super::highlighters::get_split_pane;
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::split_pane::SplitPane;
+
+pub struct SplitPanePage {
+    link: ComponentLink<Self>,
+    sidebar_size: f64,
+}
+
+pub enum Msg {
+    Resize(f64),
+}
+
+impl Component for SplitPanePage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            sidebar_size: 30.0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Resize(size) => self.sidebar_size = size,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Split Pane Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"split_pane"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_split_pane()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"first: "}</b>{"content of the first panel, on the left or on the top. Required."}</li>
+                    <li><b>{"second: "}</b>{"content of the second panel, on the right or on the bottom. Required."}</li>
+                    <li><b>{"size: "}</b>{"initial size of the first panel in percentage. Default "}<code>{"50.0"}</code>{"."}</li>
+                    <li><b>{"min_size: "}</b>{"minimum size of the first panel in percentage. Default "}<code>{"10.0"}</code>{"."}</li>
+                    <li><b>{"max_size: "}</b>{"maximum size of the first panel in percentage. Default "}<code>{"90.0"}</code>{"."}</li>
+                    <li><b>{"collapsible: "}</b>{"the first panel collapses to the edge when the divider is dragged past half of the limits. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"step: "}</b>{"percentage moved by the arrow keys. Default "}<code>{"5.0"}</code>{"."}</li>
+                    <li><b>{"vertical: "}</b>{"place the panels one above the other instead of side by side. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"onresize_signal: "}</b>{"signal emitted with the new size of the first panel in percentage, passing it back as "}<code>{"size"}</code>{" keeps it between visits."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The divider moves with the arrow keys, "}<code>{"Home"}</code>{" and "}<code>{"End"}</code>{" move it to the limits and "}
+                    <code>{"Enter"}</code>{" collapses and restores the first panel."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <p>{format!("Size of the first panel: {:.0}%", self.sidebar_size)}</p>
+                <div style="height: 250px">
+                    <SplitPane
+                        size=self.sidebar_size
+                        min_size=15.0
+                        max_size=50.0
+                        collapsible=true
+                        onresize_signal=self.link.callback(Msg::Resize)
+                        first=html! {<nav>{"Files"}</nav>}
+                        second=html! {<main>{lipsum(60)}</main>}
+                    />
+                </div>
+
+                <h3>{"Vertical"}</h3>
+                <div style="height: 300px">
+                    <SplitPane
+                        vertical=true
+                        first=html! {<div>{"Editor"}</div>}
+                        second=html! {<div>{"Terminal"}</div>}
+                    />
+                </div>
+            </>
+        }
+    }
+}
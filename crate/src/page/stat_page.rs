@@ -0,0 +1,92 @@
+use super::highlighters::get_stat;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::icon::{Icon, IconType};
+use yew_styles::layouts::{
+    container::{Container, Direction, Wrap},
+    item::{Item, ItemLayout},
+};
+use yew_styles::stat::{Stat, Trend};
+use yew_styles::styles::Palette;
+
+pub struct StatPage;
+
+impl Component for StatPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Stat Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"stat"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_stat()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"label: "}</b>{"name of the metric. Required."}</li>
+                    <li><b>{"value: "}</b>{"value of the metric, already formatted. Required."}</li>
+                    <li><b>{"delta: "}</b>{"change since the previous period, e.g. "}<code>{"\"12%\""}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"trend: "}</b>{"direction of the change, it sets the arrow and the palette of the delta. Options included in "}<code>{"Trend"}</code>{". Default "}<code>{"Neutral"}</code>{"."}</li>
+                    <li><b>{"inverted: "}</b>{"lower is better, a falling delta is shown with the success palette, e.g. for costs or errors. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"caption: "}</b>{"text after the delta, e.g. "}<code>{"\"vs last month\""}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"icon: "}</b>{"icon or illustration shown next to the label. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"icon_palette: "}</b>{"palette of the background of the icon. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Primary"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The stat fills the height of its parent, so the stats of an "}<code>{"Item"}</code>{" row have the same height."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Container direction=Direction::Row wrap=Wrap::Wrap>
+                    <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(4))>
+                        <Stat
+                            label="Revenue"
+                            value="€48,250"
+                            delta="12%"
+                            trend=Trend::Up
+                            caption="vs last month"
+                            icon=html! {<Icon icon_type=IconType::Star />}
+                        />
+                    </Item>
+                    <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(4))>
+                        <Stat
+                            label="Active users"
+                            value="1,204"
+                            icon=html! {<Icon icon_type=IconType::User />}
+                            icon_palette=Palette::Info
+                        />
+                    </Item>
+                    <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(4))>
+                        <Stat label="Failed payments" value="32" delta="4%" trend=Trend::Down inverted=true />
+                    </Item>
+                </Container>
+            </>
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::highlighters::get_sticky;
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::sticky::Sticky;
+use yew_styles::styles::tokens::Elevation;
+
+pub struct StickyPage {
+    link: ComponentLink<Self>,
+    stuck: bool,
+}
+
+pub enum Msg {
+    Stick(bool),
+}
+
+impl Component for StickyPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, stuck: false }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Stick(stuck) => self.stuck = stuck,
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Sticky Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"sticky"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_sticky()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"offset_top: "}</b>{"pixels from the top where the children stick, e.g. the height of a fixed navbar. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"container: "}</b>{"scrollable element where the children stick. Default "}<code>{"NodeRef::default()"}</code>{", the page."}</li>
+                    <li><b>{"elevation: "}</b>{"shadow level of the stuck children. Options included in "}<code>{"Elevation"}</code>{". Default "}<code>{"Level2"}</code>{"."}</li>
+                    <li><b>{"onstick_signal: "}</b>{"signal emitted with "}<code>{"true"}</code>{" when the children stick and "}<code>{"false"}</code>{" when they return to their place."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"Unlike "}<code>{"Affix"}</code>{" it stays inside of its parent: it stops sticking when the end of the parent is reached. The stuck children get the class "}
+                    <code>{"stuck"}</code>{"."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <div>
+                    <Sticky
+                        elevation=Elevation::Level3
+                        onstick_signal=self.link.callback(Msg::Stick)
+                    >
+                        <div style="padding: 10px; background-color: #ffffff">
+                            {if self.stuck { "Toolbar stuck at the top" } else { "Toolbar" }}
+                        </div>
+                    </Sticky>
+                    <p>{lipsum(200)}</p>
+                    <p>{lipsum(200)}</p>
+                </div>
+                <p>{"The toolbar stops sticking at the end of its section."}</p>
+                <p>{lipsum(150)}</p>
+            </>
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::highlighters::get_surface;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::styles::tokens::{Elevation, Radius, Spacing};
+use yew_styles::styles::Palette;
+use yew_styles::surface::Surface;
+
+pub struct SurfacePage;
+
+impl Component for SurfacePage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Surface Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"surface"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_surface()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"elevation: "}</b>{"shadow level of the surface. Options included in "}<code>{"Elevation"}</code>{". Default "}<code>{"Level1"}</code>{"."}</li>
+                    <li><b>{"radius: "}</b>{"border radius of the surface. Options included in "}<code>{"Radius"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"surface_palette: "}</b>{"type surface palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Clean"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The elevation levels are the same used by the card, dropdown menu and modal components."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                {for [
+                    Elevation::Level0,
+                    Elevation::Level1,
+                    Elevation::Level2,
+                    Elevation::Level3,
+                    Elevation::Level4,
+                    Elevation::Level5,
+                ].iter().enumerate().map(|(level, elevation)| html! {
+                    <Surface elevation=*elevation padding=Spacing::Large margin=Spacing::Medium>
+                        {format!("Level {}", level)}
+                    </Surface>
+                })}
+                <Surface
+                    elevation=Elevation::Level2
+                    radius=Radius::Large
+                    surface_palette=Palette::Info
+                    padding=Spacing::Large
+                >
+                    {"Info surface with large radius"}
+                </Surface>
+            </>
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::highlighters::get_status_page;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::status_page::{
+    DayUptime, Incident, IncidentUpdate, ServiceRow, ServiceStatus, StatusPage,
+};
+
+pub struct SystemStatusPage;
+
+impl Component for SystemStatusPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Status Page Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"status_page"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_status_page()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"services: "}</b>{"services of the system with their status and the uptime of each day. Required."}</li>
+                    <li><b>{"incidents: "}</b>{"incidents from the newest to the oldest, they are expanded to see their updates. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"days: "}</b>{"number of days of the uptime strip. Default "}<code>{"90"}</code>{"."}</li>
+                    <li><b>{"subscribe: "}</b>{"content of the subscribe section, e.g. a form to get the incidents by email. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The status of the service and the impact of the incidents are options included in "}<code>{"ServiceStatus"}</code>
+                    {", the overall status is the most severe status of the services."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <StatusPage
+                    days=30
+                    services=vec![
+                        ServiceRow {
+                            name: "API".to_string(),
+                            status: ServiceStatus::Operational,
+                            uptime: get_uptime(&[12, 13]),
+                        },
+                        ServiceRow {
+                            name: "Dashboard".to_string(),
+                            status: ServiceStatus::Degraded,
+                            uptime: get_uptime(&[29]),
+                        },
+                        ServiceRow {
+                            name: "Webhooks".to_string(),
+                            status: ServiceStatus::Maintenance,
+                            uptime: get_uptime(&[]),
+                        },
+                    ]
+                    incidents=vec![
+                        Incident {
+                            title: "Slow loading of the dashboard".to_string(),
+                            impact: ServiceStatus::Degraded,
+                            date: "2021-06-30".to_string(),
+                            resolved: false,
+                            updates: vec![IncidentUpdate {
+                                date: "2021-06-30 09:15".to_string(),
+                                message: "We are investigating the issue".to_string(),
+                            }],
+                        },
+                        Incident {
+                            title: "Errors in the API".to_string(),
+                            impact: ServiceStatus::PartialOutage,
+                            date: "2021-06-13".to_string(),
+                            resolved: true,
+                            updates: vec![
+                                IncidentUpdate {
+                                    date: "2021-06-13 18:00".to_string(),
+                                    message: "The issue has been resolved".to_string(),
+                                },
+                                IncidentUpdate {
+                                    date: "2021-06-13 16:40".to_string(),
+                                    message: "A fix has been deployed".to_string(),
+                                },
+                            ],
+                        },
+                    ]
+                />
+            </>
+        }
+    }
+}
+
+fn get_uptime(incident_days: &[u32]) -> Vec<DayUptime> {
+    (1..=30)
+        .map(|day| DayUptime {
+            date: format!("2021-06-{:02}", day),
+            uptime: Some(if incident_days.contains(&day) {
+                97.5
+            } else {
+                100.0
+            }),
+        })
+        .collect()
+}
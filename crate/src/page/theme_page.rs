@@ -0,0 +1,112 @@
+use super::highlighters::{get_global_styles, get_global_theme, get_page_style};
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::theme::{GlobalTheme, PageStyle};
+
+pub struct ThemePage;
+
+impl Component for ThemePage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Theme"}</h1>
+
+                <h2>{"Global Theme"}</h2>
+                <p>{"Adds the colors, sizes and interaction colors of the framework as css custom properties in "}<code>{":root"}</code>
+                    {", so elements that are not yew_styles components can use them, e.g. "}<code>{"border-color: var(--ys-primary)"}</code>{"."}</p>
+                <ul>
+                    <li>{"Each palette defines "}<code>{"--ys-{palette}"}</code>{", "}<code>{"--ys-{palette}-text"}</code>{", "}<code>{"--ys-{palette}-light"}</code>
+                        {", "}<code>{"--ys-{palette}-focus"}</code>{", "}<code>{"--ys-{palette}-hover"}</code>{" and "}<code>{"--ys-{palette}-active"}</code>{"."}</li>
+                    <li>{"The design tokens are defined as "}<code>{"--ys-spacing-{name}"}</code>{", "}<code>{"--ys-radius-{name}"}</code>{", "}
+                        <code>{"--ys-shadow-{name}"}</code>{", "}<code>{"--ys-elevation-{level}"}</code>{", "}<code>{"--ys-font-size-{name}"}</code>
+                        {" and "}<code>{"--ys-font-weight-{name}"}</code>{"."}</li>
+                    <li>{"It also defines "}<code>{"--ys-border-radius"}</code>{", "}<code>{"--ys-font-family"}</code>{" and "}<code>{"--ys-error"}</code>{"."}</li>
+                </ul>
+
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=get_global_theme()
+                    language="rust"
+                />
+
+                <h3>{"Properties"}</h3>
+                <ul>
+                    <li><b>{"variables: "}</b>{"extra css variables or new values for the theme ones, as pairs of name and value. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"reduced_motion: "}</b>{"shorten the transitions and animations of the components, "}<code>{"None"}</code>
+                        {" follows the system preference. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"density: "}</b>{"spacing of the controls of the app. Options included in "}<code>{"Density"}</code>{". Default "}<code>{"Comfortable"}</code>{"."}</li>
+                    <li><b>{"ripple: "}</b>{"show a ripple where the buttons, the list items and the interactive cards are pressed. Default "}<code>{"false"}</code>{"."}</li>
+                </ul>
+
+                <h3>{"Functions"}</h3>
+                <ul>
+                    <li><b>{"use_theme: "}</b>{"active theme, for the styles of your own components, e.g. in their "}<code>{"YieldStyle"}</code>{" implementations."}</li>
+                    <li><b>{"set_theme_variables: "}</b>{"add the theme css variables in "}<code>{":root"}</code>{", the variables add new ones or replace the theme values."}</li>
+                    <li><b>{"get_theme_variables: "}</b>{"css variables of the theme as pairs of name and value."}</li>
+                    <li><b>{"set_density: "}</b>{"set the density of the whole app."}</li>
+                </ul>
+
+                <h3>{"Visual examples"}</h3>
+                <GlobalTheme />
+                <div style="border: 1px solid var(--ys-primary); border-radius: var(--ys-border-radius); padding: var(--ys-spacing-medium);">
+                    {"Custom element with the primary color, the border radius and the medium spacing of the theme"}
+                </div>
+
+                <h2>{"Global Styles"}</h2>
+                <p>{"Adds a small reset as stylist global styles, so the app starts from the same baseline in every browser: "}
+                    <code>{"border-box"}</code>{" sizing, no body margin, the font family and body size of the typography tokens, form controls with the font of the page and media which doesn't overflow its container. "}
+                    {"The styles are removed when the component is destroyed."}</p>
+
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=get_global_styles()
+                    language="rust"
+                />
+
+                <h2>{"Page Style"}</h2>
+                <p>{"Adds any css at the document level as stylist global styles, for page level styles written in Rust. "}
+                    {"The css supports the nesting of stylist and "}<code>{"${name}"}</code>{" is replaced by the value of the theme variable "}<code>{"--ys-{name}"}</code>
+                    {", which also works where "}<code>{"var()"}</code>{" doesn't, like media queries. The styles are updated when the css changes and removed when the component is destroyed."}</p>
+
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=get_page_style()
+                    language="rust"
+                />
+
+                <h3>{"Properties"}</h3>
+                <ul>
+                    <li><b>{"css: "}</b>{"css added to the document. Required."}</li>
+                </ul>
+
+                <h3>{"Visual examples"}</h3>
+                <PageStyle
+                    css=".theme-page-landing {
+                        padding: ${spacing-medium};
+                        border-left: 4px solid ${primary};
+                        h3 { color: ${primary}; }
+                    }"
+                />
+                <div class="theme-page-landing">
+                    <h3>{"Welcome"}</h3>
+                    <p>{"Section styled with the primary color and the medium spacing of the theme"}</p>
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use super::highlighters::{
+    get_error_toast, get_notification, get_progress_toast, get_toast, get_undo_toast,
+};
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::interval::{IntervalService, IntervalTask};
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::styles::{Palette, Style};
+use yew_styles::toast::{
+    start_progress, CloseReason, Notification, NotificationHost, NotificationService,
+    ProgressHandle, ProgressToastHost, Toast, ToastContainer, ToastPosition,
+};
+
+pub struct ToastPage {
+    link: ComponentLink<Self>,
+    toasts: Vec<u32>,
+    undo_toasts: Vec<u32>,
+    messages: u32,
+    next_id: u32,
+    upload: Option<ProgressHandle>,
+    upload_progress: f64,
+    upload_task: Option<IntervalTask>,
+}
+
+pub enum Msg {
+    AddToast,
+    CloseToast(u32, CloseReason),
+    AddUndoToast,
+    DeleteMessage,
+    CloseUndoToast(u32),
+    StartUpload,
+    UploadTick,
+    Notify,
+    ClearNotifications,
+}
+
+impl Component for ToastPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            toasts: vec![],
+            undo_toasts: vec![],
+            messages: 10,
+            next_id: 0,
+            upload: None,
+            upload_progress: 0.0,
+            upload_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::AddToast => {
+                self.toasts.push(self.next_id);
+                self.next_id += 1;
+            }
+            Msg::CloseToast(id, _) => {
+                self.toasts.retain(|toast_id| *toast_id != id);
+            }
+            Msg::AddUndoToast => {
+                self.undo_toasts.push(self.next_id);
+                self.next_id += 1;
+            }
+            Msg::DeleteMessage => {
+                self.messages = self.messages.saturating_sub(1);
+            }
+            Msg::CloseUndoToast(id) => {
+                self.undo_toasts.retain(|toast_id| *toast_id != id);
+            }
+            Msg::StartUpload => {
+                if self.upload.is_none() {
+                    self.upload = Some(start_progress("Uploading report.pdf"));
+                    self.upload_progress = 0.0;
+                    self.upload_task = Some(IntervalService::spawn(
+                        Duration::from_millis(500),
+                        self.link.callback(|_| Msg::UploadTick),
+                    ));
+                }
+            }
+            Msg::UploadTick => {
+                self.upload_progress += 0.1;
+
+                if self.upload_progress >= 1.0 {
+                    self.upload_task = None;
+
+                    if let Some(upload) = self.upload.take() {
+                        upload.succeed("report.pdf uploaded");
+                    }
+                } else if let Some(upload) = &self.upload {
+                    upload.set_progress(self.upload_progress);
+                }
+            }
+            Msg::Notify => {
+                NotificationService::push(Notification::success("Saved").title("Document"));
+            }
+            Msg::ClearNotifications => NotificationService::clear(),
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Toast Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"toast"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_toast()
+                    language="rust"
+                />
+
+                <h2>{"Toast container properties"}</h2>
+                <ul>
+                    <li><b>{"max_visible: "}</b>{"maximum number of toasts shown at the same time, the rest wait in the queue. Default "}<code>{"3"}</code>{"."}</li>
+                    <li><b>{"toast_position: "}</b>{"corner of the screen where the toasts are shown. Options included in "}<code>{"ToastPosition"}</code>{". Default "}<code>{"TopRight"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Toast properties"}</h2>
+                <ul>
+                    <li><b>{"duration: "}</b>{"milliseconds until the toast is closed, 0 keeps it open until it is dismissed. Default "}<code>{"5000"}</code>{"."}</li>
+                    <li><b>{"pause_on_hover: "}</b>{"pause the timer while the toast is hovered or focused. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"show_progress: "}</b>{"show a progress bar with the time remaining. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"closable: "}</b>{"show the close button. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"onclose_signal: "}</b>{"signal emitted with the "}<code>{"CloseReason"}</code>
+                        {" when the duration elapses or the toast is dismissed, the toast should be removed from the container."}</li>
+                    <li><b>{"action: "}</b>{"content of the action button, e.g. \"Undo\". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onaction_signal: "}</b>{"click event of the action button, it should cancel the pending operation. The toast is closed with "}
+                        <code>{"CloseReason::Action"}</code>{"."}</li>
+                    <li><b>{"onconfirm_signal: "}</b>{"signal emitted when the duration elapses without clicking the action button."}</li>
+                    <li><b>{"confirmation: "}</b>{"content shown when the duration elapses without clicking the action button. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"confirmation_duration: "}</b>{"milliseconds the confirmation is shown before closing the toast. Default "}<code>{"2000"}</code>{"."}</li>
+                    <li><b>{"toast_palette: "}</b>{"type toast palette. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"toast_style: "}</b>{"toast styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::AddToast)
+                    button_palette=Palette::Success
+                    button_style=Style::Light
+                >{"Save"}</Button>
+                <ToastContainer max_visible=2 toast_position=ToastPosition::BottomRight>
+                    {for self.toasts.iter().map(|id| {
+                        let id = *id;
+
+                        html! {
+                            <Toast
+                                key=id.to_string()
+                                toast_palette=Palette::Success
+                                onclose_signal=self.link.callback(move |reason| Msg::CloseToast(id, reason))
+                            >
+                                {format!("Document {} saved", id)}
+                            </Toast>
+                        }
+                    })}
+                </ToastContainer>
+
+                <h2>{"Undoable actions"}</h2>
+                <p>{"With "}<code>{"action"}</code>{" the toast shows a button to cancel a pending operation, which only runs when the duration elapses."}</p>
+                <Prism
+                    code=get_undo_toast()
+                    language="rust"
+                />
+                <p>{format!("Messages in the inbox: {}", self.messages)}</p>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::AddUndoToast)
+                    button_palette=Palette::Danger
+                    button_style=Style::Light
+                >{"Delete message"}</Button>
+                <ToastContainer toast_position=ToastPosition::BottomLeft>
+                    {for self.undo_toasts.iter().map(|id| {
+                        let id = *id;
+
+                        html! {
+                            <Toast
+                                key=id.to_string()
+                                action=html! {"Undo"}
+                                confirmation=html! {"Message deleted"}
+                                onconfirm_signal=self.link.callback(|_| Msg::DeleteMessage)
+                                onclose_signal=self.link.callback(move |_| Msg::CloseUndoToast(id))
+                            >
+                                {"Deleting message..."}
+                            </Toast>
+                        }
+                    })}
+                </ToastContainer>
+
+                <h2>{"Progress toasts"}</h2>
+                <p>{"For long-running operations, like uploads or batch jobs, "}<code>{"start_progress"}</code>
+                    {" shows a toast with the elapsed time and the progress in the "}<code>{"ProgressToastHost"}</code>
+                    {", from any part of the app. Only one host should be mounted, usually in the root component. "}
+                    {"The operation ends with "}<code>{"succeed"}</code>{" or "}<code>{"fail"}</code>{", dropping its "}<code>{"ProgressHandle"}</code>
+                    {" before ends it as failed."}</p>
+                <Prism
+                    code=get_progress_toast()
+                    language="rust"
+                />
+                <h3>{"Progress toast host"}</h3>
+                <ul>
+                    <li><b>{"max_visible: "}</b>{"maximum number of operations shown at the same time. Default "}<code>{"3"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"milliseconds until an ended operation is closed, 0 keeps it open until it is dismissed. Default "}<code>{"5000"}</code>{"."}</li>
+                    <li><b>{"toast_position: "}</b>{"corner of the screen where the operations are shown. Options included in "}<code>{"ToastPosition"}</code>{". Default "}<code>{"BottomRight"}</code>{"."}</li>
+                </ul>
+                <h3>{"Progress handle"}</h3>
+                <ul>
+                    <li><b>{"set_progress: "}</b>{"update the completed fraction, from "}<code>{"0.0"}</code>{" to "}<code>{"1.0"}</code>{"."}</li>
+                    <li><b>{"set_message: "}</b>{"replace the description of the operation."}</li>
+                    <li><b>{"succeed: "}</b>{"end the operation with the success palette and a final message."}</li>
+                    <li><b>{"fail: "}</b>{"end the operation with the danger palette and a final message."}</li>
+                </ul>
+                <h3>{"Progress toast"}</h3>
+                <p>{"It can also be used directly inside of "}<code>{"ToastContainer"}</code>{"."}</p>
+                <ul>
+                    <li><b>{"message: "}</b>{"description of the operation. Required."}</li>
+                    <li><b>{"progress: "}</b>{"completed fraction from "}<code>{"0.0"}</code>{" to "}<code>{"1.0"}</code>{", "}<code>{"None"}</code>
+                        {" shows an indeterminate bar. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"status: "}</b>{"state of the operation, the toast stays open while it is running. Options included in "}<code>{"ProgressStatus"}</code>{". Default "}<code>{"Running"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"milliseconds until the toast is closed after the operation ends, 0 keeps it open. Default "}<code>{"5000"}</code>{"."}</li>
+                    <li><b>{"onclose_signal: "}</b>{"signal emitted when the toast is closed after the operation ends or it is dismissed."}</li>
+                </ul>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::StartUpload)
+                    button_palette=Palette::Primary
+                    button_style=Style::Light
+                >{"Upload"}</Button>
+                <ProgressToastHost/>
+
+                <h2>{"Notifications"}</h2>
+                <p>{"The "}<code>{"NotificationHost"}</code>{" shows as toasts the notifications sent with "}<code>{"NotificationService::push"}</code>
+                    {", from any component or from code outside of the components, e.g. the response of a request. Only one host should be mounted, usually in the root component."}</p>
+                <Prism
+                    code=get_notification()
+                    language="rust"
+                />
+                <h3>{"Notification host"}</h3>
+                <ul>
+                    <li><b>{"max_visible: "}</b>{"maximum number of notifications shown at the same time. Default "}<code>{"3"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"milliseconds until a notification is closed, 0 keeps it open until it is dismissed. Default "}<code>{"5000"}</code>{"."}</li>
+                    <li><b>{"toast_position: "}</b>{"corner of the screen where the notifications are shown. Options included in "}<code>{"ToastPosition"}</code>{". Default "}<code>{"BottomRight"}</code>{"."}</li>
+                </ul>
+                <h3>{"Notification"}</h3>
+                <ul>
+                    <li><b>{"success, info, warning, error: "}</b>{"create a notification with the palette of its purpose."}</li>
+                    <li><b>{"title: "}</b>{"add a bold title before the message."}</li>
+                    <li><b>{"duration: "}</b>{"milliseconds until it is closed, 0 keeps it open until it is dismissed. Default the duration of the host."}</li>
+                </ul>
+                <h3>{"Notification service"}</h3>
+                <ul>
+                    <li><b>{"push: "}</b>{"show the notification and return its id, if the host is not mounted yet it is shown when it is mounted."}</li>
+                    <li><b>{"dismiss: "}</b>{"close the notification with the id returned by "}<code>{"push"}</code>{"."}</li>
+                    <li><b>{"clear: "}</b>{"close all the notifications."}</li>
+                </ul>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Notify)
+                    button_palette=Palette::Success
+                    button_style=Style::Light
+                >{"Notify"}</Button>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::ClearNotifications)
+                    button_palette=Palette::Standard
+                    button_style=Style::Outline
+                >{"Clear"}</Button>
+                <NotificationHost toast_position=ToastPosition::TopLeft/>
+
+                <h2>{"Error toasts"}</h2>
+                <p>{"The "}<code>{"ErrorToastHost"}</code>{" shows as danger toasts the errors reported from any part of the app and, after calling "}
+                    <code>{"install_panic_hook"}</code>{", the panics. Only one host should be mounted, usually in the root component. It requires the "}
+                    <code>{"error_bridge"}</code>{" feature, which is not included in "}<code>{"full"}</code>{"."}</p>
+                <Prism
+                    code=get_error_toast()
+                    language="rust"
+                />
+                <ul>
+                    <li><b>{"max_visible: "}</b>{"maximum number of errors shown at the same time. Default "}<code>{"3"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"milliseconds until an error is closed, 0 keeps it open until it is dismissed. Default "}<code>{"0"}</code>{"."}</li>
+                    <li><b>{"toast_position: "}</b>{"corner of the screen where the errors are shown. Options included in "}<code>{"ToastPosition"}</code>{". Default "}<code>{"TopRight"}</code>{"."}</li>
+                </ul>
+                <ul>
+                    <li><b>{"report_error: "}</b>{"show an "}<code>{"anyhow::Error"}</code>{" in the host, if it is not mounted yet the error is shown when it is mounted."}</li>
+                    <li><b>{"report_result: "}</b>{"show the error of a failed result and return the value if it succeeded."}</li>
+                    <li><b>{"install_panic_hook: "}</b>{"show the panics of the app as error toasts, the previous panic hook is still called."}</li>
+                </ul>
+            </>
+        }
+    }
+}
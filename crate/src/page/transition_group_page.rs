@@ -0,0 +1,93 @@
+use super::highlighters::get_transition_group;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::styles::tokens::Duration;
+use yew_styles::styles::{Palette, Style};
+use yew_styles::transition_group::{Transition, TransitionGroup};
+
+pub struct TransitionGroupPage {
+    link: ComponentLink<Self>,
+    tasks: Vec<String>,
+    next_task: u32,
+}
+
+pub enum Msg {
+    Add,
+    Remove(usize),
+}
+
+impl Component for TransitionGroupPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            tasks: vec!["Task 1".to_string(), "Task 2".to_string()],
+            next_task: 3,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Add => {
+                self.tasks.push(format!("Task {}", self.next_task));
+                self.next_task += 1;
+            }
+            Msg::Remove(index) => {
+                self.tasks.remove(index);
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Transition Group Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"transition_group"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_transition_group()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"transition: "}</b>{"animation of the items. Options included in "}<code>{"Transition"}</code>{". Default "}<code>{"Fade"}</code>{"."}</li>
+                    <li><b>{"duration: "}</b>{"duration of the animation. Options included in "}<code>{"Duration"}</code>{". Default "}<code>{"Normal"}</code>{"."}</li>
+                    <li><b>{"easing: "}</b>{"easing of the animation. Options included in "}<code>{"Easing"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"appear: "}</b>{"animate the items shown when the group is mounted. Default "}<code>{"false"}</code>{"."}</li>
+                </ul>
+
+                <p>{"The children are matched by their "}<code>{"key"}</code>{": a new key enters with the transition and a removed one stays in its place while the transition runs backwards. "}
+                    {"It doesn't add a container, so the items are laid out by the parent element. When the motion is reduced the items are added and removed at once."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <Button
+                    onclick_signal=self.link.callback(|_| Msg::Add)
+                    button_palette=Palette::Primary
+                    button_style=Style::Light
+                >{"Add task"}</Button>
+                <p>{"Click a task to remove it"}</p>
+                <div style="display: flex; flex-direction: column">
+                    <TransitionGroup transition=Transition::Collapse duration=Duration::Slow>
+                        {for self.tasks.iter().enumerate().map(|(index, task)| html! {
+                            <div key=task.clone() onclick=self.link.callback(move |_| Msg::Remove(index))>
+                                {task}
+                            </div>
+                        })}
+                    </TransitionGroup>
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use super::highlighters::get_truncate_middle;
+use stylist::css;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::truncate_middle::TruncateMiddle;
+
+pub struct TruncateMiddlePage;
+
+impl Component for TruncateMiddlePage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Truncate Middle Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"truncate_middle"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_truncate_middle()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"text: "}</b>{"full text. Required."}</li>
+                    <li><b>{"end_chars: "}</b>{"number of characters always kept at the end, e.g. for the file extensions. "}<code>{"None"}</code>
+                        {" keeps the same number at the start and the end. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"tooltip: "}</b>{"show the full text in the native tooltip. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"copyable: "}</b>{"add a button to copy the full text. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"oncopy_signal: "}</b>{"emitted with the full text when it is copied."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the cut is measured again when the window is resized, resize it to see how the text changes."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <TruncateMiddle
+                    text="/home/user/projects/yew_styles/src/components/truncate_middle.rs"
+                    end_chars=Some(18)
+                    styles=css!("max-width: 300px;")
+                />
+                <TruncateMiddle
+                    text="9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                    copyable=true
+                    styles=css!("max-width: 300px;")
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::highlighters::{get_line_clamp, get_typography};
+use lipsum::lipsum;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::styles::tokens::{FontSize, FontWeight};
+use yew_styles::typography::{Heading, HeadingLevel, Label, Text};
+
+pub struct TypographyPage;
+
+impl Component for TypographyPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Typography Components"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"typography"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_typography()
+                    language="rust"
+                />
+
+                <h2>{"Common properties"}</h2>
+                <ul>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <h2>{"Heading"}</h2>
+                <ul>
+                    <li><b>{"heading_level: "}</b>{"heading element from h1 to h6. Options included in "}<code>{"HeadingLevel"}</code>{". Default "}<code>{"H2"}</code>{"."}</li>
+                    <li><b>{"heading_size: "}</b>{"font size of the heading. Options included in "}<code>{"FontSize"}</code>
+                        {". Default the size of the level, from "}<code>{"Headline"}</code>{" in h1 to "}<code>{"Caption"}</code>{" in h6."}</li>
+                    <li><b>{"heading_weight: "}</b>{"font weight of the heading. Options included in "}<code>{"FontWeight"}</code>{". Default "}<code>{"Bold"}</code>{"."}</li>
+                </ul>
+
+                <Heading heading_level=HeadingLevel::H1>{"Heading h1"}</Heading>
+                <Heading heading_level=HeadingLevel::H2>{"Heading h2"}</Heading>
+                <Heading heading_level=HeadingLevel::H3>{"Heading h3"}</Heading>
+                <Heading heading_level=HeadingLevel::H4>{"Heading h4"}</Heading>
+                <Heading heading_level=HeadingLevel::H5>{"Heading h5"}</Heading>
+                <Heading heading_level=HeadingLevel::H6>{"Heading h6"}</Heading>
+                <Heading
+                    heading_level=HeadingLevel::H2
+                    heading_size=FontSize::Medium
+                    heading_weight=FontWeight::Regular
+                >{"Heading h2 with medium size and regular weight"}</Heading>
+
+                <h2>{"Text"}</h2>
+                <p>{"It is different from the "}<code>{"text::Text"}</code>{" component, which shows alerts and tags."}</p>
+                <ul>
+                    <li><b>{"inline: "}</b>{"render a span instead of a paragraph. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"muted: "}</b>{"secondary text with less contrast. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"small: "}</b>{"small font size. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"bold: "}</b>{"bold font weight. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"truncate: "}</b>{"cut the text with an ellipsis when it doesn't fit in one line. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"line_clamp: "}</b>{"maximum number of lines before the text is cut, adding a show more button when it overflows. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"ontoggle_signal: "}</b>{"emitted with "}<code>{"true"}</code>{" when the clamped text is expanded and "}<code>{"false"}</code>{" when it is clamped again."}</li>
+                </ul>
+
+                <Text>{"Yew Styles is a framework of styles for yew"}</Text>
+                <Text inline=true muted=true small=true>{"Last update 2 days ago"}</Text>
+                <Text bold=true>{"Bold text"}</Text>
+                <Text truncate=true>{"A very long text that does not fit in one line is cut with an ellipsis at the end of the line when the screen is narrow"}</Text>
+                <h3>{"Line clamp"}</h3>
+                <p>{"The show more button is only added when the content doesn't fit, which is measured again when the window is resized."}</p>
+                <Prism
+                    code=get_line_clamp()
+                    language="rust"
+                />
+                <Text line_clamp=Some(3)>{lipsum(120)}</Text>
+
+                <h2>{"Label"}</h2>
+                <ul>
+                    <li><b>{"label_for: "}</b>{"id of the control described by the label."}</li>
+                    <li><b>{"required: "}</b>{"show a mark to indicate that the control is required. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"muted: "}</b>{"secondary label with less contrast. Default "}<code>{"false"}</code>{"."}</li>
+                </ul>
+
+                <Label label_for="typography-email" required=true>{"Email"}</Label>
+                <input id="typography-email" type="email"/>
+            </>
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use super::highlighters::get_video_thumb;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::video_thumb::VideoThumb;
+
+pub struct VideoThumbPage;
+
+impl Component for VideoThumbPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Video Thumb Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"video_thumb"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_video_thumb()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"video_src: "}</b>{"url path of the video. Required."}</li>
+                    <li><b>{"poster_src: "}</b>{"url path of the poster image. Required."}</li>
+                    <li><b>{"title: "}</b>{"title of the video, used in the label of the play button. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"autoplay: "}</b>{"start the video when the modal is opened. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"close_on_end: "}</b>{"close the modal when the video ends. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"controls: "}</b>{"show the controls of the browser in the video. Default "}<code>{"true"}</code>{"."}</li>
+                    <li><b>{"onplay_signal: "}</b>{"emitted when the video starts playing."}</li>
+                    <li><b>{"modal_size: "}</b>{"size of the modal. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Big"}</code>{"."}</li>
+                    <li><b>{"modal_palette: "}</b>{"type modal background style. Options included in "}<code>{"Palette"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                    <li><b>{"margin: "}</b>{"margin from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"padding from the spacing scale. Default "}<code>{"Spaces::default()"}</code>{"."}</li>
+                </ul>
+
+                <p><b>{"Note: "}</b>{"the modal is closed with "}<code>{"Escape"}</code>{" or clicking outside of the video."}</p>
+
+                <h2>{"Visual examples"}</h2>
+                <VideoThumb
+                    video_src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/flower.mp4"
+                    poster_src="/slide_1.jpg"
+                    title="Flower"
+                    close_on_end=true
+                />
+            </>
+        }
+    }
+}
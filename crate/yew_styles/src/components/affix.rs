@@ -0,0 +1,247 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Affix component
+///
+/// Pins its children to the top of the window once the page scrolls past them, e.g. the table of contents
+/// of a documentation page or the summary of a checkout. While it is pinned it keeps the space of the
+/// children in the page, so the content below doesn't jump
+///
+/// ## Features required
+///
+/// affix
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::affix::Affix;
+/// use yew_styles::scrollspy::{Scrollspy, ScrollspySection};
+///
+/// pub struct Sidebar;
+///
+/// impl Component for Sidebar {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Affix offset_top=64>
+///                 <Scrollspy
+///                     offset=64
+///                     sections=vec![
+///                         ScrollspySection::new("install", "Installation"),
+///                         ScrollspySection::new("usage", "Usage"),
+///                     ]
+///                 />
+///             </Affix>
+///         }
+///     }
+/// }
+/// ```
+pub struct Affix {
+    link: ComponentLink<Self>,
+    props: Props,
+    affixed: bool,
+    /// Width and height of the children before they were pinned
+    size: (f64, f64),
+    listener: Option<Closure<dyn Fn(Event)>>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Pixels from the top of the window where the children are pinned, e.g. the height of a fixed navbar. Default `0`
+    #[prop_or(0)]
+    pub offset_top: i32,
+    /// Emitted with `true` when the children are pinned and `false` when they return to the page
+    #[prop_or(Callback::noop())]
+    pub onaffix_signal: Callback<bool>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Scrolled,
+}
+
+const EVENTS: [&str; 2] = ["scroll", "resize"];
+
+impl Component for Affix {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            affixed: false,
+            size: (0.0, 0.0),
+            listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scrolled => {
+                let rect = match self.props.code_ref.cast::<Element>() {
+                    Some(affix) => affix.get_bounding_client_rect(),
+                    None => return false,
+                };
+                let affixed = rect.top() <= self.props.offset_top as f64;
+                // The placeholder keeps the height of the children while they are pinned
+                let size = (rect.width(), rect.height());
+
+                if affixed == self.affixed
+                    && (!affixed || (size.0 - self.size.0).abs() < f64::EPSILON)
+                {
+                    return false;
+                }
+
+                if affixed != self.affixed {
+                    self.props.onaffix_signal.emit(affixed);
+                }
+
+                self.affixed = affixed;
+                self.size = size;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.link.send_message(Msg::Scrolled);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.listen();
+            self.link.send_message(Msg::Scrolled);
+        }
+    }
+
+    fn destroy(&mut self) {
+        if let Some(listener) = self.listener.take() {
+            for event in EVENTS.iter() {
+                utils::window()
+                    .remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                    .ok();
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("affix")
+                    .state("affixed", self.affixed)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                style=if self.affixed { Some(format!("height: {}px;", self.size.1)) } else { None }
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <div
+                    class="affix-content"
+                    style=if self.affixed { Some(get_affix_style(self.props.offset_top, self.size.0)) } else { None }
+                >
+                    {self.props.children.clone()}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Affix {
+    fn listen(&mut self) {
+        let link = self.link.clone();
+        let listener = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Scrolled)) as Box<dyn Fn(Event)>
+        );
+
+        for event in EVENTS.iter() {
+            utils::window()
+                .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                .ok();
+        }
+        self.listener = Some(listener);
+    }
+}
+
+/// Inline style of the pinned children: fixed at `offset_top` with the width they had in the page
+fn get_affix_style(offset_top: i32, width: f64) -> String {
+    format!("top: {}px; width: {}px;", offset_top, width)
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_pin_with_the_width_of_the_page() {
+    assert_eq!(get_affix_style(64, 240.5), "top: 64px; width: 240.5px;");
+}
+
+#[wasm_bindgen_test]
+fn should_not_pin_before_the_offset() {
+    let props = Props::builder()
+        .id("affix-test".to_string())
+        .offset_top(-100000)
+        .children(Children::new(vec![html! {"Contents"}]))
+        .build();
+
+    let affix: App<Affix> = App::new();
+    affix.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let affix_element = utils::document().get_element_by_id("affix-test").unwrap();
+
+    assert!(!affix_element.class_list().contains("affixed"));
+    assert_eq!(affix_element.get_attribute("style"), None);
+}
@@ -0,0 +1,176 @@
+use std::cell::RefCell;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::{utils, App};
+
+const POLITE_REGION_ID: &str = "yew-styles-announcer-polite";
+const ASSERTIVE_REGION_ID: &str = "yew-styles-announcer-assertive";
+
+/// Hidden for the eyes but not for the screen readers
+const VISUALLY_HIDDEN: &str =
+    "position: absolute; width: 1px; height: 1px; margin: -1px; padding: 0; \
+     overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+thread_local! {
+    static ANNOUNCE_TASKS: RefCell<[Option<TimeoutTask>; 2]> = RefCell::new([None, None]);
+}
+
+/// # Announcer component
+///
+/// Live regions which read to the screen readers the messages sent with `announce`,
+/// like the result of a search, a saved form or a finished loading.
+/// Mount it once, usually in the root component. If it is not mounted,
+/// `announce` adds the regions at the end of the body
+///
+/// ## Features required
+///
+/// announcer
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::announcer::{announce, Announcer, Politeness};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Save,
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Save => announce("Document saved", Politeness::Polite),
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <button onclick=self.link.callback(|_| Msg::Save)>{"Save"}</button>
+///                 <Announcer/>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Announcer;
+
+/// Urgency of the announcement
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Politeness {
+    /// Read when the screen reader is idle
+    Polite,
+    /// Interrupt the screen reader, only for errors and urgent messages
+    Assertive,
+}
+
+impl Component for Announcer {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <div
+                    id=POLITE_REGION_ID
+                    class="announcer-region"
+                    style=VISUALLY_HIDDEN
+                    aria-live="polite"
+                    aria-atomic="true"
+                ></div>
+                <div
+                    id=ASSERTIVE_REGION_ID
+                    class="announcer-region"
+                    style=VISUALLY_HIDDEN
+                    aria-live="assertive"
+                    aria-atomic="true"
+                ></div>
+            </>
+        }
+    }
+}
+
+/// Read the message with the screen readers. The region is cleared first and
+/// the message is added after a moment, so the same message is read again if it is repeated
+pub fn announce(message: &str, politeness: Politeness) {
+    let region = get_region(politeness);
+    let message = message.to_string();
+
+    region.set_text_content(None);
+
+    let task = TimeoutService::spawn(
+        Duration::from_millis(100),
+        Callback::from(move |_| region.set_text_content(Some(&message))),
+    );
+
+    ANNOUNCE_TASKS.with(|tasks| tasks.borrow_mut()[politeness as usize] = Some(task));
+}
+
+fn get_region(politeness: Politeness) -> Element {
+    let (id, live) = match politeness {
+        Politeness::Polite => (POLITE_REGION_ID, "polite"),
+        Politeness::Assertive => (ASSERTIVE_REGION_ID, "assertive"),
+    };
+    let document = utils::document();
+
+    match document.get_element_by_id(id) {
+        Some(region) => region,
+        None => {
+            let region = document.create_element("div").unwrap();
+            region.set_id(id);
+            region.set_class_name("announcer-region");
+            region.set_attribute("style", VISUALLY_HIDDEN).unwrap();
+            region.set_attribute("aria-live", live).unwrap();
+            region.set_attribute("aria-atomic", "true").unwrap();
+            document.body().unwrap().append_child(&region).unwrap();
+            region
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_the_live_regions() {
+    let announcer: App<Announcer> = App::new();
+    announcer.mount(utils::document().get_element_by_id("output").unwrap());
+
+    let region = utils::document()
+        .get_element_by_id(ASSERTIVE_REGION_ID)
+        .unwrap();
+
+    assert_eq!(region.get_attribute("aria-live").unwrap(), "assertive");
+    assert_eq!(get_region(Politeness::Assertive), region);
+}
@@ -0,0 +1,541 @@
+use crate::calendar::CalendarDate;
+use crate::messages::get_messages;
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use crate::utils::{get_random_string, is_browser};
+use js_sys::{Array, Intl, Object, Reflect};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Availability grid component
+///
+/// Grid of days by time slots where the user paints the slots when they are available,
+/// by click-dragging over the cells or with the arrow keys and space. The painted slots are
+/// emitted when the drag ends. With `responses` the grid is read only and shows how many
+/// respondents are available in each slot, e.g. to find the best time for a meeting
+///
+/// ## Features required
+///
+/// availability_grid
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::availability_grid::{AvailabilityGrid, Slot};
+/// use yew_styles::calendar::CalendarDate;
+///
+/// pub struct Schedule {
+///     link: ComponentLink<Self>,
+///     available: Vec<Slot>,
+/// }
+///
+/// pub enum Msg {
+///     Change(Vec<Slot>),
+/// }
+///
+/// impl Component for Schedule {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             available: vec![],
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Change(available) => self.available = available,
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <AvailabilityGrid
+///                 start_date=CalendarDate::new(2021, 7, 12)
+///                 days=5
+///                 start_minutes=8 * 60
+///                 end_minutes=17 * 60
+///                 selected=self.available.clone()
+///                 onchange_signal=self.link.callback(Msg::Change)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct AvailabilityGrid {
+    link: ComponentLink<Self>,
+    props: Props,
+    selected: Vec<Slot>,
+    painting: Option<bool>,
+    active: (usize, usize),
+    focus_active: bool,
+    local_time_zone: Option<String>,
+    grid_id: String,
+}
+
+/// Time slot of a day, `minutes` is the start of the slot counted from midnight
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Slot {
+    pub date: CalendarDate,
+    pub minutes: u32,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// First day of the grid. Required
+    pub start_date: CalendarDate,
+    /// Number of days from the start date. Default `7`
+    #[prop_or(7)]
+    pub days: u32,
+    /// Start of the first slot in minutes from midnight. Default `540` (9:00)
+    #[prop_or(540)]
+    pub start_minutes: u32,
+    /// End of the last slot in minutes from midnight. Default `1080` (18:00)
+    #[prop_or(1080)]
+    pub end_minutes: u32,
+    /// Duration of each slot in minutes. Default `30`
+    #[prop_or(30)]
+    pub slot_minutes: u32,
+    /// Slots painted as available
+    #[prop_or_default]
+    pub selected: Vec<Slot>,
+    /// Available slots of each respondent, when it is not empty the grid is read only
+    /// and shows how many respondents are available in each slot
+    #[prop_or_default]
+    pub responses: Vec<Vec<Slot>>,
+    /// Time zone shown over the grid, `None` shows the time zone of the browser. Default `None`
+    #[prop_or_default]
+    pub time_zone: Option<String>,
+    /// Type availability grid purpose style. Default `Palette::Success`
+    #[prop_or(Palette::Success)]
+    pub grid_palette: Palette,
+    /// Emitted with all the available slots when the user ends painting
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<Vec<Slot>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    PaintStart(PointerEvent, usize, usize),
+    PaintOver(usize, usize),
+    PaintEnd,
+    KeyDown(KeyboardEvent),
+    LocalTimeZone(String),
+}
+
+impl Component for AvailabilityGrid {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            selected: props.selected.clone(),
+            props,
+            painting: None,
+            active: (0, 0),
+            focus_active: false,
+            local_time_zone: None,
+            grid_id: format!("availability-grid-{}", get_random_string(10)),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::LocalTimeZone(time_zone) => {
+                self.local_time_zone = Some(time_zone);
+            }
+            _ if self.is_read_only() => return false,
+            Msg::PaintStart(pointer_event, day, row) => {
+                if pointer_event.button() != 0 {
+                    return false;
+                }
+
+                // the touch pointers are captured by the first cell, release them to get the events of the next ones
+                if let Some(target) = pointer_event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                {
+                    target
+                        .release_pointer_capture(pointer_event.pointer_id())
+                        .ok();
+                }
+
+                pointer_event.prevent_default();
+                let slot = self.get_slot(day, row);
+                let available = !self.selected.contains(&slot);
+
+                self.painting = Some(available);
+                self.active = (day, row);
+                set_slot(&mut self.selected, slot, available);
+            }
+            Msg::PaintOver(day, row) => match self.painting {
+                Some(available) => {
+                    let slot = self.get_slot(day, row);
+                    set_slot(&mut self.selected, slot, available);
+                }
+                None => return false,
+            },
+            Msg::PaintEnd => {
+                if self.painting.take().is_none() {
+                    return false;
+                }
+
+                self.emit_change();
+            }
+            Msg::KeyDown(keyboard_event) => {
+                let (day, row) = self.active;
+                let days = self.props.days as usize;
+                let rows = self.get_times().len();
+
+                self.active = match keyboard_event.key().as_str() {
+                    "ArrowLeft" if day > 0 => (day - 1, row),
+                    "ArrowRight" if day + 1 < days => (day + 1, row),
+                    "ArrowUp" if row > 0 => (day, row - 1),
+                    "ArrowDown" if row + 1 < rows => (day, row + 1),
+                    "Home" => (0, row),
+                    "End" => (days.saturating_sub(1), row),
+                    " " | "Enter" => {
+                        keyboard_event.prevent_default();
+                        let slot = self.get_slot(day, row);
+                        let available = !self.selected.contains(&slot);
+
+                        set_slot(&mut self.selected, slot, available);
+                        self.emit_change();
+                        return true;
+                    }
+                    _ => return false,
+                };
+
+                keyboard_event.prevent_default();
+                self.focus_active = true;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.selected != props.selected && self.painting.is_none() {
+                self.selected = props.selected.clone();
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render && self.props.time_zone.is_none() && is_browser() {
+            if let Some(time_zone) = get_local_time_zone() {
+                self.link.send_message(Msg::LocalTimeZone(time_zone));
+            }
+        }
+
+        if self.focus_active {
+            self.focus_active = false;
+
+            if let Some(cell) = utils::document()
+                .get_element_by_id(&self.get_cell_id(self.active.0, self.active.1))
+                .and_then(|cell| cell.dyn_into::<HtmlElement>().ok())
+            {
+                cell.focus().ok();
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+        let time_zone = self
+            .props
+            .time_zone
+            .clone()
+            .or_else(|| self.local_time_zone.clone());
+
+        html! {
+            <div
+                class=ComponentClasses::new("availability-grid")
+                    .semantic(get_palette(self.props.grid_palette.clone()))
+                    .state("read-only", self.is_read_only())
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {if let Some(time_zone) = time_zone {
+                    html! {
+                        <div class="availability-grid-time-zone">
+                            {format!("{}: {}", messages.time_zone, time_zone)}
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+                <div
+                    class="availability-grid-cells"
+                    role="grid"
+                    aria-readonly=self.is_read_only().to_string()
+                    style=format!("grid-template-columns: auto repeat({}, 1fr)", self.props.days)
+                    onpointerup=self.link.callback(|_| Msg::PaintEnd)
+                    onpointerleave=self.link.callback(|_| Msg::PaintEnd)
+                    onkeydown=self.link.callback(Msg::KeyDown)
+                >
+                    <div class="availability-grid-row" role="row">
+                        <span class="availability-grid-corner"></span>
+                        {for (0..self.props.days).map(|day| {
+                            html! {
+                                <span class="availability-grid-day" role="columnheader">
+                                    {self.get_day_label(day as usize)}
+                                </span>
+                            }
+                        })}
+                    </div>
+                    {for self.get_times().into_iter().enumerate().map(|(row, minutes)| self.get_row(row, minutes))}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl AvailabilityGrid {
+    fn is_read_only(&self) -> bool {
+        !self.props.responses.is_empty()
+    }
+
+    fn get_times(&self) -> Vec<u32> {
+        get_times(
+            self.props.start_minutes,
+            self.props.end_minutes,
+            self.props.slot_minutes,
+        )
+    }
+
+    fn get_slot(&self, day: usize, row: usize) -> Slot {
+        Slot {
+            date: self.props.start_date.add_days(day as i64),
+            minutes: self.props.start_minutes + row as u32 * self.props.slot_minutes,
+        }
+    }
+
+    fn get_cell_id(&self, day: usize, row: usize) -> String {
+        format!("{}-{}-{}", self.grid_id, day, row)
+    }
+
+    fn get_day_label(&self, day: usize) -> String {
+        let messages = get_messages();
+        let date = self.props.start_date.add_days(day as i64);
+
+        format!(
+            "{} {}",
+            messages.week_days[date.weekday() as usize],
+            date.day
+        )
+    }
+
+    fn emit_change(&mut self) {
+        self.selected
+            .sort_by_key(|slot| (slot.date.year, slot.date.month, slot.date.day, slot.minutes));
+        self.props.onchange_signal.emit(self.selected.clone());
+    }
+
+    fn get_row(&self, row: usize, minutes: u32) -> Html {
+        html! {
+            <div class="availability-grid-row" role="row">
+                <span class="availability-grid-time" role="rowheader">
+                    {if minutes % 60 == 0 { format_minutes(minutes) } else { String::new() }}
+                </span>
+                {for (0..self.props.days as usize).map(|day| self.get_cell(day, row, minutes))}
+            </div>
+        }
+    }
+
+    fn get_cell(&self, day: usize, row: usize, minutes: u32) -> Html {
+        let slot = self.get_slot(day, row);
+        let label = format!("{} {}", self.get_day_label(day), format_minutes(minutes));
+
+        if self.is_read_only() {
+            let count = count_available(&self.props.responses, &slot);
+            let total = self.props.responses.len();
+
+            return html! {
+                <span
+                    class="availability-grid-cell"
+                    role="gridcell"
+                    title=format!("{}/{}", count, total)
+                    aria-label=format!("{} {}/{}", label, count, total)
+                >
+                    <span
+                        class=classes!("availability-grid-fill", get_palette(self.props.grid_palette.clone()))
+                        style=format!("opacity: {:.2}", count as f64 / total as f64)
+                    ></span>
+                </span>
+            };
+        }
+
+        let available = self.selected.contains(&slot);
+
+        html! {
+            <span
+                class=classes!("availability-grid-cell", if available { Some("available") } else { None })
+                id=self.get_cell_id(day, row)
+                role="gridcell"
+                tabindex=if self.active == (day, row) { "0" } else { "-1" }
+                aria-selected=available.to_string()
+                aria-label=label
+                onpointerdown=self.link.callback(move |e| Msg::PaintStart(e, day, row))
+                onpointerenter=self.link.callback(move |_| Msg::PaintOver(day, row))
+            >
+                <span class=classes!("availability-grid-fill", get_palette(self.props.grid_palette.clone()))></span>
+            </span>
+        }
+    }
+}
+
+fn get_times(start_minutes: u32, end_minutes: u32, slot_minutes: u32) -> Vec<u32> {
+    if slot_minutes == 0 {
+        return vec![];
+    }
+
+    (start_minutes..end_minutes)
+        .step_by(slot_minutes as usize)
+        .collect()
+}
+
+fn format_minutes(minutes: u32) -> String {
+    format!("{:02}:{:02}", minutes / 60 % 24, minutes % 60)
+}
+
+fn set_slot(selected: &mut Vec<Slot>, slot: Slot, available: bool) {
+    let position = selected.iter().position(|selected| *selected == slot);
+
+    match (position, available) {
+        (None, true) => selected.push(slot),
+        (Some(index), false) => {
+            selected.remove(index);
+        }
+        _ => {}
+    }
+}
+
+/// Number of respondents available in the slot
+pub fn count_available(responses: &[Vec<Slot>], slot: &Slot) -> usize {
+    responses
+        .iter()
+        .filter(|response| response.contains(slot))
+        .count()
+}
+
+fn get_local_time_zone() -> Option<String> {
+    let options = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+
+    Reflect::get(&options, &"timeZone".into())
+        .ok()
+        .and_then(|time_zone| time_zone.as_string())
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_a_cell_for_each_slot() {
+    let props = Props::builder()
+        .days(5)
+        .end_minutes(720)
+        .id("availability-grid-test".to_string())
+        .selected(vec![Slot {
+            date: CalendarDate::new(2021, 7, 13),
+            minutes: 600,
+        }])
+        .start_date(CalendarDate::new(2021, 7, 12))
+        .time_zone(Some("Europe/Madrid".to_string()))
+        .build();
+
+    let availability_grid: App<AvailabilityGrid> = App::new();
+    availability_grid.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let availability_grid_element = utils::document()
+        .get_element_by_id("availability-grid-test")
+        .unwrap();
+
+    assert_eq!(
+        availability_grid_element
+            .get_elements_by_class_name("availability-grid-cell")
+            .length(),
+        30
+    );
+    assert_eq!(
+        availability_grid_element
+            .get_elements_by_class_name("available")
+            .length(),
+        1
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_paint_and_count_the_slots() {
+    let monday = Slot {
+        date: CalendarDate::new(2021, 7, 12),
+        minutes: 540,
+    };
+    let tuesday = Slot {
+        date: CalendarDate::new(2021, 7, 13),
+        minutes: 540,
+    };
+    let mut selected = vec![];
+
+    set_slot(&mut selected, monday.clone(), true);
+    set_slot(&mut selected, monday.clone(), true);
+    set_slot(&mut selected, tuesday.clone(), true);
+    set_slot(&mut selected, tuesday.clone(), false);
+
+    assert_eq!(selected, vec![monday.clone()]);
+    assert_eq!(
+        count_available(
+            &[vec![monday.clone()], vec![monday.clone(), tuesday.clone()]],
+            &monday
+        ),
+        2
+    );
+    assert_eq!(get_times(540, 660, 30), vec![540, 570, 600, 630]);
+    assert_eq!(format_minutes(570), "09:30");
+}
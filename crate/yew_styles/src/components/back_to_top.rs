@@ -0,0 +1,271 @@
+use crate::icon::{Icon, IconType};
+use crate::messages::get_messages;
+use crate::styles::{animations::prefers_reduced_motion, tokens::Spaces, ComponentClasses};
+use crate::utils::is_browser;
+use stylist::{css, StyleSource};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::{Element, EventTarget, ScrollBehavior, ScrollToOptions};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Back To Top component
+///
+/// Floating button in the bottom right corner which appears after scrolling past the `threshold` and
+/// scrolls smoothly back to the top when it is clicked, at once when the motion is reduced.
+/// It follows the scroll of the page or of the scrollable `target`. Without children it shows an arrow
+///
+/// ## Features required
+///
+/// back_to_top
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::back_to_top::BackToTop;
+///
+/// pub struct Article;
+///
+/// impl Component for Article {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <article>{"A long article"}</article>
+///                 <BackToTop threshold=600 />
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct BackToTop {
+    link: ComponentLink<Self>,
+    props: Props,
+    visible: bool,
+    listener: Option<(EventTarget, Closure<dyn Fn(Event)>)>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Pixels scrolled before the button appears. Default `400`
+    #[prop_or(400)]
+    pub threshold: i32,
+    /// Scrollable element which is scrolled to the top, the page when it isn't set. Default `NodeRef::default()`
+    #[prop_or_default]
+    pub target: NodeRef,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    /// Content of the button. Default an arrow up
+    #[prop_or_default]
+    pub children: Children,
+}
+
+pub enum Msg {
+    Scrolled,
+    Clicked,
+}
+
+impl Component for BackToTop {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            visible: false,
+            listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scrolled => {
+                let visible = self.get_scroll_top() > self.props.threshold as f64;
+
+                if visible == self.visible {
+                    return false;
+                }
+
+                self.visible = visible;
+            }
+            Msg::Clicked => {
+                let mut options = ScrollToOptions::new();
+                options.top(0.0).behavior(if prefers_reduced_motion() {
+                    ScrollBehavior::Auto
+                } else {
+                    ScrollBehavior::Smooth
+                });
+
+                match self.props.target.cast::<Element>() {
+                    Some(target) => target.scroll_to_with_scroll_to_options(&options),
+                    None => utils::window().scroll_to_with_scroll_to_options(&options),
+                };
+
+                return false;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let target_changed = self.props.target != props.target;
+            self.props = props;
+
+            if target_changed {
+                self.stop_listening();
+                self.listen();
+            }
+
+            self.link.send_message(Msg::Scrolled);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.listen();
+            self.link.send_message(Msg::Scrolled);
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.stop_listening();
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+
+        html! {
+            <button
+                type="button"
+                class=ComponentClasses::new("back-to-top")
+                    .state("visible", self.visible)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                aria-label=messages.back_to_top
+                aria-hidden=if self.visible { None } else { Some("true") }
+                tabindex=if self.visible { None } else { Some("-1") }
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onclick=self.link.callback(|_| Msg::Clicked)
+            >
+                {if self.props.children.is_empty() {
+                    html! {
+                        <Icon icon_type=IconType::ChevronUp />
+                    }
+                } else {
+                    html! {<>{for self.props.children.iter()}</>}
+                }}
+            </button>
+        }
+    }
+}
+
+impl BackToTop {
+    fn listen(&mut self) {
+        if self.listener.is_some() || !is_browser() {
+            return;
+        }
+
+        let target: EventTarget = match self.props.target.cast::<Element>() {
+            Some(target) => target.into(),
+            None => utils::window().into(),
+        };
+        let link = self.link.clone();
+        let listener = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Scrolled)) as Box<dyn Fn(Event)>
+        );
+
+        target
+            .add_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+            .ok();
+        self.listener = Some((target, listener));
+    }
+
+    fn stop_listening(&mut self) {
+        if let Some((target, listener)) = self.listener.take() {
+            target
+                .remove_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+                .ok();
+        }
+    }
+
+    fn get_scroll_top(&self) -> f64 {
+        match self.props.target.cast::<Element>() {
+            Some(target) => target.scroll_top() as f64,
+            None => utils::window().scroll_y().unwrap_or(0.0),
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_hide_before_the_threshold() {
+    let props = Props::builder()
+        .id("back-to-top-test".to_string())
+        .threshold(100000)
+        .build();
+
+    let back_to_top: App<BackToTop> = App::new();
+    back_to_top.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button = utils::document()
+        .get_element_by_id("back-to-top-test")
+        .unwrap();
+
+    assert!(!button.class_list().contains("visible"));
+    assert_eq!(
+        button.get_attribute("aria-hidden"),
+        Some("true".to_string())
+    );
+    assert_eq!(
+        button.get_attribute("aria-label"),
+        Some("Back to top".to_string())
+    );
+}
@@ -1,4 +1,7 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::ripple::add_ripple;
+use crate::styles::{
+    get_palette, get_size, get_style, tokens::Spaces, ComponentClasses, Palette, Size, Style,
+};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use web_sys::window;
@@ -83,6 +86,8 @@ struct ButtonProps {
     code_ref: NodeRef,
     onclick_signal: Callback<MouseEvent>,
     styles: StyleSource<'static>,
+    margin: Spaces,
+    padding: Spaces,
     children: Children,
 }
 
@@ -98,6 +103,8 @@ impl From<Props> for ButtonProps {
             code_ref: props.code_ref,
             onclick_signal: props.onclick_signal,
             styles: props.styles,
+            margin: props.margin,
+            padding: props.padding,
             children: props.children,
         }
     }
@@ -131,6 +138,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -172,13 +185,17 @@ impl Component for Button {
         html! {
             <button
                 onclick=self.link.callback(Msg::Clicked)
-                class=classes!("button",
-                    self.props.button_palette.clone(),
-                    self.props.button_size.clone(),
-                    self.props.button_style.clone(),
-                    self.props.class_name.clone(),
-                    self.props.styles.clone(),
-                )
+                onpointerdown=Callback::from(|event: PointerEvent| add_ripple(&event))
+                class=ComponentClasses::new("button")
+                    .semantic(vec![
+                        self.props.button_palette.clone(),
+                        self.props.button_size.clone(),
+                        self.props.button_style.clone(),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
                 id=self.props.id.clone()
@@ -216,18 +233,15 @@ fn should_trigger_action_when_button_clicked() {
         content.set_text_content(Some("about"));
     });
 
-    let props = Props {
-        class_name: String::from("test-button"),
-        id: String::from("button-id-test"),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        button_size: Size::Medium,
-        button_style: Style::Regular,
-        onclick_signal: onchange_name,
-        button_palette: Palette::Standard,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {<div id="submenu">{"another menu"}</div>}]),
-    };
+    let props = Props::builder()
+        .class_name(String::from("test-button"))
+        .id(String::from("button-id-test"))
+        .onclick_signal(onchange_name)
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![
+            html! {<div id="submenu">{"another menu"}</div>},
+        ]))
+        .build();
 
     let mouse_event = MouseEvent::new("click").unwrap();
 
@@ -247,18 +261,15 @@ fn should_trigger_action_when_button_clicked() {
 
 #[wasm_bindgen_test]
 fn should_create_button_component() {
-    let props = Props {
-        class_name: String::from("test-button"),
-        id: String::from("button-id-test"),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        button_size: Size::Medium,
-        button_style: Style::Regular,
-        onclick_signal: Callback::noop(),
-        button_palette: Palette::Standard,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {<div id="result">{"result"}</div>}]),
-    };
+    let props = Props::builder()
+        .class_name(String::from("test-button"))
+        .id(String::from("button-id-test"))
+        .onclick_signal(Callback::noop())
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![
+            html! {<div id="result">{"result"}</div>},
+        ]))
+        .build();
 
     let button: App<Button> = App::new();
     button.mount_with_props(
@@ -0,0 +1,557 @@
+use crate::messages::get_messages;
+use crate::styles::{
+    get_palette, get_size, get_style, tokens::Spaces, ComponentClasses, Palette, Size, Style,
+};
+use std::rc::Rc;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Calendar component
+///
+/// ## Features required
+///
+/// calendar
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::calendar::{Calendar, CalendarDate, CalendarEvent, CalendarView};
+/// use yew_styles::styles::{Palette, Size, Style};
+///
+/// pub struct CalendarExample {
+///     link: ComponentLink<Self>,
+///     date: CalendarDate,
+///     selected: Option<CalendarDate>,
+/// }
+///
+/// pub enum Msg {
+///     Navigate(CalendarDate),
+///     SelectDay(CalendarDate),
+/// }
+///
+/// impl Component for CalendarExample {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             date: CalendarDate::new(2021, 7, 1),
+///             selected: None,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Navigate(date) => self.date = date,
+///             Msg::SelectDay(date) => self.selected = Some(date),
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Calendar
+///                 date=self.date.clone()
+///                 selected=self.selected.clone()
+///                 calendar_view=CalendarView::Month
+///                 calendar_palette=Palette::Info
+///                 calendar_style=Style::Light
+///                 events=vec![
+///                     CalendarEvent {
+///                         date: CalendarDate::new(2021, 7, 14),
+///                         title: "Release".to_string(),
+///                         palette: Palette::Success,
+///                     },
+///                 ]
+///                 onnavigate_signal=self.link.callback(Msg::Navigate)
+///                 ondayclick_signal=self.link.callback(Msg::SelectDay)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct Calendar {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+/// Date shown by the calendar, months and days start at 1
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// Event marker showed in the day cell of its date
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalendarEvent {
+    pub date: CalendarDate,
+    pub title: String,
+    pub palette: Palette,
+}
+
+/// Render of the day cell content, the closure can capture the state of the parent component:
+/// `day_render=DayRender::new(move |day, events| html! {...})`
+#[derive(Clone)]
+pub struct DayRender(Rc<dyn Fn(&CalendarDate, &[CalendarEvent]) -> Html>);
+
+impl DayRender {
+    pub fn new(render: impl Fn(&CalendarDate, &[CalendarEvent]) -> Html + 'static) -> Self {
+        DayRender(Rc::new(render))
+    }
+
+    pub fn render(&self, day: &CalendarDate, events: &[CalendarEvent]) -> Html {
+        (self.0)(day, events)
+    }
+}
+
+impl PartialEq for DayRender {
+    fn eq(&self, other: &DayRender) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// How many days are displayed at once
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalendarView {
+    Month,
+    Week,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Reference date. The month or the week that contains it is displayed. Required
+    pub date: CalendarDate,
+    /// Display a whole month or a single week. Default `CalendarView::Month`
+    #[prop_or(CalendarView::Month)]
+    pub calendar_view: CalendarView,
+    /// Events showed as markers in their day cell
+    #[prop_or_default]
+    pub events: Vec<CalendarEvent>,
+    /// Day marked as selected. Default `None`
+    #[prop_or(None)]
+    pub selected: Option<CalendarDate>,
+    /// Custom render for the content of the day cell. It receives the day and its events. Default `None`
+    #[prop_or(None)]
+    pub day_render: Option<DayRender>,
+    /// Name of the week days starting by monday. Default `Messages::week_days`
+    #[prop_or_else(default_week_days)]
+    pub week_days: Vec<String>,
+    /// Name of the months starting by january. Default `Messages::months`
+    #[prop_or_else(default_months)]
+    pub months: Vec<String>,
+    /// Click event for the day cell
+    #[prop_or(Callback::noop())]
+    pub ondayclick_signal: Callback<CalendarDate>,
+    /// Emit the new reference date when the previous or next controls are clicked
+    #[prop_or(Callback::noop())]
+    pub onnavigate_signal: Callback<CalendarDate>,
+    /// Type calendar purpose style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub calendar_palette: Palette,
+    /// Calendar styles. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub calendar_style: Style,
+    /// Three diffent calendar standard sizes. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub calendar_size: Size,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    DayClicked(CalendarDate),
+    Previous,
+    Next,
+}
+
+impl Component for Calendar {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::DayClicked(date) => {
+                self.props.ondayclick_signal.emit(date);
+            }
+            Msg::Previous => {
+                let date = match self.props.calendar_view {
+                    CalendarView::Month => self.props.date.add_months(-1),
+                    CalendarView::Week => self.props.date.add_days(-7),
+                };
+                self.props.onnavigate_signal.emit(date);
+            }
+            Msg::Next => {
+                let date = match self.props.calendar_view {
+                    CalendarView::Month => self.props.date.add_months(1),
+                    CalendarView::Week => self.props.date.add_days(7),
+                };
+                self.props.onnavigate_signal.emit(date);
+            }
+        };
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let days = get_days(self.props.date.clone(), self.props.calendar_view.clone());
+
+        html! {
+            <div
+                class=ComponentClasses::new("calendar")
+                    .semantic(vec![
+                        get_calendar_view(self.props.calendar_view.clone()),
+                        get_style(self.props.calendar_style.clone()),
+                        get_palette(self.props.calendar_palette.clone()),
+                        get_size(self.props.calendar_size.clone()),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <div class="calendar-header">
+                    <button class="calendar-control" onclick=self.link.callback(|_| Msg::Previous)>{"‹"}</button>
+                    <span class="calendar-title">{get_title(&self.props.date, &self.props.months)}</span>
+                    <button class="calendar-control" onclick=self.link.callback(|_| Msg::Next)>{"›"}</button>
+                </div>
+                <div class="calendar-grid">
+                    {self.props.week_days.iter().map(|week_day| html! {
+                        <div class="calendar-week-day">{week_day}</div>
+                    }).collect::<Html>()}
+                    {days.into_iter().map(|day| self.get_day(day)).collect::<Html>()}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Calendar {
+    fn get_day(&self, day: CalendarDate) -> Html {
+        let events = self
+            .props
+            .events
+            .iter()
+            .filter(|event| event.date == day)
+            .cloned()
+            .collect::<Vec<CalendarEvent>>();
+        let outside = self.props.calendar_view == CalendarView::Month
+            && (day.month != self.props.date.month || day.year != self.props.date.year);
+        let selected = self.props.selected.as_ref() == Some(&day);
+        let content = match &self.props.day_render {
+            Some(day_render) => day_render.render(&day, &events),
+            None => get_day_content(&day, &events),
+        };
+        let date = day.clone();
+
+        html! {
+            <div
+                class=classes!(
+                    "calendar-day",
+                    if outside { "outside" } else { "" },
+                    if selected { "active" } else { "" },
+                )
+                key=format!("{}-{}-{}", day.year, day.month, day.day)
+                onclick=self.link.callback(move |_| Msg::DayClicked(date.clone()))
+            >
+                {content}
+            </div>
+        }
+    }
+}
+
+impl CalendarDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        CalendarDate { year, month, day }
+    }
+
+    /// Day of the week where monday is 0 and sunday is 6
+    pub fn weekday(&self) -> u32 {
+        ((self.to_days() + 3).rem_euclid(7)) as u32
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        CalendarDate::from_days(self.to_days() + days)
+    }
+
+    /// Moves the date by months keeping the day inside of the target month
+    pub fn add_months(&self, months: i32) -> Self {
+        let total = self.year * 12 + self.month as i32 - 1 + months;
+        let year = total.div_euclid(12);
+        let month = total.rem_euclid(12) as u32 + 1;
+
+        CalendarDate::new(year, month, self.day.min(days_in_month(year, month)))
+    }
+
+    // Days since 1970-01-01 based on the civil calendar algorithm of Howard Hinnant
+    fn to_days(&self) -> i64 {
+        let year = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        CalendarDate::new(year, month, day)
+    }
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn default_week_days() -> Vec<String> {
+    get_messages().week_days
+}
+
+fn default_months() -> Vec<String> {
+    get_messages().months
+}
+
+fn get_days(date: CalendarDate, calendar_view: CalendarView) -> Vec<CalendarDate> {
+    match calendar_view {
+        CalendarView::Month => {
+            let first_day = CalendarDate::new(date.year, date.month, 1);
+            let start = first_day.add_days(-(first_day.weekday() as i64));
+
+            (0..42).map(|day| start.add_days(day)).collect()
+        }
+        CalendarView::Week => {
+            let start = date.add_days(-(date.weekday() as i64));
+
+            (0..7).map(|day| start.add_days(day)).collect()
+        }
+    }
+}
+
+fn get_title(date: &CalendarDate, months: &[String]) -> String {
+    match (date.month as usize)
+        .checked_sub(1)
+        .and_then(|index| months.get(index))
+    {
+        Some(month) => format!("{} {}", month, date.year),
+        None => format!("{}/{}", date.month, date.year),
+    }
+}
+
+fn get_day_content(day: &CalendarDate, events: &[CalendarEvent]) -> Html {
+    html! {
+        <>
+            <span class="calendar-day-number">{day.day}</span>
+            <div class="calendar-events">
+                {events.iter().map(|event| html! {
+                    <span
+                        class=classes!("calendar-event", get_palette(event.palette.clone()))
+                        title=event.title.clone()
+                    >{event.title.clone()}</span>
+                }).collect::<Html>()}
+            </div>
+        </>
+    }
+}
+
+fn get_calendar_view(calendar_view: CalendarView) -> String {
+    match calendar_view {
+        CalendarView::Month => String::from("month"),
+        CalendarView::Week => String::from("week"),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_days_of_the_month_view() {
+    let days = get_days(CalendarDate::new(2021, 7, 15), CalendarView::Month);
+
+    assert_eq!(days.len(), 42);
+    assert_eq!(days[0], CalendarDate::new(2021, 6, 28));
+    assert_eq!(days[3], CalendarDate::new(2021, 7, 1));
+    assert_eq!(days[41], CalendarDate::new(2021, 8, 8));
+}
+
+#[wasm_bindgen_test]
+fn should_get_days_of_the_week_view() {
+    let days = get_days(CalendarDate::new(2020, 3, 1), CalendarView::Week);
+
+    assert_eq!(days[0], CalendarDate::new(2020, 2, 24));
+    assert_eq!(days[4], CalendarDate::new(2020, 2, 28));
+    assert_eq!(days[5], CalendarDate::new(2020, 2, 29));
+    assert_eq!(days[6], CalendarDate::new(2020, 3, 1));
+}
+
+#[wasm_bindgen_test]
+fn should_move_months_keeping_the_day_in_range() {
+    let date = CalendarDate::new(2021, 1, 31);
+
+    assert_eq!(date.add_months(1), CalendarDate::new(2021, 2, 28));
+    assert_eq!(date.add_months(-1), CalendarDate::new(2020, 12, 31));
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_title_of_an_invalid_month() {
+    let months = vec!["January".to_string()];
+    let date = CalendarDate {
+        year: 2021,
+        month: 0,
+        day: 1,
+    };
+
+    assert_eq!(get_title(&date, &months), "0/2021");
+    assert_eq!(
+        get_title(&CalendarDate::new(2021, 1, 1), &months),
+        "January 2021"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_create_calendar() {
+    let props = Props::builder()
+        .class_name("calendar-test".to_string())
+        .date(CalendarDate::new(2021, 7, 15))
+        .events(vec![CalendarEvent {
+            date: CalendarDate::new(2021, 7, 14),
+            title: "Release".to_string(),
+            palette: Palette::Success,
+        }])
+        .id("calendar-id-test".to_string())
+        .months(
+            vec!["July"; 12]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<String>>(),
+        )
+        .styles(css!("background-color: #918d94;"))
+        .week_days(
+            vec!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<String>>(),
+        )
+        .build();
+
+    let calendar: App<Calendar> = App::new();
+
+    calendar.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let days = utils::document().get_elements_by_class_name("calendar-day");
+    let events = utils::document().get_elements_by_class_name("calendar-event");
+
+    assert_eq!(days.length(), 42);
+    assert_eq!(
+        events.get_with_index(0).unwrap().text_content().unwrap(),
+        "Release"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_render_the_day_content_with_the_captured_state() {
+    let prefix = String::from("Day");
+    let props = Props::builder()
+        .date(CalendarDate::new(2021, 7, 15))
+        .day_render(DayRender::new(move |day, _| {
+            html! {<span class="calendar-custom-day">{format!("{} {}", prefix, day.day)}</span>}
+        }))
+        .id("calendar-custom-day-test".to_string())
+        .build();
+
+    let calendar: App<Calendar> = App::new();
+
+    calendar.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let custom_days = utils::document()
+        .get_element_by_id("calendar-custom-day-test")
+        .unwrap()
+        .get_elements_by_class_name("calendar-custom-day");
+
+    assert_eq!(custom_days.length(), 42);
+    assert_eq!(
+        custom_days
+            .get_with_index(3)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Day 1"
+    );
+}
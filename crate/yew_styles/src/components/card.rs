@@ -2,7 +2,12 @@ use crate::layouts::{
     container::{AlignContent, Container, Direction, Mode, Wrap},
     item::{Item, ItemLayout},
 };
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::ripple::add_ripple;
+use crate::styles::{
+    get_elevation, get_palette, get_size, get_style,
+    tokens::{Elevation, Spaces},
+    ComponentClasses, Palette, Size, Style,
+};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -206,6 +211,9 @@ pub struct Props {
     /// if hove, focus, active effects are enable. Default `true`
     #[prop_or(true)]
     pub interaction_effect: bool,
+    /// Shadow level of the card. Default `Elevation::Level1`
+    #[prop_or(Elevation::Level1)]
+    pub elevation: Elevation,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -221,6 +229,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 pub enum Msg {
@@ -285,22 +299,23 @@ impl Component for Card {
     }
 
     fn view(&self) -> Html {
+        let interaction_effect = self.props.interaction_effect;
+
         html! {
             <div
                 id=self.props.id.clone()
-                class=classes!(
-                    "card",
-                    get_palette(self.props.card_palette.clone()),
-                    if self.props.interaction_effect {
-                        "interaction"
-                    } else {
-                        ""
-                    },
-                    get_size(self.props.card_size.clone()),
-                    get_style(self.props.card_style.clone()),
-                    self.props.class_name.clone(),
-                    self.props.styles.clone(),
-                )
+                class=ComponentClasses::new("card")
+                    .semantic(vec![
+                        get_palette(self.props.card_palette.clone()),
+                        get_size(self.props.card_size.clone()),
+                        get_style(self.props.card_style.clone()),
+                        get_elevation(self.props.elevation),
+                    ])
+                    .state("interaction", self.props.interaction_effect)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
                 draggable = self.props.draggable.to_string()
@@ -313,6 +328,11 @@ impl Component for Card {
                 ondragstart = self.link.callback(Msg::DragedStart)
                 ondrop = self.link.callback(Msg::Dropped)
                 onclick = self.link.callback(Msg::Clicked)
+                onpointerdown = Callback::from(move |event: PointerEvent| {
+                    if interaction_effect {
+                        add_ripple(&event);
+                    }
+                })
             >
                 {get_content(
                     self.props.single_content.clone(),
@@ -370,40 +390,22 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_card_with_three_parts() {
-    let props = Props {
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        draggable: false,
-        header: Some(html! {
-            <div id="header">{"header"}</div>
-        }),
-        header_size: 4,
-        body: Some(html! {
+    let props = Props::builder()
+        .body(Some(html! {
             <div id="body">{"body"}</div>
-        }),
-        body_size: 6,
-        footer: Some(html! {
+        }))
+        .card_palette(Palette::Primary)
+        .class_name("class-card-test".to_string())
+        .footer(Some(html! {
             <div id="footer">{"footer"}</div>
-        }),
-        footer_size: 2,
-        single_content: None,
-        card_palette: Palette::Primary,
-        card_style: Style::Regular,
-        card_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        id: "id-card-rest".to_string(),
-    };
+        }))
+        .header(Some(html! {
+            <div id="header">{"header"}</div>
+        }))
+        .id("id-card-rest".to_string())
+        .interaction_effect(false)
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let card: App<Card> = App::new();
     card.mount_with_props(
@@ -426,36 +428,16 @@ fn should_create_card_with_three_parts() {
 
 #[wasm_bindgen_test]
 fn should_create_card_with_single_content() {
-    let props = Props {
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        draggable: false,
-        header: None,
-        header_size: 4,
-        body: None,
-        body_size: 6,
-        footer: None,
-        footer_size: 2,
-        single_content: Some(html! {
+    let props = Props::builder()
+        .card_palette(Palette::Primary)
+        .class_name("class-card-test".to_string())
+        .id("id-card-test".to_string())
+        .interaction_effect(false)
+        .single_content(Some(html! {
             <div id="single-content">{"single content"}</div>
-        }),
-        card_palette: Palette::Primary,
-        card_style: Style::Regular,
-        card_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        id: "id-card-test".to_string(),
-    };
+        }))
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let card: App<Card> = App::new();
     card.mount_with_props(
@@ -475,42 +457,25 @@ fn should_create_card_with_single_content() {
 
 #[wasm_bindgen_test]
 fn should_ignore_parts_when_single_content_exist() {
-    let props = Props {
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        draggable: false,
-        header: Some(html! {
-            <div id="header">{"header"}</div>
-        }),
-        header_size: 4,
-        body: Some(html! {
+    let props = Props::builder()
+        .body(Some(html! {
             <div id="body">{"body"}</div>
-        }),
-        body_size: 6,
-        footer: Some(html! {
+        }))
+        .card_palette(Palette::Primary)
+        .class_name("class-card-test".to_string())
+        .footer(Some(html! {
             <div id="footer">{"footer"}</div>
-        }),
-        footer_size: 2,
-        single_content: Some(html! {
+        }))
+        .header(Some(html! {
+            <div id="header">{"header"}</div>
+        }))
+        .id("id-card-rest".to_string())
+        .interaction_effect(false)
+        .single_content(Some(html! {
             <div id="single-content">{"single content"}</div>
-        }),
-        card_palette: Palette::Primary,
-        card_style: Style::Regular,
-        card_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        id: "id-card-rest".to_string(),
-    };
+        }))
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let card: App<Card> = App::new();
     card.mount_with_props(
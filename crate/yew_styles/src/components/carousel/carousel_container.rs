@@ -1,6 +1,9 @@
+use crate::styles::{animations::prefers_reduced_motion, tokens::Spaces, ComponentClasses};
+use std::time::Duration;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
 use yew::{utils, App};
 
 /// # Carousel Component
@@ -9,6 +12,26 @@ use yew::{utils, App};
 ///
 /// carousel
 ///
+/// ## Autoplay
+///
+/// With `autoplay` greater than 0 the carousel emits `onautoplay_signal` with the index of the
+/// active slide when its time is over, then the app moves to the next slide as it does with `Msg::Next`.
+/// `slide_durations` overrides the time of specific slides, e.g. `vec![(0, 8000)]` keeps
+/// the first slide 8 seconds. The autoplay is paused while the mouse is over the carousel
+/// and disabled when the user prefers reduced motion
+///
+/// ```rust
+/// html! {
+///     <Carousel
+///         autoplay=5000
+///         slide_durations=vec![(0, 8000)]
+///         active_slide=self.active_image.iter().position(|active| *active).unwrap_or(0)
+///         onautoplay_signal=self.link.callback(|_| Msg::Next)>
+///         {get_images(self.images.to_vec(), self.active_image.to_vec())}
+///     </Carousel>
+/// }
+/// ```
+///
 /// ## Example
 ///
 /// ```rust
@@ -191,6 +214,7 @@ use yew::{utils, App};
 pub struct Carousel {
     props: Props,
     link: ComponentLink<Self>,
+    autoplay_task: Option<TimeoutTask>,
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -207,6 +231,22 @@ pub struct Props {
     /// mouse leave event for carousel
     #[prop_or(Callback::noop())]
     pub onmouseleave_signal: Callback<MouseEvent>,
+    /// Time in milliseconds that each slide is shown before `onautoplay_signal` is emitted,
+    /// 0 disables the autoplay. Default `0`
+    #[prop_or(0)]
+    pub autoplay: u64,
+    /// Time in milliseconds of specific slides as `(slide index, time)`, overriding `autoplay`. Default `vec![]`
+    #[prop_or_default]
+    pub slide_durations: Vec<(usize, u64)>,
+    /// Index of the active slide, the autoplay starts again when it changes. Default `0`
+    #[prop_or(0)]
+    pub active_slide: usize,
+    /// Pause the autoplay while the mouse is over the carousel. Default `true`
+    #[prop_or(true)]
+    pub pause_on_hover: bool,
+    /// Emitted with the index of the active slide when its time is over
+    #[prop_or(Callback::noop())]
+    pub onautoplay_signal: Callback<usize>,
     /// General property to add keys
     #[prop_or_default]
     pub key: String,
@@ -219,6 +259,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -226,6 +272,7 @@ pub enum Msg {
     Wheel(WheelEvent),
     MouseOver(MouseEvent),
     MouseLeave(MouseEvent),
+    Autoplay,
 }
 
 impl Component for Carousel {
@@ -233,7 +280,11 @@ impl Component for Carousel {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { props, link }
+        Self {
+            props,
+            link,
+            autoplay_task: None,
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -242,11 +293,22 @@ impl Component for Carousel {
                 self.props.onwheel_signal.emit(wheel_event);
             }
             Msg::MouseOver(mouse_event) => {
+                if self.props.pause_on_hover {
+                    self.autoplay_task = None;
+                }
                 self.props.onmouseover_signal.emit(mouse_event);
             }
             Msg::MouseLeave(mouse_event) => {
+                if self.props.pause_on_hover {
+                    self.start_autoplay();
+                }
                 self.props.onmouseleave_signal.emit(mouse_event);
             }
+            Msg::Autoplay => {
+                self.props.onautoplay_signal.emit(self.props.active_slide);
+                self.start_autoplay();
+                return false;
+            }
         }
 
         true
@@ -254,17 +316,34 @@ impl Component for Carousel {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            let restart = self.props.active_slide != props.active_slide
+                || self.props.autoplay != props.autoplay
+                || self.props.slide_durations != props.slide_durations;
             self.props = props;
+
+            if restart {
+                self.start_autoplay();
+            }
             return true;
         }
 
         false
     }
 
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.start_autoplay();
+        }
+    }
+
     fn view(&self) -> Html {
         html! {
             <div
-                class=classes!("carousel-container", self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("carousel-container")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
                 onwheel=self.link.callback(Msg::Wheel)
@@ -278,19 +357,47 @@ impl Component for Carousel {
     }
 }
 
+impl Carousel {
+    fn start_autoplay(&mut self) {
+        let duration = get_slide_duration(
+            self.props.autoplay,
+            &self.props.slide_durations,
+            self.props.active_slide,
+        );
+
+        self.autoplay_task = if duration > 0 && !prefers_reduced_motion() {
+            Some(TimeoutService::spawn(
+                Duration::from_millis(duration),
+                self.link.callback(|_| Msg::Autoplay),
+            ))
+        } else {
+            None
+        };
+    }
+}
+
+fn get_slide_duration(autoplay: u64, slide_durations: &[(usize, u64)], slide: usize) -> u64 {
+    if autoplay == 0 {
+        return 0;
+    }
+
+    slide_durations
+        .iter()
+        .find(|(index, _)| *index == slide)
+        .map(|(_, duration)| *duration)
+        .unwrap_or(autoplay)
+}
+
 #[wasm_bindgen_test]
 fn should_create_carousel_container_component() {
-    let props = Props {
-        class_name: String::from("test-carousel"),
-        id: String::from("carousel-id-test"),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        onwheel_signal: Callback::noop(),
-        onmouseover_signal: Callback::noop(),
-        onmouseleave_signal: Callback::noop(),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {<div id="result">{"result"}</div>}]),
-    };
+    let props = Props::builder()
+        .class_name(String::from("test-carousel"))
+        .id(String::from("carousel-id-test"))
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![
+            html! {<div id="result">{"result"}</div>},
+        ]))
+        .build();
 
     let carousel: App<Carousel> = App::new();
     carousel.mount_with_props(
@@ -307,3 +414,12 @@ fn should_create_carousel_container_component() {
 
     assert_eq!(child.id(), "result");
 }
+
+#[wasm_bindgen_test]
+fn should_get_the_duration_of_the_slide() {
+    let slide_durations = vec![(1, 8000)];
+
+    assert_eq!(get_slide_duration(5000, &slide_durations, 0), 5000);
+    assert_eq!(get_slide_duration(5000, &slide_durations, 1), 8000);
+    assert_eq!(get_slide_duration(0, &slide_durations, 1), 0);
+}
@@ -1,3 +1,5 @@
+use crate::keyboard::click_on_enter;
+use crate::messages::get_messages;
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -224,6 +226,7 @@ pub struct Props {
 pub enum Msg {
     PrevClicked(MouseEvent),
     NextClicked(MouseEvent),
+    Pressed(KeyboardEvent),
 }
 
 impl Component for CarouselControls {
@@ -242,6 +245,10 @@ impl Component for CarouselControls {
             Msg::NextClicked(mouse_event) => {
                 self.props.next_signal.emit(mouse_event);
             }
+            Msg::Pressed(keyboard_event) => {
+                click_on_enter(&keyboard_event);
+                return false;
+            }
         }
 
         true
@@ -257,6 +264,8 @@ impl Component for CarouselControls {
     }
 
     fn view(&self) -> Html {
+        let messages = get_messages();
+
         html! {
             <div class="carousel-control"
                 key=self.props.key.clone()
@@ -265,7 +274,10 @@ impl Component for CarouselControls {
             >
                 <div
                     class="carousel-control-left-container"
-
+                    role="button"
+                    tabindex="0"
+                    aria-label=messages.previous
+                    onkeydown=self.link.callback(Msg::Pressed)
                     onclick=self.link.callback(Msg::PrevClicked)>
                     <ControllerAssets
                         size=("50".to_string(),"50".to_string())
@@ -280,6 +292,10 @@ impl Component for CarouselControls {
                 </div>
                 <div
                     class="carousel-control-right-container"
+                    role="button"
+                    tabindex="0"
+                    aria-label=messages.next
+                    onkeydown=self.link.callback(Msg::Pressed)
                     onclick=self.link.callback(Msg::NextClicked)
                 >
                     <ControllerAssets
@@ -299,17 +315,12 @@ impl Component for CarouselControls {
 
 #[wasm_bindgen_test]
 fn should_create_carousel_controls_component() {
-    let props = Props {
-        code_ref: NodeRef::default(),
-        class_name: String::from("test-carousel"),
-        id: String::from("carousel-id-test"),
-        controls_palette: Palette::Standard,
-        controls_style: Style::Regular,
-        controls_size: Size::Medium,
-        next_signal: Callback::noop(),
-        prev_signal: Callback::noop(),
-        key: "".to_string(),
-    };
+    let props = Props::builder()
+        .class_name(String::from("test-carousel"))
+        .id(String::from("carousel-id-test"))
+        .next_signal(Callback::noop())
+        .prev_signal(Callback::noop())
+        .build();
 
     let carousel: App<CarouselControls> = App::new();
     carousel.mount_with_props(
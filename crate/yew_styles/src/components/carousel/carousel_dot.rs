@@ -1,4 +1,5 @@
-use crate::styles::{get_palette, Palette};
+use crate::keyboard::click_on_enter;
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -220,6 +221,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     /// In case that children is not included will add a dot icon by default
     #[prop_or_default]
     pub children: Option<Children>,
@@ -227,6 +234,7 @@ pub struct Props {
 
 pub enum Msg {
     DotClicked(MouseEvent),
+    Pressed(KeyboardEvent),
 }
 
 impl Component for CarouselDot {
@@ -242,6 +250,10 @@ impl Component for CarouselDot {
             Msg::DotClicked(mouse_event) => {
                 self.props.onclick_signal.emit(mouse_event);
             }
+            Msg::Pressed(keyboard_event) => {
+                click_on_enter(&keyboard_event);
+                return false;
+            }
         }
 
         true
@@ -259,21 +271,20 @@ impl Component for CarouselDot {
     fn view(&self) -> Html {
         html! {
             <div
-                class=classes!(
-                    "carousel-dot",
-                    self.props.class_name.clone(),
-                    get_palette(self.props.carousel_dot_palette.clone()),
-                    if self.props.active {
-                        "active"
-                    } else {
-                        ""
-                    },
-                    self.props.class_name.clone(),
-                    self.props.styles.clone(),
-                )
+                class=ComponentClasses::new("carousel-dot")
+                    .semantic(get_palette(self.props.carousel_dot_palette.clone()))
+                    .state("active", self.props.active)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id={self.props.id.clone()}
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
+                role="button"
+                tabindex="0"
+                aria-current=self.props.active.to_string()
+                onkeydown=self.link.callback(Msg::Pressed)
                 onclick=self.link.callback(Msg::DotClicked)
             >
             {
@@ -294,17 +305,12 @@ impl Component for CarouselDot {
 
 #[wasm_bindgen_test]
 fn should_create_carousel_dot_component() {
-    let props = Props {
-        code_ref: NodeRef::default(),
-        class_name: String::from("test-carousel"),
-        id: String::from("carousel-id-test"),
-        carousel_dot_palette: Palette::Standard,
-        active: false,
-        onclick_signal: Callback::noop(),
-        key: "".to_string(),
-        styles: css!("background-color: #918d94;"),
-        children: None,
-    };
+    let props = Props::builder()
+        .class_name(String::from("test-carousel"))
+        .id(String::from("carousel-id-test"))
+        .onclick_signal(Callback::noop())
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let carousel: App<CarouselDot> = App::new();
     carousel.mount_with_props(
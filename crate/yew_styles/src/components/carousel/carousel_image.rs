@@ -1,3 +1,4 @@
+use crate::styles::{get_spacing, tokens::Spaces};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -205,6 +206,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
@@ -243,7 +250,7 @@ impl Component for CarouselImage {
                 "active"
             } else {
                 ""
-            }, self.props.styles.clone())
+            }, get_spacing(self.props.margin, self.props.padding), self.props.styles.clone())
                 ref=self.props.code_ref.clone()
                 id=self.props.id.clone()
             >
@@ -255,15 +262,12 @@ impl Component for CarouselImage {
 
 #[wasm_bindgen_test]
 fn should_create_carousel_dot_component() {
-    let props = Props {
-        code_ref: NodeRef::default(),
-        class_name: String::from("test-carousel"),
-        id: String::from("carousel-id-test"),
-        active: false,
-        img_src: "/slide_1.jpg".to_string(),
-        styles: css!("background-color: #918d94;"),
-        key: "".to_string(),
-    };
+    let props = Props::builder()
+        .class_name(String::from("test-carousel"))
+        .id(String::from("carousel-id-test"))
+        .img_src("/slide_1.jpg".to_string())
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let carousel: App<CarouselImage> = App::new();
     carousel.mount_with_props(
@@ -0,0 +1,192 @@
+use crate::messages::{format_message, get_messages};
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Carousel Indicator
+///
+/// Shows the position of the active slide as a fraction (`3/10`) or a progress bar,
+/// as alternative to the dots when the carousel has many slides
+///
+/// ## Features required
+///
+/// carousel
+///
+/// ## Example
+///
+/// ```rust
+/// html! {
+///     <Carousel>
+///         {get_images(self.images.to_vec(), self.active_image.to_vec())}
+///         <CarouselIndicator
+///             current=self.active_image.iter().position(|active| *active).unwrap_or(0)
+///             total=self.images.len()
+///             indicator_type=IndicatorType::Fraction
+///         />
+///         {get_controls(self.link.clone())}
+///     </Carousel>
+/// }
+/// ```
+pub struct CarouselIndicator {
+    props: Props,
+}
+
+/// Type of the carousel indicator
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndicatorType {
+    /// Active slide and total of slides, e.g. `3/10`
+    Fraction,
+    /// Bar filled until the active slide
+    Progress,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Index of the active slide. Required
+    pub current: usize,
+    /// Number of slides. Required
+    pub total: usize,
+    /// Type of indicator. Default `IndicatorType::Fraction`
+    #[prop_or(IndicatorType::Fraction)]
+    pub indicator_type: IndicatorType,
+    /// Type indicator style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub carousel_indicator_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+impl Component for CarouselIndicator {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        let current = (self.props.current + 1).min(self.props.total);
+        let fraction = format_message(
+            &get_messages().carousel_fraction,
+            &[
+                ("current", current.to_string()),
+                ("total", self.props.total.to_string()),
+            ],
+        );
+
+        html! {
+            <div
+                class=ComponentClasses::new("carousel-indicator")
+                    .semantic(get_indicator_type(&self.props.indicator_type))
+                    .semantic(get_palette(self.props.carousel_indicator_palette.clone()))
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {match self.props.indicator_type {
+                    IndicatorType::Fraction => html! {
+                        <span aria-live="polite">{fraction}</span>
+                    },
+                    IndicatorType::Progress => html! {
+                        <div
+                            class="carousel-indicator-bar"
+                            role="progressbar"
+                            aria-valuemin="1"
+                            aria-valuemax=self.props.total.to_string()
+                            aria-valuenow=current.to_string()
+                            aria-valuetext=fraction
+                        >
+                            <div
+                                class="carousel-indicator-fill"
+                                style=format!("width: {}%;", get_progress(current, self.props.total))
+                            ></div>
+                        </div>
+                    },
+                }}
+            </div>
+        }
+    }
+}
+
+fn get_indicator_type(indicator_type: &IndicatorType) -> &'static str {
+    match indicator_type {
+        IndicatorType::Fraction => "fraction",
+        IndicatorType::Progress => "progress",
+    }
+}
+
+fn get_progress(current: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+
+    current as f64 * 100.0 / total as f64
+}
+
+#[wasm_bindgen_test]
+fn should_create_carousel_fraction_indicator() {
+    let props = Props::builder()
+        .class_name(String::from("test-carousel"))
+        .current(2)
+        .id(String::from("carousel-indicator-test"))
+        .styles(css!("background-color: #918d94;"))
+        .total(10)
+        .build();
+
+    let carousel: App<CarouselIndicator> = App::new();
+    carousel.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let indicator_element = utils::document()
+        .get_element_by_id("carousel-indicator-test")
+        .unwrap();
+
+    assert_eq!(indicator_element.text_content().unwrap(), "3/10");
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_progress_of_the_carousel() {
+    assert_eq!(get_progress(3, 10), 30.0);
+    assert_eq!(get_progress(0, 0), 0.0);
+}
@@ -0,0 +1,190 @@
+use crate::messages::{format_message, get_messages};
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Carousel Thumbnails
+///
+/// Strip of thumbnails synced with the active slide of the carousel,
+/// the active thumbnail is scrolled into the view when it changes
+///
+/// ## Features required
+///
+/// carousel
+///
+/// ## Example
+///
+/// ```rust
+/// html! {
+///     <>
+///         <Carousel>
+///             {get_images(self.images.to_vec(), self.active_image.to_vec())}
+///             {get_controls(self.link.clone())}
+///         </Carousel>
+///         <CarouselThumbnails
+///             images=self.images.iter().map(|image| image.to_string()).collect::<Vec<String>>()
+///             active=self.active_image.iter().position(|active| *active).unwrap_or(0)
+///             onchange_signal=self.link.callback(Msg::ChangeImage)
+///         />
+///     </>
+/// }
+/// ```
+pub struct CarouselThumbnails {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Url path of the thumbnails, in the same order than the slides. Required
+    pub images: Vec<String>,
+    /// Index of the active slide. Default `0`
+    #[prop_or(0)]
+    pub active: usize,
+    /// Emitted with the index of the clicked thumbnail
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<usize>,
+    /// Type thumbnails style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub carousel_thumbnails_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Clicked(usize),
+}
+
+impl Component for CarouselThumbnails {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Clicked(index) => {
+                self.props.onchange_signal.emit(index);
+            }
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if let Some(strip) = self.props.code_ref.cast::<HtmlElement>() {
+            if let Some(thumbnail) = strip
+                .query_selector(".carousel-thumbnail.active")
+                .ok()
+                .flatten()
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+            {
+                strip.set_scroll_left(
+                    thumbnail.offset_left() - (strip.client_width() - thumbnail.offset_width()) / 2,
+                );
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let label = get_messages().carousel_thumbnail;
+
+        html! {
+            <div
+                class=ComponentClasses::new("carousel-thumbnails")
+                    .semantic(get_palette(self.props.carousel_thumbnails_palette.clone()))
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.images.iter().enumerate().map(|(index, image)| {
+                    let active = index == self.props.active;
+
+                    html! {
+                        <button
+                            class=classes!("carousel-thumbnail", if active { Some("active") } else { None })
+                            aria-label=format_message(&label, &[("index", (index + 1).to_string())])
+                            aria-current=if active { "true" } else { "false" }
+                            onclick=self.link.callback(move |_| Msg::Clicked(index))
+                        >
+                            <img src=image.clone() alt=""/>
+                        </button>
+                    }
+                }).collect::<Html>()}
+            </div>
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn should_create_carousel_thumbnails() {
+    let props = Props::builder()
+        .active(1)
+        .class_name(String::from("test-carousel"))
+        .id(String::from("carousel-thumbnails-test"))
+        .images(vec![
+            "/slide_1.jpg".to_string(),
+            "/slide_2.jpg".to_string(),
+            "/slide_3.jpg".to_string(),
+        ])
+        .styles(css!("background-color: #918d94;"))
+        .build();
+
+    let carousel: App<CarouselThumbnails> = App::new();
+    carousel.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let thumbnails_element = utils::document()
+        .get_element_by_id("carousel-thumbnails-test")
+        .unwrap();
+    let active_thumbnail = thumbnails_element
+        .get_elements_by_class_name("active")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(thumbnails_element.child_element_count(), 3);
+    assert_eq!(
+        active_thumbnail.get_attribute("aria-label").unwrap(),
+        "Show slide 2"
+    );
+}
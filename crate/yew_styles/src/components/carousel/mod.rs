@@ -2,8 +2,12 @@ mod carousel_container;
 mod carousel_controls;
 mod carousel_dot;
 mod carousel_image;
+mod carousel_indicator;
+mod carousel_thumbnails;
 
 pub use carousel_container::Carousel;
 pub use carousel_controls::CarouselControls;
 pub use carousel_dot::CarouselDot;
 pub use carousel_image::CarouselImage;
+pub use carousel_indicator::{CarouselIndicator, IndicatorType};
+pub use carousel_thumbnails::CarouselThumbnails;
@@ -0,0 +1,312 @@
+use crate::messages::{format_message, get_messages};
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Changelog component
+///
+/// Timeline of the release notes, each version shows its changes tagged by kind (added, fixed,
+/// changed...) and colored with a palette, and an optional body with any html. Only the newest
+/// versions are expanded, the older ones are collapsed until the user opens them, and each
+/// version has an anchor to link it directly
+///
+/// ## Features required
+///
+/// changelog
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::changelog::{Change, ChangeKind, Changelog, ChangelogEntry};
+///
+/// pub struct ReleaseNotes;
+///
+/// impl Component for ReleaseNotes {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Changelog
+///                 entries=vec![
+///                     ChangelogEntry {
+///                         version: "0.11.0".to_string(),
+///                         date: Some("2021-06-01".to_string()),
+///                         changes: vec![
+///                             Change::new(ChangeKind::Added, "Toast component"),
+///                             Change::new(ChangeKind::Fixed, "Focus of the modal"),
+///                         ],
+///                         body: Some(html! {<p>{"Summer release"}</p>}),
+///                     },
+///                     ChangelogEntry {
+///                         version: "0.10.0".to_string(),
+///                         date: Some("2021-03-01".to_string()),
+///                         changes: vec![Change::new(ChangeKind::Changed, "Yew 0.18")],
+///                         body: None,
+///                     },
+///                 ]
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct Changelog {
+    props: Props,
+}
+
+/// Kind of change of a version
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+    Security,
+}
+
+impl ChangeKind {
+    /// Palette of the tag
+    pub fn palette(&self) -> Palette {
+        match self {
+            ChangeKind::Added => Palette::Success,
+            ChangeKind::Changed => Palette::Info,
+            ChangeKind::Deprecated => Palette::Warning,
+            ChangeKind::Removed => Palette::Danger,
+            ChangeKind::Fixed => Palette::Primary,
+            ChangeKind::Security => Palette::Secondary,
+        }
+    }
+
+    /// Name of the tag from the messages
+    pub fn label(&self) -> String {
+        let messages = get_messages();
+
+        match self {
+            ChangeKind::Added => messages.change_added,
+            ChangeKind::Changed => messages.change_changed,
+            ChangeKind::Deprecated => messages.change_deprecated,
+            ChangeKind::Removed => messages.change_removed,
+            ChangeKind::Fixed => messages.change_fixed,
+            ChangeKind::Security => messages.change_security,
+        }
+    }
+}
+
+/// Change of a version
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub description: String,
+}
+
+impl Change {
+    pub fn new(kind: ChangeKind, description: &str) -> Self {
+        Self {
+            kind,
+            description: description.to_string(),
+        }
+    }
+}
+
+/// Version of the changelog
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChangelogEntry {
+    pub version: String,
+    /// Release date
+    pub date: Option<String>,
+    pub changes: Vec<Change>,
+    /// Notes shown before the changes, e.g. the html of a markdown renderer
+    pub body: Option<Html>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Versions, from the newest to the oldest. Required
+    pub entries: Vec<ChangelogEntry>,
+    /// Number of versions expanded, the rest are collapsed. Default `1`
+    #[prop_or(1)]
+    pub expanded: usize,
+    /// Prefix of the anchor of each version, e.g. `#v0.11.0`. Default `"v"`
+    #[prop_or("v".to_string())]
+    pub anchor_prefix: String,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+impl Component for Changelog {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ol
+                class=ComponentClasses::new("changelog")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {for self.props.entries.iter().enumerate().map(|(index, entry)| self.get_entry(index, entry))}
+            </ol>
+        }
+    }
+}
+
+impl Changelog {
+    fn get_entry(&self, index: usize, entry: &ChangelogEntry) -> Html {
+        let anchor = get_anchor(&self.props.anchor_prefix, &entry.version);
+        let expanded = index < self.props.expanded;
+
+        html! {
+            <li class="changelog-entry">
+                <details class="changelog-version" open=expanded>
+                    <summary>
+                        <h3 class="changelog-title" id=anchor.clone()>
+                            {entry.version.clone()}
+                            <a
+                                class="changelog-anchor"
+                                href=format!("#{}", anchor)
+                                aria-label=format_message(&get_messages().version_link, &[("version", entry.version.clone())])
+                            >{"#"}</a>
+                        </h3>
+                        {if let Some(date) = entry.date.clone() {
+                            html! {
+                                <time class="changelog-date">{date}</time>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </summary>
+                    {entry.body.clone().unwrap_or_default()}
+                    <ul class="changelog-changes">
+                        {for entry.changes.iter().map(|change| html! {
+                            <li class="changelog-change">
+                                <span class=classes!("changelog-tag", get_palette(change.kind.palette()))>
+                                    {change.kind.label()}
+                                </span>
+                                {change.description.clone()}
+                            </li>
+                        })}
+                    </ul>
+                </details>
+            </li>
+        }
+    }
+}
+
+/// Id of the version heading, without the characters which are not valid in an url fragment
+pub fn get_anchor(prefix: &str, version: &str) -> String {
+    format!(
+        "{}{}",
+        prefix,
+        version
+            .chars()
+            .map(|c| if c.is_whitespace() { '-' } else { c })
+            .collect::<String>()
+    )
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_collapse_the_older_versions() {
+    let entry = |version: &str| ChangelogEntry {
+        version: version.to_string(),
+        date: None,
+        changes: vec![
+            Change::new(ChangeKind::Added, "New component"),
+            Change::new(ChangeKind::Fixed, "Old bug"),
+        ],
+        body: None,
+    };
+
+    let props = Props::builder()
+        .entries(vec![entry("0.3.0"), entry("0.2.0"), entry("0.1.0")])
+        .id("changelog-test".to_string())
+        .build();
+
+    let changelog: App<Changelog> = App::new();
+    changelog.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let changelog_element = utils::document()
+        .get_element_by_id("changelog-test")
+        .unwrap();
+    let versions = changelog_element.get_elements_by_class_name("changelog-version");
+
+    assert_eq!(versions.length(), 3);
+    assert!(versions.get_with_index(0).unwrap().has_attribute("open"));
+    assert!(!versions.get_with_index(1).unwrap().has_attribute("open"));
+    assert_eq!(
+        changelog_element
+            .get_elements_by_class_name("success")
+            .length(),
+        3
+    );
+    assert!(utils::document().get_element_by_id("v0.2.0").is_some());
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_anchor() {
+    assert_eq!(get_anchor("v", "1.2.0"), "v1.2.0");
+    assert_eq!(get_anchor("release-", "2021 June"), "release-2021-June");
+}
@@ -0,0 +1,237 @@
+use crate::styles::ComponentClasses;
+use stylist::{css, StyleSource};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Event, Node};
+use yew::prelude::*;
+use yew::{utils, App};
+
+const POINTER_DOWN: &str = "pointerdown";
+
+/// # Click away component
+///
+/// Emit a signal when the user presses outside of the children, e.g. to close overlays
+/// like the dropdown menu. The children are wrapped in an element with `display: contents`,
+/// so the layout doesn't change
+///
+/// ## Features required
+///
+/// click_away
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::click_away::ClickAway;
+///
+/// pub struct Popup {
+///     link: ComponentLink<Self>,
+///     open: bool,
+/// }
+///
+/// pub enum Msg {
+///     Open,
+///     Close,
+/// }
+///
+/// impl Component for Popup {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link, open: false }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         self.open = matches!(msg, Msg::Open);
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <ClickAway active=self.open onclickaway_signal=self.link.callback(|_| Msg::Close)>
+///                 <button onclick=self.link.callback(|_| Msg::Open)>{"Options"}</button>
+///                 {if self.open {
+///                     html! {<div class="popup">{"Popup content"}</div>}
+///                 } else {
+///                     html! {}
+///                 }}
+///             </ClickAway>
+///         }
+///     }
+/// }
+/// ```
+pub struct ClickAway {
+    link: ComponentLink<Self>,
+    props: Props,
+    listener: Option<Closure<dyn Fn(Event)>>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Listen to the presses outside, e.g. only while the overlay is open. Default `true`
+    #[prop_or(true)]
+    pub active: bool,
+    /// Emitted with the event when the user presses outside of the children
+    #[prop_or(Callback::noop())]
+    pub onclickaway_signal: Callback<Event>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+pub enum Msg {
+    ClickedAway(Event),
+}
+
+impl Component for ClickAway {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ClickedAway(event) => {
+                if self.props.active {
+                    self.props.onclickaway_signal.emit(event);
+                }
+            }
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.props.active {
+            self.listen();
+        } else {
+            self.stop_listening();
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.stop_listening();
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("click-away")
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+impl ClickAway {
+    fn listen(&mut self) {
+        if self.listener.is_some() {
+            return;
+        }
+
+        let link = self.link.clone();
+        let node_ref = self.props.code_ref.clone();
+        let listener = Closure::wrap(Box::new(move |event: Event| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+
+            if let (Some(element), Some(target)) = (node_ref.get(), target) {
+                if is_outside(&element, &target) {
+                    link.send_message(Msg::ClickedAway(event));
+                }
+            }
+        }) as Box<dyn Fn(Event)>);
+
+        utils::document()
+            .add_event_listener_with_callback(POINTER_DOWN, listener.as_ref().unchecked_ref())
+            .ok();
+        self.listener = Some(listener);
+    }
+
+    fn stop_listening(&mut self) {
+        if let Some(listener) = self.listener.take() {
+            utils::document()
+                .remove_event_listener_with_callback(
+                    POINTER_DOWN,
+                    listener.as_ref().unchecked_ref(),
+                )
+                .ok();
+        }
+    }
+}
+
+fn is_outside(element: &Node, target: &Node) -> bool {
+    // the target can be removed from the document while the event is dispatched
+    target.is_connected() && !element.contains(Some(target))
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_detect_the_targets_outside() {
+    let props = Props::builder()
+        .id("click-away-test".to_string())
+        .children(Children::new(vec![
+            html! {<button id="click-away-inside">{"Inside"}</button>},
+        ]))
+        .build();
+
+    let click_away: App<ClickAway> = App::new();
+    click_away.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let click_away_element = utils::document()
+        .get_element_by_id("click-away-test")
+        .unwrap();
+    let inside = utils::document()
+        .get_element_by_id("click-away-inside")
+        .unwrap();
+    let outside = utils::document().body().unwrap();
+
+    assert!(!is_outside(&click_away_element, &inside));
+    assert!(is_outside(&click_away_element, &outside));
+}
@@ -0,0 +1,356 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Compare Slider component
+///
+/// Overlays two images with a divider which can be dragged to reveal
+/// the before or the after image. The divider can be moved with the arrow keys too,
+/// `Home` and `End` move it to the edges
+///
+/// ## Features required
+///
+/// compare_slider
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::compare_slider::CompareSlider;
+///
+/// pub struct Restoration;
+///
+/// impl Component for Restoration {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <CompareSlider
+///                 before_src="/painting_before.jpg"
+///                 after_src="/painting_after.jpg"
+///                 before_label="Before"
+///                 after_label="After"
+///                 position=30.0
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct CompareSlider {
+    link: ComponentLink<Self>,
+    props: Props,
+    position: f64,
+    dragging: bool,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Url path of the image before the change. Required
+    pub before_src: String,
+    /// Url path of the image after the change. Required
+    pub after_src: String,
+    /// Alternative text of the image before the change. Default `""`
+    #[prop_or_default]
+    pub before_alt: String,
+    /// Alternative text of the image after the change. Default `""`
+    #[prop_or_default]
+    pub after_alt: String,
+    /// Label over the image before the change, it is not shown if it is empty. Default `""`
+    #[prop_or_default]
+    pub before_label: String,
+    /// Label over the image after the change, it is not shown if it is empty. Default `""`
+    #[prop_or_default]
+    pub after_label: String,
+    /// Initial position of the divider in percentage. Default `50.0`
+    #[prop_or(50.0)]
+    pub position: f64,
+    /// Percentage moved by the arrow keys. Default `5.0`
+    #[prop_or(5.0)]
+    pub step: f64,
+    /// Split the images from top to bottom instead of from left to right. Default `false`
+    #[prop_or(false)]
+    pub vertical: bool,
+    /// Emitted with the new position of the divider in percentage
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<f64>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    DragStart(PointerEvent),
+    Drag(PointerEvent),
+    DragEnd,
+    Pressed(KeyboardEvent),
+}
+
+impl Component for CompareSlider {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let position = props.position.clamp(0.0, 100.0);
+
+        Self {
+            link,
+            props,
+            position,
+            dragging: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let position = match msg {
+            Msg::DragStart(pointer_event) => {
+                pointer_event.prevent_default();
+                if let Some(container) = self.props.code_ref.cast::<Element>() {
+                    container
+                        .set_pointer_capture(pointer_event.pointer_id())
+                        .ok();
+                }
+                self.dragging = true;
+                self.get_pointer_position(&pointer_event)
+            }
+            Msg::Drag(pointer_event) => {
+                if !self.dragging {
+                    return false;
+                }
+                self.get_pointer_position(&pointer_event)
+            }
+            Msg::DragEnd => {
+                self.dragging = false;
+                return false;
+            }
+            Msg::Pressed(keyboard_event) => {
+                let position = get_keyboard_position(
+                    &keyboard_event.key(),
+                    self.position,
+                    self.props.step,
+                    self.props.vertical,
+                );
+                if position.is_some() {
+                    keyboard_event.prevent_default();
+                }
+                position
+            }
+        };
+
+        match position {
+            Some(position) if position != self.position => {
+                self.position = position;
+                self.props.onchange_signal.emit(position);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.position != props.position {
+                self.position = props.position.clamp(0.0, 100.0);
+            }
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("compare-slider")
+                    .semantic(if self.props.vertical { "vertical" } else { "horizontal" })
+                    .state("dragging", self.dragging)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onpointerdown=self.link.callback(Msg::DragStart)
+                onpointermove=self.link.callback(Msg::Drag)
+                onpointerup=self.link.callback(|_| Msg::DragEnd)
+                onpointercancel=self.link.callback(|_| Msg::DragEnd)
+            >
+                <img
+                    class="compare-slider-after"
+                    src=self.props.after_src.clone()
+                    alt=self.props.after_alt.clone()
+                    draggable="false"
+                />
+                <div
+                    class="compare-slider-before"
+                    style=format!("clip-path: {};", get_clip_path(self.position, self.props.vertical))
+                >
+                    <img
+                        src=self.props.before_src.clone()
+                        alt=self.props.before_alt.clone()
+                        draggable="false"
+                    />
+                </div>
+                {get_label(&self.props.before_label, "before")}
+                {get_label(&self.props.after_label, "after")}
+                <div
+                    class="compare-slider-divider"
+                    style=format!("{}: {}%;", if self.props.vertical { "top" } else { "left" }, self.position)
+                    role="slider"
+                    tabindex="0"
+                    aria-label=get_messages().compare_slider
+                    aria-orientation=if self.props.vertical { "vertical" } else { "horizontal" }
+                    aria-valuemin="0"
+                    aria-valuemax="100"
+                    aria-valuenow=self.position.round().to_string()
+                    onkeydown=self.link.callback(Msg::Pressed)
+                >
+                    <span class="compare-slider-handle"></span>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl CompareSlider {
+    fn get_pointer_position(&self, pointer_event: &PointerEvent) -> Option<f64> {
+        let rect = self
+            .props
+            .code_ref
+            .cast::<Element>()?
+            .get_bounding_client_rect();
+
+        Some(if self.props.vertical {
+            get_divider_position(pointer_event.client_y() as f64 - rect.top(), rect.height())
+        } else {
+            get_divider_position(pointer_event.client_x() as f64 - rect.left(), rect.width())
+        })
+    }
+}
+
+fn get_label(label: &str, side: &str) -> Html {
+    if label.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <span class=classes!("compare-slider-label", side.to_string()) aria-hidden="true">
+            {label}
+        </span>
+    }
+}
+
+fn get_divider_position(offset: f64, size: f64) -> f64 {
+    if size <= 0.0 {
+        return 0.0;
+    }
+
+    (offset / size * 100.0).clamp(0.0, 100.0)
+}
+
+fn get_keyboard_position(key: &str, position: f64, step: f64, vertical: bool) -> Option<f64> {
+    let next = match key {
+        "ArrowLeft" if !vertical => position - step,
+        "ArrowUp" if vertical => position - step,
+        "ArrowRight" if !vertical => position + step,
+        "ArrowDown" if vertical => position + step,
+        "Home" => 0.0,
+        "End" => 100.0,
+        _ => return None,
+    };
+
+    Some(next.clamp(0.0, 100.0))
+}
+
+fn get_clip_path(position: f64, vertical: bool) -> String {
+    if vertical {
+        format!("inset(0 0 {}% 0)", 100.0 - position)
+    } else {
+        format!("inset(0 {}% 0 0)", 100.0 - position)
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_compare_slider() {
+    let props = Props::builder()
+        .after_alt("after".to_string())
+        .after_label("After".to_string())
+        .after_src("/slide_2.jpg".to_string())
+        .before_alt("before".to_string())
+        .before_label("Before".to_string())
+        .before_src("/slide_1.jpg".to_string())
+        .id("compare-slider-test".to_string())
+        .position(30.0)
+        .build();
+
+    let compare_slider: App<CompareSlider> = App::new();
+    compare_slider.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let divider = utils::document()
+        .get_element_by_id("compare-slider-test")
+        .unwrap()
+        .get_elements_by_class_name("compare-slider-divider")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(divider.get_attribute("aria-valuenow").unwrap(), "30");
+    assert_eq!(divider.get_attribute("style").unwrap(), "left: 30%;");
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_divider_position() {
+    assert_eq!(get_divider_position(50.0, 200.0), 25.0);
+    assert_eq!(get_divider_position(-10.0, 200.0), 0.0);
+    assert_eq!(
+        get_keyboard_position("ArrowRight", 50.0, 5.0, false),
+        Some(55.0)
+    );
+    assert_eq!(
+        get_keyboard_position("ArrowLeft", 2.0, 5.0, false),
+        Some(0.0)
+    );
+    assert_eq!(get_keyboard_position("ArrowDown", 50.0, 5.0, false), None);
+    assert_eq!(get_keyboard_position("End", 50.0, 5.0, true), Some(100.0));
+    assert_eq!(get_clip_path(30.0, false), "inset(0 70% 0 0)");
+}
@@ -0,0 +1,479 @@
+use crate::layers::{acquire_layer, Layer};
+use crate::styles::animations::prefers_reduced_motion;
+use crate::styles::ComponentClasses;
+use crate::styles::Palette;
+use crate::theme::get_palette_color;
+use rand::{thread_rng, Rng};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use stylist::{css, StyleSource};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+use yew::prelude::*;
+use yew::services::render::{RenderService, RenderTask};
+use yew::utils;
+
+// px/s² and fraction of the speed lost each second
+const GRAVITY: f64 = 900.0;
+const DRAG: f64 = 1.2;
+
+thread_local! {
+    static HOSTS: RefCell<HashMap<u32, Callback<Option<(f64, f64)>>>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u32> = Cell::new(0);
+}
+
+/// # Confetti component
+///
+/// Burst of confetti drawn in a canvas over the page for success moments, like a completed
+/// onboarding or a purchase. The burst is fired with the `ConfettiHandle` passed to the component,
+/// so it can be fired from any place which has a clone of the handle.
+/// The canvas doesn't catch the pointer events and when the motion is reduced nothing is drawn
+///
+/// ## Features required
+///
+/// confetti
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::button::Button;
+/// use yew_styles::confetti::{Confetti, ConfettiHandle};
+///
+/// pub struct Checkout {
+///     link: ComponentLink<Self>,
+///     confetti: ConfettiHandle,
+/// }
+///
+/// pub enum Msg {
+///     Pay,
+/// }
+///
+/// impl Component for Checkout {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             confetti: ConfettiHandle::new(),
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Pay => self.confetti.fire(),
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Button onclick_signal=self.link.callback(|_| Msg::Pay)>{"Pay"}</Button>
+///                 <Confetti handle=self.confetti.clone() particle_count=200 />
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Confetti {
+    link: ComponentLink<Self>,
+    props: Props,
+    particles: Vec<Particle>,
+    last_frame: Option<f64>,
+    render_task: Option<RenderTask>,
+    layer: Option<Layer>,
+}
+
+/// Fires the bursts of the `Confetti` component which receives it
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConfettiHandle {
+    id: u32,
+}
+
+impl ConfettiHandle {
+    pub fn new() -> Self {
+        let id = NEXT_ID.with(|next_id| {
+            next_id.set(next_id.get() + 1);
+            next_id.get()
+        });
+
+        Self { id }
+    }
+
+    /// Fire a burst from the `origin` of the component
+    pub fn fire(&self) {
+        self.send(None);
+    }
+
+    /// Fire a burst from a point of the viewport, `x` and `y` from `0.0` to `1.0`,
+    /// e.g. from the center of the button which was clicked
+    pub fn fire_from(&self, x: f64, y: f64) {
+        self.send(Some((x, y)));
+    }
+
+    fn send(&self, origin: Option<(f64, f64)>) {
+        let host = HOSTS.with(|hosts| hosts.borrow().get(&self.id).cloned());
+
+        if let Some(fire) = host {
+            fire.emit(origin);
+        }
+    }
+}
+
+impl Default for ConfettiHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Handle which fires the bursts. Required
+    pub handle: ConfettiHandle,
+    /// Number of pieces of each burst. Default `150`
+    #[prop_or(150)]
+    pub particle_count: usize,
+    /// Milliseconds until the pieces of a burst disappear. Default `3000`
+    #[prop_or(3000)]
+    pub duration: u32,
+    /// Point of the viewport where the bursts start, `x` and `y` from `0.0` to `1.0`. Default `(0.5, 0.5)`
+    #[prop_or((0.5, 0.5))]
+    pub origin: (f64, f64),
+    /// Degrees of the cone where the pieces are thrown upwards. Default `90.0`
+    #[prop_or(90.0)]
+    pub spread: f64,
+    /// Palettes which give the colors of the pieces.
+    /// Default `Primary`, `Secondary`, `Success`, `Info`, `Warning` and `Danger`
+    #[prop_or(vec![
+        Palette::Primary,
+        Palette::Secondary,
+        Palette::Success,
+        Palette::Info,
+        Palette::Warning,
+        Palette::Danger,
+    ])]
+    pub palettes: Vec<Palette>,
+    /// Emitted when the last piece disappears
+    #[prop_or(Callback::noop())]
+    pub onend_signal: Callback<()>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+}
+
+pub enum Msg {
+    Fire(Option<(f64, f64)>),
+    Frame(f64),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Particle {
+    x: f64,
+    y: f64,
+    velocity_x: f64,
+    velocity_y: f64,
+    rotation: f64,
+    spin: f64,
+    width: f64,
+    color: String,
+    age: f64,
+}
+
+impl Particle {
+    /// Move the piece `elapsed` seconds
+    fn step(&mut self, elapsed: f64) {
+        let drag = (1.0 - DRAG * elapsed).max(0.0);
+
+        self.velocity_x *= drag;
+        self.velocity_y = self.velocity_y * drag + GRAVITY * elapsed;
+        self.x += self.velocity_x * elapsed;
+        self.y += self.velocity_y * elapsed;
+        self.rotation += self.spin * elapsed;
+        self.age += elapsed * 1000.0;
+    }
+}
+
+impl Component for Confetti {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        register_host(&props.handle, link.callback(Msg::Fire));
+
+        Self {
+            link,
+            props,
+            particles: vec![],
+            last_frame: None,
+            render_task: None,
+            layer: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Fire(origin) => {
+                if prefers_reduced_motion() {
+                    self.props.onend_signal.emit(());
+                    return false;
+                }
+
+                let was_idle = self.layer.is_none();
+
+                self.fire(origin.unwrap_or(self.props.origin));
+
+                if self.render_task.is_none() {
+                    self.request_frame();
+                }
+
+                was_idle
+            }
+            Msg::Frame(timestamp) => {
+                let elapsed = self
+                    .last_frame
+                    .map(|last_frame| ((timestamp - last_frame) / 1000.0).min(0.05))
+                    .unwrap_or(0.0);
+                let duration = self.props.duration as f64;
+                let bottom = utils::window()
+                    .inner_height()
+                    .ok()
+                    .and_then(|height| height.as_f64())
+                    .unwrap_or(0.0);
+
+                self.last_frame = Some(timestamp);
+                self.particles
+                    .iter_mut()
+                    .for_each(|particle| particle.step(elapsed));
+                self.particles
+                    .retain(|particle| particle.age < duration && particle.y < bottom + 20.0);
+                self.draw();
+
+                if self.particles.is_empty() {
+                    self.render_task = None;
+                    self.last_frame = None;
+                    self.layer = None;
+                    self.props.onend_signal.emit(());
+                    true
+                } else {
+                    self.request_frame();
+                    false
+                }
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.handle != props.handle {
+                unregister_host(&self.props.handle);
+                register_host(&props.handle, self.link.callback(Msg::Fire));
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <canvas
+                class=ComponentClasses::new("confetti")
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                style=self.layer.as_ref().map(|layer| layer.style())
+                aria-hidden="true"
+            />
+        }
+    }
+
+    fn destroy(&mut self) {
+        unregister_host(&self.props.handle);
+    }
+}
+
+impl Confetti {
+    fn fire(&mut self, origin: (f64, f64)) {
+        let window = utils::window();
+        let width = window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or(0.0);
+        let height = window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or(0.0);
+
+        if self.layer.is_none() {
+            self.layer = Some(acquire_layer());
+            self.resize_canvas(width, height);
+        }
+
+        let colors: Vec<String> = self.props.palettes.iter().map(get_palette_color).collect();
+        let spread = self.props.spread.to_radians();
+        let mut rng = thread_rng();
+
+        for index in 0..self.props.particle_count {
+            let angle = -PI / 2.0 + rng.gen_range(-0.5..=0.5) * spread;
+            let speed = rng.gen_range(400.0..900.0);
+
+            self.particles.push(Particle {
+                x: origin.0 * width,
+                y: origin.1 * height,
+                velocity_x: angle.cos() * speed,
+                velocity_y: angle.sin() * speed,
+                rotation: rng.gen_range(0.0..PI),
+                spin: rng.gen_range(-8.0..8.0),
+                width: rng.gen_range(6.0..12.0),
+                color: colors
+                    .get(index % colors.len().max(1))
+                    .cloned()
+                    .unwrap_or_default(),
+                age: 0.0,
+            });
+        }
+    }
+
+    fn request_frame(&mut self) {
+        self.render_task = Some(RenderService::request_animation_frame(
+            self.link.callback(Msg::Frame),
+        ));
+    }
+
+    fn resize_canvas(&self, width: f64, height: f64) {
+        if let Some(canvas) = self.props.code_ref.cast::<HtmlCanvasElement>() {
+            let pixel_ratio = utils::window().device_pixel_ratio();
+
+            canvas.set_width((width * pixel_ratio) as u32);
+            canvas.set_height((height * pixel_ratio) as u32);
+
+            if let Some(context) = get_context(&canvas) {
+                context
+                    .set_transform(pixel_ratio, 0.0, 0.0, pixel_ratio, 0.0, 0.0)
+                    .ok();
+            }
+        }
+    }
+
+    fn draw(&self) {
+        let canvas = match self.props.code_ref.cast::<HtmlCanvasElement>() {
+            Some(canvas) => canvas,
+            None => return,
+        };
+        let context = match get_context(&canvas) {
+            Some(context) => context,
+            None => return,
+        };
+        let duration = self.props.duration as f64;
+
+        context.clear_rect(0.0, 0.0, canvas.width() as f64, canvas.height() as f64);
+
+        for particle in &self.particles {
+            context.save();
+            context.translate(particle.x, particle.y).ok();
+            context.rotate(particle.rotation).ok();
+            context.set_global_alpha(get_opacity(particle.age, duration));
+            context.set_fill_style(&JsValue::from_str(&particle.color));
+            context.fill_rect(
+                -particle.width / 2.0,
+                -particle.width / 4.0,
+                particle.width,
+                particle.width / 2.0,
+            );
+            context.restore();
+        }
+    }
+}
+
+fn get_context(canvas: &HtmlCanvasElement) -> Option<CanvasRenderingContext2d> {
+    canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok())
+}
+
+/// The pieces fade out in the last third of the duration
+fn get_opacity(age: f64, duration: f64) -> f64 {
+    let fade_start = duration * 2.0 / 3.0;
+
+    if age <= fade_start {
+        1.0
+    } else {
+        (1.0 - (age - fade_start) / (duration - fade_start)).max(0.0)
+    }
+}
+
+fn register_host(handle: &ConfettiHandle, fire: Callback<Option<(f64, f64)>>) {
+    HOSTS.with(|hosts| hosts.borrow_mut().insert(handle.id, fire));
+}
+
+fn unregister_host(handle: &ConfettiHandle) {
+    HOSTS.with(|hosts| hosts.borrow_mut().remove(&handle.id));
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_different_handles() {
+    let handle = ConfettiHandle::new();
+
+    assert_ne!(handle, ConfettiHandle::new());
+    assert_eq!(handle, handle.clone());
+}
+
+#[wasm_bindgen_test]
+fn should_throw_the_pieces_up_and_let_them_fall() {
+    let mut particle = Particle {
+        x: 100.0,
+        y: 100.0,
+        velocity_x: 0.0,
+        velocity_y: -600.0,
+        rotation: 0.0,
+        spin: 1.0,
+        width: 8.0,
+        color: "#654016".to_string(),
+        age: 0.0,
+    };
+
+    particle.step(0.05);
+    assert!(particle.y < 100.0);
+
+    (0..40).for_each(|_| particle.step(0.05));
+    assert!(particle.y > 100.0);
+    assert_eq!(particle.age.round(), 2050.0);
+}
+
+#[wasm_bindgen_test]
+fn should_fade_out_at_the_end() {
+    assert_eq!(get_opacity(1000.0, 3000.0), 1.0);
+    assert_eq!(get_opacity(2500.0, 3000.0), 0.5);
+    assert_eq!(get_opacity(3000.0, 3000.0), 0.0);
+}
@@ -0,0 +1,255 @@
+use crate::button::Button;
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, Palette, Size, Style};
+use crate::utils::copy_to_clipboard_with_result;
+use std::time::Duration;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::{utils, App};
+
+/// # Copy Button component
+///
+/// Button which writes the text in the clipboard with the async clipboard api. After the
+/// clipboard resolves the write, the button shows the copied or the failed message for a
+/// moment and emits `oncopy_signal` or `onerror_signal`
+///
+/// ## Features required
+///
+/// copy_button
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::services::ConsoleService;
+/// use yew_styles::copy_button::CopyButton;
+/// use yew_styles::styles::{Palette, Style};
+///
+/// pub struct ApiKey {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Copied(String),
+///     NotCopied(String),
+/// }
+///
+/// impl Component for ApiKey {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Copied(text) => ConsoleService::log(&format!("Copied {}", text)),
+///             Msg::NotCopied(text) => ConsoleService::error(&format!("{} not copied", text)),
+///         }
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <CopyButton
+///                 text="sk_live_51H8"
+///                 button_palette=Palette::Info
+///                 button_style=Style::Outline
+///                 oncopy_signal=self.link.callback(Msg::Copied)
+///                 onerror_signal=self.link.callback(Msg::NotCopied)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct CopyButton {
+    link: ComponentLink<Self>,
+    props: Props,
+    status: CopyStatus,
+    feedback_task: Option<TimeoutTask>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CopyStatus {
+    Idle,
+    Copied,
+    Failed,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Text written in the clipboard. Required
+    pub text: String,
+    /// Label of the button, by default the copy message. Default `None`
+    #[prop_or_default]
+    pub label: Option<String>,
+    /// Milliseconds the copied or failed message is shown. Default `1500`
+    #[prop_or(1500)]
+    pub feedback_duration: u64,
+    /// Emitted with the text when it is written in the clipboard
+    #[prop_or(Callback::noop())]
+    pub oncopy_signal: Callback<String>,
+    /// Emitted with the text when the clipboard rejects it or it is not available
+    #[prop_or(Callback::noop())]
+    pub onerror_signal: Callback<String>,
+    /// Type botton purpose style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub button_palette: Palette,
+    /// Button styles. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub button_style: Style,
+    /// Three diffent button sizes. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub button_size: Size,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Copy,
+    Copied(bool),
+    ResetFeedback,
+}
+
+impl Component for CopyButton {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            status: CopyStatus::Idle,
+            feedback_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Copy => {
+                copy_to_clipboard_with_result(&self.props.text, self.link.callback(Msg::Copied));
+                return false;
+            }
+            Msg::Copied(copied) => {
+                if copied {
+                    self.status = CopyStatus::Copied;
+                    self.props.oncopy_signal.emit(self.props.text.clone());
+                } else {
+                    self.status = CopyStatus::Failed;
+                    self.props.onerror_signal.emit(self.props.text.clone());
+                }
+
+                self.feedback_task = Some(TimeoutService::spawn(
+                    Duration::from_millis(self.props.feedback_duration),
+                    self.link.callback(|_| Msg::ResetFeedback),
+                ));
+            }
+            Msg::ResetFeedback => {
+                self.status = CopyStatus::Idle;
+                self.feedback_task = None;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+        let label = match self.status {
+            CopyStatus::Idle => self.props.label.clone().unwrap_or(messages.copy),
+            CopyStatus::Copied => messages.copied,
+            CopyStatus::Failed => messages.copy_failed,
+        };
+        let status_class = match self.status {
+            CopyStatus::Idle => "",
+            CopyStatus::Copied => "copied",
+            CopyStatus::Failed => "failed",
+        };
+
+        html! {
+            <Button
+                class_name=format!("copy-button {} {}", status_class, self.props.class_name)
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                code_ref=self.props.code_ref.clone()
+                button_palette=self.props.button_palette.clone()
+                button_style=self.props.button_style.clone()
+                button_size=self.props.button_size.clone()
+                styles=self.props.styles.clone()
+                margin=self.props.margin
+                padding=self.props.padding
+                onclick_signal=self.link.callback(|_| Msg::Copy)
+            >
+                <span aria-live="polite">{label}</span>
+            </Button>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_feedback_of_the_copy() {
+    let props = Props::builder()
+        .id("copy-button-test".to_string())
+        .label(Some("Copy key".to_string()))
+        .text("sk_live_51H8".to_string())
+        .build();
+
+    let copy_button: App<CopyButton> = App::new();
+    let link = copy_button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let copy_button_element = utils::document()
+        .get_element_by_id("copy-button-test")
+        .unwrap();
+
+    assert_eq!(copy_button_element.text_content().unwrap(), "Copy key");
+
+    link.send_message(Msg::Copied(true));
+
+    assert_eq!(copy_button_element.text_content().unwrap(), "Copied");
+    assert!(copy_button_element.class_list().contains("copied"));
+
+    link.send_message(Msg::Copied(false));
+
+    assert_eq!(copy_button_element.text_content().unwrap(), "Copy failed");
+    assert!(copy_button_element.class_list().contains("failed"));
+}
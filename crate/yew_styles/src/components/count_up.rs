@@ -0,0 +1,325 @@
+use crate::styles::animations::{ease, prefers_reduced_motion, Easing};
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use std::rc::Rc;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::services::render::{RenderService, RenderTask};
+use yew::{utils, App};
+
+/// # Count up component
+///
+/// Number which counts from the previous value to the new one when it changes,
+/// e.g. in stats and metrics. The screen readers only read the final number, and when
+/// the motion is reduced the number changes at once
+///
+/// ## Features required
+///
+/// count_up
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::count_up::{CountUp, Formatter};
+/// use yew_styles::styles::animations::Easing;
+///
+/// pub struct Revenue {
+///     total: f64,
+///     currency: String,
+/// }
+///
+/// impl Component for Revenue {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self {
+///             total: 12500.0,
+///             currency: "€".to_string(),
+///         }
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         let currency = self.currency.clone();
+///
+///         html! {
+///             <CountUp
+///                 value=self.total
+///                 duration=2000
+///                 easing=Easing::Emphasized
+///                 formatter=Formatter::new(move |value| format!("{:.2} {}", value, currency))
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct CountUp {
+    link: ComponentLink<Self>,
+    props: Props,
+    current: f64,
+    from: f64,
+    started_at: Option<f64>,
+    render_task: Option<RenderTask>,
+}
+
+/// Format of the number, the closure can capture the state of the parent component, e.g. the currency
+#[derive(Clone)]
+pub struct Formatter(Rc<dyn Fn(f64) -> String>);
+
+impl Formatter {
+    pub fn new(format: impl Fn(f64) -> String + 'static) -> Self {
+        Formatter(Rc::new(format))
+    }
+
+    pub fn format(&self, value: f64) -> String {
+        (self.0)(value)
+    }
+}
+
+impl PartialEq for Formatter {
+    fn eq(&self, other: &Formatter) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Number to show. Required
+    pub value: f64,
+    /// Number where the count starts when the component is shown. Default `0.0`
+    #[prop_or(0.0)]
+    pub from: f64,
+    /// Count from `from` when the component is shown instead of showing the value. Default `true`
+    #[prop_or(true)]
+    pub animate_on_mount: bool,
+    /// Duration of the count in milliseconds. Default `1000`
+    #[prop_or(1000)]
+    pub duration: u32,
+    /// Timing function of the count. Default `Easing::EaseOut`
+    #[prop_or(Easing::EaseOut)]
+    pub easing: Easing,
+    /// Number of decimals when there is no formatter. Default `0`
+    #[prop_or(0)]
+    pub decimals: usize,
+    /// Format the number, e.g. to add the currency or the thousands separator. Default `None`
+    #[prop_or_default]
+    pub formatter: Option<Formatter>,
+    /// Emitted with the value when the count ends
+    #[prop_or(Callback::noop())]
+    pub onend_signal: Callback<f64>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Frame(f64),
+}
+
+impl Component for CountUp {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let current = if props.animate_on_mount {
+            props.from
+        } else {
+            props.value
+        };
+
+        Self {
+            link,
+            props,
+            current,
+            from: current,
+            started_at: None,
+            render_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Frame(timestamp) => {
+                let started_at = *self.started_at.get_or_insert(timestamp);
+                let progress = get_progress(timestamp - started_at, self.props.duration);
+
+                self.current =
+                    self.from + (self.props.value - self.from) * ease(self.props.easing, progress);
+
+                if progress < 1.0 {
+                    self.request_frame();
+                } else {
+                    self.end();
+                }
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let value_changed = self.props.value != props.value;
+            self.props = props;
+
+            if value_changed {
+                self.start();
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render && self.current != self.props.value {
+            self.start();
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <span
+                class=ComponentClasses::new("count-up")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <span class="count-up-current" aria-hidden="true">{self.format(self.current)}</span>
+                <span class="count-up-value">{self.format(self.props.value)}</span>
+            </span>
+        }
+    }
+}
+
+impl CountUp {
+    fn start(&mut self) {
+        if prefers_reduced_motion() || self.props.duration == 0 {
+            self.end();
+            return;
+        }
+
+        self.from = self.current;
+        self.started_at = None;
+        self.request_frame();
+    }
+
+    fn request_frame(&mut self) {
+        self.render_task = Some(RenderService::request_animation_frame(
+            self.link.callback(Msg::Frame),
+        ));
+    }
+
+    fn end(&mut self) {
+        self.render_task = None;
+        self.current = self.props.value;
+        self.props.onend_signal.emit(self.props.value);
+    }
+
+    fn format(&self, value: f64) -> String {
+        match &self.props.formatter {
+            Some(formatter) => formatter.format(value),
+            None => format!("{:.*}", self.props.decimals, value),
+        }
+    }
+}
+
+fn get_progress(elapsed: f64, duration: u32) -> f64 {
+    if duration == 0 {
+        1.0
+    } else {
+        (elapsed / duration as f64).clamp(0.0, 1.0)
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_value_without_animation_on_mount() {
+    let props = Props::builder()
+        .animate_on_mount(false)
+        .decimals(1)
+        .id("count-up-test".to_string())
+        .value(42.5)
+        .build();
+
+    let count_up: App<CountUp> = App::new();
+    count_up.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let current = utils::document()
+        .get_elements_by_class_name("count-up-current")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(current.text_content().unwrap(), "42.5");
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_progress_of_the_count() {
+    assert_eq!(get_progress(500.0, 1000), 0.5);
+    assert_eq!(get_progress(1500.0, 1000), 1.0);
+    assert_eq!(get_progress(10.0, 0), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn should_format_the_value_with_the_captured_state() {
+    let currency = String::from("€");
+    let props = Props::builder()
+        .animate_on_mount(false)
+        .formatter(Formatter::new(move |value| {
+            format!("{:.2} {}", value, currency)
+        }))
+        .id("count-up-formatter-test".to_string())
+        .value(1250.0)
+        .build();
+
+    let count_up: App<CountUp> = App::new();
+    count_up.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let current = utils::document()
+        .get_element_by_id("count-up-formatter-test")
+        .unwrap()
+        .get_elements_by_class_name("count-up-current")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(current.text_content().unwrap(), "1250.00 €");
+}
@@ -0,0 +1,394 @@
+use crate::messages::{format_message, get_messages};
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Document viewer component
+///
+/// Long documents like terms of service, privacy policies or handbooks: a table of contents
+/// with the sections, which highlights the section being read, the content with the typography
+/// styles, a bar with the reading progress at the top and a button to print the document.
+/// The content scrolls inside of the viewer, when it is printed only the content is shown
+///
+/// ## Features required
+///
+/// document_viewer
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::document_viewer::{DocumentSection, DocumentViewer};
+///
+/// pub struct Terms;
+///
+/// impl Component for Terms {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <DocumentViewer
+///                 title="Terms of service"
+///                 updated="June 1, 2021"
+///                 sections=vec![
+///                     DocumentSection {
+///                         id: "acceptance".to_string(),
+///                         title: "Acceptance of the terms".to_string(),
+///                         content: html! {<p>{"By using the service you accept these terms."}</p>},
+///                     },
+///                     DocumentSection {
+///                         id: "privacy".to_string(),
+///                         title: "Privacy".to_string(),
+///                         content: html! {<p>{"Your data is never sold."}</p>},
+///                     },
+///                 ]
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct DocumentViewer {
+    link: ComponentLink<Self>,
+    props: Props,
+    content_ref: NodeRef,
+    active: usize,
+    progress: f64,
+}
+
+/// Section of the document
+#[derive(Clone, PartialEq, Debug)]
+pub struct DocumentSection {
+    /// Id of the section heading, used by the links of the table of contents
+    pub id: String,
+    pub title: String,
+    pub content: Html,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Sections of the document. Required
+    pub sections: Vec<DocumentSection>,
+    /// Title of the document. Default `None`
+    #[prop_or_default]
+    pub title: Option<String>,
+    /// Date of the last update of the document. Default `None`
+    #[prop_or_default]
+    pub updated: Option<String>,
+    /// Show the reading progress bar. Default `true`
+    #[prop_or(true)]
+    pub show_progress: bool,
+    /// Show the print button. Default `true`
+    #[prop_or(true)]
+    pub printable: bool,
+    /// Emitted with the id of the section being read when it changes
+    #[prop_or(Callback::noop())]
+    pub onsection_signal: Callback<String>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Scrolled,
+    Print,
+}
+
+impl Component for DocumentViewer {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            content_ref: NodeRef::default(),
+            active: 0,
+            progress: 0.0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scrolled => {
+                let content = match self.content_ref.cast::<HtmlElement>() {
+                    Some(content) => content,
+                    None => return false,
+                };
+
+                let offsets: Vec<i32> = self
+                    .props
+                    .sections
+                    .iter()
+                    .map(|section| {
+                        utils::document()
+                            .get_element_by_id(&section.id)
+                            .and_then(|heading| heading.dyn_into::<HtmlElement>().ok())
+                            .map(|heading| heading.offset_top())
+                            .unwrap_or(0)
+                    })
+                    .collect();
+
+                let active = get_active_section(&offsets, content.scroll_top());
+                let progress = get_progress(
+                    content.scroll_top(),
+                    content.scroll_height(),
+                    content.client_height(),
+                );
+
+                if active != self.active {
+                    if let Some(section) = self.props.sections.get(active) {
+                        self.props.onsection_signal.emit(section.id.clone());
+                    }
+                }
+
+                if active == self.active && (progress - self.progress).abs() < f64::EPSILON {
+                    return false;
+                }
+
+                self.active = active;
+                self.progress = progress;
+            }
+            Msg::Print => {
+                // Printing is blocked in sandboxed iframes without allow-modals
+                utils::window().print().ok();
+                return false;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.sections.len() != props.sections.len() {
+                self.active = 0;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            // The content can be shorter than the viewer, then it is read without scrolling
+            self.link.send_message(Msg::Scrolled);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+        let progress = (self.progress * 100.0).round();
+
+        html! {
+            <div
+                class=ComponentClasses::new("document-viewer")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <nav class="document-viewer-nav" aria-label=messages.table_of_contents.clone()>
+                    <ol>
+                        {for self.props.sections.iter().enumerate().map(|(index, section)| html! {
+                            <li>
+                                <a
+                                    class=classes!("document-viewer-link", if index == self.active { Some("active") } else { None })
+                                    href=format!("#{}", section.id)
+                                    aria-current=if index == self.active { Some("location") } else { None }
+                                >
+                                    {section.title.clone()}
+                                </a>
+                            </li>
+                        })}
+                    </ol>
+                </nav>
+                <div class="document-viewer-main">
+                    <div class="document-viewer-header">
+                        <div>
+                            {if let Some(title) = self.props.title.clone() {
+                                html! {
+                                    <h1 class="document-viewer-title">{title}</h1>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                            {if let Some(updated) = self.props.updated.clone() {
+                                html! {
+                                    <p class="document-viewer-updated">
+                                        {format_message(&messages.last_updated, &[("date", updated)])}
+                                    </p>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+                        {if self.props.printable {
+                            html! {
+                                <button
+                                    class="document-viewer-print"
+                                    type="button"
+                                    onclick=self.link.callback(|_| Msg::Print)
+                                >
+                                    {messages.print.clone()}
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }}
+                    </div>
+                    {if self.props.show_progress {
+                        html! {
+                            <div
+                                class="document-viewer-progress"
+                                role="progressbar"
+                                aria-label=messages.reading_progress.clone()
+                                aria-valuemin="0"
+                                aria-valuemax="100"
+                                aria-valuenow=progress.to_string()
+                            >
+                                <div class="document-viewer-progress-value" style=format!("width: {}%", progress)></div>
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                    <div
+                        class="document-viewer-content"
+                        ref=self.content_ref.clone()
+                        onscroll=self.link.callback(|_| Msg::Scrolled)
+                    >
+                        {for self.props.sections.iter().map(|section| html! {
+                            <section class="document-viewer-section">
+                                <h2 id=section.id.clone()>{section.title.clone()}</h2>
+                                {section.content.clone()}
+                            </section>
+                        })}
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+/// Index of the last section whose heading is above the scroll position
+pub fn get_active_section(offsets: &[i32], scroll_top: i32) -> usize {
+    offsets
+        .iter()
+        .rposition(|offset| *offset <= scroll_top)
+        .unwrap_or(0)
+}
+
+/// Fraction of the content already read, from `0.0` to `1.0`
+pub fn get_progress(scroll_top: i32, scroll_height: i32, client_height: i32) -> f64 {
+    let scrollable = scroll_height - client_height;
+
+    if scrollable <= 0 {
+        return 1.0;
+    }
+
+    (scroll_top as f64 / scrollable as f64).min(1.0).max(0.0)
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_the_table_of_contents() {
+    let props = Props::builder()
+        .id("document-viewer-test".to_string())
+        .sections(vec![
+            DocumentSection {
+                id: "document-viewer-first-test".to_string(),
+                title: "First".to_string(),
+                content: html! {<p>{"First section"}</p>},
+            },
+            DocumentSection {
+                id: "document-viewer-second-test".to_string(),
+                title: "Second".to_string(),
+                content: html! {<p>{"Second section"}</p>},
+            },
+        ])
+        .title(Some("Terms".to_string()))
+        .build();
+
+    let document_viewer: App<DocumentViewer> = App::new();
+    document_viewer.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let document_viewer_element = utils::document()
+        .get_element_by_id("document-viewer-test")
+        .unwrap();
+    let links = document_viewer_element.get_elements_by_class_name("document-viewer-link");
+
+    assert_eq!(links.length(), 2);
+    assert_eq!(
+        links.get_with_index(1).unwrap().get_attribute("href"),
+        Some("#document-viewer-second-test".to_string())
+    );
+    assert_eq!(
+        links
+            .get_with_index(0)
+            .unwrap()
+            .get_attribute("aria-current"),
+        Some("location".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_active_section() {
+    let offsets = vec![0, 400, 900];
+
+    assert_eq!(get_active_section(&offsets, 0), 0);
+    assert_eq!(get_active_section(&offsets, 450), 1);
+    assert_eq!(get_active_section(&offsets, 2000), 2);
+    assert_eq!(get_active_section(&[], 100), 0);
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_reading_progress() {
+    assert_eq!(get_progress(0, 1000, 500), 0.0);
+    assert_eq!(get_progress(250, 1000, 500), 0.5);
+    assert_eq!(get_progress(0, 300, 500), 1.0);
+}
@@ -1,4 +1,11 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::click_away::ClickAway;
+use crate::keyboard::click_on_enter;
+use crate::styles::{
+    get_elevation, get_palette, get_size, get_style,
+    tokens::{Elevation, Spaces},
+    ComponentClasses, Palette, Size, Style,
+};
+use crate::utils::get_random_string;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -73,6 +80,7 @@ pub struct Dropdown {
     props: Props,
     active: bool,
     link: ComponentLink<Self>,
+    menu_id: String,
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -88,6 +96,9 @@ pub struct Props {
     /// Size for dropdown
     #[prop_or(Size::Medium)]
     pub dropdown_size: Size,
+    /// Shadow level of the menu. Default `Elevation::Level2`
+    #[prop_or(Elevation::Level2)]
+    pub elevation: Elevation,
     /// General property to add keys
     #[prop_or_default]
     pub key: String,
@@ -100,11 +111,19 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
 pub enum Msg {
     ShowDropdown,
+    Close,
+    Pressed(KeyboardEvent),
 }
 
 impl Component for Dropdown {
@@ -116,6 +135,7 @@ impl Component for Dropdown {
             props,
             link,
             active: false,
+            menu_id: format!("dropdown-menu-{}", get_random_string(10)),
         }
     }
 
@@ -124,6 +144,13 @@ impl Component for Dropdown {
             Msg::ShowDropdown => {
                 self.active = !self.active;
             }
+            Msg::Close => {
+                self.active = false;
+            }
+            Msg::Pressed(keyboard_event) => {
+                click_on_enter(&keyboard_event);
+                return false;
+            }
         }
         true
     }
@@ -137,23 +164,42 @@ impl Component for Dropdown {
 
     fn view(&self) -> Html {
         html! {
-            <div
-                class=classes!("dropdown", self.props.class_name.clone(), get_style(self.props.dropdown_style.clone()), get_palette(self.props.dropdown_palette.clone()), get_size(self.props.dropdown_size.clone()), self.props.styles.clone())
-                id=self.props.id.clone()
-                key=self.props.key.clone()
-                onclick=self.link.callback(|_| Msg::ShowDropdown)
-                >
-                <div class="main-content">{self.props.main_content.clone()}</div>
-                {get_items(self.active, self.props.children.clone())}
-            </div>
+            <ClickAway active=self.active onclickaway_signal=self.link.callback(|_| Msg::Close)>
+                <div
+                    class=ComponentClasses::new("dropdown")
+                        .semantic(vec![
+                            get_style(self.props.dropdown_style.clone()),
+                            get_palette(self.props.dropdown_palette.clone()),
+                            get_size(self.props.dropdown_size.clone()),
+                        ])
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
+                    id=self.props.id.clone()
+                    key=self.props.key.clone()
+                    onclick=self.link.callback(|_| Msg::ShowDropdown)
+                    >
+                    <div
+                        class="main-content"
+                        role="button"
+                        tabindex="0"
+                        aria-haspopup="menu"
+                        aria-expanded=self.active.to_string()
+                        aria-controls=self.menu_id.clone()
+                        onkeydown=self.link.callback(Msg::Pressed)
+                    >{self.props.main_content.clone()}</div>
+                    {get_items(self.active, self.menu_id.clone(), self.props.elevation, self.props.children.clone())}
+                </div>
+            </ClickAway>
         }
     }
 }
 
-fn get_items(active: bool, children: Children) -> Html {
+fn get_items(active: bool, menu_id: String, elevation: Elevation, children: Children) -> Html {
     if active {
         html! {
-            <ul>
+            <ul id=menu_id class=get_elevation(elevation) role="menu">
                 {children.clone()}
             </ul>
         }
@@ -166,19 +212,18 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_dropdown_container() {
-    let dropdown_container_props = Props {
-        main_content: html! {<div id="test">{"test"}</div>},
-        dropdown_palette: Palette::Clean,
-        dropdown_size: Size::Medium,
-        dropdown_style: Style::Outline,
-        key: String::from("dropdown-1"),
-        class_name: String::from("class-test"),
-        id: String::from("id-test"),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let dropdown_container_props = Props::builder()
+        .class_name(String::from("class-test"))
+        .dropdown_palette(Palette::Clean)
+        .dropdown_style(Style::Outline)
+        .id(String::from("id-test"))
+        .key(String::from("dropdown-1"))
+        .main_content(html! {<div id="test">{"test"}</div>})
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let dropdown_container: App<Dropdown> = App::new();
 
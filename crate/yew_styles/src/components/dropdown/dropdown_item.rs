@@ -1,5 +1,8 @@
+use crate::keyboard::{handle_roving_keydown, init_roving_tabindex, Orientation};
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
+use web_sys::Element;
 use yew::prelude::*;
 use yew::{utils, App};
 
@@ -71,6 +74,7 @@ use yew::{utils, App};
 pub struct DropdownItem {
     link: ComponentLink<Self>,
     props: Props,
+    item_ref: NodeRef,
 }
 
 #[derive(Properties, Clone, PartialEq)]
@@ -90,11 +94,18 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
 pub enum Msg {
     Clicked(MouseEvent),
+    Pressed(KeyboardEvent),
 }
 
 impl Component for DropdownItem {
@@ -102,7 +113,11 @@ impl Component for DropdownItem {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            props,
+            item_ref: NodeRef::default(),
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -110,6 +125,15 @@ impl Component for DropdownItem {
             Msg::Clicked(mouse_event) => {
                 self.props.onclick_signal.emit(mouse_event);
             }
+            Msg::Pressed(keyboard_event) => {
+                handle_roving_keydown(
+                    &keyboard_event,
+                    ".dropdown",
+                    ".dropdown-item",
+                    Orientation::Vertical,
+                );
+                return false;
+            }
         }
         true
     }
@@ -123,13 +147,28 @@ impl Component for DropdownItem {
         false
     }
 
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            if let Some(item) = self.item_ref.cast::<Element>() {
+                init_roving_tabindex(&item, ".dropdown", ".dropdown-item");
+            }
+        }
+    }
+
     fn view(&self) -> Html {
         html! {
             <li
-                class=classes!("dropdown-item", self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("dropdown-item")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
+                ref=self.item_ref.clone()
+                role="menuitem"
                 onclick=self.link.callback(Msg::Clicked)
+                onkeydown=self.link.callback(Msg::Pressed)
             >{self.props.children.clone()}</li>
         }
     }
@@ -137,16 +176,15 @@ impl Component for DropdownItem {
 
 #[wasm_bindgen_test]
 fn should_create_dropdown_item() {
-    let dropdown_item_props = Props {
-        onclick_signal: Callback::noop(),
-        key: String::from("dropdown-item-1"),
-        class_name: String::from("class-test"),
-        id: String::from("id-test"),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let dropdown_item_props = Props::builder()
+        .class_name(String::from("class-test"))
+        .id(String::from("id-test"))
+        .key(String::from("dropdown-item-1"))
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let dropdown_item: App<DropdownItem> = App::new();
 
@@ -0,0 +1,262 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Embed component
+///
+/// Iframe for third-party content like maps, videos or forms, which keeps its aspect ratio
+/// and is loaded only when it is close to the viewport. With `consent` a placeholder is shown
+/// instead of the iframe until the user accepts to load the content
+///
+/// ## Features required
+///
+/// embed
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::embed::Embed;
+///
+/// pub struct Location;
+///
+/// impl Component for Location {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Embed
+///                 src="https://www.openstreetmap.org/export/embed.html?bbox=-0.1,51.5,-0.09,51.51"
+///                 title="Office location"
+///                 aspect_ratio=Some((4, 3))
+///                 sandbox=Some("allow-scripts allow-same-origin".to_string())
+///                 consent=true
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct Embed {
+    link: ComponentLink<Self>,
+    props: Props,
+    accepted: bool,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Url of the content. Required
+    pub src: String,
+    /// Title of the iframe for the screen readers. Required
+    pub title: String,
+    /// Width and height proportion, `None` leaves the size to the styles. Default `Some((16, 9))`
+    #[prop_or(Some((16, 9)))]
+    pub aspect_ratio: Option<(u32, u32)>,
+    /// Load the iframe only when it is close to the viewport. Default `true`
+    #[prop_or(true)]
+    pub lazy: bool,
+    /// Restrictions of the iframe, e.g. `"allow-scripts allow-same-origin"`. `Some("")` applies all of them,
+    /// `None` none of them. Default `None`
+    #[prop_or_default]
+    pub sandbox: Option<String>,
+    /// Permissions policy of the iframe, e.g. `"autoplay; fullscreen; geolocation"`. Default `""`
+    #[prop_or_default]
+    pub allow: String,
+    /// Referrer sent to the third party. Default `"strict-origin-when-cross-origin"`
+    #[prop_or_else(|| "strict-origin-when-cross-origin".to_string())]
+    pub referrer_policy: String,
+    /// Show a placeholder and load the iframe after the user clicks the load button. Default `false`
+    #[prop_or(false)]
+    pub consent: bool,
+    /// Content of the placeholder, a text about the third party by default. Default `None`
+    #[prop_or_default]
+    pub placeholder: Option<Html>,
+    /// Emitted when the user accepts to load the content
+    #[prop_or(Callback::noop())]
+    pub onconsent_signal: Callback<()>,
+    /// Emitted when the iframe is loaded
+    #[prop_or(Callback::noop())]
+    pub onload_signal: Callback<()>,
+    /// Emitted when the iframe cannot be loaded
+    #[prop_or(Callback::noop())]
+    pub onerror_signal: Callback<()>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Accept,
+    Loaded,
+    Failed,
+}
+
+impl Component for Embed {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            accepted: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Accept => {
+                self.accepted = true;
+                self.props.onconsent_signal.emit(());
+                return true;
+            }
+            Msg::Loaded => {
+                self.props.onload_signal.emit(());
+            }
+            Msg::Failed => {
+                self.props.onerror_signal.emit(());
+            }
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("embed")
+                    .state("aspect", self.props.aspect_ratio.is_some())
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                style=get_aspect_style(self.props.aspect_ratio)
+            >
+                {if self.props.consent && !self.accepted {
+                    self.get_placeholder()
+                } else {
+                    html! {
+                        <iframe
+                            class="embed-frame"
+                            src=self.props.src.clone()
+                            title=self.props.title.clone()
+                            loading=if self.props.lazy { "lazy" } else { "eager" }
+                            sandbox=self.props.sandbox.clone()
+                            allow=self.props.allow.clone()
+                            referrerpolicy=self.props.referrer_policy.clone()
+                            onload=self.link.callback(|_| Msg::Loaded)
+                            onerror=self.link.callback(|_| Msg::Failed)
+                        ></iframe>
+                    }
+                }}
+            </div>
+        }
+    }
+}
+
+impl Embed {
+    fn get_placeholder(&self) -> Html {
+        let messages = get_messages();
+
+        html! {
+            <div class="embed-placeholder">
+                {match &self.props.placeholder {
+                    Some(placeholder) => placeholder.clone(),
+                    None => html! {<p>{messages.embed_consent}</p>},
+                }}
+                <button
+                    class="embed-load"
+                    onclick=self.link.callback(|_| Msg::Accept)
+                >{messages.embed_load}</button>
+            </div>
+        }
+    }
+}
+
+fn get_aspect_style(aspect_ratio: Option<(u32, u32)>) -> String {
+    match aspect_ratio {
+        Some((width, height)) if width > 0 => {
+            format!("padding-top: {}%;", height as f64 / width as f64 * 100.0)
+        }
+        _ => String::new(),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_consent_placeholder() {
+    let props = Props::builder()
+        .consent(true)
+        .id("embed-test".to_string())
+        .referrer_policy("strict-origin-when-cross-origin".to_string())
+        .src("https://example.com/map".to_string())
+        .title("Map".to_string())
+        .build();
+
+    let embed: App<Embed> = App::new();
+    embed.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let embed_element = utils::document().get_element_by_id("embed-test").unwrap();
+
+    assert_eq!(embed_element.get_elements_by_tag_name("iframe").length(), 0);
+    assert_eq!(
+        embed_element.get_attribute("style").unwrap(),
+        "padding-top: 56.25%;"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_aspect_style() {
+    assert_eq!(get_aspect_style(Some((4, 3))), "padding-top: 75%;");
+    assert_eq!(get_aspect_style(Some((0, 3))), "");
+    assert_eq!(get_aspect_style(None), "");
+}
@@ -0,0 +1,215 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Empty State component
+///
+/// Screen shown when a list, a table or a search has nothing to show: an illustration or icon,
+/// a title which says what is missing, a description with the next step and the actions to take it,
+/// e.g. a button to create the first item or to clear the filters.
+/// `compact` reduces the spaces and the illustration to fit inside of a table or a small card
+///
+/// ## Features required
+///
+/// empty_state
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::button::Button;
+/// use yew_styles::empty_state::EmptyState;
+/// use yew_styles::styles::Palette;
+///
+/// pub struct Projects {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Create,
+/// }
+///
+/// impl Component for Projects {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <EmptyState
+///                 illustration=html! {<img src="/assets/empty-folder.svg" alt="" />}
+///                 title="Nothing here yet"
+///                 description="Create a project to start tracking your work"
+///                 action=html! {
+///                     <Button
+///                         button_palette=Palette::Primary
+///                         onclick_signal=self.link.callback(|_| Msg::Create)
+///                     >{"New project"}</Button>
+///                 }
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct EmptyState {
+    props: Props,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Title which says what is missing. Required
+    pub title: String,
+    /// Illustration or icon shown above the title. Default `None`
+    #[prop_or_default]
+    pub illustration: Option<Html>,
+    /// Text with the reason or the next step. Default `None`
+    #[prop_or_default]
+    pub description: Option<String>,
+    /// Buttons or links shown below the description. Default `None`
+    #[prop_or_default]
+    pub action: Option<Html>,
+    /// Smaller spaces and illustration, for tables and small containers. Default `false`
+    #[prop_or(false)]
+    pub compact: bool,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+impl Component for EmptyState {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("empty-state")
+                    .state("compact", self.props.compact)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                role="status"
+            >
+                {if let Some(illustration) = self.props.illustration.clone() {
+                    html! {
+                        <div class="empty-state-illustration" aria-hidden="true">{illustration}</div>
+                    }
+                } else {
+                    html! {}
+                }}
+                <p class="empty-state-title">{&self.props.title}</p>
+                {if let Some(description) = self.props.description.clone() {
+                    html! {
+                        <p class="empty-state-description">{description}</p>
+                    }
+                } else {
+                    html! {}
+                }}
+                {if let Some(action) = self.props.action.clone() {
+                    html! {
+                        <div class="empty-state-action">{action}</div>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_the_slots_of_the_empty_state() {
+    let props = Props::builder()
+        .action(Some(
+            html! {<button id="empty-state-action-test">{"Clear filters"}</button>},
+        ))
+        .compact(true)
+        .description(Some("Try another search".to_string()))
+        .id("empty-state-test".to_string())
+        .title("No results".to_string())
+        .build();
+
+    let empty_state: App<EmptyState> = App::new();
+    empty_state.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let empty_state_element = utils::document()
+        .get_element_by_id("empty-state-test")
+        .unwrap();
+
+    assert!(empty_state_element.class_list().contains("compact"));
+    assert_eq!(
+        empty_state_element
+            .get_elements_by_class_name("empty-state-illustration")
+            .length(),
+        0
+    );
+    assert_eq!(
+        empty_state_element
+            .get_elements_by_class_name("empty-state-description")
+            .get_with_index(0)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Try another search"
+    );
+    assert!(utils::document()
+        .get_element_by_id("empty-state-action-test")
+        .is_some());
+}
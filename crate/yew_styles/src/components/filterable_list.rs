@@ -0,0 +1,401 @@
+use crate::highlight::{find_matches, Highlight};
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::get_random_string;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Filterable list component
+///
+/// Search box over a list of items, typing filters the items and highlights the matched text,
+/// the arrow keys move the active item and enter selects it, while the focus stays in the box.
+/// When no item matches, the `empty_state` is shown
+///
+/// ## Features required
+///
+/// filterable_list
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::services::ConsoleService;
+/// use yew_styles::filterable_list::FilterableList;
+///
+/// pub struct CountryPicker {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Select(String),
+/// }
+///
+/// impl Component for CountryPicker {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Select(country) => ConsoleService::log(&format!("Selected {}", country)),
+///         }
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <FilterableList
+///                 items=vec!["Argentina".to_string(), "Belgium".to_string(), "Chile".to_string()]
+///                 placeholder="Search a country"
+///                 empty_state=html! {<p>{"There is no country with that name"}</p>}
+///                 onselect_signal=self.link.callback(Msg::Select)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct FilterableList {
+    link: ComponentLink<Self>,
+    props: Props,
+    query: String,
+    active: Option<usize>,
+    scroll_to_active: bool,
+    list_id: String,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Items of the list. Required
+    pub items: Vec<String>,
+    /// Placeholder of the search box, by default the filter message. Default `None`
+    #[prop_or_default]
+    pub placeholder: Option<String>,
+    /// Match the query with the same case. Default `false`
+    #[prop_or(false)]
+    pub case_sensitive: bool,
+    /// Content shown when no item matches, by default the no matches message. Default `None`
+    #[prop_or_default]
+    pub empty_state: Option<Html>,
+    /// Emitted with the item when it is selected with enter or clicked
+    #[prop_or(Callback::noop())]
+    pub onselect_signal: Callback<String>,
+    /// Emitted with the query when it changes
+    #[prop_or(Callback::noop())]
+    pub onfilter_signal: Callback<String>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Filter(String),
+    KeyDown(KeyboardEvent),
+    Activate(usize),
+    Select(usize),
+}
+
+impl Component for FilterableList {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            query: String::new(),
+            active: None,
+            scroll_to_active: false,
+            list_id: format!("filterable-list-{}", get_random_string(10)),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Filter(query) => {
+                self.query = query;
+                self.active = None;
+                self.props.onfilter_signal.emit(self.query.clone());
+            }
+            Msg::KeyDown(keyboard_event) => {
+                let filtered = self.get_filtered();
+
+                match keyboard_event.key().as_str() {
+                    "ArrowDown" | "ArrowUp" => {
+                        keyboard_event.prevent_default();
+                        self.active = get_next_active(
+                            self.active,
+                            filtered.len(),
+                            keyboard_event.key() == "ArrowDown",
+                        );
+                        self.scroll_to_active = true;
+                    }
+                    "Enter" => {
+                        if let Some(active) = self.active {
+                            keyboard_event.prevent_default();
+                            self.link.send_message(Msg::Select(active));
+                        }
+                        return false;
+                    }
+                    "Escape" => {
+                        if self.query.is_empty() {
+                            return false;
+                        }
+
+                        keyboard_event.prevent_default();
+                        self.link.send_message(Msg::Filter(String::new()));
+                        return false;
+                    }
+                    _ => return false,
+                }
+            }
+            Msg::Activate(index) => {
+                if self.active == Some(index) {
+                    return false;
+                }
+
+                self.active = Some(index);
+            }
+            Msg::Select(index) => {
+                if let Some(item_index) = self.get_filtered().get(index) {
+                    self.props
+                        .onselect_signal
+                        .emit(self.props.items[*item_index].clone());
+                }
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.items != props.items {
+                self.active = None;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if !self.scroll_to_active {
+            return;
+        }
+
+        self.scroll_to_active = false;
+
+        if let Some(active) = self.active {
+            if let Some(option) = utils::document().get_element_by_id(&self.get_option_id(active)) {
+                option.scroll_into_view_with_bool(false);
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+        let filtered = self.get_filtered();
+        let placeholder = self
+            .props
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| messages.filter.clone());
+        let empty_state = match self.props.empty_state.clone() {
+            Some(empty_state) => empty_state,
+            None => html! {<>{messages.no_matches}</>},
+        };
+
+        html! {
+            <div
+                class=ComponentClasses::new("filterable-list")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <input
+                    class="filterable-list-input"
+                    type="search"
+                    role="combobox"
+                    aria-label=messages.filter
+                    aria-autocomplete="list"
+                    aria-expanded="true"
+                    aria-controls=self.list_id.clone()
+                    aria-activedescendant=self.active.map(|active| self.get_option_id(active))
+                    placeholder=placeholder
+                    value=self.query.clone()
+                    oninput=self.link.callback(|e: InputData| Msg::Filter(e.value))
+                    onkeydown=self.link.callback(Msg::KeyDown)
+                />
+                {if filtered.is_empty() {
+                    html! {
+                        <div class="filterable-list-empty" role="status">
+                            {empty_state}
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+                <ul class="filterable-list-items" role="listbox" id=self.list_id.clone()>
+                    {for filtered.iter().enumerate().map(|(index, item_index)| self.get_option(index, &self.props.items[*item_index]))}
+                </ul>
+            </div>
+        }
+    }
+}
+
+impl FilterableList {
+    fn get_filtered(&self) -> Vec<usize> {
+        filter_items(&self.props.items, &self.query, self.props.case_sensitive)
+    }
+
+    fn get_option_id(&self, index: usize) -> String {
+        format!("{}-{}", self.list_id, index)
+    }
+
+    fn get_option(&self, index: usize, item: &str) -> Html {
+        let active = self.active == Some(index);
+
+        html! {
+            <li
+                class=classes!("filterable-list-item", if active { Some("active") } else { None })
+                id=self.get_option_id(index)
+                role="option"
+                aria-selected=active.to_string()
+                onmousemove=self.link.callback(move |_| Msg::Activate(index))
+                onclick=self.link.callback(move |_| Msg::Select(index))
+            >
+                <Highlight
+                    text=item.to_string()
+                    terms=vec![self.query.clone()]
+                    case_sensitive=self.props.case_sensitive
+                />
+            </li>
+        }
+    }
+}
+
+/// Indexes of the items which contain the query, all of them when the query is empty
+pub fn filter_items(items: &[String], query: &str, case_sensitive: bool) -> Vec<usize> {
+    let terms = [query.to_string()];
+
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            query.is_empty() || !find_matches(item, &terms, case_sensitive, false).is_empty()
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn get_next_active(active: Option<usize>, length: usize, forward: bool) -> Option<usize> {
+    if length == 0 {
+        return None;
+    }
+
+    Some(match (active, forward) {
+        (None, true) => 0,
+        (None, false) => length - 1,
+        (Some(active), true) => (active + 1) % length,
+        (Some(active), false) => (active + length - 1) % length,
+    })
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_empty_state_when_nothing_matches() {
+    let props = Props::builder()
+        .empty_state(Some(
+            html! {<p id="filterable-list-empty-test">{"Nothing"}</p>},
+        ))
+        .id("filterable-list-test".to_string())
+        .items(vec!["Apple".to_string(), "Banana".to_string()])
+        .build();
+
+    let filterable_list: App<FilterableList> = App::new();
+    let link = filterable_list.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let filterable_list_element = utils::document()
+        .get_element_by_id("filterable-list-test")
+        .unwrap();
+
+    assert_eq!(
+        filterable_list_element
+            .get_elements_by_class_name("filterable-list-item")
+            .length(),
+        2
+    );
+
+    link.send_message(Msg::Filter("cherry".to_string()));
+
+    assert_eq!(
+        filterable_list_element
+            .get_elements_by_class_name("filterable-list-item")
+            .length(),
+        0
+    );
+    assert!(utils::document()
+        .get_element_by_id("filterable-list-empty-test")
+        .is_some());
+}
+
+#[wasm_bindgen_test]
+fn should_filter_the_items() {
+    let items = vec![
+        "Apple".to_string(),
+        "Banana".to_string(),
+        "Pineapple".to_string(),
+    ];
+
+    assert_eq!(filter_items(&items, "", false), vec![0, 1, 2]);
+    assert_eq!(filter_items(&items, "apple", false), vec![0, 2]);
+    assert_eq!(filter_items(&items, "apple", true), vec![2]);
+    assert!(filter_items(&items, "cherry", false).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn should_move_the_active_item() {
+    assert_eq!(get_next_active(None, 3, true), Some(0));
+    assert_eq!(get_next_active(None, 3, false), Some(2));
+    assert_eq!(get_next_active(Some(2), 3, true), Some(0));
+    assert_eq!(get_next_active(Some(0), 3, false), Some(2));
+    assert_eq!(get_next_active(None, 0, true), None);
+}
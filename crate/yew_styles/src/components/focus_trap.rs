@@ -0,0 +1,316 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::is_browser;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// Elements which can get the focus with the keyboard
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]):not([type=\"hidden\"]), \
+     select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable=\"true\"], [tabindex]:not([tabindex=\"-1\"])";
+
+/// # Focus Trap component
+///
+/// Keeps the focus inside of its children while it is active, Tab and Shift+Tab cycle
+/// between the focusable elements and, when it is removed or deactivated, the focus
+/// goes back to the element which had it before, usually the button that opened the overlay.
+/// The `Modal` component already includes it
+///
+/// ## Features required
+///
+/// focus_trap
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::focus_trap::FocusTrap;
+///
+/// pub struct Dialog {
+///     link: ComponentLink<Self>,
+///     open: bool,
+/// }
+///
+/// pub enum Msg {
+///     Toggle,
+/// }
+///
+/// impl Component for Dialog {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link, open: false }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Toggle => self.open = !self.open,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <button onclick=self.link.callback(|_| Msg::Toggle)>{"Open"}</button>
+///                 {if self.open {
+///                     html! {
+///                         <FocusTrap>
+///                             <div class="dialog">
+///                                 <input type="text"/>
+///                                 <button onclick=self.link.callback(|_| Msg::Toggle)>{"Close"}</button>
+///                             </div>
+///                         </FocusTrap>
+///                     }
+///                 } else {
+///                     html! {}
+///                 }}
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct FocusTrap {
+    link: ComponentLink<Self>,
+    props: Props,
+    previous_focus: Option<HtmlElement>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Keep the focus inside of the children. Default `true`
+    #[prop_or(true)]
+    pub active: bool,
+    /// Move the focus to the first focusable element when it is activated. Default `true`
+    #[prop_or(true)]
+    pub auto_focus: bool,
+    /// Move the focus back to the previous element when it is deactivated or removed. Default `true`
+    #[prop_or(true)]
+    pub restore_focus: bool,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Pressed(KeyboardEvent),
+}
+
+impl Component for FocusTrap {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            previous_focus: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Pressed(keyboard_event) => {
+                if self.props.active && keyboard_event.key() == "Tab" {
+                    if let Some(container) = self.props.code_ref.cast::<Element>() {
+                        if let Some(next) = get_next_focus(&container, keyboard_event.shift_key()) {
+                            keyboard_event.prevent_default();
+                            next.focus().ok();
+                        }
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if props.active && !self.props.active {
+                self.previous_focus = get_active_element();
+            } else if !props.active && self.props.active {
+                self.restore_focus();
+            }
+
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render && self.props.active {
+            self.previous_focus = get_active_element();
+        }
+
+        if self.props.active && self.props.auto_focus {
+            if let Some(container) = self.props.code_ref.cast::<Element>() {
+                let focus_inside = get_active_element()
+                    .map(|element| container.contains(Some(&element)))
+                    .unwrap_or(false);
+
+                if first_render || !focus_inside {
+                    if let Some(first) = get_focusable_elements(&container).first() {
+                        first.focus().ok();
+                    }
+                }
+            }
+        }
+    }
+
+    fn destroy(&mut self) {
+        if self.props.active {
+            self.restore_focus();
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("focus-trap")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onkeydown=self.link.callback(Msg::Pressed)
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+impl FocusTrap {
+    fn restore_focus(&mut self) {
+        if let Some(previous_focus) = self.previous_focus.take() {
+            if self.props.restore_focus && previous_focus.is_connected() {
+                previous_focus.focus().ok();
+            }
+        }
+    }
+}
+
+fn get_active_element() -> Option<HtmlElement> {
+    if !is_browser() {
+        return None;
+    }
+
+    utils::document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+}
+
+/// Focusable elements inside of the container in the order of the document
+pub(crate) fn get_focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    let mut elements = vec![];
+
+    if let Ok(node_list) = container.query_selector_all(FOCUSABLE_SELECTOR) {
+        for index in 0..node_list.length() {
+            if let Some(element) = node_list
+                .item(index)
+                .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+            {
+                elements.push(element);
+            }
+        }
+    }
+
+    if container.matches(FOCUSABLE_SELECTOR).unwrap_or(false) {
+        if let Ok(element) = container.clone().dyn_into::<HtmlElement>() {
+            elements.insert(0, element);
+        }
+    }
+
+    elements
+}
+
+/// Element which gets the focus when Tab is pressed at the edges of the container,
+/// `None` if the browser can move the focus by itself
+pub(crate) fn get_next_focus(container: &Element, backwards: bool) -> Option<HtmlElement> {
+    let elements = get_focusable_elements(container);
+    let (first, last) = (elements.first()?, elements.last()?);
+    let active = get_active_element().filter(|element| elements.contains(element));
+
+    match active {
+        None if backwards => Some(last.clone()),
+        None => Some(first.clone()),
+        Some(active) if backwards && &active == first => Some(last.clone()),
+        Some(active) if !backwards && &active == last => Some(first.clone()),
+        _ => None,
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_cycle_the_focus_inside_of_the_trap() {
+    let props = Props::builder()
+        .id("focus-trap-test".to_string())
+        .children(Children::new(vec![html! {
+            <>
+                <button id="focus-trap-first">{"First"}</button>
+                <button id="focus-trap-last">{"Last"}</button>
+            </>
+        }]))
+        .build();
+
+    let focus_trap: App<FocusTrap> = App::new();
+    focus_trap.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let container = utils::document()
+        .get_element_by_id("focus-trap-test")
+        .unwrap();
+    let first = utils::document()
+        .get_element_by_id("focus-trap-first")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    let last = utils::document()
+        .get_element_by_id("focus-trap-last")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+
+    assert_eq!(get_focusable_elements(&container).len(), 2);
+
+    last.focus().unwrap();
+    assert_eq!(get_next_focus(&container, false), Some(first.clone()));
+
+    first.focus().unwrap();
+    assert_eq!(get_next_focus(&container, true), Some(last));
+    assert_eq!(get_next_focus(&container, false), None);
+}
@@ -0,0 +1,23 @@
+//! Controlled and uncontrolled modes of the form controls.
+//!
+//! A form control is controlled when its `value` (or `checked`) prop is set: every new value of the prop is pushed
+//! into the element, also when the user has changed the element since the last one. Otherwise it's uncontrolled,
+//! `default_value` (or `default_checked`) is only its initial value and then the element keeps what the user types.
+//! When both are set the controlled value has precedence
+
+/// Initial value of the form control, the controlled value has precedence over the default one
+pub fn get_initial_value<T: Clone>(value: &Option<T>, default_value: &T) -> T {
+    value.clone().unwrap_or_else(|| default_value.clone())
+}
+
+/// Value to push into the element when the props change, `None` when the form control is uncontrolled
+/// or the element already shows the controlled value
+pub fn get_controlled_value<T: Clone + PartialEq>(
+    value: &Option<T>,
+    current_value: &T,
+) -> Option<T> {
+    value
+        .as_ref()
+        .filter(|value| *value != current_value)
+        .cloned()
+}
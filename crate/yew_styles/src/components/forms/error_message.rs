@@ -1,9 +1,40 @@
+use crate::messages::get_messages;
+use crate::utils::get_random_string;
 use yew::prelude::*;
 
-pub fn get_error_message(error_state: bool, error_message: String) -> Html {
+pub fn get_error_message(error_state: bool, error_message: String, error_id: String) -> Html {
     if error_state {
-        html! {<span class="form-error">{error_message}</span>}
+        html! {<span class="form-error" id=error_id role="alert">{get_messages().form_error_prefix}{error_message}</span>}
     } else {
         html! {}
     }
 }
+
+/// Id for the error message of a form control without id
+pub fn create_error_id() -> String {
+    format!("form-error-{}", get_random_string(10))
+}
+
+/// Id of the error message, `{id}-error` when the form control has id
+pub fn get_error_id(id: &str, generated_id: &str) -> String {
+    if id.is_empty() {
+        generated_id.to_string()
+    } else {
+        format!("{}-error", id)
+    }
+}
+
+/// Value of `aria-describedby`, it includes the error message when it is shown
+pub fn get_described_by(error_state: bool, error_id: &str, aria_describedby: &str) -> String {
+    let mut ids = vec![];
+
+    if !aria_describedby.is_empty() {
+        ids.push(aria_describedby);
+    }
+
+    if error_state {
+        ids.push(error_id);
+    }
+
+    ids.join(" ")
+}
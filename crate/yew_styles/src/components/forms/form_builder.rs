@@ -0,0 +1,745 @@
+use super::form_component::{get_form_values, FormValue};
+use super::form_error_summary::FormErrorSummary;
+use super::form_group::{FormGroup, Orientation};
+use super::form_input::{FormInput, InputType};
+use super::form_label::FormLabel;
+use super::form_select::FormSelect;
+use super::form_submit::FormSubmit;
+use super::form_textarea::FormTextArea;
+use crate::messages::{format_message, get_messages};
+use crate::styles::{tokens::Spaces, ComponentClasses, Palette};
+use crate::utils::get_random_string;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, HtmlFormElement};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Form Builder
+///
+/// Renders a whole form from a list of `FormField`: a label and the form control of each field in a grid of
+/// `columns`, the validation messages and the submit button. The fields are validated when they lose the focus
+/// and all together when the form is submitted, the focus goes to the first invalid field.
+/// Once every field is valid the values are converted with `FromFormValues` into the type of `onsubmit_signal`,
+/// `FormValues` keeps them untyped
+///
+/// ## Features required
+///
+/// form_builder
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::forms::form_builder::{
+///     FieldKind, FormBuilder, FormField, FormValues, FromFormValues, Validator,
+/// };
+///
+/// #[derive(Clone, PartialEq)]
+/// pub struct Contact {
+///     name: String,
+///     email: String,
+///     age: u8,
+///     newsletter: bool,
+/// }
+///
+/// impl FromFormValues for Contact {
+///     fn from_form_values(values: &FormValues) -> Result<Self, String> {
+///         Ok(Self {
+///             name: values.text("name"),
+///             email: values.text("email"),
+///             age: values.parse("age")?,
+///             newsletter: values.checked("newsletter"),
+///         })
+///     }
+/// }
+///
+/// pub struct NewContact {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Save(Contact),
+/// }
+///
+/// impl Component for NewContact {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Save(contact) => {
+///                 // send the contact to the server
+///             }
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <FormBuilder<Contact>
+///                 columns=2
+///                 submit_label="Save contact"
+///                 onsubmit_signal=self.link.callback(Msg::Save)
+///                 fields=vec![
+///                     FormField::new("name", "Name", FieldKind::Text)
+///                         .validator(Validator::Required)
+///                         .validator(Validator::MaxLength(80)),
+///                     FormField::new("email", "Email", FieldKind::Email)
+///                         .validator(Validator::Required)
+///                         .validator(Validator::Email),
+///                     FormField::new("age", "Age", FieldKind::Number)
+///                         .validator(Validator::Min(18.0)),
+///                     FormField::new("newsletter", "Subscribe to the newsletter", FieldKind::Checkbox)
+///                         .full_width(),
+///                 ]
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct FormBuilder<T: FromFormValues> {
+    link: ComponentLink<Self>,
+    props: Props<T>,
+    /// Prefix of the ids of the form controls
+    form_id: String,
+    errors: HashMap<String, String>,
+    /// Fields which have lost the focus, only their errors are shown before submitting
+    touched: HashSet<String>,
+    /// Error of the conversion of the values into the type of the submit signal
+    submit_error: Option<String>,
+    focus_error: bool,
+}
+
+/// Form control rendered for a field
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldKind {
+    Text,
+    Email,
+    Password,
+    Number,
+    Tel,
+    Url,
+    Date,
+    TextArea,
+    /// Select of one of the `options`
+    Select,
+    /// Select of several `options`
+    MultipleSelect,
+    Checkbox,
+}
+
+/// Rule checked on the value of a field, the rules other than `Required` skip the empty values
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Validator {
+    Required,
+    /// Minimum number of characters
+    MinLength(usize),
+    /// Maximum number of characters
+    MaxLength(usize),
+    /// Minimum number
+    Min(f64),
+    /// Maximum number
+    Max(f64),
+    Email,
+    /// Same value as the field with this name, e.g. the confirmation of a password
+    Matches(String),
+}
+
+/// Definition of a field of the form
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormField {
+    pub name: String,
+    pub label: String,
+    pub kind: FieldKind,
+    pub validators: Vec<Validator>,
+    /// Values and labels of the options of the selects
+    pub options: Vec<(String, String)>,
+    pub placeholder: String,
+    /// Take the whole row of the grid
+    pub full_width: bool,
+}
+
+impl FormField {
+    pub fn new(name: impl Into<String>, label: impl Into<String>, kind: FieldKind) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            kind,
+            validators: vec![],
+            options: vec![],
+            placeholder: String::new(),
+            full_width: false,
+        }
+    }
+
+    /// Add a rule checked on the value, in the order they are added
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Add an option to the selects
+    pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.options.push((value.into(), label.into()));
+        self
+    }
+
+    /// Text shown while the field is empty
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Take the whole row of the grid
+    pub fn full_width(mut self) -> Self {
+        self.full_width = true;
+        self
+    }
+}
+
+/// Values of the fields by name, with getters to convert them
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormValues {
+    values: HashMap<String, FormValue>,
+}
+
+impl FormValues {
+    pub fn new(values: HashMap<String, FormValue>) -> Self {
+        Self { values }
+    }
+
+    /// Text of the field, the selected values are joined by commas. Empty if there is no field with this name
+    pub fn text(&self, name: &str) -> String {
+        match self.values.get(name) {
+            Some(FormValue::Text(text)) => text.clone(),
+            Some(FormValue::Multiple(selected)) => selected.join(","),
+            Some(FormValue::Checked(checked)) => checked.to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Text of the field parsed, e.g. into a number. The error is the message `validation_invalid`
+    pub fn parse<V: FromStr>(&self, name: &str) -> Result<V, String> {
+        self.text(name).trim().parse().map_err(|_| {
+            format_message(
+                &get_messages().validation_invalid,
+                &[("name", name.to_string())],
+            )
+        })
+    }
+
+    /// The checkbox is checked
+    pub fn checked(&self, name: &str) -> bool {
+        matches!(self.values.get(name), Some(FormValue::Checked(true)))
+    }
+
+    /// Selected values of a multiple select
+    pub fn multiple(&self, name: &str) -> Vec<String> {
+        match self.values.get(name) {
+            Some(FormValue::Multiple(selected)) => selected.clone(),
+            Some(FormValue::Text(text)) if !text.is_empty() => vec![text.clone()],
+            _ => vec![],
+        }
+    }
+
+    pub fn into_inner(self) -> HashMap<String, FormValue> {
+        self.values
+    }
+}
+
+/// Conversion of the values of the form into the type emitted by `FormBuilder`,
+/// the error is shown over the submit button
+pub trait FromFormValues: Clone + PartialEq + Sized + 'static {
+    fn from_form_values(values: &FormValues) -> Result<Self, String>;
+}
+
+impl FromFormValues for FormValues {
+    fn from_form_values(values: &FormValues) -> Result<Self, String> {
+        Ok(values.clone())
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props<T: FromFormValues> {
+    /// Fields of the form in the order they are shown. Required
+    pub fields: Vec<FormField>,
+    /// Emitted with the converted values when the form is submitted and every field is valid
+    #[prop_or(Callback::noop())]
+    pub onsubmit_signal: Callback<T>,
+    /// Number of columns of the grid of fields. Default `1`
+    #[prop_or(1)]
+    pub columns: u8,
+    /// Position of the labels next to the form controls. Default `Orientation::Vertical`
+    #[prop_or(Orientation::Vertical)]
+    pub orientation: Orientation,
+    /// Text of the submit button. Default the message `form_submit`
+    #[prop_or_default]
+    pub submit_label: Option<String>,
+    /// Palette of the submit button. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub submit_palette: Palette,
+    /// Clear the form controls after submitting. Default `false`
+    #[prop_or(false)]
+    pub reset_on_submit: bool,
+    /// Show the list of the errors at the top of the form, see `FormErrorSummary`. Default `false`
+    #[prop_or(false)]
+    pub error_summary: bool,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Changed(String),
+    Blurred(String),
+    Submitted(FocusEvent),
+}
+
+impl<T: FromFormValues> Component for FormBuilder<T> {
+    type Message = Msg;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            form_id: format!("form-builder-{}", get_random_string(10)),
+            errors: HashMap::new(),
+            touched: HashSet::new(),
+            submit_error: None,
+            focus_error: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Changed(name) => {
+                self.submit_error = None;
+
+                if !self.touched.contains(&name) {
+                    return false;
+                }
+                self.validate();
+            }
+            Msg::Blurred(name) => {
+                self.touched.insert(name);
+                self.validate();
+            }
+            Msg::Submitted(focus_event) => {
+                focus_event.prevent_default();
+
+                self.touched = self
+                    .props
+                    .fields
+                    .iter()
+                    .map(|field| field.name.clone())
+                    .collect();
+                let values = self.validate();
+
+                if !self.errors.is_empty() {
+                    self.focus_error = true;
+                    return true;
+                }
+
+                match T::from_form_values(&values) {
+                    Ok(value) => {
+                        self.props.onsubmit_signal.emit(value);
+
+                        if self.props.reset_on_submit {
+                            if let Some(form) = self.props.code_ref.cast::<HtmlFormElement>() {
+                                form.reset();
+                            }
+                            self.touched.clear();
+                        }
+                    }
+                    Err(error) => self.submit_error = Some(error),
+                };
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.validate();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if !self.focus_error {
+            return;
+        }
+        self.focus_error = false;
+
+        let field = self
+            .props
+            .fields
+            .iter()
+            .find(|field| self.errors.contains_key(&field.name));
+
+        if let Some(field) = field {
+            if let Some(control) = utils::document()
+                .get_element_by_id(&self.get_control_id(field))
+                .and_then(|control| control.dyn_into::<HtmlElement>().ok())
+            {
+                control.focus().ok();
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let submit_label = self
+            .props
+            .submit_label
+            .clone()
+            .unwrap_or_else(|| get_messages().form_submit);
+
+        html! {
+            <form
+                class=ComponentClasses::new("form")
+                    .semantic("form-builder")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                novalidate=true
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onsubmit=self.link.callback(Msg::Submitted)
+            >
+                {if self.props.error_summary {
+                    html! {<FormErrorSummary />}
+                } else {
+                    html! {}
+                }}
+                <div
+                    class="form-builder-fields"
+                    style=format!("grid-template-columns: repeat({}, minmax(0, 1fr));", self.props.columns.max(1))
+                >
+                    {for self.props.fields.iter().map(|field| self.get_field(field))}
+                </div>
+                {match &self.submit_error {
+                    Some(error) => html! {
+                        <div class="form-builder-error" role="alert">{error}</div>
+                    },
+                    None => html! {},
+                }}
+                <FormSubmit value=submit_label submit_palette=self.props.submit_palette.clone() />
+            </form>
+        }
+    }
+}
+
+impl<T: FromFormValues> FormBuilder<T> {
+    /// Validate the touched fields with the current values of the form
+    fn validate(&mut self) -> FormValues {
+        let values = self
+            .props
+            .code_ref
+            .cast::<HtmlFormElement>()
+            .map(|form| FormValues::new(get_form_values(&form)))
+            .unwrap_or_default();
+        let fields = &self.props.fields;
+
+        self.errors = fields
+            .iter()
+            .filter(|field| self.touched.contains(&field.name))
+            .filter_map(|field| {
+                validate_field(field, &values, fields).map(|error| (field.name.clone(), error))
+            })
+            .collect();
+
+        values
+    }
+
+    fn get_control_id(&self, field: &FormField) -> String {
+        format!("{}-{}", self.form_id, field.name)
+    }
+
+    fn get_field(&self, field: &FormField) -> Html {
+        let id = self.get_control_id(field);
+        let error = self.errors.get(&field.name);
+        let error_state = error.is_some();
+        let error_message = error.cloned().unwrap_or_default();
+        let required = field.validators.contains(&Validator::Required);
+        let changed_name = field.name.clone();
+        let blurred_name = field.name.clone();
+        let onchange = self
+            .link
+            .callback(move |_| Msg::Changed(changed_name.clone()));
+        let onblur = self
+            .link
+            .callback(move |_| Msg::Blurred(blurred_name.clone()));
+
+        let control = match field.kind {
+            FieldKind::TextArea => html! {
+                <FormTextArea
+                    id=id.clone()
+                    name=field.name.clone()
+                    placeholder=field.placeholder.clone()
+                    required=required
+                    error_state=error_state
+                    error_message=error_message
+                    oninput_signal=onchange
+                    onblur_signal=onblur
+                />
+            },
+            FieldKind::Select | FieldKind::MultipleSelect => html! {
+                <FormSelect
+                    id=id.clone()
+                    name=field.name.clone()
+                    multiple=field.kind == FieldKind::MultipleSelect
+                    required=required
+                    error_state=error_state
+                    error_message=error_message
+                    onchange_signal=self.link.callback({
+                        let name = field.name.clone();
+                        move |_| Msg::Blurred(name.clone())
+                    })
+                    options=get_options(field)
+                />
+            },
+            _ => html! {
+                <FormInput
+                    id=id.clone()
+                    input_type=get_input_type(&field.kind)
+                    name=field.name.clone()
+                    placeholder=field.placeholder.clone()
+                    required=required
+                    error_state=error_state
+                    error_message=error_message
+                    oninput_signal=onchange
+                    onblur_signal=onblur
+                />
+            },
+        };
+
+        html! {
+            <div class=classes!("form-builder-field", if field.full_width { Some("full-width") } else { None })>
+                <FormGroup orientation=self.props.orientation.clone()>
+                    <FormLabel text=field.label.clone() label_for=id />
+                    {control}
+                </FormGroup>
+            </div>
+        }
+    }
+}
+
+fn get_input_type(kind: &FieldKind) -> InputType {
+    match kind {
+        FieldKind::Email => InputType::Email,
+        FieldKind::Password => InputType::Password,
+        FieldKind::Number => InputType::Number,
+        FieldKind::Tel => InputType::Tel,
+        FieldKind::Url => InputType::Url,
+        FieldKind::Date => InputType::Date,
+        FieldKind::Checkbox => InputType::Checkbox,
+        _ => InputType::Text,
+    }
+}
+
+fn get_options(field: &FormField) -> Html {
+    let placeholder = if field.kind == FieldKind::Select {
+        html! {
+            <option value="">{field.placeholder.clone()}</option>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <>
+            {placeholder}
+            {for field.options.iter().map(|(value, label)| html! {
+                <option value=value.clone()>{label}</option>
+            })}
+        </>
+    }
+}
+
+/// First error of the rules of the field, `None` when the value is valid
+pub fn validate_field(
+    field: &FormField,
+    values: &FormValues,
+    fields: &[FormField],
+) -> Option<String> {
+    let messages = get_messages();
+    let value = values.text(&field.name);
+    let empty = match field.kind {
+        FieldKind::Checkbox => !values.checked(&field.name),
+        FieldKind::MultipleSelect => values.multiple(&field.name).is_empty(),
+        _ => value.trim().is_empty(),
+    };
+    let length = value.chars().count();
+    let number = value.trim().parse::<f64>().ok();
+
+    field
+        .validators
+        .iter()
+        .find_map(|validator| match validator {
+            Validator::Required if empty => Some(messages.validation_required.clone()),
+            _ if empty => None,
+            Validator::MinLength(min) if length < *min => Some(format_message(
+                &messages.validation_min_length,
+                &[("min", min.to_string())],
+            )),
+            Validator::MaxLength(max) if length > *max => Some(format_message(
+                &messages.validation_max_length,
+                &[("max", max.to_string())],
+            )),
+            Validator::Min(min) if number.map_or(true, |number| number < *min) => Some(
+                format_message(&messages.validation_min, &[("min", min.to_string())]),
+            ),
+            Validator::Max(max) if number.map_or(true, |number| number > *max) => Some(
+                format_message(&messages.validation_max, &[("max", max.to_string())]),
+            ),
+            Validator::Email if !is_email(&value) => Some(messages.validation_email.clone()),
+            Validator::Matches(other) if value != values.text(other) => {
+                let label = fields
+                    .iter()
+                    .find(|field| &field.name == other)
+                    .map_or(other.clone(), |field| field.label.clone());
+
+                Some(format_message(
+                    &messages.validation_matches,
+                    &[("field", label)],
+                ))
+            }
+            _ => None,
+        })
+}
+
+/// A name, an `@` and a domain with a dot, without spaces
+fn is_email(value: &str) -> bool {
+    let value = value.trim();
+
+    match value.split_once('@') {
+        Some((name, domain)) => {
+            !name.is_empty()
+                && !domain.contains('@')
+                && !value.contains(char::is_whitespace)
+                && domain
+                    .split_once('.')
+                    .map_or(false, |(host, end)| !host.is_empty() && !end.is_empty())
+                && !domain.ends_with('.')
+        }
+        None => false,
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_validate_the_fields() {
+    let fields = vec![
+        FormField::new("email", "Email", FieldKind::Email)
+            .validator(Validator::Required)
+            .validator(Validator::Email),
+        FormField::new("age", "Age", FieldKind::Number).validator(Validator::Min(18.0)),
+        FormField::new("password", "Password", FieldKind::Password),
+        FormField::new("confirmation", "Confirmation", FieldKind::Password)
+            .validator(Validator::Matches("password".to_string())),
+    ];
+    let mut values = HashMap::new();
+    values.insert("email".to_string(), FormValue::Text("".to_string()));
+    values.insert("age".to_string(), FormValue::Text("16".to_string()));
+    values.insert(
+        "password".to_string(),
+        FormValue::Text("secret".to_string()),
+    );
+    values.insert(
+        "confirmation".to_string(),
+        FormValue::Text("secre".to_string()),
+    );
+    let values = FormValues::new(values);
+
+    assert_eq!(
+        validate_field(&fields[0], &values, &fields),
+        Some("This field is required".to_string())
+    );
+    assert_eq!(
+        validate_field(&fields[1], &values, &fields),
+        Some("Enter a value of at least 18".to_string())
+    );
+    assert_eq!(validate_field(&fields[2], &values, &fields), None);
+    assert_eq!(
+        validate_field(&fields[3], &values, &fields),
+        Some("The value doesn't match Password".to_string())
+    );
+    assert_eq!(values.parse::<u8>("age"), Ok(16));
+    assert!(values.parse::<u8>("password").is_err());
+}
+
+#[wasm_bindgen_test]
+fn should_check_the_emails() {
+    assert!(is_email("ada@example.com"));
+    assert!(!is_email("ada@example"));
+    assert!(!is_email("@example.com"));
+    assert!(!is_email("ada lovelace@example.com"));
+}
+
+#[wasm_bindgen_test]
+fn should_create_form_builder() {
+    let props = Props::<FormValues>::builder()
+        .columns(2)
+        .fields(vec![
+            FormField::new("name", "Name", FieldKind::Text),
+            FormField::new("role", "Role", FieldKind::Select)
+                .option("dev", "Developer")
+                .option("ops", "Operations"),
+        ])
+        .id("form-builder-test".to_string())
+        .build();
+
+    let form_builder: App<FormBuilder<FormValues>> = App::new();
+    form_builder.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let form = utils::document()
+        .get_element_by_id("form-builder-test")
+        .unwrap();
+
+    assert_eq!(form.get_elements_by_class_name("form-label").length(), 2);
+    assert_eq!(form.get_elements_by_tag_name("option").length(), 3);
+}
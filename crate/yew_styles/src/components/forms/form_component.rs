@@ -1,63 +1,49 @@
+use super::form_context::{get_form_controls, FormControl, FormControlRegistration};
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use std::collections::HashMap;
 use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::{
+    Element, HtmlFormElement, HtmlInputElement, HtmlOptionElement, HtmlSelectElement,
+    HtmlTextAreaElement,
+};
 use yew::prelude::*;
 use yew::{utils, App};
 
 /// # Form
 ///
+/// The values of the named form controls are emitted as `HashMap<String, FormValue>` by `onsubmit_signal`
+/// when the form is submitted and none of them is invalid. The submit `FocusEvent` is emitted by `onsubmit_event_signal`
+///
 /// ## Features required
 ///
-/// forms
+/// form_component, form_group, form_input, form_label, form_select, form_submit
 ///
 /// ## Example
 ///
 /// ```rust
 /// use std::collections::HashMap;
-/// use wasm_bindgen::JsCast;
-/// use web_sys::{HtmlOptionElement, HtmlSelectElement};
 /// use yew::prelude::*;
-/// use yew::utils;
 /// use yew_styles::forms::{
-///     form_component::Form,
+///     form_component::{Form, FormValue},
 ///     form_group::{FormGroup, Orientation},
 ///     form_input::{FormInput, InputType},
 ///     form_label::FormLabel,
 ///     form_select::FormSelect,
 ///     form_submit::FormSubmit,
-///     form_textarea::FormTextArea,
-/// };
-/// use yew_styles::layouts::{
-///     container::{Container, Direction, Wrap},
-///     item::{Item, ItemLayout},
 /// };
 /// use yew_styles::styles::{Palette, Style};
 ///
-/// #[derive(Clone)]
-/// struct Fields {
-///     first_name: String,
-///     last_name: String,
-///     email: String,
-///     specialty: String,
-///     skills: Vec<String>,
-///     cover_letter: String,
-/// }
-///
 /// pub struct BasicFormPage {
 ///     link: ComponentLink<Self>,
-///     fields: HashMap<String, String>,
-///     skills: Vec<String>,
-///     empty_fields: Vec<String>,
-///     result: Option<Fields>,
+///     email_error: bool,
+///     result: Option<HashMap<String, FormValue>>,
 /// }
 ///
 /// pub enum Msg {
-///     FirstName(String),
-///     LastName(String),
 ///     Email(String),
-///     Specialty(String),
-///     Skills(Vec<String>),
-///     CoverLetter(String),
-///     Submit,
+///     Submit(HashMap<String, FormValue>),
 /// }
 ///
 /// impl Component for BasicFormPage {
@@ -67,53 +53,18 @@ use yew::{utils, App};
 ///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
 ///         Self {
 ///             link,
-///             fields: HashMap::new(),
-///             skills: vec![],
-///             empty_fields: vec![],
+///             email_error: false,
 ///             result: None,
 ///         }
 ///     }
 ///
 ///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
 ///         match msg {
-///             Msg::FirstName(first_name) => {
-///                 self.fields.insert("first_name".to_string(), first_name);
-///             }
-///             Msg::LastName(last_name) => {
-///                 self.fields.insert("last_name".to_string(), last_name);
-///             }
 ///             Msg::Email(email) => {
-///                 self.fields.insert("email".to_string(), email);
+///                 self.email_error = !email.contains('@');
 ///             }
-///             Msg::Specialty(specialty) => {
-///                 self.fields.insert("specialty".to_string(), specialty);
-///             }
-///             Msg::Skills(skills) => {
-///                 self.skills = skills;
-///             }
-///             Msg::CoverLetter(cover_letter) => {
-///                 self.fields.insert("cover_letter".to_string(), cover_letter);
-///             }
-///             Msg::Submit => {
-///                 self.empty_fields = get_empty_fields(self.fields.clone());
-///
-///                 if self.empty_fields.is_empty() {
-///                     self.result = Some(Fields {
-///                         first_name: self.fields.get("first_name").unwrap().to_string(),
-///                         last_name: self.fields.get("last_name").unwrap().to_string(),
-///                         email: self.fields.get("email").unwrap().to_string(),
-///                         specialty: self.fields.get("specialty").unwrap().to_string(),
-///                         skills: self.skills.clone(),
-///                         cover_letter: self.fields.get("cover_letter").unwrap().to_string(),
-///                     });
-///                     self.fields.drain();
-///
-///                     self.skills = vec![];
-///                     remove_input_values();
-///
-///                     set_default_selected("specialty");
-///                     remove_all_selected("skills");
-///                 }
+///             Msg::Submit(values) => {
+///                 self.result = Some(values);
 ///             }
 ///         }
 ///
@@ -126,232 +77,46 @@ use yew::{utils, App};
 ///
 ///     fn view(&self) -> Html {
 ///         html! {
-///             <Container wrap=Wrap::Wrap direction=Direction::Row>
-///                 <Item layouts=vec!(ItemLayout::ItXs(12))>
-///                     <h1>{"Basic Form"}</h1>
-///                 </Item>
-///                 <Item layouts=vec!(ItemLayout::ItXs(12))>
-///                     <Form onsubmit_signal=self.link.callback(|e| Msg::Submit)>
-///                         <Container wrap=Wrap::Wrap direction=Direction::Row>
-///                             <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
-///                                 <FormGroup orientation=Orientation::Horizontal>
-///                                     <FormLabel text="First name: "/>
-///                                     <FormInput
-///                                         error_state=self.empty_fields.iter().any(|field| field == "first_name")
-///                                         error_message="First name field is required"
-///                                         input_type=InputType::Text
-///                                         oninput_signal=self.link.callback(|e: InputData| Msg::FirstName(e.value))
-///                                     />
-///                                 </FormGroup>
-///                                 <FormGroup orientation=Orientation::Horizontal>
-///                                     <FormLabel text="Last name: "/>
-///                                     <FormInput
-///                                         error_state=self.empty_fields.iter().any(|field| field == "last_name")
-///                                         error_message="Last name field is required"
-///                                         input_type=InputType::Text
-///                                         oninput_signal=self.link.callback(|e: InputData| Msg::LastName(e.value))
-///                                     />
-///                                 </FormGroup>
-///                                 <FormGroup orientation=Orientation::Horizontal>
-///                                     <FormLabel text="Email: "/>
-///                                     <FormInput
-///                                         error_state=self.empty_fields.iter().any(|field| field == "email")
-///                                         error_message="Email field is required"
-///                                         input_type=InputType::Email
-///                                         oninput_signal=self.link.callback(|e: InputData| Msg::Email(e.value))
-///                                     />
-///                                 </FormGroup>
-///                             </Item>
-///                             <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
-///                                 <FormGroup orientation=Orientation::Vertical>
-///                                     <FormLabel text="Specialty:"/>
-///                                     <FormSelect
-///                                         id="specialty"
-///                                         error_state=self.empty_fields.iter().any(|field| field == "specialty")
-///                                         error_message="Select specialty is required"
-///                                         onchange_signal=self.link.callback(|e: ChangeData| {
-///                                             match e {
-///                                                 ChangeData::Select(element) => {
-///                                                     let value = element.value();
-///                                                     Msg::Specialty(value)
-///                                                 },
-///                                                 _ => unreachable!()
-///                                             }
-///                                         })
-///                                         options=html!{
-///                                             <>
-///                                             <option value="" disabled=true>{"Choose specialty"}</option>
-///                                                 <option value="frontend">{"Frontend"}</option>
-///                                                 <option value="backend">{"Backend"}</option>
-///                                             </>
-///                                         }
-///                                     />
-///                                 </FormGroup>
-///                                 <FormGroup orientation=Orientation::Vertical>
-///                                     <FormLabel text="Skills:"/>
-///                                     <FormSelect
-///                                         id="skills"
-///                                         multiple=true
-///                                         onchange_signal=self.link.callback(|e: ChangeData| {
-///                                             match e {
-///                                                 ChangeData::Select(element) => {
-///                                                     let mut values = vec![];
-///                                                     let options = element.options();
-///
-///                                                     for i in 0..options.length() {
-///                                                         let option = options
-///                                                             .get_with_index(i)
-///                                                             .unwrap()
-///                                                             .dyn_into::<HtmlOptionElement>()
-///                                                             .unwrap();
-///                                                         if option.selected() {
-///                                                             values.push(option.value());
-///                                                         }
-///                                                     }
-///                                                     Msg::Skills(values)
-///                                                 },
-///                                                 _ => unreachable!()
-///                                             }
-///                                         })
-///                                         options=html!{
-///                                             <>
-///                                                 <option value="yew">{"Yew.rs"}</option>
-///                                                 <option value="rustwasm">{"Rustwasm"}</option>
-///                                                 <option value="rust">{"Rust"}</option>
-///                                                 <option value="warp">{"Warp"}</option>
-///                                                 <option value="tokio">{"Tokio"}</option>
-///                                             </>
-///                                         }
-///                                     />
-///                                 </FormGroup>
-///                             </Item>
-///                             <Item layouts=vec!(ItemLayout::ItXs(12))>
-///                                 <FormGroup orientation=Orientation::Vertical>
-///                                     <FormLabel text="Cover letter:"/>
-///                                     <FormTextArea
-///                                         error_state=self.empty_fields.iter().any(|field| field == "cover_letter")
-///                                         error_message="cover letter is required"
-///                                         oninput_signal=self.link.callback(|e: InputData| Msg::CoverLetter(e.value))/>
-///                                 </FormGroup>
-///                             </Item>
-///                             <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(3))>
-///                                 <FormGroup>
-///                                 <FormSubmit
-///                                     value="Submit application"
-///                                     submit_palette=Palette::Success
-///                                     submit_style=Style::Outline
-///                                 />
-///                                 </FormGroup>
-///                             </Item>
-///                         </Container>
-///                     </Form>
-///                 </Item>
-///                 <Item layouts=vec!(ItemLayout::ItXs(12))>
-///                     {get_result(self.result.clone())}
-///                 </Item>
-///             </Container>
-///         }
-///     }
-/// }
-///
-/// fn get_result(result: Option<Fields>) -> Html {
-///     if let Some(form) = result {
-///         html! {
-///             <Container wrap=Wrap::Wrap direction=Direction::Row>
-///                 <Item layouts=vec!(ItemLayout::ItXs(12))>
-///                     <p><b>{"First name: "}</b>{form.first_name.clone()}</p>
-///                     <p><b>{"last name: "}</b>{form.last_name.clone()}</p>
-///                     <p><b>{"email: "}</b>{form.email.clone()}</p>
-///                     <p><b>{"Specialty: "}</b>{form.specialty.clone()}</p>
-///                     <p><b>{"Skills: "}</b>{form.skills.join(", ")}</p>
-///                     <p><b>{"Cover letter: "}</b>{form.cover_letter}</p>
-///                 </Item>
-///             </Container>
+///             <Form
+///                 reset_on_submit=true
+///                 onsubmit_signal=self.link.callback(Msg::Submit)
+///             >
+///                 <FormGroup orientation=Orientation::Horizontal>
+///                     <FormLabel text="First name: "/>
+///                     <FormInput name="first_name" required=true input_type=InputType::Text/>
+///                 </FormGroup>
+///                 <FormGroup orientation=Orientation::Horizontal>
+///                     <FormLabel text="Email: "/>
+///                     <FormInput
+///                         name="email"
+///                         input_type=InputType::Email
+///                         error_state=self.email_error
+///                         error_message="Email is not valid"
+///                         oninput_signal=self.link.callback(|e: InputData| Msg::Email(e.value))
+///                     />
+///                 </FormGroup>
+///                 <FormGroup orientation=Orientation::Vertical>
+///                     <FormLabel text="Skills:"/>
+///                     <FormSelect
+///                         name="skills"
+///                         multiple=true
+///                         options=html!{
+///                             <>
+///                                 <option value="yew">{"Yew"}</option>
+///                                 <option value="rust">{"Rust"}</option>
+///                             </>
+///                         }
+///                     />
+///                 </FormGroup>
+///                 <FormSubmit
+///                     value="Submit application"
+///                     submit_palette=Palette::Success
+///                     submit_style=Style::Outline
+///                 />
+///             </Form>
 ///         }
-///     } else {
-///         html! {}
 ///     }
 /// }
-///
-/// fn get_empty_fields(fields: HashMap<String, String>) -> Vec<String> {
-///     let total_fields = vec![
-///         "first_name",
-///         "last_name",
-///         "email",
-///         "specialty",
-///         "cover_letter",
-///     ];
-///     let mut empty_fields: Vec<String> = vec![];
-///
-///     for field in total_fields {
-///         let is_filled = fields
-///             .iter()
-///             .any(|(key, value)| key == field && !value.is_empty());
-///         if !is_filled {
-///             empty_fields.push(field.to_string());
-///         }
-///     }
-///
-///     empty_fields
-/// }
-///
-/// fn remove_all_selected(select: &str) {
-///     let specialty_form_element = utils::document()
-///         .get_element_by_id(select)
-///         .unwrap()
-///         .dyn_into::<HtmlSelectElement>()
-///         .unwrap();
-///     let specialty_options = specialty_form_element.options();
-///
-///     for i in 0..specialty_options.length() {
-///         let option = specialty_options
-///             .get_with_index(i)
-///             .unwrap()
-///             .dyn_into::<HtmlOptionElement>()
-///             .unwrap();
-///
-///         option.set_selected(false);
-///     }
-/// }
-///
-/// fn remove_input_values() {
-///     let input_ids = vec!["first-name", "last-name", "email"];
-///     
-///     for id in input_ids {
-///         let input_from_element = utils::document()
-///             .get_element_by_id(id)
-///             .unwrap()
-///             .dyn_into::<HtmlInputElement>()
-///             .unwrap();
-///     
-///         input_from_element.set_value("");
-///     }
-///     
-///     let textarea = utils::document()
-///         .get_element_by_id("cover-letter")
-///         .unwrap()
-///         .dyn_into::<HtmlTextAreaElement>()
-///         .unwrap();
-///     
-///     textarea.set_value("");
-/// }
-///
-/// fn set_default_selected(select: &str) {
-///     let specialty_form_element = utils::document()
-///         .get_element_by_id(select)
-///         .unwrap()
-///         .dyn_into::<HtmlSelectElement>()
-///         .unwrap();
-///     let specialty_options = specialty_form_element.options();
-///
-///     let option = specialty_options
-///         .get_with_index(0)
-///         .unwrap()
-///         .dyn_into::<HtmlOptionElement>()
-///         .unwrap();
-///
-///     option.set_selected(true);
-/// }
 /// ```
 pub struct Form {
     link: ComponentLink<Self>,
@@ -360,9 +125,16 @@ pub struct Form {
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
-    /// Signal to emit the event submit. Default
+    /// Signal to emit the values of the named form controls when the form is submitted.
+    /// It is not emitted while any form control is invalid. Default `Callback::noop()`
     #[prop_or(Callback::noop())]
-    pub onsubmit_signal: Callback<FocusEvent>,
+    pub onsubmit_signal: Callback<HashMap<String, FormValue>>,
+    /// Signal to emit the event submit, even when a form control is invalid. Default `Callback::noop()`
+    #[prop_or(Callback::noop())]
+    pub onsubmit_event_signal: Callback<FocusEvent>,
+    /// Reset all the form controls after a valid submission. Default `false`
+    #[prop_or(false)]
+    pub reset_on_submit: bool,
     pub children: Children,
     /// The URL that processes the form submission
     #[prop_or_default]
@@ -387,9 +159,28 @@ pub struct Props {
     pub id: String,
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+/// Value of a named form control collected by the form
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FormValue {
+    /// Value of inputs, textareas and single selects
+    Text(String),
+    /// Selected values of a multiple select
+    Multiple(Vec<String>),
+    /// State of a checkbox
+    Checked(bool),
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Method {
     Post,
     Get,
@@ -412,7 +203,17 @@ impl Component for Form {
         match msg {
             Msg::Submitted(value) => {
                 value.prevent_default();
-                self.props.onsubmit_signal.emit(value);
+                self.props.onsubmit_event_signal.emit(value);
+
+                if let Some(form) = self.props.code_ref.cast::<HtmlFormElement>() {
+                    if is_valid_form(&form) {
+                        self.props.onsubmit_signal.emit(get_form_values(&form));
+
+                        if self.props.reset_on_submit {
+                            form.reset();
+                        }
+                    }
+                }
             }
         };
         true
@@ -436,7 +237,11 @@ impl Component for Form {
                 name=self.props.name.clone()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
-                class=classes!("form", self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("form")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.to_string()
             >
                 { self.props.children.clone() }
@@ -445,6 +250,60 @@ impl Component for Form {
     }
 }
 
+/// Reset all the form controls of the form referenced by `code_ref`
+pub fn reset_form(code_ref: &NodeRef) {
+    if let Some(form) = code_ref.cast::<HtmlFormElement>() {
+        form.reset();
+    }
+}
+
+/// The form is valid when the browser validation passes and none of its form controls is in error state
+fn is_valid_form(form: &HtmlFormElement) -> bool {
+    form.check_validity()
+        && get_form_controls(form)
+            .iter()
+            .all(|control| !control.invalid)
+}
+
+/// Values of the named yew_styles form controls of the form, collected from the controls registered in it
+pub fn get_form_values(form: &HtmlFormElement) -> HashMap<String, FormValue> {
+    get_form_controls(form)
+        .iter()
+        .filter(|control| !control.name.is_empty())
+        .filter_map(|control| Some((control.name.clone(), get_control_value(control)?)))
+        .collect()
+}
+
+fn get_control_value(control: &FormControl) -> Option<FormValue> {
+    let element: &Element = &control.element;
+
+    if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+        match input.type_().as_str() {
+            "submit" | "button" | "reset" | "image" => None,
+            "checkbox" => Some(FormValue::Checked(input.checked())),
+            "radio" if !input.checked() => None,
+            _ => Some(FormValue::Text(input.value())),
+        }
+    } else if let Some(select) = element.dyn_ref::<HtmlSelectElement>() {
+        if select.multiple() {
+            let options = select.selected_options();
+            let selected = (0..options.length())
+                .filter_map(|index| options.item(index))
+                .filter_map(|option| option.dyn_into::<HtmlOptionElement>().ok())
+                .map(|option| option.value())
+                .collect();
+
+            Some(FormValue::Multiple(selected))
+        } else {
+            Some(FormValue::Text(select.value()))
+        }
+    } else {
+        element
+            .dyn_ref::<HtmlTextAreaElement>()
+            .map(|textarea| FormValue::Text(textarea.value()))
+    }
+}
+
 fn get_method(method: Method) -> String {
     match method {
         Method::Get => "get".to_string(),
@@ -455,18 +314,13 @@ fn get_method(method: Method) -> String {
 
 #[wasm_bindgen_test]
 fn should_create_form_component() {
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "form-test".to_string(),
-        id: "form-test-id".to_string(),
-        onsubmit_signal: Callback::noop(),
-        method: Method::Post,
-        action: "".to_string(),
-        name: "form-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {<input id="result"/>}]),
-    };
+    let props = Props::builder()
+        .class_name("form-test".to_string())
+        .id("form-test-id".to_string())
+        .name("form-test".to_string())
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {<input id="result"/>}]))
+        .build();
 
     let form_component: App<Form> = App::new();
 
@@ -496,22 +350,18 @@ fn should_submit_the_form() {
         content.set_text_content(Some("form submitted"));
     });
 
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "form-test".to_string(),
-        id: "form-test-id".to_string(),
-        onsubmit_signal: onsubmit,
-        method: Method::Post,
-        action: "".to_string(),
-        name: "form-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {<input/>}]),
-    };
+    let props = Props::builder()
+        .class_name("form-test".to_string())
+        .id("form-test-id".to_string())
+        .name("form-test".to_string())
+        .onsubmit_event_signal(onsubmit)
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {<input/>}]))
+        .build();
 
     let focus_event = FocusEvent::new("Submit").unwrap();
 
-    props.onsubmit_signal.emit(focus_event);
+    props.onsubmit_event_signal.emit(focus_event);
 
     let form_element = utils::document().get_element_by_id("form").unwrap();
 
@@ -520,3 +370,89 @@ fn should_submit_the_form() {
         "form submitted".to_string()
     );
 }
+
+/// Register the form controls of the form like the yew_styles form controls do when they are rendered
+fn register_controls(form: &HtmlFormElement, invalid: &[&str]) -> Vec<FormControlRegistration> {
+    let controls = form.query_selector_all("input, select, textarea").unwrap();
+
+    (0..controls.length())
+        .filter_map(|index| controls.item(index))
+        .filter_map(|control| control.dyn_into::<Element>().ok())
+        .map(|control| {
+            let name = control.get_attribute("name").unwrap_or_default();
+            let registration = FormControlRegistration::new();
+            registration.report(Some(control), &name, invalid.contains(&name.as_str()));
+
+            registration
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_form_values() {
+    let form = utils::document()
+        .create_element("form")
+        .unwrap()
+        .dyn_into::<HtmlFormElement>()
+        .unwrap();
+
+    form.set_inner_html(
+        "<input name=\"first_name\" value=\"Francisco\"/>
+        <input name=\"terms\" type=\"checkbox\" checked/>
+        <input type=\"submit\" name=\"submit\" value=\"Send\"/>
+        <input value=\"without name\"/>
+        <select name=\"skills\" multiple>
+            <option value=\"yew\" selected>Yew</option>
+            <option value=\"rust\" selected>Rust</option>
+            <option value=\"go\">Go</option>
+        </select>
+        <textarea name=\"cover_letter\">Hello</textarea>",
+    );
+    let _registrations = register_controls(&form, &[]);
+    form.insert_adjacent_html(
+        "beforeend",
+        "<input name=\"not_registered\" value=\"plain input\"/>",
+    )
+    .unwrap();
+
+    let values = get_form_values(&form);
+
+    assert_eq!(values.len(), 4);
+    assert_eq!(
+        values.get("first_name"),
+        Some(&FormValue::Text("Francisco".to_string()))
+    );
+    assert_eq!(values.get("terms"), Some(&FormValue::Checked(true)));
+    assert_eq!(
+        values.get("skills"),
+        Some(&FormValue::Multiple(vec![
+            "yew".to_string(),
+            "rust".to_string()
+        ]))
+    );
+    assert_eq!(
+        values.get("cover_letter"),
+        Some(&FormValue::Text("Hello".to_string()))
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_block_invalid_form() {
+    let form = utils::document()
+        .create_element("form")
+        .unwrap()
+        .dyn_into::<HtmlFormElement>()
+        .unwrap();
+
+    form.set_inner_html("<input name=\"email\"/>");
+    let registrations = register_controls(&form, &[]);
+    assert!(is_valid_form(&form));
+
+    let invalid_registrations = register_controls(&form, &["email"]);
+    assert!(!is_valid_form(&form));
+
+    drop(registrations);
+    drop(invalid_registrations);
+    assert!(is_valid_form(&form));
+    assert!(get_form_values(&form).is_empty());
+}
@@ -0,0 +1,179 @@
+//! Form controls registered in the form which contains them.
+//!
+//! yew doesn't have a context api, so the form controls report themselves to their closest form every time
+//! they are rendered and the `Form` collects the values and the validity of its controls when it's submitted.
+//! A control leaves its form when its registration is dropped.
+//! The listeners are notified every time a control reports its state or leaves its form.
+//! Without the `form_component` feature there is no form to collect them, so the registration does nothing
+#[cfg(feature = "form_component")]
+use std::cell::{Cell, RefCell};
+use web_sys::Element;
+#[cfg(feature = "form_component")]
+use yew::Callback;
+
+#[cfg(feature = "form_component")]
+thread_local! {
+    static NEXT_ID: Cell<u32> = Cell::new(0);
+    static FORM_CONTROLS: RefCell<Vec<FormControl>> = RefCell::new(vec![]);
+    static LISTENERS: RefCell<Vec<(u32, Callback<()>)>> = RefCell::new(vec![]);
+}
+
+/// Form control reported to its form
+#[cfg(feature = "form_component")]
+#[derive(Clone)]
+pub struct FormControl {
+    id: u32,
+    form: Element,
+    /// Element of the form control
+    pub element: Element,
+    /// Name of the form control, the value is collected with it
+    pub name: String,
+    /// The form control is in error state, e.g. `error_state` or a failed async validation
+    pub invalid: bool,
+}
+
+/// Registration of a form control in the form which contains it
+#[cfg(feature = "form_component")]
+pub struct FormControlRegistration {
+    id: u32,
+}
+
+/// Registration of a form control in the form which contains it
+#[cfg(not(feature = "form_component"))]
+pub struct FormControlRegistration;
+
+/// Listener of the changes of the form controls, it stops listening when it's dropped
+#[cfg(feature = "form_component")]
+pub struct FormControlsListener {
+    id: u32,
+}
+
+#[cfg(feature = "form_component")]
+impl FormControlsListener {
+    pub fn new(callback: Callback<()>) -> Self {
+        let id = get_next_id();
+
+        LISTENERS.with(|listeners| listeners.borrow_mut().push((id, callback)));
+
+        Self { id }
+    }
+}
+
+#[cfg(feature = "form_component")]
+impl Drop for FormControlsListener {
+    fn drop(&mut self) {
+        LISTENERS.with(|listeners| {
+            listeners
+                .borrow_mut()
+                .retain(|(listener_id, _)| *listener_id != self.id)
+        });
+    }
+}
+
+#[cfg(feature = "form_component")]
+fn get_next_id() -> u32 {
+    NEXT_ID.with(|next_id| {
+        next_id.set(next_id.get() + 1);
+        next_id.get()
+    })
+}
+
+/// Notifies the listeners, the callbacks are cloned first because a listener can be dropped while it's notified
+#[cfg(feature = "form_component")]
+fn notify_listeners() {
+    let callbacks: Vec<Callback<()>> = LISTENERS.with(|listeners| {
+        listeners
+            .borrow()
+            .iter()
+            .map(|(_, callback)| callback.clone())
+            .collect()
+    });
+
+    for callback in callbacks {
+        callback.emit(());
+    }
+}
+
+#[cfg(feature = "form_component")]
+impl FormControlRegistration {
+    pub fn new() -> Self {
+        Self { id: get_next_id() }
+    }
+
+    /// Report the state of the form control after it's rendered, it's removed from its form
+    /// when the element isn't inside of a form
+    pub fn report(&self, element: Option<Element>, name: &str, invalid: bool) {
+        let form = element
+            .as_ref()
+            .and_then(|element| element.closest("form").ok().flatten());
+
+        FORM_CONTROLS.with(|controls| {
+            let mut controls = controls.borrow_mut();
+            let index = controls.iter().position(|control| control.id == self.id);
+
+            match (element, form, index) {
+                (Some(element), Some(form), index) => {
+                    let control = FormControl {
+                        id: self.id,
+                        form,
+                        element,
+                        name: name.to_string(),
+                        invalid,
+                    };
+
+                    match index {
+                        Some(index) => controls[index] = control,
+                        None => controls.push(control),
+                    }
+                }
+                (_, _, Some(index)) => {
+                    controls.remove(index);
+                }
+                _ => {}
+            }
+        });
+
+        notify_listeners();
+    }
+}
+
+#[cfg(not(feature = "form_component"))]
+impl FormControlRegistration {
+    pub fn new() -> Self {
+        FormControlRegistration
+    }
+
+    pub fn report(&self, _element: Option<Element>, _name: &str, _invalid: bool) {}
+}
+
+impl Default for FormControlRegistration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "form_component")]
+impl Drop for FormControlRegistration {
+    fn drop(&mut self) {
+        FORM_CONTROLS.with(|controls| {
+            controls
+                .borrow_mut()
+                .retain(|control| control.id != self.id)
+        });
+
+        notify_listeners();
+    }
+}
+
+/// Form controls registered in the form, in the order they were rendered for the first time
+#[cfg(feature = "form_component")]
+pub fn get_form_controls(form: &Element) -> Vec<FormControl> {
+    FORM_CONTROLS.with(|controls| {
+        controls
+            .borrow()
+            .iter()
+            .filter(|control| control.form == *form)
+            .cloned()
+            .collect()
+    })
+}
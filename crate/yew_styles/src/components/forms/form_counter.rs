@@ -0,0 +1,14 @@
+use yew::prelude::*;
+
+pub fn get_counter(show_counter: bool, length: usize, maxlength: usize, threshold: usize) -> Html {
+    if show_counter {
+        html! {
+            <span class=classes!(
+                "form-counter",
+                if length + threshold >= maxlength { "danger" } else { "" }
+            )>{format!("{} / {}", length, maxlength)}</span>
+        }
+    } else {
+        html! {}
+    }
+}
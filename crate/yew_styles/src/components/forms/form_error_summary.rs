@@ -0,0 +1,402 @@
+use super::form_context::{
+    get_form_controls, FormControl, FormControlRegistration, FormControlsListener,
+};
+use crate::messages::{format_message, get_messages};
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::get_random_string;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement, HtmlLabelElement};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Form Error Summary
+///
+/// List of the errors of the form controls shown at the top of a long form. It collects the yew_styles form
+/// controls in error state registered in the form which contains it, or in the one referenced by `form_ref`,
+/// with their label and their error message, and it keeps the list updated while the errors change. Each error links to its
+/// form control and clicking it moves the focus there. Nothing is rendered while there are no errors
+///
+/// ## Features required
+///
+/// form_error_summary, which includes form_component
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::forms::{
+///     form_component::Form,
+///     form_error_summary::FormErrorSummary,
+///     form_group::FormGroup,
+///     form_input::FormInput,
+///     form_label::FormLabel,
+///     form_submit::FormSubmit,
+/// };
+///
+/// pub struct SignUp {
+///     link: ComponentLink<Self>,
+///     email_error: bool,
+/// }
+///
+/// pub enum Msg {
+///     Email(String),
+/// }
+///
+/// impl Component for SignUp {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             email_error: false,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Email(email) => self.email_error = !email.contains('@'),
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Form onsubmit_signal=Callback::noop()>
+///                 <FormErrorSummary />
+///                 <FormGroup>
+///                     <FormLabel text="Email" label_for="email" />
+///                     <FormInput
+///                         id="email"
+///                         error_state=self.email_error
+///                         error_message="Enter a valid email address"
+///                         oninput_signal=self.link.callback(|e: InputData| Msg::Email(e.value))
+///                     />
+///                 </FormGroup>
+///                 <FormSubmit value="Sign up" />
+///             </Form>
+///         }
+///     }
+/// }
+/// ```
+pub struct FormErrorSummary {
+    link: ComponentLink<Self>,
+    props: Props,
+    errors: Vec<FieldError>,
+    title_id: String,
+    _listener: FormControlsListener,
+}
+
+/// Error of an invalid form control
+#[derive(Clone, Debug, PartialEq)]
+struct FieldError {
+    id: String,
+    label: String,
+    message: String,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Form with the form controls, the form which contains the summary when it isn't set. Default `NodeRef::default()`
+    #[prop_or_default]
+    pub form_ref: NodeRef,
+    /// Title of the list. Default the message `form_error_summary`
+    #[prop_or_default]
+    pub title: Option<String>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Changed,
+    Clicked(usize, MouseEvent),
+}
+
+impl Component for FormErrorSummary {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            errors: vec![],
+            title_id: format!("form-error-summary-{}", get_random_string(10)),
+            _listener: FormControlsListener::new(link.callback(|_| Msg::Changed)),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Changed => {
+                let errors = self.get_errors();
+
+                if errors == self.errors {
+                    return false;
+                }
+                self.errors = errors;
+            }
+            Msg::Clicked(index, mouse_event) => {
+                mouse_event.prevent_default();
+
+                let control = self
+                    .get_invalid_controls()
+                    .into_iter()
+                    .nth(index)
+                    .and_then(|control| control.dyn_into::<HtmlElement>().ok());
+
+                if let Some(control) = control {
+                    control.scroll_into_view_with_bool(false);
+                    control.focus().ok();
+                }
+                return false;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.form_ref != props.form_ref {
+                self.link.send_message(Msg::Changed);
+            }
+            self.props = props;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::Changed);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let title = match &self.props.title {
+            Some(title) => title.clone(),
+            None => format_message(
+                &get_messages().form_error_summary,
+                &[("count", self.errors.len().to_string())],
+            ),
+        };
+
+        html! {
+            <div
+                class=ComponentClasses::new("form-error-summary")
+                    .state("hidden", self.errors.is_empty())
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                role="alert"
+                tabindex="-1"
+                aria-labelledby=self.title_id.clone()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {if self.errors.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <>
+                            <h2 class="form-error-summary-title" id=self.title_id.clone()>{title}</h2>
+                            <ul class="form-error-summary-list">
+                                {for self.errors.iter().enumerate().map(|(index, error)| html! {
+                                    <li>
+                                        <a
+                                            href=format!("#{}", error.id)
+                                            onclick=self.link.callback(move |mouse_event| Msg::Clicked(index, mouse_event))
+                                        >
+                                            {get_error_text(&error.label, &error.message)}
+                                        </a>
+                                    </li>
+                                })}
+                            </ul>
+                        </>
+                    }
+                }}
+            </div>
+        }
+    }
+}
+
+impl FormErrorSummary {
+    fn get_form(&self) -> Option<Element> {
+        self.props.form_ref.cast::<Element>().or_else(|| {
+            self.props
+                .code_ref
+                .cast::<Element>()?
+                .closest("form")
+                .ok()
+                .flatten()
+        })
+    }
+
+    fn get_invalid_controls(&self) -> Vec<Element> {
+        match self.get_form() {
+            Some(form) => get_form_controls(&form)
+                .into_iter()
+                .filter(|control| control.invalid)
+                .map(|FormControl { element, .. }| element)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn get_errors(&self) -> Vec<FieldError> {
+        let document = utils::document();
+        let labels: Vec<HtmlLabelElement> = match self
+            .get_form()
+            .and_then(|form| form.query_selector_all("label").ok())
+        {
+            Some(labels) => (0..labels.length())
+                .filter_map(|index| labels.item(index))
+                .filter_map(|label| label.dyn_into::<HtmlLabelElement>().ok())
+                .collect(),
+            None => vec![],
+        };
+
+        self.get_invalid_controls()
+            .into_iter()
+            .map(|control| {
+                let id = control.id();
+                let label = labels
+                    .iter()
+                    .find(|label| !id.is_empty() && label.html_for() == id)
+                    .and_then(|label| label.text_content())
+                    .or_else(|| control.get_attribute("aria-label"))
+                    .or_else(|| control.get_attribute("name"))
+                    .unwrap_or_default();
+                // The error messages are the elements in `aria-describedby` rendered with the class form-error
+                let message = control
+                    .get_attribute("aria-describedby")
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .filter_map(|id| document.get_element_by_id(id))
+                    .find(|element| element.class_list().contains("form-error"))
+                    .and_then(|element| element.text_content())
+                    .map(|message| {
+                        message
+                            .trim_start_matches(&get_messages().form_error_prefix)
+                            .to_string()
+                    })
+                    .unwrap_or_default();
+
+                FieldError {
+                    id,
+                    label: label.trim().to_string(),
+                    message,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Text of an error in the list, the label of the form control and its error message
+fn get_error_text(label: &str, message: &str) -> String {
+    match (label.is_empty(), message.is_empty()) {
+        (false, false) => format!("{}: {}", label, message),
+        (false, true) => label.to_string(),
+        _ => message.to_string(),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_error_text() {
+    assert_eq!(
+        get_error_text("Email", "Enter a valid email address"),
+        "Email: Enter a valid email address"
+    );
+    assert_eq!(get_error_text("Email", ""), "Email");
+    assert_eq!(get_error_text("", "Required"), "Required");
+}
+
+#[wasm_bindgen_test]
+fn should_list_the_invalid_form_controls() {
+    let document = utils::document();
+    let form = document.create_element("form").unwrap();
+    form.set_inner_html(
+        "<label for=\"summary-email\">Email</label>\
+        <input id=\"summary-email\" name=\"email\" aria-describedby=\"summary-email-error\" />\
+        <span class=\"form-error\" id=\"summary-email-error\">Enter a valid email address</span>\
+        <label for=\"summary-name\">Name</label>\
+        <input id=\"summary-name\" name=\"name\" aria-invalid=\"true\" />\
+        <div id=\"summary-output\"></div>",
+    );
+    document
+        .get_element_by_id("output")
+        .unwrap()
+        .append_child(&form)
+        .unwrap();
+
+    // Registered like the yew_styles form controls do when they are rendered
+    let email = FormControlRegistration::new();
+    email.report(document.get_element_by_id("summary-email"), "email", true);
+    let name = FormControlRegistration::new();
+    name.report(document.get_element_by_id("summary-name"), "name", false);
+
+    let props = Props::builder()
+        .id("form-error-summary-test".to_string())
+        .build();
+
+    let form_error_summary: App<FormErrorSummary> = App::new();
+    form_error_summary
+        .mount_with_props(document.get_element_by_id("summary-output").unwrap(), props);
+
+    let links = document
+        .get_element_by_id("form-error-summary-test")
+        .unwrap()
+        .get_elements_by_tag_name("a");
+
+    assert_eq!(links.length(), 1);
+    assert_eq!(
+        links.item(0).unwrap().get_attribute("href").unwrap(),
+        "#summary-email"
+    );
+    assert_eq!(
+        links.item(0).unwrap().text_content().unwrap(),
+        "Email: Enter a valid email address"
+    );
+
+    name.report(document.get_element_by_id("summary-name"), "name", true);
+
+    assert_eq!(links.length(), 2);
+    assert_eq!(links.item(1).unwrap().text_content().unwrap(), "Name");
+
+    drop(email);
+
+    assert_eq!(links.length(), 1);
+    assert_eq!(links.item(0).unwrap().text_content().unwrap(), "Name");
+}
@@ -1,13 +1,35 @@
-use super::error_message::get_error_message;
-use crate::styles::{get_palette, get_size, Palette, Size};
+use super::error_message::{create_error_id, get_described_by, get_error_id, get_error_message};
+use super::form_upload::{
+    get_upload_percentage, UploadCancel, UploadRequest, UploadResult, UploadStatus, UploadTask,
+};
+use crate::messages::get_messages;
+use crate::styles::{get_palette, get_size, tokens::Spaces, ComponentClasses, Palette, Size};
+use js_sys::Array;
+use std::cell::RefCell;
+use std::rc::Rc;
 use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::{DataTransfer, File, HtmlElement};
 use yew::prelude::*;
 use yew::{utils, App};
 
 pub struct FormFile {
     link: ComponentLink<Self>,
     props: Props,
+    error_id: String,
+    uploads: Vec<Upload>,
+    /// Id of the last upload started, the reports of the previous attempts of a file are ignored
+    upload_id: u32,
+}
+
+struct Upload {
+    id: u32,
+    file: File,
+    progress: f64,
+    status: UploadStatus,
+    task: Option<UploadTask>,
+    cancel: UploadCancel,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -22,6 +44,19 @@ pub struct Props {
     pub input_size: Size,
     /// Signal to emit the event change
     pub onchange_signal: Callback<ChangeData>,
+    /// Endpoint where the selected files are sent one by one with a `POST` multipart request,
+    /// in the field `name` or `file` when there is no name. Default `None`
+    #[prop_or_default]
+    pub upload_url: Option<String>,
+    /// Custom driver which uploads the selected files instead of `upload_url`. Default `None`
+    #[prop_or_default]
+    pub onupload_signal: Option<Callback<UploadRequest>>,
+    /// Emitted with the name of the file and the response when an upload is completed
+    #[prop_or(Callback::noop())]
+    pub onuploaded_signal: Callback<(String, String)>,
+    /// Emitted with the name of the file and the error when an upload fails
+    #[prop_or(Callback::noop())]
+    pub onuploaderror_signal: Callback<(String, String)>,
     /// Media capture input method in file upload controls
     #[prop_or_default]
     pub capture: String,
@@ -40,6 +75,15 @@ pub struct Props {
     /// Show error message when error_state is true
     #[prop_or_default]
     pub error_message: String,
+    /// Accessible name of the form control when there is no visible label. Default `""`
+    #[prop_or_default]
+    pub aria_label: String,
+    /// Id of the element which labels the form control. Default `""`
+    #[prop_or_default]
+    pub aria_labelledby: String,
+    /// Ids of the elements which describe the form control, the error message is added when it is shown. Default `""`
+    #[prop_or_default]
+    pub aria_describedby: String,
     /// Alt attribute for the image type
     #[prop_or_default]
     pub alt: String,
@@ -70,6 +114,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
@@ -78,6 +128,10 @@ pub struct Props {
 #[derive(Debug)]
 pub enum Msg {
     Changed(ChangeData),
+    Progress(u32, f64),
+    Uploaded(u32, UploadResult),
+    Cancel(u32),
+    Retry(u32),
 }
 
 impl Component for FormFile {
@@ -85,14 +139,79 @@ impl Component for FormFile {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            props,
+            error_id: create_error_id(),
+            uploads: vec![],
+            upload_id: 0,
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Changed(changed_data) => {
+                if let ChangeData::Files(files) = &changed_data {
+                    if self.props.upload_url.is_some() || self.props.onupload_signal.is_some() {
+                        for index in 0..files.length() {
+                            if let Some(file) = files.get(index) {
+                                let upload = self.start_upload(file);
+                                self.uploads.push(upload);
+                            }
+                        }
+                    }
+                }
+
                 self.props.onchange_signal.emit(changed_data);
             }
+            Msg::Progress(id, progress) => match self.get_uploading(id) {
+                Some(upload) => upload.progress = progress,
+                None => return false,
+            },
+            Msg::Uploaded(id, result) => {
+                let upload = match self
+                    .uploads
+                    .iter_mut()
+                    .find(|upload| upload.id == id && upload.status == UploadStatus::Uploading)
+                {
+                    Some(upload) => upload,
+                    None => return false,
+                };
+                upload.task = None;
+
+                match result {
+                    Ok(response) => {
+                        upload.progress = 1.0;
+                        upload.status = UploadStatus::Completed;
+                        self.props
+                            .onuploaded_signal
+                            .emit((upload.file.name(), response));
+                    }
+                    Err(error) => {
+                        upload.status = UploadStatus::Failed(error.clone());
+                        self.props
+                            .onuploaderror_signal
+                            .emit((upload.file.name(), error));
+                    }
+                };
+            }
+            Msg::Cancel(id) => match self.get_uploading(id) {
+                Some(upload) => {
+                    upload.task = None;
+                    upload.status = UploadStatus::Cancelled;
+                    upload.cancel.cancel();
+                }
+                None => return false,
+            },
+            Msg::Retry(id) => {
+                let index = match self.uploads.iter().position(|upload| upload.id == id) {
+                    Some(index) => index,
+                    None => return false,
+                };
+                let file = self.uploads[index].file.clone();
+
+                self.uploads[index] = self.start_upload(file);
+            }
         };
 
         true
@@ -108,20 +227,29 @@ impl Component for FormFile {
     }
 
     fn view(&self) -> Html {
+        let error_state = self.props.error_state;
+        let error_id = get_error_id(&self.props.id, &self.error_id);
+
         html! {
             <>
                 <input
+                    aria-label=self.props.aria_label.clone()
+                    aria-labelledby=self.props.aria_labelledby.clone()
+                    aria-describedby=get_described_by(error_state, &error_id, &self.props.aria_describedby)
+                    aria-invalid=error_state.to_string()
                     type="file"
                     id=self.props.id.clone()
-                    class=classes!(
-                        "form-file",
-                        get_palette(self.props.input_palette.clone()),
-                        get_size(self.props.input_size.clone()),
-                        if self.props.underline { "underline" } else { "" },
-                        if self.props.hidden { "hidden" } else { "" },
-                        self.props.class_name.clone(),
-                        self.props.styles.clone(),
-                    )
+                    class=ComponentClasses::new("form-file")
+                        .semantic(vec![
+                            get_palette(self.props.input_palette.clone()),
+                            get_size(self.props.input_size.clone()),
+                        ])
+                        .state("underline", self.props.underline)
+                        .state("hidden", self.props.hidden)
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
                     key=self.props.key.clone()
                     ref=self.props.code_ref.clone()
                     onchange=self.link.callback(Msg::Changed)
@@ -135,37 +263,173 @@ impl Component for FormFile {
                     disabled=self.props.disabled
                     autofocus=self.props.autofocus
                 />
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_error_message(error_state, self.props.error_message.clone(), error_id)}
+                {if self.uploads.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <ul class="form-file-uploads" aria-live="polite">
+                            {for self.uploads.iter().map(|upload| self.get_upload(upload))}
+                        </ul>
+                    }
+                }}
             </>
         }
     }
+
+    fn destroy(&mut self) {
+        for upload in &self.uploads {
+            if upload.status == UploadStatus::Uploading {
+                upload.cancel.cancel();
+            }
+        }
+    }
+}
+
+impl FormFile {
+    fn start_upload(&mut self, file: File) -> Upload {
+        self.upload_id += 1;
+        let id = self.upload_id;
+        let progress = self
+            .link
+            .callback(move |progress| Msg::Progress(id, progress));
+        let respond = self.link.callback(move |result| Msg::Uploaded(id, result));
+        let mut task = None;
+        let cancel = UploadCancel::default();
+
+        if let Some(onupload_signal) = &self.props.onupload_signal {
+            onupload_signal.emit(UploadRequest::new(
+                file.clone(),
+                progress,
+                respond,
+                cancel.clone(),
+            ));
+        } else if let Some(upload_url) = &self.props.upload_url {
+            let field = if self.props.name.is_empty() {
+                "file"
+            } else {
+                &self.props.name
+            };
+            task = UploadTask::new(upload_url, field, &file, progress, respond);
+
+            if task.is_none() {
+                self.link
+                    .send_message(Msg::Uploaded(id, Err(get_messages().upload_failed)));
+            }
+        }
+
+        Upload {
+            id,
+            file,
+            progress: 0.0,
+            status: UploadStatus::Uploading,
+            task,
+            cancel,
+        }
+    }
+
+    fn get_uploading(&mut self, id: u32) -> Option<&mut Upload> {
+        self.uploads
+            .iter_mut()
+            .find(|upload| upload.id == id && upload.status == UploadStatus::Uploading)
+    }
+
+    fn get_upload(&self, upload: &Upload) -> Html {
+        let messages = get_messages();
+        let id = upload.id;
+        let name = upload.file.name();
+        let retry = html! {
+            <button
+                type="button"
+                class="form-file-upload-action"
+                onclick=self.link.callback(move |_| Msg::Retry(id))
+            >
+                {messages.upload_retry.clone()}
+            </button>
+        };
+
+        html! {
+            <li class=classes!("form-file-upload", get_status_class(&upload.status))>
+                <span class="form-file-upload-name">{name.clone()}</span>
+                {match &upload.status {
+                    UploadStatus::Uploading => {
+                        let percentage = get_upload_percentage(upload.progress);
+
+                        html! {
+                            <>
+                                <div
+                                    class="form-file-upload-bar"
+                                    role="progressbar"
+                                    aria-label=name
+                                    aria-valuemin="0"
+                                    aria-valuemax="100"
+                                    aria-valuenow=percentage.to_string()
+                                >
+                                    <div class="form-file-upload-value" style=format!("width: {}%", percentage)></div>
+                                </div>
+                                <button
+                                    type="button"
+                                    class="form-file-upload-action"
+                                    onclick=self.link.callback(move |_| Msg::Cancel(id))
+                                >
+                                    {messages.upload_cancel}
+                                </button>
+                            </>
+                        }
+                    }
+                    UploadStatus::Completed => html! {
+                        <span class="form-file-upload-status">{messages.upload_completed}</span>
+                    },
+                    UploadStatus::Failed(error) => html! {
+                        <>
+                            <span class="form-file-upload-status" role="alert">{error}</span>
+                            {retry}
+                        </>
+                    },
+                    UploadStatus::Cancelled => html! {
+                        <>
+                            <span class="form-file-upload-status">{messages.upload_cancelled}</span>
+                            {retry}
+                        </>
+                    },
+                }}
+            </li>
+        }
+    }
+}
+
+fn get_status_class(status: &UploadStatus) -> &'static str {
+    match status {
+        UploadStatus::Uploading => "uploading",
+        UploadStatus::Completed => "completed",
+        UploadStatus::Failed(_) => "failed",
+        UploadStatus::Cancelled => "cancelled",
+    }
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_upload_percentage() {
+    assert_eq!(get_upload_percentage(0.0), 0);
+    assert_eq!(get_upload_percentage(0.456), 46);
+    assert_eq!(get_upload_percentage(1.2), 100);
+    assert_eq!(
+        get_status_class(&UploadStatus::Failed("HTTP 500".to_string())),
+        "failed"
+    );
 }
 
 #[wasm_bindgen_test]
 fn should_create_form_input() {
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        id: "form-input-id-test".to_string(),
-        class_name: "form-input-class-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        onchange_signal: Callback::noop(),
-        error_message: "invalid input".to_string(),
-        error_state: false,
-        name: "input-test".to_string(),
-        input_palette: Palette::Standard,
-        input_size: Size::Medium,
-        required: false,
-        autofocus: false,
-        multiple: false,
-        alt: "input test".to_string(),
-        readonly: false,
-        underline: false,
-        disabled: false,
-        accept: vec!["image/png".to_string()],
-        hidden: false,
-        capture: "".to_string(),
-    };
+    let props = Props::builder()
+        .accept(vec!["image/png".to_string()])
+        .alt("input test".to_string())
+        .class_name("form-input-class-test".to_string())
+        .error_message("invalid input".to_string())
+        .id("form-input-id-test".to_string())
+        .name("input-test".to_string())
+        .onchange_signal(Callback::noop())
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let form_input: App<FormFile> = App::new();
 
@@ -180,3 +444,129 @@ fn should_create_form_input() {
 
     assert_eq!(form_input_element.tag_name(), "INPUT");
 }
+
+#[wasm_bindgen_test]
+fn should_upload_the_files_with_the_driver() {
+    let requests: Rc<RefCell<Vec<UploadRequest>>> = Rc::new(RefCell::new(vec![]));
+    let uploaded: Rc<RefCell<Vec<(String, String)>>> = Rc::new(RefCell::new(vec![]));
+    let failed: Rc<RefCell<Vec<(String, String)>>> = Rc::new(RefCell::new(vec![]));
+    let cancelled = Rc::new(RefCell::new(false));
+    let onupload_requests = requests.clone();
+    let onuploaded = uploaded.clone();
+    let onuploaderror = failed.clone();
+
+    let props = Props::builder()
+        .accept(vec![])
+        .id("form-file-upload-test".to_string())
+        .onchange_signal(Callback::noop())
+        .onupload_signal(Some(Callback::from(move |request: UploadRequest| {
+            onupload_requests.borrow_mut().push(request)
+        })))
+        .onuploaded_signal(Callback::from(move |upload: (String, String)| {
+            onuploaded.borrow_mut().push(upload)
+        }))
+        .onuploaderror_signal(Callback::from(move |upload: (String, String)| {
+            onuploaderror.borrow_mut().push(upload)
+        }))
+        .build();
+
+    let form_file: App<FormFile> = App::new();
+    let link = form_file.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let data_transfer = DataTransfer::new().unwrap();
+    for name in &["report.pdf", "photo.png"] {
+        let file = File::new_with_str_sequence(&Array::of1(&"content".into()), name).unwrap();
+        data_transfer.items().add_with_file(&file).unwrap();
+    }
+    link.send_message(Msg::Changed(ChangeData::Files(
+        data_transfer.files().unwrap(),
+    )));
+
+    let get_upload = |index| {
+        utils::document()
+            .query_selector_all(".form-file-upload")
+            .unwrap()
+            .get(index)
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap()
+    };
+
+    assert_eq!(requests.borrow().len(), 2);
+    assert_eq!(requests.borrow()[0].file.name(), "report.pdf");
+    assert_eq!(get_upload(0).class_name(), "form-file-upload uploading");
+
+    requests.borrow()[0].progress.emit(0.5);
+
+    let progress_bar = get_upload(0)
+        .query_selector(".form-file-upload-bar")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(progress_bar.get_attribute("aria-valuenow").unwrap(), "50");
+
+    requests.borrow()[0]
+        .respond
+        .emit(Ok("report uploaded".to_string()));
+
+    assert_eq!(get_upload(0).class_name(), "form-file-upload completed");
+    assert_eq!(
+        *uploaded.borrow(),
+        vec![("report.pdf".to_string(), "report uploaded".to_string())]
+    );
+
+    let oncancel = cancelled.clone();
+    requests.borrow()[1].oncancel(Callback::from(move |_| *oncancel.borrow_mut() = true));
+    get_upload(1)
+        .query_selector(".form-file-upload-action")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    assert!(*cancelled.borrow());
+    assert!(requests.borrow()[1].is_cancelled());
+    assert_eq!(get_upload(1).class_name(), "form-file-upload cancelled");
+
+    requests.borrow()[1]
+        .respond
+        .emit(Ok("photo uploaded".to_string()));
+
+    assert_eq!(get_upload(1).class_name(), "form-file-upload cancelled");
+    assert_eq!(uploaded.borrow().len(), 1);
+
+    get_upload(1)
+        .query_selector(".form-file-upload-action")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    assert_eq!(requests.borrow().len(), 3);
+    assert!(!requests.borrow()[2].is_cancelled());
+    assert_eq!(get_upload(1).class_name(), "form-file-upload uploading");
+
+    requests.borrow()[2]
+        .respond
+        .emit(Err("HTTP 500".to_string()));
+
+    assert_eq!(get_upload(1).class_name(), "form-file-upload failed");
+    assert_eq!(
+        get_upload(1)
+            .query_selector(".form-file-upload-status")
+            .unwrap()
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "HTTP 500"
+    );
+    assert_eq!(
+        *failed.borrow(),
+        vec![("photo.png".to_string(), "HTTP 500".to_string())]
+    );
+}
@@ -1,3 +1,4 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -7,7 +8,7 @@ use yew::{utils, App};
 ///
 /// ## Features required
 ///
-/// forms
+/// form_group, form_label, form_textarea
 ///
 /// ## Example
 ///
@@ -72,6 +73,7 @@ pub struct FormGroup {
 
 /// Orientation type
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Orientation {
     Horizontal,
     Vertical,
@@ -96,6 +98,12 @@ pub struct Props {
     pub id: String,
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -123,7 +131,12 @@ impl Component for FormGroup {
     fn view(&self) -> Html {
         html! {
             <div
-                class=classes!("form-group", get_orientation(self.props.orientation.clone()), self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("form-group")
+                    .semantic(get_orientation(self.props.orientation.clone()))
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
@@ -143,17 +156,15 @@ fn get_orientation(orientation: Orientation) -> String {
 
 #[wasm_bindgen_test]
 fn should_create_form_group_horizontal_oriented() {
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        id: "form-group-test-id".to_string(),
-        class_name: "form-group-test-class".to_string(),
-        orientation: Orientation::Horizontal,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let props = Props::builder()
+        .class_name("form-group-test-class".to_string())
+        .id("form-group-test-id".to_string())
+        .orientation(Orientation::Horizontal)
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <input id="input-child"/>
-        }]),
-    };
+        }]))
+        .build();
 
     let form_group: App<FormGroup> = App::new();
     form_group.mount_with_props(
@@ -175,17 +186,14 @@ fn should_create_form_group_horizontal_oriented() {
 
 #[wasm_bindgen_test]
 fn should_create_form_group_vertical_oriented() {
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        id: "form-group-test-id".to_string(),
-        class_name: "form-group-test-class".to_string(),
-        orientation: Orientation::Vertical,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let props = Props::builder()
+        .class_name("form-group-test-class".to_string())
+        .id("form-group-test-id".to_string())
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <input id="input-child"/>
-        }]),
-    };
+        }]))
+        .build();
 
     let form_group: App<FormGroup> = App::new();
     form_group.mount_with_props(
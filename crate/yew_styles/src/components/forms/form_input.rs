@@ -1,15 +1,23 @@
-use super::error_message::get_error_message;
-use crate::styles::{get_palette, get_size, Palette, Size};
+use super::controlled::{get_controlled_value, get_initial_value};
+use super::error_message::{create_error_id, get_described_by, get_error_id, get_error_message};
+use super::form_context::FormControlRegistration;
+use super::form_counter::get_counter;
+use super::form_validation::{ValidationRequest, ValidationResult};
+use crate::styles::{get_palette, get_size, tokens::Spaces, ComponentClasses, Palette, Size};
+use std::time::Duration;
 use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlInputElement, InputEvent};
 use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
 use yew::{utils, App};
 
 /// # Form Input
 ///
 /// ## Features required
 ///
-/// forms
+/// form_input
 ///
 /// ## Example
 ///
@@ -65,10 +73,19 @@ use yew::{utils, App};
 pub struct FormInput {
     link: ComponentLink<Self>,
     props: Props,
+    error_id: String,
+    value: String,
+    checked: bool,
+    validating: bool,
+    validation_error: Option<String>,
+    validation_id: u32,
+    validation_task: Option<TimeoutTask>,
+    registration: FormControlRegistration,
 }
 
 /// Different type inputs supported. You can find more information [here](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input)
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputType {
     Button,
     Checkbox,
@@ -116,9 +133,18 @@ pub struct Props {
     /// Content to be appear in the form control when the form control is empty
     #[prop_or_default]
     pub placeholder: String,
-    /// Whether the command or control is checked
+    /// Controlled value, every new value is pushed into the input even if the user has changed it. Default `None`
     #[prop_or_default]
-    pub checked: bool,
+    pub value: Option<String>,
+    /// Initial value when the input isn't controlled by `value`. Default `""`
+    #[prop_or_default]
+    pub default_value: String,
+    /// Controlled checked state of the checkboxes and radios, it works like `value`. Default `None`
+    #[prop_or_default]
+    pub checked: Option<bool>,
+    /// Initial checked state when the input isn't controlled by `checked`. Default `false`
+    #[prop_or(false)]
+    pub default_checked: bool,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -182,9 +208,37 @@ pub struct Props {
     /// Show error message when error_state is true
     #[prop_or_default]
     pub error_message: String,
+    /// Accessible name of the form control when there is no visible label. Default `""`
+    #[prop_or_default]
+    pub aria_label: String,
+    /// Id of the element which labels the form control. Default `""`
+    #[prop_or_default]
+    pub aria_labelledby: String,
+    /// Ids of the elements which describe the form control, the error message is added when it is shown. Default `""`
+    #[prop_or_default]
+    pub aria_describedby: String,
+    /// Show a counter with the number of characters and the maxlength. Default `false`
+    #[prop_or(false)]
+    pub show_counter: bool,
+    /// Remaining characters to show the counter with the danger color. Default `10`
+    #[prop_or(10)]
+    pub counter_threshold: u32,
+    /// Signal to validate the value asynchronously after the user stops typing.
+    /// The result feeds the error state and the error message. Default `None`
+    #[prop_or(None)]
+    pub onvalidate_signal: Option<Callback<ValidationRequest>>,
+    /// Milliseconds to wait since the last input before the async validation. Default `300`
+    #[prop_or(300)]
+    pub validation_debounce: u64,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 #[derive(Debug)]
@@ -192,6 +246,8 @@ pub enum Msg {
     Input(InputData),
     Blur(FocusEvent),
     KeyPressed(KeyboardEvent),
+    Validate,
+    Validated(u32, ValidationResult),
 }
 
 impl Component for FormInput {
@@ -199,14 +255,62 @@ impl Component for FormInput {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            value: get_initial_value(&props.value, &props.default_value),
+            checked: get_initial_value(&props.checked, &props.default_checked),
+            props,
+            error_id: create_error_id(),
+            validating: false,
+            validation_error: None,
+            validation_id: 0,
+            validation_task: None,
+            registration: FormControlRegistration::new(),
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Input(input_data) => {
+                self.value = input_data.value.clone();
+                if let Some(input) = self.props.code_ref.cast::<HtmlInputElement>() {
+                    self.checked = input.checked();
+                }
+                // The pending validation is of the previous value
+                self.validation_id += 1;
+
+                if self.props.onvalidate_signal.is_some() {
+                    self.validation_task = Some(TimeoutService::spawn(
+                        Duration::from_millis(self.props.validation_debounce),
+                        self.link.callback(|_| Msg::Validate),
+                    ));
+                }
                 self.props.oninput_signal.emit(input_data);
             }
+            Msg::Validate => {
+                self.validation_task = None;
+
+                if let Some(onvalidate_signal) = &self.props.onvalidate_signal {
+                    self.validation_id += 1;
+                    self.validating = true;
+
+                    let validation_id = self.validation_id;
+
+                    onvalidate_signal.emit(ValidationRequest {
+                        value: self.value.clone(),
+                        respond: self
+                            .link
+                            .callback(move |result| Msg::Validated(validation_id, result)),
+                    });
+                }
+            }
+            Msg::Validated(validation_id, result) => {
+                // Results of previous values are discarded
+                if validation_id == self.validation_id {
+                    self.validating = false;
+                    self.validation_error = result.err();
+                }
+            }
             Msg::Blur(focus_event) => {
                 self.props.onblur_signal.emit(focus_event);
             }
@@ -220,6 +324,7 @@ impl Component for FormInput {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            self.push_controlled_value(&props);
             self.props = props;
             true
         } else {
@@ -227,24 +332,45 @@ impl Component for FormInput {
         }
     }
 
+    fn rendered(&mut self, _first_render: bool) {
+        self.registration.report(
+            self.props.code_ref.cast::<Element>(),
+            &self.props.name,
+            self.props.error_state || self.validation_error.is_some(),
+        );
+    }
+
     fn view(&self) -> Html {
+        let error_state = self.props.error_state || self.validation_error.is_some();
+        let error_id = get_error_id(&self.props.id, &self.error_id);
+
         html! {
             <>
                 <input
+                    aria-label=self.props.aria_label.clone()
+                    aria-labelledby=self.props.aria_labelledby.clone()
+                    aria-describedby=get_described_by(error_state, &error_id, &self.props.aria_describedby)
+                    aria-invalid=error_state.to_string()
                     id=self.props.id.clone()
-                    class=classes!(
-                        "form-input",
-                        get_palette(self.props.input_palette.clone()),
-                        get_size(self.props.input_size.clone()),
-                        if self.props.underline { "underline" } else { "" },
-                        self.props.class_name.clone(),
-                        self.props.styles.clone(),
-                    )
+                    class=ComponentClasses::new("form-input")
+                        .semantic(vec![
+                            get_palette(self.props.input_palette.clone()),
+                            get_size(self.props.input_size.clone()),
+                        ])
+                        .state("underline", self.props.underline)
+                        .state("validating", self.validating)
+                        .state("error", error_state)
+                        .state("disabled", self.props.disabled)
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
                     key=self.props.key.clone()
                     ref=self.props.code_ref.clone()
                     type=get_type(self.props.input_type.clone())
                     oninput=self.link.callback(Msg::Input)
-                    checked=self.props.checked
+                    value=self.value.clone()
+                    checked=self.checked
                     onblur=self.link.callback(Msg::Blur)
                     onkeydown=self.link.callback(Msg::KeyPressed)
                     name=self.props.name.clone()
@@ -263,12 +389,49 @@ impl Component for FormInput {
                     step=self.props.step.to_string()
                     list=self.props.list.clone()
                 />
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_counter(
+                    self.props.show_counter,
+                    self.value.chars().count(),
+                    self.props.maxlength as usize,
+                    self.props.counter_threshold as usize
+                )}
+                {get_error_message(
+                    error_state,
+                    self.validation_error
+                        .clone()
+                        .unwrap_or_else(|| self.props.error_message.clone()),
+                    error_id
+                )}
             </>
         }
     }
 }
 
+impl FormInput {
+    /// Push the new controlled value and checked state into the input, the user may have changed it since the last ones
+    fn push_controlled_value(&mut self, props: &Props) {
+        let input = props.code_ref.cast::<HtmlInputElement>();
+        let (value, checked) = match &input {
+            Some(input) => (input.value(), input.checked()),
+            None => (self.value.clone(), self.checked),
+        };
+
+        if let Some(value) = get_controlled_value(&props.value, &value) {
+            if let Some(input) = &input {
+                input.set_value(&value);
+            }
+            self.value = value;
+        }
+
+        if let Some(checked) = get_controlled_value(&props.checked, &checked) {
+            if let Some(input) = &input {
+                input.set_checked(checked);
+            }
+            self.checked = checked;
+        }
+    }
+}
+
 fn get_type(input_type: InputType) -> String {
     match input_type {
         InputType::Button => "button".to_string(),
@@ -297,38 +460,18 @@ fn get_type(input_type: InputType) -> String {
 
 #[wasm_bindgen_test]
 fn should_create_form_input() {
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        id: "form-input-id-test".to_string(),
-        class_name: "form-input-class-test".to_string(),
-        input_type: InputType::Text,
-        oninput_signal: Callback::noop(),
-        onblur_signal: Callback::noop(),
-        onkeydown_signal: Callback::noop(),
-        checked: false,
-        error_message: "invalid input".to_string(),
-        error_state: false,
-        name: "input-test".to_string(),
-        input_palette: Palette::Standard,
-        input_size: Size::Medium,
-        placeholder: "test input".to_string(),
-        required: false,
-        autocomplete: false,
-        autofocus: false,
-        alt: "input test".to_string(),
-        pattern: "".to_string(),
-        min: 0,
-        max: 0,
-        maxlength: 100,
-        minlength: 0,
-        readonly: false,
-        underline: false,
-        disabled: false,
-        step: 1,
-        list: "".to_string(),
-        styles: css!("background-color: #918d94;"),
-    };
+    let props = Props::builder()
+        .alt("input test".to_string())
+        .class_name("form-input-class-test".to_string())
+        .error_message("invalid input".to_string())
+        .id("form-input-id-test".to_string())
+        .maxlength(100)
+        .name("input-test".to_string())
+        .pattern("".to_string())
+        .placeholder("test input".to_string())
+        .step(1)
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let form_input: App<FormInput> = App::new();
 
@@ -343,3 +486,292 @@ fn should_create_form_input() {
 
     assert_eq!(form_input_element.tag_name(), "INPUT");
 }
+
+#[wasm_bindgen_test]
+fn should_link_the_error_message_to_the_input() {
+    let props = Props::builder()
+        .alt("input test".to_string())
+        .class_name("form-input-class-test".to_string())
+        .error_message("invalid input".to_string())
+        .error_state(true)
+        .id("form-input-aria-test".to_string())
+        .maxlength(100)
+        .name("input-test".to_string())
+        .pattern("".to_string())
+        .placeholder("test input".to_string())
+        .step(1)
+        .styles(css!("background-color: #918d94;"))
+        .build();
+
+    let form_input: App<FormInput> = App::new();
+
+    form_input.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let form_input_element = utils::document()
+        .get_element_by_id("form-input-aria-test")
+        .unwrap();
+    let error_element = utils::document()
+        .get_element_by_id("form-input-aria-test-error")
+        .unwrap();
+
+    assert_eq!(
+        form_input_element.get_attribute("aria-invalid").unwrap(),
+        "true"
+    );
+    assert_eq!(
+        form_input_element
+            .get_attribute("aria-describedby")
+            .unwrap(),
+        "form-input-aria-test-error"
+    );
+    assert_eq!(error_element.get_attribute("role").unwrap(), "alert");
+}
+
+#[wasm_bindgen_test]
+fn should_show_async_validation_error() {
+    let props = Props::builder()
+        .alt("username".to_string())
+        .class_name("form-input-class-test".to_string())
+        .error_message("invalid input".to_string())
+        .id("form-input-async-test".to_string())
+        .maxlength(100)
+        .name("username".to_string())
+        .onvalidate_signal(Some(Callback::noop()))
+        .pattern("".to_string())
+        .placeholder("username".to_string())
+        .step(1)
+        .styles(css!("background-color: #918d94;"))
+        .build();
+
+    let form_input: App<FormInput> = App::new();
+
+    let link = form_input.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    link.send_message(Msg::Validate);
+
+    let form_input_element = utils::document()
+        .get_element_by_id("form-input-async-test")
+        .unwrap();
+
+    assert!(form_input_element.class_list().contains("validating"));
+
+    link.send_message(Msg::Validated(1, Err("username already taken".to_string())));
+
+    let error_element = form_input_element.next_element_sibling().unwrap();
+
+    assert!(!form_input_element.class_list().contains("validating"));
+    assert_eq!(
+        error_element.text_content().unwrap(),
+        "username already taken".to_string()
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_ignore_the_validation_of_a_previous_value() {
+    let props = Props::builder()
+        .alt("username".to_string())
+        .class_name("form-input-class-test".to_string())
+        .error_message("invalid input".to_string())
+        .id("form-input-stale-async-test".to_string())
+        .maxlength(100)
+        .name("username".to_string())
+        .onvalidate_signal(Some(Callback::noop()))
+        .pattern("".to_string())
+        .placeholder("username".to_string())
+        .step(1)
+        .styles(css!("background-color: #918d94;"))
+        .build();
+
+    let form_input: App<FormInput> = App::new();
+
+    let link = form_input.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    link.send_message(Msg::Validate);
+    link.send_message(Msg::Input(InputData {
+        value: "ada".to_string(),
+        event: InputEvent::new("input").unwrap(),
+    }));
+    link.send_message(Msg::Validated(1, Err("username already taken".to_string())));
+
+    let form_input_element = utils::document()
+        .get_element_by_id("form-input-stale-async-test")
+        .unwrap();
+
+    let error_message = form_input_element
+        .next_element_sibling()
+        .and_then(|element| element.text_content());
+
+    assert!(form_input_element.class_list().contains("validating"));
+    assert_ne!(error_message, Some("username already taken".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn should_prefer_the_controlled_value() {
+    assert_eq!(
+        get_initial_value(&Some("Ada".to_string()), &"Grace".to_string()),
+        "Ada"
+    );
+    assert_eq!(get_initial_value(&None, &"Grace".to_string()), "Grace");
+    assert_eq!(
+        get_controlled_value(&Some("".to_string()), &"Ada Lovelace".to_string()),
+        Some("".to_string())
+    );
+    assert_eq!(get_controlled_value(&Some(true), &true), None);
+    assert_eq!(get_controlled_value(&None, &"Ada".to_string()), None);
+
+    let props = Props::builder()
+        .default_value("Grace".to_string())
+        .id("form-input-controlled-test".to_string())
+        .maxlength(100)
+        .name("name".to_string())
+        .pattern("".to_string())
+        .step(1)
+        .value(Some("Ada".to_string()))
+        .build();
+
+    let form_input: App<FormInput> = App::new();
+
+    form_input.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props.clone(),
+    );
+
+    let input = props.code_ref.cast::<HtmlInputElement>().unwrap();
+
+    assert_eq!(input.value(), "Ada");
+}
+
+// Parent of the form input which changes its value props, like an app re-rendering it
+struct FormInputHost {
+    props: HostProps,
+    value: Option<String>,
+    default_value: String,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct HostProps {
+    id: String,
+}
+
+enum HostMsg {
+    Change(Option<String>, String),
+}
+
+impl Component for FormInputHost {
+    type Message = HostMsg;
+    type Properties = HostProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        FormInputHost {
+            props,
+            value: None,
+            default_value: String::from("Grace"),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            HostMsg::Change(value, default_value) => {
+                self.value = value;
+                self.default_value = default_value;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <FormInput
+                id=self.props.id.clone()
+                value=self.value.clone()
+                default_value=self.default_value.clone()
+            />
+        }
+    }
+}
+
+fn get_host_input(id: &str) -> HtmlInputElement {
+    utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap()
+}
+
+fn type_in_host_input(id: &str, value: &str) {
+    let input = get_host_input(id);
+
+    input.set_value(value);
+    input
+        .dispatch_event(&InputEvent::new("input").unwrap())
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn should_push_the_new_controlled_value() {
+    let host: App<FormInputHost> = App::new();
+
+    let link = host.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        HostProps {
+            id: "form-input-controlled-host-test".to_string(),
+        },
+    );
+
+    link.send_message(HostMsg::Change(Some("Ada".to_string()), String::new()));
+
+    assert_eq!(
+        get_host_input("form-input-controlled-host-test").value(),
+        "Ada"
+    );
+
+    type_in_host_input("form-input-controlled-host-test", "Ada Byron");
+    link.send_message(HostMsg::Change(
+        Some("Ada Lovelace".to_string()),
+        String::new(),
+    ));
+
+    assert_eq!(
+        get_host_input("form-input-controlled-host-test").value(),
+        "Ada Lovelace"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_keep_the_uncontrolled_value_on_rerender() {
+    let host: App<FormInputHost> = App::new();
+
+    let link = host.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        HostProps {
+            id: "form-input-uncontrolled-host-test".to_string(),
+        },
+    );
+
+    assert_eq!(
+        get_host_input("form-input-uncontrolled-host-test").value(),
+        "Grace"
+    );
+
+    type_in_host_input("form-input-uncontrolled-host-test", "Grace Hopper");
+    link.send_message(HostMsg::Change(None, "Grace Brewster".to_string()));
+
+    assert_eq!(
+        get_host_input("form-input-uncontrolled-host-test").value(),
+        "Grace Hopper"
+    );
+}
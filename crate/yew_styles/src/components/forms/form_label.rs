@@ -1,3 +1,4 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -7,7 +8,7 @@ use yew::{utils, App};
 ///
 /// ## Features required
 ///
-/// forms
+/// form_group, form_label, form_textarea
 ///
 /// ## Example
 ///
@@ -92,6 +93,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 impl Component for FormLabel {
@@ -118,7 +125,11 @@ impl Component for FormLabel {
     fn view(&self) -> Html {
         html! {
             <label
-                class=classes!("form-label", self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("form-label")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
@@ -130,15 +141,13 @@ impl Component for FormLabel {
 
 #[wasm_bindgen_test]
 fn should_create_form_label() {
-    let props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "form-label-class-test".to_string(),
-        id: "form-label-id-test".to_string(),
-        label_for: "label-form".to_string(),
-        styles: css!("background-color: #918d94;"),
-        text: "label text".to_string(),
-    };
+    let props = Props::builder()
+        .class_name("form-label-class-test".to_string())
+        .id("form-label-id-test".to_string())
+        .label_for("label-form".to_string())
+        .styles(css!("background-color: #918d94;"))
+        .text("label text".to_string())
+        .build();
 
     let form_label: App<FormLabel> = App::new();
 
@@ -0,0 +1,641 @@
+use super::controlled::{get_controlled_value, get_initial_value};
+use crate::messages::get_messages;
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Form Range Slider component
+///
+/// Slider with two handles to select an interval, like the price filter of a shop. The handles keep
+/// `min_gap` between them, they are dragged or moved with the arrow keys, `Page Up`, `Page Down`,
+/// `Home` and `End`, and a press in the track moves the closest handle. The values are snapped to `step`.
+/// With `value` the interval is controlled by the parent, with `default_value` the slider keeps it
+///
+/// ## Features required
+///
+/// form_range_slider
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::forms::form_range_slider::FormRangeSlider;
+/// use yew_styles::styles::Palette;
+///
+/// pub struct PriceFilter {
+///     link: ComponentLink<Self>,
+///     price: (f64, f64),
+/// }
+///
+/// pub enum Msg {
+///     ChangePrice((f64, f64)),
+/// }
+///
+/// impl Component for PriceFilter {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             price: (20.0, 80.0),
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::ChangePrice(price) => self.price = price,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <FormRangeSlider
+///                 value=self.price
+///                 min=0.0
+///                 max=200.0
+///                 step=5.0
+///                 min_gap=10.0
+///                 ticks=vec![(0.0, "0 €".to_string()), (100.0, "100 €".to_string()), (200.0, "200 €".to_string())]
+///                 slider_palette=Palette::Primary
+///                 aria_label="Price"
+///                 onchange_signal=self.link.callback(Msg::ChangePrice)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct FormRangeSlider {
+    link: ComponentLink<Self>,
+    props: Props,
+    value: (f64, f64),
+    dragging: Option<Thumb>,
+}
+
+/// Handle of the `FormRangeSlider`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Thumb {
+    Low,
+    High,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Selected interval, each new value replaces the one selected with the handles. Default `None`
+    #[prop_or_default]
+    pub value: Option<(f64, f64)>,
+    /// Initial interval when there is no `value`, then the slider keeps the one selected with the handles.
+    /// Default `(0.0, 100.0)`
+    #[prop_or((0.0, 100.0))]
+    pub default_value: (f64, f64),
+    /// Lowest value of the slider. Default `0.0`
+    #[prop_or(0.0)]
+    pub min: f64,
+    /// Highest value of the slider. Default `100.0`
+    #[prop_or(100.0)]
+    pub max: f64,
+    /// Increment of the values. Default `1.0`
+    #[prop_or(1.0)]
+    pub step: f64,
+    /// Minimum distance between the two values. Default `0.0`
+    #[prop_or(0.0)]
+    pub min_gap: f64,
+    /// Values with their labels shown below the track. Default `vec![]`
+    #[prop_or_default]
+    pub ticks: Vec<(f64, String)>,
+    /// Palette of the selected interval and the handles. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub slider_palette: Palette,
+    /// Whether the form control is disabled. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Emitted with the interval when a handle is moved
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<(f64, f64)>,
+    /// Accessible name of the form control when there is no visible label. Default `""`
+    #[prop_or_default]
+    pub aria_label: String,
+    /// Id of the element which labels the form control. Default `""`
+    #[prop_or_default]
+    pub aria_labelledby: String,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    DragStart(PointerEvent),
+    Drag(PointerEvent),
+    DragEnd,
+    Pressed(Thumb, KeyboardEvent),
+}
+
+impl Component for FormRangeSlider {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            value: get_ordered_value(
+                get_initial_value(&props.value, &props.default_value),
+                props.min,
+                props.max,
+            ),
+            props,
+            dragging: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let (thumb, value) = match msg {
+            Msg::DragStart(pointer_event) => {
+                if self.props.disabled || pointer_event.button() != 0 {
+                    return false;
+                }
+
+                let value = match self.get_pointer_value(&pointer_event) {
+                    Some(value) => value,
+                    None => return false,
+                };
+
+                pointer_event.prevent_default();
+                if let Some(slider) = self.props.code_ref.cast::<Element>() {
+                    slider.set_pointer_capture(pointer_event.pointer_id()).ok();
+                }
+
+                let thumb = get_closest_thumb(self.value, value);
+                self.dragging = Some(thumb);
+                (thumb, value)
+            }
+            Msg::Drag(pointer_event) => {
+                let thumb = match self.dragging {
+                    Some(thumb) => thumb,
+                    None => return false,
+                };
+
+                match self.get_pointer_value(&pointer_event) {
+                    Some(value) => (thumb, value),
+                    None => return false,
+                }
+            }
+            Msg::DragEnd => {
+                return self.dragging.take().is_some();
+            }
+            Msg::Pressed(thumb, keyboard_event) => {
+                if self.props.disabled {
+                    return false;
+                }
+
+                let current = match thumb {
+                    Thumb::Low => self.value.0,
+                    Thumb::High => self.value.1,
+                };
+                let step = self.props.step;
+                let value = match keyboard_event.key().as_str() {
+                    "ArrowLeft" | "ArrowDown" => current - step,
+                    "ArrowRight" | "ArrowUp" => current + step,
+                    "PageDown" => current - step * 10.0,
+                    "PageUp" => current + step * 10.0,
+                    "Home" => self.props.min,
+                    "End" => self.props.max,
+                    _ => return false,
+                };
+
+                keyboard_event.prevent_default();
+                (thumb, value)
+            }
+        };
+
+        let value = get_constrained_value(
+            self.value,
+            thumb,
+            value,
+            self.props.min_gap,
+            self.props.min,
+            self.props.max,
+        );
+
+        if value == self.value {
+            // The handle being dragged is shown as active
+            return matches!(self.dragging, Some(dragging) if dragging == thumb);
+        }
+
+        self.value = value;
+        self.props.onchange_signal.emit(value);
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let value = get_controlled_value(&props.value, &self.value).unwrap_or(self.value);
+
+            self.value = get_ordered_value(value, props.min, props.max);
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        let (low, high) = (
+            self.get_percent(self.value.0),
+            self.get_percent(self.value.1),
+        );
+
+        html! {
+            <div
+                class=ComponentClasses::new("form-range-slider")
+                    .semantic(get_palette(self.props.slider_palette.clone()))
+                    .state("dragging", self.dragging.is_some())
+                    .state("disabled", self.props.disabled)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                role="group"
+                aria-label=self.props.aria_label.clone()
+                aria-labelledby=self.props.aria_labelledby.clone()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onpointerdown=self.link.callback(Msg::DragStart)
+                onpointermove=self.link.callback(Msg::Drag)
+                onpointerup=self.link.callback(|_| Msg::DragEnd)
+                onpointercancel=self.link.callback(|_| Msg::DragEnd)
+            >
+                <div class="form-range-slider-track">
+                    <div
+                        class="form-range-slider-fill"
+                        style=format!("left: {}%; width: {}%;", low, high - low)
+                    ></div>
+                    {self.get_thumb(Thumb::Low, low)}
+                    {self.get_thumb(Thumb::High, high)}
+                </div>
+                {if self.props.ticks.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <div class="form-range-slider-ticks" aria-hidden="true">
+                            {for self.props.ticks.iter().map(|(value, label)| html! {
+                                <span
+                                    class="form-range-slider-tick"
+                                    style=format!("left: {}%;", self.get_percent(*value))
+                                >
+                                    {label}
+                                </span>
+                            })}
+                        </div>
+                    }
+                }}
+            </div>
+        }
+    }
+}
+
+impl FormRangeSlider {
+    fn get_thumb(&self, thumb: Thumb, percent: f64) -> Html {
+        let messages = get_messages();
+        let (value, label, min, max) = match thumb {
+            Thumb::Low => (
+                self.value.0,
+                messages.range_minimum,
+                self.props.min,
+                self.value.1 - self.props.min_gap,
+            ),
+            Thumb::High => (
+                self.value.1,
+                messages.range_maximum,
+                self.value.0 + self.props.min_gap,
+                self.props.max,
+            ),
+        };
+
+        html! {
+            <div
+                class=ComponentClasses::new("form-range-slider-handle")
+                    .state("active", self.dragging == Some(thumb))
+                    .build()
+                style=format!("left: {}%;", percent)
+                role="slider"
+                tabindex=if self.props.disabled { "-1" } else { "0" }
+                aria-label=label
+                aria-valuemin=min.to_string()
+                aria-valuemax=max.to_string()
+                aria-valuenow=value.to_string()
+                aria-disabled=if self.props.disabled { Some("true") } else { None }
+                onkeydown=self.link.callback(move |keyboard_event| Msg::Pressed(thumb, keyboard_event))
+            ></div>
+        }
+    }
+
+    fn get_percent(&self, value: f64) -> f64 {
+        let range = self.props.max - self.props.min;
+
+        if range <= 0.0 {
+            return 0.0;
+        }
+
+        ((value - self.props.min) / range * 100.0).clamp(0.0, 100.0)
+    }
+
+    fn get_pointer_value(&self, pointer_event: &PointerEvent) -> Option<f64> {
+        let rect = self
+            .props
+            .code_ref
+            .cast::<Element>()?
+            .get_bounding_client_rect();
+
+        if rect.width() <= 0.0 {
+            return None;
+        }
+
+        let ratio =
+            ((pointer_event.client_x() as f64 - rect.left()) / rect.width()).clamp(0.0, 1.0);
+
+        Some(get_stepped_value(
+            self.props.min + ratio * (self.props.max - self.props.min),
+            self.props.min,
+            self.props.step,
+        ))
+    }
+}
+
+/// The lowest value first, both inside of the limits
+fn get_ordered_value(value: (f64, f64), min: f64, max: f64) -> (f64, f64) {
+    let low = value.0.min(value.1).clamp(min, max.max(min));
+    let high = value.0.max(value.1).clamp(min, max.max(min));
+
+    (low, high)
+}
+
+/// Closest handle to the value, the high handle when both are in the same place and the value is above them
+fn get_closest_thumb(value: (f64, f64), target: f64) -> Thumb {
+    let low_distance = (target - value.0).abs();
+    let high_distance = (target - value.1).abs();
+
+    if high_distance < low_distance || (high_distance == low_distance && target > value.1) {
+        Thumb::High
+    } else {
+        Thumb::Low
+    }
+}
+
+fn get_stepped_value(value: f64, min: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return value;
+    }
+
+    min + ((value - min) / step).round() * step
+}
+
+/// Interval after moving the handle to `target`, which can't pass the other handle minus the gap nor the limits
+fn get_constrained_value(
+    value: (f64, f64),
+    thumb: Thumb,
+    target: f64,
+    min_gap: f64,
+    min: f64,
+    max: f64,
+) -> (f64, f64) {
+    match thumb {
+        Thumb::Low => {
+            let upper = (value.1 - min_gap).max(min);
+            (target.clamp(min, upper), value.1)
+        }
+        Thumb::High => {
+            let lower = (value.0 + min_gap).min(max);
+            (value.0, target.clamp(lower, max))
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_keep_the_gap_between_the_handles() {
+    assert_eq!(
+        get_constrained_value((20.0, 80.0), Thumb::Low, 75.0, 10.0, 0.0, 100.0),
+        (70.0, 80.0)
+    );
+    assert_eq!(
+        get_constrained_value((20.0, 80.0), Thumb::High, 10.0, 10.0, 0.0, 100.0),
+        (20.0, 30.0)
+    );
+    assert_eq!(
+        get_constrained_value((20.0, 80.0), Thumb::High, 120.0, 10.0, 0.0, 100.0),
+        (20.0, 100.0)
+    );
+    assert_eq!(
+        get_constrained_value((20.0, 80.0), Thumb::Low, -5.0, 0.0, 0.0, 100.0),
+        (0.0, 80.0)
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_snap_and_pick_the_closest_handle() {
+    assert_eq!(get_stepped_value(23.0, 0.0, 5.0), 25.0);
+    assert_eq!(get_stepped_value(11.0, 1.0, 4.0), 9.0);
+    assert_eq!(get_closest_thumb((20.0, 80.0), 30.0), Thumb::Low);
+    assert_eq!(get_closest_thumb((20.0, 80.0), 60.0), Thumb::High);
+    assert_eq!(get_closest_thumb((50.0, 50.0), 70.0), Thumb::High);
+    assert_eq!(get_closest_thumb((50.0, 50.0), 30.0), Thumb::Low);
+    assert_eq!(get_ordered_value((90.0, -10.0), 0.0, 100.0), (0.0, 90.0));
+}
+
+#[wasm_bindgen_test]
+fn should_create_form_range_slider() {
+    let props = Props::builder()
+        .aria_label("Price".to_string())
+        .id("form-range-slider-test".to_string())
+        .max(200.0)
+        .slider_palette(Palette::Primary)
+        .ticks(vec![(100.0, "100".to_string())])
+        .value((20.0, 80.0))
+        .build();
+
+    let form_range_slider: App<FormRangeSlider> = App::new();
+    form_range_slider.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let handles = utils::document()
+        .get_element_by_id("form-range-slider-test")
+        .unwrap()
+        .get_elements_by_class_name("form-range-slider-handle");
+
+    assert_eq!(handles.length(), 2);
+    assert_eq!(
+        handles.get_with_index(0).unwrap().get_attribute("style"),
+        Some("left: 10%;".to_string())
+    );
+    assert_eq!(
+        handles
+            .get_with_index(1)
+            .unwrap()
+            .get_attribute("aria-valuenow"),
+        Some("80".to_string())
+    );
+}
+
+struct FormRangeSliderHost {
+    props: HostProps,
+    value: Option<(f64, f64)>,
+    default_value: (f64, f64),
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct HostProps {
+    id: String,
+}
+
+enum HostMsg {
+    Change(Option<(f64, f64)>, (f64, f64)),
+}
+
+impl Component for FormRangeSliderHost {
+    type Message = HostMsg;
+    type Properties = HostProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        FormRangeSliderHost {
+            props,
+            value: None,
+            default_value: (20.0, 80.0),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            HostMsg::Change(value, default_value) => {
+                self.value = value;
+                self.default_value = default_value;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <FormRangeSlider
+                id=self.props.id.clone()
+                value=self.value
+                default_value=self.default_value
+            />
+        }
+    }
+}
+
+fn get_host_handle(id: &str, index: u32) -> Element {
+    utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .get_elements_by_class_name("form-range-slider-handle")
+        .get_with_index(index)
+        .unwrap()
+}
+
+fn get_host_interval(id: &str) -> (Option<String>, Option<String>) {
+    (
+        get_host_handle(id, 0).get_attribute("aria-valuenow"),
+        get_host_handle(id, 1).get_attribute("aria-valuenow"),
+    )
+}
+
+fn press_in_host_handle(id: &str, index: u32, key: &str) {
+    let keyboard_event = KeyboardEvent::new_with_keyboard_event_init_dict(
+        "keydown",
+        web_sys::KeyboardEventInit::new().key(key),
+    )
+    .unwrap();
+
+    get_host_handle(id, index)
+        .dispatch_event(&keyboard_event)
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn should_push_the_new_controlled_interval() {
+    let host: App<FormRangeSliderHost> = App::new();
+
+    let link = host.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        HostProps {
+            id: "form-range-slider-controlled-host-test".to_string(),
+        },
+    );
+
+    link.send_message(HostMsg::Change(Some((30.0, 60.0)), (20.0, 80.0)));
+
+    assert_eq!(
+        get_host_interval("form-range-slider-controlled-host-test"),
+        (Some("30".to_string()), Some("60".to_string()))
+    );
+
+    press_in_host_handle("form-range-slider-controlled-host-test", 0, "Home");
+    link.send_message(HostMsg::Change(Some((40.0, 70.0)), (20.0, 80.0)));
+
+    assert_eq!(
+        get_host_interval("form-range-slider-controlled-host-test"),
+        (Some("40".to_string()), Some("70".to_string()))
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_keep_the_uncontrolled_interval_on_rerender() {
+    let host: App<FormRangeSliderHost> = App::new();
+
+    let link = host.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        HostProps {
+            id: "form-range-slider-uncontrolled-host-test".to_string(),
+        },
+    );
+
+    assert_eq!(
+        get_host_interval("form-range-slider-uncontrolled-host-test"),
+        (Some("20".to_string()), Some("80".to_string()))
+    );
+
+    press_in_host_handle("form-range-slider-uncontrolled-host-test", 0, "Home");
+    link.send_message(HostMsg::Change(None, (30.0, 60.0)));
+
+    assert_eq!(
+        get_host_interval("form-range-slider-uncontrolled-host-test"),
+        (Some("0".to_string()), Some("80".to_string()))
+    );
+}
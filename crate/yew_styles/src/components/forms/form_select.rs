@@ -1,7 +1,10 @@
-use super::error_message::get_error_message;
-use crate::styles::{get_size, Size};
+use super::controlled::{get_controlled_value, get_initial_value};
+use super::error_message::{create_error_id, get_described_by, get_error_id, get_error_message};
+use super::form_context::FormControlRegistration;
+use crate::styles::{get_size, tokens::Spaces, ComponentClasses, Size};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlSelectElement};
 use yew::prelude::*;
 use yew::{utils, App, ChangeData};
 
@@ -9,7 +12,7 @@ use yew::{utils, App, ChangeData};
 ///
 /// ## Features required
 ///
-/// forms
+/// form_select
 ///
 /// ## Example
 ///
@@ -76,6 +79,10 @@ use yew::{utils, App, ChangeData};
 pub struct FormSelect {
     link: ComponentLink<Self>,
     props: Props,
+    error_id: String,
+    /// The initial or the controlled value must be selected after rendering
+    push_value: bool,
+    registration: FormControlRegistration,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -95,6 +102,14 @@ pub struct Props {
     /// A value is required or must be check for the form to be submittable. Default `false`
     #[prop_or(false)]
     pub required: bool,
+    /// Controlled value, the option with this value is selected every time it changes even if the user has
+    /// selected another one. In the multiple selects it leaves only this option selected. Default `None`
+    #[prop_or_default]
+    pub value: Option<String>,
+    /// Value of the option selected initially when the select isn't controlled by `value`,
+    /// when it is empty the `selected` attribute of the options is kept. Default `""`
+    #[prop_or_default]
+    pub default_value: String,
     /// Whether to allow multiple values. Default `false`
     #[prop_or(false)]
     pub multiple: bool,
@@ -121,12 +136,27 @@ pub struct Props {
     /// show error message when error_state is true.
     #[prop_or_default]
     pub error_message: String,
+    /// Accessible name of the form control when there is no visible label. Default `""`
+    #[prop_or_default]
+    pub aria_label: String,
+    /// Id of the element which labels the form control. Default `""`
+    #[prop_or_default]
+    pub aria_labelledby: String,
+    /// Ids of the elements which describe the form control, the error message is added when it is shown. Default `""`
+    #[prop_or_default]
+    pub aria_describedby: String,
     /// general property to add custom id
     #[prop_or_default]
     pub id: String,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 pub enum Msg {
@@ -138,7 +168,13 @@ impl Component for FormSelect {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            props,
+            error_id: create_error_id(),
+            push_value: true,
+            registration: FormControlRegistration::new(),
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -152,6 +188,8 @@ impl Component for FormSelect {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            // The value is pushed once the new options are rendered
+            self.push_value = props.value.is_some();
             self.props = props;
             true
         } else {
@@ -159,16 +197,52 @@ impl Component for FormSelect {
         }
     }
 
+    fn rendered(&mut self, first_render: bool) {
+        self.registration.report(
+            self.props.code_ref.cast::<Element>(),
+            &self.props.name,
+            self.props.error_state,
+        );
+
+        if !self.push_value {
+            return;
+        }
+        self.push_value = false;
+
+        if let Some(select) = self.props.code_ref.cast::<HtmlSelectElement>() {
+            let value = if first_render {
+                Some(get_initial_value(
+                    &self.props.value,
+                    &self.props.default_value,
+                ))
+                .filter(|value| !value.is_empty())
+            } else {
+                get_controlled_value(&self.props.value, &select.value())
+            };
+
+            if let Some(value) = value {
+                select.set_value(&value);
+            }
+        }
+    }
+
     fn view(&self) -> Html {
+        let error_state = self.props.error_state;
+        let error_id = get_error_id(&self.props.id, &self.error_id);
+
         html! {
             <>
                 <select
-                    class=classes!(
-                        "form-select",
-                        get_size(self.props.select_size.clone()),
-                        self.props.class_name.clone(),
-                        self.props.styles.clone()
-                    )
+                    aria-label=self.props.aria_label.clone()
+                    aria-labelledby=self.props.aria_labelledby.clone()
+                    aria-describedby=get_described_by(error_state, &error_id, &self.props.aria_describedby)
+                    aria-invalid=error_state.to_string()
+                    class=ComponentClasses::new("form-select")
+                        .semantic(get_size(self.props.select_size.clone()))
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
                     id=self.props.id.clone()
                     key=self.props.key.clone()
                     ref=self.props.code_ref.clone()
@@ -182,7 +256,7 @@ impl Component for FormSelect {
                 >
                     {self.props.options.clone()}
                 </select>
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_error_message(error_state, self.props.error_message.clone(), error_id)}
             </>
         }
     }
@@ -190,31 +264,21 @@ impl Component for FormSelect {
 
 #[wasm_bindgen_test]
 fn should_create_form_select() {
-    let props = Props {
-        onchange_signal: Callback::noop(),
-        id: "form-select-id-test".to_string(),
-        class_name: "form-select-class-test".to_string(),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        disabled: false,
-        autofocus: false,
-        required: false,
-        select_size: Size::Medium,
-        size: 0,
-        name: "options".to_string(),
-        error_message: "".to_string(),
-        error_state: false,
-        multiple: false,
-        styles: css!("background-color: #918d94;"),
-        options: html! {
+    let props = Props::builder()
+        .class_name("form-select-class-test".to_string())
+        .id("form-select-id-test".to_string())
+        .name("options".to_string())
+        .onchange_signal(Callback::noop())
+        .options(html! {
             <>
                 <option value="value-1" selected=true>{"option 1"}</option>
                 <option value="value-2">{"option 2"}</option>
                 <option value="value-3">{"option 3"}</option>
                 <option value="value-4" id="result">{"option 4"}</option>
             </>
-        },
-    };
+        })
+        .styles(css!("background-color: #918d94;"))
+        .build();
 
     let form_select: App<FormSelect> = App::new();
     form_select.mount_with_props(
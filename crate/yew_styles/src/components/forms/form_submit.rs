@@ -1,4 +1,6 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::styles::{
+    get_palette, get_size, get_style, tokens::Spaces, ComponentClasses, Palette, Size, Style,
+};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -8,7 +10,7 @@ use yew::{utils, App};
 ///
 /// ## Features required
 ///
-/// forms
+/// form_submit
 ///
 /// see example in Form
 pub struct FormSubmit {
@@ -45,6 +47,12 @@ pub struct Props {
     pub id: String,
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 impl Component for FormSubmit {
@@ -74,12 +82,15 @@ impl Component for FormSubmit {
                 type="submit"
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
-                class=classes!(
-                    "form-submit",
-                    get_style(self.props.submit_style.clone()),
-                    get_palette(self.props.submit_palette.clone()),
-                    get_size(self.props.size.clone()),
-                self.props.class_name.clone())
+                class=ComponentClasses::new("form-submit")
+                    .semantic(vec![
+                        get_style(self.props.submit_style.clone()),
+                        get_palette(self.props.submit_palette.clone()),
+                        get_size(self.props.size.clone()),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 disabled=self.props.disabled
                 id=self.props.id.clone()
                 value=self.props.value.clone()
@@ -90,18 +101,12 @@ impl Component for FormSubmit {
 
 #[wasm_bindgen_test]
 fn should_create_form_submit() {
-    let props = Props {
-        value: "submit".to_string(),
-        disabled: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        id: "result".to_string(),
-        class_name: "form-submit-test".to_string(),
-        submit_style: Style::Regular,
-        submit_palette: Palette::Standard,
-        size: Size::Medium,
-        styles: css!("background-color: #918d94;"),
-    };
+    let props = Props::builder()
+        .class_name("form-submit-test".to_string())
+        .id("result".to_string())
+        .styles(css!("background-color: #918d94;"))
+        .value("submit".to_string())
+        .build();
 
     let form_submit: App<FormSubmit> = App::new();
 
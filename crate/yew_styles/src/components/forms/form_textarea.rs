@@ -1,15 +1,22 @@
-use super::error_message::get_error_message;
-use crate::styles::{get_palette, get_size, Palette, Size};
+use super::controlled::{get_controlled_value, get_initial_value};
+use super::error_message::{create_error_id, get_described_by, get_error_id, get_error_message};
+use super::form_context::FormControlRegistration;
+use super::form_counter::get_counter;
+use super::form_validation::{ValidationRequest, ValidationResult};
+use crate::styles::{get_palette, get_size, tokens::Spaces, ComponentClasses, Palette, Size};
+use std::time::Duration;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
+use web_sys::{CssStyleDeclaration, Element, HtmlTextAreaElement};
 use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
 use yew::{utils, App};
 
 /// # Form Textearea
 ///
 /// ## Features required
 ///
-/// forms
+/// form_textarea
 ///
 /// ## Example
 ///
@@ -61,16 +68,34 @@ use yew::{utils, App};
 pub struct FormTextArea {
     link: ComponentLink<Self>,
     props: Props,
+    error_id: String,
+    value: String,
+    validating: bool,
+    validation_error: Option<String>,
+    validation_id: u32,
+    validation_task: Option<TimeoutTask>,
+    registration: FormControlRegistration,
 }
 
 /// Type of wraps. You can find more information [here](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/textarea)
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WrapText {
     Hard,
     Soft,
     Off,
 }
 
+/// Directions in which the user can resize the textarea by dragging its corner
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Resize {
+    None,
+    Both,
+    Horizontal,
+    Vertical,
+}
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
     /// General property to get the ref of the component
@@ -88,6 +113,12 @@ pub struct Props {
     /// Content to be appear in the form control when the form control is empty
     #[prop_or_default]
     pub placeholder: String,
+    /// Controlled value, every new value is pushed into the textarea even if the user has changed it. Default `None`
+    #[prop_or_default]
+    pub value: Option<String>,
+    /// Initial value when the textarea isn't controlled by `value`. Default `""`
+    #[prop_or_default]
+    pub default_value: String,
     /// The input style according with the purpose. Default `Palette::Standard`
     #[prop_or(Palette::Standard)]
     pub textarea_style: Palette,
@@ -143,12 +174,52 @@ pub struct Props {
     /// Show error message when error_state is true
     #[prop_or_default]
     pub error_message: String,
+    /// Accessible name of the form control when there is no visible label. Default `""`
+    #[prop_or_default]
+    pub aria_label: String,
+    /// Id of the element which labels the form control. Default `""`
+    #[prop_or_default]
+    pub aria_labelledby: String,
+    /// Ids of the elements which describe the form control, the error message is added when it is shown. Default `""`
+    #[prop_or_default]
+    pub aria_describedby: String,
+    /// Show a counter with the number of characters and the maxlength. Default `false`
+    #[prop_or(false)]
+    pub show_counter: bool,
+    /// Remaining characters to show the counter with the danger color. Default `10`
+    #[prop_or(10)]
+    pub counter_threshold: u32,
+    /// Signal to validate the value asynchronously after the user stops typing.
+    /// The result feeds the error state and the error message. Default `None`
+    #[prop_or(None)]
+    pub onvalidate_signal: Option<Callback<ValidationRequest>>,
+    /// Milliseconds to wait since the last input before the async validation. Default `300`
+    #[prop_or(300)]
+    pub validation_debounce: u64,
+    /// The textarea grows vertically with its content. Default `false`
+    #[prop_or(false)]
+    pub auto_resize: bool,
+    /// The textarea also shrinks when content is removed. Only with auto_resize. Default `true`
+    #[prop_or(true)]
+    pub auto_shrink: bool,
+    /// Maximum number of visible text lines with auto_resize, after that it scrolls. Default `None`
+    #[prop_or(None)]
+    pub max_rows: Option<u16>,
     /// Indicates how the control wraps text. Default `WrapText::Soft`
     #[prop_or(WrapText::Soft)]
     pub wrap: WrapText,
+    /// Directions in which the user can resize the textarea, auto_resize disables it. Default `Resize::Both`
+    #[prop_or(Resize::Both)]
+    pub resize: Resize,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 #[derive(Debug)]
@@ -156,6 +227,8 @@ pub enum Msg {
     Input(InputData),
     Blur(FocusEvent),
     KeyPressed(KeyboardEvent),
+    Validate,
+    Validated(u32, ValidationResult),
 }
 
 impl Component for FormTextArea {
@@ -163,14 +236,58 @@ impl Component for FormTextArea {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            value: get_initial_value(&props.value, &props.default_value),
+            props,
+            error_id: create_error_id(),
+            validating: false,
+            validation_error: None,
+            validation_id: 0,
+            validation_task: None,
+            registration: FormControlRegistration::new(),
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Input(input_data) => {
+                self.value = input_data.value.clone();
+                // The pending validation is of the previous value
+                self.validation_id += 1;
+
+                if self.props.onvalidate_signal.is_some() {
+                    self.validation_task = Some(TimeoutService::spawn(
+                        Duration::from_millis(self.props.validation_debounce),
+                        self.link.callback(|_| Msg::Validate),
+                    ));
+                }
                 self.props.oninput_signal.emit(input_data);
             }
+            Msg::Validate => {
+                self.validation_task = None;
+
+                if let Some(onvalidate_signal) = &self.props.onvalidate_signal {
+                    self.validation_id += 1;
+                    self.validating = true;
+
+                    let validation_id = self.validation_id;
+
+                    onvalidate_signal.emit(ValidationRequest {
+                        value: self.value.clone(),
+                        respond: self
+                            .link
+                            .callback(move |result| Msg::Validated(validation_id, result)),
+                    });
+                }
+            }
+            Msg::Validated(validation_id, result) => {
+                // Results of previous values are discarded
+                if validation_id == self.validation_id {
+                    self.validating = false;
+                    self.validation_error = result.err();
+                }
+            }
             Msg::Blur(focus_event) => {
                 self.props.onblur_signal.emit(focus_event);
             }
@@ -184,6 +301,7 @@ impl Component for FormTextArea {
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            self.push_controlled_value(&props);
             self.props = props;
             true
         } else {
@@ -191,17 +309,42 @@ impl Component for FormTextArea {
         }
     }
 
+    fn rendered(&mut self, _first_render: bool) {
+        self.registration.report(
+            self.props.code_ref.cast::<Element>(),
+            &self.props.name,
+            self.props.error_state || self.validation_error.is_some(),
+        );
+
+        if self.props.auto_resize {
+            self.resize();
+        }
+    }
+
     fn view(&self) -> Html {
+        let error_state = self.props.error_state || self.validation_error.is_some();
+        let error_id = get_error_id(&self.props.id, &self.error_id);
+
         html! {
             <>
                 <textarea
+                    aria-label=self.props.aria_label.clone()
+                    aria-labelledby=self.props.aria_labelledby.clone()
+                    aria-describedby=get_described_by(error_state, &error_id, &self.props.aria_describedby)
+                    aria-invalid=error_state.to_string()
                     id=self.props.id.clone()
-                    class=classes!("form-textarea",
-                        get_palette(self.props.textarea_style.clone()),
-                        get_size(self.props.textarea_size.clone()),
-                        self.props.class_name.clone(),
-                        self.props.styles.clone()
-                    )
+                    class=ComponentClasses::new("form-textarea")
+                        .semantic(vec![
+                            get_palette(self.props.textarea_style.clone()),
+                            get_size(self.props.textarea_size.clone()),
+                            get_resize(self.props.resize.clone()),
+                        ])
+                        .state("validating", self.validating)
+                        .state("auto-resize", self.props.auto_resize)
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
                     key=self.props.key.clone()
                     ref=self.props.code_ref.clone()
                     oninput=self.link.callback(Msg::Input)
@@ -220,13 +363,100 @@ impl Component for FormTextArea {
                     minlength=self.props.minlength.to_string()
                     maxlength=self.props.maxlength.to_string()
                     warp=get_wrap(self.props.wrap.clone())
+                    value=self.value.clone()
                 />
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_counter(
+                    self.props.show_counter,
+                    self.value.chars().count(),
+                    self.props.maxlength as usize,
+                    self.props.counter_threshold as usize
+                )}
+                {get_error_message(
+                    error_state,
+                    self.validation_error
+                        .clone()
+                        .unwrap_or_else(|| self.props.error_message.clone()),
+                    error_id
+                )}
             </>
         }
     }
 }
 
+impl FormTextArea {
+    /// Push the new controlled value into the textarea, the user may have changed it since the last one
+    fn push_controlled_value(&mut self, props: &Props) {
+        let textarea = props.code_ref.cast::<HtmlTextAreaElement>();
+        let value = textarea
+            .as_ref()
+            .map_or_else(|| self.value.clone(), |textarea| textarea.value());
+
+        if let Some(value) = get_controlled_value(&props.value, &value) {
+            if let Some(textarea) = &textarea {
+                textarea.set_value(&value);
+            }
+            self.value = value;
+        }
+    }
+
+    fn resize(&self) {
+        if let Some(textarea) = self.props.code_ref.cast::<HtmlTextAreaElement>() {
+            let style = textarea.style();
+            let borders = (textarea.offset_height() - textarea.client_height()) as f64;
+            let current_height = textarea.offset_height() as f64;
+
+            style.set_property("height", "auto").unwrap();
+
+            let mut height = textarea.scroll_height() as f64 + borders;
+            let mut overflow = "hidden";
+
+            if !self.props.auto_shrink {
+                height = height.max(current_height);
+            }
+
+            if let (Some(max_rows), Ok(Some(computed_style))) = (
+                self.props.max_rows,
+                utils::window().get_computed_style(&textarea),
+            ) {
+                let max_height = get_line_height(&computed_style) * max_rows as f64
+                    + get_pixels(&computed_style, "padding-top")
+                    + get_pixels(&computed_style, "padding-bottom")
+                    + borders;
+
+                if height > max_height {
+                    height = max_height;
+                    overflow = "auto";
+                }
+            }
+
+            style
+                .set_property("height", &format!("{}px", height))
+                .unwrap();
+            style.set_property("overflow-y", overflow).unwrap();
+        }
+    }
+}
+
+fn get_pixels(computed_style: &CssStyleDeclaration, property: &str) -> f64 {
+    computed_style
+        .get_property_value(property)
+        .unwrap_or_default()
+        .trim_end_matches("px")
+        .parse::<f64>()
+        .unwrap_or(0.0)
+}
+
+fn get_line_height(computed_style: &CssStyleDeclaration) -> f64 {
+    let line_height = get_pixels(computed_style, "line-height");
+
+    if line_height > 0.0 {
+        line_height
+    } else {
+        // line-height normal is around 1.2 times the font size
+        get_pixels(computed_style, "font-size") * 1.2
+    }
+}
+
 fn get_wrap(wrap_text: WrapText) -> String {
     match wrap_text {
         WrapText::Hard => "hard".to_string(),
@@ -235,35 +465,31 @@ fn get_wrap(wrap_text: WrapText) -> String {
     }
 }
 
+fn get_resize(resize: Resize) -> String {
+    match resize {
+        Resize::None => "resize-none".to_string(),
+        Resize::Both => "resize-both".to_string(),
+        Resize::Horizontal => "resize-horizontal".to_string(),
+        Resize::Vertical => "resize-vertical".to_string(),
+    }
+}
+
 #[wasm_bindgen_test]
 fn should_create_form_textarea() {
-    let props = Props {
-        id: "form-input-id-test".to_string(),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "form-input-class-test".to_string(),
-        styles: css!("background-color: #918d94;"),
-        oninput_signal: Callback::noop(),
-        onblur_signal: Callback::noop(),
-        onkeydown_signal: Callback::noop(),
-        error_message: "invalid input".to_string(),
-        error_state: false,
-        name: "input-test".to_string(),
-        textarea_style: Palette::Standard,
-        textarea_size: Size::Medium,
-        placeholder: "test input".to_string(),
-        required: false,
-        autocomplete: false,
-        autofocus: false,
-        maxlength: 100,
-        minlength: 0,
-        readonly: false,
-        disabled: false,
-        cols: 20,
-        rows: 10,
-        spellcheck: true,
-        wrap: WrapText::Hard,
-    };
+    let props = Props::builder()
+        .class_name("form-input-class-test".to_string())
+        .cols(20)
+        .error_message("invalid input".to_string())
+        .id("form-input-id-test".to_string())
+        .maxlength(100)
+        .name("input-test".to_string())
+        .placeholder("test input".to_string())
+        .resize(Resize::Vertical)
+        .rows(10)
+        .spellcheck(true)
+        .styles(css!("background-color: #918d94;"))
+        .wrap(WrapText::Hard)
+        .build();
 
     let form_textarea: App<FormTextArea> = App::new();
 
@@ -277,4 +503,83 @@ fn should_create_form_textarea() {
         .unwrap();
 
     assert_eq!(form_textarea_element.tag_name(), "TEXTAREA");
+    assert!(form_textarea_element
+        .class_list()
+        .contains("resize-vertical"));
+}
+
+#[wasm_bindgen_test]
+fn should_show_counter_in_danger_near_the_limit() {
+    let props = Props::builder()
+        .class_name("form-input-class-test".to_string())
+        .cols(20)
+        .counter_threshold(5)
+        .error_message("invalid input".to_string())
+        .id("form-textarea-counter-test".to_string())
+        .maxlength(5)
+        .name("input-test".to_string())
+        .placeholder("test input".to_string())
+        .rows(10)
+        .show_counter(true)
+        .spellcheck(true)
+        .styles(css!("background-color: #918d94;"))
+        .wrap(WrapText::Hard)
+        .build();
+
+    let form_textarea: App<FormTextArea> = App::new();
+
+    form_textarea.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let counter_element = utils::document()
+        .get_element_by_id("form-textarea-counter-test")
+        .unwrap()
+        .next_element_sibling()
+        .unwrap();
+
+    assert_eq!(counter_element.text_content().unwrap(), "0 / 5");
+    assert!(counter_element.class_list().contains("danger"));
+}
+
+#[wasm_bindgen_test]
+fn should_limit_auto_resize_to_max_rows() {
+    let code_ref = NodeRef::default();
+
+    let props = Props::builder()
+        .auto_resize(true)
+        .class_name("form-input-class-test".to_string())
+        .code_ref(code_ref.clone())
+        .cols(20)
+        .error_message("invalid input".to_string())
+        .id("form-textarea-resize-test".to_string())
+        .max_rows(Some(3))
+        .name("input-test".to_string())
+        .placeholder("test input".to_string())
+        .rows(1)
+        .spellcheck(true)
+        .styles(css!("line-height: 20px; padding: 0; border: none;"))
+        .wrap(WrapText::Hard)
+        .build();
+
+    let form_textarea: App<FormTextArea> = App::new();
+
+    let link = form_textarea.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let textarea = code_ref.cast::<HtmlTextAreaElement>().unwrap();
+    textarea.set_value("1\n2\n3\n4\n5\n6");
+    link.send_message(Msg::Validated(0, Ok(())));
+
+    assert_eq!(
+        textarea.style().get_property_value("height").unwrap(),
+        "60px"
+    );
+    assert_eq!(
+        textarea.style().get_property_value("overflow-y").unwrap(),
+        "auto"
+    );
 }
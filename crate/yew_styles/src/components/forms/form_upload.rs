@@ -0,0 +1,162 @@
+use crate::messages::get_messages;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{File, FormData, ProgressEvent, XmlHttpRequest};
+use yew::prelude::*;
+
+/// Result of an upload. The success contains the response of the server and the error the message to show
+pub type UploadResult = Result<String, String>;
+
+/// Request emitted by the file upload to send a file with a custom driver. The progress, from `0.0` to `1.0`,
+/// is reported through `progress` and the result through `respond` once the upload is finished.
+/// When the user cancels the upload the callback registered with `oncancel` is emitted to stop it,
+/// the reports of a cancelled upload are ignored
+#[derive(Clone)]
+pub struct UploadRequest {
+    pub file: File,
+    pub progress: Callback<f64>,
+    pub respond: Callback<UploadResult>,
+    cancel: UploadCancel,
+}
+
+impl UploadRequest {
+    pub(crate) fn new(
+        file: File,
+        progress: Callback<f64>,
+        respond: Callback<UploadResult>,
+        cancel: UploadCancel,
+    ) -> Self {
+        Self {
+            file,
+            progress,
+            respond,
+            cancel,
+        }
+    }
+
+    /// Registers the callback which stops the upload when it is cancelled,
+    /// it is emitted right away when the upload is already cancelled
+    pub fn oncancel(&self, callback: Callback<()>) {
+        if self.is_cancelled() {
+            callback.emit(());
+        } else {
+            self.cancel.0.borrow_mut().oncancel = Some(callback);
+        }
+    }
+
+    /// Whether the upload was cancelled by the user
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.0.borrow().cancelled
+    }
+}
+
+/// Cancellation of an upload shared between the file upload and the requests sent to the driver
+#[derive(Clone, Default)]
+pub(crate) struct UploadCancel(Rc<RefCell<CancelState>>);
+
+#[derive(Default)]
+struct CancelState {
+    cancelled: bool,
+    oncancel: Option<Callback<()>>,
+}
+
+impl UploadCancel {
+    /// Marks the upload as cancelled and emits the callback registered by the driver
+    pub(crate) fn cancel(&self) {
+        let oncancel = {
+            let mut cancel = self.0.borrow_mut();
+            cancel.cancelled = true;
+            cancel.oncancel.take()
+        };
+
+        if let Some(oncancel) = oncancel {
+            oncancel.emit(());
+        }
+    }
+}
+
+/// Status of a file sent by the file upload
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum UploadStatus {
+    Uploading,
+    Completed,
+    Failed(String),
+    Cancelled,
+}
+
+/// `POST` request sending a file as multipart form data, it is aborted when it is dropped before finishing
+pub(crate) struct UploadTask {
+    request: XmlHttpRequest,
+    _listeners: Vec<Closure<dyn Fn(ProgressEvent)>>,
+}
+
+impl UploadTask {
+    /// Sends `file` in the `field` of the form data, `None` when the browser can't send the request
+    pub(crate) fn new(
+        url: &str,
+        field: &str,
+        file: &File,
+        progress: Callback<f64>,
+        respond: Callback<UploadResult>,
+    ) -> Option<Self> {
+        let request = XmlHttpRequest::new().ok()?;
+        let form_data = FormData::new().ok()?;
+        form_data
+            .append_with_blob_and_filename(field, file, &file.name())
+            .ok()?;
+        request.open("POST", url).ok()?;
+
+        let onprogress = Closure::wrap(Box::new(move |event: ProgressEvent| {
+            if event.length_computable() && event.total() > 0.0 {
+                progress.emit(event.loaded() / event.total());
+            }
+        }) as Box<dyn Fn(ProgressEvent)>);
+        let load_request = request.clone();
+        let load_respond = respond.clone();
+        let onload = Closure::wrap(Box::new(move |_: ProgressEvent| {
+            let status = load_request.status().unwrap_or(0);
+
+            load_respond.emit(if (200..300).contains(&status) {
+                Ok(load_request
+                    .response_text()
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default())
+            } else {
+                Err(format!("{} ({})", get_messages().upload_failed, status))
+            });
+        }) as Box<dyn Fn(ProgressEvent)>);
+        let onerror = Closure::wrap(Box::new(move |_: ProgressEvent| {
+            respond.emit(Err(get_messages().upload_failed));
+        }) as Box<dyn Fn(ProgressEvent)>);
+
+        if let Ok(upload) = request.upload() {
+            upload.set_onprogress(Some(onprogress.as_ref().unchecked_ref()));
+        }
+        request.set_onload(Some(onload.as_ref().unchecked_ref()));
+        request.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        request.send_with_opt_form_data(Some(&form_data)).ok()?;
+
+        Some(Self {
+            request,
+            _listeners: vec![onprogress, onload, onerror],
+        })
+    }
+}
+
+impl Drop for UploadTask {
+    fn drop(&mut self) {
+        self.request.set_onload(None);
+        self.request.set_onerror(None);
+
+        if self.request.ready_state() != XmlHttpRequest::DONE {
+            self.request.abort().ok();
+        }
+    }
+}
+
+/// Rounded percentage of the progress of an upload
+pub(crate) fn get_upload_percentage(progress: f64) -> u32 {
+    (progress.clamp(0.0, 1.0) * 100.0).round() as u32
+}
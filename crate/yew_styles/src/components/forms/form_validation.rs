@@ -0,0 +1,12 @@
+use yew::prelude::*;
+
+/// Result of an async validation. The error contains the message to show
+pub type ValidationResult = Result<(), String>;
+
+/// Request emitted by the form controls to validate their value asynchronously,
+/// the result must be sent back through `respond` once the validation is finished
+#[derive(Clone)]
+pub struct ValidationRequest {
+    pub value: String,
+    pub respond: Callback<ValidationResult>,
+}
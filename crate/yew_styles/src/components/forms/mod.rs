@@ -1,9 +1,49 @@
+#[cfg(any(
+    feature = "form_input",
+    feature = "form_textarea",
+    feature = "form_select",
+    feature = "form_range_slider"
+))]
+mod controlled;
+#[cfg(any(
+    feature = "form_input",
+    feature = "form_textarea",
+    feature = "form_select",
+    feature = "form_file"
+))]
 mod error_message;
+#[cfg(feature = "form_builder")]
+pub mod form_builder;
+#[cfg(feature = "form_component")]
 pub mod form_component;
+#[cfg(any(
+    feature = "form_component",
+    feature = "form_input",
+    feature = "form_textarea",
+    feature = "form_select"
+))]
+mod form_context;
+#[cfg(any(feature = "form_input", feature = "form_textarea"))]
+mod form_counter;
+#[cfg(feature = "form_error_summary")]
+pub mod form_error_summary;
+#[cfg(feature = "form_file")]
 pub mod form_file;
+#[cfg(feature = "form_group")]
 pub mod form_group;
+#[cfg(feature = "form_input")]
 pub mod form_input;
+#[cfg(feature = "form_label")]
 pub mod form_label;
+#[cfg(feature = "form_range_slider")]
+pub mod form_range_slider;
+#[cfg(feature = "form_select")]
 pub mod form_select;
+#[cfg(feature = "form_submit")]
 pub mod form_submit;
+#[cfg(feature = "form_textarea")]
 pub mod form_textarea;
+#[cfg(feature = "form_file")]
+pub mod form_upload;
+#[cfg(any(feature = "form_input", feature = "form_textarea"))]
+pub mod form_validation;
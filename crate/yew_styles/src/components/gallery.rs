@@ -0,0 +1,493 @@
+use crate::focus_trap::FocusTrap;
+use crate::hotkeys::{register_hotkey, Hotkey, HotkeyHandle, HotkeyScope};
+use crate::icon::{Icon, IconType};
+use crate::image_zoom::{get_pinch_scale, get_touches_distance};
+use crate::layers::{acquire_layer, Layer};
+use crate::messages::{format_message, get_messages};
+use crate::portal::Portal;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// Pixels which a finger must move to go to the next or the previous image
+const SWIPE_DISTANCE: i32 = 50;
+
+/// # Gallery component
+///
+/// Thumbnails of the images in a responsive grid which open a full screen viewer when they are clicked.
+/// In the viewer the arrows `Left` and `Right`, `Home` and `End` go through the images and `Escape` closes it.
+/// In touch devices the images are changed swiping and zoomed with a pinch
+///
+/// ## Features required
+///
+/// gallery
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::gallery::{Gallery, GalleryImage};
+///
+/// pub struct Trip;
+///
+/// impl Component for Trip {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Gallery
+///                 thumbnail_width=200
+///                 images=vec![
+///                     GalleryImage::new("/lake.jpg", "Lake at sunrise")
+///                         .thumbnail("/lake_small.jpg")
+///                         .caption("Day 1, the lake"),
+///                     GalleryImage::new("/peak.jpg", "Snowy peak").thumbnail("/peak_small.jpg"),
+///                 ]
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct Gallery {
+    link: ComponentLink<Self>,
+    props: Props,
+    /// Index of the image shown in the viewer
+    open: Option<usize>,
+    scale: f64,
+    pinch: Option<(f64, f64)>,
+    swipe: Option<i32>,
+    layer: Option<Layer>,
+    escape: Option<HotkeyHandle>,
+}
+
+/// Image of the `Gallery`
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GalleryImage {
+    pub src: String,
+    pub alt: String,
+    /// Smaller version of the image for the grid
+    pub thumbnail: Option<String>,
+    /// Text shown below the image in the viewer
+    pub caption: Option<String>,
+}
+
+impl GalleryImage {
+    pub fn new(src: impl Into<String>, alt: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            alt: alt.into(),
+            thumbnail: None,
+            caption: None,
+        }
+    }
+
+    /// Show a smaller version of the image in the grid
+    pub fn thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    /// Add a text below the image in the viewer
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Images of the gallery. Required
+    pub images: Vec<GalleryImage>,
+    /// Minimum width of the thumbnails in pixels, the grid fits as many columns as it can. Default `160`
+    #[prop_or(160)]
+    pub thumbnail_width: u32,
+    /// Maximum scale of the pinch in the viewer. Default `4.0`
+    #[prop_or(4.0)]
+    pub max_scale: f64,
+    /// Emitted with the index of the image shown in the viewer, `None` when it is closed
+    #[prop_or(Callback::noop())]
+    pub onview_signal: Callback<Option<usize>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Open(usize),
+    Close,
+    Show(usize),
+    Pressed(KeyboardEvent),
+    BackdropClicked(MouseEvent),
+    TouchStart(TouchEvent),
+    TouchMove(TouchEvent),
+    TouchEnd(TouchEvent),
+}
+
+impl Component for Gallery {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            open: None,
+            scale: 1.0,
+            pinch: None,
+            swipe: None,
+            layer: None,
+            escape: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open(index) => {
+                if self.layer.is_none() {
+                    let layer = acquire_layer();
+
+                    self.escape = Some(register_hotkey(
+                        Hotkey::new("Escape"),
+                        HotkeyScope::Layer(layer.id()),
+                        self.link.callback(|_| Msg::Close),
+                    ));
+                    self.layer = Some(layer);
+                }
+                self.show(index);
+            }
+            Msg::Close => self.close(),
+            Msg::Show(index) => self.show(index),
+            Msg::Pressed(keyboard_event) => {
+                let key = keyboard_event.key();
+                let next = self
+                    .open
+                    .and_then(|current| get_next_image(current, self.props.images.len(), &key));
+
+                match next {
+                    Some(next) => {
+                        keyboard_event.prevent_default();
+                        self.show(next);
+                    }
+                    None => return false,
+                }
+            }
+            Msg::BackdropClicked(mouse_event) => {
+                if mouse_event.target() != mouse_event.current_target() {
+                    return false;
+                }
+                self.close();
+            }
+            Msg::TouchStart(touch_event) => {
+                let touches = touch_event.touches();
+
+                if let Some(distance) = get_touches_distance(&touches) {
+                    self.pinch = Some((self.scale, distance));
+                    self.swipe = None;
+                } else if self.scale <= 1.0 {
+                    self.swipe = touches.item(0).map(|touch| touch.client_x());
+                }
+                return false;
+            }
+            Msg::TouchMove(touch_event) => {
+                match (self.pinch, get_touches_distance(&touch_event.touches())) {
+                    (Some((start_scale, start_distance)), Some(distance)) => {
+                        touch_event.prevent_default();
+                        self.scale = get_pinch_scale(
+                            start_scale,
+                            start_distance,
+                            distance,
+                            self.props.max_scale,
+                        );
+                    }
+                    _ => return false,
+                }
+            }
+            Msg::TouchEnd(touch_event) => {
+                self.pinch = None;
+
+                let distance = match (self.swipe.take(), touch_event.changed_touches().item(0)) {
+                    (Some(start), Some(touch)) => touch.client_x() - start,
+                    _ => return false,
+                };
+                let key = if distance <= -SWIPE_DISTANCE {
+                    "ArrowRight"
+                } else if distance >= SWIPE_DISTANCE {
+                    "ArrowLeft"
+                } else {
+                    return false;
+                };
+
+                match self
+                    .open
+                    .and_then(|current| get_next_image(current, self.props.images.len(), key))
+                {
+                    Some(next) => self.show(next),
+                    None => return false,
+                }
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if let Some(current) = self.open {
+                if current >= props.images.len() {
+                    self.close();
+                }
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("gallery")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <ul
+                    class="gallery-grid"
+                    style=format!("grid-template-columns: repeat(auto-fill, minmax({}px, 1fr));", self.props.thumbnail_width)
+                >
+                    {for self.props.images.iter().enumerate().map(|(index, image)| html! {
+                        <li>
+                            <button
+                                type="button"
+                                class="gallery-thumbnail"
+                                onclick=self.link.callback(move |_| Msg::Open(index))
+                            >
+                                <img
+                                    src=image.thumbnail.clone().unwrap_or_else(|| image.src.clone())
+                                    alt=image.alt.clone()
+                                    loading="lazy"
+                                />
+                            </button>
+                        </li>
+                    })}
+                </ul>
+                {self.get_viewer()}
+            </div>
+        }
+    }
+}
+
+impl Gallery {
+    fn show(&mut self, index: usize) {
+        if self.open == Some(index) {
+            return;
+        }
+
+        self.open = Some(index);
+        self.scale = 1.0;
+        self.pinch = None;
+        self.props.onview_signal.emit(self.open);
+    }
+
+    fn close(&mut self) {
+        if self.open.is_none() {
+            return;
+        }
+
+        self.open = None;
+        self.pinch = None;
+        self.swipe = None;
+        self.escape = None;
+        self.layer = None;
+        self.props.onview_signal.emit(None);
+    }
+
+    fn get_viewer(&self) -> Html {
+        let (index, image) = match self
+            .open
+            .and_then(|index| self.props.images.get(index).map(|image| (index, image)))
+        {
+            Some(open) => open,
+            None => return html! {},
+        };
+        let total = self.props.images.len();
+        let first = index == 0;
+        let last = index + 1 >= total;
+        let messages = get_messages();
+        let position = format_message(
+            &messages.gallery_position,
+            &[
+                ("current", (index + 1).to_string()),
+                ("total", total.to_string()),
+            ],
+        );
+
+        html! {
+            <Portal>
+                <FocusTrap>
+                    <div
+                        class="gallery-viewer"
+                        role="dialog"
+                        aria-modal="true"
+                        aria-label=image.alt.clone()
+                        tabindex="0"
+                        style=self.layer.as_ref().map(|layer| layer.style())
+                        onkeydown=self.link.callback(Msg::Pressed)
+                        onclick=self.link.callback(Msg::BackdropClicked)
+                        ontouchstart=self.link.callback(Msg::TouchStart)
+                        ontouchmove=self.link.callback(Msg::TouchMove)
+                        ontouchend=self.link.callback(Msg::TouchEnd)
+                    >
+                        <figure class="gallery-figure">
+                            <img
+                                src=image.src.clone()
+                                alt=image.alt.clone()
+                                style=format!("transform: scale({});", self.scale)
+                            />
+                            {if let Some(caption) = image.caption.clone() {
+                                html! {
+                                    <figcaption class="gallery-caption">{caption}</figcaption>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </figure>
+                        <span class="gallery-position" aria-live="polite">{position}</span>
+                        <button
+                            type="button"
+                            class="gallery-close"
+                            aria-label=messages.close.clone()
+                            onclick=self.link.callback(|_| Msg::Close)
+                        >
+                            <Icon icon_type=IconType::X />
+                        </button>
+                        <button
+                            type="button"
+                            class="gallery-previous"
+                            aria-label=messages.previous.clone()
+                            disabled=first
+                            onclick=self.link.callback(move |_| Msg::Show(index.saturating_sub(1)))
+                        >
+                            <Icon icon_type=IconType::ChevronLeft />
+                        </button>
+                        <button
+                            type="button"
+                            class="gallery-next"
+                            aria-label=messages.next.clone()
+                            disabled=last
+                            onclick=self.link.callback(move |_| Msg::Show((index + 1).min(total - 1)))
+                        >
+                            <Icon icon_type=IconType::ChevronRight />
+                        </button>
+                    </div>
+                </FocusTrap>
+            </Portal>
+        }
+    }
+}
+
+/// Index of the image shown after pressing the key, `None` if the key doesn't change the image.
+/// The viewer stops at the first and the last images
+fn get_next_image(current: usize, len: usize, key: &str) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let next = match key {
+        "ArrowRight" => (current + 1).min(len - 1),
+        "ArrowLeft" => current.saturating_sub(1),
+        "Home" => 0,
+        "End" => len - 1,
+        _ => return None,
+    };
+
+    if next == current {
+        None
+    } else {
+        Some(next)
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_go_through_the_images() {
+    assert_eq!(get_next_image(0, 3, "ArrowRight"), Some(1));
+    assert_eq!(get_next_image(2, 3, "ArrowRight"), None);
+    assert_eq!(get_next_image(0, 3, "ArrowLeft"), None);
+    assert_eq!(get_next_image(1, 3, "Home"), Some(0));
+    assert_eq!(get_next_image(1, 3, "End"), Some(2));
+    assert_eq!(get_next_image(1, 3, "Enter"), None);
+}
+
+#[wasm_bindgen_test]
+fn should_show_the_thumbnails() {
+    let props = Props::builder()
+        .id("gallery-test".to_string())
+        .images(vec![
+            GalleryImage::new("/lake.jpg", "Lake").thumbnail("/lake_small.jpg"),
+            GalleryImage::new("/peak.jpg", "Peak"),
+        ])
+        .build();
+
+    let gallery: App<Gallery> = App::new();
+    gallery.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let images = utils::document()
+        .get_element_by_id("gallery-test")
+        .unwrap()
+        .get_elements_by_tag_name("img");
+
+    assert_eq!(images.length(), 2);
+    assert_eq!(
+        images.get_with_index(0).unwrap().get_attribute("src"),
+        Some("/lake_small.jpg".to_string())
+    );
+    assert_eq!(
+        images.get_with_index(1).unwrap().get_attribute("src"),
+        Some("/peak.jpg".to_string())
+    );
+}
@@ -0,0 +1,349 @@
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use js_sys::{Array, RegExp};
+use stylist::{css, StyleSource};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+// RegExp constructor which returns the error of an invalid pattern instead of throwing it
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = RegExp)]
+    type SearchPattern;
+
+    #[wasm_bindgen(catch, constructor, js_class = "RegExp")]
+    fn new(pattern: &str, flags: &str) -> Result<SearchPattern, JsValue>;
+}
+
+/// # Highlight component
+///
+/// Text with the matches of the search terms wrapped in marks, e.g. to show search results.
+/// The matches are case-insensitive by default and with `regex` the terms are used as
+/// javascript regular expressions. `find_matches` returns the same ranges to highlight
+/// the matches in your own elements
+///
+/// ## Features required
+///
+/// highlight
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::highlight::Highlight;
+///
+/// pub struct SearchResult;
+///
+/// impl Component for SearchResult {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Highlight
+///                 text="Yew Styles is a framework of styles for yew"
+///                 terms=vec!["yew".to_string(), "style".to_string()]
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct Highlight {
+    props: Props,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Text where the terms are searched. Required
+    pub text: String,
+    /// Terms to highlight, the empty ones are ignored. Default `vec![]`
+    #[prop_or_default]
+    pub terms: Vec<String>,
+    /// Distinguish between uppercase and lowercase letters. Default `false`
+    #[prop_or(false)]
+    pub case_sensitive: bool,
+    /// Use the terms as regular expressions, invalid ones are ignored. Default `false`
+    #[prop_or(false)]
+    pub regex: bool,
+    /// Palette of the marks. Default `Palette::Warning`
+    #[prop_or(Palette::Warning)]
+    pub highlight_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+impl Component for Highlight {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let text = &self.props.text;
+        let matches = find_matches(
+            text,
+            &self.props.terms,
+            self.props.case_sensitive,
+            self.props.regex,
+        );
+        let mut segments = vec![];
+        let mut position = 0;
+
+        for (start, end) in matches {
+            if start > position {
+                segments.push(html! {<>{&text[position..start]}</>});
+            }
+
+            segments.push(html! {
+                <mark
+                    class=classes!(
+                        "highlight-mark",
+                        "light",
+                        get_palette(self.props.highlight_palette.clone()),
+                    )
+                >{&text[start..end]}</mark>
+            });
+
+            position = end;
+        }
+
+        if position < text.len() {
+            segments.push(html! {<>{&text[position..]}</>});
+        }
+
+        html! {
+            <span
+                class=ComponentClasses::new("highlight")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {for segments}
+            </span>
+        }
+    }
+}
+
+/// Byte ranges of `text` matched by any of the terms, sorted and with the overlapping ones merged
+pub fn find_matches(
+    text: &str,
+    terms: &[String],
+    case_sensitive: bool,
+    regex: bool,
+) -> Vec<(usize, usize)> {
+    let mut matches = vec![];
+
+    for term in terms.iter().filter(|term| !term.is_empty()) {
+        if regex {
+            matches.extend(find_regex_matches(text, term, case_sensitive));
+        } else if case_sensitive {
+            matches.extend(
+                text.match_indices(term.as_str())
+                    .map(|(start, matched)| (start, start + matched.len())),
+            );
+        } else {
+            matches.extend(find_insensitive_matches(text, term));
+        }
+    }
+
+    merge_ranges(matches)
+}
+
+fn find_insensitive_matches(text: &str, term: &str) -> Vec<(usize, usize)> {
+    // the lowercase text can be longer than the original one,
+    // so every lowercase byte keeps the position of its original character
+    let mut lowercase = String::new();
+    let mut origins = vec![];
+
+    for (index, character) in text.char_indices() {
+        for lowercase_character in character.to_lowercase() {
+            lowercase.push(lowercase_character);
+            origins.resize(lowercase.len(), index);
+        }
+    }
+
+    let get_origin = |index: usize| origins.get(index).copied().unwrap_or(text.len());
+
+    lowercase
+        .match_indices(&term.to_lowercase())
+        .map(|(start, matched)| {
+            let end = get_origin(start + matched.len());
+            let last = get_origin(start + matched.len() - 1);
+
+            // a match which ends in the middle of an expanded character takes the whole character
+            let end = if end == last {
+                last + text[last..].chars().next().map_or(0, char::len_utf8)
+            } else {
+                end
+            };
+
+            (get_origin(start), end)
+        })
+        .collect()
+}
+
+fn find_regex_matches(text: &str, pattern: &str, case_sensitive: bool) -> Vec<(usize, usize)> {
+    let flags = if case_sensitive { "gu" } else { "giu" };
+    let regexp = match SearchPattern::new(pattern, flags) {
+        Ok(regexp) => regexp.unchecked_into::<RegExp>(),
+        Err(_) => return vec![],
+    };
+    let mut matches = vec![];
+
+    while let Some(result) = regexp.exec(text) {
+        let matched = Array::from(&result).get(0).as_string().unwrap_or_default();
+        let end = regexp.last_index();
+
+        if matched.is_empty() {
+            // empty matches don't move the search forward
+            regexp.set_last_index(end + 1);
+            continue;
+        }
+
+        let start = end - matched.encode_utf16().count() as u32;
+        matches.push((get_byte_index(text, start), get_byte_index(text, end)));
+    }
+
+    matches
+}
+
+// RegExp positions are counted in UTF-16 code units
+fn get_byte_index(text: &str, utf16_index: u32) -> usize {
+    let mut utf16_position = 0;
+
+    for (index, character) in text.char_indices() {
+        if utf16_position >= utf16_index as usize {
+            return index;
+        }
+
+        utf16_position += character.len_utf16();
+    }
+
+    text.len()
+}
+
+fn merge_ranges(mut ranges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(usize, usize)> = vec![];
+
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_wrap_the_matches_in_marks() {
+    let props = Props::builder()
+        .id("highlight-test".to_string())
+        .terms(vec!["yew".to_string()])
+        .text("Yew Styles is a framework of styles for yew".to_string())
+        .build();
+
+    let highlight: App<Highlight> = App::new();
+    highlight.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let highlight_element = utils::document()
+        .get_element_by_id("highlight-test")
+        .unwrap();
+    let marks = highlight_element.get_elements_by_tag_name("mark");
+
+    assert_eq!(marks.length(), 2);
+    assert_eq!(marks.item(0).unwrap().text_content().unwrap(), "Yew");
+    assert_eq!(
+        highlight_element.text_content().unwrap(),
+        "Yew Styles is a framework of styles for yew"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_find_and_merge_the_matches_of_several_terms() {
+    let terms = vec!["style".to_string(), "STYLES".to_string(), "".to_string()];
+
+    assert_eq!(
+        find_matches("Yew Styles and styles", &terms, false, false),
+        vec![(4, 10), (15, 21)]
+    );
+    assert_eq!(
+        find_matches("Yew Styles and styles", &terms, true, false),
+        vec![(15, 20)]
+    );
+    assert_eq!(
+        find_matches("Ünïcode ünïcode", &["ÜNÏ".to_string()], false, false),
+        vec![(0, 5), (10, 15)]
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_find_the_regex_matches() {
+    assert_eq!(
+        find_matches("Order 12 and ñ 345", &["\\d+".to_string()], false, true),
+        vec![(6, 8), (16, 19)]
+    );
+    assert_eq!(
+        find_matches("Order 12", &["(".to_string()], false, true),
+        vec![]
+    );
+}
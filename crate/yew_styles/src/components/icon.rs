@@ -0,0 +1,470 @@
+use crate::styles::{get_palette, get_size, tokens::Spaces, ComponentClasses, Palette, Size};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Icon component
+///
+/// Svg icon of the bundled set, drawn with lines like feather icons. The icon takes the
+/// color of the text by default, so it matches the buttons, alerts or inputs where it is placed.
+/// With `IconType::Custom` the children are drawn inside of the svg instead
+///
+/// ## Features required
+///
+/// icon
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::button::Button;
+/// use yew_styles::icon::{Flip, Icon, IconType};
+/// use yew_styles::styles::{Palette, Size};
+///
+/// pub struct Toolbar;
+///
+/// impl Component for Toolbar {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Button>
+///                     <Icon icon_type=IconType::Download icon_size=Size::Small />
+///                     {"Download"}
+///                 </Button>
+///                 <Icon icon_type=IconType::AlertTriangle icon_palette=Some(Palette::Warning) title=Some("Warning".to_string()) />
+///                 <Icon icon_type=IconType::ArrowUp rotate=45 />
+///                 <Icon icon_type=IconType::ChevronRight flip=Some(Flip::Horizontal) />
+///                 <Icon icon_type=IconType::Custom>
+///                     <circle cx="12" cy="12" r="4" />
+///                 </Icon>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Icon {
+    props: Props,
+}
+
+/// Icons of the bundled set
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconType {
+    AlertCircle,
+    AlertTriangle,
+    ArrowDown,
+    ArrowUp,
+    Bell,
+    Calendar,
+    Check,
+    CheckCircle,
+    ChevronDown,
+    ChevronLeft,
+    ChevronRight,
+    ChevronUp,
+    Clock,
+    Copy,
+    Download,
+    Edit,
+    ExternalLink,
+    Eye,
+    EyeOff,
+    Filter,
+    Heart,
+    Home,
+    Info,
+    Lock,
+    Mail,
+    Menu,
+    Minus,
+    MoreHorizontal,
+    Plus,
+    Search,
+    Star,
+    Trash,
+    Upload,
+    User,
+    X,
+    XCircle,
+    /// Draw the children of the component
+    Custom,
+}
+
+/// Mirror the icon
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Flip {
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Icon to show. Required
+    pub icon_type: IconType,
+    /// Three diffent icon standard sizes. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub icon_size: Size,
+    /// Color of the icon, `None` takes the color of the text. Default `None`
+    #[prop_or_default]
+    pub icon_palette: Option<Palette>,
+    /// Fill the shapes with the color besides the lines. Default `false`
+    #[prop_or(false)]
+    pub filled: bool,
+    /// Width of the lines. Default `2.0`
+    #[prop_or(2.0)]
+    pub stroke_width: f64,
+    /// Rotation in degrees clockwise. Default `0`
+    #[prop_or(0)]
+    pub rotate: i32,
+    /// Mirror the icon. Default `None`
+    #[prop_or_default]
+    pub flip: Option<Flip>,
+    /// Text for the screen readers, without it the icon is hidden to them. Default `None`
+    #[prop_or_default]
+    pub title: Option<String>,
+    /// View box of the custom icons. Default `"0 0 24 24"`
+    #[prop_or_else(|| "0 0 24 24".to_string())]
+    pub view_box: String,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    /// Shapes of the custom icon. Only with `IconType::Custom`
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl Component for Icon {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let (view_box, shapes) = if self.props.icon_type == IconType::Custom {
+            (
+                self.props.view_box.clone(),
+                html! {<>{self.props.children.clone()}</>},
+            )
+        } else {
+            (
+                "0 0 24 24".to_string(),
+                get_shapes(self.props.icon_type)
+                    .iter()
+                    .map(get_shape)
+                    .collect::<Html>(),
+            )
+        };
+
+        html! {
+            <svg
+                class=ComponentClasses::new("icon")
+                    .semantic(get_size(self.props.icon_size.clone()))
+                    .semantic(self.props.icon_palette.clone().map(get_palette))
+                    .state("filled", self.props.filled)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                xmlns="http://www.w3.org/2000/svg"
+                viewBox=view_box
+                fill=if self.props.filled { "currentColor" } else { "none" }
+                stroke="currentColor"
+                stroke-width=self.props.stroke_width.to_string()
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                style=get_transform(self.props.rotate, self.props.flip)
+                role=self.props.title.as_ref().map(|_| "img")
+                aria-label=self.props.title.clone()
+                aria-hidden=if self.props.title.is_none() { Some("true") } else { None }
+                focusable="false"
+            >
+                {match &self.props.title {
+                    Some(title) => html! {<title>{title}</title>},
+                    None => html! {},
+                }}
+                {shapes}
+            </svg>
+        }
+    }
+}
+
+enum Shape {
+    Path(&'static str),
+    Line(f64, f64, f64, f64),
+    Circle(f64, f64, f64),
+    Polyline(&'static str),
+    Polygon(&'static str),
+    Rect(f64, f64, f64, f64),
+}
+
+fn get_shape(shape: &Shape) -> Html {
+    match *shape {
+        Shape::Path(d) => html! {<path d=d />},
+        Shape::Line(x1, y1, x2, y2) => html! {
+            <line x1=x1.to_string() y1=y1.to_string() x2=x2.to_string() y2=y2.to_string() />
+        },
+        Shape::Circle(cx, cy, r) => html! {
+            <circle cx=cx.to_string() cy=cy.to_string() r=r.to_string() />
+        },
+        Shape::Polyline(points) => html! {<polyline points=points />},
+        Shape::Polygon(points) => html! {<polygon points=points />},
+        Shape::Rect(x, y, width, height) => html! {
+            <rect x=x.to_string() y=y.to_string() width=width.to_string() height=height.to_string() rx="2" ry="2" />
+        },
+    }
+}
+
+fn get_shapes(icon_type: IconType) -> &'static [Shape] {
+    match icon_type {
+        IconType::AlertCircle => &[
+            Shape::Circle(12.0, 12.0, 10.0),
+            Shape::Line(12.0, 8.0, 12.0, 12.0),
+            Shape::Line(12.0, 16.0, 12.01, 16.0),
+        ],
+        IconType::AlertTriangle => &[
+            Shape::Path("M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z"),
+            Shape::Line(12.0, 9.0, 12.0, 13.0),
+            Shape::Line(12.0, 17.0, 12.01, 17.0),
+        ],
+        IconType::ArrowDown => &[
+            Shape::Line(12.0, 5.0, 12.0, 19.0),
+            Shape::Polyline("19 12 12 19 5 12"),
+        ],
+        IconType::ArrowUp => &[
+            Shape::Line(12.0, 19.0, 12.0, 5.0),
+            Shape::Polyline("5 12 12 5 19 12"),
+        ],
+        IconType::Bell => &[
+            Shape::Path("M18 8A6 6 0 0 0 6 8c0 7-3 9-3 9h18s-3-2-3-9"),
+            Shape::Path("M13.73 21a2 2 0 0 1-3.46 0"),
+        ],
+        IconType::Calendar => &[
+            Shape::Rect(3.0, 4.0, 18.0, 18.0),
+            Shape::Line(16.0, 2.0, 16.0, 6.0),
+            Shape::Line(8.0, 2.0, 8.0, 6.0),
+            Shape::Line(3.0, 10.0, 21.0, 10.0),
+        ],
+        IconType::Check => &[Shape::Polyline("20 6 9 17 4 12")],
+        IconType::CheckCircle => &[
+            Shape::Path("M22 11.08V12a10 10 0 1 1-5.93-9.14"),
+            Shape::Polyline("22 4 12 14.01 9 11.01"),
+        ],
+        IconType::ChevronDown => &[Shape::Polyline("6 9 12 15 18 9")],
+        IconType::ChevronLeft => &[Shape::Polyline("15 18 9 12 15 6")],
+        IconType::ChevronRight => &[Shape::Polyline("9 18 15 12 9 6")],
+        IconType::ChevronUp => &[Shape::Polyline("18 15 12 9 6 15")],
+        IconType::Clock => &[
+            Shape::Circle(12.0, 12.0, 10.0),
+            Shape::Polyline("12 6 12 12 16 14"),
+        ],
+        IconType::Copy => &[
+            Shape::Rect(9.0, 9.0, 13.0, 13.0),
+            Shape::Path("M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1"),
+        ],
+        IconType::Download => &[
+            Shape::Path("M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"),
+            Shape::Polyline("7 10 12 15 17 10"),
+            Shape::Line(12.0, 15.0, 12.0, 3.0),
+        ],
+        IconType::Edit => &[Shape::Path(
+            "M17 3a2.828 2.828 0 1 1 4 4L7.5 20.5 2 22l1.5-5.5L17 3z",
+        )],
+        IconType::ExternalLink => &[
+            Shape::Path("M18 13v6a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V8a2 2 0 0 1 2-2h6"),
+            Shape::Polyline("15 3 21 3 21 9"),
+            Shape::Line(10.0, 14.0, 21.0, 3.0),
+        ],
+        IconType::Eye => &[
+            Shape::Path("M1 12s4-8 11-8 11 8 11 8-4 8-11 8-11-8-11-8z"),
+            Shape::Circle(12.0, 12.0, 3.0),
+        ],
+        IconType::EyeOff => &[
+            Shape::Path("M17.94 17.94A10.07 10.07 0 0 1 12 20c-7 0-11-8-11-8a18.45 18.45 0 0 1 5.06-5.94M9.9 4.24A9.12 9.12 0 0 1 12 4c7 0 11 8 11 8a18.5 18.5 0 0 1-2.16 3.19m-6.72-1.07a3 3 0 1 1-4.24-4.24"),
+            Shape::Line(1.0, 1.0, 23.0, 23.0),
+        ],
+        IconType::Filter => &[Shape::Polygon("22 3 2 3 10 12.46 10 19 14 21 14 12.46 22 3")],
+        IconType::Heart => &[Shape::Path(
+            "M20.84 4.61a5.5 5.5 0 0 0-7.78 0L12 5.67l-1.06-1.06a5.5 5.5 0 0 0-7.78 7.78l1.06 1.06L12 21.23l7.78-7.78 1.06-1.06a5.5 5.5 0 0 0 0-7.78z",
+        )],
+        IconType::Home => &[
+            Shape::Path("M3 9l9-7 9 7v11a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2z"),
+            Shape::Polyline("9 22 9 12 15 12 15 22"),
+        ],
+        IconType::Info => &[
+            Shape::Circle(12.0, 12.0, 10.0),
+            Shape::Line(12.0, 16.0, 12.0, 12.0),
+            Shape::Line(12.0, 8.0, 12.01, 8.0),
+        ],
+        IconType::Lock => &[
+            Shape::Rect(3.0, 11.0, 18.0, 11.0),
+            Shape::Path("M7 11V7a5 5 0 0 1 10 0v4"),
+        ],
+        IconType::Mail => &[
+            Shape::Path("M4 4h16c1.1 0 2 .9 2 2v12c0 1.1-.9 2-2 2H4c-1.1 0-2-.9-2-2V6c0-1.1.9-2 2-2z"),
+            Shape::Polyline("22 6 12 13 2 6"),
+        ],
+        IconType::Menu => &[
+            Shape::Line(3.0, 12.0, 21.0, 12.0),
+            Shape::Line(3.0, 6.0, 21.0, 6.0),
+            Shape::Line(3.0, 18.0, 21.0, 18.0),
+        ],
+        IconType::Minus => &[Shape::Line(5.0, 12.0, 19.0, 12.0)],
+        IconType::MoreHorizontal => &[
+            Shape::Circle(12.0, 12.0, 1.0),
+            Shape::Circle(19.0, 12.0, 1.0),
+            Shape::Circle(5.0, 12.0, 1.0),
+        ],
+        IconType::Plus => &[
+            Shape::Line(12.0, 5.0, 12.0, 19.0),
+            Shape::Line(5.0, 12.0, 19.0, 12.0),
+        ],
+        IconType::Search => &[
+            Shape::Circle(11.0, 11.0, 8.0),
+            Shape::Line(21.0, 21.0, 16.65, 16.65),
+        ],
+        IconType::Star => &[Shape::Polygon(
+            "12 2 15.09 8.26 22 9.27 17 14.14 18.18 21.02 12 17.77 5.82 21.02 7 14.14 2 9.27 8.91 8.26 12 2",
+        )],
+        IconType::Trash => &[
+            Shape::Polyline("3 6 5 6 21 6"),
+            Shape::Path("M19 6v14a2 2 0 0 1-2 2H7a2 2 0 0 1-2-2V6m3 0V4a2 2 0 0 1 2-2h4a2 2 0 0 1 2 2v2"),
+        ],
+        IconType::Upload => &[
+            Shape::Path("M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"),
+            Shape::Polyline("17 8 12 3 7 8"),
+            Shape::Line(12.0, 3.0, 12.0, 15.0),
+        ],
+        IconType::User => &[
+            Shape::Path("M20 21v-2a4 4 0 0 0-4-4H8a4 4 0 0 0-4 4v2"),
+            Shape::Circle(12.0, 7.0, 4.0),
+        ],
+        IconType::X => &[
+            Shape::Line(18.0, 6.0, 6.0, 18.0),
+            Shape::Line(6.0, 6.0, 18.0, 18.0),
+        ],
+        IconType::XCircle => &[
+            Shape::Circle(12.0, 12.0, 10.0),
+            Shape::Line(15.0, 9.0, 9.0, 15.0),
+            Shape::Line(9.0, 9.0, 15.0, 15.0),
+        ],
+        IconType::Custom => &[],
+    }
+}
+
+fn get_transform(rotate: i32, flip: Option<Flip>) -> String {
+    let mut transforms = vec![];
+
+    if rotate % 360 != 0 {
+        transforms.push(format!("rotate({}deg)", rotate));
+    }
+
+    match flip {
+        Some(Flip::Horizontal) => transforms.push("scale(-1, 1)".to_string()),
+        Some(Flip::Vertical) => transforms.push("scale(1, -1)".to_string()),
+        Some(Flip::Both) => transforms.push("scale(-1, -1)".to_string()),
+        None => {}
+    }
+
+    if transforms.is_empty() {
+        String::new()
+    } else {
+        format!("transform: {};", transforms.join(" "))
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_an_icon_hidden_to_screen_readers() {
+    let props = Props::builder()
+        .icon_palette(Some(Palette::Success))
+        .icon_size(Size::Small)
+        .icon_type(IconType::Check)
+        .id("icon-test".to_string())
+        .view_box("0 0 24 24".to_string())
+        .build();
+
+    let icon: App<Icon> = App::new();
+    icon.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let icon_element = utils::document().get_element_by_id("icon-test").unwrap();
+
+    assert!(icon_element.class_list().contains("small"));
+    assert!(icon_element.class_list().contains("success"));
+    assert_eq!(icon_element.get_attribute("aria-hidden").unwrap(), "true");
+    assert_eq!(
+        icon_element.get_elements_by_tag_name("polyline").length(),
+        1
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_transform_of_rotation_and_flip() {
+    assert_eq!(get_transform(0, None), "");
+    assert_eq!(get_transform(360, None), "");
+    assert_eq!(
+        get_transform(90, Some(Flip::Horizontal)),
+        "transform: rotate(90deg) scale(-1, 1);"
+    );
+    assert_eq!(
+        get_transform(0, Some(Flip::Both)),
+        "transform: scale(-1, -1);"
+    );
+}
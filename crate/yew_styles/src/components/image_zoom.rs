@@ -0,0 +1,394 @@
+use crate::focus_trap::FocusTrap;
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, TouchList};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Image Zoom component
+///
+/// Shows a lens which magnifies the image under the mouse and, with a click,
+/// the image in full view, where it can be zoomed with a pinch in touch devices.
+/// Press `Escape` or click the full view to close it
+///
+/// ## Features required
+///
+/// image_zoom
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::image_zoom::ImageZoom;
+///
+/// pub struct ProductImage;
+///
+/// impl Component for ProductImage {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <ImageZoom
+///                 img_src="/shoe.jpg"
+///                 zoom_img_src="/shoe_large.jpg"
+///                 img_alt="Running shoe"
+///                 zoom=3.0
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct ImageZoom {
+    link: ComponentLink<Self>,
+    props: Props,
+    lens: Option<LensPosition>,
+    full_view: bool,
+    scale: f64,
+    pinch: Option<(f64, f64)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct LensPosition {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Url image path. Required
+    pub img_src: String,
+    /// Url of a bigger version of the image for the lens and the full view. Default `img_src`
+    #[prop_or_default]
+    pub zoom_img_src: Option<String>,
+    /// Alternative text of the image. Default `""`
+    #[prop_or_default]
+    pub img_alt: String,
+    /// Magnification of the lens. Default `2.0`
+    #[prop_or(2.0)]
+    pub zoom: f64,
+    /// Size of the lens in pixels. Default `150`
+    #[prop_or(150)]
+    pub lens_size: u32,
+    /// Show the lens when the mouse is over the image. Default `true`
+    #[prop_or(true)]
+    pub hover_zoom: bool,
+    /// Open the image in full view with a click. Default `true`
+    #[prop_or(true)]
+    pub click_to_zoom: bool,
+    /// Maximum scale of the pinch in the full view. Default `4.0`
+    #[prop_or(4.0)]
+    pub max_scale: f64,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Move(MouseEvent),
+    Leave,
+    Open,
+    Close,
+    Pressed(KeyboardEvent),
+    TouchStart(TouchEvent),
+    TouchMove(TouchEvent),
+    TouchEnd,
+}
+
+impl Component for ImageZoom {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            lens: None,
+            full_view: false,
+            scale: 1.0,
+            pinch: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Move(mouse_event) => {
+                if !self.props.hover_zoom {
+                    return false;
+                }
+
+                if let Some(image) = mouse_event
+                    .target()
+                    .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+                {
+                    self.lens = Some(LensPosition {
+                        x: mouse_event.offset_x() as f64,
+                        y: mouse_event.offset_y() as f64,
+                        width: image.offset_width() as f64,
+                        height: image.offset_height() as f64,
+                    });
+                }
+            }
+            Msg::Leave => {
+                self.lens = None;
+            }
+            Msg::Open => {
+                if !self.props.click_to_zoom {
+                    return false;
+                }
+
+                self.lens = None;
+                self.scale = 1.0;
+                self.full_view = true;
+            }
+            Msg::Close => {
+                self.full_view = false;
+                self.pinch = None;
+            }
+            Msg::Pressed(keyboard_event) => match keyboard_event.key().as_str() {
+                "Escape" => {
+                    self.full_view = false;
+                    self.pinch = None;
+                }
+                "Enter" | " " if !self.full_view && self.props.click_to_zoom => {
+                    keyboard_event.prevent_default();
+                    self.scale = 1.0;
+                    self.full_view = true;
+                }
+                _ => return false,
+            },
+            Msg::TouchStart(touch_event) => {
+                if let Some(distance) = get_touches_distance(&touch_event.touches()) {
+                    self.pinch = Some((self.scale, distance));
+                }
+                return false;
+            }
+            Msg::TouchMove(touch_event) => {
+                match (self.pinch, get_touches_distance(&touch_event.touches())) {
+                    (Some((start_scale, start_distance)), Some(distance)) => {
+                        touch_event.prevent_default();
+                        self.scale = get_pinch_scale(
+                            start_scale,
+                            start_distance,
+                            distance,
+                            self.props.max_scale,
+                        );
+                    }
+                    _ => return false,
+                }
+            }
+            Msg::TouchEnd => {
+                self.pinch = None;
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        let zoom_src = self
+            .props
+            .zoom_img_src
+            .clone()
+            .unwrap_or_else(|| self.props.img_src.clone());
+
+        html! {
+            <div
+                class=ComponentClasses::new("image-zoom")
+                    .state("clickable", self.props.click_to_zoom)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                tabindex=if self.props.click_to_zoom { "0" } else { "-1" }
+                onkeydown=self.link.callback(Msg::Pressed)
+            >
+                <img
+                    class="image-zoom-image"
+                    src=self.props.img_src.clone()
+                    alt=self.props.img_alt.clone()
+                    onmousemove=self.link.callback(Msg::Move)
+                    onmouseleave=self.link.callback(|_| Msg::Leave)
+                    onclick=self.link.callback(|_| Msg::Open)
+                />
+                {match self.lens {
+                    Some(lens) => html! {
+                        <div
+                            class="image-zoom-lens"
+                            aria-hidden="true"
+                            style=get_lens_style(lens, self.props.zoom, self.props.lens_size, &zoom_src)
+                        ></div>
+                    },
+                    None => html! {},
+                }}
+                {if self.full_view {
+                    html! {
+                        <FocusTrap>
+                            <div
+                                class="image-zoom-full"
+                                role="dialog"
+                                aria-modal="true"
+                                aria-label=self.props.img_alt.clone()
+                                tabindex="0"
+                                onclick=self.link.callback(|_| Msg::Close)
+                                ontouchstart=self.link.callback(Msg::TouchStart)
+                                ontouchmove=self.link.callback(Msg::TouchMove)
+                                ontouchend=self.link.callback(|_| Msg::TouchEnd)
+                            >
+                                <img
+                                    src=zoom_src
+                                    alt=self.props.img_alt.clone()
+                                    style=format!("transform: scale({});", self.scale)
+                                />
+                                <button
+                                    class="image-zoom-close"
+                                    aria-label=get_messages().close
+                                    onclick=self.link.callback(|_| Msg::Close)
+                                >{"×"}</button>
+                            </div>
+                        </FocusTrap>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+fn get_lens_style(lens: LensPosition, zoom: f64, lens_size: u32, src: &str) -> String {
+    let half = lens_size as f64 / 2.0;
+
+    format!(
+        "width: {size}px; height: {size}px; left: {left}px; top: {top}px; background-image: url(\"{src}\"); \
+         background-size: {width}px {height}px; background-position: {x}px {y}px;",
+        size = lens_size,
+        left = lens.x - half,
+        top = lens.y - half,
+        src = src,
+        width = lens.width * zoom,
+        height = lens.height * zoom,
+        x = half - lens.x * zoom,
+        y = half - lens.y * zoom,
+    )
+}
+
+pub(crate) fn get_touches_distance(touches: &TouchList) -> Option<f64> {
+    if touches.length() < 2 {
+        return None;
+    }
+
+    let (first, second) = (touches.item(0)?, touches.item(1)?);
+    let x = (first.client_x() - second.client_x()) as f64;
+    let y = (first.client_y() - second.client_y()) as f64;
+
+    Some((x * x + y * y).sqrt())
+}
+
+pub(crate) fn get_pinch_scale(
+    start_scale: f64,
+    start_distance: f64,
+    distance: f64,
+    max_scale: f64,
+) -> f64 {
+    if start_distance <= 0.0 {
+        return start_scale;
+    }
+
+    (start_scale * distance / start_distance).clamp(1.0, max_scale.max(1.0))
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_image_zoom() {
+    let props = Props::builder()
+        .id("image-zoom-test".to_string())
+        .img_alt("slide".to_string())
+        .img_src("/slide_1.jpg".to_string())
+        .build();
+
+    let image_zoom: App<ImageZoom> = App::new();
+    image_zoom.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let image_zoom_element = utils::document()
+        .get_element_by_id("image-zoom-test")
+        .unwrap();
+
+    assert_eq!(
+        image_zoom_element
+            .first_element_child()
+            .unwrap()
+            .get_attribute("alt")
+            .unwrap(),
+        "slide"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_lens_and_pinch_values() {
+    let lens = LensPosition {
+        x: 100.0,
+        y: 50.0,
+        width: 400.0,
+        height: 300.0,
+    };
+    let style = get_lens_style(lens, 2.0, 100, "/slide_1.jpg");
+
+    assert!(style.contains("left: 50px; top: 0px;"));
+    assert!(style.contains("background-size: 800px 600px; background-position: -150px -50px;"));
+    assert_eq!(get_pinch_scale(1.0, 100.0, 200.0, 4.0), 2.0);
+    assert_eq!(get_pinch_scale(2.0, 100.0, 400.0, 4.0), 4.0);
+    assert_eq!(get_pinch_scale(1.0, 100.0, 50.0, 4.0), 1.0);
+}
@@ -0,0 +1,331 @@
+use crate::messages::get_messages;
+use crate::spinner::Spinner;
+use crate::styles::{tokens::Spaces, ComponentClasses, Size};
+use crate::utils::is_browser;
+use stylist::{css, StyleSource};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::{Element, EventTarget};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Infinite Scroll component
+///
+/// Wraps a `List`, a `Table` or any other content and emits `onloadmore_signal` when the empty element placed
+/// after the children comes closer than `threshold` to the bottom of the page or of the scrollable `container`.
+/// While `loading` it shows a spinner and it isn't emitted again, once `has_more` is false it shows the end
+/// of the results. If the loaded content doesn't fill the page it keeps asking for more
+///
+/// ## Features required
+///
+/// infinite_scroll
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::infinite_scroll::InfiniteScroll;
+/// use yew_styles::list::{List, ListItem};
+///
+/// pub struct Feed {
+///     link: ComponentLink<Self>,
+///     posts: Vec<String>,
+///     loading: bool,
+/// }
+///
+/// pub enum Msg {
+///     LoadMore,
+///     Loaded(Vec<String>),
+/// }
+///
+/// impl Component for Feed {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             posts: vec![],
+///             loading: false,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::LoadMore => {
+///                 self.loading = true;
+///                 // fetch the next page and send Msg::Loaded
+///             }
+///             Msg::Loaded(posts) => {
+///                 self.posts.extend(posts);
+///                 self.loading = false;
+///             }
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <InfiniteScroll
+///                 loading=self.loading
+///                 has_more=self.posts.len() < 100
+///                 onloadmore_signal=self.link.callback(|_| Msg::LoadMore)
+///             >
+///                 <List>
+///                     {for self.posts.iter().map(|post| html! {
+///                         <ListItem value=post.clone()>{post}</ListItem>
+///                     })}
+///                 </List>
+///             </InfiniteScroll>
+///         }
+///     }
+/// }
+/// ```
+pub struct InfiniteScroll {
+    link: ComponentLink<Self>,
+    props: Props,
+    /// More content was asked and the parent hasn't answered yet
+    requested: bool,
+    sentinel_ref: NodeRef,
+    listener: Option<(EventTarget, Closure<dyn Fn(Event)>)>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// More content is being loaded. Default `false`
+    #[prop_or(false)]
+    pub loading: bool,
+    /// There is more content to load, when it is false the end of the results is shown. Default `true`
+    #[prop_or(true)]
+    pub has_more: bool,
+    /// Pixels before the end of the content where more content is asked. Default `200`
+    #[prop_or(200)]
+    pub threshold: i32,
+    /// Scrollable element which contains the content, the page when it isn't set. Default `NodeRef::default()`
+    #[prop_or_default]
+    pub container: NodeRef,
+    /// Content shown while loading. Default a small spinner
+    #[prop_or_default]
+    pub loading_content: Option<Html>,
+    /// Content shown when there is no more content. Default the message `infinite_scroll_end`
+    #[prop_or_default]
+    pub end_content: Option<Html>,
+    /// Emitted when the end of the content is near and there is more content to load
+    #[prop_or(Callback::noop())]
+    pub onloadmore_signal: Callback<()>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Scrolled,
+}
+
+impl Component for InfiniteScroll {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            requested: false,
+            sentinel_ref: NodeRef::default(),
+            listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scrolled => {
+                let sentinel_top = match self.sentinel_ref.cast::<Element>() {
+                    Some(sentinel) => sentinel.get_bounding_client_rect().top(),
+                    None => return false,
+                };
+                let viewport_bottom = match self.props.container.cast::<Element>() {
+                    Some(container) => container.get_bounding_client_rect().bottom(),
+                    None => utils::window()
+                        .inner_height()
+                        .ok()
+                        .and_then(|height| height.as_f64())
+                        .unwrap_or(0.0),
+                };
+
+                if !is_near_end(sentinel_top, viewport_bottom, self.props.threshold) {
+                    self.requested = false;
+                } else if !self.requested && !self.props.loading && self.props.has_more {
+                    self.requested = true;
+                    self.props.onloadmore_signal.emit(());
+                }
+            }
+        };
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let container_changed = self.props.container != props.container;
+            self.props = props;
+            self.requested = false;
+
+            if container_changed {
+                self.stop_listening();
+                self.listen();
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.listen();
+        }
+
+        // The new content may not reach the end of the page yet
+        self.link.send_message(Msg::Scrolled);
+    }
+
+    fn destroy(&mut self) {
+        self.stop_listening();
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("infinite-scroll")
+                    .state("loading", self.props.loading)
+                    .state("ended", !self.props.has_more)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                aria-busy=self.props.loading.to_string()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.clone()}
+                <div class="infinite-scroll-sentinel" aria-hidden="true" ref=self.sentinel_ref.clone()></div>
+                {self.get_status()}
+            </div>
+        }
+    }
+}
+
+impl InfiniteScroll {
+    fn listen(&mut self) {
+        if self.listener.is_some() || !is_browser() {
+            return;
+        }
+
+        let target: EventTarget = match self.props.container.cast::<Element>() {
+            Some(container) => container.into(),
+            None => utils::window().into(),
+        };
+        let link = self.link.clone();
+        let listener = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Scrolled)) as Box<dyn Fn(Event)>
+        );
+
+        target
+            .add_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+            .ok();
+        self.listener = Some((target, listener));
+    }
+
+    fn stop_listening(&mut self) {
+        if let Some((target, listener)) = self.listener.take() {
+            target
+                .remove_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+                .ok();
+        }
+    }
+
+    fn get_status(&self) -> Html {
+        if self.props.loading {
+            html! {
+                <div class="infinite-scroll-loader">
+                    {self.props.loading_content.clone().unwrap_or_else(|| html! {
+                        <Spinner spinner_size=Size::Small />
+                    })}
+                </div>
+            }
+        } else if !self.props.has_more {
+            html! {
+                <div class="infinite-scroll-end" role="status">
+                    {self.props.end_content.clone().unwrap_or_else(|| html! {
+                        {get_messages().infinite_scroll_end}
+                    })}
+                </div>
+            }
+        } else {
+            html! {}
+        }
+    }
+}
+
+/// The end is near when the empty element after the children is closer than `threshold` to the bottom of the viewport
+fn is_near_end(sentinel_top: f64, viewport_bottom: f64, threshold: i32) -> bool {
+    sentinel_top - viewport_bottom <= threshold as f64
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_be_near_the_end_within_the_threshold() {
+    assert!(is_near_end(900.0, 800.0, 200));
+    assert!(is_near_end(500.0, 800.0, 0));
+    assert!(!is_near_end(1100.0, 800.0, 200));
+}
+
+#[wasm_bindgen_test]
+fn should_show_the_end_of_the_results() {
+    let props = Props::builder()
+        .has_more(false)
+        .id("infinite-scroll-test".to_string())
+        .children(Children::new(vec![html! {"Posts"}]))
+        .build();
+
+    let infinite_scroll: App<InfiniteScroll> = App::new();
+    infinite_scroll.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let end_element = utils::document()
+        .get_element_by_id("infinite-scroll-test")
+        .unwrap()
+        .last_element_child()
+        .unwrap();
+
+    assert_eq!(end_element.class_name(), "infinite-scroll-end");
+    assert_eq!(end_element.text_content().unwrap(), "No more results");
+}
@@ -0,0 +1,193 @@
+use crate::styles::{breakpoints::Breakpoint, tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Column component
+///
+/// Column of a 12 columns grid, it must be a child of `Row`.
+/// The spans, offsets and orders are applied from the breakpoint up,
+/// a column without spans shares the free space with the other columns
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::layouts::{column::Column, row::Row};
+/// use yew_styles::styles::breakpoints::Breakpoint;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Row>
+///                 <Column
+///                     spans=vec![(Breakpoint::Xs, 12), (Breakpoint::L, 6)]
+///                     offsets=vec![(Breakpoint::L, 3)]
+///                 >
+///                     <p>{"Centered in big screens"}</p>
+///                 </Column>
+///                 <Column spans=vec![(Breakpoint::Xs, 6)] orders=vec![(Breakpoint::M, 0)]>
+///                     <p>{"First from medium screens"}</p>
+///                 </Column>
+///                 <Column>
+///                     <p>{"Takes the free space"}</p>
+///                 </Column>
+///             </Row>
+///         }
+///     }
+/// }
+/// ```
+pub struct Column {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Columns of the grid, from 1 to 12, that take the column from a breakpoint up. Default `vec![]`
+    #[prop_or_default]
+    pub spans: Vec<(Breakpoint, u8)>,
+    /// Columns of the grid, from 0 to 11, left before the column from a breakpoint up. Default `vec![]`
+    #[prop_or_default]
+    pub offsets: Vec<(Breakpoint, u8)>,
+    /// Visual order of the column in the row, from 0 to 12, from a breakpoint up. Default `vec![]`
+    #[prop_or_default]
+    pub orders: Vec<(Breakpoint, u8)>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for Column {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("column")
+                    .semantic(get_grid_classes("col", &self.props.spans, 1, 12))
+                    .semantic(get_grid_classes("offset", &self.props.offsets, 0, 11))
+                    .semantic(get_grid_classes("order", &self.props.orders, 0, 12))
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+fn get_grid_classes(prefix: &str, values: &[(Breakpoint, u8)], min: u8, max: u8) -> Vec<String> {
+    values
+        .iter()
+        .map(|(breakpoint, value)| {
+            format!(
+                "{}-{}-{}",
+                prefix,
+                breakpoint.name(),
+                (*value).max(min).min(max)
+            )
+        })
+        .collect()
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_grid_classes() {
+    assert_eq!(
+        get_grid_classes("col", &[(Breakpoint::Xs, 12), (Breakpoint::M, 20)], 1, 12),
+        vec!["col-xs-12".to_string(), "col-m-12".to_string()]
+    );
+    assert_eq!(
+        get_grid_classes("col", &[(Breakpoint::L, 0)], 1, 12),
+        vec!["col-l-1".to_string()]
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_create_column_with_span_offset_and_order() {
+    let props = Props::builder()
+        .id("column".to_string())
+        .offsets(vec![(Breakpoint::M, 3)])
+        .orders(vec![(Breakpoint::Xs, 1)])
+        .spans(vec![(Breakpoint::M, 6)])
+        .children(Children::new(vec![html! {"Column"}]))
+        .build();
+
+    let column: App<Column> = App::new();
+
+    column.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let column_element = utils::document().get_element_by_id("column").unwrap();
+
+    assert!(column_element.class_list().contains("col-m-6"));
+    assert!(column_element.class_list().contains("offset-m-3"));
+    assert!(column_element.class_list().contains("order-xs-1"));
+}
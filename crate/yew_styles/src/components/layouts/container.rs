@@ -1,3 +1,4 @@
+use crate::styles::{breakpoints::Breakpoint, tokens::Spaces, ComponentClasses};
 use crate::utils::{create_style, get_random_string};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
@@ -76,6 +77,7 @@ struct ContainerModel;
 
 /// Which direction are placing the items
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Row,
     RowReverse,
@@ -85,6 +87,7 @@ pub enum Direction {
 
 /// Set a wrap for the items
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Wrap {
     Nowrap,
     Wrap,
@@ -92,6 +95,7 @@ pub enum Wrap {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mode {
     SafeMode,
     UnsafeMode,
@@ -100,6 +104,7 @@ pub enum Mode {
 
 /// Set how will be justified the content
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JustifyContent {
     FlexStart(Mode),
     FlexEnd(Mode),
@@ -115,6 +120,7 @@ pub enum JustifyContent {
 
 /// Set how will be aligned the items
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlignItems {
     Stretch(Mode),
     FlexStart(Mode),
@@ -131,6 +137,7 @@ pub enum AlignItems {
 
 /// set how will be aligned the content
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlignContent {
     FlexStart(Mode),
     FlexEnd(Mode),
@@ -163,6 +170,15 @@ pub struct Props {
     /// Set how will be aligned the items. Default `AlignItems::Stretch(Mode::NoMode)`
     #[prop_or(AlignItems::Stretch(Mode::NoMode))]
     pub align_items: AlignItems,
+    /// Direction of the items from a breakpoint up, it replaces `direction` in bigger screens.
+    /// e.g. `vec![(Breakpoint::M, Direction::Row)]` places the items in a column in small screens
+    /// and in a row from medium screens. Default `vec![]`
+    #[prop_or_default]
+    pub responsive_direction: Vec<(Breakpoint, Direction)>,
+    /// Limit the width of the container to the current breakpoint and center it.
+    /// A fluid container takes the full width. Default `false`
+    #[prop_or(false)]
+    pub fixed: bool,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -177,6 +193,12 @@ pub struct Props {
     pub id: String,
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -211,7 +233,14 @@ impl Component for Container {
 
     fn view(&self) -> Html {
         html! {
-            <div class=classes!("container", format!("container-{} {}", self.key, self.props.class_name), self.props.styles.clone())
+            <div class=ComponentClasses::new("container")
+                    .semantic(format!("container-{}", self.key))
+                    .semantic(get_responsive_direction(&self.props.responsive_direction))
+                    .state("container-fixed", self.props.fixed)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.to_string()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
@@ -222,6 +251,22 @@ impl Component for Container {
     }
 }
 
+fn get_responsive_direction(responsive_direction: &[(Breakpoint, Direction)]) -> Vec<String> {
+    responsive_direction
+        .iter()
+        .map(|(breakpoint, direction)| {
+            let direction = match direction {
+                Direction::Row => "row",
+                Direction::RowReverse => "row-reverse",
+                Direction::Column => "column",
+                Direction::ColumnReverse => "column-reverse",
+            };
+
+            format!("direction-{}-{}", breakpoint.name(), direction)
+        })
+        .collect()
+}
+
 impl ContainerModel {
     fn init(self, props: Props, key: String) {
         self.get_flow(props.direction, props.wrap, key.clone());
@@ -332,21 +377,20 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_a_container() {
-    let props_container = Props {
-        direction: Direction::Row,
-        wrap: Wrap::Wrap,
-        justify_content: JustifyContent::Center(Mode::NoMode),
-        align_content: AlignContent::Center(Mode::NoMode),
-        align_items: AlignItems::Center(Mode::NoMode),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: String::from("layout-test"),
-        styles: css!("color: red;"),
-        id: String::from("layout-id-test"),
-        children: Children::new(vec![html! {
+    let props_container = Props::builder()
+        .align_content(AlignContent::Center(Mode::NoMode))
+        .align_items(AlignItems::Center(Mode::NoMode))
+        .class_name(String::from("layout-test"))
+        .direction(Direction::Row)
+        .id(String::from("layout-id-test"))
+        .justify_content(JustifyContent::Center(Mode::NoMode))
+        .responsive_direction(vec![(Breakpoint::M, Direction::Row)])
+        .styles(css!("color: red;"))
+        .wrap(Wrap::Wrap)
+        .children(Children::new(vec![html! {
             <div id="container">{"Container"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let container: App<Container> = App::new();
     container.mount_with_props(
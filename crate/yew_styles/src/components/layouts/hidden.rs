@@ -0,0 +1,143 @@
+use crate::styles::breakpoints::Breakpoint;
+use crate::styles::ComponentClasses;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Hidden component
+///
+/// Hides its children in the selected breakpoints without writing media queries
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::layouts::hidden::Hidden;
+/// use yew_styles::styles::breakpoints::Breakpoint;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Hidden hidden_on=vec![Breakpoint::Xs, Breakpoint::S]>
+///                 <span>{"Only in medium and big screens"}</span>
+///             </Hidden>
+///         }
+///     }
+/// }
+/// ```
+pub struct Hidden {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Breakpoints where the children are hidden. Required
+    pub hidden_on: Vec<Breakpoint>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+impl Component for Hidden {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("hidden")
+                    .semantic(
+                        self.props
+                            .hidden_on
+                            .iter()
+                            .map(|breakpoint| format!("hidden-{}", breakpoint.name()))
+                            .collect::<Vec<String>>(),
+                    )
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_add_the_hidden_breakpoint_classes() {
+    let props = Props::builder()
+        .hidden_on(vec![Breakpoint::Xs, Breakpoint::Xl])
+        .id("hidden".to_string())
+        .children(Children::new(vec![html! {"content"}]))
+        .build();
+
+    let hidden: App<Hidden> = App::new();
+
+    hidden.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let hidden_element = utils::document().get_element_by_id("hidden").unwrap();
+
+    assert!(hidden_element.class_list().contains("hidden-xs"));
+    assert!(hidden_element.class_list().contains("hidden-xl"));
+    assert!(!hidden_element.class_list().contains("hidden-m"));
+}
@@ -1,3 +1,4 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use crate::utils::{create_style, get_random_string};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
@@ -7,6 +8,7 @@ use yew::{utils, App};
 
 /// Percent of the layout that will take the item.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItemLayout {
     ItXs(i8),
     ItS(i8),
@@ -17,6 +19,7 @@ pub enum ItemLayout {
 
 /// Align the item itself
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlignSelf {
     Auto,
     FlexStart,
@@ -102,6 +105,8 @@ pub struct Item {
 struct ItemProps {
     layouts_classes: String,
     class_name: String,
+    margin: Spaces,
+    padding: Spaces,
     styles: StyleSource<'static>,
 }
 
@@ -133,6 +138,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -176,7 +187,12 @@ impl Component for Item {
 
         html! {
             <div
-                class=classes!(format!("item item-{}", self.key), item_props.layouts_classes, item_props.class_name, item_props.styles)
+                class=ComponentClasses::new("item")
+                    .semantic(vec![format!("item-{}", self.key), item_props.layouts_classes])
+                    .spacing(item_props.margin, item_props.padding)
+                    .styles(item_props.styles)
+                    .class_name(item_props.class_name)
+                    .build()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
                 onclick=self.link.callback(Msg::Clicked)
@@ -192,6 +208,8 @@ impl From<Props> for ItemProps {
         ItemProps {
             layouts_classes: ItemModel.get_layout_classes(props.layouts),
             class_name: props.class_name,
+            margin: props.margin,
+            padding: props.padding,
             styles: props.styles,
         }
     }
@@ -240,19 +258,16 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_item() {
-    let props_item = Props {
-        layouts: vec![ItemLayout::ItXs(12)],
-        align_self: AlignSelf::Center,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "item-test".to_string(),
-        id: "item-id-test".to_string(),
-        onclick_signal: Callback::noop(),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let props_item = Props::builder()
+        .align_self(AlignSelf::Center)
+        .class_name("item-test".to_string())
+        .id("item-id-test".to_string())
+        .layouts(vec![ItemLayout::ItXs(12)])
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let item: App<Item> = App::new();
 
@@ -283,19 +298,17 @@ fn should_create_clickable_item() {
         body.append_child(&child_element).unwrap();
     });
 
-    let props_item = Props {
-        layouts: vec![ItemLayout::ItXs(12)],
-        align_self: AlignSelf::Center,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "item-test".to_string(),
-        id: "item-id-test".to_string(),
-        onclick_signal: on_add_item_div,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let props_item = Props::builder()
+        .align_self(AlignSelf::Center)
+        .class_name("item-test".to_string())
+        .id("item-id-test".to_string())
+        .layouts(vec![ItemLayout::ItXs(12)])
+        .onclick_signal(on_add_item_div)
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let mouse_event = MouseEvent::new("click").unwrap();
 
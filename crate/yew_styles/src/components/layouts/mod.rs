@@ -1,2 +1,6 @@
+pub mod column;
 pub mod container;
+pub mod hidden;
 pub mod item;
+pub mod row;
+pub mod visible;
@@ -0,0 +1,164 @@
+use crate::styles::{
+    tokens::{Spaces, Spacing},
+    ComponentClasses,
+};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Row component
+///
+/// Row of a 12 columns grid. The children should be `Column` components
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::layouts::{
+///     column::Column,
+///     container::{Container, Direction, Wrap},
+///     row::Row,
+/// };
+/// use yew_styles::styles::{breakpoints::Breakpoint, tokens::Spacing};
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Container direction=Direction::Column wrap=Wrap::Nowrap fixed=true>
+///                 <Row gutter=Spacing::Large>
+///                     <Column spans=vec![(Breakpoint::Xs, 12), (Breakpoint::M, 8)]>
+///                         <p>{"Content"}</p>
+///                     </Column>
+///                     <Column spans=vec![(Breakpoint::Xs, 12), (Breakpoint::M, 4)]>
+///                         <p>{"Sidebar"}</p>
+///                     </Column>
+///                 </Row>
+///             </Container>
+///         }
+///     }
+/// }
+/// ```
+pub struct Row {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Horizontal space between the columns. Default `Spacing::Medium`
+    #[prop_or(Spacing::Medium)]
+    pub gutter: Spacing,
+    /// Vertical space between the lines of columns when they wrap. Default `Spacing::None`
+    #[prop_or(Spacing::None)]
+    pub vertical_gutter: Spacing,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for Row {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("row")
+                    .semantic(vec![
+                        format!("gutter-{}", self.props.gutter.name()),
+                        format!("vertical-gutter-{}", self.props.vertical_gutter.name()),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_row_with_gutters() {
+    let props = Props::builder()
+        .gutter(Spacing::Large)
+        .id("row".to_string())
+        .vertical_gutter(Spacing::Small)
+        .children(Children::new(vec![html! {<div>{"Column"}</div>}]))
+        .build();
+
+    let row: App<Row> = App::new();
+
+    row.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let row_element = utils::document().get_element_by_id("row").unwrap();
+
+    assert!(row_element.class_list().contains("gutter-large"));
+    assert!(row_element.class_list().contains("vertical-gutter-small"));
+}
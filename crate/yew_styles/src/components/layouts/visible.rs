@@ -0,0 +1,143 @@
+use crate::styles::breakpoints::Breakpoint;
+use crate::styles::ComponentClasses;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Visible component
+///
+/// Shows its children only in the selected breakpoints without writing media queries
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::layouts::visible::Visible;
+/// use yew_styles::styles::breakpoints::Breakpoint;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Visible visible_on=vec![Breakpoint::Xs, Breakpoint::S]>
+///                 <span>{"Only in small screens"}</span>
+///             </Visible>
+///         }
+///     }
+/// }
+/// ```
+pub struct Visible {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Breakpoints where the children are shown. Required
+    pub visible_on: Vec<Breakpoint>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+impl Component for Visible {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("visible")
+                    .semantic(
+                        self.props
+                            .visible_on
+                            .iter()
+                            .map(|breakpoint| format!("visible-{}", breakpoint.name()))
+                            .collect::<Vec<String>>(),
+                    )
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_add_the_visible_breakpoint_classes() {
+    let props = Props::builder()
+        .id("visible".to_string())
+        .visible_on(vec![Breakpoint::Xs, Breakpoint::Xl])
+        .children(Children::new(vec![html! {"content"}]))
+        .build();
+
+    let visible: App<Visible> = App::new();
+
+    visible.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let visible_element = utils::document().get_element_by_id("visible").unwrap();
+
+    assert!(visible_element.class_list().contains("visible-xs"));
+    assert!(visible_element.class_list().contains("visible-xl"));
+    assert!(!visible_element.class_list().contains("visible-m"));
+}
@@ -0,0 +1,256 @@
+use super::list_item::ListItem;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+/// # List component
+///
+/// List of `ListItem` with a leading icon or avatar, a description and a trailing action in each item.
+/// With `selection` the items are selected by their `value`, clicking them or with `Enter` and `Space`,
+/// and `onselect_signal` emits the values of the selected items. The arrow keys, `Home` and `End`
+/// move the focus between the items
+///
+/// ## Features required
+///
+/// list
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::icon::{Icon, IconType};
+/// use yew_styles::list::{List, ListItem, Selection};
+///
+/// pub struct Inbox {
+///     link: ComponentLink<Self>,
+///     selected: Vec<String>,
+/// }
+///
+/// pub enum Msg {
+///     Select(Vec<String>),
+/// }
+///
+/// impl Component for Inbox {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             selected: vec![],
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Select(selected) => self.selected = selected,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <List
+///                 selection=Selection::Multiple
+///                 selected=self.selected.clone()
+///                 dividers=true
+///                 onselect_signal=self.link.callback(Msg::Select)
+///             >
+///                 <ListItem
+///                     value="welcome"
+///                     leading=html! {<Icon icon_type=IconType::Mail />}
+///                     description="The team"
+///                 >
+///                     {"Welcome to the workspace"}
+///                 </ListItem>
+///                 <ListItem
+///                     value="invoice"
+///                     leading=html! {<Icon icon_type=IconType::Mail />}
+///                     trailing=html! {<Icon icon_type=IconType::Star />}
+///                 >
+///                     {"Invoice of june"}
+///                 </ListItem>
+///             </List>
+///         }
+///     }
+/// }
+/// ```
+pub struct List {
+    link: ComponentLink<Self>,
+    props: Props,
+    selected: Vec<String>,
+}
+
+/// How many items of the list can be selected
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Selection {
+    None,
+    Single,
+    Multiple,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// How many items can be selected. Default `Selection::None`
+    #[prop_or(Selection::None)]
+    pub selection: Selection,
+    /// Values of the selected items. Default `vec![]`
+    #[prop_or_default]
+    pub selected: Vec<String>,
+    /// Lines between the items. Default `false`
+    #[prop_or(false)]
+    pub dividers: bool,
+    /// Smaller paddings and font. Default `false`
+    #[prop_or(false)]
+    pub dense: bool,
+    /// Emitted with the values of the selected items when the selection changes
+    #[prop_or(Callback::noop())]
+    pub onselect_signal: Callback<Vec<String>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: ChildrenWithProps<ListItem>,
+}
+
+pub enum Msg {
+    Select(String),
+}
+
+impl Component for List {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            selected: props.selected.clone(),
+            props,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Select(value) => {
+                let selected = get_selection(&self.selected, value, self.props.selection);
+
+                if selected == self.selected {
+                    return false;
+                }
+
+                self.selected = selected;
+                self.props.onselect_signal.emit(self.selected.clone());
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.selected != props.selected {
+                self.selected = props.selected.clone();
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let selectable = self.props.selection != Selection::None;
+
+        html! {
+            <ul
+                class=ComponentClasses::new("list")
+                    .state("dividers", self.props.dividers)
+                    .state("dense", self.props.dense)
+                    .state("selectable", selectable)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                role=if selectable { "listbox" } else { "list" }
+                aria-multiselectable=if self.props.selection == Selection::Multiple { Some("true") } else { None }
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {for self.props.children.iter().map(|mut item| {
+                    item.props.selectable = selectable;
+                    item.props.selected = self.selected.contains(&item.props.value);
+                    item.props.onselect_signal = self.link.callback(Msg::Select);
+                    item
+                })}
+            </ul>
+        }
+    }
+}
+
+/// Selected values after selecting `value`: it replaces the selection of a single selection list
+/// and it is added or removed in a multiple selection list
+pub fn get_selection(selected: &[String], value: String, selection: Selection) -> Vec<String> {
+    match selection {
+        Selection::None => selected.to_vec(),
+        Selection::Single => vec![value],
+        Selection::Multiple => {
+            if selected.contains(&value) {
+                selected
+                    .iter()
+                    .filter(|selected_value| **selected_value != value)
+                    .cloned()
+                    .collect()
+            } else {
+                let mut selected = selected.to_vec();
+                selected.push(value);
+                selected
+            }
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_update_the_selection() {
+    let selected = vec!["inbox".to_string()];
+
+    assert_eq!(
+        get_selection(&selected, "sent".to_string(), Selection::Single),
+        vec!["sent".to_string()]
+    );
+    assert_eq!(
+        get_selection(&selected, "sent".to_string(), Selection::Multiple),
+        vec!["inbox".to_string(), "sent".to_string()]
+    );
+    assert!(get_selection(&selected, "inbox".to_string(), Selection::Multiple).is_empty());
+    assert_eq!(
+        get_selection(&selected, "sent".to_string(), Selection::None),
+        selected
+    );
+}
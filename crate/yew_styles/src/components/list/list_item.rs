@@ -0,0 +1,264 @@
+use crate::keyboard::{handle_roving_keydown, init_roving_tabindex, Orientation};
+use crate::ripple::add_ripple;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # List Item component
+///
+/// Item of the `List`, the `List` sets if it is selectable and selected from its `value`
+///
+/// ## Features required
+///
+/// list
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::list::{List, ListItem};
+///
+/// pub struct Contacts;
+///
+/// impl Component for Contacts {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <List dense=true>
+///                 <ListItem
+///                     value="ada"
+///                     leading=html! {<img class="avatar" src="/ada.png" alt="" />}
+///                     description="ada@example.com"
+///                 >
+///                     {"Ada Lovelace"}
+///                 </ListItem>
+///             </List>
+///         }
+///     }
+/// }
+/// ```
+pub struct ListItem {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Value emitted by the `List` when the item is selected. Required
+    pub value: String,
+    /// Icon or avatar shown before the content. Default `None`
+    #[prop_or_default]
+    pub leading: Option<Html>,
+    /// Action shown after the content, its clicks don't select the item. Default `None`
+    #[prop_or_default]
+    pub trailing: Option<Html>,
+    /// Secondary text below the content. Default `None`
+    #[prop_or_default]
+    pub description: Option<String>,
+    /// The item can't be clicked or selected. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Click event for the item
+    #[prop_or(Callback::noop())]
+    pub onclick_signal: Callback<MouseEvent>,
+    /// Set by the `List` from its `selection`. Default `false`
+    #[prop_or(false)]
+    pub selectable: bool,
+    /// Set by the `List` from its selected values. Default `false`
+    #[prop_or(false)]
+    pub selected: bool,
+    /// Set by the `List` to select the item
+    #[prop_or(Callback::noop())]
+    pub onselect_signal: Callback<String>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Clicked(MouseEvent),
+    Pressed(KeyboardEvent),
+}
+
+impl Component for ListItem {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Clicked(mouse_event) => {
+                if self.props.disabled {
+                    return false;
+                }
+
+                self.props.onclick_signal.emit(mouse_event);
+
+                if self.props.selectable {
+                    self.props.onselect_signal.emit(self.props.value.clone());
+                }
+            }
+            Msg::Pressed(keyboard_event) => {
+                handle_roving_keydown(
+                    &keyboard_event,
+                    ".list",
+                    ".list-item",
+                    Orientation::Vertical,
+                );
+            }
+        };
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            if let Some(item) = self.props.code_ref.cast::<Element>() {
+                init_roving_tabindex(&item, ".list", ".list-item");
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let disabled = self.props.disabled;
+
+        html! {
+            <li
+                class=ComponentClasses::new("list-item")
+                    .state("active", self.props.selected)
+                    .state("disabled", disabled)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                role=if self.props.selectable { "option" } else { "listitem" }
+                aria-selected=if self.props.selectable { Some(self.props.selected.to_string()) } else { None }
+                aria-disabled=if disabled { Some("true") } else { None }
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onclick=self.link.callback(Msg::Clicked)
+                onkeydown=self.link.callback(Msg::Pressed)
+                onpointerdown=Callback::from(move |event: PointerEvent| {
+                    if !disabled {
+                        add_ripple(&event);
+                    }
+                })
+            >
+                {if let Some(leading) = self.props.leading.clone() {
+                    html! {
+                        <span class="list-item-leading">{leading}</span>
+                    }
+                } else {
+                    html! {}
+                }}
+                <span class="list-item-content">
+                    <span class="list-item-label">{self.props.children.clone()}</span>
+                    {if let Some(description) = self.props.description.clone() {
+                        html! {
+                            <span class="list-item-description">{description}</span>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </span>
+                {if let Some(trailing) = self.props.trailing.clone() {
+                    html! {
+                        <span
+                            class="list-item-trailing"
+                            onclick=Callback::from(|event: MouseEvent| event.stop_propagation())
+                            onkeydown=Callback::from(|event: KeyboardEvent| event.stop_propagation())
+                        >
+                            {trailing}
+                        </span>
+                    }
+                } else {
+                    html! {}
+                }}
+            </li>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_the_slots_of_the_item() {
+    let props = Props::builder()
+        .description(Some("ada@example.com".to_string()))
+        .id("list-item-test".to_string())
+        .leading(Some(html! {<span id="list-item-leading-test">{"A"}</span>}))
+        .selectable(true)
+        .selected(true)
+        .value("ada".to_string())
+        .children(Children::new(vec![html! {"Ada Lovelace"}]))
+        .build();
+
+    let list_item: App<ListItem> = App::new();
+    list_item.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let list_item_element = utils::document()
+        .get_element_by_id("list-item-test")
+        .unwrap();
+
+    assert!(list_item_element.class_list().contains("active"));
+    assert_eq!(
+        list_item_element.get_attribute("aria-selected"),
+        Some("true".to_string())
+    );
+    assert_eq!(
+        list_item_element.text_content().unwrap(),
+        "AAda Lovelaceada@example.com"
+    );
+}
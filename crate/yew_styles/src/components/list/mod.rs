@@ -0,0 +1,5 @@
+mod list_container;
+mod list_item;
+
+pub use list_container::{List, Selection};
+pub use list_item::ListItem;
@@ -0,0 +1,258 @@
+use crate::layers::{acquire_layer, Layer};
+use crate::messages::get_messages;
+use crate::spinner::{Spinner, SpinnerType};
+use crate::styles::{tokens::Spaces, ComponentClasses, Palette, Size};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement, Node};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Loading Overlay component
+///
+/// Covers its children while `loading` with a dimmed layer and a centered spinner, with an optional message
+/// under it. The children can't be clicked or reached with the keyboard meanwhile, the focus inside of them moves
+/// to the overlay, and with `blur` they are blurred too. With `full_page` the overlay covers the whole
+/// viewport over the rest of the overlays instead of only its children, e.g. while the page loads
+///
+/// ## Features required
+///
+/// loading_overlay
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::card::Card;
+/// use yew_styles::loading_overlay::LoadingOverlay;
+///
+/// pub struct Orders {
+///     loading: bool,
+/// }
+///
+/// impl Component for Orders {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self { loading: true }
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <LoadingOverlay loading=self.loading message="Loading the orders" blur=true>
+///                 <Card header=Some(html! {"Orders"}) body=Some(html! {"No orders yet"}) />
+///             </LoadingOverlay>
+///         }
+///     }
+/// }
+/// ```
+pub struct LoadingOverlay {
+    props: Props,
+    content_ref: NodeRef,
+    overlay_ref: NodeRef,
+    /// Layer of the full page overlay while it's shown
+    layer: Option<Layer>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Show the overlay over the children. Default `false`
+    #[prop_or(false)]
+    pub loading: bool,
+    /// Text shown under the spinner. Default `None`
+    #[prop_or_default]
+    pub message: Option<String>,
+    /// Blur the children under the overlay. Default `false`
+    #[prop_or(false)]
+    pub blur: bool,
+    /// Cover the whole viewport instead of the children. Default `false`
+    #[prop_or(false)]
+    pub full_page: bool,
+    /// Spinner type. Default `SpinnerType::Circle`
+    #[prop_or(SpinnerType::Circle)]
+    pub spinner_type: SpinnerType,
+    /// Spinner palette. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub spinner_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl Component for LoadingOverlay {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let mut loading_overlay = Self {
+            props,
+            content_ref: NodeRef::default(),
+            overlay_ref: NodeRef::default(),
+            layer: None,
+        };
+        loading_overlay.update_layer();
+
+        loading_overlay
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.update_layer();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if let Some(content) = self.content_ref.cast::<Element>() {
+            content
+                .toggle_attribute_with_force("inert", self.props.loading)
+                .ok();
+
+            // The focus can't stay in the blocked children
+            if self.props.loading && content.contains(get_active_element().as_ref()) {
+                if let Some(overlay) = self.overlay_ref.cast::<HtmlElement>() {
+                    overlay.focus().ok();
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let layer_style = self
+            .layer
+            .as_ref()
+            .map(|layer| layer.style())
+            .unwrap_or_default();
+
+        html! {
+            <div
+                class=ComponentClasses::new("loading-overlay")
+                    .state("loading", self.props.loading)
+                    .state("blur", self.props.blur)
+                    .state("full-page", self.props.full_page)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                aria-busy=self.props.loading.to_string()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <div class="loading-overlay-content" ref=self.content_ref.clone()>
+                    {self.props.children.clone()}
+                </div>
+                {if self.props.loading {
+                    html! {
+                        <div
+                            class="loading-overlay-layer"
+                            role="status"
+                            tabindex="-1"
+                            aria-label=self.props.message.clone().unwrap_or_else(|| get_messages().loading)
+                            style=layer_style
+                            ref=self.overlay_ref.clone()
+                        >
+                            <Spinner
+                                spinner_type=self.props.spinner_type.clone()
+                                spinner_palette=self.props.spinner_palette.clone()
+                                spinner_size=if self.props.full_page { Size::Big } else { Size::Medium }
+                            />
+                            {match &self.props.message {
+                                Some(message) => html! {
+                                    <span class="loading-overlay-message">{message}</span>
+                                },
+                                None => html! {},
+                            }}
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+impl LoadingOverlay {
+    /// The full page overlay stacks over the rest of the overlays while it's shown
+    fn update_layer(&mut self) {
+        if self.props.loading && self.props.full_page {
+            if self.layer.is_none() {
+                self.layer = Some(acquire_layer());
+            }
+        } else {
+            self.layer = None;
+        }
+    }
+}
+
+fn get_active_element() -> Option<Node> {
+    utils::document().active_element().map(Node::from)
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_block_the_children_while_loading() {
+    let props = Props::builder()
+        .id("loading-overlay-test".to_string())
+        .loading(true)
+        .message(Some("Loading the orders".to_string()))
+        .children(Children::new(vec![html! {<button>{"Orders"}</button>}]))
+        .build();
+
+    let loading_overlay: App<LoadingOverlay> = App::new();
+    loading_overlay.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let loading_overlay_element = utils::document()
+        .get_element_by_id("loading-overlay-test")
+        .unwrap();
+    let content = loading_overlay_element.first_element_child().unwrap();
+    let layer = loading_overlay_element.last_element_child().unwrap();
+
+    assert!(content.has_attribute("inert"));
+    assert_eq!(layer.get_attribute("role").unwrap(), "status");
+    assert_eq!(
+        layer.get_attribute("aria-label").unwrap(),
+        "Loading the orders"
+    );
+}
@@ -0,0 +1,173 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Memo component
+///
+/// Keeps its children rendered until the dependency changes,
+/// it avoids to re-render expensive static slots inside of components that update frequently
+///
+/// ## Features required
+///
+/// memo
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::memo::{memo_key, Memo};
+///
+/// pub struct MemoExample {
+///     link: ComponentLink<Self>,
+///     counter: u32,
+///     chart_points: Vec<(u32, u32)>,
+/// }
+///
+/// pub enum Msg {
+///     Increment,
+/// }
+///
+/// impl Component for MemoExample {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             counter: 0,
+///             chart_points: vec![(0, 10), (1, 20)],
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Increment => self.counter += 1,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <button onclick=self.link.callback(|_| Msg::Increment)>{self.counter}</button>
+///                 <Memo dependency=memo_key(&self.chart_points)>
+///                     <svg>
+///                         {self.chart_points.iter().map(|(x, y)| html! {
+///                             <circle cx=x.to_string() cy=y.to_string() r="2"/>
+///                         }).collect::<Html>()}
+///                     </svg>
+///                 </Memo>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Memo {
+    props: Props,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    /// Hash of the values used to build the children. Use `memo_key` to get it. Required
+    pub dependency: u64,
+    pub children: Children,
+}
+
+impl Component for Memo {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.dependency != props.dependency {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                {self.props.children.clone()}
+            </>
+        }
+    }
+}
+
+/// Get the dependency of the `Memo` component from any hashable value
+pub fn memo_key<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_same_key_for_equal_values() {
+    assert_eq!(memo_key(&vec![1, 2, 3]), memo_key(&vec![1, 2, 3]));
+    assert_ne!(memo_key("chart"), memo_key("table"));
+}
+
+#[wasm_bindgen_test]
+fn should_not_render_when_dependency_does_not_change() {
+    let mut memo = Memo {
+        props: Props::builder()
+            .dependency(memo_key(&1))
+            .children(Children::new(vec![html! {<div id="memo">{"memo"}</div>}]))
+            .build(),
+    };
+
+    let same_dependency = Props::builder()
+        .dependency(memo_key(&1))
+        .children(Children::new(vec![
+            html! {<div id="memo">{"changed"}</div>},
+        ]))
+        .build();
+
+    let new_dependency = Props::builder()
+        .dependency(memo_key(&2))
+        .children(Children::new(vec![
+            html! {<div id="memo">{"changed"}</div>},
+        ]))
+        .build();
+
+    assert!(!memo.change(same_dependency));
+    assert!(memo.change(new_dependency));
+}
+
+#[wasm_bindgen_test]
+fn should_create_memo() {
+    let props = Props::builder()
+        .dependency(memo_key(&"content"))
+        .children(Children::new(vec![
+            html! {<div id="result">{"result"}</div>},
+        ]))
+        .build();
+
+    let memo: App<Memo> = App::new();
+
+    memo.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let memo_element = utils::document().get_element_by_id("result").unwrap();
+
+    assert_eq!(memo_element.text_content().unwrap(), "result");
+}
@@ -0,0 +1,702 @@
+use crate::click_away::ClickAway;
+use crate::icon::{Icon, IconType};
+use crate::layers::{acquire_layer, Layer};
+use crate::styles::{
+    get_elevation,
+    tokens::{Elevation, Spaces},
+    ComponentClasses, Size,
+};
+use crate::utils::get_random_string;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Menu component
+///
+/// Button which opens a list of actions, for the toolbars and the "kebab" menus of the rows.
+/// Unlike `FormSelect` it doesn't keep a value: each `MenuItem` calls its own callback when it is chosen.
+/// The items can have an icon and a keyboard shortcut, be disabled, be split by a `MenuEntry::Separator`
+/// and open a nested submenu.
+///
+/// The arrows `Up` and `Down` move between the enabled items, `Right` opens the submenu of the item and
+/// `Left` closes it, `Enter` and `Space` choose the item and `Escape` closes the menu returning the focus
+/// to the trigger
+///
+/// ## Features required
+///
+/// menu
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::icon::{Icon, IconType};
+/// use yew_styles::menu::{Menu, MenuAlign, MenuEntry, MenuItem};
+///
+/// pub struct Row {
+///     link: ComponentLink<Self>,
+///     action: String,
+/// }
+///
+/// pub enum Msg {
+///     Action(String),
+/// }
+///
+/// impl Component for Row {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             action: String::from(""),
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Action(action) => self.action = action,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         let link = &self.link;
+///
+///         html! {
+///             <Menu
+///                 trigger=html! {<Icon icon_type=IconType::MoreHorizontal />}
+///                 trigger_label="Actions"
+///                 align=MenuAlign::End
+///                 items=vec![
+///                     MenuEntry::Item(
+///                         MenuItem::new("Edit")
+///                             .icon(html! {<Icon icon_type=IconType::Edit />})
+///                             .shortcut("Ctrl+E")
+///                             .onselect(link.callback(|_| Msg::Action(String::from("edit")))),
+///                     ),
+///                     MenuEntry::Item(MenuItem::new("Move to").submenu(vec![
+///                         MenuEntry::Item(
+///                             MenuItem::new("Archive")
+///                                 .onselect(link.callback(|_| Msg::Action(String::from("archive")))),
+///                         ),
+///                         MenuEntry::Item(MenuItem::new("Trash").disabled(true)),
+///                     ])),
+///                     MenuEntry::Separator,
+///                     MenuEntry::Item(
+///                         MenuItem::new("Delete")
+///                             .onselect(link.callback(|_| Msg::Action(String::from("delete")))),
+///                     ),
+///                 ]
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct Menu {
+    link: ComponentLink<Self>,
+    props: Props,
+    open: bool,
+    /// Index of the highlighted item in each open level, the last one has the focus
+    path: Vec<usize>,
+    /// The submenu of the last highlighted item is open too, after hovering it
+    expanded: bool,
+    layer: Option<Layer>,
+    menu_id: String,
+    trigger_ref: NodeRef,
+}
+
+/// Entry of a menu or a submenu
+#[derive(Clone, PartialEq, Debug)]
+pub enum MenuEntry {
+    Item(MenuItem),
+    /// Line between groups of items
+    Separator,
+}
+
+/// Action of the menu
+#[derive(Clone, PartialEq, Debug)]
+pub struct MenuItem {
+    pub label: String,
+    /// Icon shown before the label
+    pub icon: Option<Html>,
+    /// Keyboard shortcut shown after the label, only as a hint
+    pub shortcut: Option<String>,
+    pub disabled: bool,
+    /// Called when the item is chosen, the items with a submenu open it instead
+    pub onselect: Callback<()>,
+    /// Entries of the nested submenu
+    pub submenu: Vec<MenuEntry>,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            shortcut: None,
+            disabled: false,
+            onselect: Callback::noop(),
+            submenu: vec![],
+        }
+    }
+
+    /// Add an icon before the label
+    pub fn icon(mut self, icon: Html) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Show a keyboard shortcut after the label
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Callback called when the item is chosen
+    pub fn onselect(mut self, onselect: Callback<()>) -> Self {
+        self.onselect = onselect;
+        self
+    }
+
+    /// Open a nested submenu with these entries
+    pub fn submenu(mut self, submenu: Vec<MenuEntry>) -> Self {
+        self.submenu = submenu;
+        self
+    }
+}
+
+/// Side of the trigger where the menu is aligned
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MenuAlign {
+    Start,
+    End,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Content of the button which opens the menu. Required
+    pub trigger: Html,
+    /// Entries of the menu. Required
+    pub items: Vec<MenuEntry>,
+    /// Accessible name of the trigger, for the triggers with only an icon. Default `None`
+    #[prop_or_default]
+    pub trigger_label: Option<String>,
+    /// Side of the trigger where the menu is aligned. Default `MenuAlign::Start`
+    #[prop_or(MenuAlign::Start)]
+    pub align: MenuAlign,
+    /// Shadow level of the menu and the submenus. Default `Elevation::Level2`
+    #[prop_or(Elevation::Level2)]
+    pub elevation: Elevation,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Toggle,
+    Close,
+    TriggerPressed(KeyboardEvent),
+    MenuPressed(KeyboardEvent),
+    Hover(Vec<usize>),
+    Choose(Vec<usize>),
+}
+
+impl Component for Menu {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            open: false,
+            path: vec![],
+            expanded: false,
+            layer: None,
+            menu_id: format!("menu-{}", get_random_string(10)),
+            trigger_ref: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                if self.open {
+                    self.close(false);
+                } else {
+                    self.show(true);
+                }
+            }
+            Msg::Close => {
+                if !self.open {
+                    return false;
+                }
+                self.close(false);
+            }
+            Msg::TriggerPressed(keyboard_event) => {
+                let first = match keyboard_event.key().as_str() {
+                    "ArrowDown" => true,
+                    "ArrowUp" => false,
+                    _ => return false,
+                };
+
+                keyboard_event.prevent_default();
+                self.show(first);
+            }
+            Msg::MenuPressed(keyboard_event) => {
+                let key = keyboard_event.key();
+
+                if key == "Tab" {
+                    self.close(false);
+                    return true;
+                }
+
+                if !self.handle_key(&key) {
+                    return false;
+                }
+
+                keyboard_event.prevent_default();
+                keyboard_event.stop_propagation();
+            }
+            Msg::Hover(path) => {
+                let item = match get_item(&self.props.items, &path) {
+                    Some(item) if !item.disabled => item,
+                    _ => return false,
+                };
+
+                self.expanded = !item.submenu.is_empty();
+                self.path = path;
+            }
+            Msg::Choose(path) => self.choose(path),
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.items != props.items {
+                self.path.clear();
+                self.expanded = false;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if !self.open || self.path.is_empty() {
+            return;
+        }
+
+        if let Some(item) = utils::document()
+            .get_element_by_id(&get_item_id(&self.menu_id, &self.path))
+            .and_then(|item| item.dyn_into::<HtmlElement>().ok())
+        {
+            item.focus().ok();
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ClickAway active=self.open onclickaway_signal=self.link.callback(|_| Msg::Close)>
+                <div
+                    class=ComponentClasses::new("menu")
+                        .state("open", self.open)
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
+                    id=self.props.id.clone()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                >
+                    <button
+                        type="button"
+                        class="menu-trigger"
+                        aria-haspopup="menu"
+                        aria-expanded=self.open.to_string()
+                        aria-controls=self.menu_id.clone()
+                        aria-label=self.props.trigger_label.clone()
+                        ref=self.trigger_ref.clone()
+                        onclick=self.link.callback(|_| Msg::Toggle)
+                        onkeydown=self.link.callback(Msg::TriggerPressed)
+                    >
+                        {self.props.trigger.clone()}
+                    </button>
+                    {if self.open {
+                        html! {
+                            <div
+                                class=classes!(
+                                    "menu-panel",
+                                    get_align(self.props.align),
+                                    get_elevation(self.props.elevation)
+                                )
+                                style=self.layer.as_ref().map(|layer| layer.style())
+                                onkeydown=self.link.callback(Msg::MenuPressed)
+                            >
+                                {self.get_entries(&self.props.items, vec![])}
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </ClickAway>
+        }
+    }
+}
+
+impl Menu {
+    fn show(&mut self, first: bool) {
+        self.open = true;
+        self.expanded = false;
+        self.path = get_next_enabled(&self.props.items, None, first)
+            .into_iter()
+            .collect();
+
+        if self.layer.is_none() {
+            self.layer = Some(acquire_layer());
+        }
+    }
+
+    fn close(&mut self, focus_trigger: bool) {
+        self.open = false;
+        self.expanded = false;
+        self.path.clear();
+        self.layer = None;
+
+        if focus_trigger {
+            if let Some(trigger) = self.trigger_ref.cast::<HtmlElement>() {
+                trigger.focus().ok();
+            }
+        }
+    }
+
+    /// Returns `true` if the key was handled
+    fn handle_key(&mut self, key: &str) -> bool {
+        let level = match self.path.len() {
+            0 => {
+                if key == "Escape" {
+                    self.close(true);
+                    return true;
+                }
+                return false;
+            }
+            len => len - 1,
+        };
+        let entries = get_level_entries(&self.props.items, &self.path[..level]);
+        let current = self.path[level];
+
+        match key {
+            "ArrowDown" | "ArrowUp" | "Home" | "End" => {
+                let next = match key {
+                    "ArrowDown" => get_next_enabled(entries, Some(current), true),
+                    "ArrowUp" => get_next_enabled(entries, Some(current), false),
+                    "Home" => get_next_enabled(entries, None, true),
+                    _ => get_next_enabled(entries, None, false),
+                };
+
+                if let Some(next) = next {
+                    self.path[level] = next;
+                }
+                self.expanded = false;
+            }
+            "ArrowRight" => self.open_submenu(),
+            "ArrowLeft" => {
+                if level > 0 {
+                    self.path.pop();
+                }
+                self.expanded = false;
+            }
+            "Escape" => {
+                if level > 0 {
+                    self.path.pop();
+                    self.expanded = false;
+                } else {
+                    self.close(true);
+                }
+            }
+            "Enter" | " " => self.choose(self.path.clone()),
+            _ => return false,
+        };
+
+        true
+    }
+
+    fn open_submenu(&mut self) {
+        let first = get_item(&self.props.items, &self.path)
+            .and_then(|item| get_next_enabled(&item.submenu, None, true));
+
+        if let Some(first) = first {
+            self.path.push(first);
+        }
+        self.expanded = false;
+    }
+
+    fn choose(&mut self, path: Vec<usize>) {
+        let item = match get_item(&self.props.items, &path) {
+            Some(item) if !item.disabled => item.clone(),
+            _ => return,
+        };
+
+        self.path = path;
+
+        if item.submenu.is_empty() {
+            self.close(true);
+            item.onselect.emit(());
+        } else {
+            self.open_submenu();
+        }
+    }
+
+    fn get_entries(&self, entries: &[MenuEntry], parent: Vec<usize>) -> Html {
+        let level = parent.len();
+        let list_id = if parent.is_empty() {
+            self.menu_id.clone()
+        } else {
+            format!("{}-submenu", get_item_id(&self.menu_id, &parent))
+        };
+
+        html! {
+            <ul class="menu-list" role="menu" aria-orientation="vertical" id=list_id>
+                {for entries.iter().enumerate().map(|(index, entry)| {
+                    let item = match entry {
+                        MenuEntry::Item(item) => item,
+                        MenuEntry::Separator => {
+                            return html! {
+                                <li class="menu-separator" role="separator"></li>
+                            };
+                        }
+                    };
+                    let mut path = parent.clone();
+                    path.push(index);
+
+                    let highlighted = self.path.get(level) == Some(&index);
+                    let has_submenu = !item.submenu.is_empty();
+                    let submenu_open = has_submenu
+                        && highlighted
+                        && (self.path.len() > level + 1 || (self.path.len() == level + 1 && self.expanded));
+                    let item_id = get_item_id(&self.menu_id, &path);
+                    let hover_path = path.clone();
+
+                    html! {
+                        <li class="menu-entry" role="none">
+                            <div
+                                class=ComponentClasses::new("menu-item")
+                                    .state("active", highlighted)
+                                    .state("disabled", item.disabled)
+                                    .build()
+                                role="menuitem"
+                                tabindex="-1"
+                                id=item_id.clone()
+                                aria-disabled=if item.disabled { Some("true") } else { None }
+                                aria-haspopup=if has_submenu { Some("menu") } else { None }
+                                aria-expanded=if has_submenu { Some(submenu_open.to_string()) } else { None }
+                                aria-controls=if has_submenu { Some(format!("{}-submenu", item_id)) } else { None }
+                                onmouseenter=self.link.callback(move |_| Msg::Hover(hover_path.clone()))
+                                onclick=self.link.callback(move |_| Msg::Choose(path.clone()))
+                            >
+                                <span class="menu-item-icon" aria-hidden="true">
+                                    {item.icon.clone().unwrap_or_else(|| html! {})}
+                                </span>
+                                <span class="menu-item-label">{&item.label}</span>
+                                {if let Some(shortcut) = item.shortcut.clone() {
+                                    html! {
+                                        <kbd class="menu-item-shortcut">{shortcut}</kbd>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
+                                {if has_submenu {
+                                    html! {
+                                        <span class="menu-item-arrow" aria-hidden="true">
+                                            <Icon icon_type=IconType::ChevronRight icon_size=Size::Small />
+                                        </span>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
+                            </div>
+                            {if submenu_open {
+                                let mut submenu_parent = parent.clone();
+                                submenu_parent.push(index);
+
+                                html! {
+                                    <div class=classes!("menu-panel", "menu-submenu", get_elevation(self.props.elevation))>
+                                        {self.get_entries(&item.submenu, submenu_parent)}
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </li>
+                    }
+                })}
+            </ul>
+        }
+    }
+}
+
+fn get_align(align: MenuAlign) -> String {
+    match align {
+        MenuAlign::Start => String::from("align-start"),
+        MenuAlign::End => String::from("align-end"),
+    }
+}
+
+fn get_item_id(menu_id: &str, path: &[usize]) -> String {
+    let indexes: Vec<String> = path.iter().map(|index| index.to_string()).collect();
+
+    format!("{}-item-{}", menu_id, indexes.join("-"))
+}
+
+/// Entries of the submenu opened by the items of the `parent` path, the whole menu for an empty path
+fn get_level_entries<'a>(items: &'a [MenuEntry], parent: &[usize]) -> &'a [MenuEntry] {
+    parent
+        .iter()
+        .fold(items, |entries, index| match entries.get(*index) {
+            Some(MenuEntry::Item(item)) => &item.submenu,
+            _ => &[],
+        })
+}
+
+fn get_item<'a>(items: &'a [MenuEntry], path: &[usize]) -> Option<&'a MenuItem> {
+    let (index, parent) = path.split_last()?;
+
+    match get_level_entries(items, parent).get(*index) {
+        Some(MenuEntry::Item(item)) => Some(item),
+        _ => None,
+    }
+}
+
+/// Index of the next enabled item after `current`, skipping the separators and the disabled items and
+/// wrapping around. Without `current` it is the first enabled item, or the last one going backward
+pub fn get_next_enabled(
+    entries: &[MenuEntry],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = entries.len();
+
+    (1..=len)
+        .map(|step| match (current, forward) {
+            (Some(current), true) => (current + step) % len,
+            (Some(current), false) => (current + len - step) % len,
+            (None, true) => step - 1,
+            (None, false) => len - step,
+        })
+        .find(|index| matches!(&entries[*index], MenuEntry::Item(item) if !item.disabled))
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_skip_separators_and_disabled_items() {
+    let entries = vec![
+        MenuEntry::Item(MenuItem::new("Edit")),
+        MenuEntry::Separator,
+        MenuEntry::Item(MenuItem::new("Archive").disabled(true)),
+        MenuEntry::Item(MenuItem::new("Delete")),
+    ];
+
+    assert_eq!(get_next_enabled(&entries, None, true), Some(0));
+    assert_eq!(get_next_enabled(&entries, None, false), Some(3));
+    assert_eq!(get_next_enabled(&entries, Some(0), true), Some(3));
+    assert_eq!(get_next_enabled(&entries, Some(3), true), Some(0));
+    assert_eq!(get_next_enabled(&entries, Some(0), false), Some(3));
+    assert_eq!(get_next_enabled(&[MenuEntry::Separator], None, true), None);
+}
+
+#[wasm_bindgen_test]
+fn should_find_the_items_of_the_submenus() {
+    let items = vec![
+        MenuEntry::Item(MenuItem::new("Edit")),
+        MenuEntry::Item(MenuItem::new("Move to").submenu(vec![
+            MenuEntry::Item(MenuItem::new("Archive")),
+            MenuEntry::Item(MenuItem::new("Trash")),
+        ])),
+    ];
+
+    assert_eq!(get_level_entries(&items, &[]).len(), 2);
+    assert_eq!(get_level_entries(&items, &[1]).len(), 2);
+    assert!(get_level_entries(&items, &[0]).is_empty());
+    assert_eq!(
+        get_item(&items, &[1, 1]).map(|item| item.label.clone()),
+        Some("Trash".to_string())
+    );
+    assert_eq!(get_item(&items, &[2]), None);
+}
+
+#[wasm_bindgen_test]
+fn should_render_a_closed_menu() {
+    let props = Props::builder()
+        .align(MenuAlign::End)
+        .id("menu-test".to_string())
+        .items(vec![MenuEntry::Item(MenuItem::new("Edit"))])
+        .trigger(html! {"Actions"})
+        .trigger_label(Some("Row actions".to_string()))
+        .build();
+
+    let menu: App<Menu> = App::new();
+    menu.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let menu_element = utils::document().get_element_by_id("menu-test").unwrap();
+    let trigger = menu_element
+        .get_elements_by_class_name("menu-trigger")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(
+        trigger.get_attribute("aria-expanded"),
+        Some("false".to_string())
+    );
+    assert_eq!(
+        trigger.get_attribute("aria-label"),
+        Some("Row actions".to_string())
+    );
+    assert_eq!(
+        menu_element
+            .get_elements_by_class_name("menu-panel")
+            .length(),
+        0
+    );
+}
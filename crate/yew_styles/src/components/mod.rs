@@ -1,23 +1,119 @@
 extern crate getrandom;
+#[cfg(feature = "affix")]
+pub mod affix;
+#[cfg(feature = "announcer")]
+pub mod announcer;
+#[cfg(feature = "availability_grid")]
+pub mod availability_grid;
+#[cfg(feature = "back_to_top")]
+pub mod back_to_top;
 #[cfg(feature = "button")]
 pub mod button;
+#[cfg(feature = "calendar")]
+pub mod calendar;
 #[cfg(feature = "card")]
 pub mod card;
 #[cfg(feature = "carousel")]
 pub mod carousel;
+#[cfg(feature = "changelog")]
+pub mod changelog;
+#[cfg(feature = "click_away")]
+pub mod click_away;
+#[cfg(feature = "compare_slider")]
+pub mod compare_slider;
+#[cfg(feature = "confetti")]
+pub mod confetti;
+#[cfg(feature = "copy_button")]
+pub mod copy_button;
+#[cfg(feature = "count_up")]
+pub mod count_up;
+#[cfg(feature = "document_viewer")]
+pub mod document_viewer;
 #[cfg(feature = "dropdown")]
 pub mod dropdown;
-#[cfg(feature = "forms")]
+#[cfg(feature = "embed")]
+pub mod embed;
+#[cfg(feature = "empty_state")]
+pub mod empty_state;
+#[cfg(feature = "filterable_list")]
+pub mod filterable_list;
+#[cfg(feature = "focus_trap")]
+pub mod focus_trap;
+#[cfg(any(
+    feature = "form_component",
+    feature = "form_group",
+    feature = "form_label",
+    feature = "form_input",
+    feature = "form_textarea",
+    feature = "form_select",
+    feature = "form_file",
+    feature = "form_submit",
+    feature = "form_range_slider",
+    feature = "form_builder",
+    feature = "form_error_summary"
+))]
 pub mod forms;
+#[cfg(feature = "gallery")]
+pub mod gallery;
+#[cfg(feature = "highlight")]
+pub mod highlight;
+#[cfg(feature = "icon")]
+pub mod icon;
+#[cfg(feature = "image_zoom")]
+pub mod image_zoom;
+#[cfg(feature = "infinite_scroll")]
+pub mod infinite_scroll;
 #[cfg(feature = "layouts")]
 pub mod layouts;
+#[cfg(feature = "list")]
+pub mod list;
+#[cfg(feature = "loading_overlay")]
+pub mod loading_overlay;
+#[cfg(feature = "memo")]
+pub mod memo;
+#[cfg(feature = "menu")]
+pub mod menu;
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "navbar")]
 pub mod navbar;
+#[cfg(feature = "pdf_viewer")]
+pub mod pdf_viewer;
+#[cfg(feature = "portal")]
+pub mod portal;
+#[cfg(feature = "scrollspy")]
+pub mod scrollspy;
+#[cfg(feature = "seat_map")]
+pub mod seat_map;
+#[cfg(feature = "secret_text")]
+pub mod secret_text;
+#[cfg(feature = "sidenav")]
+pub mod sidenav;
+#[cfg(feature = "sortable_list")]
+pub mod sortable_list;
 #[cfg(feature = "spinner")]
 pub mod spinner;
+#[cfg(feature = "split_pane")]
+pub mod split_pane;
+#[cfg(feature = "stat")]
+pub mod stat;
+#[cfg(feature = "status_page")]
+pub mod status_page;
+#[cfg(feature = "sticky")]
+pub mod sticky;
+#[cfg(feature = "surface")]
+pub mod surface;
 #[cfg(feature = "text")]
 pub mod text;
+#[cfg(feature = "toast")]
+pub mod toast;
 #[cfg(feature = "tooltip")]
 pub mod tooltip;
+#[cfg(feature = "transition_group")]
+pub mod transition_group;
+#[cfg(feature = "truncate_middle")]
+pub mod truncate_middle;
+#[cfg(feature = "typography")]
+pub mod typography;
+#[cfg(feature = "video_thumb")]
+pub mod video_thumb;
@@ -1,5 +1,13 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
-use crate::utils::get_html_element_by_class;
+use crate::focus_trap::FocusTrap;
+use crate::hotkeys::{register_hotkey, Hotkey, HotkeyHandle, HotkeyScope};
+use crate::layers::{acquire_layer, Layer};
+use crate::portal::Portal;
+use crate::styles::{
+    get_elevation, get_palette, get_size, get_style,
+    tokens::{Elevation, Spaces},
+    ComponentClasses, Palette, Size, Style,
+};
+use crate::utils::{get_html_element_by_class, get_random_string};
 use stylist::{css, StyleSource};
 use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
@@ -9,6 +17,10 @@ use yew::{utils, App};
 
 /// # Modal component
 ///
+/// While it is open, the focus is kept inside of the modal and goes back to the element
+/// which opened it when it is closed. The modal is rendered at the end of the body with a `Portal`.
+/// `Escape` is handled by `hotkeys`: only the modal on top of the other overlays emits `onescape_signal`
+///
 /// ## Features required
 ///
 /// modal
@@ -33,7 +45,6 @@ use yew::{utils, App};
 /// pub enum Msg {
 ///     CloseModal,
 ///     OpenModal,
-///     CloseModalByKb(KeyboardEvent),
 /// }
 ///
 /// impl Component for ModalExample {
@@ -60,12 +71,6 @@ use yew::{utils, App};
 ///                 body_style.set_property("overflow", "auto").unwrap();
 ///                 self.show_modal = false;
 ///             }
-///             Msg::CloseModalByKb(keyboard_event) => {
-///                 if keyboard_event.key_code() == 27 {
-///                     body_style.set_property("overflow", "auto").unwrap();
-///                     self.show_modal = false;
-///                 }
-///             }
 ///             Msg::OpenModal => {
 ///                 body_style.set_property("overflow", "hidden").unwrap();
 ///
@@ -100,7 +105,7 @@ use yew::{utils, App};
 ///                     body_palette=Palette::Link
 ///                     is_open=self.show_modal
 ///                     onclick_signal= self.link.callback(|_| Msg::CloseModal)
-///                     onkeydown_signal= self.link.callback(Msg::CloseModalByKb)
+///                     onescape_signal= self.link.callback(|_| Msg::CloseModal)
 ///                 />
 ///                 <Button
 ///                     button_palette= Palette::Primary
@@ -114,6 +119,9 @@ use yew::{utils, App};
 pub struct Modal {
     link: ComponentLink<Self>,
     props: Props,
+    header_id: String,
+    layer: Option<Layer>,
+    escape: Option<HotkeyHandle>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -130,12 +138,18 @@ pub struct Props {
     /// keyboard event for modal (usually to close the modal)
     #[prop_or(Callback::noop())]
     pub onkeydown_signal: Callback<KeyboardEvent>,
+    /// Escape is pressed while the modal is on top of the other overlays (usually to close the modal)
+    #[prop_or(Callback::noop())]
+    pub onescape_signal: Callback<KeyboardEvent>,
     /// Type modal background style. Default `Palette::Standard`
     #[prop_or(Palette::Standard)]
     pub modal_palette: Palette,
     /// Three diffent modal standard sizes. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub modal_size: Size,
+    /// Shadow level of the modal content. Default `Elevation::Level5`
+    #[prop_or(Elevation::Level5)]
+    pub elevation: Elevation,
     /// Type modal header style. Default `Palette::Standard`
     #[prop_or(Palette::Standard)]
     pub header_palette: Palette,
@@ -157,6 +171,9 @@ pub struct Props {
     /// If the modal content get the focus. Set to false if the modal includes input events. Default `true`
     #[prop_or(true)]
     pub auto_focus: bool,
+    /// Keep the focus inside of the modal while it is open and restore it when it is closed. Default `true`
+    #[prop_or(true)]
+    pub trap_focus: bool,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -172,11 +189,19 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 pub enum Msg {
     Clicked(MouseEvent),
     Pressed(KeyboardEvent),
+    Escaped(KeyboardEvent),
+    OpenLayer,
 }
 
 impl Component for Modal {
@@ -184,7 +209,13 @@ impl Component for Modal {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            props,
+            header_id: format!("modal-header-{}", get_random_string(10)),
+            layer: None,
+            escape: None,
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -204,12 +235,30 @@ impl Component for Modal {
             Msg::Pressed(keyboard_event) => {
                 self.props.onkeydown_signal.emit(keyboard_event);
             }
+            Msg::Escaped(keyboard_event) => {
+                self.props.onescape_signal.emit(keyboard_event);
+                return false;
+            }
+            Msg::OpenLayer => {
+                if !self.props.is_open || self.layer.is_some() {
+                    return false;
+                }
+
+                self.open_layer();
+            }
         };
         true
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            if !props.is_open {
+                self.escape = None;
+                self.layer = None;
+            } else if self.layer.is_none() {
+                self.open_layer();
+            }
+
             self.props = props;
             true
         } else {
@@ -217,7 +266,11 @@ impl Component for Modal {
         }
     }
 
-    fn rendered(&mut self, _first_render: bool) {
+    fn rendered(&mut self, first_render: bool) {
+        if first_render && self.props.is_open {
+            self.link.send_message(Msg::OpenLayer);
+        }
+
         if self.props.is_open && self.props.auto_focus {
             let modal_form = get_html_element_by_class("modal", 0);
 
@@ -226,41 +279,79 @@ impl Component for Modal {
     }
 
     fn view(&self) -> Html {
-        get_modal(self.props.clone(), self.link.clone())
+        get_modal(
+            self.props.clone(),
+            self.link.clone(),
+            self.header_id.clone(),
+            self.layer.as_ref().map(|layer| layer.style()),
+        )
+    }
+}
+
+impl Modal {
+    /// Stacks the modal over the open overlays, `Escape` is dispatched to it while it is on top
+    fn open_layer(&mut self) {
+        let layer = acquire_layer();
+
+        self.escape = Some(register_hotkey(
+            Hotkey::new("Escape"),
+            HotkeyScope::Layer(layer.id()),
+            self.link.callback(Msg::Escaped),
+        ));
+        self.layer = Some(layer);
     }
 }
 
-fn get_modal(props: Props, link: ComponentLink<Modal>) -> Html {
+fn get_modal(
+    props: Props,
+    link: ComponentLink<Modal>,
+    header_id: String,
+    layer_style: Option<String>,
+) -> Html {
     if props.is_open {
         html! {
-            <div
-                class=classes!("modal", "container", get_palette(props.modal_palette), props.class_name, props.styles)
-                key=props.key
-                ref=props.code_ref
-                tabindex="0"
-                id=props.id
-                onclick=link.callback(Msg::Clicked)
-                onkeydown=link.callback(Msg::Pressed)
-            >
-                <div class=format!("modal-content {}", get_size(props.modal_size))>
-                    <div class=format!(
-                        "modal-header {} {} {}",
-                        get_style(props.header_style),
-                        get_palette(props.header_palette),
-                        if props.header_interaction { "interaction" } else { "" }
-                    )>
-                        {props.header}
-                    </div>
-                    <div class=format!(
-                        "modal-body {} {} {}",
-                        get_style(props.body_style),
-                        get_palette(props.body_palette),
-                        if props.body_interaction { "interaction" } else { "" }
-                    )>
-                        {props.body}
+            <Portal>
+                <FocusTrap active=props.trap_focus>
+                    <div
+                        class=ComponentClasses::new("modal")
+                            .semantic("container")
+                            .semantic(get_palette(props.modal_palette))
+                            .spacing(props.margin, props.padding)
+                            .styles(props.styles)
+                            .class_name(props.class_name)
+                            .build()
+                        key=props.key
+                        ref=props.code_ref
+                        tabindex="0"
+                        role="dialog"
+                        aria-modal="true"
+                        aria-labelledby=header_id.clone()
+                        id=props.id
+                        style=layer_style
+                        onclick=link.callback(Msg::Clicked)
+                        onkeydown=link.callback(Msg::Pressed)
+                    >
+                        <div class=classes!("modal-content", get_size(props.modal_size), get_elevation(props.elevation))>
+                            <div id=header_id class=format!(
+                                "modal-header {} {} {}",
+                                get_style(props.header_style),
+                                get_palette(props.header_palette),
+                                if props.header_interaction { "interaction" } else { "" }
+                            )>
+                                {props.header}
+                            </div>
+                            <div class=format!(
+                                "modal-body {} {} {}",
+                                get_style(props.body_style),
+                                get_palette(props.body_palette),
+                                if props.body_interaction { "interaction" } else { "" }
+                            )>
+                                {props.body}
+                            </div>
+                        </div>
                     </div>
-                </div>
-            </div>
+                </FocusTrap>
+            </Portal>
         }
     } else {
         html! {}
@@ -271,31 +362,19 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_modal_component() {
-    let props = Props {
-        class_name: "test-modal".to_string(),
-        id: "modal-id-test".to_string(),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        onclick_signal: Callback::noop(),
-        onkeydown_signal: Callback::noop(),
-        modal_palette: Palette::Standard,
-        modal_size: Size::Medium,
-        header: html! {<div id="header">{"Modal Test"}</div>},
-        header_style: Style::Regular,
-        header_palette: Palette::Standard,
-        header_interaction: false,
-        body: html! {<div id="body">{"Content Test"}</div>},
-        body_style: Style::Regular,
-        body_palette: Palette::Standard,
-        body_interaction: false,
-        is_open: true,
-        auto_focus: false,
-        styles: css!(
+    let props = Props::builder()
+        .auto_focus(false)
+        .body(html! {<div id="body">{"Content Test"}</div>})
+        .class_name("test-modal".to_string())
+        .header(html! {<div id="header">{"Modal Test"}</div>})
+        .id("modal-id-test".to_string())
+        .is_open(true)
+        .styles(css!(
             "modal-content {
                 color: #000;
             }"
-        ),
-    };
+        ))
+        .build();
 
     let modal: App<Modal> = App::new();
 
@@ -314,31 +393,19 @@ fn should_create_modal_component() {
 
 #[wasm_bindgen_test]
 fn should_hide_modal_component_from_doom() {
-    let props = Props {
-        class_name: "test-modal".to_string(),
-        id: "modal-id-test".to_string(),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        onclick_signal: Callback::noop(),
-        onkeydown_signal: Callback::noop(),
-        modal_palette: Palette::Standard,
-        modal_size: Size::Medium,
-        header: html! {<div id="header">{"Modal Test"}</div>},
-        header_style: Style::Regular,
-        header_palette: Palette::Standard,
-        header_interaction: false,
-        body: html! {<div id="body">{"Content Test"}</div>},
-        body_style: Style::Regular,
-        body_palette: Palette::Standard,
-        body_interaction: false,
-        is_open: false,
-        auto_focus: false,
-        styles: css!(
+    let props = Props::builder()
+        .auto_focus(false)
+        .body(html! {<div id="body">{"Content Test"}</div>})
+        .class_name("test-modal".to_string())
+        .header(html! {<div id="header">{"Modal Test"}</div>})
+        .id("modal-hidden-test".to_string())
+        .is_open(false)
+        .styles(css!(
             "modal-content {
                 color: #000;
             }"
-        ),
-    };
+        ))
+        .build();
 
     let modal: App<Modal> = App::new();
 
@@ -347,7 +414,7 @@ fn should_hide_modal_component_from_doom() {
         props,
     );
 
-    let modal_element = utils::document().get_element_by_id("modal-id-test");
+    let modal_element = utils::document().get_element_by_id("modal-hidden-test");
 
     assert_eq!(modal_element, None);
 }
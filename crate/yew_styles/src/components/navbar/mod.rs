@@ -2,3 +2,4 @@ pub mod navbar_component;
 pub mod navbar_container;
 pub mod navbar_dropdown;
 pub mod navbar_item;
+pub mod navbar_route;
@@ -1,7 +1,7 @@
 use super::navbar_container::NavbarContainer;
 use super::navbar_item::NavbarItem;
 use crate::layouts::container::{Direction, JustifyContent, Mode};
-use crate::styles::{get_palette, get_style, Palette, Style};
+use crate::styles::{get_palette, get_style, tokens::Spaces, ComponentClasses, Palette, Style};
 use crate::utils::create_style;
 use stylist::{css, StyleSource};
 use yew::prelude::*;
@@ -165,6 +165,12 @@ pub struct Props {
     pub branch: Html,
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -180,6 +186,8 @@ pub struct NavbarProps {
     pub fixed: Fixed,
     pub branch: Html,
     pub styles: StyleSource<'static>,
+    pub margin: Spaces,
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -197,12 +205,15 @@ impl From<Props> for NavbarProps {
             branch: props.branch,
             children: props.children,
             styles: props.styles,
+            margin: props.margin,
+            padding: props.padding,
         }
     }
 }
 
 /// the location of the navbar which is fixed
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Fixed {
     None,
     Top,
@@ -258,7 +269,13 @@ impl Component for Navbar {
         html! {
             <>
                 <div
-                    class=classes!("navbar-mobile", self.props.navbar_style.clone(), self.props.navbar_palette.clone(), self.props.class_name.clone(), self.props.styles.clone())
+                    class=ComponentClasses::new("navbar-mobile")
+                        .semantic(self.props.navbar_style.clone())
+                        .semantic(self.props.navbar_palette.clone())
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
                     id=self.props.id.clone()
                     key=self.props.key.clone()
                     ref=self.props.code_ref.clone()
@@ -292,7 +309,12 @@ impl Component for Navbar {
                 </div>
 
                 <div
-                    class=format!("navbar {} {} {}", self.props.navbar_style, self.props.navbar_palette, self.props.class_name)
+                    class=ComponentClasses::new("navbar")
+                        .semantic(self.props.navbar_style.clone())
+                        .semantic(self.props.navbar_palette.clone())
+                        .spacing(self.props.margin, self.props.padding)
+                        .class_name(self.props.class_name.clone())
+                        .build()
                 >
                 <NavbarContainer justify_content=JustifyContent::Start(Mode::NoMode)
                     direction=Direction::Row
@@ -1,11 +1,34 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use crate::utils::{get_html_element_by_class, get_random_string};
+use std::time::Duration;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
 use yew::{utils, App};
 
 /// # Navbar Dropdown Container component
 ///
+/// With `mega_menu`, the items are shown in a full width panel which can contain any html,
+/// like columns of links or promo cards. The panel is opened and closed on hover after `hover_delay`
+/// and it can be opened with Enter, Space or ArrowDown and closed with Escape
+///
+/// ```rust
+/// html! {
+///     <NavbarDropdown mega_menu=true main_content=html!{<span>{"Products"}</span>}>
+///         <div class="column">
+///             <b>{"Components"}</b>
+///             <a href="/button">{"Button"}</a>
+///             <a href="/card">{"Card"}</a>
+///         </div>
+///         <div class="column promo">
+///             <img src="/assets/release.png"/>
+///             <span>{"New release 0.11"}</span>
+///         </div>
+///     </NavbarDropdown>
+/// }
+/// ```
+///
 /// ## Features required
 ///
 /// navbar
@@ -112,6 +135,7 @@ pub struct NavbarDropdown {
     show: bool,
     key: String,
     link: ComponentLink<Self>,
+    hover_task: Option<TimeoutTask>,
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -130,15 +154,31 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// Show the items in a full width panel that can contain any html. Default `false`
+    #[prop_or(false)]
+    pub mega_menu: bool,
+    /// Milliseconds the pointer has to stay over or out of the dropdown to open or close it.
+    /// Only in mega menu mode. Default `150`
+    #[prop_or(150)]
+    pub hover_delay: u64,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
 pub enum Msg {
     ShowDropdown,
     HideDropdown,
+    HoverEnter,
+    HoverLeave,
+    Pressed(KeyboardEvent),
 }
 
 impl Component for NavbarDropdown {
@@ -153,17 +193,62 @@ impl Component for NavbarDropdown {
             link,
             key,
             show: false,
+            hover_task: None,
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::ShowDropdown => {
+                self.hover_task = None;
                 self.show = true;
             }
             Msg::HideDropdown => {
+                self.hover_task = None;
                 self.show = false;
             }
+            Msg::HoverEnter => {
+                if !self.props.mega_menu {
+                    self.show = true;
+                } else if self.show {
+                    self.hover_task = None;
+                    return false;
+                } else {
+                    // mouseover is fired again when the pointer moves over the children
+                    if self.hover_task.is_none() {
+                        self.hover_task = Some(TimeoutService::spawn(
+                            Duration::from_millis(self.props.hover_delay),
+                            self.link.callback(|_| Msg::ShowDropdown),
+                        ));
+                    }
+                    return false;
+                }
+            }
+            Msg::HoverLeave => {
+                if !self.props.mega_menu {
+                    self.show = false;
+                } else if !self.show {
+                    self.hover_task = None;
+                    return false;
+                } else {
+                    self.hover_task = Some(TimeoutService::spawn(
+                        Duration::from_millis(self.props.hover_delay),
+                        self.link.callback(|_| Msg::HideDropdown),
+                    ));
+                    return false;
+                }
+            }
+            Msg::Pressed(keyboard_event) => match keyboard_event.key().as_str() {
+                "Escape" => {
+                    self.hover_task = None;
+                    self.show = false;
+                }
+                "Enter" | " " | "ArrowDown" if !self.show => {
+                    keyboard_event.prevent_default();
+                    self.show = true;
+                }
+                _ => return false,
+            },
         }
         true
     }
@@ -177,7 +262,7 @@ impl Component for NavbarDropdown {
     }
 
     fn rendered(&mut self, first_render: bool) {
-        if first_render {
+        if first_render && !self.props.mega_menu {
             let navbar_dropdown =
                 get_html_element_by_class(&format!("navbar-dropdown-{}", self.key), 0);
             let navbar_dropdown_width = navbar_dropdown.offset_width();
@@ -195,19 +280,33 @@ impl Component for NavbarDropdown {
     fn view(&self) -> Html {
         html! {
             <div
-                class=classes!("navbar-dropdown", format!("navbar-dropdown-{}", self.key), if self.props.active {
-                    "active"
-                } else {
-                    ""
-                }, self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("navbar-dropdown")
+                    .semantic(format!("navbar-dropdown-{}", self.key))
+                    .state("active", self.props.active)
+                    .state("mega-menu", self.props.mega_menu)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
-                onmouseover=self.link.callback(|_| Msg::ShowDropdown)
-                onmouseleave=self.link.callback(|_| Msg::HideDropdown)
+                onmouseover=self.link.callback(|_| Msg::HoverEnter)
+                onmouseleave=self.link.callback(|_| Msg::HoverLeave)
                 onclick=self.link.callback(|_| Msg::HideDropdown)
+                onkeydown=self.link.callback(Msg::Pressed)
                 >
-                <div class="main-content">{self.props.main_content.clone()}</div>
-                {get_items(self.show, self.key.clone(), self.props.children.clone())}
+                <div
+                    class="main-content"
+                    tabindex="0"
+                    aria-haspopup="true"
+                    aria-expanded=self.show.to_string()
+                    aria-controls=format!("navbar-dropdown-menu-{}", self.key)
+                >{self.props.main_content.clone()}</div>
+                {if self.props.mega_menu {
+                    get_mega_menu(self.show, self.key.clone(), self.props.children.clone())
+                } else {
+                    get_items(self.show, self.key.clone(), self.props.children.clone())
+                }}
             </div>
         }
     }
@@ -215,25 +314,40 @@ impl Component for NavbarDropdown {
 
 fn get_items(show: bool, key: String, children: Children) -> Html {
     html! {
-        <ul class=classes!(format!("navbar-dropdown-container-{}", key), if show { "active"} else {"inactive"})>
+        <ul
+            id=format!("navbar-dropdown-menu-{}", key)
+            role="menu"
+            class=classes!(format!("navbar-dropdown-container-{}", key), if show { "active"} else {"inactive"})
+        >
             {children.clone()}
         </ul>
     }
 }
 
+fn get_mega_menu(show: bool, key: String, children: Children) -> Html {
+    html! {
+        <div id=format!("navbar-dropdown-menu-{}", key) class=classes!(
+            "navbar-mega-menu",
+            format!("navbar-dropdown-container-{}", key),
+            if show { "active" } else { "inactive" }
+        )>
+            {children}
+        </div>
+    }
+}
+
 #[wasm_bindgen_test]
 fn should_create_navbar_dropdown_container() {
-    let navbar_dropdown_container_props = Props {
-        main_content: html! {<div id="test">{"test"}</div>},
-        active: false,
-        key: String::from("navbar-dropdown-1"),
-        class_name: String::from("class-test"),
-        id: String::from("id-test"),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let navbar_dropdown_container_props = Props::builder()
+        .class_name(String::from("class-test"))
+        .id(String::from("id-test"))
+        .key(String::from("navbar-dropdown-1"))
+        .main_content(html! {<div id="test">{"test"}</div>})
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let navbar_dropdown_container: App<NavbarDropdown> = App::new();
 
@@ -245,3 +359,51 @@ fn should_create_navbar_dropdown_container() {
     let content_element = utils::document().get_element_by_id("test").unwrap();
     assert_eq!(content_element.text_content().unwrap(), "test".to_string());
 }
+
+#[wasm_bindgen_test]
+fn should_open_and_close_mega_menu_with_keyboard() {
+    let navbar_dropdown_container_props = Props::builder()
+        .class_name(String::from(""))
+        .id(String::from("mega-menu-test"))
+        .key(String::from("navbar-dropdown-mega"))
+        .main_content(html! {<div>{"Products"}</div>})
+        .mega_menu(true)
+        .children(Children::new(vec![html! {
+            <div class="column">{"Components"}</div>
+        }]))
+        .build();
+
+    let navbar_dropdown_container: App<NavbarDropdown> = App::new();
+
+    let link = navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let get_panel = || {
+        utils::document()
+            .get_element_by_id("mega-menu-test")
+            .unwrap()
+            .get_elements_by_class_name("navbar-mega-menu")
+            .get_with_index(0)
+            .unwrap()
+    };
+
+    let enter_event = KeyboardEvent::new_with_keyboard_event_init_dict(
+        "keydown",
+        web_sys::KeyboardEventInit::new().key("Enter"),
+    )
+    .unwrap();
+
+    link.send_message(Msg::Pressed(enter_event));
+    assert!(get_panel().class_list().contains("active"));
+
+    let escape_event = KeyboardEvent::new_with_keyboard_event_init_dict(
+        "keydown",
+        web_sys::KeyboardEventInit::new().key("Escape"),
+    )
+    .unwrap();
+
+    link.send_message(Msg::Pressed(escape_event));
+    assert!(get_panel().class_list().contains("inactive"));
+}
@@ -1,5 +1,9 @@
+use crate::keyboard::{handle_roving_keydown, init_roving_tabindex, Orientation};
+use crate::navbar::navbar_route::{get_active, ActiveMatch};
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
+use web_sys::Element;
 use yew::prelude::*;
 use yew::{utils, App};
 
@@ -109,6 +113,7 @@ use yew::{utils, App};
 pub struct NavbarDropdownItem {
     link: ComponentLink<Self>,
     props: Props,
+    item_ref: NodeRef,
 }
 
 #[derive(Properties, Clone, PartialEq)]
@@ -125,17 +130,33 @@ pub struct Props {
     /// show with style when the dropdown item is currrently active
     #[prop_or(false)]
     pub active: bool,
+    /// Route of the item, it is active when the route matches the current path. Default `None`
+    #[prop_or_default]
+    pub route: Option<String>,
+    /// How the route is compared with the current path. Default `ActiveMatch::Exact`
+    #[prop_or(ActiveMatch::Exact)]
+    pub active_match: ActiveMatch,
+    /// Current path of the router. Default the path of the window location
+    #[prop_or_default]
+    pub current_path: Option<String>,
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
 pub enum Msg {
     Clicked(MouseEvent),
+    Pressed(KeyboardEvent),
 }
 
 impl Component for NavbarDropdownItem {
@@ -143,7 +164,11 @@ impl Component for NavbarDropdownItem {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            props,
+            item_ref: NodeRef::default(),
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -151,6 +176,15 @@ impl Component for NavbarDropdownItem {
             Msg::Clicked(mouse_event) => {
                 self.props.onclick_signal.emit(mouse_event);
             }
+            Msg::Pressed(keyboard_event) => {
+                handle_roving_keydown(
+                    &keyboard_event,
+                    ".navbar-dropdown",
+                    ".navbar-dropdown-item",
+                    Orientation::Vertical,
+                );
+                return false;
+            }
         }
         true
     }
@@ -164,17 +198,37 @@ impl Component for NavbarDropdownItem {
         false
     }
 
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            if let Some(item) = self.item_ref.cast::<Element>() {
+                init_roving_tabindex(&item, ".navbar-dropdown", ".navbar-dropdown-item");
+            }
+        }
+    }
+
     fn view(&self) -> Html {
+        let active = get_active(
+            self.props.active,
+            &self.props.route,
+            &self.props.current_path,
+            &self.props.active_match,
+        );
+
         html! {
             <li
-                class=classes!("navbar-dropdown-item",if self.props.active {
-                    "active"
-                } else {
-                    ""
-                }, self.props.class_name.clone(), self.props.styles.clone())
+                class=ComponentClasses::new("navbar-dropdown-item")
+                    .state("active", active)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
+                aria-current=if active { "page" } else { "false" }
+                ref=self.item_ref.clone()
+                role="menuitem"
                 onclick=self.link.callback(Msg::Clicked)
+                onkeydown=self.link.callback(Msg::Pressed)
             >{self.props.children.clone()}</li>
         }
     }
@@ -182,17 +236,15 @@ impl Component for NavbarDropdownItem {
 
 #[wasm_bindgen_test]
 fn should_create_dropdown_item() {
-    let dropdown_item_props = Props {
-        onclick_signal: Callback::noop(),
-        active: false,
-        key: String::from("navbar-dropdown-item-1"),
-        class_name: String::from("class-test"),
-        id: String::from("id-test"),
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let dropdown_item_props = Props::builder()
+        .class_name(String::from("class-test"))
+        .id(String::from("id-test"))
+        .key(String::from("navbar-dropdown-item-1"))
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let dropdown_item: App<NavbarDropdownItem> = App::new();
 
@@ -1,11 +1,15 @@
+use super::navbar_route::{get_active, ActiveMatch};
+use crate::keyboard::{handle_roving_keydown, init_roving_tabindex, Orientation};
+use crate::styles::{tokens::Spaces, ComponentClasses};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
-use web_sys::window;
+use web_sys::{window, Element};
 use yew::prelude::*;
 use yew::{utils, App};
 
 pub enum Msg {
     Clicked(MouseEvent),
+    Pressed(KeyboardEvent),
 }
 
 /// # Navbar Item component
@@ -123,12 +127,30 @@ pub struct Props {
     pub interaction_effect: bool,
     #[prop_or(false)]
     pub active: bool,
+    /// Route of the item, it is active when the route matches the current path. Default `None`
+    #[prop_or_default]
+    pub route: Option<String>,
+    /// How the route is compared with the current path. Default `ActiveMatch::Exact`
+    #[prop_or(ActiveMatch::Exact)]
+    pub active_match: ActiveMatch,
+    /// Current path of the router. Default the path of the window location
+    #[prop_or_default]
+    pub current_path: Option<String>,
+    /// Show an animated bar under the active item. Default `false`
+    #[prop_or(false)]
+    pub active_indicator: bool,
     /// Click event for navbar item
     #[prop_or(Callback::noop())]
     pub onclick_signal: Callback<MouseEvent>,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -145,6 +167,15 @@ impl Component for NavbarItem {
             Msg::Clicked(mouse_event) => {
                 self.props.onclick_signal.emit(mouse_event);
             }
+            Msg::Pressed(keyboard_event) => {
+                handle_roving_keydown(
+                    &keyboard_event,
+                    ".navbar-container",
+                    ".navbar-item",
+                    Orientation::Both,
+                );
+                return false;
+            }
         }
 
         true
@@ -159,26 +190,38 @@ impl Component for NavbarItem {
         }
     }
 
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            if let Some(item) = self.props.code_ref.cast::<Element>() {
+                init_roving_tabindex(&item, ".navbar-container", ".navbar-item");
+            }
+        }
+    }
+
     fn view(&self) -> Html {
+        let active = get_active(
+            self.props.active,
+            &self.props.route,
+            &self.props.current_path,
+            &self.props.active_match,
+        );
+
         html! {
             <div
-                class=classes!("navbar-item", if self.props.active {
-                    "active"
-                } else {
-                    ""
-                },
-                if self.props.interaction_effect {
-                    "interaction"
-                } else {
-                    ""
-                },
-                self.props.class_name.clone(),
-                self.props.styles.clone()
-            )
+                class=ComponentClasses::new("navbar-item")
+                    .state("active", active)
+                    .state("interaction", self.props.interaction_effect)
+                    .state("indicator", self.props.active_indicator)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
                 id=self.props.id.clone()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
+                aria-current=if active { "page" } else { "false" }
                 onclick=self.link.callback(Msg::Clicked)
+                onkeydown=self.link.callback(Msg::Pressed)
             >
                 {self.props.children.clone()}
             </div>
@@ -190,19 +233,14 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_navbar_item() {
-    let navbar_item_props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "navbar-item-test".to_string(),
-        id: "navbar-item-id-test".to_string(),
-        onclick_signal: Callback::noop(),
-        active: false,
-        interaction_effect: true,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let navbar_item_props = Props::builder()
+        .class_name("navbar-item-test".to_string())
+        .id("navbar-item-id-test".to_string())
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let navbar_item: App<NavbarItem> = App::new();
 
@@ -233,19 +271,15 @@ fn should_create_clickable_navbar_item() {
         body.append_child(&child_element).unwrap();
     });
 
-    let navbar_item_props = Props {
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "navbar-item-test".to_string(),
-        id: "navbar-item-id-test".to_string(),
-        active: false,
-        interaction_effect: true,
-        onclick_signal: on_add_item_div,
-        styles: css!("background-color: #918d94;"),
-        children: Children::new(vec![html! {
+    let navbar_item_props = Props::builder()
+        .class_name("navbar-item-test".to_string())
+        .id("navbar-item-id-test".to_string())
+        .onclick_signal(on_add_item_div)
+        .styles(css!("background-color: #918d94;"))
+        .children(Children::new(vec![html! {
             <div id="item">{"Item"}</div>
-        }]),
-    };
+        }]))
+        .build();
 
     let mouse_event = MouseEvent::new("click").unwrap();
 
@@ -0,0 +1,97 @@
+use crate::utils::is_browser;
+use wasm_bindgen_test::*;
+use web_sys::window;
+
+/// How the route of a nav item is compared with the current path
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActiveMatch {
+    /// The current path is the route
+    Exact,
+    /// The current path is the route or one of its sub routes, e.g. `/docs` is active in `/docs/button`
+    Prefix,
+}
+
+/// Check if a nav item route is active for the current path
+pub fn is_active_route(route: &str, current_path: &str, active_match: &ActiveMatch) -> bool {
+    let route = trim_path(route);
+    let current_path = trim_path(current_path);
+
+    match active_match {
+        ActiveMatch::Exact => route == current_path,
+        ActiveMatch::Prefix => {
+            route == current_path
+                || route == "/"
+                || current_path.starts_with(&format!("{}/", route))
+        }
+    }
+}
+
+/// Check if the item is active by the `active` property or its route
+pub fn get_active(
+    active: bool,
+    route: &Option<String>,
+    current_path: &Option<String>,
+    active_match: &ActiveMatch,
+) -> bool {
+    active
+        || route.as_ref().map_or(false, |route| {
+            let current_path = current_path.clone().unwrap_or_else(get_current_path);
+
+            is_active_route(route, &current_path, active_match)
+        })
+}
+
+/// Path of the window location
+pub fn get_current_path() -> String {
+    if !is_browser() {
+        return String::new();
+    }
+
+    window()
+        .and_then(|window| window.location().pathname().ok())
+        .unwrap_or_default()
+}
+
+fn trim_path(path: &str) -> &str {
+    let path = path.split(|c| c == '?' || c == '#').next().unwrap_or("");
+
+    if path.len() > 1 {
+        path.trim_end_matches('/')
+    } else {
+        path
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_match_exact_routes() {
+    assert!(is_active_route("/docs", "/docs/", &ActiveMatch::Exact));
+    assert!(is_active_route("/docs", "/docs?tab=1", &ActiveMatch::Exact));
+    assert!(!is_active_route(
+        "/docs",
+        "/docs/button",
+        &ActiveMatch::Exact
+    ));
+}
+
+#[wasm_bindgen_test]
+fn should_match_prefix_routes() {
+    assert!(is_active_route(
+        "/docs",
+        "/docs/button",
+        &ActiveMatch::Prefix
+    ));
+    assert!(!is_active_route(
+        "/docs",
+        "/docsearch",
+        &ActiveMatch::Prefix
+    ));
+    assert!(get_active(
+        false,
+        &Some("/shop".to_string()),
+        &Some("/shop/cart".to_string()),
+        &ActiveMatch::Prefix
+    ));
+}
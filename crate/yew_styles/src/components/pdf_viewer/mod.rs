@@ -0,0 +1,5 @@
+#[cfg(feature = "pdf_js")]
+mod pdf_js;
+mod pdf_viewer;
+
+pub use pdf_viewer::{PdfRenderer, PdfViewer};
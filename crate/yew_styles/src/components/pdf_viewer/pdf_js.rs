@@ -0,0 +1,115 @@
+use js_sys::{Object, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+use yew::Callback;
+
+// Bindings of the `pdfjsLib` global added by the pdf.js script
+#[wasm_bindgen]
+extern "C" {
+    type PdfLoadingTask;
+
+    #[wasm_bindgen(catch, js_namespace = pdfjsLib, js_name = getDocument)]
+    fn get_document(src: &str) -> Result<PdfLoadingTask, JsValue>;
+
+    #[wasm_bindgen(method, getter)]
+    fn promise(this: &PdfLoadingTask) -> Promise;
+
+    /// Document loaded by pdf.js
+    #[derive(Clone, PartialEq)]
+    pub type PdfDocument;
+
+    #[wasm_bindgen(method, getter, js_name = numPages)]
+    fn num_pages(this: &PdfDocument) -> u32;
+
+    #[wasm_bindgen(method, js_name = getPage)]
+    fn get_page(this: &PdfDocument, page: u32) -> Promise;
+
+    type PdfPage;
+
+    #[wasm_bindgen(method, js_name = getViewport)]
+    fn get_viewport(this: &PdfPage, params: &JsValue) -> PdfViewport;
+
+    #[wasm_bindgen(method)]
+    fn render(this: &PdfPage, params: &JsValue) -> PdfRenderTask;
+
+    type PdfViewport;
+
+    #[wasm_bindgen(method, getter)]
+    fn width(this: &PdfViewport) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn height(this: &PdfViewport) -> f64;
+
+    type PdfRenderTask;
+
+    #[wasm_bindgen(method, getter)]
+    fn promise(this: &PdfRenderTask) -> Promise;
+}
+
+impl PdfDocument {
+    /// Number of pages of the document
+    pub fn pages(&self) -> usize {
+        self.num_pages() as usize
+    }
+}
+
+/// Load the document and emit it, or `None` if pdf.js is not available or the document fails
+pub fn load_document(src: String, onload: Callback<Option<PdfDocument>>) {
+    spawn_local(async move {
+        let document = match get_document(&src) {
+            Ok(task) => JsFuture::from(task.promise())
+                .await
+                .ok()
+                .map(|document| document.unchecked_into::<PdfDocument>()),
+            Err(_) => None,
+        };
+
+        onload.emit(document);
+    });
+}
+
+/// Draw the page in the canvas with the scale and emit `true` when it is done
+pub fn render_page(
+    document: PdfDocument,
+    page: usize,
+    scale: f64,
+    canvas: HtmlCanvasElement,
+    onrender: Callback<bool>,
+) {
+    spawn_local(async move {
+        onrender.emit(draw_page(&document, page, scale, &canvas).await.is_ok());
+    });
+}
+
+async fn draw_page(
+    document: &PdfDocument,
+    page: usize,
+    scale: f64,
+    canvas: &HtmlCanvasElement,
+) -> Result<(), JsValue> {
+    let page = JsFuture::from(document.get_page(page as u32))
+        .await?
+        .unchecked_into::<PdfPage>();
+
+    let viewport_params = Object::new();
+    Reflect::set(&viewport_params, &"scale".into(), &scale.into())?;
+    let viewport = page.get_viewport(&viewport_params);
+
+    canvas.set_width(viewport.width() as u32);
+    canvas.set_height(viewport.height() as u32);
+
+    let context = canvas
+        .get_context("2d")?
+        .ok_or(JsValue::NULL)?
+        .dyn_into::<CanvasRenderingContext2d>()?;
+
+    let render_params = Object::new();
+    Reflect::set(&render_params, &"canvasContext".into(), &context)?;
+    Reflect::set(&render_params, &"viewport".into(), &viewport)?;
+
+    JsFuture::from(page.render(&render_params).promise()).await?;
+
+    Ok(())
+}
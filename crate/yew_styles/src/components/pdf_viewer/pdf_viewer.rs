@@ -0,0 +1,524 @@
+#[cfg(feature = "pdf_js")]
+use super::pdf_js::{load_document, render_page, PdfDocument};
+use crate::messages::{format_message, get_messages};
+use crate::styles::{get_palette, get_style, tokens::Spaces, ComponentClasses, Palette, Style};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+const MIN_ZOOM: u32 = 25;
+const MAX_ZOOM: u32 = 400;
+
+/// # Pdf Viewer component
+///
+/// Shows a pdf document with a toolbar to move between the pages, zoom and download it.
+/// A skeleton is shown until the document is loaded.
+///
+/// By default the document is shown by the viewer of the browser in an iframe, where `pages`
+/// sets the number of pages because it cannot be read. With the `pdf_js` feature and
+/// `renderer=PdfRenderer::PdfJs` the pages are drawn in a canvas with [pdf.js](https://mozilla.github.io/pdf.js/),
+/// which must be added to the page before, e.g. `<script src="pdf.min.js"></script>`
+///
+/// ## Features required
+///
+/// pdf_viewer
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::pdf_viewer::PdfViewer;
+///
+/// pub struct Invoice;
+///
+/// impl Component for Invoice {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <PdfViewer
+///                 src="/invoice.pdf"
+///                 title="Invoice"
+///                 pages=Some(3)
+///                 file_name="invoice.pdf"
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct PdfViewer {
+    link: ComponentLink<Self>,
+    props: Props,
+    page: usize,
+    zoom: u32,
+    loading: bool,
+    failed: bool,
+    #[cfg(feature = "pdf_js")]
+    document: Option<PdfDocument>,
+    #[cfg(feature = "pdf_js")]
+    canvas_ref: NodeRef,
+    #[cfg(feature = "pdf_js")]
+    drawn: Option<(usize, u32)>,
+}
+
+/// How the document is shown
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PdfRenderer {
+    /// Viewer of the browser in an iframe
+    Iframe,
+    /// Viewer of the browser in an object, with a download link if the browser cannot show it
+    Object,
+    /// Canvas drawn with pdf.js
+    #[cfg(feature = "pdf_js")]
+    PdfJs,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Url path of the document. Required
+    pub src: String,
+    /// Title of the document for the screen readers. Default `""`
+    #[prop_or_default]
+    pub title: String,
+    /// How the document is shown. Default `PdfRenderer::Iframe`
+    #[prop_or(PdfRenderer::Iframe)]
+    pub renderer: PdfRenderer,
+    /// Initial page, starting by 1. Default `1`
+    #[prop_or(1)]
+    pub page: usize,
+    /// Number of pages, to disable the next button in the last page. Pdf.js reads it from the document. Default `None`
+    #[prop_or_default]
+    pub pages: Option<usize>,
+    /// Initial zoom in percentage. Default `100`
+    #[prop_or(100)]
+    pub zoom: u32,
+    /// Percentage added or removed by the zoom buttons. Default `25`
+    #[prop_or(25)]
+    pub zoom_step: u32,
+    /// Show the toolbar with the page and zoom controls. Default `true`
+    #[prop_or(true)]
+    pub show_controls: bool,
+    /// Show the download button. Default `true`
+    #[prop_or(true)]
+    pub show_download: bool,
+    /// Name of the downloaded file, the name of the url if it is empty. Default `""`
+    #[prop_or_default]
+    pub file_name: String,
+    /// Emitted with the new page when it changes
+    #[prop_or(Callback::noop())]
+    pub onpage_signal: Callback<usize>,
+    /// Type toolbar style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub pdf_viewer_palette: Palette,
+    /// Toolbar styles. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub pdf_viewer_style: Style,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Previous,
+    Next,
+    ZoomIn,
+    ZoomOut,
+    Loaded,
+    #[cfg(feature = "pdf_js")]
+    DocumentLoaded(Option<PdfDocument>),
+    #[cfg(feature = "pdf_js")]
+    PageDrawn(bool),
+}
+
+impl Component for PdfViewer {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut pdf_viewer = Self {
+            link,
+            page: props.page.max(1),
+            zoom: props.zoom.clamp(MIN_ZOOM, MAX_ZOOM),
+            props,
+            loading: true,
+            failed: false,
+            #[cfg(feature = "pdf_js")]
+            document: None,
+            #[cfg(feature = "pdf_js")]
+            canvas_ref: NodeRef::default(),
+            #[cfg(feature = "pdf_js")]
+            drawn: None,
+        };
+
+        pdf_viewer.load();
+        pdf_viewer
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Previous => {
+                self.set_page(get_next_page(self.page, -1, self.get_pages()));
+            }
+            Msg::Next => {
+                self.set_page(get_next_page(self.page, 1, self.get_pages()));
+            }
+            Msg::ZoomIn => {
+                self.zoom = (self.zoom + self.props.zoom_step).min(MAX_ZOOM);
+            }
+            Msg::ZoomOut => {
+                self.zoom = self.zoom.saturating_sub(self.props.zoom_step).max(MIN_ZOOM);
+            }
+            Msg::Loaded => {
+                self.loading = false;
+            }
+            #[cfg(feature = "pdf_js")]
+            Msg::DocumentLoaded(document) => {
+                self.failed = document.is_none();
+                self.loading = false;
+                self.document = document;
+                self.drawn = None;
+            }
+            #[cfg(feature = "pdf_js")]
+            Msg::PageDrawn(drawn) => {
+                self.failed = !drawn;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let reload = self.props.src != props.src || self.props.renderer != props.renderer;
+
+            if self.props.page != props.page {
+                self.page = props.page.max(1);
+            }
+            if self.props.zoom != props.zoom {
+                self.zoom = props.zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+            }
+            self.props = props;
+
+            if reload {
+                self.load();
+            }
+            return true;
+        }
+
+        false
+    }
+
+    #[cfg(feature = "pdf_js")]
+    fn rendered(&mut self, _first_render: bool) {
+        if let (Some(document), Some(canvas)) = (
+            self.document.clone(),
+            self.canvas_ref.cast::<web_sys::HtmlCanvasElement>(),
+        ) {
+            if self.drawn != Some((self.page, self.zoom)) {
+                self.drawn = Some((self.page, self.zoom));
+                render_page(
+                    document,
+                    self.page,
+                    self.zoom as f64 / 100.0,
+                    canvas,
+                    self.link.callback(Msg::PageDrawn),
+                );
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("pdf-viewer")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {if self.props.show_controls { self.get_toolbar() } else { html! {} }}
+                <div class=classes!("pdf-viewer-content", if self.loading { Some("loading") } else { None })>
+                    {if self.loading {
+                        html! {
+                            <div class="pdf-viewer-skeleton" aria-hidden="true">
+                                <div class="pdf-viewer-skeleton-line"></div>
+                                <div class="pdf-viewer-skeleton-line"></div>
+                                <div class="pdf-viewer-skeleton-line short"></div>
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                    {self.get_document()}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl PdfViewer {
+    fn load(&mut self) {
+        self.loading = true;
+        self.failed = false;
+
+        #[cfg(feature = "pdf_js")]
+        {
+            self.document = None;
+            self.drawn = None;
+
+            if self.props.renderer == PdfRenderer::PdfJs {
+                load_document(
+                    self.props.src.clone(),
+                    self.link.callback(Msg::DocumentLoaded),
+                );
+            }
+        }
+    }
+
+    fn get_pages(&self) -> Option<usize> {
+        #[cfg(feature = "pdf_js")]
+        {
+            if let Some(document) = &self.document {
+                return Some(document.pages());
+            }
+        }
+
+        self.props.pages
+    }
+
+    fn set_page(&mut self, page: usize) {
+        if page != self.page {
+            self.page = page;
+            self.props.onpage_signal.emit(page);
+        }
+    }
+
+    fn get_title(&self) -> String {
+        if self.props.title.is_empty() {
+            get_messages().pdf_document
+        } else {
+            self.props.title.clone()
+        }
+    }
+
+    fn get_toolbar(&self) -> Html {
+        let messages = get_messages();
+        let pages = self.get_pages();
+        let page_label = match pages {
+            Some(pages) => format_message(
+                &messages.pdf_page_total,
+                &[
+                    ("page", self.page.to_string()),
+                    ("total", pages.to_string()),
+                ],
+            ),
+            None => format_message(&messages.pdf_page, &[("page", self.page.to_string())]),
+        };
+
+        html! {
+            <div
+                class=classes!(
+                    "pdf-viewer-toolbar",
+                    get_palette(self.props.pdf_viewer_palette.clone()),
+                    get_style(self.props.pdf_viewer_style.clone()),
+                )
+                role="toolbar"
+            >
+                <button
+                    class="pdf-viewer-button"
+                    aria-label=messages.previous
+                    disabled={self.page <= 1}
+                    onclick=self.link.callback(|_| Msg::Previous)
+                >{"‹"}</button>
+                <span class="pdf-viewer-page" aria-live="polite">{page_label}</span>
+                <button
+                    class="pdf-viewer-button"
+                    aria-label=messages.next
+                    disabled={pages.map(|pages| self.page >= pages).unwrap_or(false)}
+                    onclick=self.link.callback(|_| Msg::Next)
+                >{"›"}</button>
+                <span class="pdf-viewer-separator"></span>
+                <button
+                    class="pdf-viewer-button"
+                    aria-label=messages.zoom_out
+                    disabled={self.zoom <= MIN_ZOOM}
+                    onclick=self.link.callback(|_| Msg::ZoomOut)
+                >{"−"}</button>
+                <span class="pdf-viewer-zoom">{format!("{}%", self.zoom)}</span>
+                <button
+                    class="pdf-viewer-button"
+                    aria-label=messages.zoom_in
+                    disabled={self.zoom >= MAX_ZOOM}
+                    onclick=self.link.callback(|_| Msg::ZoomIn)
+                >{"+"}</button>
+                {if self.props.show_download {
+                    html! {
+                        <a
+                            class="pdf-viewer-button pdf-viewer-download"
+                            href=self.props.src.clone()
+                            download=self.props.file_name.clone()
+                        >{messages.download}</a>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+
+    fn get_document(&self) -> Html {
+        if self.failed {
+            return self.get_fallback();
+        }
+
+        let url = get_pdf_url(&self.props.src, self.page, self.zoom);
+
+        match self.props.renderer {
+            PdfRenderer::Iframe => html! {
+                <iframe
+                    class="pdf-viewer-document"
+                    src=url
+                    title=self.get_title()
+                    onload=self.link.callback(|_| Msg::Loaded)
+                ></iframe>
+            },
+            PdfRenderer::Object => html! {
+                <object
+                    class="pdf-viewer-document"
+                    data=url
+                    type="application/pdf"
+                    aria-label=self.get_title()
+                    onload=self.link.callback(|_| Msg::Loaded)
+                >
+                    {self.get_fallback()}
+                </object>
+            },
+            #[cfg(feature = "pdf_js")]
+            PdfRenderer::PdfJs => html! {
+                <canvas
+                    class="pdf-viewer-document pdf-viewer-canvas"
+                    ref=self.canvas_ref.clone()
+                    role="img"
+                    aria-label=self.get_title()
+                ></canvas>
+            },
+        }
+    }
+
+    fn get_fallback(&self) -> Html {
+        let messages = get_messages();
+
+        html! {
+            <p class="pdf-viewer-fallback">
+                {messages.pdf_error}
+                {" "}
+                <a href=self.props.src.clone() download=self.props.file_name.clone()>{messages.download}</a>
+            </p>
+        }
+    }
+}
+
+/// Url with the pdf open parameters of the page and the zoom, which the viewers of the browsers read
+fn get_pdf_url(src: &str, page: usize, zoom: u32) -> String {
+    let src = src.split('#').next().unwrap_or(src);
+
+    format!("{}#page={}&zoom={}", src, page, zoom)
+}
+
+fn get_next_page(page: usize, delta: isize, pages: Option<usize>) -> usize {
+    let next = if delta < 0 {
+        page.saturating_sub(delta.unsigned_abs())
+    } else {
+        page + delta as usize
+    };
+
+    match pages {
+        Some(pages) => next.clamp(1, pages.max(1)),
+        None => next.max(1),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_pdf_viewer() {
+    let props = Props::builder()
+        .file_name("invoice.pdf".to_string())
+        .id("pdf-viewer-test".to_string())
+        .page(2)
+        .pages(Some(3))
+        .src("/invoice.pdf".to_string())
+        .title("Invoice".to_string())
+        .build();
+
+    let pdf_viewer: App<PdfViewer> = App::new();
+    pdf_viewer.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let pdf_viewer_element = utils::document()
+        .get_element_by_id("pdf-viewer-test")
+        .unwrap();
+    let page = pdf_viewer_element
+        .get_elements_by_class_name("pdf-viewer-page")
+        .get_with_index(0)
+        .unwrap();
+    let document = pdf_viewer_element
+        .get_elements_by_tag_name("iframe")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(page.text_content().unwrap(), "Page 2 of 3");
+    assert_eq!(
+        document.get_attribute("src").unwrap(),
+        "/invoice.pdf#page=2&zoom=100"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_next_page() {
+    assert_eq!(get_next_page(1, -1, Some(3)), 1);
+    assert_eq!(get_next_page(3, 1, Some(3)), 3);
+    assert_eq!(get_next_page(2, 1, None), 3);
+    assert_eq!(
+        get_pdf_url("/doc.pdf#page=4", 1, 150),
+        "/doc.pdf#page=1&zoom=150"
+    );
+}
@@ -0,0 +1,356 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use std::cell::{Cell, RefCell};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Portal component
+///
+/// Renders its children at the end of `document.body`, or of the `host` element, instead of
+/// in the place where it is used, so overlays escape the `overflow: hidden` and transformed
+/// ancestors. The children keep working as usual, they are removed when the portal is removed.
+/// The `Modal` and `ToastContainer` components already render inside of a portal
+///
+/// ## Features required
+///
+/// portal
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::portal::Portal;
+///
+/// pub struct Banner {
+///     link: ComponentLink<Self>,
+///     open: bool,
+/// }
+///
+/// pub enum Msg {
+///     Toggle,
+/// }
+///
+/// impl Component for Banner {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link, open: false }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Toggle => self.open = !self.open,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <div style="overflow: hidden; height: 40px">
+///                 <button onclick=self.link.callback(|_| Msg::Toggle)>{"Show banner"}</button>
+///                 {if self.open {
+///                     html! {
+///                         <Portal>
+///                             <div class="banner">
+///                                 <button onclick=self.link.callback(|_| Msg::Toggle)>{"Close"}</button>
+///                             </div>
+///                         </Portal>
+///                     }
+///                 } else {
+///                     html! {}
+///                 }}
+///             </div>
+///         }
+///     }
+/// }
+/// ```
+pub struct Portal {
+    props: Props,
+    id: u32,
+    /// Host where the children are rendered, `None` until the first render
+    host: Option<Element>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Element where the children are rendered, by default `document.body`. Default `None`
+    #[prop_or_default]
+    pub host: Option<Element>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for Portal {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let id = NEXT_ID.with(|next_id| {
+            next_id.set(next_id.get() + 1);
+            next_id.get()
+        });
+
+        Self {
+            props,
+            id,
+            host: None,
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let host_changed = self.props.host != props.host;
+            self.props = props;
+
+            if let Some(host) = &self.host {
+                if host_changed {
+                    send_to_root(host, RootMsg::Remove(self.id));
+                    self.host = None;
+                    self.render_children();
+                } else {
+                    send_to_root(host, RootMsg::Set(self.id, self.props.clone()));
+                }
+            }
+        }
+
+        false
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        // The document is only accessed after the first render so the portal can be created outside of the browser
+        if first_render {
+            self.render_children();
+        }
+    }
+
+    fn destroy(&mut self) {
+        // Removes the children so they are destroyed as well, e.g. the focus trap restores the focus
+        if let Some(host) = self.host.take() {
+            send_to_root(&host, RootMsg::Remove(self.id));
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {}
+    }
+}
+
+impl Portal {
+    fn render_children(&mut self) {
+        let host = get_host(&self.props.host);
+
+        send_to_root(&host, RootMsg::Set(self.id, self.props.clone()));
+        self.host = Some(host);
+    }
+}
+
+thread_local! {
+    static NEXT_ID: Cell<u32> = Cell::new(0);
+    /// Root app of the portals of each host. yew can't destroy an app, so the portals of a host share a
+    /// single one and each portal only adds and removes its children there
+    static PORTAL_ROOTS: RefCell<Vec<(Element, ComponentLink<PortalRoot>)>> = RefCell::new(vec![]);
+}
+
+fn get_host(host: &Option<Element>) -> Element {
+    match host {
+        Some(host) => host.clone(),
+        None => utils::document().body().unwrap().into(),
+    }
+}
+
+fn send_to_root(host: &Element, msg: RootMsg) {
+    let root = PORTAL_ROOTS.with(|roots| {
+        let mut roots = roots.borrow_mut();
+
+        if let Some((_, root)) = roots.iter().find(|(root_host, _)| root_host == host) {
+            return root.clone();
+        }
+
+        let mount_point = utils::document().create_element("div").unwrap();
+        mount_point.set_class_name("portal-root");
+        host.append_child(&mount_point).unwrap();
+
+        let root = App::<PortalRoot>::new().mount(mount_point);
+        roots.push((host.clone(), root.clone()));
+
+        root
+    });
+
+    root.send_message(msg);
+}
+
+/// Root of the app mounted in a host, it renders the children of its `Portal` components
+struct PortalRoot {
+    portals: Vec<(u32, Props)>,
+}
+
+enum RootMsg {
+    Set(u32, Props),
+    Remove(u32),
+}
+
+impl Component for PortalRoot {
+    type Message = RootMsg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { portals: vec![] }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            RootMsg::Set(id, props) => {
+                match self
+                    .portals
+                    .iter_mut()
+                    .find(|(portal_id, _)| *portal_id == id)
+                {
+                    Some(portal) => portal.1 = props,
+                    None => self.portals.push((id, props)),
+                }
+            }
+            RootMsg::Remove(id) => self.portals.retain(|(portal_id, _)| *portal_id != id),
+        };
+
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                {for self.portals.iter().map(|(id, props)| html! {
+                    <div
+                        class=ComponentClasses::new("portal")
+                            .spacing(props.margin, props.padding)
+                            .styles(props.styles.clone())
+                            .class_name(props.class_name.clone())
+                            .build()
+                        id=props.id.clone()
+                        key=id.to_string()
+                        ref=props.code_ref.clone()
+                    >
+                        {props.children.clone()}
+                    </div>
+                })}
+            </>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_the_children_in_the_body() {
+    let props = Props::builder()
+        .id("portal-test".to_string())
+        .children(Children::new(vec![
+            html! {<span id="portal-child-test">{"Content"}</span>},
+        ]))
+        .build();
+
+    let portal: App<Portal> = App::new();
+    portal.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let output = utils::document().get_element_by_id("output").unwrap();
+
+    assert!(utils::document()
+        .get_element_by_id("portal-child-test")
+        .is_some());
+    assert!(output
+        .query_selector("#portal-child-test")
+        .unwrap()
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+fn should_render_the_children_in_the_host() {
+    let host = utils::document().create_element("div").unwrap();
+    utils::document()
+        .body()
+        .unwrap()
+        .append_child(&host)
+        .unwrap();
+
+    let props = Props::builder()
+        .host(Some(host.clone()))
+        .id("portal-host-test".to_string())
+        .children(Children::new(vec![
+            html! {<span id="portal-host-child-test">{"Content"}</span>},
+        ]))
+        .build();
+
+    let portal: App<Portal> = App::new();
+    portal.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    assert!(host
+        .query_selector("#portal-host-child-test")
+        .unwrap()
+        .is_some());
+}
+
+#[wasm_bindgen_test]
+fn should_share_the_root_of_the_host() {
+    let host = utils::document().create_element("div").unwrap();
+    utils::document()
+        .body()
+        .unwrap()
+        .append_child(&host)
+        .unwrap();
+
+    for _ in 0..2 {
+        let props = Props::builder()
+            .host(Some(host.clone()))
+            .children(Children::new(vec![html! {<span>{"Content"}</span>}]))
+            .build();
+
+        let portal: App<Portal> = App::new();
+        portal.mount_with_props(
+            utils::document().get_element_by_id("output").unwrap(),
+            props,
+        );
+    }
+
+    assert_eq!(host.child_element_count(), 1);
+    assert_eq!(host.query_selector_all(".portal").unwrap().length(), 2);
+}
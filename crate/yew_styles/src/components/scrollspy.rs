@@ -0,0 +1,339 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::is_browser;
+use stylist::{css, StyleSource};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::{Element, EventTarget};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Scrollspy component
+///
+/// Navigation of the sections of a page which highlights the link of the section being read.
+/// The current section is the last one whose heading has scrolled past the `offset`, or the last
+/// section when the page is scrolled to the end. The sections are the elements with the ids of
+/// `sections`, in the page or in the scrollable `container`
+///
+/// ## Features required
+///
+/// scrollspy
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::scrollspy::{Scrollspy, ScrollspySection};
+///
+/// pub struct Guide;
+///
+/// impl Component for Guide {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Scrollspy
+///                     offset=64
+///                     sections=vec![
+///                         ScrollspySection::new("install", "Installation"),
+///                         ScrollspySection::new("usage", "Usage"),
+///                     ]
+///                 />
+///                 <h2 id="install">{"Installation"}</h2>
+///                 <p>{"Add yew_styles to the dependencies"}</p>
+///                 <h2 id="usage">{"Usage"}</h2>
+///                 <p>{"Import the components from the prelude"}</p>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Scrollspy {
+    link: ComponentLink<Self>,
+    props: Props,
+    active: Option<usize>,
+    listener: Option<(EventTarget, Closure<dyn Fn(Event)>)>,
+}
+
+/// Section tracked by the `Scrollspy`
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollspySection {
+    /// Id of the section heading, used by the link
+    pub id: String,
+    pub title: String,
+}
+
+impl ScrollspySection {
+    pub fn new(id: &str, title: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            title: title.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Sections of the page, in the order they appear. Required
+    pub sections: Vec<ScrollspySection>,
+    /// Pixels from the top where a heading becomes the current section, e.g. the height of a fixed navbar. Default `0`
+    #[prop_or(0)]
+    pub offset: i32,
+    /// Scrollable element with the sections, the page when it isn't set. Default `NodeRef::default()`
+    #[prop_or_default]
+    pub container: NodeRef,
+    /// Emitted with the id of the current section when it changes
+    #[prop_or(Callback::noop())]
+    pub onsection_signal: Callback<String>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Scrolled,
+}
+
+impl Component for Scrollspy {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            active: None,
+            listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scrolled => {
+                let active = self.get_current_section();
+
+                if active == self.active {
+                    return false;
+                }
+
+                if let Some(section) = active.and_then(|index| self.props.sections.get(index)) {
+                    self.props.onsection_signal.emit(section.id.clone());
+                }
+
+                self.active = active;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let container_changed = self.props.container != props.container;
+            self.props = props;
+
+            if container_changed {
+                self.stop_listening();
+                self.listen();
+            }
+
+            self.link.send_message(Msg::Scrolled);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.listen();
+            self.link.send_message(Msg::Scrolled);
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.stop_listening();
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+
+        html! {
+            <nav
+                class=ComponentClasses::new("scrollspy")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                aria-label=messages.table_of_contents
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <ol class="scrollspy-list">
+                    {for self.props.sections.iter().enumerate().map(|(index, section)| {
+                        let active = self.active == Some(index);
+
+                        html! {
+                            <li>
+                                <a
+                                    class=ComponentClasses::new("scrollspy-link").state("active", active).build()
+                                    href=format!("#{}", section.id)
+                                    aria-current=if active { Some("location") } else { None }
+                                >
+                                    {section.title.clone()}
+                                </a>
+                            </li>
+                        }
+                    })}
+                </ol>
+            </nav>
+        }
+    }
+}
+
+impl Scrollspy {
+    fn listen(&mut self) {
+        if self.listener.is_some() || !is_browser() {
+            return;
+        }
+
+        let target: EventTarget = match self.props.container.cast::<Element>() {
+            Some(container) => container.into(),
+            None => utils::window().into(),
+        };
+        let link = self.link.clone();
+        let listener = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Scrolled)) as Box<dyn Fn(Event)>
+        );
+
+        target
+            .add_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+            .ok();
+        self.listener = Some((target, listener));
+    }
+
+    fn stop_listening(&mut self) {
+        if let Some((target, listener)) = self.listener.take() {
+            target
+                .remove_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+                .ok();
+        }
+    }
+
+    fn get_current_section(&self) -> Option<usize> {
+        let container = self.props.container.cast::<Element>();
+        let container_top = container
+            .as_ref()
+            .map(|container| container.get_bounding_client_rect().top())
+            .unwrap_or(0.0);
+        let tops: Vec<f64> = self
+            .props
+            .sections
+            .iter()
+            .map(|section| {
+                utils::document()
+                    .get_element_by_id(&section.id)
+                    .map(|heading| heading.get_bounding_client_rect().top() - container_top)
+                    .unwrap_or(f64::INFINITY)
+            })
+            .collect();
+        let scrolling = match container {
+            Some(container) => container,
+            None => match utils::document().document_element() {
+                Some(root) => root,
+                None => return None,
+            },
+        };
+        let scroll_top = scrolling.scroll_top();
+        let at_bottom = scroll_top > 0
+            && scroll_top + scrolling.client_height() >= scrolling.scroll_height() - 1;
+
+        get_current_section(&tops, self.props.offset as f64, at_bottom)
+    }
+}
+
+/// Index of the last section whose heading is above the `offset`, the last section at the end of the
+/// scroll and `None` before the first heading
+pub fn get_current_section(tops: &[f64], offset: f64, at_bottom: bool) -> Option<usize> {
+    if at_bottom && !tops.is_empty() {
+        return Some(tops.len() - 1);
+    }
+
+    // One pixel of tolerance for the headings scrolled by their links, which stop at fractional positions
+    tops.iter().rposition(|top| *top <= offset + 1.0)
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_current_section() {
+    let tops = [-300.0, -20.0, 150.0, 900.0];
+
+    assert_eq!(get_current_section(&tops, 0.0, false), Some(1));
+    assert_eq!(get_current_section(&tops, 200.0, false), Some(2));
+    assert_eq!(get_current_section(&tops, 0.0, true), Some(3));
+    assert_eq!(get_current_section(&[40.0, 900.0], 0.0, false), None);
+    assert_eq!(get_current_section(&[], 0.0, true), None);
+}
+
+#[wasm_bindgen_test]
+fn should_link_the_sections() {
+    let props = Props::builder()
+        .id("scrollspy-test".to_string())
+        .sections(vec![
+            ScrollspySection::new("scrollspy-install", "Installation"),
+            ScrollspySection::new("scrollspy-usage", "Usage"),
+        ])
+        .build();
+
+    let scrollspy: App<Scrollspy> = App::new();
+    scrollspy.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let links = utils::document()
+        .get_element_by_id("scrollspy-test")
+        .unwrap()
+        .get_elements_by_class_name("scrollspy-link");
+
+    assert_eq!(links.length(), 2);
+    assert_eq!(
+        links.get_with_index(1).unwrap().get_attribute("href"),
+        Some("#scrollspy-usage".to_string())
+    );
+}
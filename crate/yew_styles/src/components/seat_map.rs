@@ -0,0 +1,517 @@
+use crate::messages::get_messages;
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use crate::utils::get_random_string;
+use std::fmt;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Seat map component
+///
+/// Grid of seats, e.g. in a venue or a plane, where the user selects the available seats
+/// with the pointer, or moving with the arrow keys and pressing enter or space. The categories
+/// color their seats with a palette, the gaps leave aisles without seat and `max_selected`
+/// limits how many seats can be selected at once
+///
+/// ## Features required
+///
+/// seat_map
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::seat_map::{SeatCategory, SeatId, SeatMap};
+/// use yew_styles::styles::Palette;
+///
+/// pub struct Tickets {
+///     link: ComponentLink<Self>,
+///     seats: Vec<SeatId>,
+/// }
+///
+/// pub enum Msg {
+///     Select(Vec<SeatId>),
+/// }
+///
+/// impl Component for Tickets {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link, seats: vec![] }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Select(seats) => self.seats = seats,
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SeatMap
+///                 rows=8
+///                 columns=12
+///                 gaps=(0..8).map(|row| SeatId::new(row, 6)).collect::<Vec<SeatId>>()
+///                 taken=vec![SeatId::new(2, 3), SeatId::new(2, 4)]
+///                 categories=vec![SeatCategory {
+///                     name: "VIP".to_string(),
+///                     palette: Palette::Secondary,
+///                     seats: (0..12).map(|column| SeatId::new(0, column)).collect(),
+///                 }]
+///                 max_selected=Some(4)
+///                 selected=self.seats.clone()
+///                 onselect_signal=self.link.callback(Msg::Select)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct SeatMap {
+    link: ComponentLink<Self>,
+    props: Props,
+    selected: Vec<SeatId>,
+    active: Option<SeatId>,
+    focus_active: bool,
+    map_id: String,
+}
+
+/// Position of a seat, rows and columns start at 0
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeatId {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl SeatId {
+    pub fn new(row: usize, column: usize) -> Self {
+        SeatId { row, column }
+    }
+}
+
+/// Shown as the row letter and the column number, e.g. `C12`
+impl fmt::Display for SeatId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", get_row_label(self.row), self.column + 1)
+    }
+}
+
+/// Group of seats with their own color, e.g. the price ranges
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeatCategory {
+    pub name: String,
+    pub palette: Palette,
+    pub seats: Vec<SeatId>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Number of rows. Required
+    pub rows: usize,
+    /// Number of seats in each row. Required
+    pub columns: usize,
+    /// Seats which can't be selected
+    #[prop_or_default]
+    pub taken: Vec<SeatId>,
+    /// Positions without seat, e.g. the aisles
+    #[prop_or_default]
+    pub gaps: Vec<SeatId>,
+    /// Categories of the seats, the seats without category use the `seat_palette`
+    #[prop_or_default]
+    pub categories: Vec<SeatCategory>,
+    /// Selected seats
+    #[prop_or_default]
+    pub selected: Vec<SeatId>,
+    /// Maximum number of selected seats, `None` doesn't limit it. Default `None`
+    #[prop_or_default]
+    pub max_selected: Option<usize>,
+    /// Labels of the rows, by default letters from `A`. Default `None`
+    #[prop_or_default]
+    pub row_labels: Option<Vec<String>>,
+    /// Type seat map purpose style of the seats without category. Default `Palette::Success`
+    #[prop_or(Palette::Success)]
+    pub seat_palette: Palette,
+    /// Emitted with all the selected seats when a seat is selected or unselected
+    #[prop_or(Callback::noop())]
+    pub onselect_signal: Callback<Vec<SeatId>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Toggle(SeatId),
+    KeyDown(KeyboardEvent),
+}
+
+impl Component for SeatMap {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            selected: props.selected.clone(),
+            props,
+            active: None,
+            focus_active: false,
+            map_id: format!("seat-map-{}", get_random_string(10)),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle(seat) => {
+                self.active = Some(seat);
+
+                if self.props.taken.contains(&seat)
+                    || !toggle_seat(&mut self.selected, seat, self.props.max_selected)
+                {
+                    return true;
+                }
+
+                self.props.onselect_signal.emit(self.selected.clone());
+            }
+            Msg::KeyDown(keyboard_event) => {
+                let active = self.get_active();
+                let next = match keyboard_event.key().as_str() {
+                    "ArrowLeft" => self.get_next(active, 0, -1),
+                    "ArrowRight" => self.get_next(active, 0, 1),
+                    "ArrowUp" => self.get_next(active, -1, 0),
+                    "ArrowDown" => self.get_next(active, 1, 0),
+                    _ => return false,
+                };
+
+                keyboard_event.prevent_default();
+                self.active = next.or(active);
+                self.focus_active = true;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.selected != props.selected {
+                self.selected = props.selected.clone();
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if !self.focus_active {
+            return;
+        }
+
+        self.focus_active = false;
+
+        if let Some(seat) = self.active.and_then(|active| {
+            utils::document()
+                .get_element_by_id(&self.get_seat_id(active))
+                .and_then(|seat| seat.dyn_into::<HtmlElement>().ok())
+        }) {
+            seat.focus().ok();
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("seat-map")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.get_legend()}
+                <div
+                    class="seat-map-seats"
+                    role="grid"
+                    aria-multiselectable="true"
+                    style=format!("grid-template-columns: auto repeat({}, 1fr)", self.props.columns)
+                    onkeydown=self.link.callback(Msg::KeyDown)
+                >
+                    <div class="seat-map-row" role="row">
+                        <span class="seat-map-corner"></span>
+                        {for (0..self.props.columns).map(|column| html! {
+                            <span class="seat-map-column-label" role="columnheader">{column + 1}</span>
+                        })}
+                    </div>
+                    {for (0..self.props.rows).map(|row| self.get_row(row))}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl SeatMap {
+    fn get_seat_id(&self, seat: SeatId) -> String {
+        format!("{}-{}-{}", self.map_id, seat.row, seat.column)
+    }
+
+    fn get_row_label(&self, row: usize) -> String {
+        self.props
+            .row_labels
+            .as_ref()
+            .and_then(|labels| labels.get(row).cloned())
+            .unwrap_or_else(|| get_row_label(row))
+    }
+
+    fn get_category(&self, seat: &SeatId) -> Option<&SeatCategory> {
+        self.props
+            .categories
+            .iter()
+            .find(|category| category.seats.contains(seat))
+    }
+
+    fn is_seat(&self, seat: &SeatId) -> bool {
+        seat.row < self.props.rows
+            && seat.column < self.props.columns
+            && !self.props.gaps.contains(seat)
+    }
+
+    // The seat reachable with tab, the last one used or the first seat of the map
+    fn get_active(&self) -> Option<SeatId> {
+        self.active
+            .filter(|active| self.is_seat(active))
+            .or_else(|| {
+                (0..self.props.rows)
+                    .flat_map(|row| {
+                        (0..self.props.columns).map(move |column| SeatId::new(row, column))
+                    })
+                    .find(|seat| self.is_seat(seat))
+            })
+    }
+
+    // Next seat in the direction, jumping over the gaps
+    fn get_next(&self, from: Option<SeatId>, rows: isize, columns: isize) -> Option<SeatId> {
+        let mut seat = from?;
+
+        loop {
+            let row = seat.row as isize + rows;
+            let column = seat.column as isize + columns;
+
+            if row < 0
+                || column < 0
+                || row as usize >= self.props.rows
+                || column as usize >= self.props.columns
+            {
+                return None;
+            }
+
+            seat = SeatId::new(row as usize, column as usize);
+
+            if self.is_seat(&seat) {
+                return Some(seat);
+            }
+        }
+    }
+
+    fn get_legend(&self) -> Html {
+        if self.props.categories.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <ul class="seat-map-legend">
+                {for self.props.categories.iter().map(|category| html! {
+                    <li class="seat-map-legend-item">
+                        <span class=classes!("seat-map-legend-color", get_palette(category.palette.clone()))></span>
+                        {&category.name}
+                    </li>
+                })}
+            </ul>
+        }
+    }
+
+    fn get_row(&self, row: usize) -> Html {
+        html! {
+            <div class="seat-map-row" role="row">
+                <span class="seat-map-row-label" role="rowheader">{self.get_row_label(row)}</span>
+                {for (0..self.props.columns).map(|column| self.get_seat(SeatId::new(row, column)))}
+            </div>
+        }
+    }
+
+    fn get_seat(&self, seat: SeatId) -> Html {
+        if !self.is_seat(&seat) {
+            return html! {<span class="seat-map-gap" role="gridcell"></span>};
+        }
+
+        let messages = get_messages();
+        let category = self.get_category(&seat);
+        let taken = self.props.taken.contains(&seat);
+        let selected = self.selected.contains(&seat);
+        let limit_reached = self
+            .props
+            .max_selected
+            .map_or(false, |max_selected| self.selected.len() >= max_selected);
+        let palette = category.map_or(self.props.seat_palette.clone(), |category| {
+            category.palette.clone()
+        });
+        let mut label = vec![format!(
+            "{}{}",
+            self.get_row_label(seat.row),
+            seat.column + 1
+        )];
+
+        if let Some(category) = category {
+            label.push(category.name.clone());
+        }
+        if taken {
+            label.push(messages.taken);
+        }
+
+        html! {
+            <span role="gridcell">
+                <button
+                    class=classes!(
+                        "seat-map-seat",
+                        get_palette(palette),
+                        if selected { Some("selected") } else { None },
+                        if taken { Some("taken") } else { None },
+                    )
+                    id=self.get_seat_id(seat)
+                    tabindex=if self.get_active() == Some(seat) { "0" } else { "-1" }
+                    aria-pressed=selected.to_string()
+                    aria-disabled=(taken || (limit_reached && !selected)).to_string()
+                    aria-label=label.join(", ")
+                    title=label.join(", ")
+                    onclick=self.link.callback(move |_| Msg::Toggle(seat))
+                ></button>
+            </span>
+        }
+    }
+}
+
+/// Letters of the row, `A` to `Z` and then `AA`, `AB`...
+pub fn get_row_label(row: usize) -> String {
+    let mut label = String::new();
+    let mut index = row + 1;
+
+    while index > 0 {
+        index -= 1;
+        label.insert(0, (b'A' + (index % 26) as u8) as char);
+        index /= 26;
+    }
+
+    label
+}
+
+// Select or unselect the seat, false when the selection is full
+fn toggle_seat(selected: &mut Vec<SeatId>, seat: SeatId, max_selected: Option<usize>) -> bool {
+    if let Some(index) = selected.iter().position(|selected| *selected == seat) {
+        selected.remove(index);
+        return true;
+    }
+
+    if max_selected.map_or(false, |max_selected| selected.len() >= max_selected) {
+        return false;
+    }
+
+    selected.push(seat);
+    true
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_the_seats_without_the_gaps() {
+    let props = Props::builder()
+        .columns(4)
+        .gaps(vec![
+            SeatId::new(0, 2),
+            SeatId::new(1, 2),
+            SeatId::new(2, 2),
+        ])
+        .id("seat-map-test".to_string())
+        .rows(3)
+        .selected(vec![SeatId::new(1, 1)])
+        .taken(vec![SeatId::new(0, 0)])
+        .build();
+
+    let seat_map: App<SeatMap> = App::new();
+    seat_map.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let seat_map_element = utils::document()
+        .get_element_by_id("seat-map-test")
+        .unwrap();
+
+    assert_eq!(
+        seat_map_element
+            .get_elements_by_class_name("seat-map-seat")
+            .length(),
+        9
+    );
+    assert_eq!(
+        seat_map_element
+            .get_elements_by_class_name("taken")
+            .length(),
+        1
+    );
+    assert_eq!(
+        seat_map_element
+            .get_elements_by_class_name("selected")
+            .length(),
+        1
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_limit_the_selected_seats() {
+    let mut selected = vec![];
+
+    assert!(toggle_seat(&mut selected, SeatId::new(0, 0), Some(2)));
+    assert!(toggle_seat(&mut selected, SeatId::new(0, 1), Some(2)));
+    assert!(!toggle_seat(&mut selected, SeatId::new(0, 2), Some(2)));
+    assert!(toggle_seat(&mut selected, SeatId::new(0, 0), Some(2)));
+    assert_eq!(selected, vec![SeatId::new(0, 1)]);
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_row_labels() {
+    assert_eq!(get_row_label(0), "A");
+    assert_eq!(get_row_label(25), "Z");
+    assert_eq!(get_row_label(26), "AA");
+    assert_eq!(SeatId::new(2, 11).to_string(), "C12");
+}
@@ -0,0 +1,340 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::copy_to_clipboard;
+use std::time::Duration;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::{utils, App};
+
+const COPIED_DURATION: u64 = 1500;
+
+/// # Secret text component
+///
+/// Masked sensitive value, like passwords, tokens or keys, which is revealed only while the reveal
+/// button is held with the pointer or the keyboard. The copy button writes the value in the clipboard
+/// and clears it again after `clear_clipboard_after`, or when the component is destroyed before
+///
+/// ## Features required
+///
+/// secret_text
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::services::ConsoleService;
+/// use yew_styles::secret_text::SecretText;
+///
+/// pub struct ApiKey {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Revealed,
+/// }
+///
+/// impl Component for ApiKey {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Revealed => ConsoleService::log("The api key was revealed"),
+///         }
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SecretText
+///                 value="sk_live_51H8mW2KZ9x"
+///                 clear_clipboard_after=Some(20000)
+///                 onreveal_signal=self.link.callback(|_| Msg::Revealed)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct SecretText {
+    link: ComponentLink<Self>,
+    props: Props,
+    revealed: bool,
+    copied: bool,
+    copied_task: Option<TimeoutTask>,
+    clear_task: Option<TimeoutTask>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Secret value. Required
+    pub value: String,
+    /// Character which hides the value. Default `'•'`
+    #[prop_or('•')]
+    pub mask_char: char,
+    /// Number of mask characters, so the length of the value is not shown.
+    /// `None` uses the length of the value. Default `Some(8)`
+    #[prop_or(Some(8))]
+    pub mask_length: Option<usize>,
+    /// Add a button to copy the value. Default `true`
+    #[prop_or(true)]
+    pub copyable: bool,
+    /// Milliseconds after which the copied value is removed from the clipboard,
+    /// `None` keeps it. Default `Some(30000)`
+    #[prop_or(Some(30000))]
+    pub clear_clipboard_after: Option<u32>,
+    /// Emitted each time the value is revealed, e.g. for audit logs
+    #[prop_or(Callback::noop())]
+    pub onreveal_signal: Callback<()>,
+    /// Emitted each time the value is copied
+    #[prop_or(Callback::noop())]
+    pub oncopy_signal: Callback<()>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Reveal,
+    Hide,
+    KeyDown(KeyboardEvent),
+    KeyUp(KeyboardEvent),
+    Copy,
+    ResetCopied,
+    ClearClipboard,
+}
+
+impl Component for SecretText {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            revealed: false,
+            copied: false,
+            copied_task: None,
+            clear_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Reveal => {
+                if self.revealed {
+                    return false;
+                }
+
+                self.revealed = true;
+                self.props.onreveal_signal.emit(());
+            }
+            Msg::Hide => {
+                if !self.revealed {
+                    return false;
+                }
+
+                self.revealed = false;
+            }
+            Msg::KeyDown(keyboard_event) => {
+                if !is_hold_key(&keyboard_event) {
+                    return false;
+                }
+
+                keyboard_event.prevent_default();
+                self.link.send_message(Msg::Reveal);
+                return false;
+            }
+            Msg::KeyUp(keyboard_event) => {
+                if is_hold_key(&keyboard_event) {
+                    self.link.send_message(Msg::Hide);
+                }
+
+                return false;
+            }
+            Msg::Copy => {
+                if !copy_to_clipboard(&self.props.value) {
+                    return false;
+                }
+
+                self.copied = true;
+                self.copied_task = Some(TimeoutService::spawn(
+                    Duration::from_millis(COPIED_DURATION),
+                    self.link.callback(|_| Msg::ResetCopied),
+                ));
+                self.clear_task = self.props.clear_clipboard_after.map(|clear_after| {
+                    TimeoutService::spawn(
+                        Duration::from_millis(clear_after as u64),
+                        self.link.callback(|_| Msg::ClearClipboard),
+                    )
+                });
+                self.props.oncopy_signal.emit(());
+            }
+            Msg::ResetCopied => {
+                self.copied = false;
+                self.copied_task = None;
+            }
+            Msg::ClearClipboard => {
+                self.clear_task = None;
+                copy_to_clipboard("");
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.value != props.value {
+                self.revealed = false;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn destroy(&mut self) {
+        if self.clear_task.take().is_some() {
+            copy_to_clipboard("");
+        }
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+
+        html! {
+            <span
+                class=ComponentClasses::new("secret-text")
+                    .state("revealed", self.revealed)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {if self.revealed {
+                    html! {<span class="secret-text-value">{&self.props.value}</span>}
+                } else {
+                    html! {
+                        <span class="secret-text-value" role="img" aria-label=messages.hidden_value>
+                            {get_mask(&self.props.value, self.props.mask_char, self.props.mask_length)}
+                        </span>
+                    }
+                }}
+                <button
+                    class="secret-text-reveal"
+                    aria-pressed=self.revealed.to_string()
+                    onpointerdown=self.link.callback(|_| Msg::Reveal)
+                    onpointerup=self.link.callback(|_| Msg::Hide)
+                    onpointerleave=self.link.callback(|_| Msg::Hide)
+                    onpointercancel=self.link.callback(|_| Msg::Hide)
+                    onkeydown=self.link.callback(Msg::KeyDown)
+                    onkeyup=self.link.callback(Msg::KeyUp)
+                    onblur=self.link.callback(|_| Msg::Hide)
+                >
+                    {messages.hold_to_reveal}
+                </button>
+                {if self.props.copyable {
+                    html! {
+                        <button
+                            class=classes!("secret-text-copy", if self.copied { Some("copied") } else { None })
+                            onclick=self.link.callback(|_| Msg::Copy)
+                        >
+                            {if self.copied { messages.copied } else { messages.copy }}
+                        </button>
+                    }
+                } else {
+                    html! {}
+                }}
+            </span>
+        }
+    }
+}
+
+fn is_hold_key(keyboard_event: &KeyboardEvent) -> bool {
+    matches!(keyboard_event.key().as_str(), " " | "Enter")
+}
+
+fn get_mask(value: &str, mask_char: char, mask_length: Option<usize>) -> String {
+    let length = mask_length.unwrap_or_else(|| value.chars().count());
+
+    std::iter::repeat(mask_char).take(length).collect()
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_mask_the_value_until_it_is_revealed() {
+    let props = Props::builder()
+        .id("secret-text-test".to_string())
+        .value("hunter2".to_string())
+        .build();
+
+    let secret_text: App<SecretText> = App::new();
+    let link = secret_text.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let secret_text_element = utils::document()
+        .get_element_by_id("secret-text-test")
+        .unwrap();
+
+    assert!(!secret_text_element
+        .text_content()
+        .unwrap()
+        .contains("hunter2"));
+
+    link.send_message(Msg::Reveal);
+
+    assert!(secret_text_element
+        .text_content()
+        .unwrap()
+        .contains("hunter2"));
+
+    link.send_message(Msg::Hide);
+
+    assert!(!secret_text_element
+        .text_content()
+        .unwrap()
+        .contains("hunter2"));
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_mask() {
+    assert_eq!(get_mask("hunter2", '•', Some(4)), "••••");
+    assert_eq!(get_mask("ñandú", '*', None), "*****");
+}
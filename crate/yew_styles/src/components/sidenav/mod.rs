@@ -0,0 +1,3 @@
+pub mod sidenav_component;
+pub mod sidenav_item;
+pub mod sidenav_section;
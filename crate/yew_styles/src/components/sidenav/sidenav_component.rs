@@ -0,0 +1,267 @@
+use crate::messages::get_messages;
+use crate::styles::{get_palette, get_style, tokens::Spaces, ComponentClasses, Palette, Style};
+use crate::utils::{get_stored_value, set_stored_value};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+use yew_assets::controller_assets::{ControllerAssets, ControllerIcon};
+
+/// # SideNav component
+///
+/// Sidebar navigation with nested sections. In the mini mode only the icons are shown
+/// and the labels appear as tooltips
+///
+/// ## Features required
+///
+/// sidenav
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::sidenav::{
+///     sidenav_component::SideNav,
+///     sidenav_item::SideNavItem,
+///     sidenav_section::SideNavSection,
+/// };
+/// use yew_styles::navbar::navbar_route::ActiveMatch;
+/// use yew_styles::styles::{Palette, Style};
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SideNav
+///                 sidenav_palette=Palette::Clean
+///                 sidenav_style=Style::Light
+///                 storage_key="admin-sidenav"
+///             >
+///                 <SideNavItem icon=html!{"🏠"} route="/">{"Home"}</SideNavItem>
+///                 <SideNavSection
+///                     title="Orders"
+///                     icon=html!{"📦"}
+///                     storage_key="admin-sidenav-orders"
+///                 >
+///                     <SideNavItem route="/orders" badge=html!{"12"}>{"All orders"}</SideNavItem>
+///                     <SideNavItem route="/orders/returns" active_match=ActiveMatch::Prefix>
+///                         {"Returns"}
+///                     </SideNavItem>
+///                 </SideNavSection>
+///             </SideNav>
+///         }
+///     }
+/// }
+/// ```
+pub struct SideNav {
+    link: ComponentLink<Self>,
+    props: Props,
+    mini: bool,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Show only the icons of the items. Default `false`
+    #[prop_or(false)]
+    pub mini: bool,
+    /// Show a button to switch between the full and the mini mode. Default `true`
+    #[prop_or(true)]
+    pub collapsible: bool,
+    /// Key to persist the mini mode in the local storage. Default `None`
+    #[prop_or_default]
+    pub storage_key: Option<String>,
+    /// Signal emitted with the mini mode when the toggle button is clicked
+    #[prop_or(Callback::noop())]
+    pub ontoggle_signal: Callback<bool>,
+    /// Type sidenav purpose style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub sidenav_palette: Palette,
+    /// Sidenav styles. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub sidenav_style: Style,
+    /// Label for the screen readers. Default `Messages::side_navigation`
+    #[prop_or_else(default_aria_label)]
+    pub aria_label: String,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Toggle,
+    Restore(bool),
+}
+
+impl Component for SideNav {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mini = props.mini;
+
+        Self { link, props, mini }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                self.mini = !self.mini;
+
+                if let Some(storage_key) = &self.props.storage_key {
+                    set_stored_value(storage_key, &self.mini.to_string());
+                }
+
+                self.props.ontoggle_signal.emit(self.mini);
+            }
+            Msg::Restore(mini) => {
+                self.mini = mini;
+            }
+        }
+
+        true
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            if let Some(mini) = get_stored_mini(&self.props.storage_key) {
+                if mini != self.mini {
+                    self.link.send_message(Msg::Restore(mini));
+                }
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.mini != props.mini {
+                self.mini = props.mini;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <nav
+                class=ComponentClasses::new("sidenav")
+                    .semantic(vec![
+                        get_palette(self.props.sidenav_palette.clone()),
+                        get_style(self.props.sidenav_style.clone()),
+                    ])
+                    .state("mini", self.mini)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                aria-label=self.props.aria_label.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                <div class="sidenav-content">
+                    {self.props.children.clone()}
+                </div>
+                {if self.props.collapsible {
+                    html! {
+                        <button
+                            class="sidenav-toggle"
+                            aria-label=if self.mini {
+                                get_messages().expand_side_navigation
+                            } else {
+                                get_messages().collapse_side_navigation
+                            }
+                            aria-expanded=(!self.mini).to_string()
+                            onclick=self.link.callback(|_| Msg::Toggle)
+                        >
+                            <ControllerAssets
+                                icon=if self.mini { ControllerIcon::ChevronsRight } else { ControllerIcon::ChevronsLeft }
+                                size=(String::from("20"), String::from("20"))
+                            />
+                        </button>
+                    }
+                } else {
+                    html! {}
+                }}
+            </nav>
+        }
+    }
+}
+
+fn default_aria_label() -> String {
+    get_messages().side_navigation
+}
+
+fn get_stored_mini(storage_key: &Option<String>) -> Option<bool> {
+    storage_key
+        .as_ref()
+        .and_then(|storage_key| get_stored_value(storage_key))
+        .and_then(|value| value.parse().ok())
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_toggle_and_persist_the_mini_mode() {
+    let props = Props::builder()
+        .aria_label("Side navigation".to_string())
+        .id("sidenav".to_string())
+        .storage_key(Some("sidenav-test".to_string()))
+        .children(Children::new(vec![html! {<div>{"Item"}</div>}]))
+        .build();
+
+    let sidenav: App<SideNav> = App::new();
+
+    let link = sidenav.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    link.send_message(Msg::Toggle);
+
+    let sidenav_element = utils::document().get_element_by_id("sidenav").unwrap();
+
+    assert!(sidenav_element.class_list().contains("mini"));
+    assert_eq!(
+        get_stored_mini(&Some("sidenav-test".to_string())),
+        Some(true)
+    );
+}
@@ -0,0 +1,237 @@
+use super::sidenav_section::{get_badge, get_icon};
+use crate::keyboard::{handle_roving_keydown, init_roving_tabindex, Orientation};
+use crate::navbar::navbar_route::{get_active, ActiveMatch};
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # SideNav Item component
+///
+/// Item of the sidenav. It is active by the `active` property or when its route
+/// matches the current path
+///
+/// ## Features required
+///
+/// sidenav
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::sidenav::{sidenav_component::SideNav, sidenav_item::SideNavItem};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     current_path: String,
+/// }
+///
+/// pub enum Msg {
+///     Navigate(String),
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             current_path: "/".to_string(),
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Navigate(path) => self.current_path = path,
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SideNav>
+///                 <SideNavItem
+///                     route="/"
+///                     current_path=self.current_path.clone()
+///                     onclick_signal=self.link.callback(|_| Msg::Navigate("/".to_string()))
+///                 >
+///                     {"Home"}
+///                 </SideNavItem>
+///                 <SideNavItem
+///                     route="/reports"
+///                     current_path=self.current_path.clone()
+///                     badge=html!{"3"}
+///                     onclick_signal=self.link.callback(|_| Msg::Navigate("/reports".to_string()))
+///                 >
+///                     {"Reports"}
+///                 </SideNavItem>
+///             </SideNav>
+///         }
+///     }
+/// }
+/// ```
+pub struct SideNavItem {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Icon shown before the label and in the mini mode. Default `None`
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Badge shown after the label. Default `None`
+    #[prop_or_default]
+    pub badge: Option<Html>,
+    /// Active item style. Default `false`
+    #[prop_or(false)]
+    pub active: bool,
+    /// Route of the item, it is active when the route matches the current path. Default `None`
+    #[prop_or_default]
+    pub route: Option<String>,
+    /// How the route is compared with the current path. Default `ActiveMatch::Exact`
+    #[prop_or(ActiveMatch::Exact)]
+    pub active_match: ActiveMatch,
+    /// Current path of the router. Default the path of the window location
+    #[prop_or_default]
+    pub current_path: Option<String>,
+    /// Click event for the item
+    #[prop_or(Callback::noop())]
+    pub onclick_signal: Callback<MouseEvent>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Clicked(MouseEvent),
+    Pressed(KeyboardEvent),
+}
+
+impl Component for SideNavItem {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Clicked(mouse_event) => {
+                self.props.onclick_signal.emit(mouse_event);
+            }
+            Msg::Pressed(keyboard_event) => {
+                handle_roving_keydown(
+                    &keyboard_event,
+                    ".sidenav",
+                    ".sidenav-item",
+                    Orientation::Vertical,
+                );
+                return false;
+            }
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            if let Some(item) = self.props.code_ref.cast::<Element>() {
+                init_roving_tabindex(&item, ".sidenav", ".sidenav-item");
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let active = get_active(
+            self.props.active,
+            &self.props.route,
+            &self.props.current_path,
+            &self.props.active_match,
+        );
+
+        html! {
+            <div
+                class=ComponentClasses::new("sidenav-item")
+                    .state("active", active)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                role="link"
+                aria-current=if active { "page" } else { "false" }
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+                onclick=self.link.callback(Msg::Clicked)
+                onkeydown=self.link.callback(Msg::Pressed)
+            >
+                {get_icon(&self.props.icon)}
+                <span class="sidenav-label">{self.props.children.clone()}</span>
+                {get_badge(&self.props.badge)}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_activate_the_item_by_route() {
+    let props = Props::builder()
+        .active_match(ActiveMatch::Prefix)
+        .badge(Some(html! {"3"}))
+        .current_path(Some("/reports/sales".to_string()))
+        .id("sidenav-item".to_string())
+        .route(Some("/reports".to_string()))
+        .children(Children::new(vec![html! {"Reports"}]))
+        .build();
+
+    let item: App<SideNavItem> = App::new();
+
+    item.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let item_element = utils::document().get_element_by_id("sidenav-item").unwrap();
+
+    assert!(item_element.class_list().contains("active"));
+    assert_eq!(item_element.text_content().unwrap(), "Reports3");
+}
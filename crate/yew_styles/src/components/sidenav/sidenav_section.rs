@@ -0,0 +1,259 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::{get_stored_value, set_stored_value};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+use yew_assets::controller_assets::{ControllerAssets, ControllerIcon};
+
+/// # SideNav Section component
+///
+/// Collapsible group of items of the sidenav, sections can be nested
+///
+/// ## Features required
+///
+/// sidenav
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::sidenav::{
+///     sidenav_component::SideNav,
+///     sidenav_item::SideNavItem,
+///     sidenav_section::SideNavSection,
+/// };
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SideNav>
+///                 <SideNavSection title="Settings" expanded=true>
+///                     <SideNavItem route="/settings/profile">{"Profile"}</SideNavItem>
+///                     <SideNavSection title="Billing">
+///                         <SideNavItem route="/settings/billing/invoices">{"Invoices"}</SideNavItem>
+///                     </SideNavSection>
+///                 </SideNavSection>
+///             </SideNav>
+///         }
+///     }
+/// }
+/// ```
+pub struct SideNavSection {
+    link: ComponentLink<Self>,
+    props: Props,
+    expanded: bool,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Title of the section. Required
+    pub title: String,
+    /// Icon shown before the title and in the mini mode. Default `None`
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Badge shown after the title. Default `None`
+    #[prop_or_default]
+    pub badge: Option<Html>,
+    /// Show the items of the section. Default `false`
+    #[prop_or(false)]
+    pub expanded: bool,
+    /// Key to persist the expansion state in the local storage. Default `None`
+    #[prop_or_default]
+    pub storage_key: Option<String>,
+    /// Signal emitted with the expansion state when the title is clicked
+    #[prop_or(Callback::noop())]
+    pub ontoggle_signal: Callback<bool>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Toggle,
+    Restore(bool),
+}
+
+impl Component for SideNavSection {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let expanded = props.expanded;
+
+        Self {
+            link,
+            props,
+            expanded,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                self.expanded = !self.expanded;
+
+                if let Some(storage_key) = &self.props.storage_key {
+                    set_stored_value(storage_key, &self.expanded.to_string());
+                }
+
+                self.props.ontoggle_signal.emit(self.expanded);
+            }
+            Msg::Restore(expanded) => {
+                self.expanded = expanded;
+            }
+        }
+
+        true
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            let stored_expanded = self
+                .props
+                .storage_key
+                .as_ref()
+                .and_then(|storage_key| get_stored_value(storage_key))
+                .and_then(|value| value.parse().ok());
+
+            if let Some(expanded) = stored_expanded {
+                if expanded != self.expanded {
+                    self.link.send_message(Msg::Restore(expanded));
+                }
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.expanded != props.expanded {
+                self.expanded = props.expanded;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("sidenav-section")
+                    .state("expanded", self.expanded)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                <button
+                    class="sidenav-section-header"
+                    aria-expanded=self.expanded.to_string()
+                    onclick=self.link.callback(|_| Msg::Toggle)
+                >
+                    {get_icon(&self.props.icon)}
+                    <span class="sidenav-label">{&self.props.title}</span>
+                    {get_badge(&self.props.badge)}
+                    <ControllerAssets
+                        icon=ControllerIcon::ChevronDown
+                        size=(String::from("16"), String::from("16"))
+                        class_name="sidenav-caret"
+                    />
+                </button>
+                {if self.expanded {
+                    html! {
+                        <div class="sidenav-section-content">
+                            {self.props.children.clone()}
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+pub(crate) fn get_icon(icon: &Option<Html>) -> Html {
+    match icon {
+        Some(icon) => html! {<span class="sidenav-icon" aria-hidden="true">{icon.clone()}</span>},
+        None => html! {},
+    }
+}
+
+pub(crate) fn get_badge(badge: &Option<Html>) -> Html {
+    match badge {
+        Some(badge) => html! {<span class="sidenav-badge">{badge.clone()}</span>},
+        None => html! {},
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_expand_the_section() {
+    let props = Props::builder()
+        .id("sidenav-section".to_string())
+        .title("Orders".to_string())
+        .children(Children::new(vec![
+            html! {<div id="section-item">{"Returns"}</div>},
+        ]))
+        .build();
+
+    let section: App<SideNavSection> = App::new();
+
+    let link = section.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    assert!(utils::document()
+        .get_element_by_id("section-item")
+        .is_none());
+
+    link.send_message(Msg::Toggle);
+
+    assert!(utils::document()
+        .get_element_by_id("section-item")
+        .is_some());
+}
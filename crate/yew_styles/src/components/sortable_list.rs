@@ -0,0 +1,467 @@
+use crate::announcer::{announce, Politeness};
+use crate::icon::{Icon, IconType};
+use crate::messages::{format_message, get_messages};
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Sortable List component
+///
+/// List whose children are reordered dragging them with the mouse or the finger, by their handle or by the
+/// whole item when `drag_handle` is false. While an item is dragged the other items move to make room for it
+/// and an outline marks where it will be dropped. The focused handle moves its item with the arrow keys.
+/// The list doesn't reorder the children itself: `onreorder_signal` emits the new order as the previous
+/// indexes of the items, e.g. `[1, 0, 2]` when the first item is moved below the second one
+///
+/// ## Features required
+///
+/// sortable_list
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::sortable_list::SortableList;
+///
+/// pub struct Playlist {
+///     link: ComponentLink<Self>,
+///     songs: Vec<String>,
+/// }
+///
+/// pub enum Msg {
+///     Reorder(Vec<usize>),
+/// }
+///
+/// impl Component for Playlist {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             songs: vec!["Intro".to_string(), "Chorus".to_string(), "Outro".to_string()],
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Reorder(order) => {
+///                 self.songs = order.into_iter().map(|index| self.songs[index].clone()).collect();
+///             }
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SortableList onreorder_signal=self.link.callback(Msg::Reorder)>
+///                 {for self.songs.iter().map(|song| html! {<span>{song}</span>})}
+///             </SortableList>
+///         }
+///     }
+/// }
+/// ```
+pub struct SortableList {
+    link: ComponentLink<Self>,
+    props: Props,
+    drag: Option<Drag>,
+    /// Index of the item moved with the keyboard, its handle is focused again after the reorder
+    moved: Option<usize>,
+}
+
+struct Drag {
+    index: usize,
+    target: usize,
+    start_y: f64,
+    offset: f64,
+    /// Tops of the items relative to the list and their heights when the drag started
+    tops: Vec<f64>,
+    heights: Vec<f64>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Items are dragged by their handle, otherwise by the whole item. Default `true`
+    #[prop_or(true)]
+    pub drag_handle: bool,
+    /// Content of the handles. Default a menu icon
+    #[prop_or_default]
+    pub handle: Option<Html>,
+    /// The items can't be reordered. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Emitted with the previous indexes of the items in their new order
+    #[prop_or(Callback::noop())]
+    pub onreorder_signal: Callback<Vec<usize>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    DragStart(usize, PointerEvent),
+    Drag(PointerEvent),
+    DragEnd,
+    DragCancel,
+    Pressed(usize, KeyboardEvent),
+}
+
+impl Component for SortableList {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            drag: None,
+            moved: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::DragStart(index, pointer_event) => {
+                let on_handle = pointer_event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| target.closest(".sortable-handle").ok().flatten())
+                    .is_some();
+
+                if self.props.disabled
+                    || pointer_event.button() != 0
+                    || (self.props.drag_handle && !on_handle)
+                {
+                    return false;
+                }
+
+                let list = match self.props.code_ref.cast::<Element>() {
+                    Some(list) => list,
+                    None => return false,
+                };
+
+                pointer_event.prevent_default();
+                list.set_pointer_capture(pointer_event.pointer_id()).ok();
+
+                let list_top = list.get_bounding_client_rect().top();
+                let items = list.children();
+                let (tops, heights) = (0..self.props.children.len() as u32)
+                    .filter_map(|item| items.item(item))
+                    .map(|item| {
+                        let rect = item.get_bounding_client_rect();
+                        (rect.top() - list_top, rect.height())
+                    })
+                    .unzip();
+
+                self.drag = Some(Drag {
+                    index,
+                    target: index,
+                    start_y: pointer_event.client_y() as f64,
+                    offset: 0.0,
+                    tops,
+                    heights,
+                });
+            }
+            Msg::Drag(pointer_event) => match self.drag.as_mut() {
+                Some(drag) => {
+                    drag.offset = pointer_event.client_y() as f64 - drag.start_y;
+                    drag.target =
+                        get_drop_index(&drag.tops, &drag.heights, drag.index, drag.offset);
+                }
+                None => return false,
+            },
+            Msg::DragEnd => match self.drag.take() {
+                Some(drag) => {
+                    if drag.target != drag.index {
+                        self.props.onreorder_signal.emit(get_new_order(
+                            self.props.children.len(),
+                            drag.index,
+                            drag.target,
+                        ));
+                    }
+                }
+                None => return false,
+            },
+            Msg::DragCancel => {
+                if self.drag.take().is_none() {
+                    return false;
+                }
+            }
+            Msg::Pressed(index, keyboard_event) => {
+                let len = self.props.children.len();
+                let target = match keyboard_event.key().as_str() {
+                    "ArrowUp" if index > 0 => index - 1,
+                    "ArrowDown" if index + 1 < len => index + 1,
+                    "Escape" => {
+                        self.link.send_message(Msg::DragCancel);
+                        return false;
+                    }
+                    _ => return false,
+                };
+
+                if self.props.disabled {
+                    return false;
+                }
+
+                keyboard_event.prevent_default();
+                self.moved = Some(target);
+                self.props
+                    .onreorder_signal
+                    .emit(get_new_order(len, index, target));
+                announce(
+                    &format_message(
+                        &get_messages().sortable_moved,
+                        &[
+                            ("position", (target + 1).to_string()),
+                            ("total", len.to_string()),
+                        ],
+                    ),
+                    Politeness::Assertive,
+                );
+
+                return false;
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.children.len() != props.children.len() {
+                self.drag = None;
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if let Some(index) = self.moved.take() {
+            let handle = self
+                .props
+                .code_ref
+                .cast::<Element>()
+                .and_then(|list| list.children().item(index as u32))
+                .and_then(|item| item.query_selector(".sortable-handle").ok().flatten())
+                .and_then(|handle| handle.dyn_into::<HtmlElement>().ok());
+
+            if let Some(handle) = handle {
+                handle.focus().ok();
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let dragging = self.drag.is_some();
+
+        html! {
+            <ul
+                class=ComponentClasses::new("sortable-list")
+                    .state("sorting", dragging)
+                    .state("disabled", self.props.disabled)
+                    .state("drag-whole", !self.props.drag_handle)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onpointermove=self.link.callback(Msg::Drag)
+                onpointerup=self.link.callback(|_| Msg::DragEnd)
+                onpointercancel=self.link.callback(|_| Msg::DragCancel)
+            >
+                {for self.props.children.iter().enumerate().map(|(index, child)| self.get_item(index, child))}
+                {self.get_placeholder()}
+            </ul>
+        }
+    }
+}
+
+impl SortableList {
+    fn get_item(&self, index: usize, child: Html) -> Html {
+        let messages = get_messages();
+        let dragged = self.drag.as_ref().map_or(false, |drag| drag.index == index);
+        let transform = self.drag.as_ref().map(|drag| {
+            let offset = if drag.index == index {
+                drag.offset
+            } else {
+                get_shift(
+                    index,
+                    drag.index,
+                    drag.target,
+                    get_span(&drag.tops, &drag.heights, drag.index),
+                )
+            };
+
+            format!("transform: translateY({}px);", offset)
+        });
+
+        html! {
+            <li
+                class=classes!("sortable-item", if dragged { Some("dragging") } else { None })
+                style=transform
+                onpointerdown=self.link.callback(move |pointer_event| Msg::DragStart(index, pointer_event))
+            >
+                <button
+                    type="button"
+                    class="sortable-handle"
+                    aria-label=format_message(&messages.sortable_handle, &[("index", (index + 1).to_string())])
+                    disabled=self.props.disabled
+                    onkeydown=self.link.callback(move |keyboard_event| Msg::Pressed(index, keyboard_event))
+                >
+                    {self.props.handle.clone().unwrap_or_else(|| html! {
+                        <Icon icon_type=IconType::Menu />
+                    })}
+                </button>
+                <div class="sortable-content">{child}</div>
+            </li>
+        }
+    }
+
+    fn get_placeholder(&self) -> Html {
+        match &self.drag {
+            Some(drag) => {
+                let top = if drag.target > drag.index {
+                    drag.tops[drag.target] + drag.heights[drag.target] - drag.heights[drag.index]
+                } else {
+                    drag.tops[drag.target]
+                };
+
+                html! {
+                    <li
+                        class="sortable-placeholder"
+                        aria-hidden="true"
+                        style=format!("top: {}px; height: {}px;", top, drag.heights[drag.index])
+                    ></li>
+                }
+            }
+            None => html! {},
+        }
+    }
+}
+
+/// Index where the dragged item is dropped: the number of the other items whose center is above its center
+fn get_drop_index(tops: &[f64], heights: &[f64], dragged: usize, offset: f64) -> usize {
+    let center = tops[dragged] + heights[dragged] / 2.0 + offset;
+
+    tops.iter()
+        .zip(heights.iter())
+        .enumerate()
+        .filter(|(index, (top, height))| *index != dragged && *top + *height / 2.0 < center)
+        .count()
+}
+
+/// Room taken by the dragged item: its height and the gap between the items
+fn get_span(tops: &[f64], heights: &[f64], dragged: usize) -> f64 {
+    let gap = if tops.len() > 1 {
+        tops[1] - tops[0] - heights[0]
+    } else {
+        0.0
+    };
+
+    heights[dragged] + gap
+}
+
+/// Pixels that an item moves to make room for the dragged item
+fn get_shift(index: usize, dragged: usize, target: usize, span: f64) -> f64 {
+    if dragged < index && index <= target {
+        -span
+    } else if target <= index && index < dragged {
+        span
+    } else {
+        0.0
+    }
+}
+
+/// Previous indexes of the items after moving the item `from` to `to`
+fn get_new_order(len: usize, from: usize, to: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..len).collect();
+    let item = order.remove(from);
+    order.insert(to, item);
+
+    order
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_new_order() {
+    assert_eq!(get_new_order(3, 0, 1), vec![1, 0, 2]);
+    assert_eq!(get_new_order(4, 3, 0), vec![3, 0, 1, 2]);
+    assert_eq!(get_new_order(3, 1, 1), vec![0, 1, 2]);
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_drop_index() {
+    let tops = vec![0.0, 50.0, 100.0];
+    let heights = vec![40.0, 40.0, 40.0];
+
+    assert_eq!(get_drop_index(&tops, &heights, 0, 0.0), 0);
+    assert_eq!(get_drop_index(&tops, &heights, 0, 60.0), 1);
+    assert_eq!(get_drop_index(&tops, &heights, 0, 200.0), 2);
+    assert_eq!(get_drop_index(&tops, &heights, 2, -80.0), 0);
+}
+
+#[wasm_bindgen_test]
+fn should_make_room_for_the_dragged_item() {
+    assert!((get_span(&[0.0, 50.0], &[40.0, 40.0], 0) - 50.0).abs() < f64::EPSILON);
+    assert!((get_shift(1, 0, 2, 50.0) + 50.0).abs() < f64::EPSILON);
+    assert!((get_shift(0, 2, 0, 50.0) - 50.0).abs() < f64::EPSILON);
+    assert!(get_shift(2, 0, 1, 50.0).abs() < f64::EPSILON);
+}
+
+#[wasm_bindgen_test]
+fn should_create_sortable_list() {
+    let props = Props::builder()
+        .id("sortable-list-test".to_string())
+        .children(Children::new(vec![html! {"Intro"}, html! {"Chorus"}]))
+        .build();
+
+    let sortable_list: App<SortableList> = App::new();
+    sortable_list.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let list = utils::document()
+        .get_element_by_id("sortable-list-test")
+        .unwrap();
+    let handle = list.query_selector(".sortable-handle").unwrap().unwrap();
+
+    assert_eq!(list.children().length(), 2);
+    assert_eq!(
+        handle.get_attribute("aria-label"),
+        Some("Move item 1".to_string())
+    );
+}
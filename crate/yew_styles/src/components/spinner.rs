@@ -1,4 +1,5 @@
-use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::messages::get_messages;
+use crate::styles::{get_palette, get_size, tokens::Spaces, ComponentClasses, Palette, Size};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -53,6 +54,7 @@ pub struct Spinner {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpinnerType {
     Plane,
     Chase,
@@ -94,6 +96,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 impl Component for Spinner {
@@ -157,10 +165,20 @@ fn render_spinner_type(spinner_type: &str, dots: u8, props: Props) -> Html {
     }
     html! {
         <div
-            class=classes!(spinner_type.to_owned(), get_palette(props.spinner_palette), get_size(props.spinner_size), props.class_name, props.styles)
+            class=ComponentClasses::new(spinner_type.to_owned())
+                .semantic(vec![
+                    get_palette(props.spinner_palette),
+                    get_size(props.spinner_size),
+                ])
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             ref=props.code_ref
             id=props.id
             key=props.key
+            role="status"
+            aria-label=get_messages().loading
         >
             {vdots.into_iter().collect::<Html>()}
         </div>
@@ -171,16 +189,13 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_spinner() {
-    let spinner_props = Props {
-        spinner_palette: Palette::Clean,
-        spinner_type: SpinnerType::Circle,
-        spinner_size: Size::Medium,
-        code_ref: NodeRef::default(),
-        key: String::from("dropdown-1"),
-        class_name: String::from("class-test"),
-        id: String::from("id-test"),
-        styles: css!("font-size: 50px;"),
-    };
+    let spinner_props = Props::builder()
+        .class_name(String::from("class-test"))
+        .id(String::from("id-test"))
+        .key(String::from("dropdown-1"))
+        .spinner_palette(Palette::Clean)
+        .styles(css!("font-size: 50px;"))
+        .build();
 
     let spinner: App<Spinner> = App::new();
 
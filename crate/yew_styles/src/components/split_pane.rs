@@ -0,0 +1,420 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Split Pane component
+///
+/// Two panels separated by a divider which can be dragged to resize them, side by side or one above the other
+/// when it is `vertical`. The size of the first panel is kept between `min_size` and `max_size`; when it is
+/// `collapsible` dragging the divider past half of the limit collapses the panel to the edge. The divider moves
+/// with the arrow keys, `Home` and `End` move it to the limits and `Enter` collapses and restores the first panel.
+/// The new size is emitted by `onresize_signal`, passing it back as `size` keeps it between visits
+///
+/// ## Features required
+///
+/// split_pane
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::split_pane::SplitPane;
+///
+/// pub struct Editor {
+///     link: ComponentLink<Self>,
+///     sidebar_size: f64,
+/// }
+///
+/// pub enum Msg {
+///     Resize(f64),
+/// }
+///
+/// impl Component for Editor {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             sidebar_size: 25.0,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Resize(size) => self.sidebar_size = size,
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SplitPane
+///                 size=self.sidebar_size
+///                 min_size=15.0
+///                 max_size=50.0
+///                 collapsible=true
+///                 onresize_signal=self.link.callback(Msg::Resize)
+///                 first=html! {<nav>{"Files"}</nav>}
+///                 second=html! {<main>{"Code"}</main>}
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct SplitPane {
+    link: ComponentLink<Self>,
+    props: Props,
+    size: f64,
+    /// Size restored when the collapsed first panel is expanded with the keyboard
+    restore_size: f64,
+    dragging: bool,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Content of the first panel, on the left or on the top. Required
+    pub first: Html,
+    /// Content of the second panel, on the right or on the bottom. Required
+    pub second: Html,
+    /// Initial size of the first panel in percentage. Default `50.0`
+    #[prop_or(50.0)]
+    pub size: f64,
+    /// Minimum size of the first panel in percentage. Default `10.0`
+    #[prop_or(10.0)]
+    pub min_size: f64,
+    /// Maximum size of the first panel in percentage. Default `90.0`
+    #[prop_or(90.0)]
+    pub max_size: f64,
+    /// The first panel collapses to `0` and `100` when the divider is dragged past half of the limits. Default `false`
+    #[prop_or(false)]
+    pub collapsible: bool,
+    /// Percentage moved by the arrow keys. Default `5.0`
+    #[prop_or(5.0)]
+    pub step: f64,
+    /// Place the panels one above the other instead of side by side. Default `false`
+    #[prop_or(false)]
+    pub vertical: bool,
+    /// Emitted with the new size of the first panel in percentage
+    #[prop_or(Callback::noop())]
+    pub onresize_signal: Callback<f64>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    DragStart(PointerEvent),
+    Drag(PointerEvent),
+    DragEnd,
+    Pressed(KeyboardEvent),
+}
+
+impl Component for SplitPane {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let size = get_split_size(
+            props.size,
+            props.min_size,
+            props.max_size,
+            props.collapsible,
+        );
+
+        Self {
+            link,
+            props,
+            size,
+            restore_size: size,
+            dragging: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let size = match msg {
+            Msg::DragStart(pointer_event) => {
+                if pointer_event.button() != 0 {
+                    return false;
+                }
+
+                pointer_event.prevent_default();
+                if let Some(split_pane) = self.props.code_ref.cast::<Element>() {
+                    split_pane
+                        .set_pointer_capture(pointer_event.pointer_id())
+                        .ok();
+                }
+                self.dragging = true;
+                return true;
+            }
+            Msg::Drag(pointer_event) => {
+                if !self.dragging {
+                    return false;
+                }
+                self.get_pointer_size(&pointer_event)
+            }
+            Msg::DragEnd => {
+                if !self.dragging {
+                    return false;
+                }
+                self.dragging = false;
+                return true;
+            }
+            Msg::Pressed(keyboard_event) => {
+                let size = if keyboard_event.key() == "Enter" && self.props.collapsible {
+                    Some(if self.size > 0.0 && self.size < 100.0 {
+                        0.0
+                    } else {
+                        self.restore_size
+                    })
+                } else {
+                    get_keyboard_size(
+                        &keyboard_event.key(),
+                        self.size,
+                        self.props.step,
+                        self.props.vertical,
+                    )
+                    .map(|size| get_limited_size(size, self.props.min_size, self.props.max_size))
+                };
+                if size.is_some() {
+                    keyboard_event.prevent_default();
+                }
+                size
+            }
+        };
+
+        match size {
+            Some(size) if (size - self.size).abs() > f64::EPSILON => {
+                if self.size > 0.0 && self.size < 100.0 {
+                    self.restore_size = self.size;
+                }
+                self.size = size;
+                self.props.onresize_signal.emit(size);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.size != props.size
+                || self.props.min_size != props.min_size
+                || self.props.max_size != props.max_size
+            {
+                self.size = get_split_size(
+                    props.size,
+                    props.min_size,
+                    props.max_size,
+                    props.collapsible,
+                );
+            }
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        let dimension = if self.props.vertical {
+            "height"
+        } else {
+            "width"
+        };
+
+        html! {
+            <div
+                class=ComponentClasses::new("split-pane")
+                    .semantic(if self.props.vertical { "vertical" } else { "horizontal" })
+                    .state("dragging", self.dragging)
+                    .state("collapsed", self.size <= 0.0 || self.size >= 100.0)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onpointermove=self.link.callback(Msg::Drag)
+                onpointerup=self.link.callback(|_| Msg::DragEnd)
+                onpointercancel=self.link.callback(|_| Msg::DragEnd)
+            >
+                <div class="split-pane-first" style=format!("{}: {}%;", dimension, self.size)>
+                    {self.props.first.clone()}
+                </div>
+                <div
+                    class="split-pane-divider"
+                    role="separator"
+                    tabindex="0"
+                    aria-label=get_messages().split_pane
+                    aria-orientation=if self.props.vertical { "horizontal" } else { "vertical" }
+                    aria-valuemin="0"
+                    aria-valuemax="100"
+                    aria-valuenow=self.size.round().to_string()
+                    onpointerdown=self.link.callback(Msg::DragStart)
+                    onkeydown=self.link.callback(Msg::Pressed)
+                >
+                    <span class="split-pane-handle"></span>
+                </div>
+                <div class="split-pane-second">
+                    {self.props.second.clone()}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl SplitPane {
+    fn get_pointer_size(&self, pointer_event: &PointerEvent) -> Option<f64> {
+        let rect = self
+            .props
+            .code_ref
+            .cast::<Element>()?
+            .get_bounding_client_rect();
+        let position = if self.props.vertical {
+            get_pointer_percentage(pointer_event.client_y() as f64 - rect.top(), rect.height())
+        } else {
+            get_pointer_percentage(pointer_event.client_x() as f64 - rect.left(), rect.width())
+        };
+
+        Some(get_split_size(
+            position,
+            self.props.min_size,
+            self.props.max_size,
+            self.props.collapsible,
+        ))
+    }
+}
+
+fn get_pointer_percentage(offset: f64, size: f64) -> f64 {
+    if size <= 0.0 {
+        return 0.0;
+    }
+
+    (offset / size * 100.0).clamp(0.0, 100.0)
+}
+
+/// Size of the first panel within the limits, collapsed to the edge past half of the limits when it is collapsible
+fn get_split_size(position: f64, min_size: f64, max_size: f64, collapsible: bool) -> f64 {
+    let max_size = max_size.max(min_size);
+
+    if collapsible && position < min_size / 2.0 {
+        0.0
+    } else if collapsible && position > (max_size + 100.0) / 2.0 {
+        100.0
+    } else {
+        get_limited_size(position, min_size, max_size)
+    }
+}
+
+/// Size between the limits, an inverted `max_size` is raised to `min_size` so the clamp can't panic
+fn get_limited_size(size: f64, min_size: f64, max_size: f64) -> f64 {
+    size.clamp(min_size, max_size.max(min_size))
+}
+
+fn get_keyboard_size(key: &str, size: f64, step: f64, vertical: bool) -> Option<f64> {
+    match key {
+        "ArrowLeft" if !vertical => Some(size - step),
+        "ArrowUp" if vertical => Some(size - step),
+        "ArrowRight" if !vertical => Some(size + step),
+        "ArrowDown" if vertical => Some(size + step),
+        "Home" => Some(0.0),
+        "End" => Some(100.0),
+        _ => None,
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_split_size() {
+    assert_eq!(get_split_size(50.0, 10.0, 90.0, false), 50.0);
+    assert_eq!(get_split_size(2.0, 10.0, 90.0, false), 10.0);
+    assert_eq!(get_split_size(2.0, 10.0, 90.0, true), 0.0);
+    assert_eq!(get_split_size(7.0, 10.0, 90.0, true), 10.0);
+    assert_eq!(get_split_size(97.0, 10.0, 90.0, true), 100.0);
+    assert_eq!(get_pointer_percentage(50.0, 200.0), 25.0);
+    assert_eq!(get_keyboard_size("ArrowDown", 50.0, 5.0, true), Some(55.0));
+    assert_eq!(get_keyboard_size("ArrowDown", 50.0, 5.0, false), None);
+}
+
+#[wasm_bindgen_test]
+fn should_keep_the_size_with_inverted_limits() {
+    assert_eq!(get_split_size(50.0, 95.0, 90.0, false), 95.0);
+    assert_eq!(get_split_size(99.0, 95.0, 90.0, true), 100.0);
+    assert_eq!(get_limited_size(20.0, 95.0, 90.0), 95.0);
+
+    let props = Props::builder()
+        .first(html! {"Files"})
+        .id("split-pane-inverted-test".to_string())
+        .min_size(95.0)
+        .second(html! {"Code"})
+        .build();
+
+    let split_pane: App<SplitPane> = App::new();
+    split_pane.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let first_panel = utils::document()
+        .get_element_by_id("split-pane-inverted-test")
+        .unwrap()
+        .first_element_child()
+        .unwrap();
+
+    assert_eq!(first_panel.get_attribute("style").unwrap(), "width: 95%;");
+}
+
+#[wasm_bindgen_test]
+fn should_create_split_pane() {
+    let props = Props::builder()
+        .first(html! {"Files"})
+        .id("split-pane-test".to_string())
+        .max_size(80.0)
+        .min_size(20.0)
+        .second(html! {"Code"})
+        .size(5.0)
+        .build();
+
+    let split_pane: App<SplitPane> = App::new();
+    split_pane.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let first_panel = utils::document()
+        .get_element_by_id("split-pane-test")
+        .unwrap()
+        .first_element_child()
+        .unwrap();
+
+    assert_eq!(first_panel.get_attribute("style").unwrap(), "width: 20%;");
+}
@@ -0,0 +1,281 @@
+use crate::icon::{Icon, IconType};
+use crate::messages::{format_message, get_messages};
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette, Size};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Stat component
+///
+/// Key metric for the headers of the dashboards: a label, the value in a large font and
+/// optionally the change since the previous period with an arrow. A rising delta is shown
+/// with the success palette and a falling one with the danger palette, `inverted` swaps them
+/// for the metrics where lower is better, like costs or errors.
+/// The stat fills the height of its parent, so the stats of an `Item` row have the same height
+///
+/// ## Features required
+///
+/// stat
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::icon::{Icon, IconType};
+/// use yew_styles::layouts::{
+///     container::{Container, Direction, Wrap},
+///     item::{Item, ItemLayout},
+/// };
+/// use yew_styles::stat::{Stat, Trend};
+///
+/// pub struct Dashboard;
+///
+/// impl Component for Dashboard {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Container direction=Direction::Row wrap=Wrap::Wrap>
+///                 <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(6))>
+///                     <Stat
+///                         label="Revenue"
+///                         value="€48,250"
+///                         delta="12%"
+///                         trend=Trend::Up
+///                         caption="vs last month"
+///                         icon=html! {<Icon icon_type=IconType::Star />}
+///                     />
+///                 </Item>
+///                 <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(6))>
+///                     <Stat label="Failed payments" value="32" delta="4%" trend=Trend::Down inverted=true />
+///                 </Item>
+///             </Container>
+///         }
+///     }
+/// }
+/// ```
+pub struct Stat {
+    props: Props,
+}
+
+/// Direction of the change of the metric
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Trend {
+    Up,
+    Down,
+    Neutral,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Name of the metric. Required
+    pub label: String,
+    /// Value of the metric, already formatted. Required
+    pub value: String,
+    /// Change since the previous period, e.g. `"12%"`. Default `None`
+    #[prop_or_default]
+    pub delta: Option<String>,
+    /// Direction of the change, it sets the arrow and the palette of the delta. Default `Trend::Neutral`
+    #[prop_or(Trend::Neutral)]
+    pub trend: Trend,
+    /// Lower is better, a falling delta is shown with the success palette. Default `false`
+    #[prop_or(false)]
+    pub inverted: bool,
+    /// Text after the delta, e.g. `"vs last month"`. Default `None`
+    #[prop_or_default]
+    pub caption: Option<String>,
+    /// Icon or illustration shown next to the label. Default `None`
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Palette of the background of the icon. Default `Palette::Primary`
+    #[prop_or(Palette::Primary)]
+    pub icon_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+impl Component for Stat {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("stat")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <div class="stat-header">
+                    <span class="stat-label">{&self.props.label}</span>
+                    {if let Some(icon) = self.props.icon.clone() {
+                        html! {
+                            <span
+                                class=classes!("stat-icon", get_palette(self.props.icon_palette.clone()))
+                                aria-hidden="true"
+                            >
+                                {icon}
+                            </span>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+                <div class="stat-value">{&self.props.value}</div>
+                {self.get_delta()}
+            </div>
+        }
+    }
+}
+
+impl Stat {
+    fn get_delta(&self) -> Html {
+        let delta = match self.props.delta.clone() {
+            Some(delta) => delta,
+            None => return html! {},
+        };
+        let messages = get_messages();
+        let (icon_type, description) = match self.props.trend {
+            Trend::Up => (
+                Some(IconType::ArrowUp),
+                format_message(&messages.stat_increase, &[("delta", delta.clone())]),
+            ),
+            Trend::Down => (
+                Some(IconType::ArrowDown),
+                format_message(&messages.stat_decrease, &[("delta", delta.clone())]),
+            ),
+            Trend::Neutral => (None, delta.clone()),
+        };
+
+        html! {
+            <div class="stat-footer">
+                <span class=classes!("stat-delta", get_trend_palette(self.props.trend, self.props.inverted))>
+                    {if let Some(icon_type) = icon_type {
+                        html! {
+                            <Icon icon_type=icon_type icon_size=Size::Small />
+                        }
+                    } else {
+                        html! {}
+                    }}
+                    <span class="stat-delta-text" aria-hidden="true">{delta}</span>
+                    <span class="stat-delta-description">{description}</span>
+                </span>
+                {if let Some(caption) = self.props.caption.clone() {
+                    html! {
+                        <span class="stat-caption">{caption}</span>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+/// Palette class of the delta: success when the metric improves and danger when it gets worse
+pub fn get_trend_palette(trend: Trend, inverted: bool) -> String {
+    match (trend, inverted) {
+        (Trend::Up, false) | (Trend::Down, true) => get_palette(Palette::Success),
+        (Trend::Down, false) | (Trend::Up, true) => get_palette(Palette::Danger),
+        (Trend::Neutral, _) => get_palette(Palette::Standard),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_delta_with_the_trend() {
+    let props = Props::builder()
+        .caption(Some("vs last month".to_string()))
+        .delta(Some("12%".to_string()))
+        .id("stat-test".to_string())
+        .label("Revenue".to_string())
+        .trend(Trend::Up)
+        .value("€48,250".to_string())
+        .build();
+
+    let stat: App<Stat> = App::new();
+    stat.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let stat_element = utils::document().get_element_by_id("stat-test").unwrap();
+    let delta = stat_element
+        .get_elements_by_class_name("stat-delta")
+        .get_with_index(0)
+        .unwrap();
+
+    assert!(delta.class_list().contains("success"));
+    assert_eq!(
+        delta
+            .get_elements_by_class_name("stat-delta-description")
+            .get_with_index(0)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Increased by 12%"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_invert_the_trend_palette() {
+    assert_eq!(get_trend_palette(Trend::Down, false), "danger");
+    assert_eq!(get_trend_palette(Trend::Down, true), "success");
+    assert_eq!(get_trend_palette(Trend::Neutral, true), "standard");
+}
@@ -0,0 +1,504 @@
+use crate::messages::{format_message, get_messages};
+use crate::styles::{get_palette, tokens::Spaces, ComponentClasses, Palette};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Status page component
+///
+/// System status of an application: the overall status, a row for each service with its
+/// current status and a strip with its uptime in the last days, the past incidents, which
+/// are expanded to see their updates, and a slot for a subscribe form. The statuses and the
+/// uptime segments are colored with the success, info, warning and danger palettes
+///
+/// ## Features required
+///
+/// status_page
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::status_page::{
+///     DayUptime, Incident, IncidentUpdate, ServiceRow, ServiceStatus, StatusPage,
+/// };
+///
+/// pub struct Status;
+///
+/// impl Component for Status {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <StatusPage
+///                 services=vec![
+///                     ServiceRow {
+///                         name: "API".to_string(),
+///                         status: ServiceStatus::Operational,
+///                         uptime: vec![
+///                             DayUptime { date: "2021-06-01".to_string(), uptime: Some(100.0) },
+///                             DayUptime { date: "2021-06-02".to_string(), uptime: Some(98.5) },
+///                         ],
+///                     },
+///                 ]
+///                 incidents=vec![
+///                     Incident {
+///                         title: "Slow responses of the API".to_string(),
+///                         impact: ServiceStatus::Degraded,
+///                         date: "2021-06-02".to_string(),
+///                         resolved: true,
+///                         updates: vec![IncidentUpdate {
+///                             date: "2021-06-02 10:30".to_string(),
+///                             message: "The database has been scaled up".to_string(),
+///                         }],
+///                     },
+///                 ]
+///                 subscribe=html! {
+///                     <form>
+///                         <input type="email" placeholder="Email"/>
+///                         <button>{"Subscribe"}</button>
+///                     </form>
+///                 }
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct StatusPage {
+    props: Props,
+}
+
+/// Status of a service, from the least to the most severe
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServiceStatus {
+    Operational,
+    Maintenance,
+    Degraded,
+    PartialOutage,
+    MajorOutage,
+}
+
+impl ServiceStatus {
+    /// Palette of the status chips
+    pub fn palette(&self) -> Palette {
+        match self {
+            ServiceStatus::Operational => Palette::Success,
+            ServiceStatus::Maintenance => Palette::Info,
+            ServiceStatus::Degraded | ServiceStatus::PartialOutage => Palette::Warning,
+            ServiceStatus::MajorOutage => Palette::Danger,
+        }
+    }
+
+    /// Name of the status from the messages
+    pub fn label(&self) -> String {
+        let messages = get_messages();
+
+        match self {
+            ServiceStatus::Operational => messages.status_operational,
+            ServiceStatus::Maintenance => messages.status_maintenance,
+            ServiceStatus::Degraded => messages.status_degraded,
+            ServiceStatus::PartialOutage => messages.status_partial_outage,
+            ServiceStatus::MajorOutage => messages.status_major_outage,
+        }
+    }
+}
+
+/// Uptime of a service in a day
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayUptime {
+    /// Date shown in the segment title
+    pub date: String,
+    /// Percentage of the day the service was up, `None` when there is no data
+    pub uptime: Option<f64>,
+}
+
+/// Service shown in the status page
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceRow {
+    pub name: String,
+    pub status: ServiceStatus,
+    /// Uptime of each day, from the oldest to the newest
+    pub uptime: Vec<DayUptime>,
+}
+
+/// Update published while an incident is solved
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncidentUpdate {
+    pub date: String,
+    pub message: String,
+}
+
+/// Past or ongoing incident
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Incident {
+    pub title: String,
+    pub impact: ServiceStatus,
+    pub date: String,
+    pub resolved: bool,
+    pub updates: Vec<IncidentUpdate>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Services of the system. Required
+    pub services: Vec<ServiceRow>,
+    /// Incidents, from the newest to the oldest. Default `vec![]`
+    #[prop_or_default]
+    pub incidents: Vec<Incident>,
+    /// Number of days of the uptime strip. Default `90`
+    #[prop_or(90)]
+    pub days: usize,
+    /// Content of the subscribe section, e.g. a form to get the incidents by email. Default `None`
+    #[prop_or_default]
+    pub subscribe: Option<Html>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+impl Component for StatusPage {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+        let overall_status = get_overall_status(&self.props.services);
+
+        html! {
+            <div
+                class=ComponentClasses::new("status-page")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <div class=classes!("status-page-overall", get_palette(overall_status.palette())) role="status">
+                    {if overall_status == ServiceStatus::Operational {
+                        messages.all_operational.clone()
+                    } else {
+                        overall_status.label()
+                    }}
+                </div>
+                <ul class="status-page-services">
+                    {for self.props.services.iter().map(|service| self.get_service(service))}
+                </ul>
+                <section class="status-page-incidents">
+                    <h2 class="status-page-title">{messages.past_incidents.clone()}</h2>
+                    {if self.props.incidents.is_empty() {
+                        html! {
+                            <p class="status-page-empty">{messages.no_incidents.clone()}</p>
+                        }
+                    } else {
+                        html! {
+                            <>{for self.props.incidents.iter().map(get_incident)}</>
+                        }
+                    }}
+                </section>
+                {if let Some(subscribe) = self.props.subscribe.clone() {
+                    html! {
+                        <section class="status-page-subscribe">{subscribe}</section>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+impl StatusPage {
+    fn get_service(&self, service: &ServiceRow) -> Html {
+        let messages = get_messages();
+        let days = get_last_days(&service.uptime, self.props.days);
+        let uptime = get_average_uptime(&days)
+            .map(|uptime| format_message(&messages.uptime, &[("uptime", format_uptime(uptime))]))
+            .unwrap_or(messages.no_data);
+
+        html! {
+            <li class="status-page-service">
+                <div class="status-page-service-header">
+                    <span class="status-page-service-name">{service.name.clone()}</span>
+                    <span class=classes!("status-page-chip", get_palette(service.status.palette()))>
+                        {service.status.label()}
+                    </span>
+                </div>
+                <div
+                    class="status-page-uptime"
+                    role="img"
+                    aria-label=format!("{}: {}", service.name, uptime)
+                    style=format!("grid-template-columns: repeat({}, 1fr)", self.props.days)
+                >
+                    {for days.iter().map(get_day)}
+                </div>
+                <div class="status-page-uptime-total">{uptime}</div>
+            </li>
+        }
+    }
+}
+
+fn get_day(day: &Option<DayUptime>) -> Html {
+    let messages = get_messages();
+    let uptime = day.as_ref().and_then(|day| day.uptime);
+    let title = match day {
+        Some(day) => format!(
+            "{}: {}",
+            day.date,
+            uptime
+                .map(|uptime| format_message(
+                    &messages.uptime,
+                    &[("uptime", format_uptime(uptime))]
+                ))
+                .unwrap_or(messages.no_data)
+        ),
+        None => messages.no_data,
+    };
+
+    html! {
+        <span class=classes!("status-page-day", get_day_class(uptime)) title=title></span>
+    }
+}
+
+fn get_incident(incident: &Incident) -> Html {
+    let messages = get_messages();
+
+    html! {
+        <details class="status-page-incident">
+            <summary>
+                <span class="status-page-incident-title">{incident.title.clone()}</span>
+                <span class=classes!("status-page-chip", get_palette(incident.impact.palette()))>
+                    {incident.impact.label()}
+                </span>
+                {if incident.resolved {
+                    html! {
+                        <span class=classes!("status-page-chip", "success")>{messages.resolved}</span>
+                    }
+                } else {
+                    html! {}
+                }}
+                <time class="status-page-date">{incident.date.clone()}</time>
+            </summary>
+            <ul class="status-page-updates">
+                {for incident.updates.iter().map(|update| html! {
+                    <li class="status-page-update">
+                        <time class="status-page-date">{update.date.clone()}</time>
+                        <p>{update.message.clone()}</p>
+                    </li>
+                })}
+            </ul>
+        </details>
+    }
+}
+
+/// Most severe status of the services
+pub fn get_overall_status(services: &[ServiceRow]) -> ServiceStatus {
+    services.iter().map(|service| service.status).fold(
+        ServiceStatus::Operational,
+        |overall, status| {
+            if status > overall {
+                status
+            } else {
+                overall
+            }
+        },
+    )
+}
+
+/// Average uptime of the days with data, `None` when no day has data
+pub fn get_average_uptime(days: &[Option<DayUptime>]) -> Option<f64> {
+    let uptimes: Vec<f64> = days
+        .iter()
+        .filter_map(|day| day.as_ref().and_then(|day| day.uptime))
+        .collect();
+
+    if uptimes.is_empty() {
+        None
+    } else {
+        Some(uptimes.iter().sum::<f64>() / uptimes.len() as f64)
+    }
+}
+
+/// Last `days` days of the uptime, the missing days at the beginning are `None`
+fn get_last_days(uptime: &[DayUptime], days: usize) -> Vec<Option<DayUptime>> {
+    let missing = days.saturating_sub(uptime.len());
+
+    (0..missing)
+        .map(|_| None)
+        .chain(
+            uptime[uptime.len().saturating_sub(days)..]
+                .iter()
+                .cloned()
+                .map(Some),
+        )
+        .collect()
+}
+
+fn get_day_class(uptime: Option<f64>) -> String {
+    match uptime {
+        None => String::from("no-data"),
+        Some(uptime) if uptime >= 99.9 => get_palette(Palette::Success),
+        Some(uptime) if uptime >= 99.0 => get_palette(Palette::Warning),
+        Some(_) => get_palette(Palette::Danger),
+    }
+}
+
+fn format_uptime(uptime: f64) -> String {
+    format!("{:.2}", uptime)
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_render_the_services_and_the_incidents() {
+    let props = Props::builder()
+        .id("status-page-test".to_string())
+        .incidents(vec![Incident {
+            title: "Dashboard not loading".to_string(),
+            impact: ServiceStatus::PartialOutage,
+            date: "2021-06-02".to_string(),
+            resolved: false,
+            updates: vec![IncidentUpdate {
+                date: "2021-06-02 10:30".to_string(),
+                message: "Investigating".to_string(),
+            }],
+        }])
+        .services(vec![
+            ServiceRow {
+                name: "API".to_string(),
+                status: ServiceStatus::Operational,
+                uptime: vec![DayUptime {
+                    date: "2021-06-01".to_string(),
+                    uptime: Some(100.0),
+                }],
+            },
+            ServiceRow {
+                name: "Dashboard".to_string(),
+                status: ServiceStatus::PartialOutage,
+                uptime: vec![],
+            },
+        ])
+        .build();
+
+    let status_page: App<StatusPage> = App::new();
+    status_page.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let status_page_element = utils::document()
+        .get_element_by_id("status-page-test")
+        .unwrap();
+
+    assert_eq!(
+        status_page_element
+            .get_elements_by_class_name("status-page-day")
+            .length(),
+        180
+    );
+    assert_eq!(
+        status_page_element
+            .get_elements_by_class_name("status-page-incident")
+            .length(),
+        1
+    );
+    assert_eq!(
+        status_page_element
+            .get_elements_by_class_name("status-page-overall")
+            .get_with_index(0)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Partial outage"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_overall_status() {
+    let service = |status| ServiceRow {
+        name: "Service".to_string(),
+        status,
+        uptime: vec![],
+    };
+
+    assert_eq!(get_overall_status(&[]), ServiceStatus::Operational);
+    assert_eq!(
+        get_overall_status(&[
+            service(ServiceStatus::Degraded),
+            service(ServiceStatus::MajorOutage),
+            service(ServiceStatus::Maintenance),
+        ]),
+        ServiceStatus::MajorOutage
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_average_uptime() {
+    let day = |uptime| {
+        Some(DayUptime {
+            date: "2021-06-01".to_string(),
+            uptime,
+        })
+    };
+
+    assert_eq!(
+        get_average_uptime(&[day(Some(100.0)), day(None), day(Some(99.0)), None]),
+        Some(99.5)
+    );
+    assert_eq!(get_average_uptime(&[day(None), None]), None);
+}
@@ -0,0 +1,284 @@
+use crate::styles::{
+    get_elevation,
+    tokens::{Elevation, Spaces},
+    ComponentClasses,
+};
+use crate::utils::is_browser;
+use stylist::{css, StyleSource};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::{Element, EventTarget};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Sticky component
+///
+/// Keeps its children, e.g. a header or a toolbar, at the top of the page or of the scrollable `container`
+/// while the content below them scrolls. Unlike `Affix` it stays inside of its parent: it stops sticking
+/// when the end of the parent is reached. The stuck children get the class `stuck` and the shadow of `elevation`
+///
+/// ## Features required
+///
+/// sticky
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::sticky::Sticky;
+/// use yew_styles::styles::tokens::Elevation;
+///
+/// pub struct Report {
+///     link: ComponentLink<Self>,
+///     stuck: bool,
+/// }
+///
+/// pub enum Msg {
+///     Stick(bool),
+/// }
+///
+/// impl Component for Report {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link, stuck: false }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Stick(stuck) => self.stuck = stuck,
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Sticky
+///                     offset_top=64
+///                     elevation=Elevation::Level3
+///                     onstick_signal=self.link.callback(Msg::Stick)
+///                 >
+///                     <div class="toolbar">{"Filters"}</div>
+///                 </Sticky>
+///                 <table>{"A long table"}</table>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Sticky {
+    link: ComponentLink<Self>,
+    props: Props,
+    stuck: bool,
+    sentinel_ref: NodeRef,
+    listener: Option<(EventTarget, Closure<dyn Fn(Event)>)>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Pixels from the top where the children stick, e.g. the height of a fixed navbar. Default `0`
+    #[prop_or(0)]
+    pub offset_top: i32,
+    /// Scrollable element where the children stick, the page when it isn't set. Default `NodeRef::default()`
+    #[prop_or_default]
+    pub container: NodeRef,
+    /// Shadow level of the stuck children. Default `Elevation::Level2`
+    #[prop_or(Elevation::Level2)]
+    pub elevation: Elevation,
+    /// Emitted with `true` when the children stick and `false` when they return to their place
+    #[prop_or(Callback::noop())]
+    pub onstick_signal: Callback<bool>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Scrolled,
+}
+
+impl Component for Sticky {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            stuck: false,
+            sentinel_ref: NodeRef::default(),
+            listener: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Scrolled => {
+                let sentinel_top = match self.sentinel_ref.cast::<Element>() {
+                    Some(sentinel) => sentinel.get_bounding_client_rect().top(),
+                    None => return false,
+                };
+                let container_top = self
+                    .props
+                    .container
+                    .cast::<Element>()
+                    .map(|container| container.get_bounding_client_rect().top())
+                    .unwrap_or(0.0);
+                let stuck = is_stuck(sentinel_top, container_top, self.props.offset_top);
+
+                if stuck == self.stuck {
+                    return false;
+                }
+
+                self.stuck = stuck;
+                self.props.onstick_signal.emit(stuck);
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let container_changed = self.props.container != props.container;
+            self.props = props;
+
+            if container_changed {
+                self.stop_listening();
+                self.listen();
+            }
+
+            self.link.send_message(Msg::Scrolled);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.listen();
+            self.link.send_message(Msg::Scrolled);
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.stop_listening();
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <div class="sticky-sentinel" aria-hidden="true" ref=self.sentinel_ref.clone()></div>
+                <div
+                    class=ComponentClasses::new("sticky")
+                        .semantic(if self.stuck { Some(get_elevation(self.props.elevation)) } else { None })
+                        .state("stuck", self.stuck)
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
+                    style=format!("top: {}px;", self.props.offset_top)
+                    id=self.props.id.clone()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                >
+                    {self.props.children.clone()}
+                </div>
+            </>
+        }
+    }
+}
+
+impl Sticky {
+    fn listen(&mut self) {
+        if self.listener.is_some() || !is_browser() {
+            return;
+        }
+
+        let target: EventTarget = match self.props.container.cast::<Element>() {
+            Some(container) => container.into(),
+            None => utils::window().into(),
+        };
+        let link = self.link.clone();
+        let listener = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Scrolled)) as Box<dyn Fn(Event)>
+        );
+
+        target
+            .add_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+            .ok();
+        self.listener = Some((target, listener));
+    }
+
+    fn stop_listening(&mut self) {
+        if let Some((target, listener)) = self.listener.take() {
+            target
+                .remove_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+                .ok();
+        }
+    }
+}
+
+/// The children are stuck once the empty element placed before them scrolls past the offset
+fn is_stuck(sentinel_top: f64, container_top: f64, offset_top: i32) -> bool {
+    sentinel_top < container_top + offset_top as f64
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_stick_past_the_offset() {
+    assert!(is_stuck(40.0, 0.0, 64));
+    assert!(!is_stuck(64.0, 0.0, 64));
+    assert!(is_stuck(90.0, 100.0, 0));
+    assert!(!is_stuck(120.0, 100.0, 0));
+}
+
+#[wasm_bindgen_test]
+fn should_not_stick_in_its_place() {
+    let props = Props::builder()
+        .id("sticky-test".to_string())
+        .offset_top(-100000)
+        .children(Children::new(vec![html! {"Filters"}]))
+        .build();
+
+    let sticky: App<Sticky> = App::new();
+    sticky.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let sticky_element = utils::document().get_element_by_id("sticky-test").unwrap();
+
+    assert!(!sticky_element.class_list().contains("stuck"));
+    assert_eq!(
+        sticky_element.get_attribute("style"),
+        Some("top: -100000px;".to_string())
+    );
+}
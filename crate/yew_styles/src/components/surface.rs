@@ -0,0 +1,161 @@
+use crate::styles::{
+    get_elevation, get_palette,
+    tokens::{Elevation, Radius, Spaces},
+    ComponentClasses, Palette,
+};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Surface component
+///
+/// Generic container with the elevation, border radius and background of the theme,
+/// so your own panels have the same depth than the cards, dropdown menus and modals
+///
+/// ## Features required
+///
+/// surface
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::styles::tokens::{Elevation, Radius, Spacing};
+/// use yew_styles::surface::Surface;
+///
+/// pub struct Panel;
+///
+/// impl Component for Panel {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Surface elevation=Elevation::Level3 radius=Radius::Large padding=Spacing::Large>
+///                 {"Floating panel"}
+///             </Surface>
+///         }
+///     }
+/// }
+/// ```
+pub struct Surface {
+    props: Props,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Shadow level of the surface. Default `Elevation::Level1`
+    #[prop_or(Elevation::Level1)]
+    pub elevation: Elevation,
+    /// Border radius of the surface. Default `Radius::Medium`
+    #[prop_or(Radius::Medium)]
+    pub radius: Radius,
+    /// Type surface purpose style. Default `Palette::Clean`
+    #[prop_or(Palette::Clean)]
+    pub surface_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for Surface {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("surface")
+                    .semantic(vec![
+                        get_elevation(self.props.elevation),
+                        format!("radius-{}", self.props.radius.name()),
+                        get_palette(self.props.surface_palette.clone()),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_add_the_elevation_class() {
+    let props = Props::builder()
+        .elevation(Elevation::Level3)
+        .id("surface-test".to_string())
+        .radius(Radius::Large)
+        .children(Children::new(vec![html! {<span>{"Content"}</span>}]))
+        .build();
+
+    let surface: App<Surface> = App::new();
+    surface.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let class_list = utils::document()
+        .get_element_by_id("surface-test")
+        .unwrap()
+        .class_list();
+
+    assert!(class_list.contains("elevation-3"));
+    assert!(class_list.contains("radius-large"));
+}
@@ -1,4 +1,6 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::styles::{
+    get_palette, get_size, get_style, tokens::Spaces, ComponentClasses, Palette, Size, Style,
+};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -158,6 +160,7 @@ pub struct Text {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Header {
     H1,
     H2,
@@ -168,6 +171,7 @@ pub enum Header {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextType {
     Title(Header),
     Plain,
@@ -249,6 +253,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
 }
 
 pub enum Msg {
@@ -339,7 +349,12 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
         TextType::Plain => {
             html! {
                 <span
-                    class=classes!("plain-text", get_size(props.text_size), props.class_name, props.styles)
+                    class=ComponentClasses::new("plain-text")
+                        .semantic(get_size(props.text_size))
+                        .spacing(props.margin, props.padding)
+                        .styles(props.styles)
+                        .class_name(props.class_name)
+                        .build()
                     id=props.id
                     key=props.key
                     ref=props.code_ref
@@ -349,7 +364,12 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
         TextType::Paragraph => {
             html! {
                 <p
-                    class=classes!("paragraph-text", get_size(props.text_size), props.class_name, props.styles)
+                    class=ComponentClasses::new("paragraph-text")
+                        .semantic(get_size(props.text_size))
+                        .spacing(props.margin, props.padding)
+                        .styles(props.styles)
+                        .class_name(props.class_name)
+                        .build()
                     id=props.id
                     key=props.key
                     ref=props.code_ref
@@ -359,14 +379,16 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
         TextType::Alert => {
             html! {
                 <div
-                    class=classes!(
-                        "alert-text",
-                        get_style(props.text_style),
-                        get_palette(props.text_palette),
-                        get_size(props.text_size),
-                        props.class_name,
-                        props.styles
-                    )
+                    class=ComponentClasses::new("alert-text")
+                        .semantic(vec![
+                            get_style(props.text_style),
+                            get_palette(props.text_palette),
+                            get_size(props.text_size),
+                        ])
+                        .spacing(props.margin, props.padding)
+                        .styles(props.styles)
+                        .class_name(props.class_name)
+                        .build()
                     id =props.id
                     key=props.key
                     ref=props.code_ref
@@ -378,19 +400,17 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
         TextType::Tag => {
             html! {
                 <div
-                    class=classes!(
-                        "tag-text",
-                        if props.interaction_effect {
-                            "interaction"
-                        } else {
-                            ""
-                        },
-                        get_style(props.text_style),
-                        get_palette(props.text_palette),
-                        get_size(props.text_size.clone()),
-                        props.class_name,
-                        props.styles,
-                    )
+                    class=ComponentClasses::new("tag-text")
+                        .semantic(vec![
+                            get_style(props.text_style),
+                            get_palette(props.text_palette),
+                            get_size(props.text_size.clone()),
+                        ])
+                        .state("interaction", props.interaction_effect)
+                        .spacing(props.margin, props.padding)
+                        .styles(props.styles)
+                        .class_name(props.class_name)
+                        .build()
                     id =props.id
                     key=props.key
                     ref=props.code_ref
@@ -437,37 +457,61 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
 fn get_header(header: Header, props: Props) -> Html {
     match header {
         Header::H1 => html! {<h1
-            class=classes!("header-text", props.class_name, props.styles)
+            class=ComponentClasses::new("header-text")
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             id=props.id
             key=props.key
             ref=props.code_ref
         >{get_content(props.plain_text, props.html_text)}</h1>},
         Header::H2 => html! {<h2
-            class=classes!("header-text", props.class_name, props.styles)
+            class=ComponentClasses::new("header-text")
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             id=props.id
             key=props.key
             ref=props.code_ref
         >{get_content(props.plain_text, props.html_text)}</h2>},
         Header::H3 => html! {<h3
-            class=classes!("header-text", props.class_name, props.styles)
+            class=ComponentClasses::new("header-text")
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             id=props.id
             key=props.key
             ref=props.code_ref
         >{get_content(props.plain_text, props.html_text)}</h3>},
         Header::H4 => html! {<h4
-            class=classes!("header-text", props.class_name, props.styles)
+            class=ComponentClasses::new("header-text")
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             id=props.id
             key=props.key
             ref=props.code_ref
         >{get_content(props.plain_text, props.html_text)}</h4>},
         Header::H5 => html! {<h5
-            class=classes!("header-text", props.class_name, props.styles)
+            class=ComponentClasses::new("header-text")
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             id=props.id
             key=props.key
             ref=props.code_ref
         >{get_content(props.plain_text, props.html_text)}</h5>},
         Header::H6 => html! {<h6
-            class=classes!("header-text", props.class_name, props.styles)
+            class=ComponentClasses::new("header-text")
+                .spacing(props.margin, props.padding)
+                .styles(props.styles)
+                .class_name(props.class_name)
+                .build()
             id=props.id
             key=props.key
             ref=props.code_ref
@@ -479,32 +523,15 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
 fn should_create_plain_text() {
-    let props = Props {
-        text_type: TextType::Plain,
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        ondelete_signal: Callback::noop(),
-        draggable: false,
-        removable: false,
-        plain_text: "hello test".to_string(),
-        html_text: None,
-        text_palette: Palette::Primary,
-        text_style: Style::Regular,
-        text_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("color: blue;"),
-        id: "id-text-test".to_string(),
-    };
+    let props = Props::builder()
+        .class_name("class-card-test".to_string())
+        .html_text(None)
+        .id("id-text-test".to_string())
+        .plain_text("hello test".to_string())
+        .styles(css!("color: blue;"))
+        .text_palette(Palette::Primary)
+        .text_type(TextType::Plain)
+        .build();
 
     let text: App<Text> = App::new();
 
@@ -522,32 +549,15 @@ fn should_create_plain_text() {
 
 #[wasm_bindgen_test]
 fn should_create_paragraph_text() {
-    let props = Props {
-        text_type: TextType::Paragraph,
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        ondelete_signal: Callback::noop(),
-        draggable: false,
-        removable: false,
-        plain_text: "hello test".to_string(),
-        html_text: None,
-        text_palette: Palette::Primary,
-        text_style: Style::Regular,
-        text_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("color: blue;"),
-        id: "id-text-test".to_string(),
-    };
+    let props = Props::builder()
+        .class_name("class-card-test".to_string())
+        .html_text(None)
+        .id("id-text-test".to_string())
+        .plain_text("hello test".to_string())
+        .styles(css!("color: blue;"))
+        .text_palette(Palette::Primary)
+        .text_type(TextType::Paragraph)
+        .build();
 
     let text: App<Text> = App::new();
 
@@ -565,32 +575,15 @@ fn should_create_paragraph_text() {
 
 #[wasm_bindgen_test]
 fn should_create_alert_text() {
-    let props = Props {
-        text_type: TextType::Alert,
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        ondelete_signal: Callback::noop(),
-        draggable: false,
-        removable: false,
-        plain_text: "hello test".to_string(),
-        html_text: None,
-        text_palette: Palette::Primary,
-        text_style: Style::Regular,
-        text_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("color: blue;"),
-        id: "id-text-test".to_string(),
-    };
+    let props = Props::builder()
+        .class_name("class-card-test".to_string())
+        .html_text(None)
+        .id("id-text-test".to_string())
+        .plain_text("hello test".to_string())
+        .styles(css!("color: blue;"))
+        .text_palette(Palette::Primary)
+        .text_type(TextType::Alert)
+        .build();
 
     let text: App<Text> = App::new();
 
@@ -608,32 +601,15 @@ fn should_create_alert_text() {
 
 #[wasm_bindgen_test]
 fn should_create_tag_text() {
-    let props = Props {
-        text_type: TextType::Tag,
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        ondelete_signal: Callback::noop(),
-        draggable: false,
-        removable: false,
-        plain_text: "hello test".to_string(),
-        html_text: None,
-        text_palette: Palette::Primary,
-        text_style: Style::Regular,
-        text_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("color: blue;"),
-        id: "id-text-test".to_string(),
-    };
+    let props = Props::builder()
+        .class_name("class-card-test".to_string())
+        .html_text(None)
+        .id("id-text-test".to_string())
+        .plain_text("hello test".to_string())
+        .styles(css!("color: blue;"))
+        .text_palette(Palette::Primary)
+        .text_type(TextType::Tag)
+        .build();
 
     let text: App<Text> = App::new();
 
@@ -651,32 +627,16 @@ fn should_create_tag_text() {
 
 #[wasm_bindgen_test]
 fn should_add_delete_icon_tag_text() {
-    let props = Props {
-        text_type: TextType::Tag,
-        ondrag_signal: Callback::noop(),
-        ondragend_signal: Callback::noop(),
-        ondragenter_signal: Callback::noop(),
-        ondragexit_signal: Callback::noop(),
-        ondragleave_signal: Callback::noop(),
-        ondragover_signal: Callback::noop(),
-        ondragstart_signal: Callback::noop(),
-        ondrop_signal: Callback::noop(),
-        onclick_signal: Callback::noop(),
-        ondelete_signal: Callback::noop(),
-        draggable: false,
-        removable: true,
-        plain_text: "hello test".to_string(),
-        html_text: None,
-        text_palette: Palette::Primary,
-        text_style: Style::Regular,
-        text_size: Size::Medium,
-        interaction_effect: false,
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        class_name: "class-card-test".to_string(),
-        styles: css!("color: blue;"),
-        id: "id-text-test".to_string(),
-    };
+    let props = Props::builder()
+        .class_name("class-card-test".to_string())
+        .html_text(None)
+        .id("id-text-test".to_string())
+        .plain_text("hello test".to_string())
+        .removable(true)
+        .styles(css!("color: blue;"))
+        .text_palette(Palette::Primary)
+        .text_type(TextType::Tag)
+        .build();
 
     let text: App<Text> = App::new();
 
@@ -0,0 +1,344 @@
+use super::{CloseReason, Toast, ToastContainer, ToastPosition};
+use crate::messages::get_messages;
+use crate::styles::Palette;
+use std::cell::RefCell;
+use std::panic;
+use wasm_bindgen_test::*;
+use web_sys::window;
+use yew::prelude::*;
+use yew::services::ConsoleService;
+use yew::{utils, App};
+
+const ERROR_HOST_ID: &str = "yew-styles-error-toasts";
+
+thread_local! {
+    static HOST: RefCell<Option<Callback<ReportedError>>> = RefCell::new(None);
+    static PENDING: RefCell<Vec<ReportedError>> = RefCell::new(vec![]);
+}
+
+/// # Error Toast Host component
+///
+/// Shows as danger toasts the errors sent with `report_error` and, after calling `install_panic_hook`,
+/// the panics of the app. The detail of the error is shown when the toast is expanded.
+/// Only one host should be mounted, usually in the root component
+///
+/// ## Features required
+///
+/// error_bridge
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::toast::{install_panic_hook, report_error, ErrorToastHost};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Save,
+/// }
+///
+/// fn save() -> anyhow::Result<()> {
+///     anyhow::bail!("The server is not available")
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         install_panic_hook();
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Save => {
+///                 if let Err(error) = save() {
+///                     report_error(error.context("Document not saved"));
+///                 }
+///             }
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <button onclick=self.link.callback(|_| Msg::Save)>{"Save"}</button>
+///                 <ErrorToastHost/>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct ErrorToastHost {
+    link: ComponentLink<Self>,
+    props: Props,
+    errors: Vec<(u32, ReportedError)>,
+    next_id: u32,
+}
+
+/// Error shown by `ErrorToastHost`
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReportedError {
+    /// Message shown in the toast
+    pub message: String,
+    /// Detail shown when the toast is expanded
+    pub detail: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Maximum number of errors shown at the same time. Default `3`
+    #[prop_or(3)]
+    pub max_visible: usize,
+    /// Milliseconds until an error is closed, 0 keeps it open until it is dismissed. Default `0`
+    #[prop_or(0)]
+    pub duration: u32,
+    /// Corner of the screen where the errors are shown. Default `ToastPosition::TopRight`
+    #[prop_or(ToastPosition::TopRight)]
+    pub toast_position: ToastPosition,
+}
+
+pub enum Msg {
+    Report(ReportedError),
+    Close(u32, CloseReason),
+}
+
+impl Component for ErrorToastHost {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let report = link.callback(Msg::Report);
+
+        HOST.with(|host| *host.borrow_mut() = Some(report.clone()));
+        PENDING.with(|pending| {
+            pending
+                .borrow_mut()
+                .drain(..)
+                .for_each(|error| report.emit(error))
+        });
+
+        Self {
+            link,
+            props,
+            errors: vec![],
+            next_id: 0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Report(error) => {
+                self.errors.push((self.next_id, error));
+                self.next_id += 1;
+            }
+            Msg::Close(id, _) => {
+                self.errors.retain(|(error_id, _)| *error_id != id);
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ToastContainer
+                id=ERROR_HOST_ID
+                max_visible=self.props.max_visible
+                toast_position=self.props.toast_position.clone()
+            >
+                {for self.errors.iter().map(|(id, error)| {
+                    let id = *id;
+
+                    html! {
+                        <Toast
+                            key=id.to_string()
+                            class_name="error-toast"
+                            duration=self.props.duration
+                            toast_palette=Palette::Danger
+                            onclose_signal=self.link.callback(move |reason| Msg::Close(id, reason))
+                        >
+                            {get_error_content(error)}
+                        </Toast>
+                    }
+                })}
+            </ToastContainer>
+        }
+    }
+
+    fn destroy(&mut self) {
+        HOST.with(|host| *host.borrow_mut() = None);
+    }
+}
+
+fn get_error_content(error: &ReportedError) -> Html {
+    match &error.detail {
+        Some(detail) => html! {
+            <details>
+                <summary>{&error.message}</summary>
+                <pre>{detail}</pre>
+            </details>
+        },
+        None => html! {
+            <span>{&error.message}</span>
+        },
+    }
+}
+
+/// Show the error in the `ErrorToastHost`.
+/// If the host is not mounted yet, the error is shown when it is mounted
+pub fn report_error(error: anyhow::Error) {
+    let detail = if error.chain().count() > 1 {
+        Some(format!("{:?}", error))
+    } else {
+        None
+    };
+
+    send_error(ReportedError {
+        message: error.to_string(),
+        detail,
+    });
+}
+
+/// Show the error of a failed result in the `ErrorToastHost` and return the value if it succeeded
+pub fn report_result<T>(result: anyhow::Result<T>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(error) => {
+            report_error(error);
+            None
+        }
+    }
+}
+
+fn send_error(error: ReportedError) {
+    let host = HOST.with(|host| host.borrow().clone());
+
+    match host {
+        Some(report) => report.emit(error),
+        None => PENDING.with(|pending| pending.borrow_mut().push(error)),
+    }
+}
+
+/// Show the panics of the app as error toasts, the previous panic hook is still called.
+/// After a panic the app cannot update its components, so the toast is added directly in the document
+pub fn install_panic_hook() {
+    let previous_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        previous_hook(info);
+        show_panic(&info.to_string());
+    }));
+}
+
+fn show_panic(detail: &str) {
+    // A failure here would panic again inside of the hook, so the panic is only logged then
+    if add_panic_toast(detail).is_none() {
+        ConsoleService::error(detail);
+    }
+}
+
+fn add_panic_toast(detail: &str) -> Option<()> {
+    let document = window()?.document()?;
+
+    let container = match document.get_element_by_id(ERROR_HOST_ID) {
+        Some(container) => container,
+        None => {
+            let container = document.create_element("div").ok()?;
+            container.set_id(ERROR_HOST_ID);
+            container.set_class_name("toast-container top-right");
+            document.body()?.append_child(&container).ok()?;
+            container
+        }
+    };
+
+    let toast = document.create_element("div").ok()?;
+    toast.set_class_name("toast regular danger error-toast");
+    toast.set_attribute("role", "alert").ok()?;
+    toast.set_inner_html(
+        "<div class=\"toast-content\"><details><summary></summary><pre></pre></details></div>",
+    );
+
+    // text_content escapes the panic message
+    toast
+        .query_selector("summary")
+        .ok()??
+        .set_text_content(Some(&get_messages().unexpected_error));
+    toast
+        .query_selector("pre")
+        .ok()??
+        .set_text_content(Some(detail));
+
+    container.append_child(&toast).ok()?;
+
+    Some(())
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_reported_errors() {
+    let error_host: App<ErrorToastHost> = App::new();
+
+    error_host.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        Props::builder().build(),
+    );
+
+    report_error(anyhow::anyhow!("Connection refused").context("Document not saved"));
+
+    let error_toast = utils::document()
+        .get_elements_by_class_name("error-toast")
+        .get_with_index(0)
+        .unwrap();
+
+    let summary = error_toast.query_selector("summary").unwrap().unwrap();
+    let detail = error_toast.query_selector("pre").unwrap().unwrap();
+
+    assert_eq!(summary.text_content().unwrap(), "Document not saved");
+    assert!(detail
+        .text_content()
+        .unwrap()
+        .contains("Connection refused"));
+}
+
+#[wasm_bindgen_test]
+fn should_show_panics_in_the_document() {
+    show_panic("panicked at 'index out of bounds'");
+
+    let error_toasts = utils::document()
+        .get_element_by_id(ERROR_HOST_ID)
+        .unwrap()
+        .get_elements_by_class_name("error-toast");
+
+    let error_toast = error_toasts
+        .get_with_index(error_toasts.length() - 1)
+        .unwrap();
+
+    assert_eq!(
+        error_toast
+            .query_selector("pre")
+            .unwrap()
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "panicked at 'index out of bounds'"
+    );
+}
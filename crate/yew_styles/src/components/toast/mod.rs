@@ -0,0 +1,17 @@
+#[cfg(feature = "error_bridge")]
+mod error_bridge;
+mod notification;
+mod progress_toast;
+mod toast_container;
+mod toast_item;
+
+#[cfg(feature = "error_bridge")]
+pub use error_bridge::{
+    install_panic_hook, report_error, report_result, ErrorToastHost, ReportedError,
+};
+pub use notification::{Notification, NotificationCommand, NotificationHost, NotificationService};
+pub use progress_toast::{
+    start_progress, ProgressHandle, ProgressStatus, ProgressToast, ProgressToastHost,
+};
+pub use toast_container::{ToastContainer, ToastPosition};
+pub use toast_item::{CloseReason, Toast};
@@ -0,0 +1,323 @@
+use super::{CloseReason, Toast, ToastContainer, ToastPosition};
+use crate::styles::animations::set_reduced_motion;
+use crate::styles::Palette;
+use std::cell::RefCell;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+const NOTIFICATION_HOST_ID: &str = "yew-styles-notifications";
+
+thread_local! {
+    static HOST: RefCell<Option<Callback<NotificationCommand>>> = RefCell::new(None);
+    static PENDING: RefCell<Vec<NotificationCommand>> = RefCell::new(vec![]);
+    static NEXT_ID: RefCell<u32> = RefCell::new(0);
+}
+
+/// # Notification Host component
+///
+/// Shows as toasts the notifications sent with `NotificationService::push`, from any component
+/// or from code outside of the components, e.g. the response of a request. The notifications
+/// are stacked in the order they are pushed and closed after their duration.
+/// Only one host should be mounted, usually in the root component
+///
+/// ## Features required
+///
+/// toast
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::toast::{Notification, NotificationHost, NotificationService};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Save,
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Save => {
+///                 NotificationService::push(Notification::success("Saved").title("Document"));
+///             }
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <button onclick=self.link.callback(|_| Msg::Save)>{"Save"}</button>
+///                 <NotificationHost/>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct NotificationHost {
+    link: ComponentLink<Self>,
+    props: Props,
+    notifications: Vec<(u32, Notification)>,
+}
+
+/// Notification shown by `NotificationHost`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Notification {
+    /// Text of the toast
+    pub message: String,
+    /// Bold text before the message
+    pub title: Option<String>,
+    /// Type notification purpose style
+    pub palette: Palette,
+    /// Milliseconds until it is closed, by default the duration of the host
+    pub duration: Option<u32>,
+}
+
+impl Notification {
+    pub fn new(palette: Palette, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            title: None,
+            palette,
+            duration: None,
+        }
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(Palette::Success, message)
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(Palette::Info, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Palette::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Palette::Danger, message)
+    }
+
+    /// Add a bold title before the message
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Milliseconds until it is closed, 0 keeps it open until it is dismissed
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Maximum number of notifications shown at the same time. Default `3`
+    #[prop_or(3)]
+    pub max_visible: usize,
+    /// Milliseconds until a notification is closed, 0 keeps it open until it is dismissed. Default `5000`
+    #[prop_or(5000)]
+    pub duration: u32,
+    /// Corner of the screen where the notifications are shown. Default `ToastPosition::BottomRight`
+    #[prop_or(ToastPosition::BottomRight)]
+    pub toast_position: ToastPosition,
+}
+
+/// Command sent by `NotificationService` to the host
+#[derive(Clone, PartialEq, Debug)]
+pub enum NotificationCommand {
+    Push(u32, Notification),
+    Dismiss(u32),
+    Clear,
+}
+
+pub enum Msg {
+    Command(NotificationCommand),
+    Close(u32, CloseReason),
+}
+
+impl Component for NotificationHost {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let command = link.callback(Msg::Command);
+
+        HOST.with(|host| *host.borrow_mut() = Some(command.clone()));
+        PENDING.with(|pending| {
+            pending
+                .borrow_mut()
+                .drain(..)
+                .for_each(|notification_command| command.emit(notification_command))
+        });
+
+        Self {
+            link,
+            props,
+            notifications: vec![],
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Command(NotificationCommand::Push(id, notification)) => {
+                self.notifications.push((id, notification));
+            }
+            Msg::Command(NotificationCommand::Dismiss(id)) | Msg::Close(id, _) => {
+                self.notifications
+                    .retain(|(notification_id, _)| *notification_id != id);
+            }
+            Msg::Command(NotificationCommand::Clear) => {
+                self.notifications.clear();
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ToastContainer
+                id=NOTIFICATION_HOST_ID
+                max_visible=self.props.max_visible
+                toast_position=self.props.toast_position.clone()
+            >
+                {for self.notifications.iter().map(|(id, notification)| {
+                    let id = *id;
+
+                    html! {
+                        <Toast
+                            key=id.to_string()
+                            class_name="notification"
+                            duration=notification.duration.unwrap_or(self.props.duration)
+                            toast_palette=notification.palette.clone()
+                            onclose_signal=self.link.callback(move |reason| Msg::Close(id, reason))
+                        >
+                            {get_notification_content(notification)}
+                        </Toast>
+                    }
+                })}
+            </ToastContainer>
+        }
+    }
+
+    fn destroy(&mut self) {
+        HOST.with(|host| *host.borrow_mut() = None);
+    }
+}
+
+fn get_notification_content(notification: &Notification) -> Html {
+    match &notification.title {
+        Some(title) => html! {
+            <>
+                <b class="notification-title">{title}</b>
+                <span>{&notification.message}</span>
+            </>
+        },
+        None => html! {
+            <span>{&notification.message}</span>
+        },
+    }
+}
+
+/// Sends notifications to the `NotificationHost`
+pub struct NotificationService;
+
+impl NotificationService {
+    /// Show the notification and return its id.
+    /// If the host is not mounted yet, the notification is shown when it is mounted
+    pub fn push(notification: Notification) -> u32 {
+        let id = NEXT_ID.with(|next_id| {
+            let mut next_id = next_id.borrow_mut();
+            *next_id += 1;
+            *next_id
+        });
+
+        send_command(NotificationCommand::Push(id, notification));
+        id
+    }
+
+    /// Close the notification with the id returned by `push`
+    pub fn dismiss(id: u32) {
+        send_command(NotificationCommand::Dismiss(id));
+    }
+
+    /// Close all the notifications
+    pub fn clear() {
+        send_command(NotificationCommand::Clear);
+    }
+}
+
+fn send_command(notification_command: NotificationCommand) {
+    let host = HOST.with(|host| host.borrow().clone());
+
+    match host {
+        Some(command) => command.emit(notification_command),
+        None => PENDING.with(|pending| pending.borrow_mut().push(notification_command)),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_pushed_notifications() {
+    // the closed toasts are removed at once instead of after the leave transition
+    set_reduced_motion(Some(true));
+    NotificationService::push(Notification::info("Pushed before mounting the host"));
+
+    let notification_host: App<NotificationHost> = App::new();
+
+    notification_host.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        Props::builder().duration(0).build(),
+    );
+
+    let saved = NotificationService::push(Notification::success("Saved").title("Document"));
+
+    let notifications = utils::document()
+        .get_element_by_id(NOTIFICATION_HOST_ID)
+        .unwrap()
+        .get_elements_by_class_name("notification");
+
+    assert_eq!(notifications.length(), 2);
+    assert!(notifications
+        .get_with_index(1)
+        .unwrap()
+        .class_list()
+        .contains("success"));
+
+    NotificationService::dismiss(saved);
+
+    assert_eq!(notifications.length(), 1);
+
+    NotificationService::clear();
+
+    assert_eq!(notifications.length(), 0);
+    set_reduced_motion(None);
+}
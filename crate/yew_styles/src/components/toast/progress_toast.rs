@@ -0,0 +1,633 @@
+use super::{CloseReason, Toast, ToastContainer, ToastPosition};
+use crate::styles::{tokens::Spaces, Palette, Style};
+use std::cell::RefCell;
+use std::time::Duration;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::services::interval::{IntervalService, IntervalTask};
+use yew::{utils, App};
+
+const PROGRESS_HOST_ID: &str = "yew-styles-progress-toasts";
+
+thread_local! {
+    static HOST: RefCell<Option<Callback<ProgressUpdate>>> = RefCell::new(None);
+    static PENDING: RefCell<Vec<ProgressUpdate>> = RefCell::new(vec![]);
+    static NEXT_ID: RefCell<u32> = RefCell::new(0);
+}
+
+/// # Progress Toast component
+///
+/// Toast for long-running operations, like uploads or batch jobs, which shows the elapsed time
+/// and the progress. It stays open while the status is `ProgressStatus::Running` and, when the operation
+/// ends, it changes to the success or danger palette and closes after `duration` milliseconds.
+/// It is used inside of `ToastContainer`, or through `start_progress` and `ProgressToastHost`
+///
+/// ## Features required
+///
+/// toast
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::toast::{start_progress, ProgressHandle, ProgressToastHost};
+///
+/// pub struct Uploader {
+///     link: ComponentLink<Self>,
+///     upload: Option<ProgressHandle>,
+/// }
+///
+/// pub enum Msg {
+///     Start,
+///     Progress(f64),
+///     Done,
+/// }
+///
+/// impl Component for Uploader {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self { link, upload: None }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Start => self.upload = Some(start_progress("Uploading report.pdf")),
+///             Msg::Progress(progress) => {
+///                 if let Some(upload) = &self.upload {
+///                     upload.set_progress(progress);
+///                 }
+///             }
+///             Msg::Done => {
+///                 if let Some(upload) = self.upload.take() {
+///                     upload.succeed("report.pdf uploaded");
+///                 }
+///             }
+///         };
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <button onclick=self.link.callback(|_| Msg::Start)>{"Upload"}</button>
+///                 <ProgressToastHost/>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct ProgressToast {
+    link: ComponentLink<Self>,
+    props: Props,
+    started_at: Option<f64>,
+    elapsed: f64,
+    interval_task: Option<IntervalTask>,
+}
+
+/// State of the operation shown by the progress toast
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProgressStatus {
+    Running,
+    Success,
+    Error,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Description of the operation. Required
+    pub message: String,
+    /// Completed fraction from `0.0` to `1.0`, `None` shows an indeterminate bar. Default `None`
+    #[prop_or_default]
+    pub progress: Option<f64>,
+    /// State of the operation. Default `ProgressStatus::Running`
+    #[prop_or(ProgressStatus::Running)]
+    pub status: ProgressStatus,
+    /// Milliseconds until the toast is closed after the operation ends, 0 keeps it open. Default `5000`
+    #[prop_or(5000)]
+    pub duration: u32,
+    /// Signal emitted when the toast is closed after the operation ends or it is dismissed
+    #[prop_or(Callback::noop())]
+    pub onclose_signal: Callback<CloseReason>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Tick,
+}
+
+impl Component for ProgressToast {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            started_at: None,
+            elapsed: 0.0,
+            interval_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Tick => {
+                if let Some(started_at) = self.started_at {
+                    self.elapsed = js_sys::Date::now() - started_at;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if props.status != ProgressStatus::Running {
+                // the elapsed time stops when the operation ends
+                self.link.send_message(Msg::Tick);
+                self.interval_task = None;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render && self.props.status == ProgressStatus::Running {
+            self.started_at = Some(js_sys::Date::now());
+            self.interval_task = Some(IntervalService::spawn(
+                Duration::from_secs(1),
+                self.link.callback(|_| Msg::Tick),
+            ));
+        }
+    }
+
+    fn view(&self) -> Html {
+        let (palette, style) = match self.props.status {
+            ProgressStatus::Running => (Palette::Standard, Style::Light),
+            ProgressStatus::Success => (Palette::Success, Style::Regular),
+            ProgressStatus::Error => (Palette::Danger, Style::Regular),
+        };
+        let running = self.props.status == ProgressStatus::Running;
+
+        html! {
+            <Toast
+                class_name=format!("progress-toast {}", self.props.class_name)
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                code_ref=self.props.code_ref.clone()
+                duration=if running { 0 } else { self.props.duration }
+                pause_on_hover=true
+                show_progress=!running
+                closable=!running
+                toast_palette=palette
+                toast_style=style
+                onclose_signal=self.props.onclose_signal.clone()
+                styles=self.props.styles.clone()
+                margin=self.props.margin
+                padding=self.props.padding
+            >
+                <div class="progress-toast-header">
+                    <span class="progress-toast-message">{&self.props.message}</span>
+                    <span class="progress-toast-elapsed">{format_elapsed(self.elapsed)}</span>
+                </div>
+                {if running {
+                    self.get_progress_bar()
+                } else {
+                    html! {}
+                }}
+            </Toast>
+        }
+    }
+}
+
+impl ProgressToast {
+    fn get_progress_bar(&self) -> Html {
+        match self.props.progress {
+            Some(progress) => {
+                let percentage = (progress.clamp(0.0, 1.0) * 100.0).round();
+
+                html! {
+                    <div
+                        class="progress-toast-bar"
+                        role="progressbar"
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                        aria-valuenow=percentage.to_string()
+                    >
+                        <div class="progress-toast-value" style=format!("width: {}%", percentage)></div>
+                    </div>
+                }
+            }
+            None => html! {
+                <div class="progress-toast-bar indeterminate" role="progressbar">
+                    <div class="progress-toast-value"></div>
+                </div>
+            },
+        }
+    }
+}
+
+/// Elapsed time as minutes and seconds, with the hours when they are reached
+fn format_elapsed(elapsed: f64) -> String {
+    let seconds = (elapsed.max(0.0) / 1000.0) as u64;
+
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else {
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+/// # Progress Toast Host component
+///
+/// Shows the progress toasts started with `start_progress`.
+/// Only one host should be mounted, usually in the root component, see the example of `ProgressToast`
+///
+/// ## Features required
+///
+/// toast
+pub struct ProgressToastHost {
+    link: ComponentLink<Self>,
+    props: HostProps,
+    operations: Vec<Operation>,
+}
+
+struct Operation {
+    id: u32,
+    message: String,
+    progress: Option<f64>,
+    status: ProgressStatus,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct HostProps {
+    /// Maximum number of operations shown at the same time. Default `3`
+    #[prop_or(3)]
+    pub max_visible: usize,
+    /// Milliseconds until an ended operation is closed, 0 keeps it open until it is dismissed. Default `5000`
+    #[prop_or(5000)]
+    pub duration: u32,
+    /// Corner of the screen where the operations are shown. Default `ToastPosition::BottomRight`
+    #[prop_or(ToastPosition::BottomRight)]
+    pub toast_position: ToastPosition,
+}
+
+/// Change of an operation sent by its handle to the host
+#[derive(Clone, PartialEq, Debug)]
+pub enum ProgressUpdate {
+    Start(u32, String),
+    Progress(u32, f64),
+    Message(u32, String),
+    End(u32, ProgressStatus, Option<String>),
+}
+
+impl ProgressUpdate {
+    fn get_id(&self) -> u32 {
+        match self {
+            ProgressUpdate::Start(id, _)
+            | ProgressUpdate::Progress(id, _)
+            | ProgressUpdate::Message(id, _)
+            | ProgressUpdate::End(id, _, _) => *id,
+        }
+    }
+}
+
+pub enum HostMsg {
+    Update(ProgressUpdate),
+    Close(u32),
+}
+
+impl Component for ProgressToastHost {
+    type Message = HostMsg;
+    type Properties = HostProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let update = link.callback(HostMsg::Update);
+
+        HOST.with(|host| *host.borrow_mut() = Some(update.clone()));
+        PENDING.with(|pending| {
+            pending
+                .borrow_mut()
+                .drain(..)
+                .for_each(|progress_update| update.emit(progress_update))
+        });
+
+        Self {
+            link,
+            props,
+            operations: vec![],
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            HostMsg::Update(ProgressUpdate::Start(id, message)) => {
+                self.operations.push(Operation {
+                    id,
+                    message,
+                    progress: None,
+                    status: ProgressStatus::Running,
+                });
+            }
+            HostMsg::Update(ProgressUpdate::Progress(id, progress)) => {
+                if let Some(operation) = self.get_operation(id) {
+                    operation.progress = Some(progress);
+                }
+            }
+            HostMsg::Update(ProgressUpdate::Message(id, message)) => {
+                if let Some(operation) = self.get_operation(id) {
+                    operation.message = message;
+                }
+            }
+            HostMsg::Update(ProgressUpdate::End(id, status, message)) => {
+                if let Some(operation) = self.get_operation(id) {
+                    operation.status = status;
+
+                    if let Some(message) = message {
+                        operation.message = message;
+                    }
+                }
+            }
+            HostMsg::Close(id) => {
+                self.operations.retain(|operation| operation.id != id);
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <ToastContainer
+                id=PROGRESS_HOST_ID
+                max_visible=self.props.max_visible
+                toast_position=self.props.toast_position.clone()
+            >
+                {for self.operations.iter().map(|operation| {
+                    let id = operation.id;
+
+                    html! {
+                        <ProgressToast
+                            key=id.to_string()
+                            message=operation.message.clone()
+                            progress=operation.progress
+                            status=operation.status
+                            duration=self.props.duration
+                            onclose_signal=self.link.callback(move |_| HostMsg::Close(id))
+                        />
+                    }
+                })}
+            </ToastContainer>
+        }
+    }
+
+    fn destroy(&mut self) {
+        HOST.with(|host| *host.borrow_mut() = None);
+    }
+}
+
+impl ProgressToastHost {
+    fn get_operation(&mut self, id: u32) -> Option<&mut Operation> {
+        self.operations
+            .iter_mut()
+            .find(|operation| operation.id == id)
+    }
+}
+
+/// Handle of an operation shown in the `ProgressToastHost`, returned by `start_progress`.
+/// The operation ends with `succeed` or `fail`, a handle dropped before ends it as failed
+/// keeping its last message, so the toast is never left running
+#[derive(PartialEq, Debug)]
+pub struct ProgressHandle {
+    id: u32,
+    ended: bool,
+}
+
+impl ProgressHandle {
+    /// Update the completed fraction, from `0.0` to `1.0`
+    pub fn set_progress(&self, progress: f64) {
+        send_update(ProgressUpdate::Progress(self.id, progress));
+    }
+
+    /// Replace the description of the operation
+    pub fn set_message(&self, message: impl Into<String>) {
+        send_update(ProgressUpdate::Message(self.id, message.into()));
+    }
+
+    /// End the operation with the success palette and a final message
+    pub fn succeed(mut self, message: impl Into<String>) {
+        self.end(ProgressStatus::Success, Some(message.into()));
+    }
+
+    /// End the operation with the danger palette and a final message
+    pub fn fail(mut self, message: impl Into<String>) {
+        self.end(ProgressStatus::Error, Some(message.into()));
+    }
+
+    fn end(&mut self, status: ProgressStatus, message: Option<String>) {
+        if !self.ended {
+            self.ended = true;
+            send_update(ProgressUpdate::End(self.id, status, message));
+        }
+    }
+}
+
+impl Drop for ProgressHandle {
+    fn drop(&mut self) {
+        self.end(ProgressStatus::Error, None);
+    }
+}
+
+/// Show a new operation in the `ProgressToastHost` and return its handle.
+/// If the host is not mounted yet, the operation is shown when it is mounted unless it already ended
+pub fn start_progress(message: impl Into<String>) -> ProgressHandle {
+    let id = NEXT_ID.with(|next_id| {
+        let mut next_id = next_id.borrow_mut();
+        *next_id += 1;
+        *next_id
+    });
+
+    send_update(ProgressUpdate::Start(id, message.into()));
+    ProgressHandle { id, ended: false }
+}
+
+fn send_update(progress_update: ProgressUpdate) {
+    let host = HOST.with(|host| host.borrow().clone());
+
+    match host {
+        Some(update) => update.emit(progress_update),
+        None => PENDING.with(|pending| add_pending(&mut pending.borrow_mut(), progress_update)),
+    }
+}
+
+/// Keeps only the last state of the running operations until the host is mounted,
+/// so the pending updates don't grow while there is no host
+fn add_pending(pending: &mut Vec<ProgressUpdate>, progress_update: ProgressUpdate) {
+    match progress_update {
+        ProgressUpdate::Start(..) => pending.push(progress_update),
+        ProgressUpdate::Progress(id, _) => {
+            pending.retain(|update| {
+                !matches!(update, ProgressUpdate::Progress(update_id, _) if *update_id == id)
+            });
+            pending.push(progress_update);
+        }
+        ProgressUpdate::Message(id, message) => {
+            for update in pending.iter_mut() {
+                if let ProgressUpdate::Start(start_id, start_message) = update {
+                    if *start_id == id {
+                        *start_message = message.clone();
+                    }
+                }
+            }
+        }
+        // nothing of an operation ended before the host is mounted is shown
+        ProgressUpdate::End(id, _, _) => pending.retain(|update| update.get_id() != id),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_progress_while_running() {
+    let props = Props::builder()
+        .id("progress-toast-test".to_string())
+        .message("Uploading".to_string())
+        .progress(Some(0.42))
+        .build();
+
+    let progress_toast: App<ProgressToast> = App::new();
+    progress_toast.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let progress_toast_element = utils::document()
+        .get_element_by_id("progress-toast-test")
+        .unwrap();
+    let bar = progress_toast_element
+        .get_elements_by_class_name("progress-toast-bar")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(bar.get_attribute("aria-valuenow").unwrap(), "42");
+    assert_eq!(
+        progress_toast_element
+            .get_elements_by_class_name("toast-close")
+            .length(),
+        0
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_format_the_elapsed_time() {
+    assert_eq!(format_elapsed(0.0), "00:00");
+    assert_eq!(format_elapsed(83_500.0), "01:23");
+    assert_eq!(format_elapsed(3_723_000.0), "1:02:03");
+}
+
+#[wasm_bindgen_test]
+fn should_keep_the_last_state_of_the_pending_operations() {
+    let mut pending = vec![];
+
+    add_pending(
+        &mut pending,
+        ProgressUpdate::Start(1, "Uploading".to_string()),
+    );
+    add_pending(
+        &mut pending,
+        ProgressUpdate::Start(2, "Exporting".to_string()),
+    );
+    add_pending(&mut pending, ProgressUpdate::Progress(1, 0.2));
+    add_pending(&mut pending, ProgressUpdate::Progress(1, 0.6));
+    add_pending(
+        &mut pending,
+        ProgressUpdate::Message(1, "Uploading report.pdf".to_string()),
+    );
+    add_pending(
+        &mut pending,
+        ProgressUpdate::End(2, ProgressStatus::Success, None),
+    );
+
+    assert_eq!(
+        pending,
+        vec![
+            ProgressUpdate::Start(1, "Uploading report.pdf".to_string()),
+            ProgressUpdate::Progress(1, 0.6),
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_end_the_operation_when_the_handle_is_dropped() {
+    let host: App<ProgressToastHost> = App::new();
+    host.mount(utils::document().get_element_by_id("output").unwrap());
+
+    let upload = start_progress("Uploading report.pdf");
+    let toast = utils::document()
+        .get_element_by_id(PROGRESS_HOST_ID)
+        .unwrap()
+        .get_elements_by_class_name("progress-toast")
+        .get_with_index(0)
+        .unwrap();
+
+    assert!(!toast.class_list().contains("danger"));
+
+    drop(upload);
+
+    assert!(toast.class_list().contains("danger"));
+    assert_eq!(
+        toast
+            .get_elements_by_class_name("progress-toast-message")
+            .get_with_index(0)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Uploading report.pdf"
+    );
+}
@@ -0,0 +1,308 @@
+use crate::layers::{acquire_layer, get_top_layer_id, Layer, LAYER_STEP};
+use crate::messages::{format_message, get_messages};
+use crate::portal::Portal;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::transition_group::{Transition, TransitionGroup};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Toast Container component
+///
+/// Shows the toasts in a corner of the screen. Only `max_visible` toasts
+/// are shown at the same time, the rest wait in the queue until the visible ones are closed.
+/// The container is rendered at the end of the body with a `Portal` and the toasts slide in and out with a `TransitionGroup`.
+/// It takes a layer when the first toast is shown and releases it when the last one is closed,
+/// so a toast shown while a modal is open is stacked over the modal
+///
+/// ## Features required
+///
+/// toast
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::button::Button;
+/// use yew_styles::styles::Palette;
+/// use yew_styles::toast::{CloseReason, Toast, ToastContainer, ToastPosition};
+///
+/// pub struct ToastExample {
+///     link: ComponentLink<Self>,
+///     toasts: Vec<u32>,
+///     next_id: u32,
+/// }
+///
+/// pub enum Msg {
+///     AddToast,
+///     CloseToast(u32, CloseReason),
+/// }
+///
+/// impl Component for ToastExample {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             toasts: vec![],
+///             next_id: 0,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::AddToast => {
+///                 self.toasts.push(self.next_id);
+///                 self.next_id += 1;
+///             }
+///             Msg::CloseToast(id, _) => {
+///                 self.toasts.retain(|toast_id| *toast_id != id);
+///             }
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Button onclick_signal=self.link.callback(|_| Msg::AddToast)>{"Save"}</Button>
+///                 <ToastContainer max_visible=2 toast_position=ToastPosition::BottomRight>
+///                     {for self.toasts.iter().map(|id| {
+///                         let id = *id;
+///
+///                         html! {
+///                             <Toast
+///                                 key=id.to_string()
+///                                 toast_palette=Palette::Success
+///                                 onclose_signal=self.link.callback(move |reason| Msg::CloseToast(id, reason))
+///                             >
+///                                 {format!("Document {} saved", id)}
+///                             </Toast>
+///                         }
+///                     })}
+///                 </ToastContainer>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct ToastContainer {
+    props: Props,
+    layer: Option<Layer>,
+}
+
+/// Corner of the screen where the toasts are shown
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToastPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Maximum number of toasts shown at the same time. Default `3`
+    #[prop_or(3)]
+    pub max_visible: usize,
+    /// Corner of the screen where the toasts are shown. Default `ToastPosition::TopRight`
+    #[prop_or(ToastPosition::TopRight)]
+    pub toast_position: ToastPosition,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for ToastContainer {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let mut toast_container = Self { props, layer: None };
+        toast_container.update_layer();
+
+        toast_container
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.update_layer();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let queued = self
+            .props
+            .children
+            .len()
+            .saturating_sub(self.props.max_visible);
+
+        html! {
+            <Portal>
+                <div
+                    class=ComponentClasses::new("toast-container")
+                        .semantic(get_position(self.props.toast_position.clone()))
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                    id=self.props.id.clone()
+                    style=self.layer.as_ref().map(|layer| layer.style())
+                    aria-live="polite"
+                >
+                    <TransitionGroup transition=Transition::Slide>
+                        {for self.props.children.iter().take(self.props.max_visible)}
+                    </TransitionGroup>
+                    {if queued > 0 {
+                        html! {
+                            <div class="toast-queue">{format_message(&get_messages().toast_queue, &[("count", queued.to_string())])}</div>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </Portal>
+        }
+    }
+}
+
+impl ToastContainer {
+    fn update_layer(&mut self) {
+        if self.props.children.is_empty() {
+            self.layer = None;
+        } else if self.layer.is_none() {
+            self.layer = Some(acquire_layer());
+        }
+    }
+}
+
+pub fn get_position(position: ToastPosition) -> String {
+    match position {
+        ToastPosition::TopLeft => String::from("top-left"),
+        ToastPosition::TopRight => String::from("top-right"),
+        ToastPosition::BottomLeft => String::from("bottom-left"),
+        ToastPosition::BottomRight => String::from("bottom-right"),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_queue_toasts_over_max_visible() {
+    let props = Props::builder()
+        .id("toast-container".to_string())
+        .max_visible(2)
+        .children(Children::new(vec![
+            html! {<div class="toast-test">{"first"}</div>},
+            html! {<div class="toast-test">{"second"}</div>},
+            html! {<div class="toast-test">{"third"}</div>},
+        ]))
+        .build();
+
+    let toast_container: App<ToastContainer> = App::new();
+
+    toast_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let toasts = utils::document().get_elements_by_class_name("toast-test");
+    let queue = utils::document()
+        .get_elements_by_class_name("toast-queue")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(toasts.length(), 2);
+    assert_eq!(queue.text_content().unwrap(), "+1");
+}
+
+#[wasm_bindgen_test]
+fn should_stack_the_toasts_over_an_open_modal() {
+    struct ToastsOverModal {
+        toasts: Vec<&'static str>,
+    }
+
+    impl Component for ToastsOverModal {
+        type Message = Vec<&'static str>;
+        type Properties = ();
+
+        fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+            Self { toasts: vec![] }
+        }
+
+        fn update(&mut self, toasts: Self::Message) -> ShouldRender {
+            self.toasts = toasts;
+            true
+        }
+
+        fn change(&mut self, _: Self::Properties) -> ShouldRender {
+            false
+        }
+
+        fn view(&self) -> Html {
+            html! {
+                <ToastContainer id="toast-container-layer">
+                    {for self.toasts.iter().map(|toast| html! {<div>{toast}</div>})}
+                </ToastContainer>
+            }
+        }
+    }
+
+    let toasts: App<ToastsOverModal> = App::new();
+    let link = toasts.mount(utils::document().get_element_by_id("output").unwrap());
+
+    let container = utils::document()
+        .get_element_by_id("toast-container-layer")
+        .unwrap();
+
+    assert_eq!(container.get_attribute("style"), None);
+
+    let modal = acquire_layer();
+    link.send_message(vec!["Document saved"]);
+
+    assert_eq!(get_top_layer_id(), Some(modal.id() + 1));
+    assert_eq!(
+        container.get_attribute("style").unwrap(),
+        format!("z-index: {};", modal.z_index() + LAYER_STEP)
+    );
+
+    link.send_message(vec![]);
+
+    assert_eq!(get_top_layer_id(), Some(modal.id()));
+}
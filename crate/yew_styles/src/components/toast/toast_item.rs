@@ -0,0 +1,463 @@
+use crate::messages::get_messages;
+use crate::styles::{get_palette, get_style, tokens::Spaces, ComponentClasses, Palette, Style};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use stylist::{css, StyleSource};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, Event};
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::{utils, App};
+
+/// # Toast component
+///
+/// Notification closed automatically after `duration` milliseconds.
+/// The timer is paused while the toast is hovered or focused and
+/// the progress bar shows the time remaining.
+/// It is used inside of `ToastContainer`, see its example
+///
+/// ## Undoable actions
+///
+/// With `action`, the toast shows a button (e.g. "Undo") to cancel a pending operation.
+/// Clicking it emits `onaction_signal` and closes the toast with `CloseReason::Action`.
+/// If the duration elapses, `onconfirm_signal` is emitted to run the operation and, when `confirmation` is set,
+/// the toast shows it for `confirmation_duration` milliseconds before closing
+///
+/// ```rust
+/// html! {
+///     <Toast
+///         action=html! {"Undo"}
+///         confirmation=html! {"Message deleted"}
+///         onaction_signal=self.link.callback(move |_| Msg::RestoreMessage(id))
+///         onconfirm_signal=self.link.callback(move |_| Msg::DeleteMessage(id))
+///         onclose_signal=self.link.callback(move |_| Msg::CloseToast(id))
+///     >
+///         {"Deleting message..."}
+///     </Toast>
+/// }
+/// ```
+///
+/// ## Features required
+///
+/// toast
+pub struct Toast {
+    link: ComponentLink<Self>,
+    props: Props,
+    remaining: f64,
+    started_at: f64,
+    paused: bool,
+    confirmed: bool,
+    timeout_task: Option<TimeoutTask>,
+    /// focusin and focusout listeners of the toast, yew doesn't have these events
+    focus_listeners: Option<(Element, Closure<dyn Fn(Event)>, Closure<dyn Fn(Event)>)>,
+}
+
+/// Why the toast asks to be closed
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloseReason {
+    /// The duration elapsed
+    Timeout,
+    /// The user clicked the close button
+    Dismissed,
+    /// The user clicked the action button
+    Action,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Milliseconds until the toast is closed, 0 keeps it open until it is dismissed. Default `5000`
+    #[prop_or(5000)]
+    pub duration: u32,
+    /// Pause the timer while the toast is hovered or focused. Default `true`
+    #[prop_or(true)]
+    pub pause_on_hover: bool,
+    /// Show a progress bar with the time remaining. Default `true`
+    #[prop_or(true)]
+    pub show_progress: bool,
+    /// Show the close button. Default `true`
+    #[prop_or(true)]
+    pub closable: bool,
+    /// Signal emitted when the duration elapses or the toast is dismissed.
+    /// The toast should be removed from the container when it is received
+    #[prop_or(Callback::noop())]
+    pub onclose_signal: Callback<CloseReason>,
+    /// Content of the action button, e.g. "Undo". Default `None`
+    #[prop_or_default]
+    pub action: Option<Html>,
+    /// Click event of the action button, it should cancel the pending operation
+    #[prop_or(Callback::noop())]
+    pub onaction_signal: Callback<MouseEvent>,
+    /// Signal emitted when the duration elapses without clicking the action button
+    #[prop_or(Callback::noop())]
+    pub onconfirm_signal: Callback<()>,
+    /// Content shown when the duration elapses without clicking the action button. Default `None`
+    #[prop_or_default]
+    pub confirmation: Option<Html>,
+    /// Milliseconds the confirmation is shown before closing the toast. Default `2000`
+    #[prop_or(2000)]
+    pub confirmation_duration: u32,
+    /// Type toast purpose style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub toast_palette: Palette,
+    /// Toast styles. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub toast_style: Style,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Pause,
+    Resume,
+    Elapsed,
+    Dismiss,
+    Action(MouseEvent),
+}
+
+impl Component for Toast {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            remaining: props.duration as f64,
+            props,
+            started_at: 0.0,
+            paused: false,
+            confirmed: false,
+            timeout_task: None,
+            focus_listeners: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Pause => {
+                if !self.props.pause_on_hover || self.timeout_task.is_none() {
+                    return false;
+                }
+
+                self.timeout_task = None;
+                self.remaining -= js_sys::Date::now() - self.started_at;
+                self.paused = true;
+            }
+            Msg::Resume => {
+                if !self.paused {
+                    return false;
+                }
+
+                self.paused = false;
+                self.start_timer();
+            }
+            Msg::Elapsed => {
+                self.timeout_task = None;
+
+                if self.confirmed {
+                    self.props.onclose_signal.emit(CloseReason::Timeout);
+                    return false;
+                }
+
+                if self.props.action.is_some() {
+                    self.props.onconfirm_signal.emit(());
+                }
+
+                if self.props.confirmation.is_some() {
+                    self.confirmed = true;
+                    self.remaining = self.props.confirmation_duration as f64;
+                    self.start_timer();
+                } else {
+                    self.props.onclose_signal.emit(CloseReason::Timeout);
+                }
+            }
+            Msg::Dismiss => {
+                self.timeout_task = None;
+                self.props.onclose_signal.emit(CloseReason::Dismissed);
+            }
+            Msg::Action(mouse_event) => {
+                self.timeout_task = None;
+                self.props.onaction_signal.emit(mouse_event);
+                self.props.onclose_signal.emit(CloseReason::Action);
+            }
+        };
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let restart = !self.confirmed && self.props.duration != props.duration;
+            self.props = props;
+
+            if restart {
+                self.remaining = self.props.duration as f64;
+                self.paused = false;
+                self.start_timer();
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.start_timer();
+            self.listen_focus();
+        }
+    }
+
+    fn destroy(&mut self) {
+        if let Some((element, focus_in, focus_out)) = self.focus_listeners.take() {
+            element
+                .remove_event_listener_with_callback("focusin", focus_in.as_ref().unchecked_ref())
+                .ok();
+            element
+                .remove_event_listener_with_callback("focusout", focus_out.as_ref().unchecked_ref())
+                .ok();
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=ComponentClasses::new("toast")
+                    .semantic(vec![
+                        get_style(self.props.toast_style.clone()),
+                        get_palette(self.props.toast_palette.clone()),
+                    ])
+                    .state("confirmed", self.confirmed)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+                role=if self.props.toast_palette == Palette::Danger { "alert" } else { "status" }
+                tabindex="0"
+                onmouseenter=self.link.callback(|_| Msg::Pause)
+                onmouseleave=self.link.callback(|_| Msg::Resume)
+            >
+                <div class="toast-content">
+                    {if let (true, Some(confirmation)) = (self.confirmed, &self.props.confirmation) {
+                        confirmation.clone()
+                    } else {
+                        html! {<>{for self.props.children.iter()}</>}
+                    }}
+                </div>
+                {match &self.props.action {
+                    Some(action) if !self.confirmed => html! {
+                        <button
+                            class="toast-action"
+                            onclick=self.link.callback(Msg::Action)
+                        >{action.clone()}</button>
+                    },
+                    _ => html! {},
+                }}
+                {if self.props.closable {
+                    html! {
+                        <button
+                            class="toast-close"
+                            aria-label=get_messages().close
+                            onclick=self.link.callback(|_| Msg::Dismiss)
+                        >{"×"}</button>
+                    }
+                } else {
+                    html! {}
+                }}
+                {if self.props.show_progress && !self.confirmed && self.props.duration > 0 {
+                    html! {
+                        <div
+                            class="toast-progress"
+                            style=format!(
+                                "animation-duration: {}ms; animation-play-state: {};",
+                                self.props.duration,
+                                if self.paused { "paused" } else { "running" }
+                            )
+                        ></div>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+impl Toast {
+    fn listen_focus(&mut self) {
+        let element = match self.props.code_ref.cast::<Element>() {
+            Some(element) => element,
+            None => return,
+        };
+        let link = self.link.clone();
+        let focus_in = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Pause)) as Box<dyn Fn(Event)>
+        );
+        let link = self.link.clone();
+        let focus_out = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::Resume)) as Box<dyn Fn(Event)>
+        );
+
+        element
+            .add_event_listener_with_callback("focusin", focus_in.as_ref().unchecked_ref())
+            .ok();
+        element
+            .add_event_listener_with_callback("focusout", focus_out.as_ref().unchecked_ref())
+            .ok();
+        self.focus_listeners = Some((element, focus_in, focus_out));
+    }
+
+    fn start_timer(&mut self) {
+        let duration = if self.confirmed {
+            self.props.confirmation_duration
+        } else {
+            self.props.duration
+        };
+
+        if duration == 0 {
+            self.timeout_task = None;
+            return;
+        }
+
+        self.started_at = js_sys::Date::now();
+        self.timeout_task = Some(TimeoutService::spawn(
+            Duration::from_millis(self.remaining.max(0.0) as u64),
+            self.link.callback(|_| Msg::Elapsed),
+        ));
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_toast() {
+    let props = Props::builder()
+        .duration(0)
+        .id("toast".to_string())
+        .toast_palette(Palette::Success)
+        .children(Children::new(vec![html! {<span>{"Saved"}</span>}]))
+        .build();
+
+    let toast: App<Toast> = App::new();
+
+    toast.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let toast_element = utils::document().get_element_by_id("toast").unwrap();
+
+    assert_eq!(
+        toast_element
+            .get_elements_by_class_name("toast-content")
+            .get_with_index(0)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Saved"
+    );
+    assert_eq!(
+        toast_element
+            .get_elements_by_class_name("toast-progress")
+            .length(),
+        0
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_pause_the_timer_on_hover() {
+    let props = Props::builder()
+        .id("toast-pause".to_string())
+        .toast_palette(Palette::Info)
+        .children(Children::new(vec![html! {<span>{"Uploading"}</span>}]))
+        .build();
+
+    let toast: App<Toast> = App::new();
+
+    let link = toast.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    link.send_message(Msg::Pause);
+
+    let progress = utils::document()
+        .get_element_by_id("toast-pause")
+        .unwrap()
+        .get_elements_by_class_name("toast-progress")
+        .get_with_index(0)
+        .unwrap();
+
+    assert!(progress
+        .get_attribute("style")
+        .unwrap()
+        .contains("animation-play-state: paused"));
+}
+
+#[wasm_bindgen_test]
+fn should_show_the_confirmation_when_the_action_is_not_clicked() {
+    let confirmed = Rc::new(Cell::new(false));
+    let confirmed_signal = confirmed.clone();
+
+    let props = Props::builder()
+        .action(Some(html! {"Undo"}))
+        .confirmation(Some(html! {"Message deleted"}))
+        .id("toast-undo".to_string())
+        .onconfirm_signal(Callback::from(move |_| confirmed_signal.set(true)))
+        .children(Children::new(vec![html! {"Deleting message..."}]))
+        .build();
+
+    let toast: App<Toast> = App::new();
+
+    let link = toast.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    link.send_message(Msg::Elapsed);
+
+    let toast_element = utils::document().get_element_by_id("toast-undo").unwrap();
+
+    assert!(confirmed.get());
+    assert!(toast_element.class_list().contains("confirmed"));
+    assert_eq!(
+        toast_element
+            .get_elements_by_class_name("toast-content")
+            .get_with_index(0)
+            .unwrap()
+            .text_content()
+            .unwrap(),
+        "Message deleted"
+    );
+    assert_eq!(
+        toast_element
+            .get_elements_by_class_name("toast-action")
+            .length(),
+        0
+    );
+}
@@ -1,6 +1,13 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Position, Size, Style};
+use crate::layers::{acquire_layer, Layer};
+use crate::styles::{
+    get_palette, get_size, get_style, tokens::Spaces, ComponentClasses, Palette, Position, Size,
+    Style,
+};
 use stylist::{css, StyleSource};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::{Element, Event};
 use yew::prelude::*;
 use yew::{utils, App};
 
@@ -57,6 +64,10 @@ pub struct Tooltip {
     props: Props,
     link: ComponentLink<Self>,
     show_tooltip: bool,
+    layer: Option<Layer>,
+    container_ref: NodeRef,
+    /// focusin and focusout listeners of the container, yew doesn't have these events
+    focus_listeners: Option<(Element, Closure<dyn Fn(Event)>, Closure<dyn Fn(Event)>)>,
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -89,6 +100,12 @@ pub struct Props {
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
     pub children: Children,
 }
 
@@ -106,13 +123,24 @@ impl Component for Tooltip {
             props,
             link,
             show_tooltip: false,
+            layer: None,
+            container_ref: NodeRef::default(),
+            focus_listeners: None,
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::TargetOver => self.show_tooltip = true,
-            Msg::TargetLeave => self.show_tooltip = false,
+            Msg::TargetOver => {
+                if self.layer.is_none() {
+                    self.layer = Some(acquire_layer());
+                }
+                self.show_tooltip = true;
+            }
+            Msg::TargetLeave => {
+                self.layer = None;
+                self.show_tooltip = false;
+            }
         };
 
         true
@@ -126,21 +154,42 @@ impl Component for Tooltip {
         false
     }
 
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.listen_focus();
+        }
+    }
+
+    fn destroy(&mut self) {
+        if let Some((element, focus_in, focus_out)) = self.focus_listeners.take() {
+            element
+                .remove_event_listener_with_callback("focusin", focus_in.as_ref().unchecked_ref())
+                .ok();
+            element
+                .remove_event_listener_with_callback("focusout", focus_out.as_ref().unchecked_ref())
+                .ok();
+        }
+    }
+
     fn view(&self) -> Html {
         let tooltip = html! {
             <div
                 id=self.props.id.clone()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
-                class=classes!(
-                    "tooltip",
-                    get_position(self.props.tooltip_position.clone()),
-                    get_palette(self.props.tooltip_palette.clone()),
-                    get_style(self.props.tooltip_style.clone()),
-                    get_size(self.props.tooltip_size.clone()),
-                    self.props.class_name.clone(),
-                    self.props.styles.clone()
-                )
+                role="tooltip"
+                style=self.layer.as_ref().map(|layer| layer.style())
+                class=ComponentClasses::new("tooltip")
+                    .semantic(vec![
+                        get_position(self.props.tooltip_position.clone()),
+                        get_palette(self.props.tooltip_palette.clone()),
+                        get_style(self.props.tooltip_style.clone()),
+                        get_size(self.props.tooltip_size.clone()),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
             >
              {self.props.content.clone()}
             </div>
@@ -150,6 +199,7 @@ impl Component for Tooltip {
             <div class="tooltip-container"
                 onmouseover = self.link.callback(|_| Msg::TargetOver)
                 onmouseleave = self.link.callback(|_| Msg::TargetLeave)
+                ref=self.container_ref.clone()
             >
                 {if self.show_tooltip {
                     tooltip
@@ -162,6 +212,31 @@ impl Component for Tooltip {
     }
 }
 
+impl Tooltip {
+    fn listen_focus(&mut self) {
+        let element = match self.container_ref.cast::<Element>() {
+            Some(element) => element,
+            None => return,
+        };
+        let link = self.link.clone();
+        let focus_in = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::TargetOver)) as Box<dyn Fn(Event)>
+        );
+        let link = self.link.clone();
+        let focus_out = Closure::wrap(
+            Box::new(move |_: Event| link.send_message(Msg::TargetLeave)) as Box<dyn Fn(Event)>,
+        );
+
+        element
+            .add_event_listener_with_callback("focusin", focus_in.as_ref().unchecked_ref())
+            .ok();
+        element
+            .add_event_listener_with_callback("focusout", focus_out.as_ref().unchecked_ref())
+            .ok();
+        self.focus_listeners = Some((element, focus_in, focus_out));
+    }
+}
+
 fn get_position(position: Position) -> String {
     match position {
         Position::Left => String::from("left"),
@@ -173,19 +248,18 @@ fn get_position(position: Position) -> String {
 
 #[wasm_bindgen_test]
 fn should_create_tooltip() {
-    let tooltip_props = Props {
-        tooltip_palette: Palette::Clean,
-        tooltip_style: Style::Regular,
-        tooltip_size: Size::Medium,
-        tooltip_position: Position::Above,
-        content: html! {<p>{"tooltip"}</p>},
-        code_ref: NodeRef::default(),
-        key: String::from("dropdown-1"),
-        class_name: String::from("class-test"),
-        id: String::from("id-test"),
-        styles: css!("color: blue;"),
-        children: Children::new(vec![html! {<div id="result">{"result"}</div>}]),
-    };
+    let tooltip_props = Props::builder()
+        .class_name(String::from("class-test"))
+        .content(html! {<p>{"tooltip"}</p>})
+        .id(String::from("id-test"))
+        .key(String::from("dropdown-1"))
+        .styles(css!("color: blue;"))
+        .tooltip_palette(Palette::Clean)
+        .tooltip_position(Position::Above)
+        .children(Children::new(vec![
+            html! {<div id="result">{"result"}</div>},
+        ]))
+        .build();
 
     let tooltip: App<Tooltip> = App::new();
 
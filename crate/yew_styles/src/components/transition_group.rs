@@ -0,0 +1,378 @@
+use crate::styles::animations::{animation, prefers_reduced_motion, Keyframes};
+use crate::styles::tokens::{Duration, Easing};
+use std::collections::HashMap;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::virtual_dom::Key;
+use yew::{utils, App};
+
+/// # Transition Group component
+///
+/// Animates the children of a keyed list when they are added or removed, instead of appearing
+/// and disappearing at once. The children are matched by their `key`: a new key enters with the
+/// transition and a removed one stays in its place while the transition runs backwards.
+/// It doesn't add a container, so the items are laid out by the parent element, e.g. a flex column.
+/// When the motion is reduced the items are added and removed at once
+///
+/// ## Features required
+///
+/// transition_group
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::transition_group::{Transition, TransitionGroup};
+///
+/// pub struct Tasks {
+///     link: ComponentLink<Self>,
+///     tasks: Vec<String>,
+/// }
+///
+/// pub enum Msg {
+///     Remove(usize),
+/// }
+///
+/// impl Component for Tasks {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         Self {
+///             link,
+///             tasks: vec!["Write the docs".to_string(), "Release".to_string()],
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Remove(index) => {
+///                 self.tasks.remove(index);
+///             }
+///         };
+///         true
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <div class="tasks">
+///                 <TransitionGroup transition=Transition::Collapse>
+///                     {for self.tasks.iter().enumerate().map(|(index, task)| html! {
+///                         <div key=task.clone() onclick=self.link.callback(move |_| Msg::Remove(index))>
+///                             {task}
+///                         </div>
+///                     })}
+///                 </TransitionGroup>
+///             </div>
+///         }
+///     }
+/// }
+/// ```
+pub struct TransitionGroup {
+    link: ComponentLink<Self>,
+    props: Props,
+    items: Vec<TransitionItem>,
+}
+
+struct TransitionItem {
+    key: Key,
+    node: Html,
+    phase: Phase,
+    // dropping the task cancels the end of the transition, e.g. when an item comes back while it is leaving
+    task: Option<TimeoutTask>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Phase {
+    Entering,
+    Entered,
+    Leaving,
+}
+
+/// Animation of the items which enter and leave
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Transition {
+    Fade,
+    Slide,
+    Collapse,
+}
+
+impl Transition {
+    fn keyframes(self) -> Keyframes {
+        match self {
+            Transition::Fade => Keyframes::FadeIn,
+            Transition::Slide => Keyframes::SlideInUp,
+            Transition::Collapse => Keyframes::CollapseIn,
+        }
+    }
+
+    fn class_name(self) -> &'static str {
+        match self {
+            Transition::Fade => "fade",
+            Transition::Slide => "slide",
+            Transition::Collapse => "collapse",
+        }
+    }
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Keyed items of the list. Required
+    pub children: Children,
+    /// Animation of the items. Default `Transition::Fade`
+    #[prop_or(Transition::Fade)]
+    pub transition: Transition,
+    /// Duration of the animation. Default `Duration::Normal`
+    #[prop_or(Duration::Normal)]
+    pub duration: Duration,
+    /// Easing of the animation. Default `Easing::Standard`
+    #[prop_or(Easing::Standard)]
+    pub easing: Easing,
+    /// Animate the items shown when the group is mounted. Default `false`
+    #[prop_or(false)]
+    pub appear: bool,
+}
+
+pub enum Msg {
+    Entered(Key),
+    Left(Key),
+}
+
+impl TransitionGroup {
+    fn spawn_end(&self, msg: fn(Key) -> Msg, key: Key) -> Option<TimeoutTask> {
+        Some(TimeoutService::spawn(
+            std::time::Duration::from_millis(self.props.duration.millis()),
+            self.link.callback_once(move |_| msg(key)),
+        ))
+    }
+
+    fn enter(&self, key: Key, node: Html, animate: bool) -> TransitionItem {
+        if animate && !prefers_reduced_motion() {
+            TransitionItem {
+                task: self.spawn_end(Msg::Entered, key.clone()),
+                key,
+                node,
+                phase: Phase::Entering,
+            }
+        } else {
+            TransitionItem {
+                key,
+                node,
+                phase: Phase::Entered,
+                task: None,
+            }
+        }
+    }
+}
+
+impl Component for TransitionGroup {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut transition_group = Self {
+            link,
+            props,
+            items: vec![],
+        };
+
+        transition_group.items = get_keyed_children(&transition_group.props.children)
+            .into_iter()
+            .map(|(key, node)| transition_group.enter(key, node, transition_group.props.appear))
+            .collect();
+
+        transition_group
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Entered(key) => {
+                if let Some(item) = self.items.iter_mut().find(|item| item.key == key) {
+                    item.phase = Phase::Entered;
+                    item.task = None;
+                }
+            }
+            Msg::Left(key) => {
+                self.items
+                    .retain(|item| item.key != key || item.phase != Phase::Leaving);
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        let children_changed = self.props.children != props.children;
+        self.props = props;
+
+        if children_changed {
+            let keyed_children = get_keyed_children(&self.props.children);
+            let next_keys: Vec<Key> = keyed_children.iter().map(|(key, _)| key.clone()).collect();
+            let mut next: HashMap<Key, Html> = keyed_children.into_iter().collect();
+            let current_keys: Vec<Key> = self.items.iter().map(|item| item.key.clone()).collect();
+            let mut current: HashMap<Key, TransitionItem> = self
+                .items
+                .drain(..)
+                .map(|item| (item.key.clone(), item))
+                .collect();
+            let reduced_motion = prefers_reduced_motion();
+
+            for key in merge_keys(&current_keys, &next_keys) {
+                let item = match (current.remove(&key), next.remove(&key)) {
+                    (Some(item), Some(node)) if item.phase == Phase::Leaving => {
+                        self.enter(key, node, true)
+                    }
+                    (Some(item), Some(node)) => TransitionItem { node, ..item },
+                    (None, Some(node)) => self.enter(key, node, true),
+                    (Some(item), None) if item.phase == Phase::Leaving => item,
+                    (Some(_), None) if reduced_motion => continue,
+                    (Some(item), None) => TransitionItem {
+                        task: self.spawn_end(Msg::Left, key),
+                        phase: Phase::Leaving,
+                        ..item
+                    },
+                    (None, None) => continue,
+                };
+
+                self.items.push(item);
+            }
+        }
+
+        true
+    }
+
+    fn view(&self) -> Html {
+        let keyframes = self.props.transition.keyframes();
+
+        html! {
+            <>
+                {for self.items.iter().map(|item| {
+                    let style = match item.phase {
+                        Phase::Entering => Some(format!(
+                            "animation: {};",
+                            animation(keyframes, self.props.duration, self.props.easing)
+                        )),
+                        Phase::Leaving => Some(format!(
+                            "animation: {} reverse;",
+                            animation(keyframes, self.props.duration, self.props.easing)
+                        )),
+                        Phase::Entered => None,
+                    };
+
+                    html! {
+                        <div
+                            class=classes!(
+                                "transition-item",
+                                self.props.transition.class_name(),
+                                if item.phase == Phase::Leaving { Some("leaving") } else { None },
+                            )
+                            key=item.key.clone()
+                            style=style
+                            aria-hidden=if item.phase == Phase::Leaving { Some("true") } else { None }
+                        >
+                            <div class="transition-item-content">{item.node.clone()}</div>
+                        </div>
+                    }
+                })}
+            </>
+        }
+    }
+}
+
+/// Children with their key, the position is used as key when the child doesn't have one
+fn get_keyed_children(children: &Children) -> Vec<(Key, Html)> {
+    children
+        .iter()
+        .enumerate()
+        .map(|(index, child)| {
+            let key = child
+                .key()
+                .unwrap_or_else(|| Key::from(format!("transition-item-{}", index)));
+
+            (key, child)
+        })
+        .collect()
+}
+
+/// Order of the keys after a change: the next keys in their order, and the removed ones
+/// after the closest key which was before them and is kept
+fn merge_keys(current: &[Key], next: &[Key]) -> Vec<Key> {
+    let mut removed_after: HashMap<Option<Key>, Vec<Key>> = HashMap::new();
+    let mut previous: Option<Key> = None;
+
+    for key in current {
+        if next.contains(key) {
+            previous = Some(key.clone());
+        } else {
+            removed_after
+                .entry(previous.clone())
+                .or_default()
+                .push(key.clone());
+        }
+    }
+
+    let mut merged = removed_after.remove(&None).unwrap_or_default();
+
+    for key in next {
+        merged.push(key.clone());
+        merged.extend(removed_after.remove(&Some(key.clone())).unwrap_or_default());
+    }
+
+    merged
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_keep_the_removed_keys_in_their_place() {
+    let keys = |keys: &[&str]| -> Vec<Key> { keys.iter().map(|key| Key::from(*key)).collect() };
+
+    assert_eq!(
+        merge_keys(&keys(&["a", "b", "c"]), &keys(&["a", "c", "d"])),
+        keys(&["a", "b", "c", "d"])
+    );
+    assert_eq!(
+        merge_keys(&keys(&["a", "b"]), &keys(&["c", "b"])),
+        keys(&["a", "c", "b"])
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_render_the_items_of_the_group() {
+    let props = Props::builder()
+        .transition(Transition::Collapse)
+        .children(Children::new(vec![
+            html! {<span key="first" class="transition-group-test">{"First"}</span>},
+            html! {<span key="second" class="transition-group-test">{"Second"}</span>},
+        ]))
+        .build();
+
+    let transition_group: App<TransitionGroup> = App::new();
+    transition_group.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let items = utils::document().get_elements_by_class_name("transition-group-test");
+
+    assert_eq!(items.length(), 2);
+    assert!(items
+        .get_with_index(0)
+        .unwrap()
+        .parent_element()
+        .unwrap()
+        .parent_element()
+        .unwrap()
+        .class_list()
+        .contains("collapse"));
+}
@@ -0,0 +1,320 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use crate::utils::copy_to_clipboard;
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::services::resize::{ResizeService, ResizeTask};
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::{utils, App};
+
+const COPIED_DURATION: u64 = 1500;
+
+/// # Truncate middle component
+///
+/// Single line text which keeps visible its start and end, cutting the middle with an ellipsis
+/// when it doesn't fit, e.g. file paths, hashes or addresses. The cut is measured again when
+/// the window is resized, the full text is shown in the native tooltip and `copyable` adds
+/// a button to copy it
+///
+/// ## Features required
+///
+/// truncate_middle
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::truncate_middle::TruncateMiddle;
+///
+/// pub struct Commit;
+///
+/// impl Component for Commit {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <TruncateMiddle text="/home/user/projects/yew_styles/src/components/truncate_middle.rs" end_chars=Some(18) />
+///                 <TruncateMiddle text="9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08" copyable=true />
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct TruncateMiddle {
+    link: ComponentLink<Self>,
+    props: Props,
+    text_ref: NodeRef,
+    measure_ref: NodeRef,
+    kept: Option<usize>,
+    copied: bool,
+    resize_task: Option<ResizeTask>,
+    copied_task: Option<TimeoutTask>,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Full text. Required
+    pub text: String,
+    /// Number of characters always kept at the end, e.g. for the file extensions.
+    /// `None` keeps the same number at the start and the end. Default `None`
+    #[prop_or_default]
+    pub end_chars: Option<usize>,
+    /// Show the full text in the native tooltip. Default `true`
+    #[prop_or(true)]
+    pub tooltip: bool,
+    /// Add a button to copy the full text. Default `false`
+    #[prop_or(false)]
+    pub copyable: bool,
+    /// Emitted with the full text when it is copied
+    #[prop_or(Callback::noop())]
+    pub oncopy_signal: Callback<String>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Measure,
+    Copy,
+    ResetCopied,
+}
+
+impl Component for TruncateMiddle {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            text_ref: NodeRef::default(),
+            measure_ref: NodeRef::default(),
+            kept: None,
+            copied: false,
+            resize_task: None,
+            copied_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Measure => {
+                let kept = self.measure();
+
+                if kept != self.kept {
+                    self.kept = kept;
+                    return true;
+                }
+            }
+            Msg::Copy => {
+                if copy_to_clipboard(&self.props.text) {
+                    self.copied = true;
+                    self.copied_task = Some(TimeoutService::spawn(
+                        std::time::Duration::from_millis(COPIED_DURATION),
+                        self.link.callback(|_| Msg::ResetCopied),
+                    ));
+                    self.props.oncopy_signal.emit(self.props.text.clone());
+                    return true;
+                }
+            }
+            Msg::ResetCopied => {
+                self.copied = false;
+                self.copied_task = None;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.resize_task = Some(ResizeService::register(
+                self.link.callback(|_| Msg::Measure),
+            ));
+        }
+
+        self.link.send_message(Msg::Measure);
+    }
+
+    fn view(&self) -> Html {
+        let messages = get_messages();
+        let display = match self.kept {
+            Some(kept) => truncate_middle(&self.props.text, kept, self.props.end_chars),
+            None => self.props.text.clone(),
+        };
+
+        html! {
+            <span
+                class=ComponentClasses::new("truncate-middle")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                title=if self.props.tooltip { Some(self.props.text.clone()) } else { None }
+            >
+                <span class="truncate-middle-text" aria-hidden="true" ref=self.text_ref.clone()>
+                    {display}
+                </span>
+                <span class="truncate-middle-value">{&self.props.text}</span>
+                <span class="truncate-middle-measure" aria-hidden="true" ref=self.measure_ref.clone()></span>
+                {if self.props.copyable {
+                    html! {
+                        <button
+                            class=classes!("truncate-middle-copy", if self.copied { Some("copied") } else { None })
+                            onclick=self.link.callback(|_| Msg::Copy)
+                        >
+                            {if self.copied { messages.copied } else { messages.copy }}
+                        </button>
+                    }
+                } else {
+                    html! {}
+                }}
+            </span>
+        }
+    }
+}
+
+impl TruncateMiddle {
+    // Largest number of characters which fits in the text width, `None` when the full text fits
+    fn measure(&self) -> Option<usize> {
+        let text = self.text_ref.cast::<HtmlElement>()?;
+        let measure = self.measure_ref.cast::<HtmlElement>()?;
+        let available = text.client_width();
+        let fits = |value: &str| {
+            measure.set_text_content(Some(value));
+            measure.offset_width() <= available
+        };
+
+        let kept = if fits(&self.props.text) {
+            None
+        } else {
+            let (mut low, mut high) = (0, self.props.text.chars().count());
+
+            while low < high {
+                let middle = (low + high + 1) / 2;
+
+                if fits(&truncate_middle(
+                    &self.props.text,
+                    middle,
+                    self.props.end_chars,
+                )) {
+                    low = middle;
+                } else {
+                    high = middle - 1;
+                }
+            }
+
+            Some(low)
+        };
+
+        measure.set_text_content(None);
+        kept
+    }
+}
+
+/// Keep `kept` characters of the text, from the start and the end, joined by an ellipsis.
+/// `end_chars` sets how many of them are from the end, by default the half
+pub fn truncate_middle(text: &str, kept: usize, end_chars: Option<usize>) -> String {
+    let chars: Vec<char> = text.chars().collect();
+
+    if kept >= chars.len() {
+        return text.to_string();
+    }
+
+    let end = end_chars.map_or(kept / 2, |end_chars| end_chars.min(kept));
+    let start = kept - end;
+
+    format!(
+        "{}…{}",
+        chars[..start].iter().collect::<String>(),
+        chars[chars.len() - end..].iter().collect::<String>()
+    )
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_show_the_full_text_in_the_tooltip() {
+    let props = Props::builder()
+        .copyable(true)
+        .id("truncate-middle-test".to_string())
+        .text("0x71C7656EC7ab88b098defB751B7401B5f6d8976F".to_string())
+        .build();
+
+    let truncate_middle: App<TruncateMiddle> = App::new();
+    truncate_middle.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let truncate_middle_element = utils::document()
+        .get_element_by_id("truncate-middle-test")
+        .unwrap();
+
+    assert_eq!(
+        truncate_middle_element.get_attribute("title").unwrap(),
+        "0x71C7656EC7ab88b098defB751B7401B5f6d8976F"
+    );
+    assert_eq!(
+        truncate_middle_element
+            .get_elements_by_class_name("truncate-middle-copy")
+            .length(),
+        1
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_keep_the_start_and_the_end() {
+    assert_eq!(truncate_middle("abcdefghij", 4, None), "ab…ij");
+    assert_eq!(truncate_middle("abcdefghij", 5, None), "abc…ij");
+    assert_eq!(truncate_middle("report.final.pdf", 6, Some(4)), "re….pdf");
+    assert_eq!(truncate_middle("abc", 3, None), "abc");
+    assert_eq!(truncate_middle("ñandú", 2, Some(5)), "…dú");
+}
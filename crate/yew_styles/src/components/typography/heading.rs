@@ -0,0 +1,205 @@
+use crate::styles::{
+    tokens::{FontSize, FontWeight, Spaces},
+    ComponentClasses,
+};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Heading component
+///
+/// Title from `h1` to `h6` with the size and weight of the typography tokens
+///
+/// ## Features required
+///
+/// typography
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::styles::tokens::{FontSize, FontWeight};
+/// use yew_styles::typography::{Heading, HeadingLevel};
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Heading heading_level=HeadingLevel::H1>{"Yew Styles"}</Heading>
+///                 <Heading
+///                     heading_level=HeadingLevel::H2
+///                     heading_size=FontSize::Medium
+///                     heading_weight=FontWeight::Regular
+///                 >{"Framework styles for yew"}</Heading>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Heading {
+    props: Props,
+}
+
+/// Level of the heading element
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeadingLevel {
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+}
+
+impl HeadingLevel {
+    fn tag(self) -> &'static str {
+        match self {
+            HeadingLevel::H1 => "h1",
+            HeadingLevel::H2 => "h2",
+            HeadingLevel::H3 => "h3",
+            HeadingLevel::H4 => "h4",
+            HeadingLevel::H5 => "h5",
+            HeadingLevel::H6 => "h6",
+        }
+    }
+
+    fn font_size(self) -> FontSize {
+        match self {
+            HeadingLevel::H1 => FontSize::Headline,
+            HeadingLevel::H2 => FontSize::Big,
+            HeadingLevel::H3 => FontSize::Medium,
+            HeadingLevel::H4 => FontSize::Body,
+            HeadingLevel::H5 => FontSize::Small,
+            HeadingLevel::H6 => FontSize::Caption,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Heading element from h1 to h6. Default `HeadingLevel::H2`
+    #[prop_or(HeadingLevel::H2)]
+    pub heading_level: HeadingLevel,
+    /// Font size of the heading.
+    /// Default the size of the level, from `FontSize::Headline` in h1 to `FontSize::Caption` in h6
+    #[prop_or_default]
+    pub heading_size: Option<FontSize>,
+    /// Font weight of the heading. Default `FontWeight::Bold`
+    #[prop_or(FontWeight::Bold)]
+    pub heading_weight: FontWeight,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for Heading {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let font_size = self
+            .props
+            .heading_size
+            .unwrap_or_else(|| self.props.heading_level.font_size());
+
+        html! {
+            <@{self.props.heading_level.tag()}
+                class=ComponentClasses::new("heading")
+                    .semantic(vec![
+                        format!("font-size-{}", font_size.name()),
+                        format!("font-weight-{}", self.props.heading_weight.name()),
+                    ])
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                {self.props.children.clone()}
+            </@>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_heading_with_the_size_of_the_level() {
+    let props = Props::builder()
+        .heading_level(HeadingLevel::H3)
+        .heading_weight(FontWeight::Medium)
+        .id("heading".to_string())
+        .children(Children::new(vec![html! {"Title"}]))
+        .build();
+
+    let heading: App<Heading> = App::new();
+
+    heading.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let heading_element = utils::document().get_element_by_id("heading").unwrap();
+
+    assert_eq!(heading_element.tag_name(), "H3");
+    assert!(heading_element.class_list().contains("font-size-medium"));
+    assert!(heading_element.class_list().contains("font-weight-medium"));
+    assert_eq!(heading_element.text_content().unwrap(), "Title");
+}
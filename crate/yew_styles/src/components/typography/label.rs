@@ -0,0 +1,156 @@
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Label component
+///
+/// Label of a form control with the typography tokens
+///
+/// ## Features required
+///
+/// typography
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::typography::Label;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Label label_for="email" required=true>{"Email"}</Label>
+///                 <input id="email" type="email"/>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Label {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Id of the control described by the label
+    #[prop_or_default]
+    pub label_for: String,
+    /// Show a mark to indicate that the control is required. Default `false`
+    #[prop_or(false)]
+    pub required: bool,
+    /// Secondary label with less contrast. Default `false`
+    #[prop_or(false)]
+    pub muted: bool,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+impl Component for Label {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <label
+                class=ComponentClasses::new("typography-label")
+                    .state("muted", self.props.muted)
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                for=self.props.label_for.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                id=self.props.id.clone()
+            >
+                {self.props.children.clone()}
+                {if self.props.required {
+                    html! {<span class="label-required" aria-hidden="true">{"*"}</span>}
+                } else {
+                    html! {}
+                }}
+            </label>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_required_label() {
+    let props = Props::builder()
+        .id("label".to_string())
+        .label_for("email".to_string())
+        .required(true)
+        .children(Children::new(vec![html! {"Email"}]))
+        .build();
+
+    let label: App<Label> = App::new();
+
+    label.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let label_element = utils::document().get_element_by_id("label").unwrap();
+
+    assert_eq!(label_element.get_attribute("for").unwrap(), "email");
+    assert_eq!(label_element.text_content().unwrap(), "Email*");
+}
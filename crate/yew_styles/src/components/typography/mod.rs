@@ -0,0 +1,7 @@
+mod heading;
+mod label;
+mod text;
+
+pub use heading::{Heading, HeadingLevel};
+pub use label::Label;
+pub use text::Text;
@@ -0,0 +1,308 @@
+use crate::messages::get_messages;
+use crate::styles::{tokens::Spaces, ComponentClasses};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::services::resize::{ResizeService, ResizeTask};
+use yew::{utils, App};
+
+/// # Typography Text component
+///
+/// Paragraph or inline text with the typography tokens.
+/// It is different from `text::Text`, which shows alerts and tags.
+/// With `line_clamp` the text is cut after that number of lines and a show more button
+/// is added only when the content doesn't fit, which is measured again when the window is resized
+///
+/// ## Features required
+///
+/// typography
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::typography::Text;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <Text>{"Yew Styles is a framework of styles for yew"}</Text>
+///                 <Text inline=true muted=true small=true>{"Last update 2 days ago"}</Text>
+///                 <Text truncate=true bold=true>{"A very long title that does not fit in one line"}</Text>
+///                 <Text line_clamp=Some(3)>{"A long description which is expanded with the show more button"}</Text>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct Text {
+    link: ComponentLink<Self>,
+    props: Props,
+    expanded: bool,
+    overflowing: bool,
+    resize_task: Option<ResizeTask>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Render a span instead of a paragraph. Default `false`
+    #[prop_or(false)]
+    pub inline: bool,
+    /// Secondary text with less contrast. Default `false`
+    #[prop_or(false)]
+    pub muted: bool,
+    /// Small font size. Default `false`
+    #[prop_or(false)]
+    pub small: bool,
+    /// Bold font weight. Default `false`
+    #[prop_or(false)]
+    pub bold: bool,
+    /// Cut the text with an ellipsis when it doesn't fit in one line. Default `false`
+    #[prop_or(false)]
+    pub truncate: bool,
+    /// Maximum number of lines before the text is cut, adding a show more button when it overflows. Default `None`
+    #[prop_or_default]
+    pub line_clamp: Option<u32>,
+    /// Emitted with `true` when the clamped text is expanded and `false` when it is clamped again
+    #[prop_or(Callback::noop())]
+    pub ontoggle_signal: Callback<bool>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+    pub children: Children,
+}
+
+pub enum Msg {
+    Toggle,
+    Measure,
+}
+
+impl Component for Text {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            expanded: false,
+            overflowing: false,
+            resize_task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                self.expanded = !self.expanded;
+                self.props.ontoggle_signal.emit(self.expanded);
+                true
+            }
+            Msg::Measure => {
+                // while expanded the clamp is removed, so the last measure is kept
+                if self.expanded {
+                    return false;
+                }
+
+                let overflowing = self
+                    .props
+                    .code_ref
+                    .cast::<Element>()
+                    .map_or(false, |element| is_overflowing(&element));
+
+                if overflowing != self.overflowing {
+                    self.overflowing = overflowing;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.line_clamp != props.line_clamp {
+                self.expanded = false;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.props.line_clamp.is_some() {
+            if self.resize_task.is_none() {
+                self.resize_task = Some(ResizeService::register(
+                    self.link.callback(|_| Msg::Measure),
+                ));
+            }
+
+            self.link.send_message(Msg::Measure);
+        } else {
+            self.resize_task = None;
+            self.overflowing = false;
+        }
+    }
+
+    fn view(&self) -> Html {
+        let clamped = self.props.line_clamp.is_some() && !self.expanded;
+
+        html! {
+            <>
+                <@{if self.props.inline { "span" } else { "p" }}
+                    class=ComponentClasses::new("typography-text")
+                        .state("muted", self.props.muted)
+                        .state("small", self.props.small)
+                        .state("bold", self.props.bold)
+                        .state("truncate", self.props.truncate)
+                        .state("clamped", clamped)
+                        .spacing(self.props.margin, self.props.padding)
+                        .styles(self.props.styles.clone())
+                        .class_name(self.props.class_name.clone())
+                        .build()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                    id=self.props.id.clone()
+                    style=if clamped { get_clamp_style(self.props.line_clamp) } else { String::new() }
+                >
+                    {self.props.children.clone()}
+                </@>
+                {if self.overflowing {
+                    self.get_toggle()
+                } else {
+                    html! {}
+                }}
+            </>
+        }
+    }
+}
+
+impl Text {
+    fn get_toggle(&self) -> Html {
+        let messages = get_messages();
+
+        html! {
+            <button
+                class="typography-text-toggle"
+                aria-expanded=self.expanded.to_string()
+                aria-controls=self.props.id.clone()
+                onclick=self.link.callback(|_| Msg::Toggle)
+            >
+                {if self.expanded {
+                    messages.show_less
+                } else {
+                    messages.show_more
+                }}
+            </button>
+        }
+    }
+}
+
+fn get_clamp_style(line_clamp: Option<u32>) -> String {
+    match line_clamp {
+        Some(lines) => format!("-webkit-line-clamp: {}; line-clamp: {};", lines, lines),
+        None => String::new(),
+    }
+}
+
+fn is_overflowing(element: &Element) -> bool {
+    element.scroll_height() > element.client_height()
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_inline_text_with_variants() {
+    let props = Props::builder()
+        .id("typography-text".to_string())
+        .inline(true)
+        .muted(true)
+        .truncate(true)
+        .children(Children::new(vec![html! {"Last update"}]))
+        .build();
+
+    let text: App<Text> = App::new();
+
+    text.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let text_element = utils::document()
+        .get_element_by_id("typography-text")
+        .unwrap();
+
+    assert_eq!(text_element.tag_name(), "SPAN");
+    assert!(text_element.class_list().contains("muted"));
+    assert!(text_element.class_list().contains("truncate"));
+    assert!(!text_element.class_list().contains("bold"));
+}
+
+#[wasm_bindgen_test]
+fn should_clamp_the_text_without_toggle_when_it_fits() {
+    let props = Props::builder()
+        .id("clamped-text".to_string())
+        .line_clamp(Some(3))
+        .children(Children::new(vec![html! {"Short"}]))
+        .build();
+
+    let text: App<Text> = App::new();
+
+    text.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let text_element = utils::document().get_element_by_id("clamped-text").unwrap();
+
+    assert!(text_element.class_list().contains("clamped"));
+    assert_eq!(
+        text_element.get_attribute("style").unwrap(),
+        "-webkit-line-clamp: 3; line-clamp: 3;"
+    );
+    assert!(utils::document()
+        .get_elements_by_class_name("typography-text-toggle")
+        .get_with_index(0)
+        .is_none());
+}
@@ -0,0 +1,246 @@
+use crate::messages::{format_message, get_messages};
+use crate::modal::Modal;
+use crate::styles::{tokens::Spaces, ComponentClasses, Palette, Size};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Video Thumb component
+///
+/// Poster of a video with a play button over it. The video is played in a modal,
+/// which is closed with `Escape`, clicking outside of the video or,
+/// with `close_on_end`, when the video ends
+///
+/// ## Features required
+///
+/// video_thumb
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::video_thumb::VideoThumb;
+///
+/// pub struct Trailer;
+///
+/// impl Component for Trailer {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <VideoThumb
+///                 video_src="/trailer.mp4"
+///                 poster_src="/trailer.jpg"
+///                 title="Official trailer"
+///                 close_on_end=true
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct VideoThumb {
+    link: ComponentLink<Self>,
+    props: Props,
+    open: bool,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Url path of the video. Required
+    pub video_src: String,
+    /// Url path of the poster image. Required
+    pub poster_src: String,
+    /// Title of the video, used in the label of the play button. Default `""`
+    #[prop_or_default]
+    pub title: String,
+    /// Start the video when the modal is opened. Default `true`
+    #[prop_or(true)]
+    pub autoplay: bool,
+    /// Close the modal when the video ends. Default `false`
+    #[prop_or(false)]
+    pub close_on_end: bool,
+    /// Show the controls of the browser in the video. Default `true`
+    #[prop_or(true)]
+    pub controls: bool,
+    /// Emitted when the video starts playing
+    #[prop_or(Callback::noop())]
+    pub onplay_signal: Callback<()>,
+    /// Size of the modal. Default `Size::Big`
+    #[prop_or(Size::Big)]
+    pub modal_size: Size,
+    /// Type modal background style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub modal_palette: Palette,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+    #[prop_or_default]
+    pub margin: Spaces,
+    /// Padding from the spacing scale. Default `Spaces::default()`
+    #[prop_or_default]
+    pub padding: Spaces,
+}
+
+pub enum Msg {
+    Open,
+    Close,
+    Played,
+    Ended,
+}
+
+impl Component for VideoThumb {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            open: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open => {
+                self.open = true;
+            }
+            Msg::Close => {
+                self.open = false;
+            }
+            Msg::Played => {
+                self.props.onplay_signal.emit(());
+                return false;
+            }
+            Msg::Ended => {
+                if !self.props.close_on_end {
+                    return false;
+                }
+                self.open = false;
+            }
+        }
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            return true;
+        }
+
+        false
+    }
+
+    fn view(&self) -> Html {
+        let label = format_message(
+            &get_messages().play_video,
+            &[("title", self.props.title.clone())],
+        );
+
+        html! {
+            <div
+                class=ComponentClasses::new("video-thumb")
+                    .spacing(self.props.margin, self.props.padding)
+                    .styles(self.props.styles.clone())
+                    .class_name(self.props.class_name.clone())
+                    .build()
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <button
+                    class="video-thumb-button"
+                    aria-label=label.trim().to_string()
+                    aria-haspopup="dialog"
+                    onclick=self.link.callback(|_| Msg::Open)
+                >
+                    <img class="video-thumb-poster" src=self.props.poster_src.clone() alt=""/>
+                    <span class="video-thumb-play" aria-hidden="true"></span>
+                </button>
+                <Modal
+                    class_name="video-thumb-modal"
+                    header=html! {<span class="video-thumb-title">{&self.props.title}</span>}
+                    body=html! {
+                        <video
+                            class="video-thumb-video"
+                            src=self.props.video_src.clone()
+                            poster=self.props.poster_src.clone()
+                            autoplay=if self.props.autoplay { Some("") } else { None }
+                            controls=self.props.controls
+                            onplay=self.link.callback(|_| Msg::Played)
+                            onended=self.link.callback(|_| Msg::Ended)
+                        ></video>
+                    }
+                    is_open=self.open
+                    modal_size=self.props.modal_size.clone()
+                    modal_palette=self.props.modal_palette.clone()
+                    onclick_signal=self.link.callback(|_| Msg::Close)
+                    onescape_signal=self.link.callback(|_| Msg::Close)
+                />
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_video_thumb() {
+    let props = Props::builder()
+        .id("video-thumb-test".to_string())
+        .poster_src("/slide_1.jpg".to_string())
+        .title("Trailer".to_string())
+        .video_src("/trailer.mp4".to_string())
+        .build();
+
+    let video_thumb: App<VideoThumb> = App::new();
+    video_thumb.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button = utils::document()
+        .get_element_by_id("video-thumb-test")
+        .unwrap()
+        .first_element_child()
+        .unwrap();
+
+    assert_eq!(
+        button.get_attribute("aria-label").unwrap(),
+        "Play video Trailer"
+    );
+    assert_eq!(
+        utils::document()
+            .get_elements_by_class_name("video-thumb-video")
+            .length(),
+        0
+    );
+}
@@ -0,0 +1,500 @@
+//! # Hotkeys
+//!
+//! Central registry of the keyboard shortcuts. A single listener on the window dispatches each key combination
+//! to the callbacks registered for it, the registration lasts while its `HotkeyHandle` is kept.
+//! A hotkey is scoped to the whole app, to a region of the page which has the focus or to an overlay layer:
+//! the callbacks of the overlay on top go first, then the ones of the innermost focused region
+//! and last the ones of the app.
+//! The modal and the gallery viewer register `Escape` on their layer, so only the overlay on top is closed.
+//! While the focus is in a text field only the combinations with `Ctrl`, `Alt` or `Meta` and `Escape` are dispatched
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_styles::hotkeys::{register_hotkey, Hotkey, HotkeyHandle, HotkeyScope};
+//!
+//! pub struct Editor {
+//!     link: ComponentLink<Self>,
+//!     editor_ref: NodeRef,
+//!     hotkeys: Vec<HotkeyHandle>,
+//! }
+//!
+//! pub enum Msg {
+//!     Save,
+//!     Bold,
+//! }
+//!
+//! impl Component for Editor {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+//!         let editor_ref = NodeRef::default();
+//!         let hotkeys = vec![
+//!             register_hotkey(
+//!                 Hotkey::parse("Ctrl+S"),
+//!                 HotkeyScope::App,
+//!                 link.callback(|_| Msg::Save),
+//!             ),
+//!             register_hotkey(
+//!                 Hotkey::new("b").ctrl(),
+//!                 HotkeyScope::Region(editor_ref.clone()),
+//!                 link.callback(|_| Msg::Bold),
+//!             ),
+//!         ];
+//!
+//!         Self { link, editor_ref, hotkeys }
+//!     }
+//!
+//!     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+//!         match msg {
+//!             Msg::Save => {}
+//!             Msg::Bold => {}
+//!         };
+//!         false
+//!     }
+//!
+//!     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+//!         false
+//!     }
+//!
+//!     fn view(&self) -> Html {
+//!         html! {
+//!             <div class="editor" ref=self.editor_ref.clone()>
+//!                 <textarea />
+//!             </div>
+//!         }
+//!     }
+//! }
+//! ```
+use crate::layers::get_top_layer_id;
+use crate::utils::is_browser;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, Node};
+use yew::prelude::*;
+use yew::utils;
+
+/// Key combination: a value of `KeyboardEvent.key` and the modifiers pressed with it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hotkey {
+    key: String,
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    meta: bool,
+}
+
+impl Hotkey {
+    /// Combination of the key without modifiers, e.g. `Hotkey::new("Escape")`
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            ctrl: false,
+            shift: false,
+            alt: false,
+            meta: false,
+        }
+    }
+
+    /// Combination written as the modifiers and the key joined by `+`, e.g. `"Ctrl+Shift+K"`.
+    /// The modifiers are `Ctrl`, `Shift`, `Alt` and `Meta`, `Space` is the space key
+    pub fn parse(combination: &str) -> Self {
+        let mut parts: Vec<&str> = combination.split('+').map(|part| part.trim()).collect();
+        let key = match parts.pop() {
+            Some("Space") | Some("") => " ",
+            Some(key) => key,
+            None => "",
+        };
+
+        parts
+            .into_iter()
+            .fold(Hotkey::new(key), |hotkey, modifier| {
+                match modifier.to_lowercase().as_str() {
+                    "ctrl" | "control" => hotkey.ctrl(),
+                    "shift" => hotkey.shift(),
+                    "alt" | "option" => hotkey.alt(),
+                    "meta" | "cmd" | "command" => hotkey.meta(),
+                    _ => hotkey,
+                }
+            })
+    }
+
+    /// Pressed with Ctrl
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Pressed with Shift
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Pressed with Alt
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Pressed with Meta, the command key on macOS
+    pub fn meta(mut self) -> Self {
+        self.meta = true;
+        self
+    }
+
+    /// The key and exactly the same modifiers are pressed, the letters match in any case
+    pub fn matches(&self, keyboard_event: &KeyboardEvent) -> bool {
+        keyboard_event.key().eq_ignore_ascii_case(&self.key)
+            && keyboard_event.ctrl_key() == self.ctrl
+            && keyboard_event.shift_key() == self.shift
+            && keyboard_event.alt_key() == self.alt
+            && keyboard_event.meta_key() == self.meta
+    }
+
+    /// It can be dispatched while the focus is in a text field without stopping the typing
+    fn is_allowed_in_fields(&self) -> bool {
+        self.ctrl || self.alt || self.meta || self.key == "Escape"
+    }
+}
+
+/// Where a hotkey is dispatched
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HotkeyScope {
+    /// Anywhere in the app
+    App,
+    /// While the focus is inside of the element, only the innermost of the nested regions receives the hotkey.
+    /// The element reference is not serialized
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Region(NodeRef),
+    /// While the overlay of the layer with this id is on top of the other overlays, see `layers::Layer::id`
+    Layer(usize),
+}
+
+/// Registration of a hotkey, it is removed when dropped
+#[derive(Debug, PartialEq)]
+pub struct HotkeyHandle {
+    id: usize,
+}
+
+impl Drop for HotkeyHandle {
+    fn drop(&mut self) {
+        HOTKEYS.with(|hotkeys| {
+            hotkeys
+                .borrow_mut()
+                .retain(|registration| registration.id != self.id)
+        });
+    }
+}
+
+struct Registration {
+    id: usize,
+    hotkey: Hotkey,
+    scope: HotkeyScope,
+    callback: Callback<KeyboardEvent>,
+}
+
+thread_local! {
+    static HOTKEYS: RefCell<Vec<Registration>> = RefCell::new(vec![]);
+    static NEXT_ID: RefCell<usize> = RefCell::new(0);
+    static LISTENER: RefCell<Option<Closure<dyn Fn(KeyboardEvent)>>> = RefCell::new(None);
+}
+
+/// Calls `callback` with the keyboard event when the combination is pressed in the scope
+pub fn register_hotkey(
+    hotkey: Hotkey,
+    scope: HotkeyScope,
+    callback: Callback<KeyboardEvent>,
+) -> HotkeyHandle {
+    listen();
+
+    let id = NEXT_ID.with(|next_id| {
+        let mut next_id = next_id.borrow_mut();
+        *next_id += 1;
+        *next_id
+    });
+
+    HOTKEYS.with(|hotkeys| {
+        hotkeys.borrow_mut().push(Registration {
+            id,
+            hotkey,
+            scope,
+            callback,
+        })
+    });
+
+    HotkeyHandle { id }
+}
+
+fn listen() {
+    if !is_browser() {
+        return;
+    }
+
+    LISTENER.with(|listener| {
+        let mut listener = listener.borrow_mut();
+
+        if listener.is_some() {
+            return;
+        }
+
+        let keydown = Closure::wrap(Box::new(dispatch) as Box<dyn Fn(KeyboardEvent)>);
+        utils::window()
+            .add_event_listener_with_callback("keydown", keydown.as_ref().unchecked_ref())
+            .ok();
+        *listener = Some(keydown);
+    });
+}
+
+fn dispatch(keyboard_event: KeyboardEvent) {
+    if keyboard_event.default_prevented() {
+        return;
+    }
+
+    let target = keyboard_event
+        .target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+    let in_field = target.as_ref().map_or(false, is_text_field);
+    let top_layer = get_top_layer_id();
+
+    // The callbacks are cloned so they can register or drop hotkeys
+    let callbacks: Vec<((u8, Reverse<usize>), Callback<KeyboardEvent>)> = HOTKEYS.with(|hotkeys| {
+        hotkeys
+            .borrow()
+            .iter()
+            .filter(|registration| {
+                registration.hotkey.matches(&keyboard_event)
+                    && (!in_field || registration.hotkey.is_allowed_in_fields())
+            })
+            .filter_map(|registration| {
+                get_scope_priority(&registration.scope, top_layer, target.as_ref())
+                    .map(|priority| (priority, registration.callback.clone()))
+            })
+            .collect()
+    });
+    let first_priority = callbacks.iter().map(|(priority, _)| *priority).min();
+    let callbacks: Vec<Callback<KeyboardEvent>> = callbacks
+        .into_iter()
+        .filter(|(priority, _)| Some(*priority) == first_priority)
+        .map(|(_, callback)| callback)
+        .collect();
+
+    if callbacks.is_empty() {
+        return;
+    }
+
+    keyboard_event.prevent_default();
+    for callback in callbacks {
+        callback.emit(keyboard_event.clone());
+    }
+}
+
+/// Order in which the scopes receive a hotkey, `None` when the scope isn't active.
+/// The regions are ordered by their depth in the document, so the innermost one goes first
+fn get_scope_priority(
+    scope: &HotkeyScope,
+    top_layer: Option<usize>,
+    target: Option<&Node>,
+) -> Option<(u8, Reverse<usize>)> {
+    match scope {
+        HotkeyScope::Layer(id) if Some(*id) == top_layer => Some((0, Reverse(0))),
+        HotkeyScope::Region(region) => region
+            .cast::<Node>()
+            .filter(|region| region.contains(target))
+            .map(|region| (1, Reverse(get_depth(&region)))),
+        HotkeyScope::App => Some((2, Reverse(0))),
+        _ => None,
+    }
+}
+
+fn get_depth(node: &Node) -> usize {
+    let mut depth = 0;
+    let mut parent = node.parent_node();
+
+    while let Some(node) = parent {
+        depth += 1;
+        parent = node.parent_node();
+    }
+
+    depth
+}
+
+fn is_text_field(target: &Node) -> bool {
+    match target.node_name().as_str() {
+        "INPUT" | "TEXTAREA" | "SELECT" => true,
+        _ => target
+            .dyn_ref::<HtmlElement>()
+            .map_or(false, |element| element.is_content_editable()),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn press(key: &str, ctrl: bool) -> KeyboardEvent {
+    KeyboardEvent::new_with_keyboard_event_init_dict(
+        "keydown",
+        web_sys::KeyboardEventInit::new()
+            .key(key)
+            .ctrl_key(ctrl)
+            .bubbles(true)
+            .cancelable(true),
+    )
+    .unwrap()
+}
+
+#[wasm_bindgen_test]
+fn should_match_the_combination() {
+    let hotkey = Hotkey::parse("Ctrl+Shift+K");
+
+    assert_eq!(hotkey, Hotkey::new("K").ctrl().shift());
+    assert_eq!(Hotkey::parse("Space"), Hotkey::new(" "));
+    assert!(Hotkey::parse("ctrl+s").matches(&press("s", true)));
+    assert!(!Hotkey::parse("ctrl+s").matches(&press("s", false)));
+}
+
+#[wasm_bindgen_test]
+fn should_dispatch_to_the_top_layer() {
+    use crate::layers::acquire_layer;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let closed = Rc::new(Cell::new((0, 0)));
+    let modal = acquire_layer();
+    let drawer = acquire_layer();
+
+    let modal_closed = closed.clone();
+    let _modal_escape = register_hotkey(
+        Hotkey::new("Escape"),
+        HotkeyScope::Layer(modal.id()),
+        Callback::from(move |_| modal_closed.set((modal_closed.get().0 + 1, modal_closed.get().1))),
+    );
+    let drawer_closed = closed.clone();
+    let drawer_escape = register_hotkey(
+        Hotkey::new("Escape"),
+        HotkeyScope::Layer(drawer.id()),
+        Callback::from(move |_| {
+            drawer_closed.set((drawer_closed.get().0, drawer_closed.get().1 + 1))
+        }),
+    );
+
+    utils::window()
+        .dispatch_event(&press("Escape", false))
+        .unwrap();
+    assert_eq!(closed.get(), (0, 1));
+
+    drop(drawer_escape);
+    drop(drawer);
+    utils::window()
+        .dispatch_event(&press("Escape", false))
+        .unwrap();
+    assert_eq!(closed.get(), (1, 1));
+}
+
+#[wasm_bindgen_test]
+fn should_not_dispatch_to_a_closed_layer_with_the_same_z_index() {
+    use crate::layers::acquire_layer;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let closed = Rc::new(Cell::new(false));
+    let modal = acquire_layer();
+    let z_index = modal.z_index();
+
+    let modal_closed = closed.clone();
+    let _stale_escape = register_hotkey(
+        Hotkey::new("Escape"),
+        HotkeyScope::Layer(modal.id()),
+        Callback::from(move |_| modal_closed.set(true)),
+    );
+
+    drop(modal);
+    let dialog = acquire_layer();
+    utils::window()
+        .dispatch_event(&press("Escape", false))
+        .unwrap();
+
+    assert_eq!(dialog.z_index(), z_index);
+    assert!(!closed.get());
+}
+
+#[wasm_bindgen_test]
+fn should_dispatch_to_the_innermost_region() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use yew::App;
+
+    #[derive(Clone, Properties, PartialEq)]
+    struct RegionsProps {
+        inner: NodeRef,
+        outer: NodeRef,
+    }
+
+    struct Regions(RegionsProps);
+
+    impl Component for Regions {
+        type Message = ();
+        type Properties = RegionsProps;
+
+        fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+            Regions(props)
+        }
+
+        fn update(&mut self, _: Self::Message) -> ShouldRender {
+            false
+        }
+
+        fn change(&mut self, _: Self::Properties) -> ShouldRender {
+            false
+        }
+
+        fn view(&self) -> Html {
+            html! {
+                <div ref=self.0.outer.clone()>
+                    <div ref=self.0.inner.clone()>
+                        <button id="hotkeys-inner-region-test" />
+                    </div>
+                </div>
+            }
+        }
+    }
+
+    let inner = NodeRef::default();
+    let outer = NodeRef::default();
+    let regions: App<Regions> = App::new();
+    regions.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        RegionsProps::builder()
+            .inner(inner.clone())
+            .outer(outer.clone())
+            .build(),
+    );
+
+    let pressed = Rc::new(Cell::new((0, 0)));
+    let outer_pressed = pressed.clone();
+    let _outer_bold = register_hotkey(
+        Hotkey::parse("Ctrl+B"),
+        HotkeyScope::Region(outer),
+        Callback::from(move |_| {
+            outer_pressed.set((outer_pressed.get().0 + 1, outer_pressed.get().1))
+        }),
+    );
+    let inner_pressed = pressed.clone();
+    let _inner_bold = register_hotkey(
+        Hotkey::parse("Ctrl+B"),
+        HotkeyScope::Region(inner),
+        Callback::from(move |_| {
+            inner_pressed.set((inner_pressed.get().0, inner_pressed.get().1 + 1))
+        }),
+    );
+
+    utils::document()
+        .get_element_by_id("hotkeys-inner-region-test")
+        .unwrap()
+        .dispatch_event(&press("b", true))
+        .unwrap();
+
+    assert_eq!(pressed.get(), (0, 1));
+}
@@ -0,0 +1,257 @@
+//! # Keyboard navigation
+//!
+//! Roving tabindex for composite widgets: only one item of the group is reachable with Tab,
+//! the arrow keys move the focus between the items and `Enter` or `Space` click them.
+//! The navbar, dropdown and sidenav items already use it, to add it in your own widgets
+//! call `init_roving_tabindex` when the item is rendered and `handle_roving_keydown` in its `onkeydown` event:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_styles::keyboard::{handle_roving_keydown, init_roving_tabindex, Orientation};
+//!
+//! pub struct Tab {
+//!     link: ComponentLink<Self>,
+//!     tab_ref: NodeRef,
+//! }
+//!
+//! pub enum Msg {
+//!     Pressed(KeyboardEvent),
+//! }
+//!
+//! impl Component for Tab {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+//!         Self { link, tab_ref: NodeRef::default() }
+//!     }
+//!
+//!     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+//!         match msg {
+//!             Msg::Pressed(keyboard_event) => {
+//!                 handle_roving_keydown(&keyboard_event, ".tabs", ".tab", Orientation::Horizontal);
+//!             }
+//!         }
+//!         false
+//!     }
+//!
+//!     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+//!         false
+//!     }
+//!
+//!     fn rendered(&mut self, first_render: bool) {
+//!         if first_render {
+//!             if let Some(tab) = self.tab_ref.cast::<web_sys::Element>() {
+//!                 init_roving_tabindex(&tab, ".tabs", ".tab");
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Html {
+//!         html! {
+//!             <div class="tab" role="tab" ref=self.tab_ref.clone() onkeydown=self.link.callback(Msg::Pressed)>
+//!                 {"Tab"}
+//!             </div>
+//!         }
+//!     }
+//! }
+//! ```
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+use yew::utils;
+
+/// Arrow keys which move the focus
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// Left and right arrows
+    Horizontal,
+    /// Up and down arrows
+    Vertical,
+    /// All the arrows
+    Both,
+}
+
+/// Index of the item which gets the focus after pressing the key, `None` if the key doesn't move the focus.
+/// The focus wraps around from the last item to the first one and vice versa
+pub fn get_roving_index(
+    key: &str,
+    current: usize,
+    len: usize,
+    orientation: Orientation,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let horizontal = orientation != Orientation::Vertical;
+    let vertical = orientation != Orientation::Horizontal;
+
+    match key {
+        "ArrowRight" if horizontal => Some((current + 1) % len),
+        "ArrowDown" if vertical => Some((current + 1) % len),
+        "ArrowLeft" if horizontal => Some((current + len - 1) % len),
+        "ArrowUp" if vertical => Some((current + len - 1) % len),
+        "Home" => Some(0),
+        "End" => Some(len - 1),
+        _ => None,
+    }
+}
+
+/// Let only one item of the group reachable with Tab: the item which has already `tabindex="0"`,
+/// otherwise the active item or the first one
+pub fn init_roving_tabindex(item: &Element, group_selector: &str, item_selector: &str) {
+    let items = get_group_items(item, group_selector, item_selector);
+
+    if items.is_empty() {
+        return;
+    }
+
+    let selected = items
+        .iter()
+        .position(|item| item.get_attribute("tabindex").as_deref() == Some("0"))
+        .or_else(|| {
+            items
+                .iter()
+                .position(|item| item.class_list().contains("active"))
+        })
+        .unwrap_or(0);
+
+    set_roving_tabindex(&items, selected);
+}
+
+/// Click the element with `Enter` or `Space`, for the custom widgets with `role="button"`.
+/// Returns `true` if the key was handled
+pub fn click_on_enter(keyboard_event: &KeyboardEvent) -> bool {
+    let key = keyboard_event.key();
+
+    if key != "Enter" && key != " " {
+        return false;
+    }
+
+    match keyboard_event
+        .current_target()
+        .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+    {
+        Some(element) => {
+            keyboard_event.prevent_default();
+            element.click();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Move the focus with the arrow keys, `Home` and `End`, and click the item with `Enter` or `Space`.
+/// Returns `true` if the key was handled
+pub fn handle_roving_keydown(
+    keyboard_event: &KeyboardEvent,
+    group_selector: &str,
+    item_selector: &str,
+    orientation: Orientation,
+) -> bool {
+    if click_on_enter(keyboard_event) {
+        return true;
+    }
+
+    let item = match keyboard_event
+        .current_target()
+        .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+    {
+        Some(item) => item,
+        None => return false,
+    };
+
+    let key = keyboard_event.key();
+    let items = get_group_items(&item, group_selector, item_selector);
+    let next = items
+        .iter()
+        .position(|group_item| group_item == &item)
+        .and_then(|current| get_roving_index(&key, current, items.len(), orientation));
+
+    match next {
+        Some(next) => {
+            keyboard_event.prevent_default();
+            set_roving_tabindex(&items, next);
+            items[next].focus().ok();
+            true
+        }
+        None => false,
+    }
+}
+
+fn get_group_items(item: &Element, group_selector: &str, item_selector: &str) -> Vec<HtmlElement> {
+    let mut items = vec![];
+
+    if let Some(node_list) = item
+        .closest(group_selector)
+        .ok()
+        .flatten()
+        .and_then(|group| group.query_selector_all(item_selector).ok())
+    {
+        for index in 0..node_list.length() {
+            if let Some(item) = node_list
+                .item(index)
+                .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+            {
+                items.push(item);
+            }
+        }
+    }
+
+    items
+}
+
+fn set_roving_tabindex(items: &[HtmlElement], selected: usize) {
+    for (index, item) in items.iter().enumerate() {
+        item.set_tab_index(if index == selected { 0 } else { -1 });
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_roving_index() {
+    assert_eq!(
+        get_roving_index("ArrowRight", 2, 3, Orientation::Horizontal),
+        Some(0)
+    );
+    assert_eq!(
+        get_roving_index("ArrowUp", 0, 3, Orientation::Vertical),
+        Some(2)
+    );
+    assert_eq!(
+        get_roving_index("ArrowDown", 0, 3, Orientation::Horizontal),
+        None
+    );
+    assert_eq!(get_roving_index("End", 0, 3, Orientation::Both), Some(2));
+}
+
+#[wasm_bindgen_test]
+fn should_init_the_roving_tabindex() {
+    let group = utils::document().create_element("ul").unwrap();
+    group.set_class_name("roving-group-test");
+    group.set_inner_html(
+        "<li class=\"roving-item\">1</li><li class=\"roving-item active\">2</li><li class=\"roving-item\">3</li>",
+    );
+    utils::document()
+        .get_element_by_id("output")
+        .unwrap()
+        .append_child(&group)
+        .unwrap();
+
+    let first = group.first_element_child().unwrap();
+    init_roving_tabindex(&first, ".roving-group-test", ".roving-item");
+
+    let items = get_group_items(&first, ".roving-group-test", ".roving-item");
+
+    assert_eq!(
+        items
+            .iter()
+            .map(|item| item.tab_index())
+            .collect::<Vec<i32>>(),
+        vec![-1, 0, -1]
+    );
+}
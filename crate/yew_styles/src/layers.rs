@@ -0,0 +1,153 @@
+//! # Layers
+//!
+//! Central manager of the z-index of the overlays. Every open overlay holds a `Layer`,
+//! each new layer is stacked over the ones already open, so the last overlay mounted
+//! is always on top (a tooltip inside a modal, a toast over the modal...) without hard-coded values.
+//! The modal, tooltip and toast container already use it, to stack your own overlays
+//! acquire a layer when it is shown and drop it when it is hidden:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_styles::layers::{acquire_layer, Layer};
+//!
+//! pub struct Popup {
+//!     layer: Option<Layer>,
+//! }
+//!
+//! pub enum Msg {
+//!     Open,
+//!     Close,
+//! }
+//!
+//! impl Component for Popup {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+//!         Self { layer: None }
+//!     }
+//!
+//!     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+//!         match msg {
+//!             Msg::Open => self.layer = Some(acquire_layer()),
+//!             Msg::Close => self.layer = None,
+//!         }
+//!         true
+//!     }
+//!
+//!     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+//!         false
+//!     }
+//!
+//!     fn view(&self) -> Html {
+//!         match &self.layer {
+//!             Some(layer) => html! {
+//!                 <div class="popup" style=layer.style()>{"Popup content"}</div>
+//!             },
+//!             None => html! {},
+//!         }
+//!     }
+//! }
+//! ```
+use std::cell::RefCell;
+use wasm_bindgen_test::*;
+
+/// z-index of the first layer
+pub const BASE_LAYER: i32 = 1000;
+/// Distance between two stacked layers, so an overlay can place its parts between them
+pub const LAYER_STEP: i32 = 10;
+
+thread_local! {
+    static LAYERS: RefCell<Vec<(usize, i32)>> = RefCell::new(vec![]);
+    static NEXT_ID: RefCell<usize> = RefCell::new(0);
+}
+
+/// Place of an open overlay in the stack, it is released when dropped
+#[derive(Debug, PartialEq)]
+pub struct Layer {
+    id: usize,
+    z_index: i32,
+}
+
+impl Layer {
+    /// Identifier of the layer, unlike the z-index it is never reused by another layer
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// z-index assigned to the overlay
+    pub fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    /// Inline style with the z-index of the layer
+    pub fn style(&self) -> String {
+        format!("z-index: {};", self.z_index)
+    }
+}
+
+impl Drop for Layer {
+    fn drop(&mut self) {
+        LAYERS.with(|layers| {
+            layers
+                .borrow_mut()
+                .retain(|(layer_id, _)| *layer_id != self.id)
+        });
+    }
+}
+
+/// Stacks a new layer over the ones already open
+pub fn acquire_layer() -> Layer {
+    let id = NEXT_ID.with(|next_id| {
+        let mut next_id = next_id.borrow_mut();
+        *next_id += 1;
+        *next_id
+    });
+
+    LAYERS.with(|layers| {
+        let mut layers = layers.borrow_mut();
+        let z_index = match layers.last() {
+            Some((_, top)) => top + LAYER_STEP,
+            None => BASE_LAYER,
+        };
+
+        layers.push((id, z_index));
+
+        Layer { id, z_index }
+    })
+}
+
+/// z-index of the layer on top of the stack, `None` when there is no overlay open
+pub fn get_top_layer() -> Option<i32> {
+    LAYERS.with(|layers| layers.borrow().last().map(|(_, z_index)| *z_index))
+}
+
+/// Identifier of the layer on top of the stack, `None` when there is no overlay open
+pub fn get_top_layer_id() -> Option<usize> {
+    LAYERS.with(|layers| layers.borrow().last().map(|(id, _)| *id))
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_stack_the_layers_in_mount_order() {
+    let drawer = acquire_layer();
+    let modal = acquire_layer();
+    let tooltip = acquire_layer();
+
+    assert!(drawer.z_index() < modal.z_index());
+    assert!(modal.z_index() < tooltip.z_index());
+    assert_eq!(get_top_layer(), Some(tooltip.z_index()));
+
+    drop(modal);
+    let toast = acquire_layer();
+
+    assert!(toast.z_index() > tooltip.z_index());
+
+    drop(toast);
+    drop(tooltip);
+    drop(drawer);
+
+    assert_eq!(get_top_layer(), None);
+    assert_eq!(acquire_layer().z_index(), BASE_LAYER);
+}
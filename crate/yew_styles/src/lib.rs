@@ -19,12 +19,107 @@
 //! ```toml
 //! yew_styles = { version="0.11", features=["button", "text", "navbar"] }
 //! ```
+//! Every component has its own feature, e.g. `form_input` or `modal`, so only the components used are compiled.
+//! `forms` enables all the form components and `full` all the components
 //! 3. Import the main.css file in you main javascript/typescript file project:
 //! ```typescript
 //!     import 'node_modules/yew-styles/main.css';
 //! ```
 //! 4. Ready to import and use in your project 🚀
 //!
+//! The `prelude` module re-exports the enabled components and the style types:
+//! ```rust
+//! use yew_styles::prelude::*;
+//! ```
+//!
+//! ### Style properties in your own components
+//! With the `macros` feature, `#[style_props]` adds the standard properties
+//! (`class_name`, `id`, `key`, `code_ref`, `styles`, `palette`, `size`, `margin`, `padding`) to the props of your components
+//! and the `style_classes` method to compose them as yew_styles does.
+//!
+//! ### Custom palettes
+//! Besides the built-in palettes, brand colors can be registered at runtime with `palettes::register_palette`,
+//! after that they can be used as `Palette::Custom` or by name, e.g. `button_palette="brand"`.
+//! The components get the class `palette-brand` and the color is defined in `--ys-palette-brand`
+//!
+//! ### Theme variables
+//! `theme::GlobalTheme` adds the palette colors, sizes and interaction colors as css variables in `:root`
+//! (`--ys-primary`, `--ys-primary-hover`, `--ys-border-radius`...) to style other elements consistently.
+//! `theme::use_theme` returns the same values as a `Theme`, to use them in the `css!` blocks and the `YieldStyle`
+//! implementations of your own components
+//!
+//! ### Global styles
+//! `theme::GlobalStyles` adds a small css reset (box sizing, body margin, font of the typography tokens)
+//! so the app starts from a consistent baseline without an external css file.
+//! `theme::PageStyle` adds any page level css written in Rust, with `${primary}` or `${spacing-medium}`
+//! replaced by the values of the theme variables
+//!
+//! ### Ripple
+//! The `ripple` property of `GlobalTheme` shows a circle which expands from the pointer when the buttons
+//! and the interactive cards are pressed, `ripple::add_ripple` adds it to other elements
+//!
+//! ### Class composition
+//! `styles::ComponentClasses` builds the classes of the root element of the components always in the same order:
+//! the semantic classes, the state classes (`disabled`, `error`, `active`...), the spacing classes, the stylist class
+//! and at the end the `class_name` of the user, so components which extend the ones of the library compose them the same way.
+//! The inner elements of the components keep their fixed classes
+//!
+//! ### Density
+//! The `density` property of `GlobalTheme` (`Density::Compact`, `Comfortable` or `Spacious`) scales the paddings
+//! of the buttons, inputs and list items in the whole app, the classes `ys-compact`, `ys-comfortable`
+//! and `ys-spacious` change it only inside of an element
+//!
+//! ### Design tokens
+//! `styles::tokens` defines the spacing, border radius, shadow, elevation and typography scales used by the components,
+//! they can be used in the `css!` blocks of your own components. `styles::animations` adds the shared keyframes
+//! (fade, slide, pulse, shimmer, spin) with the easing and duration tokens
+//!
+//! ### Spacing properties
+//! Every component has the `margin` and `padding` properties which take values of the spacing scale,
+//! e.g. `margin=Spacing::Medium` or `padding=Spaces::horizontal(Spacing::Large)`, without adding a custom class
+//!
+//! ### Messages
+//! The built-in texts of the components (error prefix, close labels, month names...) can be translated
+//! with `messages::set_messages`
+//!
+//! ### Keyboard navigation
+//! The navbar, dropdown and sidenav items use a roving tabindex: only one item of the group is reachable with Tab
+//! and the arrow keys move between them. `keyboard` exposes the same utility for your own widgets
+//!
+//! ### Overlay layers
+//! The modal, tooltip and toast container take their z-index from `layers`, in the order they are opened,
+//! so nested overlays always stack correctly. Use `layers::acquire_layer` to stack your own overlays with them
+//!
+//! ### Hotkeys
+//! `hotkeys::register_hotkey` binds key combinations to callbacks for the whole app, a focused region or an overlay.
+//! `Escape` closes only the modal or the gallery viewer on top, the same registry handles it for your own overlays
+//!
+//! ### Debounce and throttle
+//! `utils::timing::debounce` and `utils::timing::throttle` wrap any `Callback`, including the signals passed to the
+//! components, to emit it only after a pause or at most once in an interval
+//!
+//! ### Reduced motion
+//! The transitions and animations follow the `prefers-reduced-motion` preference of the system,
+//! it can be overridden with `styles::animations::set_reduced_motion` or the `reduced_motion` property of `GlobalTheme`
+//!
+//! ### Notifications
+//! `toast::NotificationService::push(Notification::success("Saved"))` shows a toast from any component,
+//! or from code outside of the components, in the `toast::NotificationHost` mounted in the app
+//!
+//! ### Error toasts
+//! With the `error_bridge` feature, `toast::ErrorToastHost` shows as toasts the errors sent with
+//! `toast::report_error(anyhow::Error)` and the panics of the app after calling `toast::install_panic_hook`
+//!
+//! ### Server-side rendering
+//! The components don't access the `window` or the local storage while they are created, the timers, focus,
+//! theme variables and stored states start in `rendered`, and outside of the browser those accesses are skipped.
+//! This covers `create`, `change` and `view` of every component and `GlobalTheme`, the code which only runs
+//! in `rendered`, `update` or the event callbacks still expects the browser
+//!
+//! ### Serde
+//! With the `serde` feature, the style enums (`Palette`, `Size`, `Style`...) and the component enums
+//! implement `Serialize` and `Deserialize`, so they can be stored or read from configuration
+//!
 //! ### Yew styles with Trunk
 //! To create an app with Yew Styles framework using [Trunk](https://trunkrs.dev/) instead of `Parcel` is possible, please check [Yew Styles Trunk Template](https://github.com/dancespiele/yew_styles_trunk_template)
 //!
@@ -58,28 +153,134 @@
 //! * Create a component page in `/crate/src/page` with the same structure than the rest of the components
 #![recursion_limit = "512"]
 mod components;
+pub mod hotkeys;
+pub mod keyboard;
+pub mod layers;
+pub mod messages;
+pub mod palettes;
+pub mod prelude;
+pub mod ripple;
 pub mod styles;
-mod utils;
+pub mod theme;
+pub mod utils;
 
+#[cfg(feature = "affix")]
+pub use components::affix;
+#[cfg(feature = "announcer")]
+pub use components::announcer;
+#[cfg(feature = "availability_grid")]
+pub use components::availability_grid;
+#[cfg(feature = "back_to_top")]
+pub use components::back_to_top;
 #[cfg(feature = "button")]
 pub use components::button;
+#[cfg(feature = "calendar")]
+pub use components::calendar;
 #[cfg(feature = "card")]
 pub use components::card;
 #[cfg(feature = "carousel")]
 pub use components::carousel;
+#[cfg(feature = "changelog")]
+pub use components::changelog;
+#[cfg(feature = "click_away")]
+pub use components::click_away;
+#[cfg(feature = "compare_slider")]
+pub use components::compare_slider;
+#[cfg(feature = "confetti")]
+pub use components::confetti;
+#[cfg(feature = "copy_button")]
+pub use components::copy_button;
+#[cfg(feature = "count_up")]
+pub use components::count_up;
+#[cfg(feature = "document_viewer")]
+pub use components::document_viewer;
 #[cfg(feature = "dropdown")]
 pub use components::dropdown;
-#[cfg(feature = "forms")]
+#[cfg(feature = "embed")]
+pub use components::embed;
+#[cfg(feature = "empty_state")]
+pub use components::empty_state;
+#[cfg(feature = "filterable_list")]
+pub use components::filterable_list;
+#[cfg(feature = "focus_trap")]
+pub use components::focus_trap;
+#[cfg(any(
+    feature = "form_component",
+    feature = "form_group",
+    feature = "form_label",
+    feature = "form_input",
+    feature = "form_textarea",
+    feature = "form_select",
+    feature = "form_file",
+    feature = "form_submit",
+    feature = "form_range_slider",
+    feature = "form_builder",
+    feature = "form_error_summary"
+))]
 pub use components::forms;
+#[cfg(feature = "gallery")]
+pub use components::gallery;
+#[cfg(feature = "highlight")]
+pub use components::highlight;
+#[cfg(feature = "icon")]
+pub use components::icon;
+#[cfg(feature = "image_zoom")]
+pub use components::image_zoom;
+#[cfg(feature = "infinite_scroll")]
+pub use components::infinite_scroll;
 #[cfg(feature = "layouts")]
 pub use components::layouts;
+#[cfg(feature = "list")]
+pub use components::list;
+#[cfg(feature = "loading_overlay")]
+pub use components::loading_overlay;
+#[cfg(feature = "memo")]
+pub use components::memo;
+#[cfg(feature = "menu")]
+pub use components::menu;
 #[cfg(feature = "modal")]
 pub use components::modal;
 #[cfg(feature = "navbar")]
 pub use components::navbar;
+#[cfg(feature = "pdf_viewer")]
+pub use components::pdf_viewer;
+#[cfg(feature = "portal")]
+pub use components::portal;
+#[cfg(feature = "scrollspy")]
+pub use components::scrollspy;
+#[cfg(feature = "seat_map")]
+pub use components::seat_map;
+#[cfg(feature = "secret_text")]
+pub use components::secret_text;
+#[cfg(feature = "sidenav")]
+pub use components::sidenav;
+#[cfg(feature = "sortable_list")]
+pub use components::sortable_list;
 #[cfg(feature = "spinner")]
 pub use components::spinner;
+#[cfg(feature = "split_pane")]
+pub use components::split_pane;
+#[cfg(feature = "stat")]
+pub use components::stat;
+#[cfg(feature = "status_page")]
+pub use components::status_page;
+#[cfg(feature = "sticky")]
+pub use components::sticky;
+#[cfg(feature = "surface")]
+pub use components::surface;
 #[cfg(feature = "text")]
 pub use components::text;
+#[cfg(feature = "toast")]
+pub use components::toast;
 #[cfg(feature = "tooltip")]
 pub use components::tooltip;
+#[cfg(feature = "transition_group")]
+pub use components::transition_group;
+#[cfg(feature = "truncate_middle")]
+pub use components::truncate_middle;
+#[cfg(feature = "typography")]
+pub use components::typography;
+#[cfg(feature = "video_thumb")]
+pub use components::video_thumb;
+#[cfg(feature = "macros")]
+pub use yew_styles_macro::style_props;
@@ -0,0 +1,344 @@
+//! # Messages
+//!
+//! Built-in texts of the components, like the error prefix of the forms, the close labels
+//! or the names of the months in the calendar. They are in english by default and can be
+//! translated with `set_messages` before mounting the app.
+//!
+//! ```rust
+//! use yew_styles::messages::{set_messages, Messages};
+//!
+//! set_messages(Messages {
+//!     form_error_prefix: "Error: ".to_string(),
+//!     close: "Cerrar".to_string(),
+//!     unexpected_error: "Error inesperado".to_string(),
+//!     ..Messages::default()
+//! });
+//! ```
+use std::cell::RefCell;
+use wasm_bindgen_test::*;
+
+thread_local! {
+    static MESSAGES: RefCell<Messages> = RefCell::new(Messages::default());
+}
+
+/// Texts used by the components
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Messages {
+    /// Text before the error message of the form controls. Default `""`
+    pub form_error_prefix: String,
+    /// Label of the close buttons. Default `"Close"`
+    pub close: String,
+    /// Indicator of the toasts in the queue, `{count}` is replaced by the number of toasts. Default `"+{count}"`
+    pub toast_queue: String,
+    /// Title of the toasts of the panics. Default `"Unexpected error"`
+    pub unexpected_error: String,
+    /// Label of the sidenav for the screen readers. Default `"Side navigation"`
+    pub side_navigation: String,
+    /// Label of the button which shows the sidenav labels. Default `"Expand side navigation"`
+    pub expand_side_navigation: String,
+    /// Label of the button which hides the sidenav labels. Default `"Collapse side navigation"`
+    pub collapse_side_navigation: String,
+    /// Label of the buttons which go to the previous element. Default `"Previous"`
+    pub previous: String,
+    /// Label of the buttons which go to the next element. Default `"Next"`
+    pub next: String,
+    /// Label of the spinners. Default `"Loading"`
+    pub loading: String,
+    /// Fraction indicator of the carousel, `{current}` and `{total}` are replaced by the slide numbers. Default `"{current}/{total}"`
+    pub carousel_fraction: String,
+    /// Label of the carousel thumbnails, `{index}` is replaced by the slide number. Default `"Show slide {index}"`
+    pub carousel_thumbnail: String,
+    /// Label of the divider of the compare slider. Default `"Before and after comparison"`
+    pub compare_slider: String,
+    /// Label of the play button of the video thumbs, `{title}` is replaced by the title of the video. Default `"Play video {title}"`
+    pub play_video: String,
+    /// Label of the zoom in buttons. Default `"Zoom in"`
+    pub zoom_in: String,
+    /// Label of the zoom out buttons. Default `"Zoom out"`
+    pub zoom_out: String,
+    /// Text of the download buttons. Default `"Download"`
+    pub download: String,
+    /// Title of the pdf viewer when the document doesn't have one. Default `"PDF document"`
+    pub pdf_document: String,
+    /// Current page of the pdf viewer, `{page}` is replaced by the page number. Default `"Page {page}"`
+    pub pdf_page: String,
+    /// Current page of the pdf viewer when the number of pages is known, `{page}` and `{total}` are replaced by the page numbers. Default `"Page {page} of {total}"`
+    pub pdf_page_total: String,
+    /// Shown when the document of the pdf viewer cannot be loaded. Default `"The document cannot be shown."`
+    pub pdf_error: String,
+    /// Placeholder of the embeds until the user accepts to load them. Default `"This content is hosted by a third party."`
+    pub embed_consent: String,
+    /// Button which loads the content of the embeds. Default `"Load content"`
+    pub embed_load: String,
+    /// Button which expands the clamped texts. Default `"Show more"`
+    pub show_more: String,
+    /// Button which clamps the expanded texts again. Default `"Show less"`
+    pub show_less: String,
+    /// Button which copies a text in the clipboard. Default `"Copy"`
+    pub copy: String,
+    /// Feedback after copying a text. Default `"Copied"`
+    pub copied: String,
+    /// Label of the copy buttons when the clipboard rejects the text. Default `"Copy failed"`
+    pub copy_failed: String,
+    /// Button which shows the secret texts while it is held. Default `"Hold to reveal"`
+    pub hold_to_reveal: String,
+    /// Read by the screen readers instead of the hidden secret texts. Default `"Hidden value"`
+    pub hidden_value: String,
+    /// Placeholder and label of the filter boxes. Default `"Filter"`
+    pub filter: String,
+    /// Shown in the filtered lists when no item matches. Default `"No matches"`
+    pub no_matches: String,
+    /// Label of the time zone shown by the availability grid. Default `"Time zone"`
+    pub time_zone: String,
+    /// Read by the screen readers in the seats which are not available. Default `"Taken"`
+    pub taken: String,
+    /// Status of the services which work normally. Default `"Operational"`
+    pub status_operational: String,
+    /// Status of the services under maintenance. Default `"Under maintenance"`
+    pub status_maintenance: String,
+    /// Status of the services which work slower than usual. Default `"Degraded performance"`
+    pub status_degraded: String,
+    /// Status of the services which partially don't work. Default `"Partial outage"`
+    pub status_partial_outage: String,
+    /// Status of the services which don't work. Default `"Major outage"`
+    pub status_major_outage: String,
+    /// Overall status of the status page when every service works. Default `"All systems operational"`
+    pub all_operational: String,
+    /// Uptime of the services, `{uptime}` is replaced by the percentage. Default `"{uptime}% uptime"`
+    pub uptime: String,
+    /// Uptime of the days without data. Default `"No data"`
+    pub no_data: String,
+    /// Title of the incidents of the status page. Default `"Past incidents"`
+    pub past_incidents: String,
+    /// Text of the status page when there are no incidents. Default `"No incidents reported"`
+    pub no_incidents: String,
+    /// Chip of the resolved incidents. Default `"Resolved"`
+    pub resolved: String,
+    /// Tag of the added features in the changelog. Default `"Added"`
+    pub change_added: String,
+    /// Tag of the changed features in the changelog. Default `"Changed"`
+    pub change_changed: String,
+    /// Tag of the deprecated features in the changelog. Default `"Deprecated"`
+    pub change_deprecated: String,
+    /// Tag of the removed features in the changelog. Default `"Removed"`
+    pub change_removed: String,
+    /// Tag of the fixed bugs in the changelog. Default `"Fixed"`
+    pub change_fixed: String,
+    /// Tag of the security fixes in the changelog. Default `"Security"`
+    pub change_security: String,
+    /// Label of the anchor of each version in the changelog, `{version}` is replaced by the version. Default `"Link to version {version}"`
+    pub version_link: String,
+    /// Label of the table of contents of the document viewer. Default `"Table of contents"`
+    pub table_of_contents: String,
+    /// Date of the last update of the document viewer, `{date}` is replaced by the date. Default `"Last updated {date}"`
+    pub last_updated: String,
+    /// Label of the print button of the document viewer. Default `"Print"`
+    pub print: String,
+    /// Label of the reading progress bar of the document viewer. Default `"Reading progress"`
+    pub reading_progress: String,
+    /// Read by the screen readers before a rising delta of the stats, `{delta}` is replaced by the delta. Default `"Increased by {delta}"`
+    pub stat_increase: String,
+    /// Read by the screen readers before a falling delta of the stats, `{delta}` is replaced by the delta. Default `"Decreased by {delta}"`
+    pub stat_decrease: String,
+    /// Label of the back to top button. Default `"Back to top"`
+    pub back_to_top: String,
+    /// Position of the image shown by the gallery, `{current}` and `{total}` are replaced by the numbers. Default `"Image {current} of {total}"`
+    pub gallery_position: String,
+    /// Label of the handle of the lowest value of the range sliders. Default `"Minimum"`
+    pub range_minimum: String,
+    /// Label of the handle of the highest value of the range sliders. Default `"Maximum"`
+    pub range_maximum: String,
+    /// Label of the button which cancels the upload of a file. Default `"Cancel"`
+    pub upload_cancel: String,
+    /// Label of the button which uploads again a failed or cancelled file. Default `"Retry"`
+    pub upload_retry: String,
+    /// Status of an uploaded file. Default `"Uploaded"`
+    pub upload_completed: String,
+    /// Status of a cancelled upload. Default `"Cancelled"`
+    pub upload_cancelled: String,
+    /// Error of an upload when the server doesn't answer or rejects the file. Default `"Upload failed"`
+    pub upload_failed: String,
+    /// End of the results of the infinite scroll. Default `"No more results"`
+    pub infinite_scroll_end: String,
+    /// Label of the handles of the sortable lists, `{index}` is replaced by the position of the item. Default `"Move item {index}"`
+    pub sortable_handle: String,
+    /// Read by the screen readers when an item is moved with the keyboard, `{position}` and `{total}` are replaced by the numbers. Default `"Moved to position {position} of {total}"`
+    pub sortable_moved: String,
+    /// Label of the divider of the split panes. Default `"Resize panels"`
+    pub split_pane: String,
+    /// Error of the required fields of the form builder. Default `"This field is required"`
+    pub validation_required: String,
+    /// Error of a text shorter than `{min}` characters. Default `"Enter at least {min} characters"`
+    pub validation_min_length: String,
+    /// Error of a text longer than `{max}` characters. Default `"Enter at most {max} characters"`
+    pub validation_max_length: String,
+    /// Error of a number lower than `{min}`. Default `"Enter a value of at least {min}"`
+    pub validation_min: String,
+    /// Error of a number greater than `{max}`. Default `"Enter a value of at most {max}"`
+    pub validation_max: String,
+    /// Error of an invalid email address. Default `"Enter a valid email address"`
+    pub validation_email: String,
+    /// Error of a value different from the one of the field labelled `{field}`. Default `"The value doesn't match {field}"`
+    pub validation_matches: String,
+    /// Error of a value which can't be converted, `{name}` is the name of the field. Default `"The value of {name} is not valid"`
+    pub validation_invalid: String,
+    /// Text of the submit button of the form builder. Default `"Submit"`
+    pub form_submit: String,
+    /// Title of the error summary of the forms, `{count}` is replaced by the number of errors. Default `"There are {count} errors in the form"`
+    pub form_error_summary: String,
+    /// Short names of the week days starting by monday
+    pub week_days: Vec<String>,
+    /// Names of the months starting by january
+    pub months: Vec<String>,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Messages {
+            form_error_prefix: "".to_string(),
+            close: "Close".to_string(),
+            toast_queue: "+{count}".to_string(),
+            unexpected_error: "Unexpected error".to_string(),
+            side_navigation: "Side navigation".to_string(),
+            expand_side_navigation: "Expand side navigation".to_string(),
+            collapse_side_navigation: "Collapse side navigation".to_string(),
+            previous: "Previous".to_string(),
+            next: "Next".to_string(),
+            loading: "Loading".to_string(),
+            carousel_fraction: "{current}/{total}".to_string(),
+            carousel_thumbnail: "Show slide {index}".to_string(),
+            compare_slider: "Before and after comparison".to_string(),
+            play_video: "Play video {title}".to_string(),
+            zoom_in: "Zoom in".to_string(),
+            zoom_out: "Zoom out".to_string(),
+            download: "Download".to_string(),
+            pdf_document: "PDF document".to_string(),
+            pdf_page: "Page {page}".to_string(),
+            pdf_page_total: "Page {page} of {total}".to_string(),
+            pdf_error: "The document cannot be shown.".to_string(),
+            embed_consent: "This content is hosted by a third party.".to_string(),
+            embed_load: "Load content".to_string(),
+            show_more: "Show more".to_string(),
+            show_less: "Show less".to_string(),
+            copy: "Copy".to_string(),
+            copied: "Copied".to_string(),
+            copy_failed: "Copy failed".to_string(),
+            hold_to_reveal: "Hold to reveal".to_string(),
+            hidden_value: "Hidden value".to_string(),
+            filter: "Filter".to_string(),
+            no_matches: "No matches".to_string(),
+            time_zone: "Time zone".to_string(),
+            taken: "Taken".to_string(),
+            status_operational: "Operational".to_string(),
+            status_maintenance: "Under maintenance".to_string(),
+            status_degraded: "Degraded performance".to_string(),
+            status_partial_outage: "Partial outage".to_string(),
+            status_major_outage: "Major outage".to_string(),
+            all_operational: "All systems operational".to_string(),
+            uptime: "{uptime}% uptime".to_string(),
+            no_data: "No data".to_string(),
+            past_incidents: "Past incidents".to_string(),
+            no_incidents: "No incidents reported".to_string(),
+            resolved: "Resolved".to_string(),
+            change_added: "Added".to_string(),
+            change_changed: "Changed".to_string(),
+            change_deprecated: "Deprecated".to_string(),
+            change_removed: "Removed".to_string(),
+            change_fixed: "Fixed".to_string(),
+            change_security: "Security".to_string(),
+            version_link: "Link to version {version}".to_string(),
+            table_of_contents: "Table of contents".to_string(),
+            last_updated: "Last updated {date}".to_string(),
+            print: "Print".to_string(),
+            reading_progress: "Reading progress".to_string(),
+            stat_increase: "Increased by {delta}".to_string(),
+            stat_decrease: "Decreased by {delta}".to_string(),
+            back_to_top: "Back to top".to_string(),
+            gallery_position: "Image {current} of {total}".to_string(),
+            range_minimum: "Minimum".to_string(),
+            range_maximum: "Maximum".to_string(),
+            upload_cancel: "Cancel".to_string(),
+            upload_retry: "Retry".to_string(),
+            upload_completed: "Uploaded".to_string(),
+            upload_cancelled: "Cancelled".to_string(),
+            upload_failed: "Upload failed".to_string(),
+            infinite_scroll_end: "No more results".to_string(),
+            sortable_handle: "Move item {index}".to_string(),
+            sortable_moved: "Moved to position {position} of {total}".to_string(),
+            split_pane: "Resize panels".to_string(),
+            validation_required: "This field is required".to_string(),
+            validation_min_length: "Enter at least {min} characters".to_string(),
+            validation_max_length: "Enter at most {max} characters".to_string(),
+            validation_min: "Enter a value of at least {min}".to_string(),
+            validation_max: "Enter a value of at most {max}".to_string(),
+            validation_email: "Enter a valid email address".to_string(),
+            validation_matches: "The value doesn't match {field}".to_string(),
+            validation_invalid: "The value of {name} is not valid".to_string(),
+            form_submit: "Submit".to_string(),
+            form_error_summary: "There are {count} errors in the form".to_string(),
+            week_days: vec!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            months: vec![
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        }
+    }
+}
+
+/// Replace the texts used by the components. The components mounted before keep the previous texts
+/// until they render again
+pub fn set_messages(messages: Messages) {
+    MESSAGES.with(|current| *current.borrow_mut() = messages);
+}
+
+/// Texts used by the components
+pub fn get_messages() -> Messages {
+    MESSAGES.with(|messages| messages.borrow().clone())
+}
+
+/// Replace the `{name}` placeholders of a message
+pub fn format_message(message: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(message.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{}}}", name), value)
+        })
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_replace_the_messages() {
+    set_messages(Messages {
+        close: "Cerrar".to_string(),
+        toast_queue: "{count} más".to_string(),
+        ..Messages::default()
+    });
+
+    let messages = get_messages();
+
+    assert_eq!(messages.close, "Cerrar");
+    assert_eq!(
+        format_message(&messages.toast_queue, &[("count", 3.to_string())]),
+        "3 más"
+    );
+
+    set_messages(Messages::default());
+}
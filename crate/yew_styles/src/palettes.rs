@@ -0,0 +1,479 @@
+use crate::styles::{Palette, ParseStyleError};
+use crate::theme::darken;
+use crate::utils::is_browser;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wasm_bindgen_test::*;
+use web_sys::window;
+use yew::utils;
+
+thread_local! {
+    static REGISTERED_PALETTES: RefCell<HashMap<String, CustomPalette>> = RefCell::new(HashMap::new());
+}
+
+/// Colors of a palette defined by the user
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::button::Button;
+/// use yew_styles::palettes::{register_palette, CustomPalette};
+/// use yew_styles::styles::Style;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         register_palette(CustomPalette {
+///             name: "brand".to_string(),
+///             color: "#6a1b9a".to_string(),
+///             text_color: "#fff".to_string(),
+///             light_color: "#e1bee7".to_string(),
+///         })
+///         .unwrap();
+///
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Button button_palette="brand" button_style=Style::Outline>{"Brand"}</Button>
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct CustomPalette {
+    /// Name of the palette, it is used in the class name `palette-{name}` so only letters, digits, `-` and `_`
+    /// are allowed
+    pub name: String,
+    /// Background in regular style, text and border color in outline and light style.
+    /// The colors are css values, e.g. `#6a1b9a` or `rgb(106, 27, 154)`
+    pub color: String,
+    /// Text color in regular style
+    pub text_color: String,
+    /// Background in light style
+    pub light_color: String,
+}
+
+/// Register a custom palette adding its styles to the document head.
+/// Registering a palette with the same name again replaces its colors.
+/// It returns the `Palette::Custom` to use in the palette properties of the components,
+/// or a `ParseStyleError` when the name or the colors aren't valid
+pub fn register_palette(palette: CustomPalette) -> Result<Palette, ParseStyleError> {
+    if !is_valid_name(&palette.name) {
+        return Err(ParseStyleError {
+            kind: "palette name",
+            value: palette.name,
+        });
+    }
+
+    for color in [&palette.color, &palette.text_color, &palette.light_color].iter() {
+        if !is_valid_color(color) {
+            return Err(ParseStyleError {
+                kind: "palette color",
+                value: color.to_string(),
+            });
+        }
+    }
+
+    add_palette_style(&palette);
+
+    let name = palette.name.clone();
+    REGISTERED_PALETTES.with(|palettes| palettes.borrow_mut().insert(name.clone(), palette));
+
+    Ok(Palette::Custom(name))
+}
+
+/// Check if a custom palette was registered with this name
+pub fn is_registered(name: &str) -> bool {
+    REGISTERED_PALETTES.with(|palettes| palettes.borrow().contains_key(name))
+}
+
+/// Colors of the custom palette registered with this name
+pub(crate) fn get_registered_palette(name: &str) -> Option<CustomPalette> {
+    REGISTERED_PALETTES.with(|palettes| palettes.borrow().get(name).cloned())
+}
+
+/// Class of the custom palette in the components, prefixed so it doesn't collide with the classes
+/// of the styles, sizes or states, e.g. `palette-light` for a palette named `light`
+pub(crate) fn get_class_name(name: &str) -> String {
+    format!("palette-{}", name)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && name.parse::<Palette>().map_or(true, |palette| {
+            // built-in palettes cannot be overridden
+            matches!(palette, Palette::Custom(_))
+        })
+}
+
+// The colors are written inside of the style element, so they can't close the declaration or the element
+fn is_valid_color(color: &str) -> bool {
+    !color.trim().is_empty()
+        && !color
+            .chars()
+            .any(|c| c.is_control() || [';', '{', '}', '<', '>', '"', '\\'].contains(&c))
+}
+
+// Outside of the browser, e.g. server-side rendering, the palette is only registered
+fn add_palette_style(palette: &CustomPalette) -> Option<()> {
+    if !is_browser() {
+        return None;
+    }
+
+    let document = window()?.document()?;
+    let style_id = format!("yew-styles-palette-{}", palette.name);
+
+    let style_element = match document.get_element_by_id(&style_id) {
+        Some(element) => element,
+        None => {
+            let element = document.create_element("style").ok()?;
+            element.set_id(&style_id);
+            document.head()?.append_child(&element).ok()?;
+            element
+        }
+    };
+
+    style_element.set_text_content(Some(&get_palette_css(palette)));
+
+    Some(())
+}
+
+// Elements colored by the palette besides the generic rules of its styles, e.g. `.regular.palette-brand`.
+// ci/run_checks.sh checks that every element which takes a palette in the sass partials is listed here
+
+// Elements with the palette color as background
+const FILLED_ELEMENTS: &[&str] = &[
+    ".surface",
+    ".availability-grid-fill",
+    ".calendar-event",
+    ".seat-map-legend-color",
+    ".status-page-overall",
+    ".status-page-day",
+];
+
+// Elements with the light color as background
+const LIGHT_ELEMENTS: &[&str] = &[
+    ".changelog-tag",
+    ".seat-map-seat",
+    ".stat-icon",
+    ".stat-delta",
+    ".status-page-chip",
+];
+
+// Elements darkened when they are focused, hovered or pressed
+const INTERACTIVE_ELEMENTS: &[&str] = &[
+    ".button",
+    ".form-submit",
+    ".tooltip",
+    ".card.interaction",
+    ".tag-text.interaction",
+    ".modal-header.interaction",
+    ".modal-body.interaction",
+    ".carousel-control-left",
+    ".carousel-control-right",
+];
+
+// Items darkened inside of the container with the palette, also when they are active
+const INTERACTIVE_ITEMS: &[(&str, &str)] = &[
+    (".dropdown", ".dropdown-item"),
+    (".navbar", ".navbar-item.interaction"),
+    (".navbar", ".navbar-dropdown"),
+    (".navbar", ".navbar-dropdown-item"),
+    (".navbar-mobile", ".navbar-item.interaction"),
+    (".navbar-mobile", ".navbar-dropdown"),
+    (".navbar-mobile", ".navbar-dropdown-item"),
+    (".sidenav", ".sidenav-item"),
+    (".sidenav", ".sidenav-section-header"),
+    (".sidenav", ".sidenav-toggle"),
+];
+
+const NAVBAR_ELEMENTS: &[&str] = &[".navbar", ".navbar-mobile"];
+
+const MODAL_ELEMENTS: &[&str] = &[".modal"];
+
+const TOOLTIP_ELEMENTS: &[&str] = &[".tooltip"];
+
+const SPINNER_ELEMENTS: &[&str] = &[".sk-plane", ".sk-pulse"];
+
+// Dots of the spinners inside of the element with the palette
+const SPINNER_DOTS: &[&str] = &[
+    "div[class$=\"-dot\"]:before",
+    "div[class$=\"-rect\"]:before",
+    "div[class$=\"-cube\"]:before",
+    ".sk-grid-cube",
+    ".sk-wander-cube",
+    ".sk-wave-rect",
+    ".sk-swing-dot",
+    ".sk-bounce-dot",
+    ".sk-flow-dot",
+];
+
+const FORM_ELEMENTS: &[&str] = &[".form-input", ".form-file", ".form-textarea"];
+
+const UNDERLINE_FORM_ELEMENTS: &[&str] = &[".form-input.underline", ".form-file.underline"];
+
+const CAROUSEL_DOTS: &[&str] = &[".carousel-dot"];
+
+const CAROUSEL_INDICATORS: &[&str] = &[".carousel-indicator", ".carousel-thumbnails"];
+
+// Same rules than the sass mixins generate for the built-in palettes
+fn get_palette_css(palette: &CustomPalette) -> String {
+    let CustomPalette {
+        name,
+        color,
+        text_color,
+        light_color,
+    } = palette;
+    let class = get_class_name(name);
+    let styles = [
+        ("regular", color.as_str(), text_color.as_str(), None),
+        ("outline", "#fff", color.as_str(), Some(color.as_str())),
+        ("light", light_color.as_str(), color.as_str(), None),
+    ];
+    let select = |elements: &[&str], suffix: &str| select_elements(elements, &class, suffix);
+
+    let mut css = format!(
+        ":root {{ --ys-{class}: {color}; --ys-{class}-text: {text_color}; --ys-{class}-light: {light_color}; }}\n",
+        class = class,
+        color = color,
+        text_color = text_color,
+        light_color = light_color,
+    );
+
+    for (style, background, text, border_color) in styles.iter() {
+        let border = border_color.map_or(String::from("none"), |border_color| {
+            format!("1px solid {}", border_color)
+        });
+        let styled_class = format!("{}.{}", style, class);
+        let select_styled =
+            |elements: &[&str], suffix: &str| select_elements(elements, &styled_class, suffix);
+
+        css.push_str(&format!(
+            ".{styled_class} {{ background-color: {background}; color: {text}; border: {border}; }}
+{navbar_menu} {{ fill: {text}; }}
+{navbar_list}, {navbar_mega_menu} {{ background-color: {background}; }}
+.sidenav.{styled_class} {{ border: none; border-right: {border}; }}
+.sidenav.{styled_class} .sidenav-toggle, .sidenav.{styled_class} .sidenav-caret {{ color: {text}; fill: {text}; }}
+.sidenav.{styled_class}.mini .sidenav-label {{ background-color: {background}; }}\n",
+            styled_class = styled_class,
+            background = background,
+            text = text,
+            border = border,
+            navbar_menu = select_styled(NAVBAR_ELEMENTS, " .navbar-menu"),
+            navbar_list = select_styled(NAVBAR_ELEMENTS, " ul"),
+            navbar_mega_menu = select_styled(NAVBAR_ELEMENTS, " .navbar-mega-menu"),
+        ));
+    }
+
+    css.push_str(&format!(
+        "{filled} {{ background-color: {color}; color: {text_color}; border: none; }}
+{light} {{ background-color: {light_color}; color: {color}; border: none; }}
+.seat-map-seat.selected.{class} {{ background-color: {color}; color: {text_color}; }}
+{interactive_focus} {{ filter: brightness(95%); }}
+{interactive_hover} {{ filter: brightness(90%); }}
+{interactive_active} {{ filter: brightness(85%); }}
+{tooltip_arrow} {{ content: \" \"; position: absolute; border-style: solid; border-width: 5px; }}
+{tooltip_left} {{ border-color: transparent transparent transparent {color}; }}
+{tooltip_right} {{ border-color: transparent {color} transparent transparent; }}
+{tooltip_below} {{ border-color: transparent transparent {color} transparent; }}
+{tooltip_above} {{ border-color: {color} transparent transparent transparent; }}
+{modal} {{ background-color: color-mix(in srgb, {color} 60%, transparent); }}
+{spinner_dots}, {spinner} {{ background: {color}; }}
+{form} {{ border: 1px solid {color}; }}
+{form_underline} {{ border-radius: 2px; border-top: 0; border-left: 0; border-right: 0; border-bottom: 2px solid {color}; }}
+{form_placeholder} {{ color: {color}; }}
+{form_focus} {{ border-color: {color_focus}; }}
+{form_hover} {{ border-color: {color_hover}; }}
+{form_active} {{ border-color: {color_active}; }}
+{carousel_dot} {{ fill: {light_color}; }}
+{carousel_dot_focus} {{ fill: {light_focus}; }}
+{carousel_dot_hover} {{ fill: {light_hover}; }}
+{carousel_dot_active}, {carousel_dot_selected} {{ fill: {light_active}; }}
+{carousel_indicator} {{ color: {color}; }}
+{carousel_indicator_bar} {{ background-color: {light_color}; }}
+{carousel_indicator_fill} {{ background-color: {light_strong}; }}
+{carousel_indicator_thumbnail} {{ border-color: {light_strong}; }}",
+        class = class,
+        color = color,
+        text_color = text_color,
+        light_color = light_color,
+        filled = select(FILLED_ELEMENTS, ""),
+        light = select(LIGHT_ELEMENTS, ""),
+        interactive_focus = get_interactive_selectors(&class, ":focus"),
+        interactive_hover = get_interactive_selectors(&class, ":hover"),
+        interactive_active = get_interactive_selectors(&class, ":active"),
+        tooltip_arrow = select(TOOLTIP_ELEMENTS, "::after"),
+        tooltip_left = select(TOOLTIP_ELEMENTS, ".left::after"),
+        tooltip_right = select(TOOLTIP_ELEMENTS, ".right::after"),
+        tooltip_below = select(TOOLTIP_ELEMENTS, ".below::after"),
+        tooltip_above = select(TOOLTIP_ELEMENTS, ".above::after"),
+        modal = select(MODAL_ELEMENTS, ""),
+        spinner_dots = SPINNER_DOTS
+            .iter()
+            .map(|dot| format!("div[class^=\"sk-\"].{} {}", class, dot))
+            .collect::<Vec<String>>()
+            .join(", "),
+        spinner = select(SPINNER_ELEMENTS, ""),
+        form = select(FORM_ELEMENTS, ""),
+        form_underline = select(UNDERLINE_FORM_ELEMENTS, ""),
+        form_placeholder = select(FORM_ELEMENTS, "::placeholder"),
+        form_focus = select(FORM_ELEMENTS, ":focus"),
+        form_hover = select(FORM_ELEMENTS, ":hover"),
+        form_active = select(FORM_ELEMENTS, ":active"),
+        color_focus = darken(color, 10.0),
+        color_hover = darken(color, 20.0),
+        color_active = darken(color, 30.0),
+        carousel_dot = select(CAROUSEL_DOTS, " svg"),
+        carousel_dot_focus = select(CAROUSEL_DOTS, ":focus svg"),
+        carousel_dot_hover = select(CAROUSEL_DOTS, ":hover svg"),
+        carousel_dot_active = select(CAROUSEL_DOTS, ":active svg"),
+        carousel_dot_selected = select(CAROUSEL_DOTS, ".active svg"),
+        carousel_indicator = select(CAROUSEL_INDICATORS, ""),
+        carousel_indicator_bar = select(CAROUSEL_INDICATORS, " .carousel-indicator-bar"),
+        carousel_indicator_fill = select(CAROUSEL_INDICATORS, " .carousel-indicator-fill"),
+        carousel_indicator_thumbnail = select(CAROUSEL_INDICATORS, " .carousel-thumbnail.active"),
+        light_focus = darken(light_color, 5.0),
+        light_hover = darken(light_color, 10.0),
+        light_active = darken(light_color, 15.0),
+        light_strong = darken(light_color, 30.0),
+    ));
+
+    css
+}
+
+// Selectors of the elements with the class, e.g. `.surface.palette-brand:hover`
+fn select_elements(elements: &[&str], class: &str, suffix: &str) -> String {
+    elements
+        .iter()
+        .map(|element| format!("{}.{}{}", element, class, suffix))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// Elements darkened when they are focused, hovered or pressed, the items also when they are active
+fn get_interactive_selectors(class: &str, state: &str) -> String {
+    let mut selectors = vec![select_elements(INTERACTIVE_ELEMENTS, class, state)];
+
+    for (container, item) in INTERACTIVE_ITEMS.iter() {
+        selectors.push(format!("{}.{} {}{}", container, class, item, state));
+
+        if state == ":active" {
+            selectors.push(format!("{}.{} {}.active", container, class, item));
+        }
+    }
+
+    selectors.join(", ")
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_register_custom_palette() {
+    let palette = register_palette(CustomPalette {
+        name: "brand".to_string(),
+        color: "#6a1b9a".to_string(),
+        text_color: "#fff".to_string(),
+        light_color: "#e1bee7".to_string(),
+    })
+    .unwrap();
+
+    let style_element = utils::document()
+        .get_element_by_id("yew-styles-palette-brand")
+        .unwrap();
+
+    assert_eq!(palette, Palette::Custom("brand".to_string()));
+    assert_eq!(crate::styles::get_palette(palette.clone()), "palette-brand");
+    assert_eq!("brand".parse::<Palette>(), Ok(palette));
+    assert!(style_element
+        .text_content()
+        .unwrap()
+        .contains(".regular.palette-brand { background-color: #6a1b9a;"));
+}
+
+#[wasm_bindgen_test]
+fn should_not_register_invalid_palette_names() {
+    for name in ["primary", "1brand", "brand color", ""].iter() {
+        let result = register_palette(CustomPalette {
+            name: name.to_string(),
+            color: "#000".to_string(),
+            text_color: "#fff".to_string(),
+            light_color: "#ccc".to_string(),
+        });
+
+        assert!(result.is_err());
+    }
+}
+
+#[wasm_bindgen_test]
+fn should_not_register_invalid_palette_colors() {
+    for color in [
+        "",
+        "red; } body { display: none",
+        "</style>",
+        "#fff\"",
+        "rgb(0 0 0)\n",
+    ]
+    .iter()
+    {
+        let result = register_palette(CustomPalette {
+            name: "invalid-color".to_string(),
+            color: color.to_string(),
+            text_color: "#fff".to_string(),
+            light_color: "#ccc".to_string(),
+        });
+
+        assert_eq!(
+            result,
+            Err(ParseStyleError {
+                kind: "palette color",
+                value: color.to_string(),
+            })
+        );
+    }
+
+    assert!(!is_registered("invalid-color"));
+    assert!(utils::document()
+        .get_element_by_id("yew-styles-palette-invalid-color")
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+fn should_add_the_rules_of_every_palette_component() {
+    let css = get_palette_css(&CustomPalette {
+        name: "ocean".to_string(),
+        color: "#0077be".to_string(),
+        text_color: "#fff".to_string(),
+        light_color: "#b3e0ff".to_string(),
+    });
+
+    assert!(css.contains(
+        ".outline.palette-ocean { background-color: #fff; color: #0077be; border: 1px solid #0077be; }"
+    ));
+    assert!(css.contains(".navbar.light.palette-ocean .navbar-menu"));
+    assert!(css.contains(".sidenav.regular.palette-ocean { border: none; border-right: none; }"));
+    assert!(css.contains(".calendar-event.palette-ocean"));
+    assert!(css.contains(".tooltip.palette-ocean.left::after"));
+    assert!(css.contains(".form-input.palette-ocean:hover"));
+    assert!(css.contains(".carousel-dot.palette-ocean svg { fill: #b3e0ff; }"));
+    assert!(css.contains(".sidenav.palette-ocean .sidenav-item.active"));
+    assert!(css.contains(".modal-header.interaction.palette-ocean:hover"));
+}
@@ -0,0 +1,173 @@
+//! # Prelude
+//!
+//! Re-exports the components enabled by the features, the style enums
+//! and the types received by the component signals, so a single import is enough:
+//!
+//! ```rust
+//! use yew_styles::prelude::*;
+//! ```
+pub use crate::messages::{set_messages, Messages};
+pub use crate::palettes::{register_palette, CustomPalette};
+pub use crate::styles::breakpoints::Breakpoint;
+pub use crate::styles::tokens::Elevation;
+pub use crate::styles::{Palette, Position, Size, Style};
+pub use crate::theme::{use_theme, Density, GlobalStyles, GlobalTheme, PageStyle, Theme};
+
+#[cfg(feature = "affix")]
+pub use crate::affix::Affix;
+#[cfg(feature = "announcer")]
+pub use crate::announcer::{announce, Announcer, Politeness};
+#[cfg(feature = "availability_grid")]
+pub use crate::availability_grid::AvailabilityGrid;
+#[cfg(feature = "back_to_top")]
+pub use crate::back_to_top::BackToTop;
+#[cfg(feature = "button")]
+pub use crate::button::Button;
+#[cfg(feature = "calendar")]
+pub use crate::calendar::{Calendar, CalendarDate, CalendarEvent, CalendarView, DayRender};
+#[cfg(feature = "card")]
+pub use crate::card::Card;
+#[cfg(feature = "carousel")]
+pub use crate::carousel::{
+    Carousel, CarouselControls, CarouselDot, CarouselImage, CarouselIndicator, CarouselThumbnails,
+    IndicatorType,
+};
+#[cfg(feature = "changelog")]
+pub use crate::changelog::{Change, ChangeKind, Changelog, ChangelogEntry};
+#[cfg(feature = "click_away")]
+pub use crate::click_away::ClickAway;
+#[cfg(feature = "compare_slider")]
+pub use crate::compare_slider::CompareSlider;
+#[cfg(feature = "confetti")]
+pub use crate::confetti::{Confetti, ConfettiHandle};
+#[cfg(feature = "copy_button")]
+pub use crate::copy_button::CopyButton;
+#[cfg(feature = "count_up")]
+pub use crate::count_up::CountUp;
+#[cfg(feature = "document_viewer")]
+pub use crate::document_viewer::{DocumentSection, DocumentViewer};
+#[cfg(feature = "dropdown")]
+pub use crate::dropdown::{Dropdown, DropdownItem};
+#[cfg(feature = "embed")]
+pub use crate::embed::Embed;
+#[cfg(feature = "empty_state")]
+pub use crate::empty_state::EmptyState;
+#[cfg(feature = "filterable_list")]
+pub use crate::filterable_list::FilterableList;
+#[cfg(feature = "focus_trap")]
+pub use crate::focus_trap::FocusTrap;
+#[cfg(feature = "form_builder")]
+pub use crate::forms::form_builder::{
+    FieldKind, FormBuilder, FormField, FormValues, FromFormValues, Validator,
+};
+#[cfg(feature = "form_component")]
+pub use crate::forms::form_component::{Form, FormValue, Method};
+#[cfg(feature = "form_error_summary")]
+pub use crate::forms::form_error_summary::FormErrorSummary;
+#[cfg(feature = "form_file")]
+pub use crate::forms::form_file::FormFile;
+#[cfg(feature = "form_group")]
+pub use crate::forms::form_group::{FormGroup, Orientation};
+#[cfg(feature = "form_input")]
+pub use crate::forms::form_input::{FormInput, InputType};
+#[cfg(feature = "form_label")]
+pub use crate::forms::form_label::FormLabel;
+#[cfg(feature = "form_range_slider")]
+pub use crate::forms::form_range_slider::FormRangeSlider;
+#[cfg(feature = "form_select")]
+pub use crate::forms::form_select::FormSelect;
+#[cfg(feature = "form_submit")]
+pub use crate::forms::form_submit::FormSubmit;
+#[cfg(feature = "form_textarea")]
+pub use crate::forms::form_textarea::{FormTextArea, Resize, WrapText};
+#[cfg(feature = "form_file")]
+pub use crate::forms::form_upload::{UploadRequest, UploadResult};
+#[cfg(any(feature = "form_input", feature = "form_textarea"))]
+pub use crate::forms::form_validation::{ValidationRequest, ValidationResult};
+#[cfg(feature = "gallery")]
+pub use crate::gallery::{Gallery, GalleryImage};
+#[cfg(feature = "highlight")]
+pub use crate::highlight::Highlight;
+#[cfg(feature = "icon")]
+pub use crate::icon::{Flip, Icon, IconType};
+#[cfg(feature = "image_zoom")]
+pub use crate::image_zoom::ImageZoom;
+#[cfg(feature = "infinite_scroll")]
+pub use crate::infinite_scroll::InfiniteScroll;
+#[cfg(feature = "layouts")]
+pub use crate::layouts::{
+    column::Column,
+    container::{AlignContent, AlignItems, Container, Direction, JustifyContent, Mode, Wrap},
+    hidden::Hidden,
+    item::{AlignSelf, Item, ItemLayout},
+    row::Row,
+    visible::Visible,
+};
+#[cfg(feature = "list")]
+pub use crate::list::{List, ListItem, Selection};
+#[cfg(feature = "loading_overlay")]
+pub use crate::loading_overlay::LoadingOverlay;
+#[cfg(feature = "memo")]
+pub use crate::memo::{memo_key, Memo};
+#[cfg(feature = "menu")]
+pub use crate::menu::{Menu, MenuAlign, MenuEntry, MenuItem};
+#[cfg(feature = "modal")]
+pub use crate::modal::Modal;
+#[cfg(feature = "navbar")]
+pub use crate::navbar::{
+    navbar_component::{Fixed, Navbar},
+    navbar_container::NavbarContainer,
+    navbar_dropdown::{NavbarDropdown, NavbarDropdownItem},
+    navbar_item::NavbarItem,
+    navbar_route::ActiveMatch,
+};
+#[cfg(feature = "pdf_viewer")]
+pub use crate::pdf_viewer::{PdfRenderer, PdfViewer};
+#[cfg(feature = "portal")]
+pub use crate::portal::Portal;
+#[cfg(feature = "scrollspy")]
+pub use crate::scrollspy::{Scrollspy, ScrollspySection};
+#[cfg(feature = "seat_map")]
+pub use crate::seat_map::{SeatCategory, SeatId, SeatMap};
+#[cfg(feature = "secret_text")]
+pub use crate::secret_text::SecretText;
+#[cfg(feature = "sidenav")]
+pub use crate::sidenav::{
+    sidenav_component::SideNav, sidenav_item::SideNavItem, sidenav_section::SideNavSection,
+};
+#[cfg(feature = "sortable_list")]
+pub use crate::sortable_list::SortableList;
+#[cfg(feature = "spinner")]
+pub use crate::spinner::{Spinner, SpinnerType};
+#[cfg(feature = "split_pane")]
+pub use crate::split_pane::SplitPane;
+#[cfg(feature = "stat")]
+pub use crate::stat::{Stat, Trend};
+#[cfg(feature = "status_page")]
+pub use crate::status_page::{
+    DayUptime, Incident, IncidentUpdate, ServiceRow, ServiceStatus, StatusPage,
+};
+#[cfg(feature = "sticky")]
+pub use crate::sticky::Sticky;
+#[cfg(feature = "surface")]
+pub use crate::surface::Surface;
+#[cfg(feature = "text")]
+pub use crate::text::{Header, Text, TextType};
+#[cfg(feature = "error_bridge")]
+pub use crate::toast::{report_error, ErrorToastHost};
+#[cfg(feature = "toast")]
+pub use crate::toast::{
+    start_progress, CloseReason, Notification, NotificationHost, NotificationService,
+    ProgressHandle, ProgressStatus, ProgressToast, ProgressToastHost, Toast, ToastContainer,
+    ToastPosition,
+};
+#[cfg(feature = "tooltip")]
+pub use crate::tooltip::Tooltip;
+#[cfg(feature = "transition_group")]
+pub use crate::transition_group::{Transition, TransitionGroup};
+#[cfg(feature = "truncate_middle")]
+pub use crate::truncate_middle::TruncateMiddle;
+#[cfg(feature = "typography")]
+pub use crate::typography::{Heading, HeadingLevel, Label, Text as TypographyText};
+#[cfg(feature = "video_thumb")]
+pub use crate::video_thumb::VideoThumb;
@@ -0,0 +1,119 @@
+//! # Ripple
+//!
+//! Material style ripple: a circle which expands from the point where the pointer presses an element.
+//! The buttons, the list items and the cards with `interaction_effect` show it when it is enabled with `set_ripple`
+//! or the `ripple` property of `GlobalTheme`, and it is never shown when the motion is reduced.
+//! The circle takes the color of the text and is removed when its animation ends.
+//!
+//! Other elements can show it calling `add_ripple` when they are pressed:
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_styles::ripple::add_ripple;
+//!
+//! let item = html! {
+//!     <li onpointerdown=Callback::from(|event: PointerEvent| add_ripple(&event))>{"Inbox"}</li>
+//! };
+//! ```
+use crate::styles::animations::prefers_reduced_motion;
+use std::cell::Cell;
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, MouseEvent};
+use yew::utils;
+
+const HOST_CLASS: &str = "ys-ripple-host";
+const RIPPLE_CLASS: &str = "ys-ripple";
+
+thread_local! {
+    static RIPPLE: Cell<bool> = Cell::new(false);
+}
+
+/// Enable or disable the ripple of the whole app
+pub fn set_ripple(enabled: bool) {
+    RIPPLE.with(|setting| setting.set(enabled));
+}
+
+/// If the ripple is enabled with `set_ripple` or the `ripple` property of `GlobalTheme`
+pub fn is_ripple_enabled() -> bool {
+    RIPPLE.with(|setting| setting.get())
+}
+
+/// Show a ripple in the element which handles the event, from the point of the pointer.
+/// Nothing is shown when the ripple is disabled or the motion is reduced
+pub fn add_ripple(event: &MouseEvent) {
+    if !is_ripple_enabled() || prefers_reduced_motion() {
+        return;
+    }
+
+    let element = match event
+        .current_target()
+        .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+    {
+        Some(element) => element,
+        None => return,
+    };
+    let rect = element.get_bounding_client_rect();
+    let (size, left, top) = get_ripple_geometry(
+        rect.width(),
+        rect.height(),
+        event.client_x() as f64 - rect.left(),
+        event.client_y() as f64 - rect.top(),
+    );
+
+    let ripple = utils::document().create_element("span").unwrap();
+    ripple.set_class_name(RIPPLE_CLASS);
+    ripple
+        .set_attribute(
+            "style",
+            &format!(
+                "width: {size}px; height: {size}px; left: {left}px; top: {top}px;",
+                size = size,
+                left = left,
+                top = top,
+            ),
+        )
+        .ok();
+    ripple.set_attribute("aria-hidden", "true").ok();
+
+    let finished = ripple.clone();
+    let remove = Closure::once_into_js(move || finished.remove());
+    ripple
+        .add_event_listener_with_callback("animationend", remove.unchecked_ref())
+        .ok();
+
+    element.class_list().add_1(HOST_CLASS).ok();
+    element.append_child(&ripple).ok();
+}
+
+/// Size, left and top of the circle which covers the whole element from the point `x`, `y`
+fn get_ripple_geometry(width: f64, height: f64, x: f64, y: f64) -> (f64, f64, f64) {
+    let farthest_x = x.max(width - x);
+    let farthest_y = y.max(height - y);
+    let radius = (farthest_x.powi(2) + farthest_y.powi(2)).sqrt();
+
+    (radius * 2.0, x - radius, y - radius)
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_cover_the_element_from_the_pointer() {
+    assert_eq!(
+        get_ripple_geometry(30.0, 40.0, 0.0, 0.0),
+        (100.0, -50.0, -50.0)
+    );
+    assert_eq!(
+        get_ripple_geometry(60.0, 80.0, 30.0, 40.0),
+        (100.0, -20.0, -10.0)
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_toggle_the_ripple() {
+    set_ripple(true);
+    assert!(is_ripple_enabled());
+
+    set_ripple(false);
+    assert!(!is_ripple_enabled());
+}
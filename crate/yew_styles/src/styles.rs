@@ -1,5 +1,20 @@
+use crate::palettes;
+use std::fmt;
+use std::str::FromStr;
+use stylist::StyleSource;
+use wasm_bindgen_test::*;
+use yew::html::IntoPropValue;
+use yew::services::ConsoleService;
+use yew::Classes;
+
+pub mod animations;
+pub mod breakpoints;
+pub mod tokens;
+
 /// Palette of styles according with the purpose
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Palette {
     Primary,
     Secondary,
@@ -10,6 +25,8 @@ pub enum Palette {
     Danger,
     Standard,
     Clean,
+    /// Palette registered with `palettes::register_palette`
+    Custom(String),
 }
 
 pub fn get_palette(palette: Palette) -> String {
@@ -23,11 +40,14 @@ pub fn get_palette(palette: Palette) -> String {
         Palette::Danger => String::from("danger"),
         Palette::Standard => String::from("standard"),
         Palette::Clean => String::from("clean"),
+        Palette::Custom(name) => palettes::get_class_name(&name),
     }
 }
 
 /// The standard sizes of the element
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Size {
     Small,
     Medium,
@@ -35,7 +55,9 @@ pub enum Size {
 }
 
 /// Standars button styles
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Style {
     Regular,
     Outline,
@@ -43,7 +65,9 @@ pub enum Style {
 }
 
 /// Position over targeted element
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Position {
     Left,
     Right,
@@ -66,3 +90,246 @@ pub fn get_style(style: Style) -> String {
         Style::Light => String::from("light"),
     }
 }
+
+/// Utility classes of the `margin` and `padding` properties
+pub fn get_spacing(margin: tokens::Spaces, padding: tokens::Spaces) -> Vec<String> {
+    let mut classes = margin.classes("m");
+    classes.extend(padding.classes("p"));
+    classes
+}
+
+/// Builder of the classes of a component, the classes are added in a fixed order whatever
+/// the order of the calls: the semantic classes (the component class and its palette, size or style),
+/// the state classes (`disabled`, `error`, `active`...), the spacing utility classes,
+/// the class generated by stylist for the `styles` property and at the end the `class_name` of the user,
+/// so the custom classes can always override the component ones
+///
+/// ```rust
+/// use stylist::css;
+/// use yew_styles::styles::{tokens::Spaces, ComponentClasses};
+///
+/// let classes = ComponentClasses::new("form-input")
+///     .class_name("search")
+///     .state("disabled", true)
+///     .state("error", false)
+///     .semantic("primary")
+///     .spacing(Spaces::default(), Spaces::default())
+///     .styles(css!("color: red;"))
+///     .build();
+///
+/// assert!(classes.to_string().starts_with("form-input primary disabled"));
+/// assert!(classes.to_string().ends_with("search"));
+/// ```
+#[derive(Clone, Default)]
+pub struct ComponentClasses {
+    semantic: Classes,
+    states: Classes,
+    spacing: Classes,
+    styles: Classes,
+    class_name: Classes,
+}
+
+impl ComponentClasses {
+    /// Start with the class of the component, e.g. `"button"`
+    pub fn new(component: impl Into<Classes>) -> Self {
+        Self {
+            semantic: component.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Add semantic classes like the palette, size or style
+    pub fn semantic(mut self, class: impl Into<Classes>) -> Self {
+        self.semantic.push(class);
+        self
+    }
+
+    /// Add the state class when `active` is true
+    pub fn state(mut self, class: &'static str, active: bool) -> Self {
+        if active {
+            self.states.push(class);
+        }
+        self
+    }
+
+    /// Add the utility classes of the `margin` and `padding` properties
+    pub fn spacing(mut self, margin: tokens::Spaces, padding: tokens::Spaces) -> Self {
+        self.spacing.push(get_spacing(margin, padding));
+        self
+    }
+
+    /// Add the class generated by stylist for the `styles` property
+    pub fn styles(mut self, styles: StyleSource<'static>) -> Self {
+        self.styles.push(styles);
+        self
+    }
+
+    /// Add the custom classes of the user
+    pub fn class_name(mut self, class_name: impl Into<Classes>) -> Self {
+        self.class_name.push(class_name);
+        self
+    }
+
+    pub fn build(self) -> Classes {
+        let mut classes = self.semantic;
+        classes.push(self.states);
+        classes.push(self.spacing);
+        classes.push(self.styles);
+        classes.push(self.class_name);
+        classes
+    }
+}
+
+/// Utility class of the `elevation` property, defined in `_elevation.sass`
+pub fn get_elevation(elevation: tokens::Elevation) -> String {
+    format!("elevation-{}", elevation.name())
+}
+
+/// Error returned when a string doesn't match any variant of the style enums
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParseStyleError {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid {}", self.value, self.kind)
+    }
+}
+
+impl std::error::Error for ParseStyleError {}
+
+macro_rules! impl_style_enum {
+    ($enum_type:ident, $kind:expr, $default:ident, { $($value:expr => $variant:ident),* $(,)? } $(, $custom:ident)?) => {
+        impl $enum_type {
+            /// Every built-in variant in declaration order
+            pub const ALL: &'static [$enum_type] = &[$($enum_type::$variant),*];
+
+            /// Iterates over every built-in variant in declaration order
+            pub fn iter() -> impl Iterator<Item = $enum_type> {
+                Self::ALL.iter().cloned()
+            }
+        }
+
+        impl fmt::Display for $enum_type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value = match self {
+                    $($enum_type::$variant => $value,)*
+                    $($enum_type::$custom(name) => name.as_str(),)?
+                };
+
+                f.write_str(value)
+            }
+        }
+
+        impl FromStr for $enum_type {
+            type Err = ParseStyleError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value.to_lowercase().as_str() {
+                    $($value => Ok($enum_type::$variant),)*
+                    $(_ if palettes::is_registered(value) => Ok($enum_type::$custom(value.to_string())),)?
+                    _ => Err(ParseStyleError {
+                        kind: $kind,
+                        value: value.to_string(),
+                    }),
+                }
+            }
+        }
+
+        // Allows to set the property with a string literal in html!, e.g. button_palette="danger".
+        // An unknown value logs a warning and falls back to the default variant instead of breaking the render
+        impl IntoPropValue<$enum_type> for &'static str {
+            fn into_prop_value(self) -> $enum_type {
+                self.parse().unwrap_or_else(|error: ParseStyleError| {
+                    ConsoleService::warn(&error.to_string());
+                    $enum_type::$default
+                })
+            }
+        }
+    };
+}
+
+impl_style_enum!(Palette, "palette", Standard, {
+    "primary" => Primary,
+    "secondary" => Secondary,
+    "success" => Success,
+    "info" => Info,
+    "link" => Link,
+    "warning" => Warning,
+    "danger" => Danger,
+    "standard" => Standard,
+    "clean" => Clean,
+}, Custom);
+
+impl_style_enum!(Size, "size", Medium, {
+    "small" => Small,
+    "medium" => Medium,
+    "big" => Big,
+});
+
+impl_style_enum!(Style, "style", Regular, {
+    "regular" => Regular,
+    "outline" => Outline,
+    "light" => Light,
+});
+
+impl_style_enum!(Position, "position", Below, {
+    "left" => Left,
+    "right" => Right,
+    "above" => Above,
+    "below" => Below,
+});
+
+#[wasm_bindgen_test]
+fn should_parse_style_enums_from_strings() {
+    assert!("danger".parse::<Palette>() == Ok(Palette::Danger));
+    assert!("Big".parse::<Size>() == Ok(Size::Big));
+    assert!("outline".parse::<Style>() == Ok(Style::Outline));
+    assert!("below".parse::<Position>() == Ok(Position::Below));
+    assert_eq!(
+        "huge".parse::<Size>().err().unwrap().to_string(),
+        "'huge' is not a valid size"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_convert_string_literals_into_prop_values() {
+    let palette: Palette = "warning".into_prop_value();
+    let size: Size = "huge".into_prop_value();
+
+    assert!(palette == Palette::Warning);
+    assert!(size == Size::Medium);
+}
+
+#[wasm_bindgen_test]
+fn should_iterate_over_every_variant() {
+    assert_eq!(Palette::ALL.len(), 9);
+    assert_eq!(
+        Size::iter().collect::<Vec<Size>>(),
+        vec![Size::Small, Size::Medium, Size::Big]
+    );
+
+    for palette in Palette::iter() {
+        assert_eq!(palette.to_string(), get_palette(palette.clone()));
+        assert_eq!(palette.to_string().parse::<Palette>(), Ok(palette));
+    }
+
+    for style in Style::iter() {
+        assert_eq!(style.to_string(), get_style(style.clone()));
+    }
+}
+
+#[wasm_bindgen_test]
+fn should_compose_the_classes_in_order() {
+    let classes = ComponentClasses::new("button")
+        .class_name("custom")
+        .state("active", true)
+        .state("disabled", false)
+        .semantic(vec!["primary", "regular"])
+        .spacing(tokens::Spaces::default(), tokens::Spaces::default())
+        .build();
+
+    assert_eq!(classes.to_string(), "button primary regular active custom");
+}
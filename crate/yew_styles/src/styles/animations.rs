@@ -0,0 +1,259 @@
+//! # Animations
+//!
+//! Keyframes shared by the components. They are defined in `_animations.sass`, so they are available
+//! in the `css!` blocks of your components together with the `Duration` and `Easing` tokens:
+//!
+//! ```rust
+//! use stylist::css;
+//! use yew_styles::styles::animations::{animation, Duration, Easing, Keyframes};
+//!
+//! let styles = css!(
+//!     "animation: ${fade}; transition: opacity ${duration} ${easing};",
+//!     fade = animation(Keyframes::FadeIn, Duration::Normal, Easing::EaseOut),
+//!     duration = Duration::Fast,
+//!     easing = Easing::Standard,
+//! );
+//! ```
+//!
+//! ## Reduced motion
+//!
+//! When the user prefers reduced motion in the system, the transitions and animations of the components
+//! are shortened to an instant, the carousel doesn't move by itself and `CountUp` shows the final number
+//! at once. The preference can be overridden with `set_reduced_motion` or the `reduced_motion` property of `GlobalTheme`
+pub use super::tokens::{Duration, Easing};
+use crate::utils::is_browser;
+use std::cell::RefCell;
+use std::fmt;
+use wasm_bindgen_test::*;
+use yew::utils;
+
+const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+const REDUCED_MOTION_CLASS: &str = "ys-reduced-motion";
+const FULL_MOTION_CLASS: &str = "ys-full-motion";
+
+thread_local! {
+    static REDUCED_MOTION: RefCell<Option<bool>> = RefCell::new(None);
+}
+
+/// Keyframes defined in `_animations.sass`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Keyframes {
+    FadeIn,
+    FadeOut,
+    SlideInUp,
+    SlideInDown,
+    SlideInLeft,
+    SlideInRight,
+    CollapseIn,
+    Pulse,
+    Shimmer,
+    Spin,
+}
+
+impl Keyframes {
+    /// Every keyframes
+    pub const ALL: &'static [Keyframes] = &[
+        Keyframes::FadeIn,
+        Keyframes::FadeOut,
+        Keyframes::SlideInUp,
+        Keyframes::SlideInDown,
+        Keyframes::SlideInLeft,
+        Keyframes::SlideInRight,
+        Keyframes::CollapseIn,
+        Keyframes::Pulse,
+        Keyframes::Shimmer,
+        Keyframes::Spin,
+    ];
+
+    /// Name of the keyframes, e.g. `ys-fade-in`
+    pub fn name(self) -> &'static str {
+        match self {
+            Keyframes::FadeIn => "ys-fade-in",
+            Keyframes::FadeOut => "ys-fade-out",
+            Keyframes::SlideInUp => "ys-slide-in-up",
+            Keyframes::SlideInDown => "ys-slide-in-down",
+            Keyframes::SlideInLeft => "ys-slide-in-left",
+            Keyframes::SlideInRight => "ys-slide-in-right",
+            Keyframes::CollapseIn => "ys-collapse-in",
+            Keyframes::Pulse => "ys-pulse",
+            Keyframes::Shimmer => "ys-shimmer",
+            Keyframes::Spin => "ys-spin",
+        }
+    }
+
+    /// The animation repeats until it is removed
+    pub fn is_infinite(self) -> bool {
+        matches!(
+            self,
+            Keyframes::Pulse | Keyframes::Shimmer | Keyframes::Spin
+        )
+    }
+
+    /// Steps of the keyframes
+    pub fn steps(self) -> &'static str {
+        match self {
+            Keyframes::FadeIn => "from { opacity: 0; } to { opacity: 1; }",
+            Keyframes::FadeOut => "from { opacity: 1; } to { opacity: 0; }",
+            Keyframes::SlideInUp => {
+                "from { opacity: 0; transform: translateY(20px); } to { opacity: 1; transform: translateY(0); }"
+            }
+            Keyframes::SlideInDown => {
+                "from { opacity: 0; transform: translateY(-20px); } to { opacity: 1; transform: translateY(0); }"
+            }
+            Keyframes::SlideInLeft => {
+                "from { opacity: 0; transform: translateX(20px); } to { opacity: 1; transform: translateX(0); }"
+            }
+            Keyframes::SlideInRight => {
+                "from { opacity: 0; transform: translateX(-20px); } to { opacity: 1; transform: translateX(0); }"
+            }
+            Keyframes::CollapseIn => {
+                "from { opacity: 0; grid-template-rows: 0fr; } to { opacity: 1; grid-template-rows: 1fr; }"
+            }
+            Keyframes::Pulse => "0%, 100% { opacity: 1; } 50% { opacity: 0.5; }",
+            Keyframes::Shimmer => {
+                "from { background-position: -200% 0; } to { background-position: 200% 0; }"
+            }
+            Keyframes::Spin => "from { transform: rotate(0deg); } to { transform: rotate(360deg); }",
+        }
+    }
+
+    /// Css definition of the keyframes, to use them without the yew_styles css
+    pub fn css(self) -> String {
+        format!("@keyframes {} {{ {} }}", self.name(), self.steps())
+    }
+}
+
+impl fmt::Display for Keyframes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Value of the `animation` property, e.g. `ys-fade-in 300ms cubic-bezier(0, 0, 0.2, 1) both`
+pub fn animation(keyframes: Keyframes, duration: Duration, easing: Easing) -> String {
+    format!(
+        "{} {} {} {}",
+        keyframes,
+        duration,
+        easing,
+        if keyframes.is_infinite() {
+            "infinite"
+        } else {
+            "both"
+        }
+    )
+}
+
+/// Progress of the easing curve at `time`, both from 0 to 1, to animate values from rust
+pub fn ease(easing: Easing, time: f64) -> f64 {
+    let time = time.clamp(0.0, 1.0);
+    // same cubic-bezier curves than the css values of Easing
+    let (x1, y1, x2, y2) = match easing {
+        Easing::Linear => return time,
+        Easing::Standard => (0.4, 0.0, 0.2, 1.0),
+        Easing::EaseIn => (0.4, 0.0, 1.0, 1.0),
+        Easing::EaseOut => (0.0, 0.0, 0.2, 1.0),
+        Easing::Emphasized => (0.2, 0.0, 0.0, 1.0),
+    };
+    let bezier = |point_1: f64, point_2: f64, t: f64| {
+        3.0 * (1.0 - t).powi(2) * t * point_1 + 3.0 * (1.0 - t) * t.powi(2) * point_2 + t.powi(3)
+    };
+
+    // x always grows with t, so the t of the time is found by bisection
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..32 {
+        let middle = (low + high) / 2.0;
+
+        if bezier(x1, x2, middle) < time {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    bezier(y1, y2, (low + high) / 2.0)
+}
+
+/// Override the reduced motion preference of the system, `None` follows the system again
+pub fn set_reduced_motion(reduced_motion: Option<bool>) {
+    REDUCED_MOTION.with(|setting| *setting.borrow_mut() = reduced_motion);
+
+    if !is_browser() {
+        return;
+    }
+
+    if let Some(root) = utils::document().document_element() {
+        let class_list = root.class_list();
+
+        class_list
+            .remove_2(REDUCED_MOTION_CLASS, FULL_MOTION_CLASS)
+            .ok();
+        match reduced_motion {
+            Some(true) => class_list.add_1(REDUCED_MOTION_CLASS).ok(),
+            Some(false) => class_list.add_1(FULL_MOTION_CLASS).ok(),
+            None => None,
+        };
+    }
+}
+
+/// If the motion should be reduced, from `set_reduced_motion` or the `prefers-reduced-motion` media query
+pub fn prefers_reduced_motion() -> bool {
+    REDUCED_MOTION
+        .with(|setting| *setting.borrow())
+        .unwrap_or_else(|| {
+            is_browser()
+                && utils::window()
+                    .match_media(REDUCED_MOTION_QUERY)
+                    .ok()
+                    .flatten()
+                    .map(|query| query.matches())
+                    .unwrap_or(false)
+        })
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_build_the_animation_value() {
+    assert_eq!(
+        animation(Keyframes::FadeIn, Duration::Normal, Easing::Linear),
+        "ys-fade-in 300ms linear both"
+    );
+    assert_eq!(
+        animation(Keyframes::Spin, Duration::Slow, Easing::Linear),
+        "ys-spin 500ms linear infinite"
+    );
+    assert!(Keyframes::Pulse.css().starts_with("@keyframes ys-pulse {"));
+}
+
+#[wasm_bindgen_test]
+fn should_override_the_reduced_motion() {
+    set_reduced_motion(Some(true));
+    assert!(prefers_reduced_motion());
+    assert!(utils::document()
+        .document_element()
+        .unwrap()
+        .class_list()
+        .contains(REDUCED_MOTION_CLASS));
+
+    set_reduced_motion(Some(false));
+    assert!(!prefers_reduced_motion());
+
+    set_reduced_motion(None);
+    assert!(!utils::document()
+        .document_element()
+        .unwrap()
+        .class_list()
+        .contains(FULL_MOTION_CLASS));
+}
+
+#[wasm_bindgen_test]
+fn should_ease_the_progress() {
+    assert_eq!(ease(Easing::Linear, 0.3), 0.3);
+    assert_eq!(ease(Easing::Standard, -1.0), 0.0);
+    assert!((ease(Easing::Standard, 1.0) - 1.0).abs() < 1e-6);
+    assert!(ease(Easing::EaseOut, 0.5) > 0.5);
+    assert!(ease(Easing::EaseIn, 0.5) < 0.5);
+}
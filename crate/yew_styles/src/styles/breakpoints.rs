@@ -0,0 +1,131 @@
+//! # Breakpoints
+//!
+//! Screen widths where the layout changes. They are the same than the screens of `ItemLayout`
+//! and `$breakpoints` in `_global-variables.sass`
+//!
+//! | Breakpoint | Width            |
+//! |------------|------------------|
+//! | `Xs`       | < 576px          |
+//! | `S`        | 576px - 767px    |
+//! | `M`        | 768px - 991px    |
+//! | `L`        | 992px - 1199px   |
+//! | `Xl`       | >= 1200px        |
+use crate::utils::is_browser;
+use std::fmt;
+use wasm_bindgen_test::*;
+use web_sys::window;
+
+/// Named screen widths
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Breakpoint {
+    Xs,
+    S,
+    M,
+    L,
+    Xl,
+}
+
+impl Breakpoint {
+    /// Every breakpoint from the smallest to the biggest
+    pub const ALL: &'static [Breakpoint] = &[
+        Breakpoint::Xs,
+        Breakpoint::S,
+        Breakpoint::M,
+        Breakpoint::L,
+        Breakpoint::Xl,
+    ];
+
+    /// Name used in the class names, e.g. `hidden-m`
+    pub fn name(self) -> &'static str {
+        match self {
+            Breakpoint::Xs => "xs",
+            Breakpoint::S => "s",
+            Breakpoint::M => "m",
+            Breakpoint::L => "l",
+            Breakpoint::Xl => "xl",
+        }
+    }
+
+    /// Minimum width in pixels where the breakpoint starts
+    pub fn min_width(self) -> u32 {
+        match self {
+            Breakpoint::Xs => 0,
+            Breakpoint::S => 576,
+            Breakpoint::M => 768,
+            Breakpoint::L => 992,
+            Breakpoint::Xl => 1200,
+        }
+    }
+
+    /// Maximum width in pixels of the breakpoint, `None` for `Xl`
+    pub fn max_width(self) -> Option<u32> {
+        Self::ALL
+            .iter()
+            .find(|breakpoint| **breakpoint > self)
+            .map(|next| next.min_width() - 1)
+    }
+
+    /// Media query from this breakpoint up, e.g. `(min-width: 768px)`
+    pub fn up(self) -> String {
+        format!("(min-width: {}px)", self.min_width())
+    }
+
+    /// Media query only for this breakpoint, e.g. `(min-width: 768px) and (max-width: 991px)`
+    pub fn only(self) -> String {
+        match self.max_width() {
+            Some(max_width) => format!("{} and (max-width: {}px)", self.up(), max_width),
+            None => self.up(),
+        }
+    }
+
+    /// Breakpoint of a width in pixels
+    pub fn from_width(width: f64) -> Breakpoint {
+        *Self::ALL
+            .iter()
+            .rev()
+            .find(|breakpoint| width >= breakpoint.min_width() as f64)
+            .unwrap_or(&Breakpoint::Xs)
+    }
+
+    /// Breakpoint of the current window width
+    pub fn current() -> Breakpoint {
+        if !is_browser() {
+            return Breakpoint::Xs;
+        }
+
+        let width = window()
+            .and_then(|window| window.inner_width().ok())
+            .and_then(|width| width.as_f64())
+            .unwrap_or(0.0);
+
+        Self::from_width(width)
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_breakpoint_of_a_width() {
+    assert_eq!(Breakpoint::from_width(320.0), Breakpoint::Xs);
+    assert_eq!(Breakpoint::from_width(768.0), Breakpoint::M);
+    assert_eq!(Breakpoint::from_width(1199.0), Breakpoint::L);
+    assert_eq!(Breakpoint::from_width(1920.0), Breakpoint::Xl);
+}
+
+#[wasm_bindgen_test]
+fn should_build_media_queries() {
+    assert_eq!(
+        Breakpoint::M.only(),
+        "(min-width: 768px) and (max-width: 991px)"
+    );
+    assert_eq!(Breakpoint::Xl.only(), "(min-width: 1200px)");
+    assert_eq!(Breakpoint::S.up(), "(min-width: 576px)");
+}
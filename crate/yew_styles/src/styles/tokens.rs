@@ -0,0 +1,334 @@
+//! # Design tokens
+//!
+//! Scales of spacing, border radius, shadows, elevation and typography used by the components.
+//! The same values are defined in `_global-variables.sass` and exported as css variables by `GlobalTheme`.
+//!
+//! Every token implements `Display`, so it can be used in the `css!` blocks of your components:
+//!
+//! ```rust
+//! use stylist::css;
+//! use yew_styles::styles::tokens::{Radius, Shadow, Spacing};
+//!
+//! let styles = css!(
+//!     "padding: ${padding}; border-radius: ${radius}; box-shadow: ${shadow};",
+//!     padding = Spacing::Medium,
+//!     radius = Radius::Medium,
+//!     shadow = Shadow::Low,
+//! );
+//! ```
+use std::fmt;
+use wasm_bindgen_test::*;
+use yew::html::IntoPropValue;
+
+macro_rules! impl_token {
+    ($token:ident, $variable:expr, { $($variant:ident => $name:expr, $value:expr),* $(,)? }) => {
+        impl $token {
+            /// Every token of the scale from the lowest to the highest
+            pub const ALL: &'static [$token] = &[$($token::$variant),*];
+
+            /// Css value of the token
+            pub fn value(&self) -> &'static str {
+                match self {
+                    $($token::$variant => $value,)*
+                }
+            }
+
+            /// Name of the token used in the sass map and the css variable
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($token::$variant => $name,)*
+                }
+            }
+
+            /// Css variable defined by `GlobalTheme`, e.g. `var(--ys-spacing-medium)`
+            pub fn variable(&self) -> String {
+                format!("var(--ys-{}-{})", $variable, self.name())
+            }
+        }
+
+        impl fmt::Display for $token {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.value())
+            }
+        }
+    };
+}
+
+/// Spacing scale for margins, paddings and gaps
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Spacing {
+    None,
+    XSmall,
+    Small,
+    Medium,
+    Large,
+    XLarge,
+}
+
+impl_token!(Spacing, "spacing", {
+    None => "none", "0",
+    XSmall => "xsmall", "2px",
+    Small => "small", "5px",
+    Medium => "medium", "10px",
+    Large => "large", "20px",
+    XLarge => "xlarge", "30px",
+});
+
+/// Spacing of each side used by the `margin` and `padding` properties of the components.
+/// A `Spacing` can be passed directly to set the same space in every side
+///
+/// ```rust
+/// use yew_styles::styles::tokens::{Spaces, Spacing};
+///
+/// let padding = Spaces::horizontal(Spacing::Large);
+/// let margin = Spaces {
+///     bottom: Some(Spacing::Medium),
+///     ..Spaces::default()
+/// };
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spaces {
+    pub top: Option<Spacing>,
+    pub right: Option<Spacing>,
+    pub bottom: Option<Spacing>,
+    pub left: Option<Spacing>,
+}
+
+impl Spaces {
+    /// Same space in every side
+    pub fn all(spacing: Spacing) -> Spaces {
+        Spaces {
+            top: Some(spacing),
+            right: Some(spacing),
+            bottom: Some(spacing),
+            left: Some(spacing),
+        }
+    }
+
+    /// Space in the left and the right sides
+    pub fn horizontal(spacing: Spacing) -> Spaces {
+        Spaces {
+            right: Some(spacing),
+            left: Some(spacing),
+            ..Spaces::default()
+        }
+    }
+
+    /// Space in the top and the bottom sides
+    pub fn vertical(spacing: Spacing) -> Spaces {
+        Spaces {
+            top: Some(spacing),
+            bottom: Some(spacing),
+            ..Spaces::default()
+        }
+    }
+
+    /// Utility classes of the spaces with the prefix of the property, e.g. `mt-medium`
+    pub fn classes(self, prefix: &str) -> Vec<String> {
+        if self.top.is_some()
+            && [self.right, self.bottom, self.left]
+                .iter()
+                .all(|side| *side == self.top)
+        {
+            return vec![format!("{}-{}", prefix, self.top.unwrap().name())];
+        }
+
+        [
+            ("t", self.top),
+            ("r", self.right),
+            ("b", self.bottom),
+            ("l", self.left),
+        ]
+        .iter()
+        .filter_map(|(side, spacing)| {
+            spacing.map(|spacing| format!("{}{}-{}", prefix, side, spacing.name()))
+        })
+        .collect()
+    }
+}
+
+impl From<Spacing> for Spaces {
+    fn from(spacing: Spacing) -> Self {
+        Spaces::all(spacing)
+    }
+}
+
+impl IntoPropValue<Spaces> for Spacing {
+    fn into_prop_value(self) -> Spaces {
+        Spaces::all(self)
+    }
+}
+
+/// Border radius scale
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Radius {
+    None,
+    Small,
+    Medium,
+    Large,
+    Round,
+}
+
+impl_token!(Radius, "radius", {
+    None => "none", "0",
+    Small => "small", "2px",
+    Medium => "medium", "4px",
+    Large => "large", "8px",
+    Round => "round", "50%",
+});
+
+/// Shadow levels, from flat to elements floating over the page
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shadow {
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl_token!(Shadow, "shadow", {
+    None => "none", "none",
+    Low => "low", "0 2px 8px rgba(0, 0, 0, 0.2)",
+    Medium => "medium", "0 4px 12px rgba(0, 0, 0, 0.25)",
+    High => "high", "0 8px 24px rgba(0, 0, 0, 0.3)",
+});
+
+/// Elevation levels of the surfaces, from flat to overlays like the modals.
+/// Card, dropdown menu and modal use them with the `elevation` property
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Elevation {
+    Level0,
+    Level1,
+    Level2,
+    Level3,
+    Level4,
+    Level5,
+}
+
+impl_token!(Elevation, "elevation", {
+    Level0 => "0", "none",
+    Level1 => "1", "0 1px 3px rgba(0, 0, 0, 0.12), 0 1px 2px rgba(0, 0, 0, 0.24)",
+    Level2 => "2", "0 3px 6px rgba(0, 0, 0, 0.15), 0 2px 4px rgba(0, 0, 0, 0.12)",
+    Level3 => "3", "0 10px 20px rgba(0, 0, 0, 0.15), 0 3px 6px rgba(0, 0, 0, 0.1)",
+    Level4 => "4", "0 15px 25px rgba(0, 0, 0, 0.15), 0 5px 10px rgba(0, 0, 0, 0.05)",
+    Level5 => "5", "0 20px 40px rgba(0, 0, 0, 0.2)",
+});
+
+impl Default for Elevation {
+    fn default() -> Self {
+        Elevation::Level0
+    }
+}
+
+/// Typography scale for font sizes
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontSize {
+    Caption,
+    Small,
+    Body,
+    Medium,
+    Big,
+    Headline,
+}
+
+impl_token!(FontSize, "font-size", {
+    Caption => "caption", "10px",
+    Small => "small", "12px",
+    Body => "body", "14px",
+    Medium => "medium", "18px",
+    Big => "big", "26px",
+    Headline => "headline", "36px",
+});
+
+/// Font weights of the typography
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontWeight {
+    Light,
+    Regular,
+    Medium,
+    Bold,
+}
+
+impl_token!(FontWeight, "font-weight", {
+    Light => "light", "300",
+    Regular => "regular", "400",
+    Medium => "medium", "500",
+    Bold => "bold", "700",
+});
+
+/// Timing functions of the transitions and animations
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    Linear,
+    Standard,
+    EaseIn,
+    EaseOut,
+    Emphasized,
+}
+
+impl_token!(Easing, "easing", {
+    Linear => "linear", "linear",
+    Standard => "standard", "cubic-bezier(0.4, 0, 0.2, 1)",
+    EaseIn => "in", "cubic-bezier(0.4, 0, 1, 1)",
+    EaseOut => "out", "cubic-bezier(0, 0, 0.2, 1)",
+    Emphasized => "emphasized", "cubic-bezier(0.2, 0, 0, 1)",
+});
+
+/// Durations of the transitions and animations
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Duration {
+    Fast,
+    Normal,
+    Slow,
+}
+
+impl_token!(Duration, "duration", {
+    Fast => "fast", "150ms",
+    Normal => "normal", "300ms",
+    Slow => "slow", "500ms",
+});
+
+impl Duration {
+    /// Milliseconds of the duration, to wait for the end of a transition from rust
+    pub fn millis(&self) -> u64 {
+        self.value().trim_end_matches("ms").parse().unwrap_or(0)
+    }
+}
+
+/// Font family of the components
+pub const FONT_FAMILY: &str = "Rosario";
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_format_tokens_as_css_values() {
+    assert_eq!(Spacing::Medium.to_string(), "10px");
+    assert_eq!(
+        format!("border-radius: {};", Radius::Large),
+        "border-radius: 8px;"
+    );
+    assert_eq!(Shadow::None.value(), "none");
+    assert_eq!(FontSize::Big.variable(), "var(--ys-font-size-big)");
+    assert_eq!(Spacing::ALL.len(), 6);
+    assert_eq!(Elevation::Level2.variable(), "var(--ys-elevation-2)");
+    assert_eq!(Elevation::ALL.len(), 6);
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_spacing_classes() {
+    assert_eq!(Spaces::all(Spacing::Small).classes("m"), vec!["m-small"]);
+    assert_eq!(
+        Spaces::horizontal(Spacing::Large).classes("p"),
+        vec!["pr-large", "pl-large"]
+    );
+    assert!(Spaces::default().classes("m").is_empty());
+}
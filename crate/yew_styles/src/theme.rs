@@ -0,0 +1,908 @@
+use crate::palettes::get_registered_palette;
+use crate::ripple::set_ripple;
+use crate::styles::animations::set_reduced_motion;
+use crate::styles::tokens::{
+    Duration, Easing, Elevation, FontSize, FontWeight, Radius, Shadow, Spacing, FONT_FAMILY,
+};
+use crate::styles::{get_palette, Palette};
+use crate::utils::is_browser;
+use std::cell::{Cell, RefCell};
+use stylist::GlobalStyle as StylistGlobalStyle;
+use wasm_bindgen_test::*;
+use web_sys::window;
+use yew::prelude::*;
+use yew::utils;
+
+const THEME_STYLE_ID: &str = "yew-styles-theme";
+
+thread_local! {
+    static DENSITY: Cell<Density> = Cell::new(Density::Comfortable);
+    static THEME_OVERRIDES: RefCell<Vec<(String, String)>> = RefCell::new(vec![]);
+}
+
+/// Spacing of the controls, it scales the paddings of the buttons, inputs and list items,
+/// and so their heights, with the `--ys-density` css variable
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Density {
+    Compact,
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    pub const ALL: &'static [Density] =
+        &[Density::Compact, Density::Comfortable, Density::Spacious];
+
+    /// Factor applied to the paddings, same values than `_density.sass`
+    pub fn scale(&self) -> f64 {
+        match self {
+            Density::Compact => 0.6,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.4,
+        }
+    }
+
+    /// Class which sets the density in an element and its children, e.g. `class_name="ys-compact"`
+    pub fn class_name(&self) -> &'static str {
+        match self {
+            Density::Compact => "ys-compact",
+            Density::Comfortable => "ys-comfortable",
+            Density::Spacious => "ys-spacious",
+        }
+    }
+}
+
+impl Default for Density {
+    fn default() -> Self {
+        Density::Comfortable
+    }
+}
+
+/// # Global Theme component
+///
+/// Adds the colors, sizes and interaction colors of the framework as css custom properties in `:root`,
+/// so elements that are not yew_styles components can use them,
+/// e.g. `border-color: var(--ys-primary)` or `background-color: var(--ys-danger-hover)`.
+///
+/// Each palette defines `--ys-{palette}`, `--ys-{palette}-text`, `--ys-{palette}-light`,
+/// `--ys-{palette}-focus`, `--ys-{palette}-hover` and `--ys-{palette}-active`.
+/// The design tokens are defined as `--ys-spacing-{name}`, `--ys-radius-{name}`, `--ys-shadow-{name}`, `--ys-elevation-{level}`,
+/// `--ys-font-size-{name}` and `--ys-font-weight-{name}`.
+/// It also defines `--ys-border-radius`, `--ys-font-family` and `--ys-error`.
+/// `reduced_motion` overrides the `prefers-reduced-motion` preference of the system
+/// `density` sets how compact are the controls of the whole app and `ripple` enables the ripple of the buttons and the cards.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::theme::{Density, GlobalTheme};
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <GlobalTheme
+///                     variables=vec![("--ys-border-radius".to_string(), "8px".to_string())]
+///                     density=Density::Compact
+///                 />
+///                 <div style="border: 1px solid var(--ys-primary)">{"Custom element"}</div>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct GlobalTheme {
+    props: Props,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Extra css variables or new values for the theme ones, as pairs of name and value
+    #[prop_or_default]
+    pub variables: Vec<(String, String)>,
+    /// Shorten the transitions and animations of the components, `None` follows the system preference. Default `None`
+    #[prop_or_default]
+    pub reduced_motion: Option<bool>,
+    /// Spacing of the controls of the app. Default `Density::Comfortable`
+    #[prop_or_default]
+    pub density: Density,
+    /// Show a ripple where the buttons, the list items and the interactive cards are pressed. Default `false`
+    #[prop_or(false)]
+    pub ripple: bool,
+}
+
+impl Component for GlobalTheme {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            set_theme_variables(&props.variables);
+            set_reduced_motion(props.reduced_motion);
+            set_density(props.density);
+            set_ripple(props.ripple);
+            self.props = props;
+        }
+        false
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            set_theme_variables(&self.props.variables);
+            set_reduced_motion(self.props.reduced_motion);
+            set_density(self.props.density);
+            set_ripple(self.props.ripple);
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {}
+    }
+}
+
+/// # Global Styles component
+///
+/// Adds a small reset as stylist global styles, so the app starts from the same baseline in every browser:
+/// `border-box` sizing, no body margin, the font family and body size of the typography tokens,
+/// form controls with the font of the page and media which doesn't overflow its container.
+/// The styles are removed when the component is destroyed
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::theme::{GlobalStyles, GlobalTheme};
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <GlobalStyles />
+///                 <GlobalTheme />
+///                 <main>{"Content"}</main>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct GlobalStyles {
+    style: Option<StylistGlobalStyle>,
+}
+
+impl Component for GlobalStyles {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { style: None }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.style = StylistGlobalStyle::new(get_reset_css()).ok();
+        }
+    }
+
+    fn destroy(&mut self) {
+        if let Some(style) = self.style.take() {
+            style.unregister();
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {}
+    }
+}
+
+/// # Page Style component
+///
+/// Adds any css at the document level as stylist global styles, for page level styles written in Rust.
+/// The css supports the nesting of stylist, e.g. `.page { h1 { margin: 0; } }`,
+/// and `${name}` is replaced by the value of the theme variable `--ys-{name}`,
+/// e.g. `${primary}` or `${spacing-medium}`, which also works where `var()` doesn't, like media queries.
+/// The styles are updated when the css changes and removed when the component is destroyed
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::theme::PageStyle;
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+///         App
+///     }
+///
+///     fn update(&mut self, _: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <>
+///                 <PageStyle
+///                     css=".landing {
+///                         padding: ${spacing-large};
+///                         h1 { color: ${primary}; }
+///                     }"
+///                 />
+///                 <main class="landing"><h1>{"Welcome"}</h1></main>
+///             </>
+///         }
+///     }
+/// }
+/// ```
+pub struct PageStyle {
+    props: PageStyleProps,
+    style: Option<StylistGlobalStyle>,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct PageStyleProps {
+    /// Css added to the document, `${name}` is replaced by the value of `--ys-{name}`. Required
+    pub css: String,
+}
+
+impl PageStyle {
+    fn register(&mut self) {
+        self.unregister();
+        self.style = StylistGlobalStyle::new(interpolate_theme(&self.props.css)).ok();
+    }
+
+    fn unregister(&mut self) {
+        if let Some(style) = self.style.take() {
+            style.unregister();
+        }
+    }
+}
+
+impl Component for PageStyle {
+    type Message = ();
+    type Properties = PageStyleProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props, style: None }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.register();
+        }
+        false
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.register();
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.unregister();
+    }
+
+    fn view(&self) -> Html {
+        html! {}
+    }
+}
+
+/// Values of the active theme: the css variables of `GlobalTheme` with its overrides and the density.
+/// It is a copy taken by `use_theme`, so take a new one when the theme changes
+///
+/// ```rust
+/// use stylist::{css, StyleSource, YieldStyle};
+/// use yew_styles::styles::{tokens::Spacing, Palette};
+/// use yew_styles::theme::use_theme;
+///
+/// pub struct Banner;
+///
+/// impl YieldStyle for Banner {
+///     fn style_from(&self) -> StyleSource<'static> {
+///         let theme = use_theme();
+///
+///         css!(
+///             "padding: ${padding}; background-color: ${background}; color: ${color};
+///             &:hover { background-color: ${hover}; }",
+///             padding = theme.spacing(Spacing::Medium),
+///             background = theme.color(&Palette::Primary),
+///             color = theme.text_color(&Palette::Primary),
+///             hover = theme.hover_color(&Palette::Primary),
+///         )
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    variables: Vec<(String, String)>,
+    density: Density,
+}
+
+impl Theme {
+    /// Value of a css variable of the theme, with or without the `--ys-` prefix, e.g. `"border-radius"`
+    pub fn variable(&self, name: &str) -> Option<&str> {
+        let name = if name.starts_with("--") {
+            name.to_string()
+        } else {
+            format!("--ys-{}", name)
+        };
+
+        self.variables
+            .iter()
+            .find(|(variable, _)| *variable == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Main color of the palette
+    pub fn color(&self, palette: &Palette) -> String {
+        self.get_palette_variable(palette, "")
+            .unwrap_or_else(|| get_palette_color(palette))
+    }
+
+    /// Color of the text over the main color of the palette
+    pub fn text_color(&self, palette: &Palette) -> String {
+        self.get_palette_variable(palette, "-text")
+            .unwrap_or_else(|| get_palette_colors(palette).1)
+    }
+
+    /// Light variant of the palette, used in the backgrounds
+    pub fn light_color(&self, palette: &Palette) -> String {
+        self.get_palette_variable(palette, "-light")
+            .unwrap_or_else(|| get_palette_colors(palette).2)
+    }
+
+    /// Color of the palette while the element has the focus
+    pub fn focus_color(&self, palette: &Palette) -> String {
+        self.get_interaction_color(palette, "-focus", 5.0)
+    }
+
+    /// Color of the palette while the pointer is over the element
+    pub fn hover_color(&self, palette: &Palette) -> String {
+        self.get_interaction_color(palette, "-hover", 10.0)
+    }
+
+    /// Color of the palette while the element is pressed
+    pub fn active_color(&self, palette: &Palette) -> String {
+        self.get_interaction_color(palette, "-active", 15.0)
+    }
+
+    pub fn spacing(&self, spacing: Spacing) -> String {
+        self.get_token("spacing", spacing.name(), spacing.value())
+    }
+
+    pub fn radius(&self, radius: Radius) -> String {
+        self.get_token("radius", radius.name(), radius.value())
+    }
+
+    pub fn shadow(&self, shadow: Shadow) -> String {
+        self.get_token("shadow", shadow.name(), shadow.value())
+    }
+
+    pub fn font_size(&self, font_size: FontSize) -> String {
+        self.get_token("font-size", font_size.name(), font_size.value())
+    }
+
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    /// Replace each `${name}` in the css by the value of the theme variable `--ys-{name}`,
+    /// the unknown names are kept as they are
+    pub fn interpolate(&self, css: &str) -> String {
+        let mut interpolated = String::with_capacity(css.len());
+        let mut rest = css;
+
+        while let Some(start) = rest.find("${") {
+            interpolated.push_str(&rest[..start]);
+            let placeholder = &rest[start..];
+
+            match placeholder.find('}') {
+                Some(end) => {
+                    match self.variable(placeholder[2..end].trim()) {
+                        Some(value) => interpolated.push_str(value),
+                        None => interpolated.push_str(&placeholder[..=end]),
+                    }
+                    rest = &placeholder[end + 1..];
+                }
+                None => {
+                    interpolated.push_str(placeholder);
+                    rest = "";
+                }
+            }
+        }
+        interpolated.push_str(rest);
+
+        interpolated
+    }
+
+    fn get_palette_variable(&self, palette: &Palette, suffix: &str) -> Option<String> {
+        self.variable(&format!("{}{}", get_palette(palette.clone()), suffix))
+            .map(str::to_string)
+    }
+
+    fn get_interaction_color(&self, palette: &Palette, suffix: &str, amount: f64) -> String {
+        self.get_palette_variable(palette, suffix)
+            .unwrap_or_else(|| darken(&self.color(palette), amount))
+    }
+
+    fn get_token(&self, variable: &str, name: &str, value: &str) -> String {
+        self.variable(&format!("{}-{}", variable, name))
+            .unwrap_or(value)
+            .to_string()
+    }
+}
+
+/// Active theme, for the styles of your own components, e.g. in their `YieldStyle` implementations.
+/// It includes the overrides of `GlobalTheme` and `set_theme_variables`
+pub fn use_theme() -> Theme {
+    let overrides = THEME_OVERRIDES.with(|overrides| overrides.borrow().clone());
+
+    Theme {
+        variables: get_active_variables(&overrides),
+        density: get_density(),
+    }
+}
+
+/// Replace each `${name}` in the css by the value of the theme variable `--ys-{name}`,
+/// the unknown names are kept as they are
+pub fn interpolate_theme(css: &str) -> String {
+    use_theme().interpolate(css)
+}
+
+/// Add the theme css variables in `:root`, `variables` adds new ones or replaces the theme values
+pub fn set_theme_variables(variables: &[(String, String)]) {
+    THEME_OVERRIDES.with(|overrides| *overrides.borrow_mut() = variables.to_vec());
+
+    if !is_browser() {
+        return;
+    }
+
+    add_theme_style(variables);
+}
+
+fn add_theme_style(variables: &[(String, String)]) -> Option<()> {
+    let document = window()?.document()?;
+
+    let style_element = match document.get_element_by_id(THEME_STYLE_ID) {
+        Some(element) => element,
+        None => {
+            let element = document.create_element("style").ok()?;
+            element.set_id(THEME_STYLE_ID);
+            document.head()?.append_child(&element).ok()?;
+            element
+        }
+    };
+
+    style_element.set_text_content(Some(&get_theme_css(variables)));
+
+    Some(())
+}
+
+/// Set the density of the whole app with its class in the root element
+pub fn set_density(density: Density) {
+    DENSITY.with(|setting| setting.set(density));
+
+    if !is_browser() {
+        return;
+    }
+
+    if let Some(root) = utils::document().document_element() {
+        let class_list = root.class_list();
+
+        for density in Density::ALL {
+            class_list.remove_1(density.class_name()).ok();
+        }
+        class_list.add_1(density.class_name()).ok();
+    }
+}
+
+/// Density of the app set with `set_density` or the `density` property of `GlobalTheme`
+pub fn get_density() -> Density {
+    DENSITY.with(|setting| setting.get())
+}
+
+/// Css variables of the theme as pairs of name and value
+pub fn get_theme_variables() -> Vec<(String, String)> {
+    let mut variables = vec![];
+
+    for palette in Palette::iter() {
+        let (background, text, light) = get_palette_colors(&palette);
+        let name = format!("--ys-{}", get_palette(palette));
+
+        variables.push((name.clone(), background.clone()));
+        variables.push((format!("{}-text", name), text));
+        variables.push((format!("{}-light", name), light));
+        variables.push((format!("{}-focus", name), darken(&background, 5.0)));
+        variables.push((format!("{}-hover", name), darken(&background, 10.0)));
+        variables.push((format!("{}-active", name), darken(&background, 15.0)));
+    }
+
+    macro_rules! push_tokens {
+        ($token:ident, $variable:expr) => {
+            for token in $token::ALL {
+                variables.push((
+                    format!("--ys-{}-{}", $variable, token.name()),
+                    token.value().to_string(),
+                ));
+            }
+        };
+    }
+
+    push_tokens!(Spacing, "spacing");
+    push_tokens!(Radius, "radius");
+    push_tokens!(Shadow, "shadow");
+    push_tokens!(Elevation, "elevation");
+    push_tokens!(FontSize, "font-size");
+    push_tokens!(FontWeight, "font-weight");
+    push_tokens!(Easing, "easing");
+    push_tokens!(Duration, "duration");
+
+    variables.push(("--ys-border-radius".to_string(), Radius::Medium.to_string()));
+    variables.push(("--ys-font-family".to_string(), FONT_FAMILY.to_string()));
+    variables.push(("--ys-error".to_string(), "#ed1c24".to_string()));
+
+    variables
+}
+
+/// Main color of the palette, the value of its css variable when it is defined,
+/// e.g. by `GlobalTheme` or `register_palette`, or else the default color of the theme
+pub fn get_palette_color(palette: &Palette) -> String {
+    let variable = format!("--ys-{}", get_palette(palette.clone()));
+    let defined = if is_browser() {
+        utils::document()
+            .document_element()
+            .and_then(|root| utils::window().get_computed_style(&root).ok().flatten())
+            .and_then(|style| style.get_property_value(&variable).ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    } else {
+        None
+    };
+
+    defined.unwrap_or_else(|| get_palette_colors(palette).0)
+}
+
+/// Css variables of the theme with the overrides, the interaction colors of an overridden palette color
+/// are darkened from it unless they are overridden too
+fn get_active_variables(overrides: &[(String, String)]) -> Vec<(String, String)> {
+    let mut variables = get_theme_variables();
+    let mut derived = vec![];
+
+    for palette in Palette::iter() {
+        let name = format!("--ys-{}", get_palette(palette));
+
+        if let Some((_, color)) = overrides
+            .iter()
+            .find(|(variable, value)| *variable == name && value.starts_with('#'))
+        {
+            for (suffix, amount) in &[("-focus", 5.0), ("-hover", 10.0), ("-active", 15.0)] {
+                derived.push((format!("{}{}", name, suffix), darken(color, *amount)));
+            }
+        }
+    }
+
+    for (name, value) in derived.iter().chain(overrides) {
+        match variables.iter_mut().find(|(variable, _)| variable == name) {
+            Some(variable) => variable.1 = value.clone(),
+            None => variables.push((name.clone(), value.clone())),
+        }
+    }
+
+    variables
+}
+
+fn get_theme_css(overrides: &[(String, String)]) -> String {
+    let variables = get_active_variables(overrides);
+    let declarations: Vec<String> = variables
+        .iter()
+        .map(|(name, value)| format!("{}: {};", name, value))
+        .collect();
+
+    format!(":root {{ {} }}", declarations.join(" "))
+}
+
+fn get_reset_css() -> String {
+    format!(
+        "*, *::before, *::after {{ box-sizing: border-box; }}
+html {{ -webkit-text-size-adjust: 100%; line-height: 1.5; }}
+body {{ margin: 0; font-family: {font_family}, -apple-system, BlinkMacSystemFont, \"Segoe UI\", Roboto, sans-serif; font-size: {font_size}; color: #313131; }}
+button, input, select, textarea {{ font: inherit; }}
+img, video, canvas {{ max-width: 100%; height: auto; }}",
+        font_family = FONT_FAMILY,
+        font_size = FontSize::Body,
+    )
+}
+
+// Same colors than $regular-style and $light-style in _global-variables.sass,
+// the custom palettes take the colors they were registered with
+fn get_palette_colors(palette: &Palette) -> (String, String, String) {
+    let (background, text, light) = match palette {
+        Palette::Standard => ("#918d94", "#fff", "#faf3f3"),
+        Palette::Primary => ("#654016", "#fff", "#e9d7c4"),
+        Palette::Secondary => ("#c77b21", "#fff", "#ffd9ac"),
+        Palette::Success => ("#40c600", "#fff", "#b6f5c6"),
+        Palette::Info => ("#008fd5", "#fff", "#cedaff"),
+        Palette::Link => ("#034da1", "#fff", "#4fb0ff"),
+        Palette::Warning => ("#fff200", "#000", "#fdffa8"),
+        Palette::Danger => ("#ed1c24", "#fff", "#fdc5c5"),
+        Palette::Clean => ("#fff", "#313131", "#fff"),
+        Palette::Custom(name) => match get_registered_palette(name) {
+            Some(palette) => return (palette.color, palette.text_color, palette.light_color),
+            None => ("inherit", "inherit", "inherit"),
+        },
+    };
+
+    (background.to_string(), text.to_string(), light.to_string())
+}
+
+/// Reduce the lightness of a hex color like sass `darken` does,
+/// colors which aren't `#rrggbb` or `#rgb` are returned unchanged
+pub(crate) fn darken(color: &str, amount: f64) -> String {
+    let (r, g, b) = match parse_hex_color(color) {
+        Some(channels) => channels,
+        None => return color.to_string(),
+    };
+    let (h, s, l) = rgb_to_hsl(r, g, b);
+    let (r, g, b) = hsl_to_rgb(h, s, (l - amount / 100.0).max(0.0));
+
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn parse_hex_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#')?;
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let hex = match hex.len() {
+        3 => hex.chars().flat_map(|c| vec![c, c]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f64, f64, f64) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    if (max - min).abs() < f64::EPSILON {
+        return (0.0, 0.0, l);
+    }
+
+    let delta = max - min;
+    let s = if l > 0.5 {
+        delta / (2.0 - max - min)
+    } else {
+        delta / (max + min)
+    };
+
+    let h = if (max - r).abs() < f64::EPSILON {
+        (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+    } else if (max - g).abs() < f64::EPSILON {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    (h / 6.0, s, l)
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let to_channel = |value: f64| (value * 255.0).round() as u8;
+
+    if s.abs() < f64::EPSILON {
+        return (to_channel(l), to_channel(l), to_channel(l));
+    }
+
+    let q = if l < 0.5 {
+        l * (1.0 + s)
+    } else {
+        l + s - l * s
+    };
+    let p = 2.0 * l - q;
+
+    let hue_to_rgb = |t: f64| {
+        let t = if t < 0.0 {
+            t + 1.0
+        } else if t > 1.0 {
+            t - 1.0
+        } else {
+            t
+        };
+
+        if t < 1.0 / 6.0 {
+            p + (q - p) * 6.0 * t
+        } else if t < 1.0 / 2.0 {
+            q
+        } else if t < 2.0 / 3.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        }
+    };
+
+    (
+        to_channel(hue_to_rgb(h + 1.0 / 3.0)),
+        to_channel(hue_to_rgb(h)),
+        to_channel(hue_to_rgb(h - 1.0 / 3.0)),
+    )
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_darken_colors_as_sass() {
+    assert_eq!(darken("#fff", 10.0), "#e6e6e6");
+    assert_eq!(darken("#ed1c24", 10.0), "#c61017");
+    assert_eq!(darken("#f00a", 10.0), "#f00a");
+    assert_eq!(darken("#abcd", 10.0), "#abcd");
+    assert_eq!(darken("#ééé", 10.0), "#ééé");
+    assert_eq!(darken("rgb(0, 0, 0)", 10.0), "rgb(0, 0, 0)");
+}
+
+#[wasm_bindgen_test]
+fn should_add_theme_variables_in_root() {
+    set_theme_variables(&[("--ys-border-radius".to_string(), "8px".to_string())]);
+
+    let css = utils::document()
+        .get_element_by_id(THEME_STYLE_ID)
+        .unwrap()
+        .text_content()
+        .unwrap();
+
+    assert!(css.starts_with(":root {"));
+    assert!(css.contains("--ys-primary: #654016;"));
+    assert!(css.contains("--ys-font-size-big: 26px;"));
+    assert!(css.contains("--ys-border-radius: 8px;"));
+    assert!(!css.contains("--ys-border-radius: 4px;"));
+}
+
+#[wasm_bindgen_test]
+fn should_get_the_reset_with_the_typography_tokens() {
+    let css = get_reset_css();
+
+    assert!(css.contains("box-sizing: border-box;"));
+    assert!(css.contains("body { margin: 0; font-family: Rosario,"));
+    assert!(css.contains("font-size: 14px;"));
+}
+
+#[wasm_bindgen_test]
+fn should_interpolate_the_theme_variables() {
+    assert_eq!(
+        interpolate_theme(".page { color: ${primary}; padding: ${ spacing-medium }; }"),
+        ".page { color: #654016; padding: 10px; }"
+    );
+    assert_eq!(
+        interpolate_theme("a { color: ${unknown}; } b { color: ${primary"),
+        "a { color: ${unknown}; } b { color: ${primary"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_use_the_active_theme() {
+    set_theme_variables(&[
+        ("--ys-primary".to_string(), "#123456".to_string()),
+        ("--ys-spacing-medium".to_string(), "12px".to_string()),
+    ]);
+
+    let theme = use_theme();
+
+    assert_eq!(theme.color(&Palette::Primary), "#123456");
+    assert_eq!(
+        theme.hover_color(&Palette::Primary),
+        darken("#123456", 10.0)
+    );
+    assert_eq!(theme.spacing(Spacing::Medium), "12px");
+    assert_eq!(theme.radius(Radius::Medium), Radius::Medium.value());
+    assert_eq!(theme.variable("border-radius"), Some("4px"));
+    assert_eq!(interpolate_theme("color: ${primary};"), "color: #123456;");
+
+    set_theme_variables(&[]);
+}
+
+#[wasm_bindgen_test]
+fn should_use_the_colors_of_the_custom_palettes() {
+    use crate::palettes::{register_palette, CustomPalette};
+
+    let palette = register_palette(CustomPalette {
+        name: "theme-brand".to_string(),
+        color: "#6a1b9a".to_string(),
+        text_color: "#fff".to_string(),
+        light_color: "#e1bee7".to_string(),
+    })
+    .unwrap();
+
+    let theme = use_theme();
+
+    assert_eq!(theme.text_color(&palette), "#fff");
+    assert_eq!(theme.light_color(&palette), "#e1bee7");
+    assert_eq!(
+        get_palette_colors(&Palette::Custom("unknown".to_string())).0,
+        "inherit"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_set_the_density_in_the_root_element() {
+    set_density(Density::Compact);
+    set_density(Density::Spacious);
+
+    let class_list = utils::document().document_element().unwrap().class_list();
+
+    assert_eq!(get_density(), Density::Spacious);
+    assert!(class_list.contains("ys-spacious"));
+    assert!(!class_list.contains("ys-compact"));
+
+    set_density(Density::default());
+}
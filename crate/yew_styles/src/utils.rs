@@ -1,13 +1,16 @@
 extern crate rand;
 extern crate wasm_bindgen;
 extern crate web_sys;
+use js_sys::{Function, Reflect};
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
 use wasm_bindgen_test::*;
 use web_sys::{window, HtmlElement};
-use yew::utils;
+use yew::{utils, Callback};
 
-pub fn create_style(style: String, value: String, wrap: String) {
+pub mod timing;
+
+pub(crate) fn create_style(style: String, value: String, wrap: String) {
     let element = get_html_element_by_class(&wrap, 0);
 
     element.style().set_property(&style, &value).unwrap();
@@ -21,7 +24,7 @@ pub fn get_random_string(len: usize) -> String {
         .collect()
 }
 
-pub fn get_html_element_by_class(class_name: &str, index: u32) -> HtmlElement {
+pub(crate) fn get_html_element_by_class(class_name: &str, index: u32) -> HtmlElement {
     utils::document()
         .get_elements_by_class_name(class_name)
         .get_with_index(index)
@@ -30,6 +33,79 @@ pub fn get_html_element_by_class(class_name: &str, index: u32) -> HtmlElement {
         .unwrap()
 }
 
+/// If the browser APIs are available. They are not when the components are rendered in the server,
+/// where the access to the document, the window or the storage must be skipped
+pub fn is_browser() -> bool {
+    cfg!(target_arch = "wasm32")
+}
+
+/// Write the text in the clipboard, it returns `false` when the clipboard api is not available
+pub fn copy_to_clipboard(text: &str) -> bool {
+    write_text(text).is_some()
+}
+
+/// Write the text in the clipboard and emit `true` when the clipboard api resolves the write,
+/// `false` when it is rejected or the clipboard api is not available
+pub fn copy_to_clipboard_with_result(text: &str, onresult: Callback<bool>) {
+    let then = write_text(text).and_then(|promise| {
+        Reflect::get(&promise, &"then".into())
+            .ok()
+            .and_then(|then| then.dyn_into::<Function>().ok())
+            .map(|then| (promise, then))
+    });
+
+    match then {
+        Some((promise, then)) => {
+            let onresolve = onresult.clone();
+            let onreject = onresult.clone();
+            let resolve = Closure::once_into_js(move |_: JsValue| onresolve.emit(true));
+            let reject = Closure::once_into_js(move |_: JsValue| onreject.emit(false));
+
+            if then.call2(&promise, &resolve, &reject).is_err() {
+                onresult.emit(false);
+            }
+        }
+        None => onresult.emit(false),
+    }
+}
+
+// Promise returned by `navigator.clipboard.writeText`, `None` when the clipboard api is not available
+fn write_text(text: &str) -> Option<JsValue> {
+    if !is_browser() {
+        return None;
+    }
+
+    let clipboard = window()
+        .and_then(|window| Reflect::get(&window, &"navigator".into()).ok())
+        .and_then(|navigator| Reflect::get(&navigator, &"clipboard".into()).ok())
+        .filter(|clipboard| !clipboard.is_undefined())?;
+
+    Reflect::get(&clipboard, &"writeText".into())
+        .ok()
+        .and_then(|write_text| write_text.dyn_into::<Function>().ok())
+        .and_then(|write_text| write_text.call1(&clipboard, &text.into()).ok())
+}
+
+pub fn get_stored_value(key: &str) -> Option<String> {
+    if !is_browser() {
+        return None;
+    }
+
+    window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+}
+
+pub fn set_stored_value(key: &str, value: &str) {
+    if !is_browser() {
+        return;
+    }
+
+    if let Some(storage) = window().and_then(|window| window.local_storage().ok().flatten()) {
+        storage.set_item(key, value).ok();
+    }
+}
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
@@ -91,3 +167,14 @@ fn should_generate_random_string() {
         assert!(!*repeat);
     }
 }
+
+#[wasm_bindgen_test]
+fn should_store_values() {
+    set_stored_value("yew-styles-test", "true");
+
+    assert_eq!(
+        get_stored_value("yew-styles-test"),
+        Some("true".to_string())
+    );
+    assert_eq!(get_stored_value("yew-styles-missing"), None);
+}
@@ -0,0 +1,280 @@
+//! Wrappers of `Callback` which limit how often it is emitted. They can be passed as the signals of the
+//! components or used with any callback. The delayed values are emitted by a timer, so the events received
+//! that way can't be prevented anymore
+//!
+//! The wrapper keeps the timer, so it has to be created once, e.g. in `create`, and stored in the component.
+//! A wrapper created in `view` is replaced on each render, which drops its timer and the value waiting in it
+//!
+//! ## Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_styles::forms::form_input::FormInput;
+//! use yew_styles::utils::timing::debounce;
+//!
+//! pub struct Search {
+//!     search: Callback<InputData>,
+//!     query: String,
+//! }
+//!
+//! pub enum Msg {
+//!     Search(InputData),
+//! }
+//!
+//! impl Component for Search {
+//!     type Message = Msg;
+//!     type Properties = ();
+//!
+//!     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+//!         Self {
+//!             search: debounce(link.callback(Msg::Search), 300),
+//!             query: String::new(),
+//!         }
+//!     }
+//!
+//!     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+//!         match msg {
+//!             Msg::Search(input_data) => {
+//!                 self.query = input_data.value;
+//!             }
+//!         }
+//!         true
+//!     }
+//!
+//!     fn change(&mut self, _: Self::Properties) -> ShouldRender {
+//!         false
+//!     }
+//!
+//!     fn view(&self) -> Html {
+//!         html! {
+//!             <FormInput oninput_signal=self.search.clone() />
+//!         }
+//!     }
+//! }
+//! ```
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+use yew::Callback;
+
+struct Debounce<IN> {
+    value: Option<IN>,
+    task: Option<TimeoutTask>,
+}
+
+struct Throttle<IN> {
+    /// Time of the last emit in milliseconds
+    last_emit: Option<f64>,
+    /// Last value received during the wait, it is emitted when the wait ends
+    value: Option<IN>,
+    scheduled: bool,
+    task: Option<TimeoutTask>,
+}
+
+/// Emits only the last value after `ms` milliseconds without receiving new ones, e.g. to search while typing
+pub fn debounce<IN: 'static>(callback: Callback<IN>, ms: u64) -> Callback<IN> {
+    let state = Rc::new(RefCell::new(Debounce {
+        value: None,
+        task: None,
+    }));
+
+    Callback::from(move |value: IN| {
+        let weak_state = Rc::downgrade(&state);
+        let callback = callback.clone();
+        // The previous task is dropped, which cancels its timer
+        let task = TimeoutService::spawn(
+            Duration::from_millis(ms),
+            Callback::from(move |_| {
+                let value = weak_state
+                    .upgrade()
+                    .and_then(|state| state.borrow_mut().value.take());
+
+                if let Some(value) = value {
+                    callback.emit(value);
+                }
+            }),
+        );
+
+        let mut state = state.borrow_mut();
+        state.value = Some(value);
+        state.task = Some(task);
+    })
+}
+
+/// Emits the first value at once and then at most one value each `ms` milliseconds,
+/// the last value received during the wait is emitted when it ends, e.g. to follow the scroll or a drag
+pub fn throttle<IN: 'static>(callback: Callback<IN>, ms: u64) -> Callback<IN> {
+    let state = Rc::new(RefCell::new(Throttle {
+        last_emit: None,
+        value: None,
+        scheduled: false,
+        task: None,
+    }));
+
+    Callback::from(move |value: IN| {
+        let now = js_sys::Date::now();
+        let wait = get_throttle_wait(state.borrow().last_emit, now, ms);
+
+        if wait <= 0.0 {
+            {
+                let mut state = state.borrow_mut();
+                state.last_emit = Some(now);
+                state.value = None;
+                state.scheduled = false;
+                state.task = None;
+            }
+            callback.emit(value);
+            return;
+        }
+
+        let mut throttle = state.borrow_mut();
+        throttle.value = Some(value);
+
+        if throttle.scheduled {
+            return;
+        }
+
+        let weak_state = Rc::downgrade(&state);
+        let callback = callback.clone();
+        throttle.scheduled = true;
+        throttle.task = Some(TimeoutService::spawn(
+            Duration::from_millis(wait as u64),
+            Callback::from(move |_| {
+                let value = weak_state.upgrade().and_then(|state| {
+                    let mut state = state.borrow_mut();
+                    state.scheduled = false;
+                    state.last_emit = Some(js_sys::Date::now());
+                    state.value.take()
+                });
+
+                if let Some(value) = value {
+                    callback.emit(value);
+                }
+            }),
+        ));
+    })
+}
+
+/// Milliseconds to wait before the next emit, zero or less when it can be emitted at once
+fn get_throttle_wait(last_emit: Option<f64>, now: f64, ms: u64) -> f64 {
+    match last_emit {
+        Some(last_emit) => last_emit + ms as f64 - now,
+        None => 0.0,
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_the_throttle_wait() {
+    assert_eq!(get_throttle_wait(None, 1000.0, 200), 0.0);
+    assert_eq!(get_throttle_wait(Some(900.0), 1000.0, 200), 100.0);
+    assert!(get_throttle_wait(Some(700.0), 1000.0, 200) <= 0.0);
+}
+
+#[wasm_bindgen_test]
+fn should_limit_the_emits() {
+    use std::cell::Cell;
+
+    let debounced = Rc::new(Cell::new(0));
+    let throttled = Rc::new(Cell::new(0));
+    let debounced_count = debounced.clone();
+    let throttled_count = throttled.clone();
+    let debounce_callback = debounce(
+        Callback::from(move |_: ()| debounced_count.set(debounced_count.get() + 1)),
+        200,
+    );
+    let throttle_callback = throttle(
+        Callback::from(move |_: ()| throttled_count.set(throttled_count.get() + 1)),
+        200,
+    );
+
+    debounce_callback.emit(());
+    debounce_callback.emit(());
+    throttle_callback.emit(());
+    throttle_callback.emit(());
+
+    assert_eq!(debounced.get(), 0);
+    assert_eq!(throttled.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn should_emit_once_through_the_same_wrapper() {
+    let debounced = Rc::new(RefCell::new(vec![]));
+    let throttled = Rc::new(RefCell::new(vec![]));
+    let debounced_values = debounced.clone();
+    let throttled_values = throttled.clone();
+    let debounce_callback = debounce(
+        Callback::from(move |value: u32| debounced_values.borrow_mut().push(value)),
+        50,
+    );
+    let throttle_callback = throttle(
+        Callback::from(move |value: u32| throttled_values.borrow_mut().push(value)),
+        50,
+    );
+
+    for value in 1..=3 {
+        debounce_callback.emit(value);
+        throttle_callback.emit(value);
+    }
+
+    sleep(100).await;
+
+    assert_eq!(*debounced.borrow(), vec![3]);
+    assert_eq!(*throttled.borrow(), vec![1, 3]);
+}
+
+/// Resolves after `ms` milliseconds, the tests wait with it for the timers of the wrappers
+struct Sleep {
+    state: Rc<RefCell<SleepState>>,
+    _task: TimeoutTask,
+}
+
+struct SleepState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+fn sleep(ms: u64) -> Sleep {
+    let state = Rc::new(RefCell::new(SleepState {
+        done: false,
+        waker: None,
+    }));
+    let timer_state = state.clone();
+    let task = TimeoutService::spawn(
+        Duration::from_millis(ms),
+        Callback::from(move |_| {
+            let waker = {
+                let mut state = timer_state.borrow_mut();
+                state.done = true;
+                state.waker.take()
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }),
+    );
+
+    Sleep { state, _task: task }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<()> {
+        let mut state = self.state.borrow_mut();
+
+        if state.done {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(context.waker().clone());
+            Poll::Pending
+        }
+    }
+}
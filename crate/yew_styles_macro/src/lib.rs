@@ -0,0 +1,259 @@
+//! # Yew Styles Macro
+//!
+//! Macros to use the standard yew_styles properties in user components.
+//! They are re-exported by yew_styles with the `macros` feature
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Error, Fields, FieldsNamed, ItemStruct};
+
+/// Adds the standard yew_styles properties to the component props:
+/// `class_name`, `id`, `key`, `code_ref`, `styles`, `palette`, `size`, `margin` and `padding`.
+/// The properties already declared in the struct are kept.
+///
+/// It also generates the `style_classes` method which composes the classes with `styles::ComponentClasses`,
+/// in the same order than yew_styles components: the base class, the palette, the size, the spacing,
+/// the stylist styles and the custom class.
+/// The attribute must be placed before `#[derive(Properties)]`.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::style_props;
+///
+/// #[style_props]
+/// #[derive(Clone, Properties, PartialEq)]
+/// pub struct Props {
+///     pub title: String,
+/// }
+///
+/// pub struct Badge {
+///     props: Props,
+/// }
+///
+/// impl Component for Badge {
+///     type Message = ();
+///     type Properties = Props;
+///
+///     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+///         Self { props }
+///     }
+///
+///     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, props: Self::Properties) -> ShouldRender {
+///         if self.props != props {
+///             self.props = props;
+///             true
+///         } else {
+///             false
+///         }
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <span
+///                 class=self.props.style_classes("badge")
+///                 id=self.props.id.clone()
+///                 key=self.props.key.clone()
+///                 ref=self.props.code_ref.clone()
+///             >{self.props.title.clone()}</span>
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn style_props(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(item as ItemStruct);
+
+    expand_style_props(item_struct).into()
+}
+
+fn expand_style_props(mut item_struct: ItemStruct) -> proc_macro2::TokenStream {
+    let name = item_struct.ident.clone();
+    let generics = item_struct.generics.clone();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields = match &mut item_struct.fields {
+        Fields::Named(fields) => fields,
+        _ => {
+            return Error::new_spanned(
+                &item_struct,
+                "style_props only supports structs with named fields",
+            )
+            .to_compile_error()
+        }
+    };
+
+    let style_fields: FieldsNamed = parse_quote!({
+        /// General property to add custom class styles
+        #[prop_or_default]
+        pub class_name: String,
+        /// General property to add custom id
+        #[prop_or_default]
+        pub id: String,
+        /// General property to add keys
+        #[prop_or_default]
+        pub key: String,
+        /// General property to get the ref of the component
+        #[prop_or_default]
+        pub code_ref: ::yew::html::NodeRef,
+        /// Set css styles directly in the component
+        #[prop_or(::stylist::css!(""))]
+        pub styles: ::stylist::StyleSource<'static>,
+        /// Type purpose style. Default `Palette::Standard`
+        #[prop_or(::yew_styles::styles::Palette::Standard)]
+        pub palette: ::yew_styles::styles::Palette,
+        /// Three diffent standard sizes. Default `Size::Medium`
+        #[prop_or(::yew_styles::styles::Size::Medium)]
+        pub size: ::yew_styles::styles::Size,
+        /// Margin from the spacing scale, e.g. `Spacing::Medium`. Default `Spaces::default()`
+        #[prop_or_default]
+        pub margin: ::yew_styles::styles::tokens::Spaces,
+        /// Padding from the spacing scale. Default `Spaces::default()`
+        #[prop_or_default]
+        pub padding: ::yew_styles::styles::tokens::Spaces,
+    });
+
+    let declared = fields
+        .named
+        .iter()
+        .filter_map(|field| field.ident.clone())
+        .collect::<Vec<_>>();
+
+    for field in style_fields.named {
+        if !declared
+            .iter()
+            .any(|ident| Some(ident) == field.ident.as_ref())
+        {
+            fields.named.push(field);
+        }
+    }
+
+    let expanded = quote! {
+        #item_struct
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Compose the base class with the palette, size, spacing, styles and custom class
+            pub fn style_classes(&self, base: &'static str) -> ::yew::html::Classes {
+                ::yew_styles::styles::ComponentClasses::new(base)
+                    .semantic(vec![
+                        ::yew_styles::styles::get_palette(self.palette.clone()),
+                        ::yew_styles::styles::get_size(self.size.clone()),
+                    ])
+                    .spacing(self.margin, self.padding)
+                    .styles(self.styles.clone())
+                    .class_name(self.class_name.clone())
+                    .build()
+            }
+        }
+    };
+
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::{ImplItem, Item};
+
+    fn expand(item_struct: ItemStruct) -> Vec<Item> {
+        syn::parse2::<syn::File>(expand_style_props(item_struct))
+            .unwrap()
+            .items
+    }
+
+    fn field_names(item: &Item) -> Vec<String> {
+        match item {
+            Item::Struct(item_struct) => item_struct
+                .fields
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap().to_string())
+                .collect(),
+            _ => panic!("expected the props struct"),
+        }
+    }
+
+    #[test]
+    fn should_add_the_style_properties() {
+        let items = expand(parse_quote! {
+            #[derive(Clone, Properties, PartialEq)]
+            pub struct Props {
+                pub title: String,
+            }
+        });
+
+        assert_eq!(
+            field_names(&items[0]),
+            vec![
+                "title",
+                "class_name",
+                "id",
+                "key",
+                "code_ref",
+                "styles",
+                "palette",
+                "size",
+                "margin",
+                "padding"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_the_declared_style_properties() {
+        let items = expand(parse_quote! {
+            #[derive(Clone, Properties, PartialEq)]
+            pub struct Props {
+                #[prop_or(Palette::Primary)]
+                pub palette: Palette,
+            }
+        });
+
+        let names = field_names(&items[0]);
+
+        assert_eq!(names.iter().filter(|name| *name == "palette").count(), 1);
+        assert_eq!(names[0], "palette");
+        assert_eq!(names.len(), 9);
+    }
+
+    #[test]
+    fn should_compose_the_style_classes_in_order() {
+        let items = expand(parse_quote! {
+            pub struct Props {
+                pub title: String,
+            }
+        });
+
+        let method = match &items[1] {
+            Item::Impl(item_impl) => match &item_impl.items[0] {
+                ImplItem::Method(method) => method.clone(),
+                _ => panic!("expected the style_classes method"),
+            },
+            _ => panic!("expected the impl of the props"),
+        };
+        let body = method.block.into_token_stream().to_string();
+        let position = |call: &str| body.find(call).unwrap();
+
+        assert_eq!(method.sig.ident, "style_classes");
+        assert!(body.contains("ComponentClasses :: new (base)"));
+        assert!(position(". semantic") < position(". spacing"));
+        assert!(position(". spacing") < position(". styles"));
+        assert!(position(". styles") < position(". class_name"));
+        assert!(position(". class_name") < position(". build"));
+    }
+
+    #[test]
+    fn should_reject_the_tuple_structs() {
+        let expanded = expand_style_props(parse_quote! {
+            pub struct Props(String);
+        });
+
+        assert!(expanded.to_string().contains("compile_error"));
+    }
+}